@@ -1,5 +1,6 @@
 """Admin routes for tournament management."""
 
+import hashlib
 import json
 import logging
 import random
@@ -348,6 +349,8 @@ def _get_finalization_service() -> FinalizationService:
         solana_service=solana_service,
         admin_keypair=admin_keypair,
         points_mint=points_mint,
+        vesting_threshold_points=settings.VESTING_THRESHOLD_POINTS,
+        dispute_window_seconds=settings.DISPUTE_WINDOW_SECONDS,
     )
 
 
@@ -413,6 +416,8 @@ async def begin_tournament(
         ix = solana_service.build_start_tournament_ix(
             admin=admin_keypair.pubkey(),
             tournament_id=tournament.on_chain_id,
+            engine_version_hash=hashlib.sha256(settings.ENGINE_VERSION.encode()).digest(),
+            model_id_hash=hashlib.sha256(settings.ANTHROPIC_MODEL.encode()).digest(),
         )
 
         recent_blockhash = await solana_service.get_blockhash()
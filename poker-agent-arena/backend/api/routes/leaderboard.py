@@ -41,6 +41,9 @@ async def get_leaderboard(
                 ps.tournaments_played,
                 ps.tournaments_won,
                 ps.best_finish,
+                pp.display_name,
+                pp.avatar_uri,
+                pp.country_code,
                 (
                     SELECT r.agent_name
                     FROM registrations r
@@ -49,6 +52,7 @@ async def get_leaderboard(
                     LIMIT 1
                 ) as agent_name
             FROM player_stats ps
+            LEFT JOIN player_profiles pp ON pp.wallet = ps.wallet
             WHERE ps.total_points > 0
             ORDER BY ps.total_points DESC
             LIMIT :limit OFFSET :offset
@@ -63,6 +67,9 @@ async def get_leaderboard(
                 rank=offset + i + 1,
                 wallet=row.wallet,
                 agent_name=row.agent_name,
+                display_name=row.display_name,
+                avatar_uri=row.avatar_uri,
+                country_code=row.country_code,
                 total_points=row.total_points,
                 tournaments_played=row.tournaments_played,
                 tournaments_won=row.tournaments_won,
@@ -105,8 +112,12 @@ async def get_player_rank(
                 WHERE reg.wallet = r.wallet
                 ORDER BY reg.registered_at DESC
                 LIMIT 1
-            ) as agent_name
+            ) as agent_name,
+            pp.display_name,
+            pp.avatar_uri,
+            pp.country_code
             FROM ranked r
+            LEFT JOIN player_profiles pp ON pp.wallet = r.wallet
             WHERE r.wallet = :wallet
         """),
         {"wallet": wallet},
@@ -120,6 +131,9 @@ async def get_player_rank(
         rank=row.rank,
         wallet=row.wallet,
         agent_name=row.agent_name,
+        display_name=row.display_name,
+        avatar_uri=row.avatar_uri,
+        country_code=row.country_code,
         total_points=row.total_points,
         tournaments_played=row.tournaments_played,
         tournaments_won=row.tournaments_won,
@@ -13,6 +13,9 @@ class LeaderboardEntry(BaseModel):
     rank: int
     wallet: str
     agent_name: str | None = None
+    display_name: str | None = None
+    avatar_uri: str | None = None
+    country_code: str | None = None
     total_points: int
     tournaments_played: int
     tournaments_won: int
@@ -45,6 +45,9 @@ class Settings(BaseSettings):
     ANTHROPIC_MODEL: str = "claude-sonnet-4-5-20250929"
 
     # Tournament
+    ENGINE_VERSION: str = "0.1.0"
+    VESTING_THRESHOLD_POINTS: int = 0  # Must match ArenaConfig.vesting_threshold_points (0 = disabled)
+    DISPUTE_WINDOW_SECONDS: int = 0  # Must match ArenaConfig.dispute_window_seconds (0 = no escrow)
     MAX_TOURNAMENT_BUDGET_MULTIPLIER: float = 3.0
     DECISION_TIMEOUT_NORMAL: int = 5
     DECISION_TIMEOUT_ALLIN: int = 10
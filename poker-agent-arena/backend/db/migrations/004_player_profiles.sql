@@ -0,0 +1,16 @@
+-- Player Profiles
+-- Mirrors the on-chain PlayerProfile PDA so leaderboards show a display identity
+
+CREATE TABLE IF NOT EXISTS player_profiles (
+    wallet VARCHAR(44) PRIMARY KEY,
+
+    display_name VARCHAR(32) NOT NULL,
+    avatar_uri VARCHAR(128),
+    bio_hash VARCHAR(64),
+    country_code CHAR(2),
+
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+COMMENT ON TABLE player_profiles IS 'Display identity from the on-chain PlayerProfile account';
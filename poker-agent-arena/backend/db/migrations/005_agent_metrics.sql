@@ -0,0 +1,10 @@
+-- Agent Behavioral Metrics
+-- Engine metrics recorded on-chain with each player's result
+
+ALTER TABLE registrations ADD COLUMN IF NOT EXISTS vpip_bps INTEGER;
+ALTER TABLE registrations ADD COLUMN IF NOT EXISTS aggression_factor_centi INTEGER;
+ALTER TABLE registrations ADD COLUMN IF NOT EXISTS showdown_win_bps INTEGER;
+
+COMMENT ON COLUMN registrations.vpip_bps IS 'Voluntarily put money in pot, basis points of hands dealt';
+COMMENT ON COLUMN registrations.aggression_factor_centi IS 'Aggression factor ((bets + raises) / calls) x 100';
+COMMENT ON COLUMN registrations.showdown_win_bps IS 'Showdowns won, basis points of showdowns reached';
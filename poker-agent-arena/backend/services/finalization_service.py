@@ -30,11 +30,15 @@ class FinalizationService:
         solana_service: SolanaService,
         admin_keypair: Keypair,
         points_mint: Pubkey,
+        vesting_threshold_points: int = 0,
+        dispute_window_seconds: int = 0,
     ):
         self.solana = solana_service
         self.admin_keypair = admin_keypair
         self.admin_pubkey = admin_keypair.pubkey()
         self.points_mint = points_mint
+        self.vesting_threshold_points = vesting_threshold_points
+        self.dispute_window_seconds = dispute_window_seconds
 
     async def generate_results_hash(
         self,
@@ -95,7 +99,10 @@ class FinalizationService:
         db: AsyncSession,
         tournament_id: UUID,
     ) -> str:
-        """Call FinalizeTournament instruction on-chain.
+        """Call SubmitResults instruction on-chain.
+
+        Results stay provisional on-chain until ConfirmResults is called
+        after the dispute window closes.
 
         Returns:
             Transaction signature
@@ -130,7 +137,7 @@ class FinalizationService:
         winner_pubkey = Pubkey.from_string(winner_reg.wallet)
 
         # Build instruction
-        ix = self.solana.build_finalize_tournament_ix(
+        ix = self.solana.build_submit_results_ix(
             admin=self.admin_pubkey,
             tournament_id=tournament.on_chain_id,
             results_hash=results_hash,
@@ -224,6 +231,10 @@ class FinalizationService:
                     points_awarded=reg.points_awarded or 0,
                     hands_played=reg.hands_played or 0,
                     eliminations=reg.eliminations or 0,
+                    vpip_bps=reg.vpip_bps or 0,
+                    aggression_factor_centi=reg.aggression_factor_centi or 0,
+                    showdown_win_bps=reg.showdown_win_bps or 0,
+                    stats_v2=True,
                 )
 
                 # Build and send transaction
@@ -326,6 +337,9 @@ class FinalizationService:
                     player_wallet=player_wallet,
                     points_mint=self.points_mint,
                     player_token_account=player_token_account,
+                    vest=0 < self.vesting_threshold_points <= reg.points_awarded,
+                    # Points distributed right after finalization fall inside the dispute window
+                    escrow=self.dispute_window_seconds > 0,
                 )
 
                 # Build and send transaction
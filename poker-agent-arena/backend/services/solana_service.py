@@ -110,6 +110,53 @@ class SolanaService:
             self.program_id,
         )
 
+    def get_player_profile_pda(self, wallet_pubkey: Pubkey) -> tuple[Pubkey, int]:
+        """Derive player profile PDA."""
+        return Pubkey.find_program_address(
+            [b"player_profile", bytes(wallet_pubkey)],
+            self.program_id,
+        )
+
+    def get_backing_pool_pda(
+        self,
+        tournament_pubkey: Pubkey,
+        wallet_pubkey: Pubkey,
+    ) -> tuple[Pubkey, int]:
+        """Derive backing pool PDA."""
+        return Pubkey.find_program_address(
+            [b"backing_pool", bytes(tournament_pubkey), bytes(wallet_pubkey)],
+            self.program_id,
+        )
+
+    def get_vesting_pda(
+        self,
+        tournament_pubkey: Pubkey,
+        wallet_pubkey: Pubkey,
+    ) -> tuple[Pubkey, int]:
+        """Derive vesting account PDA."""
+        return Pubkey.find_program_address(
+            [b"vesting", bytes(tournament_pubkey), bytes(wallet_pubkey)],
+            self.program_id,
+        )
+
+    def get_player_stats_v2_pda(self, wallet_pubkey: Pubkey) -> tuple[Pubkey, int]:
+        """Derive extended player stats PDA."""
+        return Pubkey.find_program_address(
+            [b"player_stats_v2", bytes(wallet_pubkey)],
+            self.program_id,
+        )
+
+    def get_points_escrow_pda(
+        self,
+        tournament_pubkey: Pubkey,
+        wallet_pubkey: Pubkey,
+    ) -> tuple[Pubkey, int]:
+        """Derive points escrow PDA."""
+        return Pubkey.find_program_address(
+            [b"points_escrow", bytes(tournament_pubkey), bytes(wallet_pubkey)],
+            self.program_id,
+        )
+
     def get_points_mint_authority_pda(self) -> tuple[Pubkey, int]:
         """Derive points mint authority PDA."""
         return Pubkey.find_program_address(
@@ -190,14 +237,20 @@ class SolanaService:
         self,
         admin: Pubkey,
         tournament_id: int,
+        engine_version_hash: bytes,
+        model_id_hash: bytes,
     ) -> Instruction:
         """Build StartTournament instruction.
 
         Accounts:
-        - admin: Signer
+        - authority: Signer (admin or tournament operator)
         - arena_config: PDA
         - tournament: PDA
         - recent_slothashes: Sysvar
+
+        Args:
+        - engine_version_hash: SHA-256 hash of the game engine build (32 bytes)
+        - model_id_hash: SHA-256 hash of the LLM model identifier (32 bytes)
         """
         arena_config_pda, _ = self.get_arena_config_pda()
         tournament_pda, _ = self.get_tournament_pda(tournament_id)
@@ -209,6 +262,7 @@ class SolanaService:
 
         # Anchor discriminator for start_tournament
         discriminator = hashlib.sha256(b"global:start_tournament").digest()[:8]
+        data = discriminator + engine_version_hash + model_id_hash
 
         accounts = [
             AccountMeta(pubkey=admin, is_signer=True, is_writable=False),
@@ -220,22 +274,26 @@ class SolanaService:
         return Instruction(
             program_id=self.program_id,
             accounts=accounts,
-            data=discriminator,
+            data=data,
         )
 
-    def build_finalize_tournament_ix(
+    def build_submit_results_ix(
         self,
         admin: Pubkey,
         tournament_id: int,
         results_hash: bytes,
         winner: Pubkey,
     ) -> Instruction:
-        """Build FinalizeTournament instruction.
+        """Build SubmitResults instruction.
+
+        Results are provisional until ConfirmResults is called after the
+        dispute window closes.
 
         Accounts:
-        - admin: Signer
+        - authority: Signer (admin, operator, or results authority)
         - arena_config: PDA
         - tournament: PDA
+        - winner_registration: PDA
 
         Args:
         - results_hash: SHA-256 hash of final standings JSON (32 bytes)
@@ -243,9 +301,10 @@ class SolanaService:
         """
         arena_config_pda, _ = self.get_arena_config_pda()
         tournament_pda, _ = self.get_tournament_pda(tournament_id)
+        winner_registration_pda, _ = self.get_registration_pda(tournament_pda, winner)
 
-        # Anchor discriminator for finalize_tournament
-        discriminator = hashlib.sha256(b"global:finalize_tournament").digest()[:8]
+        # Anchor discriminator for submit_results
+        discriminator = hashlib.sha256(b"global:submit_results").digest()[:8]
 
         # Serialize args: results_hash (32 bytes) + winner (32 bytes)
         data = discriminator + results_hash[:32] + bytes(winner)
@@ -254,6 +313,7 @@ class SolanaService:
             AccountMeta(pubkey=admin, is_signer=True, is_writable=False),
             AccountMeta(pubkey=arena_config_pda, is_signer=False, is_writable=False),
             AccountMeta(pubkey=tournament_pda, is_signer=False, is_writable=True),
+            AccountMeta(pubkey=winner_registration_pda, is_signer=False, is_writable=False),
         ]
 
         return Instruction(
@@ -262,6 +322,37 @@ class SolanaService:
             data=data,
         )
 
+    def build_confirm_results_ix(
+        self,
+        admin: Pubkey,
+        tournament_id: int,
+    ) -> Instruction:
+        """Build ConfirmResults instruction.
+
+        Accounts:
+        - authority: Signer, writable (admin, operator, or results authority)
+        - arena_config: PDA
+        - tournament: PDA
+        """
+        arena_config_pda, _ = self.get_arena_config_pda()
+        tournament_pda, _ = self.get_tournament_pda(tournament_id)
+
+        # Anchor discriminator for confirm_results
+        discriminator = hashlib.sha256(b"global:confirm_results").digest()[:8]
+
+        # Champion trophy accounts are optional trailing accounts and omitted here
+        accounts = [
+            AccountMeta(pubkey=admin, is_signer=True, is_writable=True),
+            AccountMeta(pubkey=arena_config_pda, is_signer=False, is_writable=False),
+            AccountMeta(pubkey=tournament_pda, is_signer=False, is_writable=True),
+        ]
+
+        return Instruction(
+            program_id=self.program_id,
+            accounts=accounts,
+            data=discriminator,
+        )
+
     def build_record_player_result_ix(
         self,
         admin: Pubkey,
@@ -272,22 +363,32 @@ class SolanaService:
         points_awarded: int,
         hands_played: int,
         eliminations: int,
+        vpip_bps: int = 0,
+        aggression_factor_centi: int = 0,
+        showdown_win_bps: int = 0,
+        stats_v2: bool = False,
     ) -> Instruction:
         """Build RecordPlayerResult instruction.
 
+        Set `stats_v2` to also update the player's extended PlayerStatsV2.
+
         Accounts:
-        - admin: Signer, mut
+        - authority: Signer (admin or tournament operator), mut
         - arena_config: PDA
         - tournament: PDA
         - registration: PDA
         - player_stats: PDA (init_if_needed)
         - system_program
+        - trophy, season and guild accounts: optional (omitted)
+        - player_stats_v2: PDA, init_if_needed (only with stats_v2)
 
         Args:
         - final_rank: u16
         - points_awarded: u64
         - hands_played: u32
         - eliminations: u8
+        - metrics: AgentMetrics { vpip_bps: u16, aggression_factor_centi: u16,
+          showdown_win_bps: u16 }
         """
         arena_config_pda, _ = self.get_arena_config_pda()
         tournament_pda, _ = self.get_tournament_pda(tournament_id)
@@ -306,6 +407,9 @@ class SolanaService:
             + points_awarded.to_bytes(8, "little")
             + hands_played.to_bytes(4, "little")
             + eliminations.to_bytes(1, "little")
+            + vpip_bps.to_bytes(2, "little")
+            + aggression_factor_centi.to_bytes(2, "little")
+            + showdown_win_bps.to_bytes(2, "little")
         )
 
         accounts = [
@@ -316,6 +420,14 @@ class SolanaService:
             AccountMeta(pubkey=player_stats_pda, is_signer=False, is_writable=True),
             AccountMeta(pubkey=system_program, is_signer=False, is_writable=False),
         ]
+        if stats_v2:
+            player_stats_v2_pda, _ = self.get_player_stats_v2_pda(player_wallet)
+            # Omitted trophy (7), season (2) and guild (3) accounts are passed as the program ID
+            accounts += [
+                AccountMeta(pubkey=self.program_id, is_signer=False, is_writable=False)
+                for _ in range(12)
+            ]
+            accounts.append(AccountMeta(pubkey=player_stats_v2_pda, is_signer=False, is_writable=True))
 
         return Instruction(
             program_id=self.program_id,
@@ -331,11 +443,19 @@ class SolanaService:
         player_wallet: Pubkey,
         points_mint: Pubkey,
         player_token_account: Pubkey,
+        vest: bool = False,
+        escrow: bool = False,
     ) -> Instruction:
         """Build DistributePoints instruction.
 
+        Set `vest` when the award meets the on-chain vesting threshold, so the
+        POINTS are recorded on a vesting account instead of minted. Set
+        `escrow` while the tournament's dispute window is open, so the POINTS
+        are held in a points escrow until `release_points`; escrow takes
+        precedence over vesting.
+
         Accounts:
-        - admin: Signer
+        - admin: Signer, writable
         - arena_config: PDA
         - tournament: PDA
         - registration: PDA
@@ -343,11 +463,17 @@ class SolanaService:
         - mint_authority: PDA
         - player_token_account: Token account
         - token_program
+        - backing_pool: PDA (may be uninitialized)
+        - guild_member, guild, guild_token_account: optional (omitted)
+        - vesting_account: PDA (only when vesting)
+        - system_program (only when vesting or escrowing)
+        - points_escrow: PDA (only when escrowing)
         """
         arena_config_pda, _ = self.get_arena_config_pda()
         tournament_pda, _ = self.get_tournament_pda(tournament_id)
         registration_pda, _ = self.get_registration_pda(tournament_pubkey, player_wallet)
         mint_authority_pda, _ = self.get_points_mint_authority_pda()
+        backing_pool_pda, _ = self.get_backing_pool_pda(tournament_pubkey, player_wallet)
 
         token_program = Pubkey.from_string(
             "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
@@ -357,7 +483,7 @@ class SolanaService:
         discriminator = hashlib.sha256(b"global:distribute_points").digest()[:8]
 
         accounts = [
-            AccountMeta(pubkey=admin, is_signer=True, is_writable=False),
+            AccountMeta(pubkey=admin, is_signer=True, is_writable=True),
             AccountMeta(pubkey=arena_config_pda, is_signer=False, is_writable=False),
             AccountMeta(pubkey=tournament_pda, is_signer=False, is_writable=False),
             AccountMeta(pubkey=registration_pda, is_signer=False, is_writable=True),
@@ -365,7 +491,27 @@ class SolanaService:
             AccountMeta(pubkey=mint_authority_pda, is_signer=False, is_writable=False),
             AccountMeta(pubkey=player_token_account, is_signer=False, is_writable=True),
             AccountMeta(pubkey=token_program, is_signer=False, is_writable=False),
+            AccountMeta(pubkey=backing_pool_pda, is_signer=False, is_writable=True),
         ]
+        if vest or escrow:
+            if escrow:
+                # Omitted vesting account is passed as the program ID
+                vesting_meta = AccountMeta(pubkey=self.program_id, is_signer=False, is_writable=False)
+            else:
+                vesting_pda, _ = self.get_vesting_pda(tournament_pubkey, player_wallet)
+                vesting_meta = AccountMeta(pubkey=vesting_pda, is_signer=False, is_writable=True)
+            system_program = Pubkey.from_string("11111111111111111111111111111111")
+            # Omitted guild accounts are passed as the program ID
+            accounts += [
+                AccountMeta(pubkey=self.program_id, is_signer=False, is_writable=False),
+                AccountMeta(pubkey=self.program_id, is_signer=False, is_writable=False),
+                AccountMeta(pubkey=self.program_id, is_signer=False, is_writable=False),
+                vesting_meta,
+                AccountMeta(pubkey=system_program, is_signer=False, is_writable=False),
+            ]
+        if escrow:
+            escrow_pda, _ = self.get_points_escrow_pda(tournament_pubkey, player_wallet)
+            accounts.append(AccountMeta(pubkey=escrow_pda, is_signer=False, is_writable=True))
 
         return Instruction(
             program_id=self.program_id,
@@ -0,0 +1,184 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+
+/// Who posts a level's ante
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum AnteMode {
+    /// The big blind posts a single ante for the whole table
+    #[default]
+    BigBlind,
+    /// Every player dealt in posts the ante
+    Classic,
+}
+
+/// One level of a blind structure
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct BlindLevel {
+    /// Small blind in chips
+    pub small_blind: u64,
+
+    /// Big blind in chips
+    pub big_blind: u64,
+
+    /// Ante in chips (0 for none)
+    pub ante: u64,
+
+    /// Who posts the ante
+    pub ante_mode: AnteMode,
+
+    /// Length of the level in minutes
+    pub duration_minutes: u16,
+}
+
+impl BlindLevel {
+    /// Level with a big-blind ante
+    const fn new(small_blind: u64, big_blind: u64, ante: u64, duration_minutes: u16) -> Self {
+        Self {
+            small_blind,
+            big_blind,
+            ante,
+            ante_mode: AnteMode::BigBlind,
+            duration_minutes,
+        }
+    }
+
+    /// Total antes posted on a hand dealt to `players` players
+    pub fn antes_posted(&self, players: u16) -> u64 {
+        match self.ante_mode {
+            AnteMode::BigBlind => self.ante,
+            AnteMode::Classic => self.ante.saturating_mul(players as u64),
+        }
+    }
+
+    /// Chips posted before cards are dealt to `players` players
+    pub fn dead_money(&self, players: u16) -> u64 {
+        self.small_blind
+            .saturating_add(self.big_blind)
+            .saturating_add(self.antes_posted(players))
+    }
+}
+
+/// Built-in blind structure a tournament is played with
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum BlindPreset {
+    /// Bespoke structure committed by `blind_structure_hash`
+    #[default]
+    Custom,
+    /// 12-minute levels
+    Regular,
+    /// 6-minute levels
+    Turbo,
+    /// 3-minute levels with steeper increases
+    Hyper,
+}
+
+impl BlindPreset {
+    /// Flags of every preset
+    pub const ALL_FLAGS: u8 = 0b1111;
+
+    /// Bit of the preset in ArenaConfig.disabled_blind_presets
+    pub fn flag(&self) -> u8 {
+        1 << *self as u8
+    }
+
+    /// Level table of the preset (empty for Custom)
+    pub fn levels(&self) -> &'static [BlindLevel] {
+        match self {
+            BlindPreset::Custom => &[],
+            BlindPreset::Regular => &REGULAR,
+            BlindPreset::Turbo => &TURBO,
+            BlindPreset::Hyper => &HYPER,
+        }
+    }
+
+    /// Level in effect `elapsed_seconds` after the tournament started
+    /// (None for Custom)
+    pub fn level_at(&self, elapsed_seconds: i64) -> Option<(usize, BlindLevel)> {
+        level_at(self.levels(), elapsed_seconds)
+    }
+}
+
+/// Validate a level table: every level has a positive duration, a small
+/// blind below its big blind and an ante no larger than the big blind, and
+/// blinds and antes never decrease from level to level
+pub fn validate_levels(levels: &[BlindLevel]) -> Result<()> {
+    require!(!levels.is_empty(), ArenaError::InvalidBlindStructure);
+    for level in levels {
+        require!(
+            level.duration_minutes > 0
+                && level.small_blind > 0
+                && level.small_blind < level.big_blind
+                && level.ante <= level.big_blind,
+            ArenaError::InvalidBlindStructure
+        );
+    }
+    for pair in levels.windows(2) {
+        require!(
+            pair[1].small_blind >= pair[0].small_blind
+                && pair[1].big_blind >= pair[0].big_blind
+                && pair[1].ante >= pair[0].ante,
+            ArenaError::InvalidBlindStructure
+        );
+    }
+    Ok(())
+}
+
+/// Index and level in effect `elapsed_seconds` into a level table.
+///
+/// Levels run back to back from 0; once the last level's time is up it
+/// stays in effect. Returns None for an empty table.
+pub fn level_at(levels: &[BlindLevel], elapsed_seconds: i64) -> Option<(usize, BlindLevel)> {
+    let mut remaining = elapsed_seconds.max(0);
+    for (index, level) in levels.iter().enumerate() {
+        let duration = level.duration_minutes as i64 * 60;
+        if remaining < duration {
+            return Some((index, *level));
+        }
+        remaining -= duration;
+    }
+    levels.last().map(|level| (levels.len() - 1, *level))
+}
+
+const REGULAR: [BlindLevel; 12] = [
+    BlindLevel::new(25, 50, 0, 12),
+    BlindLevel::new(50, 100, 0, 12),
+    BlindLevel::new(75, 150, 0, 12),
+    BlindLevel::new(100, 200, 25, 12),
+    BlindLevel::new(125, 250, 25, 12),
+    BlindLevel::new(150, 300, 50, 12),
+    BlindLevel::new(200, 400, 50, 12),
+    BlindLevel::new(250, 500, 75, 12),
+    BlindLevel::new(300, 600, 75, 12),
+    BlindLevel::new(400, 800, 100, 12),
+    BlindLevel::new(500, 1000, 125, 12),
+    BlindLevel::new(600, 1200, 150, 12),
+];
+
+const TURBO: [BlindLevel; 12] = [
+    BlindLevel::new(25, 50, 0, 6),
+    BlindLevel::new(50, 100, 0, 6),
+    BlindLevel::new(75, 150, 0, 6),
+    BlindLevel::new(100, 200, 25, 6),
+    BlindLevel::new(150, 300, 50, 6),
+    BlindLevel::new(200, 400, 50, 6),
+    BlindLevel::new(300, 600, 75, 6),
+    BlindLevel::new(400, 800, 100, 6),
+    BlindLevel::new(600, 1200, 150, 6),
+    BlindLevel::new(800, 1600, 200, 6),
+    BlindLevel::new(1000, 2000, 250, 6),
+    BlindLevel::new(1500, 3000, 400, 6),
+];
+
+const HYPER: [BlindLevel; 10] = [
+    BlindLevel::new(25, 50, 0, 3),
+    BlindLevel::new(50, 100, 0, 3),
+    BlindLevel::new(100, 200, 25, 3),
+    BlindLevel::new(150, 300, 50, 3),
+    BlindLevel::new(250, 500, 75, 3),
+    BlindLevel::new(400, 800, 100, 3),
+    BlindLevel::new(600, 1200, 150, 3),
+    BlindLevel::new(1000, 2000, 250, 3),
+    BlindLevel::new(1500, 3000, 400, 3),
+    BlindLevel::new(2500, 5000, 600, 3),
+];
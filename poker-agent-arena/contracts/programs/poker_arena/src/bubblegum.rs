@@ -0,0 +1,142 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+
+/// Metaplex Bubblegum program
+pub mod mpl_bubblegum {
+    anchor_lang::declare_id!("BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY");
+}
+
+/// Metaplex Bubblegum program ID
+pub const MPL_BUBBLEGUM_ID: Pubkey = mpl_bubblegum::ID;
+
+/// Bubblegum token standard (mirrors mpl-bubblegum's `TokenStandard`)
+#[derive(AnchorSerialize, Clone, Copy)]
+pub enum TokenStandard {
+    NonFungible,
+    FungibleAsset,
+    Fungible,
+    NonFungibleEdition,
+}
+
+/// Bubblegum token program version (mirrors mpl-bubblegum's `TokenProgramVersion`)
+#[derive(AnchorSerialize, Clone, Copy)]
+pub enum TokenProgramVersion {
+    Original,
+    Token2022,
+}
+
+/// Verified collection reference
+#[derive(AnchorSerialize, Clone, Copy)]
+pub struct Collection {
+    pub verified: bool,
+    pub key: Pubkey,
+}
+
+/// How a cNFT may be consumed
+#[derive(AnchorSerialize, Clone, Copy)]
+pub enum UseMethod {
+    Burn,
+    Multiple,
+    Single,
+}
+
+/// Usage limits for a cNFT
+#[derive(AnchorSerialize, Clone, Copy)]
+pub struct Uses {
+    pub use_method: UseMethod,
+    pub remaining: u64,
+    pub total: u64,
+}
+
+/// Royalty creator entry
+#[derive(AnchorSerialize, Clone, Copy)]
+pub struct Creator {
+    pub address: Pubkey,
+    pub verified: bool,
+    pub share: u8,
+}
+
+/// Leaf metadata passed to `mint_v1` (mirrors mpl-bubblegum's `MetadataArgs`)
+#[derive(AnchorSerialize, Clone)]
+pub struct MetadataArgs {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub seller_fee_basis_points: u16,
+    pub primary_sale_happened: bool,
+    pub is_mutable: bool,
+    pub edition_nonce: Option<u8>,
+    pub token_standard: Option<TokenStandard>,
+    pub collection: Option<Collection>,
+    pub uses: Option<Uses>,
+    pub token_program_version: TokenProgramVersion,
+    pub creators: Vec<Creator>,
+}
+
+/// Accounts for a Bubblegum `mint_v1` CPI.
+pub struct MintV1Accounts<'a, 'info> {
+    /// Bubblegum program
+    pub bubblegum_program: &'a AccountInfo<'info>,
+    /// Bubblegum tree config PDA for `merkle_tree`
+    pub tree_config: &'a AccountInfo<'info>,
+    /// Wallet receiving the cNFT
+    pub leaf_owner: &'a AccountInfo<'info>,
+    /// Bubblegum-managed Merkle tree
+    pub merkle_tree: &'a AccountInfo<'info>,
+    /// Pays for the CPI
+    pub payer: &'a AccountInfo<'info>,
+    /// Tree creator or delegate (a program PDA that signs via `signer_seeds`)
+    pub tree_delegate: &'a AccountInfo<'info>,
+    /// SPL noop program
+    pub log_wrapper: &'a AccountInfo<'info>,
+    /// SPL account compression program
+    pub compression_program: &'a AccountInfo<'info>,
+    /// System program
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+/// Mint a compressed NFT to `leaf_owner` (who is also the leaf delegate)
+pub fn mint_v1(
+    accounts: &MintV1Accounts<'_, '_>,
+    metadata: &MetadataArgs,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let mut data = hash(b"global:mint_v1").to_bytes()[..8].to_vec();
+    metadata.serialize(&mut data)?;
+
+    let ix = Instruction {
+        program_id: accounts.bubblegum_program.key(),
+        accounts: vec![
+            AccountMeta::new(accounts.tree_config.key(), false),
+            AccountMeta::new_readonly(accounts.leaf_owner.key(), false),
+            AccountMeta::new_readonly(accounts.leaf_owner.key(), false),
+            AccountMeta::new(accounts.merkle_tree.key(), false),
+            AccountMeta::new(accounts.payer.key(), true),
+            AccountMeta::new_readonly(accounts.tree_delegate.key(), true),
+            AccountMeta::new_readonly(accounts.log_wrapper.key(), false),
+            AccountMeta::new_readonly(accounts.compression_program.key(), false),
+            AccountMeta::new_readonly(accounts.system_program.key(), false),
+        ],
+        data,
+    };
+
+    invoke_signed(
+        &ix,
+        &[
+            accounts.tree_config.clone(),
+            accounts.leaf_owner.clone(),
+            accounts.merkle_tree.clone(),
+            accounts.payer.clone(),
+            accounts.tree_delegate.clone(),
+            accounts.log_wrapper.clone(),
+            accounts.compression_program.clone(),
+            accounts.system_program.clone(),
+            accounts.bubblegum_program.clone(),
+        ],
+        signer_seeds,
+    )?;
+
+    Ok(())
+}
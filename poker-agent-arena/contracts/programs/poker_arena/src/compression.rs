@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+
+/// SPL account compression program
+pub mod spl_account_compression {
+    anchor_lang::declare_id!("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK");
+}
+
+/// SPL noop program used by account compression to log changelogs
+pub mod spl_noop {
+    anchor_lang::declare_id!("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV");
+}
+
+/// SPL account compression program ID
+pub const SPL_ACCOUNT_COMPRESSION_ID: Pubkey = spl_account_compression::ID;
+
+/// SPL noop program ID
+pub const SPL_NOOP_ID: Pubkey = spl_noop::ID;
+
+/// Accounts shared by every tree-modifying CPI.
+pub struct TreeAccounts<'a, 'info> {
+    /// SPL account compression program
+    pub compression_program: &'a AccountInfo<'info>,
+    /// Concurrent Merkle tree account
+    pub merkle_tree: &'a AccountInfo<'info>,
+    /// Tree authority (a program PDA that signs via `signer_seeds`)
+    pub authority: &'a AccountInfo<'info>,
+    /// SPL noop program
+    pub noop: &'a AccountInfo<'info>,
+}
+
+/// Anchor instruction discriminator: first 8 bytes of SHA-256("global:<name>")
+fn discriminator(name: &str) -> [u8; 8] {
+    let mut out = [0u8; 8];
+    out.copy_from_slice(&hash(format!("global:{}", name).as_bytes()).to_bytes()[..8]);
+    out
+}
+
+/// Invoke a tree instruction with the standard (tree, authority, noop) accounts
+fn invoke_tree<'info>(
+    accounts: &TreeAccounts<'_, 'info>,
+    data: Vec<u8>,
+    proof: &[AccountInfo<'info>],
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let mut metas = vec![
+        AccountMeta::new(accounts.merkle_tree.key(), false),
+        AccountMeta::new_readonly(accounts.authority.key(), true),
+        AccountMeta::new_readonly(accounts.noop.key(), false),
+    ];
+    metas.extend(proof.iter().map(|node| AccountMeta::new_readonly(node.key(), false)));
+
+    let mut infos = vec![
+        accounts.merkle_tree.clone(),
+        accounts.authority.clone(),
+        accounts.noop.clone(),
+        accounts.compression_program.clone(),
+    ];
+    infos.extend(proof.iter().cloned());
+
+    let ix = Instruction {
+        program_id: accounts.compression_program.key(),
+        accounts: metas,
+        data,
+    };
+    invoke_signed(&ix, &infos, signer_seeds)?;
+
+    Ok(())
+}
+
+/// Initialize an empty concurrent Merkle tree owned by `authority`
+pub fn init_empty_merkle_tree(
+    accounts: &TreeAccounts<'_, '_>,
+    max_depth: u32,
+    max_buffer_size: u32,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let mut data = discriminator("init_empty_merkle_tree").to_vec();
+    data.extend_from_slice(&max_depth.to_le_bytes());
+    data.extend_from_slice(&max_buffer_size.to_le_bytes());
+    invoke_tree(accounts, data, &[], signer_seeds)
+}
+
+/// Append a leaf to the tree
+pub fn append(accounts: &TreeAccounts<'_, '_>, leaf: [u8; 32], signer_seeds: &[&[&[u8]]]) -> Result<()> {
+    let mut data = discriminator("append").to_vec();
+    data.extend_from_slice(&leaf);
+    invoke_tree(accounts, data, &[], signer_seeds)
+}
+
+/// Replace `previous_leaf` at `index` with `new_leaf`, proving against `root`.
+/// `proof` holds the sibling nodes as remaining accounts.
+pub fn replace_leaf<'info>(
+    accounts: &TreeAccounts<'_, 'info>,
+    root: [u8; 32],
+    previous_leaf: [u8; 32],
+    new_leaf: [u8; 32],
+    index: u32,
+    proof: &[AccountInfo<'info>],
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let mut data = discriminator("replace_leaf").to_vec();
+    data.extend_from_slice(&root);
+    data.extend_from_slice(&previous_leaf);
+    data.extend_from_slice(&new_leaf);
+    data.extend_from_slice(&index.to_le_bytes());
+    invoke_tree(accounts, data, proof, signer_seeds)
+}
@@ -15,6 +15,22 @@ pub enum ArenaError {
     #[msg("Invalid signature provided")]
     InvalidSignature = 1002,
 
+    /// Signer is not a member of the arbiter set
+    #[msg("Signer is not an arbiter")]
+    NotArbiter = 1003,
+
+    /// Session key is expired, out of scope, or misconfigured
+    #[msg("Invalid session key")]
+    InvalidSession = 1004,
+
+    /// Beneficiary has not approved a gift from this sponsor
+    #[msg("Gift not approved by beneficiary")]
+    GiftNotApproved = 1005,
+
+    /// Registration was invoked via CPI by a program that is not allowlisted
+    #[msg("Calling program is not an allowlisted partner")]
+    CallerNotAllowed = 1006,
+
     // =========================================================================
     // Tournament (2000-2099)
     // =========================================================================
@@ -58,6 +74,256 @@ pub enum ArenaError {
     #[msg("Player has no points to distribute")]
     NoPointsToDistribute = 2010,
 
+    /// Burning distributed POINTS requires a token account delegated to the mint authority
+    #[msg("Burning distributed POINTS requires a token account delegated to the mint authority")]
+    PenaltyBurnNotDelegated = 2011,
+
+    /// Penalties must reference a reason and remove a non-zero amount
+    #[msg("Penalty requires a reason hash and a non-zero amount")]
+    InvalidPenalty = 2012,
+
+    /// Player has been disqualified from this tournament
+    #[msg("Player has been disqualified from this tournament")]
+    PlayerDisqualified = 2013,
+
+    /// An appeal is pending for this registration
+    #[msg("An appeal is pending for this registration")]
+    AppealPending = 2014,
+
+    /// Appeal window has closed or there is no sanction to appeal
+    #[msg("No sanction can be appealed for this registration")]
+    AppealNotAllowed = 2015,
+
+    /// Appeal has already been resolved
+    #[msg("Appeal has already been resolved")]
+    AppealAlreadyResolved = 2016,
+
+    /// Conditions for this permissionless crank are not met yet
+    #[msg("Crank conditions are not met yet")]
+    CrankNotReady = 2017,
+
+    /// A points Merkle root has already been committed
+    #[msg("Points Merkle root already committed")]
+    PointsRootAlreadyCommitted = 2018,
+
+    /// No points Merkle root has been committed
+    #[msg("Points Merkle root not committed")]
+    PointsRootNotCommitted = 2019,
+
+    /// Tournament points are settled by Merkle claims, not per-player minting
+    #[msg("Tournament points are settled by Merkle claims")]
+    PointsSettledByMerkleRoot = 2020,
+
+    /// Trophies are enabled but the Bubblegum trophy accounts were not supplied
+    #[msg("Trophy accounts are required when trophies are enabled")]
+    TrophyAccountsMissing = 2021,
+
+    /// Tournament has not been voided
+    #[msg("Tournament has not been voided")]
+    TournamentNotVoided = 2022,
+
+    /// Seat assignments repeat a player or do not match the seeded draw
+    #[msg("Invalid seat assignments")]
+    InvalidSeating = 2023,
+
+    /// Player's points have not been distributed yet
+    #[msg("Player's points have not been distributed yet")]
+    PointsNotDistributed = 2024,
+
+    /// Requested action share exceeds what is listed or sellable
+    #[msg("Not enough action available")]
+    ActionOversold = 2025,
+
+    /// Season window is empty or the season has ended
+    #[msg("Invalid season")]
+    InvalidSeason = 2026,
+
+    /// Eliminator and eliminated player must differ
+    #[msg("Invalid elimination")]
+    InvalidElimination = 2027,
+
+    /// Season has not ended yet
+    #[msg("Season has not ended")]
+    SeasonNotEnded = 2028,
+
+    /// Disputed results require threshold arbiter attestations
+    #[msg("Arbiter attestation threshold not met")]
+    ArbiterThresholdNotMet = 2029,
+
+    /// Guild has reached its member limit
+    #[msg("Guild is full")]
+    GuildFull = 2030,
+
+    /// Guild name is malformed or the action is not allowed for this member
+    #[msg("Invalid guild")]
+    InvalidGuild = 2031,
+
+    /// Registration for this tier has not opened yet
+    #[msg("Registration for this tier has not opened yet")]
+    TierNotOpen = 2032,
+
+    /// Higher tiers must open no later than lower tiers
+    #[msg("Invalid registration windows")]
+    InvalidRegistrationWindows = 2033,
+
+    /// Reserved seats exceed the tournament's capacity
+    #[msg("Invalid reserved seats")]
+    InvalidReservedSeats = 2034,
+
+    /// Capacity cannot drop below current registrations or reserved seats
+    #[msg("Invalid max players")]
+    InvalidMaxPlayers = 2035,
+
+    /// Escrowed points are locked until the dispute window closes or the dispute is resolved
+    #[msg("Points are locked in escrow")]
+    PointsEscrowLocked = 2036,
+
+    /// A points escrow must be supplied exactly when the dispute window is active
+    #[msg("Points escrow required only during the dispute window")]
+    PointsEscrowMismatch = 2037,
+
+    /// Results can no longer be disputed
+    #[msg("Dispute window has closed")]
+    DisputeWindowClosed = 2038,
+
+    /// Registration still has unsettled fees, points, appeals or action shares
+    #[msg("Registration cannot be collected yet")]
+    RegistrationNotCollectable = 2039,
+
+    /// Famous hand participants or proof exceed limits, or a recipient is not a participant
+    #[msg("Invalid famous hand")]
+    InvalidFamousHand = 2040,
+
+    /// No hand-history Merkle root has been committed
+    #[msg("Hand-history root not committed")]
+    HandHistoryRootNotCommitted = 2041,
+
+    /// A hand-history Merkle root has already been committed
+    #[msg("Hand-history root already committed")]
+    HandHistoryRootAlreadyCommitted = 2042,
+
+    /// The player has already forfeited or was disqualified
+    #[msg("Player has already left the tournament")]
+    AlreadyForfeited = 2043,
+
+    /// Division floors must ascend and the K-factor must be positive
+    #[msg("Invalid ladder configuration")]
+    InvalidLadderConfig = 2044,
+
+    /// Ladder matches must be completed, unrecorded heads-up tournaments
+    /// between the two rated players
+    #[msg("Invalid ladder match")]
+    InvalidLadderMatch = 2045,
+
+    /// The tournament is restricted to a division the player is not in
+    #[msg("Player is not in the tournament's division")]
+    DivisionMismatch = 2046,
+
+    /// The instruction does not apply to the tournament's format
+    #[msg("Invalid tournament format")]
+    InvalidTournamentFormat = 2047,
+
+    /// Bracket entrants, match slot or match result is invalid
+    #[msg("Invalid bracket match")]
+    InvalidBracketMatch = 2048,
+
+    /// Swiss round is not complete, already paired, or the standings or
+    /// pairing do not match it
+    #[msg("Invalid Swiss round")]
+    InvalidSwissRound = 2049,
+
+    /// Table is not part of the current shootout round, already has a
+    /// winner, or the winner was not seated at it
+    #[msg("Invalid shootout table")]
+    InvalidShootoutTable = 2050,
+
+    /// Blind levels are empty, have a zero duration, a small blind not
+    /// below the big blind, an ante above the big blind, or decreasing
+    /// blinds or antes; a preset was combined with a custom structure hash
+    /// or is disabled
+    #[msg("Invalid blind structure")]
+    InvalidBlindStructure = 2051,
+
+    /// Winner is disqualified, forfeited or recorded at a rank other than first
+    #[msg("Invalid tournament winner")]
+    InvalidWinner = 2052,
+
+    /// Results cannot be confirmed while the dispute window is open or a
+    /// dispute is unresolved
+    #[msg("Dispute window is still open")]
+    DisputeWindowOpen = 2053,
+
+    /// The scheduled start and any late registration period have passed
+    #[msg("Registration has closed")]
+    RegistrationClosed = 2054,
+
+    /// A busted player's result must use the position fixed at elimination
+    #[msg("Final rank does not match the bust position")]
+    RankMismatch = 2055,
+
+    /// Places pay more than the pool, out of order, or a tier bonus exceeds 100%
+    #[msg("Invalid points formula")]
+    InvalidPointsFormula = 2056,
+
+    /// Awarded points differ from the tournament's committed points formula
+    #[msg("Points award does not match the points formula")]
+    PointsFormulaMismatch = 2057,
+
+    /// Tournament creation would exceed the daily or concurrently open limit
+    #[msg("Tournament creation limit reached")]
+    TournamentRateLimited = 2058,
+
+    /// The tournament is invite-only and no invite redeemed by the wallet was passed
+    #[msg("A redeemed invite is required for this tournament")]
+    InviteRequired = 2059,
+
+    /// The invite code, its PDA or its redemption state does not match
+    #[msg("Invalid invite")]
+    InvalidInvite = 2060,
+
+    /// The wallet's registration index has no free entry
+    #[msg("Registration index is full")]
+    RegistrationIndexFull = 2061,
+
+    /// The tournament's highlights account has no free entry
+    #[msg("Highlights are full")]
+    HighlightsFull = 2062,
+
+    /// Awarded POINTS (in base units) are not a whole number of POINTS
+    #[msg("Points award must be a whole number of POINTS")]
+    FractionalPointsAward = 2063,
+
+    /// The tournament's host account is missing or does not match
+    #[msg("Invalid host")]
+    InvalidHost = 2064,
+
+    /// The host's bond is below the arena's required bond
+    #[msg("Host bond is below the required amount")]
+    HostBondTooLow = 2065,
+
+    /// Slashing requires a disputed hosted tournament that was voided or
+    /// left unfinalized past the deadline
+    #[msg("Host cannot be slashed for this tournament")]
+    HostNotSlashable = 2066,
+
+    /// Each registration may dispute a tournament's results only once
+    #[msg("Player has already disputed this tournament")]
+    AlreadyDisputed = 2067,
+
+    /// Restoring a granted appeal's POINTS needs the escrow or token accounts
+    /// the sanction took them from
+    #[msg("Accounts needed to restore the sanctioned POINTS are missing")]
+    SanctionRestoreAccountsMissing = 2068,
+
+    /// POINTS are held while a sanction can still be appealed or an appeal
+    /// awaits resolution
+    #[msg("POINTS are held until the sanction's appeal window closes or the appeal is resolved")]
+    SanctionPending = 2069,
+
+    /// The registration has no recorded result to discard
+    #[msg("No result recorded")]
+    NoResultRecorded = 2070,
+
     // =========================================================================
     // Agent (3000-3099)
     // =========================================================================
@@ -77,6 +343,54 @@ pub enum ArenaError {
     #[msg("Slider value out of valid range (0-100)")]
     SliderOutOfRange = 3004,
 
+    /// Agent name is empty, not UTF-8, or contains non-printable characters
+    #[msg("Agent name must be non-empty printable UTF-8")]
+    InvalidAgentName = 3005,
+
+    /// Agent image URI is malformed or uses a disallowed scheme
+    #[msg("Agent image URI must be printable and start with ipfs:// or https://")]
+    InvalidAgentImageUri = 3006,
+
+    /// Revealed prompt does not hash to the committed agent_prompt_hash
+    #[msg("Revealed prompt does not match the committed hash")]
+    PromptHashMismatch = 3007,
+
+    /// Prompt has already been revealed and verified
+    #[msg("Prompt has already been verified")]
+    PromptAlreadyVerified = 3008,
+
+    /// Revealed key does not hash to the committed key commitment
+    #[msg("Revealed key does not match the key commitment")]
+    KeyCommitmentMismatch = 3009,
+
+    /// Decryption key has already been revealed
+    #[msg("Decryption key has already been revealed")]
+    KeyAlreadyRevealed = 3010,
+
+    /// Key reveal window has closed
+    #[msg("Key reveal window has closed")]
+    RevealWindowClosed = 3011,
+
+    /// Key reveal window is still open
+    #[msg("Key reveal window is still open")]
+    RevealWindowOpen = 3012,
+
+    /// Profile display name, avatar URI or country code is malformed
+    #[msg("Invalid player profile")]
+    InvalidProfile = 3013,
+
+    /// PRO registrations must reference an approved model
+    #[msg("Model is not approved")]
+    ModelNotApproved = 3014,
+
+    /// The requested tier upgrade is not a step up or is not offered for POINTS
+    #[msg("Tier upgrade not available")]
+    TierUpgradeNotAvailable = 3015,
+
+    /// Tickets are disabled, the tier is free, or the quantity is zero
+    #[msg("Tickets not available")]
+    TicketsNotAvailable = 3016,
+
     // =========================================================================
     // Payment (4000-4099)
     // =========================================================================
@@ -92,6 +406,106 @@ pub enum ArenaError {
     #[msg("Transaction failed")]
     TransactionFailed = 4003,
 
+    /// Redemption vault cannot cover the requested redemption
+    #[msg("Redemption vault has insufficient SOL for this redemption")]
+    InsufficientRedemptionFunds = 4004,
+
+    /// Buyback interval has not elapsed since the last burn
+    #[msg("Buyback interval has not elapsed")]
+    BuybackTooSoon = 4005,
+
+    /// Tier fee has already been fully refunded
+    #[msg("Nothing left to refund for this registration")]
+    NothingToRefund = 4006,
+
+    /// Backing would exceed the pool target or share offered
+    #[msg("Backing exceeds the pool target")]
+    BackingTargetExceeded = 4007,
+
+    /// Player has already withdrawn the backing funds
+    #[msg("Backing funds already withdrawn")]
+    BackingAlreadyWithdrawn = 4008,
+
+    /// Backing can only be refunded after registration closes without a withdrawal
+    #[msg("Backing is not refundable yet")]
+    BackingNotRefundable = 4009,
+
+    /// Daily ticket was already claimed in the last 24 hours
+    #[msg("Daily ticket already claimed today")]
+    TicketNotReady = 4010,
+
+    /// Fee recipients are misconfigured or the recipient accounts do not match the split
+    #[msg("Invalid fee recipients")]
+    InvalidFeeRecipients = 4011,
+
+    /// Wallet has no unclaimed reward for this season
+    #[msg("No season reward to claim")]
+    NoSeasonReward = 4012,
+
+    /// Vesting cliff must not exceed a positive duration
+    #[msg("Invalid vesting schedule")]
+    InvalidVestingSchedule = 4013,
+
+    /// A vesting account must be supplied exactly when the award vests
+    #[msg("Vesting account required only for vesting awards")]
+    VestingAccountMismatch = 4014,
+
+    /// No vested POINTS are claimable yet
+    #[msg("No vested POINTS to claim")]
+    NothingVested = 4015,
+
+    /// Rent recipient does not match the registration's routing
+    #[msg("Invalid rent recipient")]
+    InvalidRentRecipient = 4016,
+
+    /// Airdrop claim window has closed
+    #[msg("Airdrop has expired")]
+    AirdropExpired = 4017,
+
+    /// Airdrop expiry is in the past or the allocation is zero
+    #[msg("Invalid airdrop")]
+    InvalidAirdrop = 4018,
+
+    /// Prize pool accounts do not match its denomination, or it cannot cover the prize
+    #[msg("Invalid prize pool")]
+    InvalidPrizePool = 4019,
+
+    /// Finishing place is unpaid or its prize was already claimed
+    #[msg("No prize to claim")]
+    NoPrizeToClaim = 4020,
+
+    /// SOL/USD price update is missing, unverified or for the wrong feed
+    #[msg("Invalid price feed")]
+    InvalidPriceFeed = 4021,
+
+    /// SOL/USD price update is older than the accepted age
+    #[msg("Price feed is stale")]
+    StalePriceFeed = 4022,
+
+    /// The tournament escrows tier fees but its fee escrow was not passed
+    #[msg("Fee escrow required")]
+    FeeEscrowRequired = 4023,
+
+    /// The fee escrow has already been released
+    #[msg("Fee escrow already released")]
+    FeeEscrowReleased = 4024,
+
+    /// Compressed registrations have no PDA to refund escrowed fees against
+    #[msg("Compressed registration is unavailable while fees are escrowed")]
+    FeeEscrowUnsupported = 4025,
+
+    /// The POINTS token account is already frozen
+    #[msg("Token account is already frozen")]
+    TokenAccountAlreadyFrozen = 4026,
+
+    /// The POINTS token account is not frozen
+    #[msg("Token account is not frozen")]
+    TokenAccountNotFrozen = 4027,
+
+    /// The tournament's fees are still escrowed and must be refunded from the escrow
+    #[msg("Fee escrow not released")]
+    FeeEscrowNotReleased = 4028,
+
     // =========================================================================
     // System (5000-5099)
     // =========================================================================
@@ -102,4 +516,65 @@ pub enum ArenaError {
     /// Invalid payout structure
     #[msg("Invalid payout structure")]
     InvalidPayoutStructure = 5002,
+
+    /// Commitment batch index does not follow the last committed batch
+    #[msg("Commitment batch is out of order")]
+    CommitmentOutOfOrder = 5003,
+
+    /// Basis points value exceeds 10000
+    #[msg("Basis points value exceeds 10000")]
+    InvalidBasisPoints = 5004,
+
+    /// Merkle proof does not match the committed root
+    #[msg("Invalid Merkle proof")]
+    InvalidMerkleProof = 5005,
+
+    /// Voting period for this proposal has ended
+    #[msg("Voting has ended for this proposal")]
+    VotingClosed = 5006,
+
+    /// Proposal is still in voting or timelock, or was already executed
+    #[msg("Proposal is not executable")]
+    ProposalNotExecutable = 5007,
+
+    /// Proposal did not reach quorum or majority
+    #[msg("Proposal did not pass")]
+    ProposalRejected = 5008,
+
+    /// Staked POINTS are locked by an active vote
+    #[msg("Stake is locked until voting ends")]
+    StakeLocked = 5009,
+
+    /// No POINTS staked before the proposal snapshot
+    #[msg("No voting power for this proposal")]
+    NoVotingPower = 5010,
+
+    /// Loyalty thresholds must be ascending among enabled levels
+    #[msg("Loyalty thresholds must be ascending")]
+    InvalidLoyaltyThresholds = 5011,
+
+    /// Arbiter members repeat or the threshold is out of range
+    #[msg("Invalid arbiter set")]
+    InvalidArbiterSet = 5012,
+
+    /// The subsystem is turned off by the arena's feature flags
+    #[msg("Feature is disabled")]
+    FeatureDisabled = 5013,
+
+    /// Asset recovery is still timelocked, exceeds the stranded balance, or
+    /// names the wrong accounts
+    #[msg("Invalid asset recovery")]
+    InvalidAssetRecovery = 5014,
+
+    /// A counter or balance would overflow its integer type
+    #[msg("Arithmetic overflow")]
+    ArithmeticOverflow = 5015,
+
+    /// Mint authority change is still timelocked or names the current authority
+    #[msg("Invalid mint authority change")]
+    InvalidMintAuthorityChange = 5017,
+
+    /// Proposal moves a rate or price further than governance may in one step
+    #[msg("Proposal change is out of bounds")]
+    ProposalChangeOutOfBounds = 5018,
 }
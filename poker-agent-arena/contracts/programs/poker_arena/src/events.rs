@@ -0,0 +1,578 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{
+    AgentTier, BracketSide, CompressedRegistration, Division, PrizeDenomination, Table,
+    TournamentFormat,
+};
+
+/// Emitted when an admin reduces or burns a player's POINTS.
+#[event]
+pub struct PenaltyApplied {
+    /// Tournament the penalty relates to
+    pub tournament: Pubkey,
+    /// Penalized player's wallet
+    pub wallet: Pubkey,
+    /// POINTS removed (base units)
+    pub amount: u64,
+    /// True if already-distributed POINTS were burned, false if the award was reduced
+    pub burned: bool,
+    /// SHA-256 hash of the off-chain reason document (zeros for the
+    /// automatic timeout penalty)
+    pub reason_hash: [u8; 32],
+    /// Unix timestamp of the penalty
+    pub timestamp: i64,
+}
+
+/// Emitted when an admin freezes or thaws a player's POINTS token account.
+#[event]
+pub struct PointsFreezeUpdated {
+    /// Owner of the token account
+    pub wallet: Pubkey,
+    /// POINTS token account frozen or thawed
+    pub token_account: Pubkey,
+    /// True if the account was frozen, false if thawed
+    pub frozen: bool,
+    /// SHA-256 hash of the off-chain investigation record
+    pub reason_hash: [u8; 32],
+    /// Unix timestamp of the change
+    pub timestamp: i64,
+}
+
+/// Emitted when a player is disqualified from a tournament.
+#[event]
+pub struct PlayerDisqualified {
+    /// Tournament the player was disqualified from
+    pub tournament: Pubkey,
+    /// Disqualified player's wallet
+    pub wallet: Pubkey,
+    /// Lamports refunded from the treasury
+    pub refund_lamports: u64,
+    /// SHA-256 hash of the off-chain reason document
+    pub reason_hash: [u8; 32],
+    /// Unix timestamp of the disqualification
+    pub timestamp: i64,
+}
+
+/// Emitted when a player voluntarily forfeits a running tournament.
+#[event]
+pub struct PlayerForfeited {
+    /// Tournament the player forfeited
+    pub tournament: Pubkey,
+    /// Forfeiting player's wallet
+    pub wallet: Pubkey,
+    /// Unix timestamp of the forfeit
+    pub timestamp: i64,
+}
+
+/// Emitted when a player appeals a penalty or disqualification.
+#[event]
+pub struct AppealOpened {
+    /// Tournament the sanction relates to
+    pub tournament: Pubkey,
+    /// Appealing player's wallet
+    pub wallet: Pubkey,
+    /// SHA-256 hash of the off-chain appeal statement
+    pub reason_hash: [u8; 32],
+    /// Unix timestamp when the appeal was opened
+    pub timestamp: i64,
+}
+
+/// Emitted when an appeal is resolved.
+#[event]
+pub struct AppealResolved {
+    /// Tournament the sanction relates to
+    pub tournament: Pubkey,
+    /// Appealing player's wallet
+    pub wallet: Pubkey,
+    /// Whether the appeal was granted
+    pub granted: bool,
+    /// POINTS restored to the pending award
+    pub points_restored: u64,
+    /// Unix timestamp of the resolution
+    pub timestamp: i64,
+}
+
+/// Emitted when the admin delegates (or revokes) a tournament operator.
+#[event]
+pub struct OperatorDelegated {
+    /// Tournament the operator may run
+    pub tournament: Pubkey,
+    /// Delegated operator key (Pubkey::default() when revoked)
+    pub operator: Pubkey,
+    /// Unix timestamp of the delegation
+    pub timestamp: i64,
+}
+
+/// Emitted when a permissionless crank pays its caller a bounty.
+#[event]
+pub struct CrankBountyPaid {
+    /// Wallet that ran the crank
+    pub caller: Pubkey,
+    /// Tournament the crank acted on
+    pub tournament: Pubkey,
+    /// Lamports paid from the crank vault
+    pub amount: u64,
+}
+
+/// Emitted whenever a compressed registration leaf is appended or replaced.
+/// Indexers rebuild the roster (and proofs) from these events.
+#[event]
+pub struct CompressedRegistrationUpdated {
+    /// Merkle tree holding the leaf
+    pub merkle_tree: Pubkey,
+    /// Index of the leaf in the tree
+    pub leaf_index: u32,
+    /// Full registration data hashed into the leaf
+    pub registration: CompressedRegistration,
+}
+
+/// Emitted when a champion trophy NFT is minted to a tournament winner.
+#[event]
+pub struct ChampionTrophyMinted {
+    /// Tournament that was won
+    pub tournament: Pubkey,
+    /// Winner's wallet
+    pub winner: Pubkey,
+    /// Trophy NFT mint
+    pub mint: Pubkey,
+    /// Number of registered players
+    pub field_size: u16,
+    /// Unix timestamp the tournament completed
+    pub completed_at: i64,
+}
+
+/// Emitted when rake-funded POINTS are burned from the buyback vault.
+#[event]
+pub struct BuybackBurned {
+    /// Rake reported for this period (lamports)
+    pub rake_lamports: u64,
+    /// Lamports of rake spent buying back POINTS
+    pub lamports_spent: u64,
+    /// POINTS burned (base units)
+    pub points_burned: u64,
+    /// Unix timestamp of the burn
+    pub timestamp: i64,
+}
+
+/// Emitted when an in-progress tournament is voided after an engine failure.
+#[event]
+pub struct TournamentVoided {
+    /// Voided tournament
+    pub tournament: Pubkey,
+    /// SHA-256 hash of the off-chain incident report
+    pub reason_hash: [u8; 32],
+    /// Unix timestamp of the void
+    pub timestamp: i64,
+}
+
+/// Emitted when an admin slashes a host's bond over a failed tournament.
+#[event]
+pub struct HostSlashed {
+    /// Slashed host entry
+    pub host: Pubkey,
+    /// Disputed tournament the host failed
+    pub tournament: Pubkey,
+    /// Lamports moved from the bond to the tournament's fee escrow
+    pub amount: u64,
+    /// SHA-256 hash of the off-chain dispute ruling
+    pub reason_hash: [u8; 32],
+    /// Host's reputation after the slash, in basis points
+    pub reputation_bps: u16,
+    /// Unix timestamp of the slash
+    pub timestamp: i64,
+}
+
+/// Emitted when a player disputes a tournament's results.
+#[event]
+pub struct TournamentDisputed {
+    /// Disputed tournament
+    pub tournament: Pubkey,
+    /// Wallet that raised the dispute
+    pub wallet: Pubkey,
+    /// SHA-256 hash of the off-chain dispute statement
+    pub reason_hash: [u8; 32],
+    /// Unix timestamp of the dispute
+    pub timestamp: i64,
+}
+
+/// Emitted when a table's seating changes (break or balancing move).
+#[event]
+pub struct TableUpdated {
+    /// Tournament the table belongs to
+    pub tournament: Pubkey,
+    /// Table number within the tournament
+    pub table_number: u16,
+    /// Player wallet in each seat (Pubkey::default() if empty)
+    pub seats: [Pubkey; Table::MAX_SEATS],
+    /// Hands dealt at this table so far
+    pub hand_count: u64,
+    /// Unix timestamp of the change
+    pub timestamp: i64,
+}
+
+/// Emitted when a tournament's capacity changes during registration.
+#[event]
+pub struct TournamentExpanded {
+    /// Tournament that was resized
+    pub tournament: Pubkey,
+    /// Capacity before the change
+    pub previous_max_players: u16,
+    /// New capacity
+    pub max_players: u16,
+    /// Players registered at the time of the change
+    pub registered_players: u16,
+    /// Unix timestamp of the change
+    pub timestamp: i64,
+}
+
+/// Emitted when the arena metadata URI changes.
+#[event]
+pub struct ArenaMetadataUpdated {
+    /// New metadata URI, zero-padded to 128 bytes
+    pub metadata_uri: [u8; 128],
+    /// Unix timestamp of the change
+    pub timestamp: i64,
+}
+
+/// Emitted when a tournament stuck in Created is expired by the crank.
+#[event]
+pub struct TournamentExpired {
+    /// Tournament that was cancelled
+    pub tournament: Pubkey,
+    /// Wallet that ran the crank
+    pub caller: Pubkey,
+    /// Scheduled start the tournament never reached
+    pub starts_at: i64,
+    /// Unix timestamp of the expiry
+    pub timestamp: i64,
+}
+
+/// Emitted when a famous hand commemorative is minted to its participants.
+#[event]
+pub struct FamousHandMinted {
+    /// Tournament the hand was played in
+    pub tournament: Pubkey,
+    /// Index of the hand within the tournament
+    pub hand_index: u32,
+    /// FamousHand account recording the hand and its proof
+    pub famous_hand: Pubkey,
+    /// Players who received the commemorative
+    pub participants: Vec<Pubkey>,
+}
+
+/// Emitted when a leaderboard snapshot root is committed.
+#[event]
+pub struct LeaderboardSnapshotted {
+    /// Snapshot account
+    pub snapshot: Pubkey,
+    /// Sequential snapshot ID
+    pub id: u64,
+    /// Merkle root of (wallet, total_points)
+    pub root: [u8; 32],
+    /// Wallets included in the snapshot
+    pub wallet_count: u32,
+    /// Slot the snapshot was taken at
+    pub slot: u64,
+}
+
+/// Emitted when a player registers through `register_player`.
+#[event]
+pub struct PlayerRegistered {
+    /// Tournament registered for
+    pub tournament: Pubkey,
+    /// Registered wallet
+    pub wallet: Pubkey,
+    /// Agent tier
+    pub tier: AgentTier,
+    /// Tier fee paid, in lamports
+    pub fee_lamports: u64,
+    /// Solana Pay reference key passed with the registration, if any
+    pub reference: Option<Pubkey>,
+    /// Client-supplied id matching the registration to an off-chain session (0 if unused)
+    pub correlation_id: u64,
+    /// Unix timestamp of the registration
+    pub timestamp: i64,
+}
+
+/// Emitted when a registration is upgraded by burning POINTS.
+#[event]
+pub struct TierUpgraded {
+    /// Tournament registered for
+    pub tournament: Pubkey,
+    /// Registered wallet
+    pub wallet: Pubkey,
+    /// Tier before the upgrade
+    pub from_tier: AgentTier,
+    /// Tier after the upgrade
+    pub to_tier: AgentTier,
+    /// POINTS burned for the upgrade
+    pub points_burned: u64,
+}
+
+/// Emitted when tickets are bought with POINTS.
+#[event]
+pub struct TicketsPurchased {
+    /// Buying wallet
+    pub wallet: Pubkey,
+    /// Tier the tickets cover
+    pub tier: AgentTier,
+    /// Tickets bought
+    pub quantity: u16,
+    /// POINTS burned
+    pub points_burned: u64,
+}
+
+/// Emitted when a heads-up ladder match is rated.
+#[event]
+pub struct LadderMatchRecorded {
+    /// Heads-up tournament the match was played in
+    pub tournament: Pubkey,
+    /// Winning wallet
+    pub winner: Pubkey,
+    /// Losing wallet
+    pub loser: Pubkey,
+    /// Winner's rating after the match
+    pub winner_rating: u16,
+    /// Loser's rating after the match
+    pub loser_rating: u16,
+}
+
+/// Emitted when a ladder rating is rolled over into a new season.
+#[event]
+pub struct LadderRatingRolledOver {
+    /// Rated wallet
+    pub wallet: Pubkey,
+    /// Ladder season rolled into
+    pub season: u32,
+    /// Division before the rollover
+    pub from_division: Division,
+    /// Division after promotion or relegation
+    pub to_division: Division,
+    /// Rating at rollover
+    pub rating: u16,
+}
+
+/// Emitted when a bracket match result is recorded.
+#[event]
+pub struct BracketMatchCompleted {
+    /// Tournament the bracket belongs to
+    pub tournament: Pubkey,
+    /// Part of the bracket the match was in
+    pub side: BracketSide,
+    /// Round of the match within its part
+    pub round: u8,
+    /// Position of the match in its round
+    pub index: u16,
+    /// Winning wallet
+    pub winner: Pubkey,
+    /// Losing wallet (eliminated unless dropped into the losers bracket)
+    pub loser: Pubkey,
+}
+
+/// Emitted when a Swiss round is paired.
+#[event]
+pub struct SwissRoundPaired {
+    /// Tournament being played
+    pub tournament: Pubkey,
+    /// Round number (1-based)
+    pub round: u8,
+    /// Players in pairing order; adjacent players meet
+    pub pairings: Vec<Pubkey>,
+    /// Player receiving the bye (Pubkey::default() if none)
+    pub bye: Pubkey,
+}
+
+/// Emitted when a shootout table is won.
+#[event]
+pub struct ShootoutTableWon {
+    /// Tournament being played
+    pub tournament: Pubkey,
+    /// Shootout round (1-based)
+    pub round: u8,
+    /// Table number within the tournament
+    pub table_number: u16,
+    /// Player advancing from the table
+    pub winner: Pubkey,
+}
+
+/// Emitted when a sweep of stranded assets is queued.
+#[event]
+pub struct AssetRecoveryQueued {
+    /// PDA holding the stranded assets
+    pub source: Pubkey,
+    /// Token mint to sweep (None = lamports)
+    pub mint: Option<Pubkey>,
+    /// Amount to sweep
+    pub amount: u64,
+    /// Unix timestamp after which the sweep may be executed
+    pub executable_at: i64,
+}
+
+/// Emitted when stranded assets are swept to the treasury.
+#[event]
+pub struct StrandedAssetsRecovered {
+    /// PDA the assets were swept from
+    pub source: Pubkey,
+    /// Token mint swept (None = lamports)
+    pub mint: Option<Pubkey>,
+    /// Amount swept
+    pub amount: u64,
+    /// Treasury wallet receiving the assets
+    pub treasury: Pubkey,
+    /// Unix timestamp of the sweep
+    pub timestamp: i64,
+}
+
+/// Emitted when a POINTS mint authority change is queued.
+#[event]
+pub struct MintAuthorityChangeQueued {
+    /// Authority minting will be handed to (None = revoke)
+    pub new_authority: Option<Pubkey>,
+    /// Unix timestamp after which the change may be executed
+    pub executable_at: i64,
+}
+
+/// Emitted when the POINTS mint authority is handed over or revoked.
+#[event]
+pub struct MintAuthorityChanged {
+    /// POINTS mint
+    pub mint: Pubkey,
+    /// New mint authority (None = minting revoked)
+    pub new_authority: Option<Pubkey>,
+    /// Unix timestamp of the change
+    pub timestamp: i64,
+}
+
+/// Emitted when a tournament is finalized.
+#[event]
+pub struct TournamentFinalized {
+    /// Finalized tournament
+    pub tournament: Pubkey,
+    /// Tournament ID
+    pub id: u64,
+    /// Winner's wallet
+    pub winner: Pubkey,
+    /// SHA-256 hash of the final standings JSON
+    pub results_hash: [u8; 32],
+    /// Format the tournament was played in
+    pub format: TournamentFormat,
+    /// Players registered at the finish
+    pub registered_players: u16,
+    /// Tier fees collected for the tournament, in lamports
+    pub fees_collected_lamports: u64,
+    /// Whether the results were disputed and settled by arbiters
+    pub disputed: bool,
+    /// Unix timestamp the dispute window closes (0 if none)
+    pub dispute_window_ends_at: i64,
+    /// Key that finalized the tournament
+    pub finalized_by: Pubkey,
+    /// Unix timestamp of completion
+    pub completed_at: i64,
+}
+
+/// Emitted when a tournament's provisional results are submitted.
+#[event]
+pub struct ResultsSubmitted {
+    /// Tournament the results are for
+    pub tournament: Pubkey,
+    /// Provisional winner's wallet
+    pub winner: Pubkey,
+    /// SHA-256 hash of the final standings JSON
+    pub results_hash: [u8; 32],
+    /// Unix timestamp the dispute window closes
+    pub dispute_window_ends_at: i64,
+    /// Key that submitted the results
+    pub submitted_by: Pubkey,
+}
+
+/// Emitted when a player is knocked out during play.
+#[event]
+pub struct PlayerEliminated {
+    /// Tournament being played
+    pub tournament: Pubkey,
+    /// Player who took the last chips
+    pub eliminator: Pubkey,
+    /// Player knocked out
+    pub victim: Pubkey,
+    /// Finishing position fixed for the victim
+    pub bust_position: u16,
+    /// Players still holding chips after the elimination
+    pub players_remaining: u16,
+    /// Bounties credited to the eliminator so far
+    pub eliminator_bounties: u16,
+    /// Unix timestamp of the elimination
+    pub timestamp: i64,
+}
+
+/// Emitted when a tournament's prize pool is funded.
+#[event]
+pub struct PrizePoolFunded {
+    /// Tournament the prize pool is for
+    pub tournament: Pubkey,
+    /// Currency of the deposit
+    pub denomination: PrizeDenomination,
+    /// Wallet that funded the pool
+    pub funder: Pubkey,
+    /// Amount added, in the denomination's base units
+    pub amount: u64,
+    /// Pool total after the deposit
+    pub total: u64,
+}
+
+/// Emitted when a finishing place's prize is paid out.
+#[event]
+pub struct PrizeClaimed {
+    /// Tournament the prize is from
+    pub tournament: Pubkey,
+    /// Wallet paid
+    pub wallet: Pubkey,
+    /// Finishing place paid
+    pub final_rank: u16,
+    /// Currency of the payout
+    pub denomination: PrizeDenomination,
+    /// Amount paid, in the denomination's base units
+    pub amount: u64,
+}
+
+/// Emitted when a started tournament's escrowed tier fees are released.
+#[event]
+pub struct FeeEscrowReleased {
+    /// Tournament the fees were paid for
+    pub tournament: Pubkey,
+    /// Lamports released
+    pub amount: u64,
+    /// Whether the fees went into the prize pool rather than the treasury
+    pub to_prize_pool: bool,
+}
+
+/// Emitted when a player unregisters before a tournament starts.
+#[event]
+pub struct PlayerUnregistered {
+    /// Tournament withdrawn from
+    pub tournament: Pubkey,
+    /// Unregistered wallet
+    pub wallet: Pubkey,
+    /// Lamports of the tier fee refunded
+    pub refund_lamports: u64,
+    /// Lamports of the tier fee kept by the treasury
+    pub retained_lamports: u64,
+    /// Unix timestamp of the unregistration
+    pub timestamp: i64,
+}
+
+/// Emitted when a commentator anchors commentary to a tournament.
+#[event]
+pub struct CommentaryPosted {
+    /// Tournament the commentary covers
+    pub tournament: Pubkey,
+    /// Commentator that posted it
+    pub commentator: Pubkey,
+    /// Zero-based position of the entry in the tournament's commentary chain
+    pub sequence: u32,
+    /// SHA-256 hash of the commentary or highlight metadata
+    pub content_hash: [u8; 32],
+    /// Hand-log batches committed when the entry was posted
+    pub game_log_batches: u32,
+    /// Commentary chain head after the entry
+    pub chain_head: [u8; 32],
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog, Ladder};
+
+/// Accounts required for starting a new ladder season.
+#[derive(Accounts)]
+pub struct AdvanceLadderSeason<'info> {
+    /// Admin wallet - must match arena_config.admin
+    pub admin: Signer<'info>,
+
+    /// Arena config for admin verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Ladder to advance
+    #[account(
+        mut,
+        seeds = [Ladder::SEED_PREFIX],
+        bump = ladder.bump
+    )]
+    pub ladder: Account<'info, Ladder>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Start the next ladder season (admin only).
+///
+/// Every rating becomes due a rollover, applied by the permissionless
+/// `roll_over_ladder_rating` crank.
+pub fn handler(ctx: Context<AdvanceLadderSeason>) -> Result<()> {
+    let ladder = &mut ctx.accounts.ladder;
+    ladder.season = ladder
+        .season
+        .checked_add(1)
+        .ok_or(ArenaError::ArithmeticOverflow)?;
+    ladder.season_started_at = Clock::get()?.unix_timestamp;
+
+    msg!("Ladder season {} started", ladder.season);
+
+    AuditLog::record_call::<instruction::AdvanceLadderSeason>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        0,
+    )?;
+
+    Ok(())
+}
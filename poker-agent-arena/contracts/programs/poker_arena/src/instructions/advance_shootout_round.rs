@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::state::{Shootout, Tournament, TournamentStatus};
+
+/// Accounts required for advancing a shootout to its next round.
+#[derive(Accounts)]
+pub struct AdvanceShootoutRound<'info> {
+    /// Anyone may advance a completed round
+    pub caller: Signer<'info>,
+
+    /// Tournament being played - must be InProgress
+    #[account(
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.status == TournamentStatus::InProgress @ ArenaError::TournamentNotInProgress
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Tournament's shootout schedule
+    #[account(
+        mut,
+        seeds = [Shootout::SEED_PREFIX, tournament.key().as_ref()],
+        bump = shootout.bump
+    )]
+    pub shootout: Box<Account<'info, Shootout>>,
+}
+
+/// Advance the shootout to its next round (permissionless crank).
+///
+/// Once every table of the current round has a winner, the winners become
+/// the next round's field, seated at tables numbered after the current
+/// round's. Fails with CrankNotReady while a table is undecided or after
+/// the final table.
+pub fn handler(ctx: Context<AdvanceShootoutRound>) -> Result<()> {
+    let shootout = &mut ctx.accounts.shootout;
+    require!(
+        shootout.round_complete() && shootout.champion.is_none(),
+        ArenaError::CrankNotReady
+    );
+
+    let round = shootout.round + 1;
+    let winners = shootout.winners.clone();
+    shootout.first_table += winners.len() as u16;
+    shootout.start_round(round, winners);
+
+    msg!(
+        "Shootout round {} started: {} players at {} tables",
+        shootout.round,
+        shootout.players.len(),
+        shootout.winners.len()
+    );
+
+    Ok(())
+}
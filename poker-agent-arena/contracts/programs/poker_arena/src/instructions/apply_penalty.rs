@@ -0,0 +1,199 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount};
+
+use crate::errors::ArenaError;
+use crate::events::PenaltyApplied;
+use crate::instruction;
+use crate::state::{
+    ArenaConfig, AuditLog, PlayerRegistration, PointsEscrow, PointsMintAuthority, Tournament,
+    TournamentStatus,
+};
+
+/// Accounts required for penalizing a player's POINTS.
+#[derive(Accounts)]
+pub struct ApplyPenalty<'info> {
+    /// Admin wallet - must match arena_config.admin
+    pub admin: Signer<'info>,
+
+    /// Arena config for admin verification and points mint
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament - must be Completed
+    #[account(
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.status == TournamentStatus::Completed @ ArenaError::TournamentNotCompleted
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Penalized player's registration - must have points awarded
+    #[account(
+        mut,
+        seeds = [PlayerRegistration::SEED_PREFIX, tournament.key().as_ref(), registration.wallet.as_ref()],
+        bump = registration.bump,
+        constraint = registration.tournament == tournament.key() @ ArenaError::TournamentNotFound,
+        constraint = registration.points_awarded.is_some() @ ArenaError::NoPointsToDistribute,
+        constraint = !registration.appeal_pending @ ArenaError::AppealPending
+    )]
+    pub registration: Account<'info, PlayerRegistration>,
+
+    /// PDA that holds mint authority (and acts as burn delegate)
+    #[account(
+        seeds = [PointsMintAuthority::SEED_PREFIX],
+        bump = mint_authority.bump
+    )]
+    pub mint_authority: Account<'info, PointsMintAuthority>,
+
+    /// POINTS SPL token mint (required only when burning distributed POINTS)
+    #[account(mut)]
+    pub points_mint: Option<Account<'info, Mint>>,
+
+    /// Player's POINTS token account (required only when burning distributed POINTS)
+    #[account(mut)]
+    pub player_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Token program (required only when burning distributed POINTS)
+    pub token_program: Option<Program<'info, Token>>,
+
+    /// Escrow still holding the player's distributed POINTS (omit once released)
+    #[account(
+        mut,
+        seeds = [PointsEscrow::SEED_PREFIX, tournament.key().as_ref(), registration.wallet.as_ref()],
+        bump = points_escrow.bump,
+        constraint = !points_escrow.released @ ArenaError::PointsAlreadyDistributed
+    )]
+    pub points_escrow: Option<Account<'info, PointsEscrow>>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Penalize a player's POINTS for a rules violation (admin only).
+///
+/// This instruction:
+/// 1. Reduces `points_awarded` if POINTS have not been distributed yet
+/// 2. Reduces the PointsEscrow if distributed POINTS are still escrowed
+/// 3. Otherwise burns distributed POINTS via the mint authority's delegation
+/// 4. Records the cumulative penalty, and what it took so a granted appeal
+///    can restore it, and emits a PenaltyApplied event
+///
+/// The player's POINTS are held back until the appeal window closes or an
+/// appeal is resolved. Further penalties are blocked while an appeal is
+/// pending.
+///
+/// # Arguments
+/// * `amount` - POINTS to remove (base units)
+/// * `reason_hash` - SHA-256 hash of the off-chain reason document
+pub fn handler(ctx: Context<ApplyPenalty>, amount: u64, reason_hash: [u8; 32]) -> Result<()> {
+    require!(
+        amount > 0 && reason_hash != [0u8; 32],
+        ArenaError::InvalidPenalty
+    );
+
+    let clock = Clock::get()?;
+    let registration = &mut ctx.accounts.registration;
+    let burned = registration.points_distributed && ctx.accounts.points_escrow.is_none();
+    let escrowed = registration.points_distributed && !burned;
+
+    let applied = if !registration.points_distributed {
+        // Pre-distribution: reduce the pending award
+        let awarded = registration.points_awarded.unwrap_or_default();
+        let applied = amount.min(awarded);
+        registration.points_awarded = Some(awarded - applied);
+        applied
+    } else if let Some(points_escrow) = ctx.accounts.points_escrow.as_mut() {
+        // Escrowed: reduce the POINTS held for release
+        let applied = amount.min(points_escrow.points);
+        points_escrow.points -= applied;
+        applied
+    } else {
+        // Post-distribution: burn from the player's account via delegate
+        let (Some(points_mint), Some(player_token_account), Some(token_program)) = (
+            ctx.accounts.points_mint.as_ref(),
+            ctx.accounts.player_token_account.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        ) else {
+            return err!(ArenaError::PenaltyBurnNotDelegated);
+        };
+
+        let expected_mint = ctx
+            .accounts
+            .tournament
+            .points_mint(&ctx.accounts.arena_config);
+        require!(
+            points_mint.key() == expected_mint,
+            ArenaError::InvalidTierPayment
+        );
+        require!(
+            player_token_account.mint == points_mint.key()
+                && player_token_account.owner == registration.wallet,
+            ArenaError::Unauthorized
+        );
+        require!(
+            player_token_account.delegate.contains(&ctx.accounts.mint_authority.key())
+                && player_token_account.delegated_amount >= amount,
+            ArenaError::PenaltyBurnNotDelegated
+        );
+
+        let seeds = &[
+            PointsMintAuthority::SEED_PREFIX,
+            &[ctx.accounts.mint_authority.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        token::burn(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                Burn {
+                    mint: points_mint.to_account_info(),
+                    from: player_token_account.to_account_info(),
+                    authority: ctx.accounts.mint_authority.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )?;
+        amount
+    };
+
+    registration.penalty_points = registration.penalty_points.saturating_add(applied);
+
+    let sanction = registration.record_sanction(clock.unix_timestamp)?;
+    let restorable = if burned {
+        &mut sanction.burned_points
+    } else if escrowed {
+        &mut sanction.escrowed_points
+    } else {
+        &mut sanction.award_points
+    };
+    *restorable = restorable.saturating_add(applied);
+
+    emit!(PenaltyApplied {
+        tournament: ctx.accounts.tournament.key(),
+        wallet: registration.wallet,
+        amount: applied,
+        burned,
+        reason_hash,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Penalty of {} POINTS applied to player: {}", applied, registration.wallet);
+
+    AuditLog::record_call::<instruction::ApplyPenalty>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        ctx.accounts.tournament.id,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::state::{GiftConsent, Tournament};
+
+/// Accounts required for consenting to a gifted registration.
+#[derive(Accounts)]
+pub struct ApproveGift<'info> {
+    /// Beneficiary wallet giving consent; pays the consent rent
+    #[account(mut)]
+    pub beneficiary: Signer<'info>,
+
+    /// Tournament the gifted entry is for
+    #[account(
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.is_registration_open() @ ArenaError::RegistrationNotOpen
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Consent PDA to be created
+    #[account(
+        init,
+        payer = beneficiary,
+        space = GiftConsent::SIZE,
+        seeds = [GiftConsent::SEED_PREFIX, tournament.key().as_ref(), beneficiary.key().as_ref()],
+        bump
+    )]
+    pub gift_consent: Account<'info, GiftConsent>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Consent to be registered for a tournament by a sponsor.
+///
+/// # Arguments
+/// * `sponsor` - Sponsor allowed to gift the entry, or the default pubkey for any sponsor
+pub fn handler(ctx: Context<ApproveGift>, sponsor: Pubkey) -> Result<()> {
+    let gift_consent = &mut ctx.accounts.gift_consent;
+    gift_consent.beneficiary = ctx.accounts.beneficiary.key();
+    gift_consent.tournament = ctx.accounts.tournament.key();
+    gift_consent.sponsor = sponsor;
+    gift_consent.created_at = Clock::get()?.unix_timestamp;
+    gift_consent.bump = ctx.bumps.gift_consent;
+
+    msg!(
+        "{} approved a gifted entry to tournament {}",
+        gift_consent.beneficiary,
+        ctx.accounts.tournament.id
+    );
+
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::state::{ArbiterAttestation, ArbiterSet, Tournament, TournamentStatus};
+
+/// Accounts required for an arbiter to attest tournament results.
+#[derive(Accounts)]
+#[instruction(results_hash: [u8; 32])]
+pub struct AttestResults<'info> {
+    /// Arbiter attesting the results
+    #[account(mut)]
+    pub arbiter: Signer<'info>,
+
+    /// Current arbiter committee
+    #[account(
+        seeds = [ArbiterSet::SEED_PREFIX],
+        bump = arbiter_set.bump
+    )]
+    pub arbiter_set: Account<'info, ArbiterSet>,
+
+    /// Disputed tournament - must be InProgress, or have results submitted
+    /// and disputed in its dispute window
+    #[account(
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.status == TournamentStatus::InProgress
+            || tournament.status == TournamentStatus::ResultsSubmitted @ ArenaError::TournamentNotInProgress
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Attestation for this results hash (created by the first arbiter)
+    #[account(
+        init_if_needed,
+        payer = arbiter,
+        space = ArbiterAttestation::SIZE,
+        seeds = [ArbiterAttestation::SEED_PREFIX, tournament.key().as_ref(), results_hash.as_ref()],
+        bump
+    )]
+    pub attestation: Account<'info, ArbiterAttestation>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Attest a tournament's results (arbiters only).
+///
+/// Signatures for the same results hash accumulate on one attestation;
+/// submitting or confirming disputed results requires the committee threshold.
+///
+/// # Arguments
+/// * `results_hash` - Results hash the arbiter agrees with
+/// * `winner` - Winner the arbiter agrees with
+pub fn handler(ctx: Context<AttestResults>, results_hash: [u8; 32], winner: Pubkey) -> Result<()> {
+    let arbiter_set = &ctx.accounts.arbiter_set;
+    let index = arbiter_set
+        .member_index(&ctx.accounts.arbiter.key())
+        .ok_or(ArenaError::NotArbiter)?;
+
+    let attestation = &mut ctx.accounts.attestation;
+    if attestation.tournament == Pubkey::default() {
+        attestation.tournament = ctx.accounts.tournament.key();
+        attestation.results_hash = results_hash;
+        attestation.winner = winner;
+        attestation.bump = ctx.bumps.attestation;
+    }
+    require_keys_eq!(attestation.winner, winner, ArenaError::InvalidResultsHash);
+
+    // Signatures from a previous committee no longer count
+    if attestation.arbiter_set_version != arbiter_set.version {
+        attestation.arbiter_set_version = arbiter_set.version;
+        attestation.signer_mask = 0;
+    }
+    attestation.signer_mask |= 1 << index;
+
+    msg!(
+        "Arbiter {} attested tournament {} ({}/{})",
+        ctx.accounts.arbiter.key(),
+        ctx.accounts.tournament.id,
+        attestation.signer_mask.count_ones(),
+        arbiter_set.threshold
+    );
+
+    Ok(())
+}
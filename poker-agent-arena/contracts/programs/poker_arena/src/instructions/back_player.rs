@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::errors::ArenaError;
+use crate::state::{BackerShare, BackingPool, LedgerCategory, LedgerDirection, TreasuryLedger};
+
+/// Accounts required for backing a player.
+#[derive(Accounts)]
+pub struct BackPlayer<'info> {
+    /// Backer funding the player
+    #[account(mut)]
+    pub backer: Signer<'info>,
+
+    /// Backing pool - must not be withdrawn yet
+    #[account(
+        mut,
+        seeds = [BackingPool::SEED_PREFIX, backing_pool.tournament.as_ref(), backing_pool.player.as_ref()],
+        bump = backing_pool.bump,
+        constraint = !backing_pool.withdrawn @ ArenaError::BackingAlreadyWithdrawn
+    )]
+    pub backing_pool: Account<'info, BackingPool>,
+
+    /// Backer's share (created on first contribution)
+    #[account(
+        init_if_needed,
+        payer = backer,
+        space = BackerShare::SIZE,
+        seeds = [BackerShare::SEED_PREFIX, backing_pool.key().as_ref(), backer.key().as_ref()],
+        bump
+    )]
+    pub backer_share: Account<'info, BackerShare>,
+
+    /// System program for account creation and transfers
+    pub system_program: Program<'info, System>,
+
+    /// Treasury ledger recording the contribution (omit to skip)
+    #[account(
+        mut,
+        seeds = [TreasuryLedger::SEED_PREFIX],
+        bump = treasury_ledger.bump
+    )]
+    pub treasury_ledger: Option<Box<Account<'info, TreasuryLedger>>>,
+}
+
+/// Contribute lamports to a player's backing pool in exchange for a share.
+///
+/// # Arguments
+/// * `lamports` - Lamports to contribute
+pub fn handler(ctx: Context<BackPlayer>, lamports: u64) -> Result<()> {
+    let backing_pool = &mut ctx.accounts.backing_pool;
+
+    let raised = backing_pool
+        .raised_lamports
+        .checked_add(lamports)
+        .ok_or(ArenaError::BackingTargetExceeded)?;
+    require!(
+        lamports > 0 && raised <= backing_pool.target_lamports,
+        ArenaError::BackingTargetExceeded
+    );
+    let share_bps = backing_pool.share_for(lamports);
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.backer.to_account_info(),
+                to: backing_pool.to_account_info(),
+            },
+        ),
+        lamports,
+    )?;
+    if let Some(treasury_ledger) = ctx.accounts.treasury_ledger.as_mut() {
+        treasury_ledger.record(
+            LedgerCategory::BackingEscrow,
+            LedgerDirection::Inflow,
+            backing_pool.key(),
+            lamports,
+            Clock::get()?.unix_timestamp,
+        );
+    }
+
+    backing_pool.raised_lamports = raised;
+    backing_pool.sold_share_bps += share_bps;
+
+    let backer_share = &mut ctx.accounts.backer_share;
+    backer_share.pool = backing_pool.key();
+    backer_share.backer = ctx.accounts.backer.key();
+    backer_share.lamports += lamports;
+    backer_share.share_bps += share_bps;
+    backer_share.bump = ctx.bumps.backer_share;
+
+    msg!("Backed {} lamports for {} bps", lamports, share_bps);
+
+    Ok(())
+}
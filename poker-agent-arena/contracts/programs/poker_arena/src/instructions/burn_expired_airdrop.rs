@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount};
+
+use crate::errors::ArenaError;
+use crate::state::{Airdrop, ArenaConfig};
+
+/// Accounts required for burning an expired airdrop's unclaimed POINTS.
+#[derive(Accounts)]
+pub struct BurnExpiredAirdrop<'info> {
+    /// Any wallet running the crank
+    pub caller: Signer<'info>,
+
+    /// Arena config for the points mint
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Expired airdrop - must not have been finalized
+    #[account(
+        mut,
+        seeds = [Airdrop::SEED_PREFIX, &airdrop.id.to_le_bytes()],
+        bump = airdrop.bump,
+        constraint = !airdrop.finalized @ ArenaError::CrankNotReady
+    )]
+    pub airdrop: Account<'info, Airdrop>,
+
+    /// Airdrop vault holding the unclaimed allocation
+    #[account(
+        mut,
+        seeds = [Airdrop::VAULT_SEED_PREFIX, airdrop.key().as_ref()],
+        bump
+    )]
+    pub airdrop_vault: Account<'info, TokenAccount>,
+
+    /// POINTS SPL token mint
+    #[account(
+        mut,
+        constraint = points_mint.key() == arena_config.points_mint @ ArenaError::InvalidTierPayment
+    )]
+    pub points_mint: Account<'info, Mint>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+/// Burn whatever an airdrop left unclaimed once it expires (permissionless).
+pub fn handler(ctx: Context<BurnExpiredAirdrop>) -> Result<()> {
+    let airdrop = &mut ctx.accounts.airdrop;
+    require!(
+        !airdrop.is_open(Clock::get()?.unix_timestamp),
+        ArenaError::CrankNotReady
+    );
+
+    let unclaimed = ctx.accounts.airdrop_vault.amount;
+
+    let id = airdrop.id.to_le_bytes();
+    let seeds = &[Airdrop::SEED_PREFIX, id.as_ref(), &[airdrop.bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    token::burn(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.points_mint.to_account_info(),
+                from: ctx.accounts.airdrop_vault.to_account_info(),
+                authority: airdrop.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        unclaimed,
+    )?;
+
+    airdrop.burned = unclaimed;
+    airdrop.finalized = true;
+
+    msg!(
+        "Airdrop {} expired: burned {} unclaimed POINTS",
+        airdrop.id,
+        unclaimed
+    );
+
+    Ok(())
+}
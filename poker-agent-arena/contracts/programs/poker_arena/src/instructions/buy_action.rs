@@ -0,0 +1,155 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::errors::ArenaError;
+use crate::state::{
+    ActionListing, ActionShare, ArenaConfig, BackingPool, PlayerRegistration, Tournament,
+};
+
+/// Accounts required for buying a share of a player's action.
+#[derive(Accounts)]
+pub struct BuyAction<'info> {
+    /// Buyer purchasing the share
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    /// Arena config for the treasury address - the marketplace must be enabled
+    #[account(
+        mut,
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.is_enabled(ArenaConfig::FEATURE_MARKETPLACE) @ ArenaError::FeatureDisabled
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament - registration must still be open
+    #[account(
+        mut,
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.is_registration_open() @ ArenaError::RegistrationNotOpen
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Seller's registration
+    #[account(
+        mut,
+        seeds = [PlayerRegistration::SEED_PREFIX, tournament.key().as_ref(), registration.wallet.as_ref()],
+        bump = registration.bump
+    )]
+    pub registration: Account<'info, PlayerRegistration>,
+
+    /// Action listing being bought from
+    #[account(
+        mut,
+        seeds = [ActionListing::SEED_PREFIX, registration.key().as_ref()],
+        bump = action_listing.bump
+    )]
+    pub action_listing: Account<'info, ActionListing>,
+
+    /// Buyer's share (created on first purchase)
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = ActionShare::SIZE,
+        seeds = [ActionShare::SEED_PREFIX, registration.key().as_ref(), buyer.key().as_ref()],
+        bump
+    )]
+    pub action_share: Account<'info, ActionShare>,
+
+    /// Seller's wallet receiving the price
+    /// CHECK: Verified against action_listing.seller
+    #[account(
+        mut,
+        address = action_listing.seller @ ArenaError::Unauthorized
+    )]
+    pub seller: UncheckedAccount<'info>,
+
+    /// Treasury receiving the marketplace fee
+    /// CHECK: Verified against arena_config.treasury
+    #[account(
+        mut,
+        address = arena_config.treasury @ ArenaError::InvalidTierPayment
+    )]
+    pub treasury: UncheckedAccount<'info>,
+
+    /// Seller's backing pool PDA (may be uninitialized)
+    /// CHECK: Address derived from seeds; only deserialized if it holds data
+    #[account(
+        seeds = [BackingPool::SEED_PREFIX, tournament.key().as_ref(), registration.wallet.as_ref()],
+        bump
+    )]
+    pub backing_pool: UncheckedAccount<'info>,
+
+    /// System program for account creation and transfers
+    pub system_program: Program<'info, System>,
+}
+
+/// Buy a share of a registered player's winnings.
+///
+/// This instruction:
+/// 1. Ensures action plus any backing offer never exceeds 100% of winnings
+/// 2. Pays the seller, minus the marketplace fee sent to the treasury
+/// 3. Records the share on the buyer's ActionShare and the registration
+///
+/// # Arguments
+/// * `share_bps` - Share to buy, in basis points
+pub fn handler(ctx: Context<BuyAction>, share_bps: u16) -> Result<()> {
+    let action_listing = &mut ctx.accounts.action_listing;
+    let registration = &mut ctx.accounts.registration;
+
+    require!(
+        share_bps > 0 && share_bps <= action_listing.available_bps,
+        ArenaError::ActionOversold
+    );
+
+    let backing_info = ctx.accounts.backing_pool.to_account_info();
+    let backing_bps = if backing_info.data_is_empty() {
+        0
+    } else {
+        BackingPool::try_deserialize(&mut &backing_info.try_borrow_data()?[..])?.offered_share_bps
+    };
+    require!(
+        backing_bps as u32 + registration.action_sold_bps as u32 + share_bps as u32 <= 10_000,
+        ArenaError::ActionOversold
+    );
+
+    let price = (share_bps as u64)
+        .checked_mul(action_listing.price_lamports_per_bps)
+        .ok_or(ArenaError::InvalidTierPayment)?;
+    let fee = price * ActionListing::MARKETPLACE_FEE_BPS / 10_000;
+
+    for (to, amount) in [
+        (ctx.accounts.seller.to_account_info(), price - fee),
+        (ctx.accounts.treasury.to_account_info(), fee),
+    ] {
+        if amount > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.buyer.to_account_info(),
+                        to,
+                    },
+                ),
+                amount,
+            )?;
+        }
+    }
+
+    ctx.accounts.tournament.record_fee(fee);
+    ctx.accounts.arena_config.record_fee(fee);
+
+    action_listing.available_bps -= share_bps;
+    registration.action_sold_bps += share_bps;
+
+    let action_share = &mut ctx.accounts.action_share;
+    action_share.registration = registration.key();
+    action_share.buyer = ctx.accounts.buyer.key();
+    action_share.share_bps += share_bps;
+    action_share.bump = ctx.bumps.action_share;
+
+    msg!("Bought {} bps of action for {} lamports", share_bps, price);
+
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::errors::ArenaError;
+use crate::state::{ArenaConfig, Season, SeasonPass};
+
+/// Accounts required for buying a season pass.
+#[derive(Accounts)]
+pub struct BuySeasonPass<'info> {
+    /// Wallet buying the pass
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    /// Arena config account (for treasury address)
+    #[account(
+        mut,
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Season to buy a pass for - must not have ended
+    #[account(
+        mut,
+        seeds = [Season::SEED_PREFIX, &season.id.to_le_bytes()],
+        bump = season.bump
+    )]
+    pub season: Account<'info, Season>,
+
+    /// Season pass PDA to be created
+    #[account(
+        init,
+        payer = player,
+        space = SeasonPass::SIZE,
+        seeds = [SeasonPass::SEED_PREFIX, season.key().as_ref(), player.key().as_ref()],
+        bump
+    )]
+    pub season_pass: Account<'info, SeasonPass>,
+
+    /// Treasury wallet to receive the pass price
+    /// CHECK: This is verified against arena_config.treasury
+    #[account(
+        mut,
+        constraint = treasury.key() == arena_config.treasury @ ArenaError::InvalidTierPayment
+    )]
+    pub treasury: AccountInfo<'info>,
+
+    /// System program for account creation and transfers
+    pub system_program: Program<'info, System>,
+}
+
+/// Buy a season pass at the season's fixed price.
+///
+/// This instruction:
+/// 1. Validates the season has not ended
+/// 2. Transfers the pass price to the treasury
+/// 3. Creates the SeasonPass for the buyer
+pub fn handler(ctx: Context<BuySeasonPass>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let season = &mut ctx.accounts.season;
+    require!(now < season.ends_at, ArenaError::InvalidSeason);
+
+    if season.pass_price_lamports > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.player.to_account_info(),
+                    to: ctx.accounts.treasury.to_account_info(),
+                },
+            ),
+            season.pass_price_lamports,
+        )?;
+    }
+
+    season.passes_sold = season.passes_sold.saturating_add(1);
+    ctx.accounts.arena_config.record_fee(season.pass_price_lamports);
+
+    let season_pass = &mut ctx.accounts.season_pass;
+    season_pass.season = season.key();
+    season_pass.wallet = ctx.accounts.player.key();
+    season_pass.purchased_at = now;
+    season_pass.bump = ctx.bumps.season_pass;
+
+    msg!(
+        "Season {} pass bought by {} for {} lamports",
+        season.id,
+        season_pass.wallet,
+        season.pass_price_lamports
+    );
+
+    Ok(())
+}
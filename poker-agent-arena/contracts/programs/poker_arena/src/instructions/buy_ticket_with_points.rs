@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount};
+
+use crate::errors::ArenaError;
+use crate::events::TicketsPurchased;
+use crate::state::{AgentTier, ArenaConfig, Ticket};
+
+/// Accounts required for buying tournament tickets with POINTS.
+#[derive(Accounts)]
+#[instruction(tier: AgentTier)]
+pub struct BuyTicketWithPoints<'info> {
+    /// Wallet burning POINTS; pays rent for a new ticket account
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    /// Arena config for the points mint and ticket rate
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Buyer's tickets for `tier` (created on first purchase)
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = Ticket::SIZE,
+        seeds = [Ticket::SEED_PREFIX, buyer.key().as_ref(), &[tier as u8]],
+        bump
+    )]
+    pub ticket: Account<'info, Ticket>,
+
+    /// POINTS SPL token mint
+    #[account(
+        mut,
+        constraint = points_mint.key() == arena_config.points_mint @ ArenaError::InvalidTierPayment
+    )]
+    pub points_mint: Account<'info, Mint>,
+
+    /// Buyer's POINTS token account to burn from
+    #[account(
+        mut,
+        constraint = buyer_token_account.mint == points_mint.key() @ ArenaError::InvalidTierPayment,
+        constraint = buyer_token_account.owner == buyer.key() @ ArenaError::Unauthorized
+    )]
+    pub buyer_token_account: Account<'info, TokenAccount>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Burn POINTS for tickets that waive a tier fee.
+///
+/// This instruction:
+/// 1. Prices each ticket at the configured POINTS-per-SOL rate times the tier fee
+/// 2. Burns the POINTS from the buyer's token account
+/// 3. Credits the tickets to the buyer's Ticket PDA for `tier`
+///
+/// Tickets are spent by passing the Ticket account to `register_player`.
+///
+/// # Arguments
+/// * `tier` - Tier the tickets cover (BASIC or PRO)
+/// * `quantity` - Number of tickets to buy
+pub fn handler(ctx: Context<BuyTicketWithPoints>, tier: AgentTier, quantity: u16) -> Result<()> {
+    require!(quantity > 0, ArenaError::TicketsNotAvailable);
+    let points = ctx
+        .accounts
+        .arena_config
+        .ticket_price_points(tier)
+        .and_then(|price| price.checked_mul(quantity as u64))
+        .ok_or(ArenaError::TicketsNotAvailable)?;
+
+    token::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.points_mint.to_account_info(),
+                from: ctx.accounts.buyer_token_account.to_account_info(),
+                authority: ctx.accounts.buyer.to_account_info(),
+            },
+        ),
+        points,
+    )?;
+
+    let ticket = &mut ctx.accounts.ticket;
+    ticket.wallet = ctx.accounts.buyer.key();
+    ticket.tier = tier;
+    ticket.tickets = ticket.tickets.saturating_add(quantity);
+    ticket.total_bought = ticket.total_bought.saturating_add(quantity as u32);
+    ticket.total_points_burned = ticket.total_points_burned.saturating_add(points);
+    ticket.bump = ctx.bumps.ticket;
+
+    emit!(TicketsPurchased {
+        wallet: ticket.wallet,
+        tier,
+        quantity,
+        points_burned: points,
+    });
+
+    msg!(
+        "{} {:?} tickets bought by {} for {} POINTS ({} held)",
+        quantity,
+        tier,
+        ticket.wallet,
+        points,
+        ticket.tickets
+    );
+
+    Ok(())
+}
@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount};
+
+use crate::errors::ArenaError;
+use crate::events::BuybackBurned;
+use crate::state::{ArenaConfig, BuybackLedger};
+
+/// Accounts required for a rake-funded buyback-and-burn.
+#[derive(Accounts)]
+pub struct BuybackAndBurn<'info> {
+    /// Treasury wallet - must match arena_config.treasury
+    pub treasury: Signer<'info>,
+
+    /// Arena config for treasury verification and points mint
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.treasury == treasury.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Buyback ledger recording every burn
+    #[account(
+        mut,
+        seeds = [BuybackLedger::SEED_PREFIX],
+        bump = buyback_ledger.bump
+    )]
+    pub buyback_ledger: Account<'info, BuybackLedger>,
+
+    /// POINTS SPL token mint
+    #[account(
+        mut,
+        constraint = points_mint.key() == arena_config.points_mint @ ArenaError::InvalidTierPayment
+    )]
+    pub points_mint: Account<'info, Mint>,
+
+    /// Designated vault holding bought-back POINTS (owned by the ledger PDA)
+    #[account(
+        mut,
+        constraint = buyback_vault.mint == points_mint.key() @ ArenaError::InvalidTierPayment,
+        constraint = buyback_vault.owner == buyback_ledger.key() @ ArenaError::Unauthorized
+    )]
+    pub buyback_vault: Account<'info, TokenAccount>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+/// Burn POINTS bought back with the configured slice of collected rake.
+///
+/// The treasury buys POINTS into the buyback vault off-chain; this
+/// instruction records the spend and burns the vault's POINTS.
+///
+/// This instruction:
+/// 1. Enforces the minimum interval between burns
+/// 2. Computes the buyback budget from `rake_lamports` and buyback_bps
+/// 3. Burns `points_amount` from the buyback vault
+/// 4. Records rake, spend and burn totals in the ledger
+///
+/// # Arguments
+/// * `rake_lamports` - Rake collected since the last burn
+/// * `points_amount` - POINTS bought with the budget (base units)
+pub fn handler(ctx: Context<BuybackAndBurn>, rake_lamports: u64, points_amount: u64) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        ctx.accounts.buyback_ledger.is_due(now),
+        ArenaError::BuybackTooSoon
+    );
+
+    let lamports_spent = ctx.accounts.buyback_ledger.buyback_budget(rake_lamports);
+
+    let seeds = &[
+        BuybackLedger::SEED_PREFIX,
+        &[ctx.accounts.buyback_ledger.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    token::burn(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.points_mint.to_account_info(),
+                from: ctx.accounts.buyback_vault.to_account_info(),
+                authority: ctx.accounts.buyback_ledger.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        points_amount,
+    )?;
+
+    let buyback_ledger = &mut ctx.accounts.buyback_ledger;
+    buyback_ledger.total_rake_lamports = buyback_ledger
+        .total_rake_lamports
+        .saturating_add(rake_lamports);
+    buyback_ledger.total_lamports_spent = buyback_ledger
+        .total_lamports_spent
+        .saturating_add(lamports_spent);
+    buyback_ledger.total_points_burned = buyback_ledger
+        .total_points_burned
+        .saturating_add(points_amount);
+    buyback_ledger.burn_count = buyback_ledger
+        .burn_count
+        .checked_add(1)
+        .ok_or(ArenaError::ArithmeticOverflow)?;
+    buyback_ledger.last_burn_at = now;
+
+    emit!(BuybackBurned {
+        rake_lamports,
+        lamports_spent,
+        points_burned: points_amount,
+        timestamp: now,
+    });
+
+    msg!(
+        "Burned {} POINTS bought with {} lamports of rake",
+        points_amount,
+        lamports_spent
+    );
+
+    Ok(())
+}
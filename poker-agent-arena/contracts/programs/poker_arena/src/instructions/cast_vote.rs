@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::state::{Proposal, VoteRecord, VoteStake};
+
+/// Accounts required for voting on a proposal.
+#[derive(Accounts)]
+pub struct CastVote<'info> {
+    /// Voter - must have POINTS staked before the proposal was created
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    /// Proposal being voted on
+    #[account(
+        mut,
+        seeds = [Proposal::SEED_PREFIX, &proposal.id.to_le_bytes()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    /// Voter's vote stake
+    #[account(
+        mut,
+        seeds = [VoteStake::SEED_PREFIX, voter.key().as_ref()],
+        bump = vote_stake.bump
+    )]
+    pub vote_stake: Account<'info, VoteStake>,
+
+    /// Vote record - init prevents voting twice
+    #[account(
+        init,
+        payer = voter,
+        space = VoteRecord::SIZE,
+        seeds = [VoteRecord::SEED_PREFIX, proposal.key().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Vote on a proposal with staked POINTS.
+///
+/// This instruction:
+/// 1. Weights the vote by POINTS staked before the proposal snapshot
+/// 2. Locks the stake until voting ends so it cannot be reused
+/// 3. Records the vote and updates the tally
+///
+/// # Arguments
+/// * `support` - True to vote in favor, false to vote against
+pub fn handler(ctx: Context<CastVote>, support: bool) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    let vote_stake = &mut ctx.accounts.vote_stake;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(proposal.is_voting_open(now), ArenaError::VotingClosed);
+
+    let weight = vote_stake.weight_at(proposal.created_at);
+    require!(weight > 0, ArenaError::NoVotingPower);
+
+    vote_stake.locked_until = vote_stake.locked_until.max(proposal.voting_ends_at);
+
+    if support {
+        proposal.votes_for = proposal.votes_for.saturating_add(weight);
+    } else {
+        proposal.votes_against = proposal.votes_against.saturating_add(weight);
+    }
+
+    let vote_record = &mut ctx.accounts.vote_record;
+    vote_record.proposal = proposal.key();
+    vote_record.voter = ctx.accounts.voter.key();
+    vote_record.weight = weight;
+    vote_record.support = support;
+    vote_record.bump = ctx.bumps.vote_record;
+
+    msg!(
+        "Vote on proposal {}: {} with weight {}",
+        proposal.id,
+        if support { "for" } else { "against" },
+        weight
+    );
+
+    Ok(())
+}
@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
+
+use crate::errors::ArenaError;
+use crate::state::{
+    ActionShare, ArenaConfig, BackingPool, PlayerRegistration, PointsMintAuthority, Tournament,
+};
+
+/// Accounts required for claiming POINTS owed on an action share.
+#[derive(Accounts)]
+pub struct ClaimActionShare<'info> {
+    /// Buyer claiming their share
+    pub buyer: Signer<'info>,
+
+    /// Arena config for the points mint
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Seller's registration - points must have been distributed
+    #[account(
+        seeds = [PlayerRegistration::SEED_PREFIX, registration.tournament.as_ref(), registration.wallet.as_ref()],
+        bump = registration.bump,
+        constraint = registration.points_distributed @ ArenaError::PointsNotDistributed
+    )]
+    pub registration: Account<'info, PlayerRegistration>,
+
+    /// Tournament the POINTS were awarded in, for its points mint
+    #[account(
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.key() == registration.tournament @ ArenaError::TournamentNotFound
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Buyer's action share
+    #[account(
+        mut,
+        seeds = [ActionShare::SEED_PREFIX, registration.key().as_ref(), buyer.key().as_ref()],
+        bump = action_share.bump,
+        constraint = !action_share.claimed @ ArenaError::PointsAlreadyDistributed
+    )]
+    pub action_share: Account<'info, ActionShare>,
+
+    /// POINTS SPL token mint
+    #[account(
+        mut,
+        constraint = points_mint.key() == tournament.points_mint(&arena_config) @ ArenaError::InvalidTierPayment
+    )]
+    pub points_mint: Account<'info, Mint>,
+
+    /// PDA that holds mint authority
+    #[account(
+        seeds = [PointsMintAuthority::SEED_PREFIX],
+        bump = mint_authority.bump
+    )]
+    pub mint_authority: Account<'info, PointsMintAuthority>,
+
+    /// Buyer's token account for POINTS
+    #[account(
+        mut,
+        constraint = buyer_token_account.mint == points_mint.key() @ ArenaError::InvalidTierPayment,
+        constraint = buyer_token_account.owner == buyer.key() @ ArenaError::Unauthorized
+    )]
+    pub buyer_token_account: Account<'info, TokenAccount>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+/// Claim the POINTS owed on an action share after distribution, once the
+/// tournament's results can no longer be disputed.
+pub fn handler(ctx: Context<ClaimActionShare>) -> Result<()> {
+    require!(
+        ctx.accounts
+            .tournament
+            .points_releasable(Clock::get()?.unix_timestamp),
+        ArenaError::PointsEscrowLocked
+    );
+
+    let action_share = &mut ctx.accounts.action_share;
+    let amount = BackingPool::points_for_share(
+        ctx.accounts.registration.points_awarded.unwrap_or_default(),
+        action_share.share_bps,
+    );
+
+    action_share.claimed = true;
+
+    if amount > 0 {
+        let seeds = &[
+            PointsMintAuthority::SEED_PREFIX,
+            &[ctx.accounts.mint_authority.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.points_mint.to_account_info(),
+                    to: ctx.accounts.buyer_token_account.to_account_info(),
+                    authority: ctx.accounts.mint_authority.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )?;
+    }
+
+    msg!(
+        "Action buyer {} claimed {} POINTS",
+        action_share.buyer,
+        amount
+    );
+
+    Ok(())
+}
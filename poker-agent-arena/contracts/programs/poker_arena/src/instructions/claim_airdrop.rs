@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::errors::ArenaError;
+use crate::state::{Airdrop, AirdropClaim};
+
+/// Accounts required for claiming an airdrop allocation.
+#[derive(Accounts)]
+pub struct ClaimAirdrop<'info> {
+    /// Claiming wallet (pays for the claim marker)
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+
+    /// Airdrop being claimed
+    #[account(
+        mut,
+        seeds = [Airdrop::SEED_PREFIX, &airdrop.id.to_le_bytes()],
+        bump = airdrop.bump
+    )]
+    pub airdrop: Account<'info, Airdrop>,
+
+    /// Airdrop vault holding the allocation
+    #[account(
+        mut,
+        seeds = [Airdrop::VAULT_SEED_PREFIX, airdrop.key().as_ref()],
+        bump
+    )]
+    pub airdrop_vault: Account<'info, TokenAccount>,
+
+    /// Claim marker PDA (its existence prevents double claims)
+    #[account(
+        init,
+        payer = claimant,
+        space = AirdropClaim::SIZE,
+        seeds = [AirdropClaim::SEED_PREFIX, airdrop.key().as_ref(), claimant.key().as_ref()],
+        bump
+    )]
+    pub airdrop_claim: Account<'info, AirdropClaim>,
+
+    /// Claimant's token account for POINTS
+    #[account(
+        mut,
+        constraint = claimant_token_account.mint == airdrop_vault.mint @ ArenaError::InvalidTierPayment,
+        constraint = claimant_token_account.owner == claimant.key() @ ArenaError::Unauthorized
+    )]
+    pub claimant_token_account: Account<'info, TokenAccount>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Claim an airdrop allocation by proving inclusion in its Merkle root.
+///
+/// This instruction:
+/// 1. Validates the airdrop has not expired
+/// 2. Verifies the (airdrop, wallet, amount) leaf against the root
+/// 3. Records a claim marker and transfers the POINTS from the vault
+///
+/// # Arguments
+/// * `amount` - POINTS allocated to the claimant (base units)
+/// * `proof` - Sibling hashes from the leaf up to the root
+pub fn handler(ctx: Context<ClaimAirdrop>, amount: u64, proof: Vec<[u8; 32]>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let airdrop_key = ctx.accounts.airdrop.key();
+    let claimant_key = ctx.accounts.claimant.key();
+    let airdrop = &mut ctx.accounts.airdrop;
+
+    require!(airdrop.is_open(now), ArenaError::AirdropExpired);
+    require!(
+        airdrop.verify(&airdrop_key, &claimant_key, amount, &proof),
+        ArenaError::InvalidMerkleProof
+    );
+
+    let airdrop_claim = &mut ctx.accounts.airdrop_claim;
+    airdrop_claim.airdrop = airdrop_key;
+    airdrop_claim.wallet = claimant_key;
+    airdrop_claim.amount = amount;
+    airdrop_claim.claimed_at = now;
+    airdrop_claim.bump = ctx.bumps.airdrop_claim;
+
+    airdrop.claimed = airdrop.claimed.saturating_add(amount);
+
+    let id = airdrop.id.to_le_bytes();
+    let seeds = &[Airdrop::SEED_PREFIX, id.as_ref(), &[airdrop.bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.airdrop_vault.to_account_info(),
+                to: ctx.accounts.claimant_token_account.to_account_info(),
+                authority: airdrop.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )?;
+
+    msg!("Claimed {} airdrop POINTS for {}", amount, claimant_key);
+
+    Ok(())
+}
@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
+
+use crate::errors::ArenaError;
+use crate::state::{ArenaConfig, BackerShare, BackingPool, PointsMintAuthority, Tournament};
+
+/// Accounts required for claiming a backer's share of POINTS.
+#[derive(Accounts)]
+pub struct ClaimBackerPoints<'info> {
+    /// Backer claiming their share
+    pub backer: Signer<'info>,
+
+    /// Arena config for the points mint
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Backing pool - player's points must have been distributed
+    #[account(
+        seeds = [BackingPool::SEED_PREFIX, backing_pool.tournament.as_ref(), backing_pool.player.as_ref()],
+        bump = backing_pool.bump,
+        constraint = backing_pool.points_settled @ ArenaError::PointsNotDistributed
+    )]
+    pub backing_pool: Account<'info, BackingPool>,
+
+    /// Tournament the POINTS were awarded in, for its points mint
+    #[account(
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.key() == backing_pool.tournament @ ArenaError::TournamentNotFound
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Backer's share
+    #[account(
+        mut,
+        seeds = [BackerShare::SEED_PREFIX, backing_pool.key().as_ref(), backer.key().as_ref()],
+        bump = backer_share.bump,
+        constraint = !backer_share.claimed @ ArenaError::PointsAlreadyDistributed
+    )]
+    pub backer_share: Account<'info, BackerShare>,
+
+    /// POINTS SPL token mint
+    #[account(
+        mut,
+        constraint = points_mint.key() == tournament.points_mint(&arena_config) @ ArenaError::InvalidTierPayment
+    )]
+    pub points_mint: Account<'info, Mint>,
+
+    /// PDA that holds mint authority
+    #[account(
+        seeds = [PointsMintAuthority::SEED_PREFIX],
+        bump = mint_authority.bump
+    )]
+    pub mint_authority: Account<'info, PointsMintAuthority>,
+
+    /// Backer's token account for POINTS
+    #[account(
+        mut,
+        constraint = backer_token_account.mint == points_mint.key() @ ArenaError::InvalidTierPayment,
+        constraint = backer_token_account.owner == backer.key() @ ArenaError::Unauthorized
+    )]
+    pub backer_token_account: Account<'info, TokenAccount>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+/// Claim a backer's share of the player's distributed POINTS, once the
+/// tournament's results can no longer be disputed.
+pub fn handler(ctx: Context<ClaimBackerPoints>) -> Result<()> {
+    require!(
+        ctx.accounts
+            .tournament
+            .points_releasable(Clock::get()?.unix_timestamp),
+        ArenaError::PointsEscrowLocked
+    );
+
+    let backer_share = &mut ctx.accounts.backer_share;
+    let amount = BackingPool::points_for_share(
+        ctx.accounts.backing_pool.points_awarded,
+        backer_share.share_bps,
+    );
+
+    backer_share.claimed = true;
+
+    if amount > 0 {
+        let seeds = &[
+            PointsMintAuthority::SEED_PREFIX,
+            &[ctx.accounts.mint_authority.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.points_mint.to_account_info(),
+                    to: ctx.accounts.backer_token_account.to_account_info(),
+                    authority: ctx.accounts.mint_authority.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )?;
+    }
+
+    msg!("Backer {} claimed {} POINTS", backer_share.backer, amount);
+
+    Ok(())
+}
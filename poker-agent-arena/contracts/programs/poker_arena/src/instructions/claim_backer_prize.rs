@@ -0,0 +1,167 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::errors::ArenaError;
+use crate::events::PrizeClaimed;
+use crate::instructions::claim_prize::PrizePayout;
+use crate::state::{
+    BackerShare, BackingPool, PlayerRegistration, PointsMintAuthority, PrizePool, PrizeShareClaim,
+    Tournament, TournamentStatus, TreasuryLedger,
+};
+
+/// Accounts required for claiming an action share's cut of a prize.
+///
+/// The token accounts are required for USDC and POINTS pools; the points
+/// mint and mint authority only for POINTS pools.
+#[derive(Accounts)]
+pub struct ClaimBackerPrize<'info> {
+    /// Backer claiming their cut; pays the claim marker's rent
+    #[account(mut)]
+    pub backer: Signer<'info>,
+
+    /// Tournament - results must be confirmed
+    #[account(
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.status == TournamentStatus::Completed @ ArenaError::TournamentNotCompleted
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Tournament's prize pool
+    #[account(
+        mut,
+        seeds = [PrizePool::SEED_PREFIX, tournament.key().as_ref()],
+        bump = prize_pool.bump
+    )]
+    pub prize_pool: Account<'info, PrizePool>,
+
+    /// Backed player's registration - must be ranked and in good standing
+    #[account(
+        seeds = [PlayerRegistration::SEED_PREFIX, tournament.key().as_ref(), registration.wallet.as_ref()],
+        bump = registration.bump,
+        constraint = !registration.disqualified && !registration.forfeited @ ArenaError::NoPrizeToClaim
+    )]
+    pub registration: Account<'info, PlayerRegistration>,
+
+    /// Player's backing pool - only withdrawn pools own a cut of the prize
+    #[account(
+        seeds = [BackingPool::SEED_PREFIX, tournament.key().as_ref(), registration.wallet.as_ref()],
+        bump = backing_pool.bump,
+        constraint = backing_pool.withdrawn @ ArenaError::NoPrizeToClaim
+    )]
+    pub backing_pool: Account<'info, BackingPool>,
+
+    /// Backer's share
+    #[account(
+        seeds = [BackerShare::SEED_PREFIX, backing_pool.key().as_ref(), backer.key().as_ref()],
+        bump = backer_share.bump
+    )]
+    pub backer_share: Account<'info, BackerShare>,
+
+    /// Marker preventing the cut from being claimed twice
+    #[account(
+        init,
+        payer = backer,
+        space = PrizeShareClaim::SIZE,
+        seeds = [PrizeShareClaim::SEED_PREFIX, backer_share.key().as_ref()],
+        bump
+    )]
+    pub share_claim: Account<'info, PrizeShareClaim>,
+
+    /// Pool's USDC token account
+    #[account(
+        mut,
+        token::authority = prize_pool
+    )]
+    pub pool_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Backer's token account for the pool's mint
+    #[account(
+        mut,
+        constraint = backer_token_account.owner == backer.key() @ ArenaError::Unauthorized
+    )]
+    pub backer_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// POINTS SPL token mint
+    #[account(mut)]
+    pub points_mint: Option<Account<'info, Mint>>,
+
+    /// PDA that holds mint authority
+    #[account(
+        seeds = [PointsMintAuthority::SEED_PREFIX],
+        bump = mint_authority.bump
+    )]
+    pub mint_authority: Option<Account<'info, PointsMintAuthority>>,
+
+    /// SPL Token program
+    pub token_program: Option<Program<'info, Token>>,
+
+    /// System program for the claim marker
+    pub system_program: Program<'info, System>,
+
+    /// Treasury ledger recording a SOL payout (omit to skip)
+    #[account(
+        mut,
+        seeds = [TreasuryLedger::SEED_PREFIX],
+        bump = treasury_ledger.bump
+    )]
+    pub treasury_ledger: Option<Box<Account<'info, TreasuryLedger>>>,
+}
+
+/// Claim a backer's cut of the player's prize, withheld from them by
+/// `claim_prize` once they withdrew the backing, in the pool's denomination.
+pub fn handler(ctx: Context<ClaimBackerPrize>) -> Result<()> {
+    let final_rank = ctx
+        .accounts
+        .registration
+        .final_rank
+        .ok_or(ArenaError::NoPrizeToClaim)?;
+    let (_, prize) = ctx
+        .accounts
+        .prize_pool
+        .prize(final_rank)
+        .ok_or(ArenaError::NoPrizeToClaim)?;
+    let amount = BackingPool::points_for_share(prize, ctx.accounts.backer_share.share_bps);
+
+    PrizePayout {
+        prize_pool: &ctx.accounts.prize_pool,
+        tournament: ctx.accounts.tournament.key(),
+        pool_token_account: ctx.accounts.pool_token_account.as_ref(),
+        points_mint: ctx.accounts.points_mint.as_ref(),
+        mint_authority: ctx.accounts.mint_authority.as_ref(),
+        token_program: ctx.accounts.token_program.as_ref(),
+        treasury_ledger: ctx.accounts.treasury_ledger.as_deref_mut(),
+    }
+    .pay(
+        &ctx.accounts.backer,
+        ctx.accounts.backer_token_account.as_ref(),
+        amount,
+    )?;
+
+    let prize_pool = &mut ctx.accounts.prize_pool;
+    prize_pool.record_payout(amount)?;
+
+    let share_claim = &mut ctx.accounts.share_claim;
+    share_claim.prize_pool = prize_pool.key();
+    share_claim.holder = ctx.accounts.backer.key();
+    share_claim.amount = amount;
+    share_claim.claimed_at = Clock::get()?.unix_timestamp;
+    share_claim.bump = ctx.bumps.share_claim;
+
+    emit!(PrizeClaimed {
+        tournament: prize_pool.tournament,
+        wallet: share_claim.holder,
+        final_rank,
+        denomination: prize_pool.denomination,
+        amount,
+    });
+
+    msg!(
+        "Backer {} claimed {} of place {}'s prize",
+        share_claim.holder,
+        amount,
+        final_rank
+    );
+
+    Ok(())
+}
@@ -0,0 +1,178 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
+
+use crate::compression::{self, TreeAccounts, SPL_ACCOUNT_COMPRESSION_ID, SPL_NOOP_ID};
+use crate::errors::ArenaError;
+use crate::events::CompressedRegistrationUpdated;
+use crate::state::{
+    ArenaConfig, CompressedRegistration, CompressedRoster, PointsMintAuthority, Tournament,
+    TournamentStatus,
+};
+
+/// Accounts required for claiming POINTS awarded to a compressed registration.
+/// The Merkle proof for the leaf is passed as remaining accounts.
+#[derive(Accounts)]
+pub struct ClaimCompressedPoints<'info> {
+    /// Player who owns the compressed registration
+    pub claimant: Signer<'info>,
+
+    /// Arena config for the points mint
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament - must be Completed and not settled by a points root
+    #[account(
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.status == TournamentStatus::Completed @ ArenaError::TournamentNotCompleted,
+        constraint = tournament.points_root.is_none() @ ArenaError::PointsSettledByMerkleRoot
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Compressed roster (tree authority)
+    #[account(
+        seeds = [CompressedRoster::SEED_PREFIX, tournament.key().as_ref()],
+        bump = compressed_roster.bump
+    )]
+    pub compressed_roster: Account<'info, CompressedRoster>,
+
+    /// Concurrent Merkle tree holding registration leaves
+    /// CHECK: Verified against compressed_roster.merkle_tree
+    #[account(
+        mut,
+        address = compressed_roster.merkle_tree
+    )]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    /// POINTS SPL token mint
+    #[account(
+        mut,
+        constraint = points_mint.key() == tournament.points_mint(&arena_config) @ ArenaError::InvalidTierPayment
+    )]
+    pub points_mint: Account<'info, Mint>,
+
+    /// PDA that holds mint authority
+    #[account(
+        seeds = [PointsMintAuthority::SEED_PREFIX],
+        bump = mint_authority.bump
+    )]
+    pub mint_authority: Account<'info, PointsMintAuthority>,
+
+    /// Claimant's token account for POINTS
+    #[account(
+        mut,
+        constraint = claimant_token_account.mint == points_mint.key() @ ArenaError::InvalidTierPayment,
+        constraint = claimant_token_account.owner == claimant.key() @ ArenaError::Unauthorized
+    )]
+    pub claimant_token_account: Account<'info, TokenAccount>,
+
+    /// SPL account compression program
+    /// CHECK: Validated by address
+    #[account(address = SPL_ACCOUNT_COMPRESSION_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+
+    /// SPL noop program
+    /// CHECK: Validated by address
+    #[account(address = SPL_NOOP_ID)]
+    pub noop_program: UncheckedAccount<'info>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+/// Claim POINTS recorded on a compressed registration.
+///
+/// This instruction:
+/// 1. Validates the leaf belongs to the claimant and has undistributed points
+/// 2. Replaces the leaf with `points_distributed = true` (proof in remaining accounts)
+/// 3. Mints the awarded POINTS to the claimant's token account
+///
+/// # Arguments
+/// * `root` - Current tree root the proof was generated against
+/// * `leaf_index` - Index of the registration leaf
+/// * `registration` - Current leaf data (must hash to the stored leaf)
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ClaimCompressedPoints<'info>>,
+    root: [u8; 32],
+    leaf_index: u32,
+    registration: CompressedRegistration,
+) -> Result<()> {
+    let tournament_key = ctx.accounts.tournament.key();
+
+    require!(
+        registration.tournament == tournament_key,
+        ArenaError::TournamentNotFound
+    );
+    require!(
+        registration.wallet == ctx.accounts.claimant.key(),
+        ArenaError::Unauthorized
+    );
+    require!(
+        !registration.points_distributed,
+        ArenaError::PointsAlreadyDistributed
+    );
+    let points_to_mint = registration
+        .points_awarded
+        .ok_or(ArenaError::NoPointsToDistribute)?;
+
+    let previous_leaf = registration.leaf_hash()?;
+    let mut updated = registration;
+    updated.points_distributed = true;
+
+    let compressed_roster = &ctx.accounts.compressed_roster;
+    let seeds = &[
+        CompressedRoster::SEED_PREFIX,
+        tournament_key.as_ref(),
+        &[compressed_roster.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    compression::replace_leaf(
+        &TreeAccounts {
+            compression_program: &ctx.accounts.compression_program.to_account_info(),
+            merkle_tree: &ctx.accounts.merkle_tree.to_account_info(),
+            authority: &compressed_roster.to_account_info(),
+            noop: &ctx.accounts.noop_program.to_account_info(),
+        },
+        root,
+        previous_leaf,
+        updated.leaf_hash()?,
+        leaf_index,
+        ctx.remaining_accounts,
+        signer_seeds,
+    )?;
+
+    if points_to_mint > 0 {
+        let seeds = &[
+            PointsMintAuthority::SEED_PREFIX,
+            &[ctx.accounts.mint_authority.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.points_mint.to_account_info(),
+                    to: ctx.accounts.claimant_token_account.to_account_info(),
+                    authority: ctx.accounts.mint_authority.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            points_to_mint,
+        )?;
+    }
+
+    msg!("Distributed {} POINTS to player: {}", points_to_mint, updated.wallet);
+
+    emit!(CompressedRegistrationUpdated {
+        merkle_tree: compressed_roster.merkle_tree,
+        leaf_index,
+        registration: updated,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::state::DailyTicket;
+
+/// Accounts required for claiming the daily freeroll ticket.
+#[derive(Accounts)]
+pub struct ClaimDailyTicket<'info> {
+    /// Wallet claiming the ticket
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    /// Player's ticket PDA (created on first claim)
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = DailyTicket::SIZE,
+        seeds = [DailyTicket::SEED_PREFIX, player.key().as_ref()],
+        bump
+    )]
+    pub daily_ticket: Account<'info, DailyTicket>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Claim one freeroll ticket, at most once every 24 hours.
+///
+/// Tickets waive the BASIC tier fee when passed to `register_player`.
+pub fn handler(ctx: Context<ClaimDailyTicket>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let daily_ticket = &mut ctx.accounts.daily_ticket;
+    require!(daily_ticket.can_claim(now), ArenaError::TicketNotReady);
+
+    daily_ticket.wallet = ctx.accounts.player.key();
+    daily_ticket.tickets = daily_ticket.tickets.saturating_add(1);
+    daily_ticket.last_claimed_at = now;
+    daily_ticket.total_claimed = daily_ticket.total_claimed.saturating_add(1);
+    daily_ticket.bump = ctx.bumps.daily_ticket;
+
+    msg!(
+        "Daily ticket claimed by {} ({} held)",
+        daily_ticket.wallet,
+        daily_ticket.tickets
+    );
+
+    Ok(())
+}
@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
+
+use crate::errors::ArenaError;
+use crate::state::{
+    ArenaConfig, Guild, GuildEventEntry, GuildStats, PointsMintAuthority, Tournament,
+};
+
+/// Accounts required for claiming a guild event prize.
+#[derive(Accounts)]
+pub struct ClaimGuildPrize<'info> {
+    /// Anyone may crank the claim; POINTS go to the guild vault
+    pub caller: Signer<'info>,
+
+    /// Arena config for the points mint
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Guild event tournament - standings must be final
+    #[account(
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.guild_standings_final @ ArenaError::TournamentNotCompleted
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Ranked guild
+    #[account(
+        seeds = [Guild::SEED_PREFIX, guild.name.as_ref()],
+        bump = guild.bump
+    )]
+    pub guild: Account<'info, Guild>,
+
+    /// Guild's stats, credited with event wins
+    #[account(
+        mut,
+        seeds = [GuildStats::SEED_PREFIX, guild.key().as_ref()],
+        bump = guild_stats.bump
+    )]
+    pub guild_stats: Account<'info, GuildStats>,
+
+    /// Guild's event entry - must be ranked and unclaimed
+    #[account(
+        mut,
+        seeds = [GuildEventEntry::SEED_PREFIX, tournament.key().as_ref(), guild.key().as_ref()],
+        bump = guild_event_entry.bump,
+        constraint = guild_event_entry.rank > 0 @ ArenaError::NoPointsToDistribute,
+        constraint = !guild_event_entry.prize_claimed @ ArenaError::PointsAlreadyDistributed
+    )]
+    pub guild_event_entry: Account<'info, GuildEventEntry>,
+
+    /// POINTS SPL token mint
+    #[account(
+        mut,
+        constraint = points_mint.key() == tournament.points_mint(&arena_config) @ ArenaError::InvalidTierPayment
+    )]
+    pub points_mint: Account<'info, Mint>,
+
+    /// PDA that holds mint authority
+    #[account(
+        seeds = [PointsMintAuthority::SEED_PREFIX],
+        bump = mint_authority.bump
+    )]
+    pub mint_authority: Account<'info, PointsMintAuthority>,
+
+    /// Guild vault's token account for POINTS
+    #[account(
+        mut,
+        constraint = guild_token_account.mint == points_mint.key() @ ArenaError::InvalidTierPayment,
+        constraint = guild_token_account.owner == guild.vault @ ArenaError::InvalidGuild
+    )]
+    pub guild_token_account: Account<'info, TokenAccount>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+/// Mint a ranked guild's share of the guild event prize to its vault.
+pub fn handler(ctx: Context<ClaimGuildPrize>) -> Result<()> {
+    let guild_event_entry = &mut ctx.accounts.guild_event_entry;
+    let amount = guild_event_entry.prize_share(ctx.accounts.tournament.guild_prize_points);
+
+    if amount > 0 {
+        let seeds = &[
+            PointsMintAuthority::SEED_PREFIX,
+            &[ctx.accounts.mint_authority.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.points_mint.to_account_info(),
+                    to: ctx.accounts.guild_token_account.to_account_info(),
+                    authority: ctx.accounts.mint_authority.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )?;
+    }
+
+    guild_event_entry.prize_claimed = true;
+    if guild_event_entry.rank == 1 {
+        let guild_stats = &mut ctx.accounts.guild_stats;
+        guild_stats.guild_event_wins = guild_stats.guild_event_wins.saturating_add(1);
+    }
+
+    msg!(
+        "Guild {} claimed {} POINTS for rank {}",
+        ctx.accounts.guild.key(),
+        amount,
+        guild_event_entry.rank
+    );
+
+    Ok(())
+}
@@ -0,0 +1,232 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
+
+use crate::errors::ArenaError;
+use crate::merkle;
+use crate::state::{
+    ArenaConfig, BackingPool, PlayerRegistration, PointsClaim, PointsMintAuthority, Tournament,
+    TournamentStatus, VestingAccount,
+};
+
+/// Accounts required for claiming POINTS with a Merkle proof.
+#[derive(Accounts)]
+pub struct ClaimPointsWithProof<'info> {
+    /// Claiming player (pays for the claim marker)
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+
+    /// Arena config for the points mint
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament - must be Completed with a committed points root
+    #[account(
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.status == TournamentStatus::Completed @ ArenaError::TournamentNotCompleted,
+        constraint = tournament.points_root.is_some() @ ArenaError::PointsRootNotCommitted
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Claim marker PDA (its existence prevents double claims)
+    #[account(
+        init,
+        payer = claimant,
+        space = PointsClaim::SIZE,
+        seeds = [PointsClaim::SEED_PREFIX, tournament.key().as_ref(), claimant.key().as_ref()],
+        bump
+    )]
+    pub points_claim: Account<'info, PointsClaim>,
+
+    /// Claimant's registration - must have points awarded and not have been
+    /// disqualified
+    #[account(
+        mut,
+        seeds = [PlayerRegistration::SEED_PREFIX, tournament.key().as_ref(), claimant.key().as_ref()],
+        bump = registration.bump,
+        constraint = registration.points_awarded.is_some() @ ArenaError::NoPointsToDistribute,
+        constraint = !registration.points_distributed @ ArenaError::PointsAlreadyDistributed,
+        constraint = !registration.disqualified @ ArenaError::PlayerDisqualified
+    )]
+    pub registration: Account<'info, PlayerRegistration>,
+
+    /// Claimant's backing pool PDA (may be uninitialized if the player was not backed)
+    /// CHECK: Address derived from seeds; only deserialized if it holds data
+    #[account(
+        mut,
+        seeds = [BackingPool::SEED_PREFIX, tournament.key().as_ref(), claimant.key().as_ref()],
+        bump
+    )]
+    pub backing_pool: UncheckedAccount<'info>,
+
+    /// Vesting account to create when the claimant's award vests (omit otherwise)
+    #[account(
+        init,
+        payer = claimant,
+        space = VestingAccount::SIZE,
+        seeds = [VestingAccount::SEED_PREFIX, tournament.key().as_ref(), claimant.key().as_ref()],
+        bump
+    )]
+    pub vesting_account: Option<Account<'info, VestingAccount>>,
+
+    /// POINTS SPL token mint
+    #[account(
+        mut,
+        constraint = points_mint.key() == tournament.points_mint(&arena_config) @ ArenaError::InvalidTierPayment
+    )]
+    pub points_mint: Account<'info, Mint>,
+
+    /// PDA that holds mint authority
+    #[account(
+        seeds = [PointsMintAuthority::SEED_PREFIX],
+        bump = mint_authority.bump
+    )]
+    pub mint_authority: Account<'info, PointsMintAuthority>,
+
+    /// Claimant's token account for POINTS
+    #[account(
+        mut,
+        constraint = claimant_token_account.mint == points_mint.key() @ ArenaError::InvalidTierPayment,
+        constraint = claimant_token_account.owner == claimant.key() @ ArenaError::Unauthorized
+    )]
+    pub claimant_token_account: Account<'info, TokenAccount>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Claim awarded POINTS by proving inclusion in the tournament's points root.
+///
+/// This instruction:
+/// 1. Verifies the (tournament, wallet, amount) leaf against the committed
+///    root, and that the amount is a whole number of POINTS
+/// 2. Requires the dispute window to have closed and no sanction that can
+///    still be appealed or awaits its appeal
+/// 3. Caps the award at the registration's recorded points, which carry the
+///    points formula check and any penalties
+/// 4. Reserves sold action shares and settles the backing pool, if any
+/// 5. Records a claim marker so the award cannot be claimed twice
+/// 6. Mints the rest to the claimant's token account, or records it on a
+///    VestingAccount if it meets the vesting threshold
+///
+/// # Arguments
+/// * `amount` - POINTS awarded to the claimant (base units)
+/// * `proof` - Sibling hashes from the leaf up to the root
+pub fn handler(ctx: Context<ClaimPointsWithProof>, amount: u64, proof: Vec<[u8; 32]>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let tournament_key = ctx.accounts.tournament.key();
+    let claimant_key = ctx.accounts.claimant.key();
+    let root = ctx.accounts.tournament.points_root.unwrap();
+
+    let leaf = merkle::leaf_hash(&[
+        tournament_key.as_ref(),
+        claimant_key.as_ref(),
+        &amount.to_le_bytes(),
+    ]);
+    require!(
+        merkle::verify_proof(&proof, &root, leaf),
+        ArenaError::InvalidMerkleProof
+    );
+    let arena_config = &ctx.accounts.arena_config;
+    arena_config.require_whole_points(amount)?;
+
+    // Proof claims settle like distribute_points once nothing can be disputed
+    require!(
+        ctx.accounts.tournament.points_releasable(now),
+        ArenaError::DisputeWindowOpen
+    );
+    let registration = &mut ctx.accounts.registration;
+    require!(
+        !registration.sanction_pending(now),
+        ArenaError::SanctionPending
+    );
+    let points_awarded = amount.min(registration.points_awarded.unwrap());
+    let mut points_to_mint = points_awarded;
+
+    // Action buyers and backers who funded a withdrawn pool claim their shares separately
+    points_to_mint = points_to_mint.saturating_sub(BackingPool::points_for_share(
+        points_awarded,
+        registration.action_sold_bps,
+    ));
+    let backing_info = ctx.accounts.backing_pool.to_account_info();
+    if !backing_info.data_is_empty() {
+        require_keys_eq!(
+            *backing_info.owner,
+            crate::ID,
+            ErrorCode::AccountOwnedByWrongProgram
+        );
+        let mut data = backing_info.try_borrow_mut_data()?;
+        let mut backing_pool = BackingPool::try_deserialize(&mut &data[..])?;
+        if backing_pool.withdrawn {
+            points_to_mint = points_to_mint.saturating_sub(BackingPool::points_for_share(
+                points_awarded,
+                backing_pool.sold_share_bps,
+            ));
+            backing_pool.points_awarded = points_awarded;
+            backing_pool.points_settled = true;
+            backing_pool.try_serialize(&mut &mut data[..])?;
+        }
+    }
+    registration.points_distributed = true;
+
+    let points_claim = &mut ctx.accounts.points_claim;
+    points_claim.tournament = tournament_key;
+    points_claim.wallet = claimant_key;
+    points_claim.amount = points_to_mint;
+    points_claim.claimed_at = now;
+    points_claim.bump = ctx.bumps.points_claim;
+
+    let vests = arena_config.vests(points_to_mint);
+    require!(
+        ctx.accounts.vesting_account.is_some() == vests,
+        ArenaError::VestingAccountMismatch
+    );
+    if let Some(vesting_account) = ctx.accounts.vesting_account.as_mut() {
+        vesting_account.start(
+            claimant_key,
+            tournament_key,
+            points_to_mint,
+            arena_config,
+            now,
+            ctx.bumps.vesting_account.unwrap(),
+        );
+        msg!(
+            "{} POINTS vest until {}",
+            points_to_mint,
+            vesting_account.end_at
+        );
+    } else if points_to_mint > 0 {
+        let seeds = &[
+            PointsMintAuthority::SEED_PREFIX,
+            &[ctx.accounts.mint_authority.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.points_mint.to_account_info(),
+                    to: ctx.accounts.claimant_token_account.to_account_info(),
+                    authority: ctx.accounts.mint_authority.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            points_to_mint,
+        )?;
+    }
+
+    msg!(
+        "Claimed {} POINTS for player: {}",
+        points_to_mint,
+        claimant_key
+    );
+
+    Ok(())
+}
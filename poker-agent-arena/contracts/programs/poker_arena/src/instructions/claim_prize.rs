@@ -0,0 +1,297 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount, Transfer};
+
+use crate::errors::ArenaError;
+use crate::events::PrizeClaimed;
+use crate::state::{
+    BackingPool, LedgerCategory, LedgerDirection, PlayerRegistration, PointsMintAuthority,
+    PrizeDenomination, PrizePool, Tournament, TournamentStatus, TreasuryLedger,
+};
+
+/// Accounts required for paying a finishing place its prize.
+///
+/// The token accounts are required for USDC and POINTS pools; the points
+/// mint and mint authority only for POINTS pools.
+#[derive(Accounts)]
+pub struct ClaimPrize<'info> {
+    /// Anyone may crank the claim; the prize goes to the registered wallet
+    pub caller: Signer<'info>,
+
+    /// Tournament - results must be confirmed
+    #[account(
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.status == TournamentStatus::Completed @ ArenaError::TournamentNotCompleted
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Tournament's prize pool
+    #[account(
+        mut,
+        seeds = [PrizePool::SEED_PREFIX, tournament.key().as_ref()],
+        bump = prize_pool.bump
+    )]
+    pub prize_pool: Account<'info, PrizePool>,
+
+    /// Winner's registration - must be ranked and in good standing
+    #[account(
+        seeds = [PlayerRegistration::SEED_PREFIX, tournament.key().as_ref(), registration.wallet.as_ref()],
+        bump = registration.bump,
+        constraint = !registration.disqualified && !registration.forfeited @ ArenaError::NoPrizeToClaim
+    )]
+    pub registration: Account<'info, PlayerRegistration>,
+
+    /// Player's backing pool PDA (may be uninitialized if the player was not backed)
+    /// CHECK: Address derived from seeds; only deserialized if it holds data
+    #[account(
+        seeds = [BackingPool::SEED_PREFIX, tournament.key().as_ref(), registration.wallet.as_ref()],
+        bump
+    )]
+    pub backing_pool: UncheckedAccount<'info>,
+
+    /// Registered wallet, receiving SOL prizes
+    /// CHECK: Verified against registration.wallet
+    #[account(
+        mut,
+        address = registration.wallet @ ArenaError::Unauthorized
+    )]
+    pub wallet: UncheckedAccount<'info>,
+
+    /// Pool's USDC token account
+    #[account(
+        mut,
+        token::authority = prize_pool
+    )]
+    pub pool_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Winner's token account for the pool's mint
+    #[account(
+        mut,
+        constraint = player_token_account.owner == registration.wallet @ ArenaError::Unauthorized
+    )]
+    pub player_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// POINTS SPL token mint
+    #[account(mut)]
+    pub points_mint: Option<Account<'info, Mint>>,
+
+    /// PDA that holds mint authority
+    #[account(
+        seeds = [PointsMintAuthority::SEED_PREFIX],
+        bump = mint_authority.bump
+    )]
+    pub mint_authority: Option<Account<'info, PointsMintAuthority>>,
+
+    /// SPL Token program
+    pub token_program: Option<Program<'info, Token>>,
+
+    /// Treasury ledger recording a SOL payout (omit to skip)
+    #[account(
+        mut,
+        seeds = [TreasuryLedger::SEED_PREFIX],
+        bump = treasury_ledger.bump
+    )]
+    pub treasury_ledger: Option<Box<Account<'info, TreasuryLedger>>>,
+}
+
+/// Pay a finishing place its share of the prize pool (permissionless).
+///
+/// This instruction:
+/// 1. Requires confirmed results and an unclaimed, paid finishing place
+/// 2. Withholds the cut sold to action buyers, and to backers if the player
+///    withdrew their backing, which they claim with `claim_action_prize`
+///    and `claim_backer_prize`
+/// 3. Pays the rest in the pool's denomination: lamports from the pool PDA,
+///    USDC from a pool-owned token account, or freshly minted POINTS
+/// 4. Marks the place claimed and emits PrizeClaimed
+///
+/// Funds stay escrowed in the pool until `confirm_results` closes the
+/// dispute window, so disputed results never pay out.
+pub fn handler(ctx: Context<ClaimPrize>) -> Result<()> {
+    let registration = &ctx.accounts.registration;
+    let final_rank = registration.final_rank.ok_or(ArenaError::NoPrizeToClaim)?;
+    let (index, prize) = ctx
+        .accounts
+        .prize_pool
+        .unclaimed(final_rank)
+        .ok_or(ArenaError::NoPrizeToClaim)?;
+
+    // Prizes are split with action buyers and backers like POINTS awards
+    let mut amount = prize - BackingPool::points_for_share(prize, registration.action_sold_bps);
+    let backing_info = ctx.accounts.backing_pool.to_account_info();
+    if !backing_info.data_is_empty() {
+        require_keys_eq!(
+            *backing_info.owner,
+            crate::ID,
+            ErrorCode::AccountOwnedByWrongProgram
+        );
+        let backing_pool = BackingPool::try_deserialize(&mut &backing_info.try_borrow_data()?[..])?;
+        if backing_pool.withdrawn {
+            amount = amount.saturating_sub(BackingPool::points_for_share(
+                prize,
+                backing_pool.sold_share_bps,
+            ));
+        }
+    }
+
+    PrizePayout {
+        prize_pool: &ctx.accounts.prize_pool,
+        tournament: ctx.accounts.tournament.key(),
+        pool_token_account: ctx.accounts.pool_token_account.as_ref(),
+        points_mint: ctx.accounts.points_mint.as_ref(),
+        mint_authority: ctx.accounts.mint_authority.as_ref(),
+        token_program: ctx.accounts.token_program.as_ref(),
+        treasury_ledger: ctx.accounts.treasury_ledger.as_deref_mut(),
+    }
+    .pay(
+        &ctx.accounts.wallet,
+        ctx.accounts.player_token_account.as_ref(),
+        amount,
+    )?;
+
+    let prize_pool = &mut ctx.accounts.prize_pool;
+    prize_pool.claimed_mask |= 1 << index;
+    prize_pool.record_payout(amount)?;
+
+    emit!(PrizeClaimed {
+        tournament: prize_pool.tournament,
+        wallet: ctx.accounts.registration.wallet,
+        final_rank,
+        denomination: prize_pool.denomination,
+        amount,
+    });
+
+    msg!(
+        "Paid {} to {} for place {}",
+        amount,
+        ctx.accounts.registration.wallet,
+        final_rank
+    );
+
+    Ok(())
+}
+
+/// Accounts that pay out of a prize pool in its denomination.
+pub(crate) struct PrizePayout<'a, 'info> {
+    /// Prize pool paying out
+    pub prize_pool: &'a Account<'info, PrizePool>,
+    /// Tournament the pool belongs to, for the pool's PDA seeds
+    pub tournament: Pubkey,
+    /// Pool's USDC token account
+    pub pool_token_account: Option<&'a Account<'info, TokenAccount>>,
+    /// POINTS SPL token mint
+    pub points_mint: Option<&'a Account<'info, Mint>>,
+    /// PDA that holds mint authority
+    pub mint_authority: Option<&'a Account<'info, PointsMintAuthority>>,
+    /// SPL Token program
+    pub token_program: Option<&'a Program<'info, Token>>,
+    /// Treasury ledger recording a SOL payout
+    pub treasury_ledger: Option<&'a mut Account<'info, TreasuryLedger>>,
+}
+
+impl<'info> PrizePayout<'_, 'info> {
+    /// Pay `amount` to `wallet` for SOL pools, or to `destination` for USDC
+    /// and POINTS pools
+    pub(crate) fn pay(
+        self,
+        wallet: &AccountInfo<'info>,
+        destination: Option<&Account<'info, TokenAccount>>,
+        amount: u64,
+    ) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+        let prize_pool = self.prize_pool;
+
+        match prize_pool.denomination {
+            PrizeDenomination::Sol => {
+                let source = prize_pool.to_account_info();
+                let rent_floor = Rent::get()?.minimum_balance(source.data_len());
+                require!(
+                    source.lamports().saturating_sub(rent_floor) >= amount,
+                    ArenaError::InvalidPrizePool
+                );
+                **source.try_borrow_mut_lamports()? -= amount;
+                **wallet.try_borrow_mut_lamports()? += amount;
+                if let Some(treasury_ledger) = self.treasury_ledger {
+                    treasury_ledger.record(
+                        LedgerCategory::PrizePool,
+                        LedgerDirection::Outflow,
+                        source.key(),
+                        amount,
+                        Clock::get()?.unix_timestamp,
+                    );
+                }
+            }
+            PrizeDenomination::Usdc => {
+                let (Some(source), Some(destination), Some(token_program)) =
+                    (self.pool_token_account, destination, self.token_program)
+                else {
+                    return err!(ArenaError::InvalidPrizePool);
+                };
+                require!(
+                    source.mint == prize_pool.mint && destination.mint == prize_pool.mint,
+                    ArenaError::InvalidPrizePool
+                );
+
+                let seeds = &[
+                    PrizePool::SEED_PREFIX,
+                    self.tournament.as_ref(),
+                    &[prize_pool.bump],
+                ];
+                let signer_seeds = &[&seeds[..]];
+
+                token::transfer(
+                    CpiContext::new_with_signer(
+                        token_program.to_account_info(),
+                        Transfer {
+                            from: source.to_account_info(),
+                            to: destination.to_account_info(),
+                            authority: prize_pool.to_account_info(),
+                        },
+                        signer_seeds,
+                    ),
+                    amount,
+                )?;
+            }
+            PrizeDenomination::Points => {
+                let (
+                    Some(points_mint),
+                    Some(mint_authority),
+                    Some(destination),
+                    Some(token_program),
+                ) = (
+                    self.points_mint,
+                    self.mint_authority,
+                    destination,
+                    self.token_program,
+                )
+                else {
+                    return err!(ArenaError::InvalidPrizePool);
+                };
+                require!(
+                    points_mint.key() == prize_pool.mint && destination.mint == prize_pool.mint,
+                    ArenaError::InvalidPrizePool
+                );
+
+                let seeds = &[PointsMintAuthority::SEED_PREFIX, &[mint_authority.bump]];
+                let signer_seeds = &[&seeds[..]];
+
+                token::mint_to(
+                    CpiContext::new_with_signer(
+                        token_program.to_account_info(),
+                        MintTo {
+                            mint: points_mint.to_account_info(),
+                            to: destination.to_account_info(),
+                            authority: mint_authority.to_account_info(),
+                        },
+                        signer_seeds,
+                    ),
+                    amount,
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::state::{LedgerCategory, LedgerDirection, SeasonRewards, TreasuryLedger};
+
+/// Accounts required for claiming a season revenue-share reward.
+#[derive(Accounts)]
+pub struct ClaimSeasonReward<'info> {
+    /// Winner claiming their share
+    #[account(mut)]
+    pub winner: Signer<'info>,
+
+    /// Funded season rewards vault
+    #[account(
+        mut,
+        seeds = [SeasonRewards::SEED_PREFIX, season_rewards.season.as_ref()],
+        bump = season_rewards.bump
+    )]
+    pub season_rewards: Account<'info, SeasonRewards>,
+
+    /// Treasury ledger recording the movement (omit to skip)
+    #[account(
+        mut,
+        seeds = [TreasuryLedger::SEED_PREFIX],
+        bump = treasury_ledger.bump
+    )]
+    pub treasury_ledger: Option<Box<Account<'info, TreasuryLedger>>>,
+}
+
+/// Claim the signer's share of a season's revenue-share rewards.
+pub fn handler(ctx: Context<ClaimSeasonReward>) -> Result<()> {
+    let winner = ctx.accounts.winner.key();
+    let (index, amount) = ctx
+        .accounts
+        .season_rewards
+        .unclaimed(&winner)
+        .ok_or(ArenaError::NoSeasonReward)?;
+
+    **ctx
+        .accounts
+        .season_rewards
+        .to_account_info()
+        .try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.winner.to_account_info().try_borrow_mut_lamports()? += amount;
+
+    ctx.accounts.season_rewards.claimed_mask |= 1 << index;
+
+    if let Some(treasury_ledger) = ctx.accounts.treasury_ledger.as_mut() {
+        treasury_ledger.record(
+            LedgerCategory::SeasonRewards,
+            LedgerDirection::Outflow,
+            ctx.accounts.season_rewards.key(),
+            amount,
+            Clock::get()?.unix_timestamp,
+        );
+    }
+
+    msg!("Season reward of {} lamports claimed by {}", amount, winner);
+
+    Ok(())
+}
@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
+
+use crate::errors::ArenaError;
+use crate::state::{ArenaConfig, PointsMintAuthority, Tournament, VestingAccount};
+
+/// Accounts required for claiming vested POINTS.
+#[derive(Accounts)]
+pub struct ClaimVestedPoints<'info> {
+    /// Player the POINTS vest to
+    pub player: Signer<'info>,
+
+    /// Arena config for the points mint
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Player's vesting account
+    #[account(
+        mut,
+        seeds = [VestingAccount::SEED_PREFIX, vesting_account.tournament.as_ref(), player.key().as_ref()],
+        bump = vesting_account.bump
+    )]
+    pub vesting_account: Account<'info, VestingAccount>,
+
+    /// Tournament the POINTS were awarded in, for its points mint
+    #[account(
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.key() == vesting_account.tournament @ ArenaError::TournamentNotFound
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// POINTS SPL token mint
+    #[account(
+        mut,
+        constraint = points_mint.key() == tournament.points_mint(&arena_config) @ ArenaError::InvalidTierPayment
+    )]
+    pub points_mint: Account<'info, Mint>,
+
+    /// PDA that holds mint authority
+    #[account(
+        seeds = [PointsMintAuthority::SEED_PREFIX],
+        bump = mint_authority.bump
+    )]
+    pub mint_authority: Account<'info, PointsMintAuthority>,
+
+    /// Player's token account for POINTS
+    #[account(
+        mut,
+        constraint = player_token_account.mint == points_mint.key() @ ArenaError::InvalidTierPayment,
+        constraint = player_token_account.owner == player.key() @ ArenaError::Unauthorized
+    )]
+    pub player_token_account: Account<'info, TokenAccount>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+/// Mint the unlocked, unclaimed portion of a vesting award to the player.
+pub fn handler(ctx: Context<ClaimVestedPoints>) -> Result<()> {
+    let vesting_account = &mut ctx.accounts.vesting_account;
+    let unlocked = vesting_account.unlocked(Clock::get()?.unix_timestamp);
+    let amount = unlocked.saturating_sub(vesting_account.claimed_points);
+    require!(amount > 0, ArenaError::NothingVested);
+
+    let seeds = &[
+        PointsMintAuthority::SEED_PREFIX,
+        &[ctx.accounts.mint_authority.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    token::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.points_mint.to_account_info(),
+                to: ctx.accounts.player_token_account.to_account_info(),
+                authority: ctx.accounts.mint_authority.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )?;
+
+    vesting_account.claimed_points = unlocked;
+
+    msg!(
+        "Claimed {} vested POINTS ({}/{})",
+        amount,
+        vesting_account.claimed_points,
+        vesting_account.total_points
+    );
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::state::PaymentReceipt;
+
+/// Accounts required for closing a payment receipt.
+#[derive(Accounts)]
+pub struct ClosePaymentReceipt<'info> {
+    /// Wallet that paid the fee
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Receipt to close; rent returns to the payer
+    #[account(
+        mut,
+        close = payer,
+        seeds = [PaymentReceipt::SEED_PREFIX, payment_receipt.registration.as_ref(), &[payment_receipt.kind as u8]],
+        bump = payment_receipt.bump,
+        constraint = payment_receipt.payer == payer.key() @ ArenaError::Unauthorized
+    )]
+    pub payment_receipt: Account<'info, PaymentReceipt>,
+}
+
+/// Close a payment receipt once it is no longer needed, reclaiming its rent.
+pub fn handler(ctx: Context<ClosePaymentReceipt>) -> Result<()> {
+    msg!(
+        "Closed {:?} receipt of {} lamports for {}",
+        ctx.accounts.payment_receipt.kind,
+        ctx.accounts.payment_receipt.amount,
+        ctx.accounts.payer.key()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{
+    ActionLog, ArenaConfig, AuditLog, PlayerRegistration, Tournament, TournamentStatus,
+};
+
+/// Accounts required for committing a batch of agent decisions.
+#[derive(Accounts)]
+pub struct CommitActionLog<'info> {
+    /// Arena admin, the tournament's delegated operator or its host
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Arena config for admin verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament - must be InProgress
+    #[account(
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.can_run(&authority.key(), &arena_config.admin) @ ArenaError::Unauthorized,
+        constraint = tournament.status == TournamentStatus::InProgress @ ArenaError::TournamentNotInProgress
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Registration whose decisions are being committed
+    #[account(
+        seeds = [PlayerRegistration::SEED_PREFIX, tournament.key().as_ref(), registration.wallet.as_ref()],
+        bump = registration.bump,
+        constraint = registration.tournament == tournament.key() @ ArenaError::TournamentNotFound
+    )]
+    pub registration: Account<'info, PlayerRegistration>,
+
+    /// Action log for this registration (created on the first batch)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ActionLog::SIZE,
+        seeds = [ActionLog::SEED_PREFIX, registration.key().as_ref()],
+        bump
+    )]
+    pub action_log: Account<'info, ActionLog>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Commit a batch of an agent's decisions to its action log (admin, operator or host).
+///
+/// This instruction:
+/// 1. Creates the action log on the first batch
+/// 2. Validates batches arrive in order
+/// 3. Extends the hash chain with the batch hash
+///
+/// # Arguments
+/// * `batch_index` - Zero-based index of this batch (each covers 100 hands)
+/// * `batch_hash` - SHA-256 hash of the agent's decisions in this batch
+pub fn handler(ctx: Context<CommitActionLog>, batch_index: u32, batch_hash: [u8; 32]) -> Result<()> {
+    let action_log = &mut ctx.accounts.action_log;
+
+    // Initialize on first commitment
+    if action_log.registration == Pubkey::default() {
+        action_log.registration = ctx.accounts.registration.key();
+        action_log.tournament = ctx.accounts.tournament.key();
+        action_log.chain_head = [0u8; 32];
+        action_log.batches_committed = 0;
+        action_log.bump = ctx.bumps.action_log;
+    }
+
+    require!(
+        batch_index == action_log.batches_committed,
+        ArenaError::CommitmentOutOfOrder
+    );
+
+    action_log.chain_head = ActionLog::next_head(&action_log.chain_head, &batch_hash);
+    action_log.batches_committed = action_log
+        .batches_committed
+        .checked_add(1)
+        .ok_or(ArenaError::ArithmeticOverflow)?;
+    action_log.last_committed_at = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "Action log batch {} committed for player: {}",
+        batch_index,
+        ctx.accounts.registration.wallet
+    );
+
+    AuditLog::record_call::<instruction::CommitActionLog>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.authority.key(),
+        ctx.accounts.tournament.id,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::errors::ArenaError;
+use crate::state::{
+    AgentTier, LedgerCategory, LedgerDirection, PlayerRegistration, PromptEscrow, Tournament,
+    TreasuryLedger,
+};
+
+/// Accounts required for committing an encrypted prompt.
+#[derive(Accounts)]
+pub struct CommitEncryptedPrompt<'info> {
+    /// Player who owns the registration
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    /// Tournament - registration must still be open
+    #[account(
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.is_registration_open() @ ArenaError::AgentLocked
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Player's PRO registration for this tournament
+    #[account(
+        seeds = [PlayerRegistration::SEED_PREFIX, tournament.key().as_ref(), player.key().as_ref()],
+        bump = registration.bump,
+        constraint = registration.tournament == tournament.key() @ ArenaError::TournamentNotFound,
+        constraint = registration.tier == AgentTier::Pro @ ArenaError::InvalidTier
+    )]
+    pub registration: Account<'info, PlayerRegistration>,
+
+    /// Prompt escrow PDA to be created (holds the bond)
+    #[account(
+        init,
+        payer = player,
+        space = PromptEscrow::SIZE,
+        seeds = [PromptEscrow::SEED_PREFIX, registration.key().as_ref()],
+        bump
+    )]
+    pub prompt_escrow: Account<'info, PromptEscrow>,
+
+    /// System program for account creation and the bond transfer
+    pub system_program: Program<'info, System>,
+
+    /// Treasury ledger recording the bond movement (omit to skip)
+    #[account(
+        mut,
+        seeds = [TreasuryLedger::SEED_PREFIX],
+        bump = treasury_ledger.bump
+    )]
+    pub treasury_ledger: Option<Box<Account<'info, TreasuryLedger>>>,
+}
+
+/// Commit an encrypted prompt and its key commitment, posting a reveal bond.
+///
+/// This instruction:
+/// 1. Validates the registration is PRO tier and registration is still open
+/// 2. Records the encrypted blob hash and the key commitment
+/// 3. Transfers the reveal bond from the player into the escrow PDA
+///
+/// # Arguments
+/// * `encrypted_prompt_hash` - SHA-256 hash of the encrypted prompt blob
+/// * `key_commitment` - SHA-256 hash of the decryption key
+pub fn handler(
+    ctx: Context<CommitEncryptedPrompt>,
+    encrypted_prompt_hash: [u8; 32],
+    key_commitment: [u8; 32],
+) -> Result<()> {
+    let prompt_escrow = &mut ctx.accounts.prompt_escrow;
+
+    // Post the bond into the escrow account
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.player.to_account_info(),
+                to: prompt_escrow.to_account_info(),
+            },
+        ),
+        PromptEscrow::BOND_LAMPORTS,
+    )?;
+
+    prompt_escrow.registration = ctx.accounts.registration.key();
+    prompt_escrow.wallet = ctx.accounts.player.key();
+    prompt_escrow.encrypted_prompt_hash = encrypted_prompt_hash;
+    prompt_escrow.key_commitment = key_commitment;
+    prompt_escrow.decryption_key = [0u8; 32];
+    prompt_escrow.bond_lamports = PromptEscrow::BOND_LAMPORTS;
+    prompt_escrow.key_revealed = false;
+    prompt_escrow.committed_at = Clock::get()?.unix_timestamp;
+    prompt_escrow.bump = ctx.bumps.prompt_escrow;
+
+    if let Some(treasury_ledger) = ctx.accounts.treasury_ledger.as_mut() {
+        treasury_ledger.record(
+            LedgerCategory::PromptBond,
+            LedgerDirection::Inflow,
+            prompt_escrow.key(),
+            PromptEscrow::BOND_LAMPORTS,
+            prompt_escrow.committed_at,
+        );
+    }
+
+    msg!("Encrypted prompt committed for player: {}", prompt_escrow.wallet);
+    msg!("Bond posted: {} lamports", PromptEscrow::BOND_LAMPORTS);
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{ActionLog, ArenaConfig, AuditLog, Tournament, TournamentStatus};
+
+/// Accounts required for committing a batch of the tournament hand log.
+#[derive(Accounts)]
+pub struct CommitGameLog<'info> {
+    /// Arena admin, the tournament's delegated operator or its host
+    pub authority: Signer<'info>,
+
+    /// Arena config for admin verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament - must be InProgress
+    #[account(
+        mut,
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.can_run(&authority.key(), &arena_config.admin) @ ArenaError::Unauthorized,
+        constraint = tournament.status == TournamentStatus::InProgress @ ArenaError::TournamentNotInProgress
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Extend the tournament's hand-log hash chain (admin, operator or host).
+///
+/// Each head is SHA-256(prev_head || hand_log_hash), so third parties can
+/// replay every batch from the published logs. The final batch must be the
+/// standings, making the last head the results hash checked at finalization.
+///
+/// # Arguments
+/// * `batch_index` - Zero-based index of this batch
+/// * `prev_hash` - Chain head the batch builds on (must match the current head)
+/// * `hand_log_hash` - SHA-256 hash of the hand logs in this batch
+pub fn handler(
+    ctx: Context<CommitGameLog>,
+    batch_index: u32,
+    prev_hash: [u8; 32],
+    hand_log_hash: [u8; 32],
+) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+
+    require!(
+        batch_index == tournament.game_log_batches && prev_hash == tournament.game_log_head,
+        ArenaError::CommitmentOutOfOrder
+    );
+
+    tournament.game_log_head = ActionLog::next_head(&prev_hash, &hand_log_hash);
+    tournament.game_log_batches = tournament
+        .game_log_batches
+        .checked_add(1)
+        .ok_or(ArenaError::ArithmeticOverflow)?;
+
+    msg!(
+        "Game log batch {} committed for tournament {}",
+        batch_index,
+        tournament.id
+    );
+
+    AuditLog::record_call::<instruction::CommitGameLog>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.authority.key(),
+        ctx.accounts.tournament.id,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog, Tournament, TournamentStatus};
+
+/// Accounts required for committing a hand-history Merkle root.
+#[derive(Accounts)]
+pub struct CommitHandHistoryRoot<'info> {
+    /// Arena admin, the tournament's delegated operator or its host
+    pub authority: Signer<'info>,
+
+    /// Arena config for admin verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament - must be Completed without a committed root
+    #[account(
+        mut,
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.can_run(&authority.key(), &arena_config.admin) @ ArenaError::Unauthorized,
+        constraint = tournament.status == TournamentStatus::Completed @ ArenaError::TournamentNotCompleted,
+        constraint = tournament.hand_history_root.is_none() @ ArenaError::HandHistoryRootAlreadyCommitted
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Commit the Merkle root over the tournament's hand histories (admin, operator or host).
+///
+/// Individual hands can then be proven on-chain, e.g. by `mint_famous_hand`.
+///
+/// # Arguments
+/// * `hand_history_root` - Root over leaves
+///   SHA-256(0x00 || tournament || hand_index_le || hand_hash || participants...)
+pub fn handler(ctx: Context<CommitHandHistoryRoot>, hand_history_root: [u8; 32]) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+
+    tournament.hand_history_root = Some(hand_history_root);
+
+    msg!(
+        "Hand-history root committed for tournament {}",
+        tournament.id
+    );
+
+    AuditLog::record_call::<instruction::CommitHandHistoryRoot>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.authority.key(),
+        ctx.accounts.tournament.id,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog, Tournament, TournamentStatus};
+
+/// Accounts required for committing a points Merkle root.
+#[derive(Accounts)]
+pub struct CommitPointsRoot<'info> {
+    /// Results authority, or the arena admin or tournament's operator if none
+    /// is set; the admin alone for hosted tournaments
+    pub authority: Signer<'info>,
+
+    /// Arena config for results authority verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament - must be Completed without a committed root
+    #[account(
+        mut,
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = arena_config.can_award_points(&authority.key(), &tournament) @ ArenaError::Unauthorized,
+        constraint = tournament.status == TournamentStatus::Completed @ ArenaError::TournamentNotCompleted,
+        constraint = tournament.points_root.is_none() @ ArenaError::PointsRootAlreadyCommitted
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Commit the Merkle root of (wallet, points) awards (results
+/// authority, or admin or operator if unset).
+///
+/// Hosts may not mint POINTS, so the admin commits hosted tournaments' roots.
+///
+/// Once committed, players settle with `claim_points_with_proof` and
+/// per-player `distribute_points` is disabled for this tournament.
+///
+/// # Arguments
+/// * `points_root` - Root over leaves SHA-256(0x00 || tournament || wallet || amount_le)
+pub fn handler(ctx: Context<CommitPointsRoot>, points_root: [u8; 32]) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+
+    tournament.points_root = Some(points_root);
+
+    msg!("Points root committed for tournament {}", tournament.id);
+
+    AuditLog::record_call::<instruction::CommitPointsRoot>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.authority.key(),
+        ctx.accounts.tournament.id,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog, PlayerRegistration, Tournament, TournamentStatus};
+
+/// Accounts required for committing an agent's timeout and time-bank usage.
+#[derive(Accounts)]
+pub struct CommitTimeBank<'info> {
+    /// Arena admin, the tournament's delegated operator or its host
+    pub authority: Signer<'info>,
+
+    /// Arena config for admin verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament - must be InProgress
+    #[account(
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.can_run(&authority.key(), &arena_config.admin) @ ArenaError::Unauthorized,
+        constraint = tournament.status == TournamentStatus::InProgress @ ArenaError::TournamentNotInProgress
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Agent's registration for this tournament
+    #[account(
+        mut,
+        seeds = [PlayerRegistration::SEED_PREFIX, tournament.key().as_ref(), registration.wallet.as_ref()],
+        bump = registration.bump
+    )]
+    pub registration: Account<'info, PlayerRegistration>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Commit an agent's decision timeouts and time-bank usage (admin, operator or host).
+///
+/// Called by the engine at checkpoints with running totals, which may never
+/// move backwards. Timeouts over the configured threshold cost the player
+/// the timeout penalty when their result is recorded.
+///
+/// # Arguments
+/// * `timeouts` - Decisions the agent has timed out on so far
+/// * `time_bank_used_secs` - Seconds of time bank the agent has used so far
+pub fn handler(
+    ctx: Context<CommitTimeBank>,
+    timeouts: u16,
+    time_bank_used_secs: u32,
+) -> Result<()> {
+    let registration = &mut ctx.accounts.registration;
+    require!(
+        timeouts >= registration.timeouts
+            && time_bank_used_secs >= registration.time_bank_used_secs,
+        ArenaError::CommitmentOutOfOrder
+    );
+
+    registration.timeouts = timeouts;
+    registration.time_bank_used_secs = time_bank_used_secs;
+
+    msg!(
+        "Player {}: {} timeouts, {}s of time bank used",
+        registration.wallet,
+        timeouts,
+        time_bank_used_secs
+    );
+
+    AuditLog::record_call::<instruction::CommitTimeBank>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.authority.key(),
+        ctx.accounts.tournament.id,
+    )?;
+
+    Ok(())
+}
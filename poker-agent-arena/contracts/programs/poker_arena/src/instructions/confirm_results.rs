@@ -0,0 +1,285 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::metadata::mpl_token_metadata::types::DataV2;
+use anchor_spl::metadata::{
+    self, CreateMasterEditionV3, CreateMetadataAccountsV3, Metadata as TokenMetadata,
+};
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
+
+use crate::errors::ArenaError;
+use crate::events::{ChampionTrophyMinted, TournamentFinalized};
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog, Host, Tournament, TournamentStatus, TrophyAuthority};
+
+/// Accounts required for confirming a tournament's results.
+///
+/// The trailing champion trophy accounts are optional; omit them to confirm
+/// without minting a trophy.
+#[derive(Accounts)]
+pub struct ConfirmResults<'info> {
+    /// Results authority, or the arena admin or tournament's operator if none is set
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Arena config for results authority verification
+    #[account(
+        mut,
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament to confirm - results must be submitted and undisputable
+    #[account(
+        mut,
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = arena_config.can_record_results(&authority.key(), &tournament) @ ArenaError::Unauthorized,
+        constraint = tournament.status == TournamentStatus::ResultsSubmitted @ ArenaError::TournamentNotInProgress
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Trophy authority PDA (mint and update authority of the trophy)
+    #[account(
+        seeds = [TrophyAuthority::SEED_PREFIX],
+        bump = trophy_authority.bump
+    )]
+    pub trophy_authority: Option<Account<'info, TrophyAuthority>>,
+
+    /// Champion trophy mint (one per tournament)
+    #[account(
+        init,
+        payer = authority,
+        mint::decimals = 0,
+        mint::authority = trophy_authority,
+        mint::freeze_authority = trophy_authority,
+        seeds = [TrophyAuthority::CHAMPION_MINT_SEED_PREFIX, tournament.key().as_ref()],
+        bump
+    )]
+    pub trophy_mint: Option<Account<'info, Mint>>,
+
+    /// Winner's wallet
+    /// CHECK: Must match the submitted winner
+    #[account(
+        constraint = winner_wallet.key() == tournament.winner.unwrap_or_default() @ ArenaError::Unauthorized
+    )]
+    pub winner_wallet: Option<UncheckedAccount<'info>>,
+
+    /// Winner's associated token account for the trophy
+    #[account(
+        init,
+        payer = authority,
+        associated_token::mint = trophy_mint,
+        associated_token::authority = winner_wallet
+    )]
+    pub winner_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Token Metadata account for the trophy mint
+    /// CHECK: Created and validated by the Token Metadata program
+    #[account(mut)]
+    pub trophy_metadata: Option<UncheckedAccount<'info>>,
+
+    /// Master edition account for the trophy mint
+    /// CHECK: Created and validated by the Token Metadata program
+    #[account(mut)]
+    pub trophy_master_edition: Option<UncheckedAccount<'info>>,
+
+    /// Metaplex Token Metadata program
+    pub token_metadata_program: Option<Program<'info, TokenMetadata>>,
+
+    /// Token program
+    pub token_program: Option<Program<'info, Token>>,
+
+    /// Associated token program
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+
+    /// System program for account creation
+    pub system_program: Option<Program<'info, System>>,
+
+    /// Rent sysvar
+    pub rent: Option<Sysvar<'info, Rent>>,
+
+    /// Tournament's host, required if the tournament is hosted
+    #[account(
+        mut,
+        seeds = [Host::SEED_PREFIX, host.authority.as_ref()],
+        bump = host.bump
+    )]
+    pub host: Option<Account<'info, Host>>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+impl<'info> ConfirmResults<'info> {
+    /// Mint a 1/1 champion trophy NFT to the winner, if trophy accounts were supplied
+    fn mint_champion_trophy(&self, winner: Pubkey) -> Result<()> {
+        let (
+            Some(trophy_authority),
+            Some(trophy_mint),
+            Some(winner_token_account),
+            Some(trophy_metadata),
+            Some(trophy_master_edition),
+            Some(token_metadata_program),
+            Some(token_program),
+            Some(system_program),
+            Some(rent),
+        ) = (
+            self.trophy_authority.as_ref(),
+            self.trophy_mint.as_ref(),
+            self.winner_token_account.as_ref(),
+            self.trophy_metadata.as_ref(),
+            self.trophy_master_edition.as_ref(),
+            self.token_metadata_program.as_ref(),
+            self.token_program.as_ref(),
+            self.system_program.as_ref(),
+            self.rent.as_ref(),
+        )
+        else {
+            return err!(ArenaError::TrophyAccountsMissing);
+        };
+
+        let tournament = &self.tournament;
+        let completed_at = tournament.completed_at.unwrap_or_default();
+        let field_size = tournament.registered_players;
+
+        let seeds = &[TrophyAuthority::SEED_PREFIX, &[trophy_authority.bump]];
+        let signer_seeds = &[&seeds[..]];
+
+        metadata::create_metadata_accounts_v3(
+            CpiContext::new_with_signer(
+                token_metadata_program.to_account_info(),
+                CreateMetadataAccountsV3 {
+                    metadata: trophy_metadata.to_account_info(),
+                    mint: trophy_mint.to_account_info(),
+                    mint_authority: trophy_authority.to_account_info(),
+                    payer: self.authority.to_account_info(),
+                    update_authority: trophy_authority.to_account_info(),
+                    system_program: system_program.to_account_info(),
+                    rent: rent.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            DataV2 {
+                name: format!("Arena #{} Champion", tournament.id),
+                symbol: TrophyAuthority::SYMBOL.to_string(),
+                uri: trophy_authority.champion_uri(tournament.id, completed_at, field_size),
+                seller_fee_basis_points: 0,
+                creators: None,
+                collection: None,
+                uses: None,
+            },
+            false,
+            true,
+            None,
+        )?;
+
+        token::mint_to(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                MintTo {
+                    mint: trophy_mint.to_account_info(),
+                    to: winner_token_account.to_account_info(),
+                    authority: trophy_authority.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            1,
+        )?;
+
+        // Master edition with zero prints makes the trophy a 1/1
+        metadata::create_master_edition_v3(
+            CpiContext::new_with_signer(
+                token_metadata_program.to_account_info(),
+                CreateMasterEditionV3 {
+                    edition: trophy_master_edition.to_account_info(),
+                    mint: trophy_mint.to_account_info(),
+                    update_authority: trophy_authority.to_account_info(),
+                    mint_authority: trophy_authority.to_account_info(),
+                    payer: self.authority.to_account_info(),
+                    metadata: trophy_metadata.to_account_info(),
+                    token_program: token_program.to_account_info(),
+                    system_program: system_program.to_account_info(),
+                    rent: rent.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            Some(0),
+        )?;
+
+        emit!(ChampionTrophyMinted {
+            tournament: tournament.key(),
+            winner,
+            mint: trophy_mint.key(),
+            field_size,
+            completed_at,
+        });
+
+        msg!("Champion trophy minted: {}", trophy_mint.key());
+
+        Ok(())
+    }
+}
+
+/// Confirm a tournament's submitted results (results authority, or admin or
+/// operator if unset).
+///
+/// This instruction:
+/// 1. Requires the dispute window to have closed undisputed, or the dispute
+///    to have been resolved by the arbiters
+/// 2. Updates status to Completed with timestamp, unlocking results
+///    recording and POINTS distribution
+/// 3. Emits TournamentFinalized with the final state
+/// 4. Mints a 1/1 champion trophy NFT to the winner if trophy accounts are supplied
+pub fn handler(ctx: Context<ConfirmResults>) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+    let clock = Clock::get()?;
+
+    require!(
+        tournament.points_releasable(clock.unix_timestamp),
+        ArenaError::DisputeWindowOpen
+    );
+
+    tournament.status = TournamentStatus::Completed;
+    match Host::for_tournament(ctx.accounts.host.as_mut(), tournament)? {
+        Some(host) => host.record_completed(tournament.disputed),
+        None => ctx.accounts.arena_config.record_tournament_closed(),
+    }
+    tournament.completed_at = Some(clock.unix_timestamp);
+    let winner = tournament.winner.unwrap_or_default();
+
+    emit!(TournamentFinalized {
+        tournament: tournament.key(),
+        id: tournament.id,
+        winner,
+        results_hash: tournament.results_hash.unwrap_or_default(),
+        format: tournament.format,
+        registered_players: tournament.registered_players,
+        fees_collected_lamports: tournament.fees_collected_lamports,
+        disputed: tournament.disputed,
+        dispute_window_ends_at: tournament.dispute_window_ends_at,
+        finalized_by: ctx.accounts.authority.key(),
+        completed_at: clock.unix_timestamp,
+    });
+
+    msg!("Tournament {} finalized", tournament.id);
+    msg!("Winner: {}", winner);
+    msg!("Completed at: {}", clock.unix_timestamp);
+
+    if ctx.accounts.trophy_mint.is_some() {
+        ctx.accounts.mint_champion_trophy(winner)?;
+    }
+
+    AuditLog::record_call::<instruction::ConfirmResults>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.authority.key(),
+        ctx.accounts.tournament.id,
+    )?;
+
+    Ok(())
+}
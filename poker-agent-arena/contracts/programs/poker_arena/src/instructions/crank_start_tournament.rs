@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::events::CrankBountyPaid;
+use crate::instructions::start_tournament::begin_tournament;
+use crate::state::{ArenaConfig, CrankVault, LedgerCategory, LedgerDirection, Tournament, TreasuryLedger};
+
+/// Accounts required for the permissionless tournament start crank.
+#[derive(Accounts)]
+pub struct CrankStartTournament<'info> {
+    /// Any wallet running the crank (receives the bounty)
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    /// Arena config for the bounty amount
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament to start - must be startable and past its scheduled start
+    #[account(
+        mut,
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.can_start() @ ArenaError::CrankNotReady
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Crank vault paying the bounty
+    #[account(
+        mut,
+        seeds = [CrankVault::SEED_PREFIX],
+        bump = crank_vault.bump
+    )]
+    pub crank_vault: Account<'info, CrankVault>,
+
+    /// Recent slot hashes sysvar for provably fair RNG seed
+    /// CHECK: This is the SlotHashes sysvar, validated by address
+    #[account(address = anchor_lang::solana_program::sysvar::slot_hashes::id())]
+    pub recent_slothashes: UncheckedAccount<'info>,
+
+    /// Treasury ledger recording the movement (omit to skip)
+    #[account(
+        mut,
+        seeds = [TreasuryLedger::SEED_PREFIX],
+        bump = treasury_ledger.bump
+    )]
+    pub treasury_ledger: Option<Box<Account<'info, TreasuryLedger>>>,
+}
+
+/// Start a tournament whose scheduled start time has passed (permissionless).
+///
+/// This instruction:
+/// 1. Validates the tournament can start and `starts_at` has passed
+/// 2. Captures the RNG seed and moves the tournament to InProgress
+/// 3. Pays the caller the configured crank bounty
+pub fn handler(ctx: Context<CrankStartTournament>) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+    let clock = Clock::get()?;
+
+    require!(
+        clock.unix_timestamp >= tournament.starts_at,
+        ArenaError::CrankNotReady
+    );
+
+    begin_tournament(tournament, &ctx.accounts.recent_slothashes)?;
+
+    let paid = CrankVault::pay_bounty(
+        &mut ctx.accounts.crank_vault,
+        &ctx.accounts.caller.to_account_info(),
+        ctx.accounts.arena_config.crank_bounty_lamports,
+    )?;
+
+    if let Some(treasury_ledger) = ctx.accounts.treasury_ledger.as_mut() {
+        treasury_ledger.record(
+            LedgerCategory::CrankVault,
+            LedgerDirection::Outflow,
+            ctx.accounts.crank_vault.key(),
+            paid,
+            Clock::get()?.unix_timestamp,
+        );
+    }
+
+    emit!(CrankBountyPaid {
+        caller: ctx.accounts.caller.key(),
+        tournament: tournament.key(),
+        amount: paid,
+    });
+
+    msg!("Tournament {} started by crank", tournament.id);
+    msg!("Crank bounty paid: {} lamports", paid);
+
+    Ok(())
+}
@@ -0,0 +1,140 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{Airdrop, ArenaConfig, AuditLog, PointsMintAuthority};
+
+/// Accounts required for creating a claim-based airdrop.
+#[derive(Accounts)]
+#[instruction(airdrop_id: u64)]
+pub struct CreateAirdrop<'info> {
+    /// Admin wallet (must match arena config admin)
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// Arena config for admin verification and the points mint
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Airdrop PDA to be created
+    #[account(
+        init,
+        payer = admin,
+        space = Airdrop::SIZE,
+        seeds = [Airdrop::SEED_PREFIX, &airdrop_id.to_le_bytes()],
+        bump
+    )]
+    pub airdrop: Account<'info, Airdrop>,
+
+    /// Token account holding the allocation (owned by the airdrop PDA)
+    #[account(
+        init,
+        payer = admin,
+        token::mint = points_mint,
+        token::authority = airdrop,
+        seeds = [Airdrop::VAULT_SEED_PREFIX, airdrop.key().as_ref()],
+        bump
+    )]
+    pub airdrop_vault: Account<'info, TokenAccount>,
+
+    /// POINTS SPL token mint
+    #[account(
+        mut,
+        constraint = points_mint.key() == arena_config.points_mint @ ArenaError::InvalidTierPayment
+    )]
+    pub points_mint: Account<'info, Mint>,
+
+    /// PDA that holds mint authority
+    #[account(
+        seeds = [PointsMintAuthority::SEED_PREFIX],
+        bump = mint_authority.bump
+    )]
+    pub mint_authority: Account<'info, PointsMintAuthority>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Create a retroactive POINTS airdrop to past participants (admin only).
+///
+/// This instruction:
+/// 1. Records the Merkle root of eligible wallets and amounts
+/// 2. Mints the full allocation into the airdrop vault
+///
+/// # Arguments
+/// * `airdrop_id` - Unique airdrop ID
+/// * `root` - Root over leaves SHA-256(0x00 || airdrop || wallet || amount_le)
+/// * `total_allocation` - Sum of all allocations in the tree
+/// * `expires_at` - Unix timestamp after which unclaimed POINTS are burned
+pub fn handler(
+    ctx: Context<CreateAirdrop>,
+    airdrop_id: u64,
+    root: [u8; 32],
+    total_allocation: u64,
+    expires_at: i64,
+) -> Result<()> {
+    require!(
+        total_allocation > 0 && expires_at > Clock::get()?.unix_timestamp,
+        ArenaError::InvalidAirdrop
+    );
+
+    let airdrop = &mut ctx.accounts.airdrop;
+    airdrop.id = airdrop_id;
+    airdrop.root = root;
+    airdrop.total_allocation = total_allocation;
+    airdrop.claimed = 0;
+    airdrop.expires_at = expires_at;
+    airdrop.burned = 0;
+    airdrop.finalized = false;
+    airdrop.bump = ctx.bumps.airdrop;
+
+    let seeds = &[
+        PointsMintAuthority::SEED_PREFIX,
+        &[ctx.accounts.mint_authority.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    token::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.points_mint.to_account_info(),
+                to: ctx.accounts.airdrop_vault.to_account_info(),
+                authority: ctx.accounts.mint_authority.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        total_allocation,
+    )?;
+
+    msg!(
+        "Airdrop {} created: {} POINTS until {}",
+        airdrop_id,
+        total_allocation,
+        expires_at
+    );
+
+    AuditLog::record_call::<instruction::CreateAirdrop>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        0,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{
+    ArenaConfig, AuditLog, FeeEscrow, PrizeDenomination, Tournament, TournamentStatus,
+};
+
+/// Accounts required for escrowing a tournament's tier fees.
+#[derive(Accounts)]
+pub struct CreateFeeEscrow<'info> {
+    /// Admin, tournament operator or host; pays the escrow's rent
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Arena config for operator verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament - must still be Created, so no fee has been paid yet
+    #[account(
+        mut,
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.can_run(&authority.key(), &arena_config.admin) @ ArenaError::Unauthorized,
+        constraint = tournament.status == TournamentStatus::Created @ ArenaError::TournamentAlreadyStarted
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Fee escrow PDA to be created
+    #[account(
+        init,
+        payer = authority,
+        space = FeeEscrow::SIZE,
+        seeds = [FeeEscrow::SEED_PREFIX, tournament.key().as_ref()],
+        bump
+    )]
+    pub fee_escrow: Account<'info, FeeEscrow>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Hold the tournament's tier fees in escrow until it starts (admin, operator or host).
+///
+/// This instruction:
+/// 1. Creates the tournament's FeeEscrow
+/// 2. Marks the tournament so every registration pays its tier fee into the
+///    escrow instead of the treasury and fee recipients
+///
+/// Fees are released by `release_fee_escrow` once the tournament starts, or
+/// refunded by `refund_escrowed_fee` if it is cancelled or voided first.
+///
+/// # Arguments
+/// * `to_prize_pool` - Release the fees into the tournament's SOL prize pool
+///   instead of the treasury
+pub fn handler(ctx: Context<CreateFeeEscrow>, to_prize_pool: bool) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+    require!(
+        !to_prize_pool || tournament.prize_denomination == PrizeDenomination::Sol,
+        ArenaError::InvalidPrizePool
+    );
+
+    let fee_escrow = &mut ctx.accounts.fee_escrow;
+    fee_escrow.tournament = tournament.key();
+    fee_escrow.to_prize_pool = to_prize_pool;
+    fee_escrow.released = false;
+    fee_escrow.bump = ctx.bumps.fee_escrow;
+    fee_escrow.host_slashed = false;
+    fee_escrow.slashed_lamports = 0;
+    tournament.fees_escrowed = true;
+
+    msg!(
+        "Tier fees for tournament {} will be escrowed",
+        tournament.id
+    );
+
+    AuditLog::record_call::<instruction::CreateFeeEscrow>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.authority.key(),
+        ctx.accounts.tournament.id,
+    )?;
+
+    Ok(())
+}
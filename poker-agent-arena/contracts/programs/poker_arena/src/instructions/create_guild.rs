@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Guild, GuildMember, GuildStats, GuildVault};
+
+/// Accounts required for founding a guild.
+#[derive(Accounts)]
+#[instruction(name: [u8; 32])]
+pub struct CreateGuild<'info> {
+    /// Founding wallet; becomes leader and first member
+    #[account(mut)]
+    pub leader: Signer<'info>,
+
+    /// Guild PDA to be created
+    #[account(
+        init,
+        payer = leader,
+        space = Guild::SIZE,
+        seeds = [Guild::SEED_PREFIX, name.as_ref()],
+        bump
+    )]
+    pub guild: Account<'info, Guild>,
+
+    /// Guild vault PDA to be created
+    #[account(
+        init,
+        payer = leader,
+        space = GuildVault::SIZE,
+        seeds = [GuildVault::SEED_PREFIX, guild.key().as_ref()],
+        bump
+    )]
+    pub guild_vault: Account<'info, GuildVault>,
+
+    /// Leader's membership PDA to be created
+    #[account(
+        init,
+        payer = leader,
+        space = GuildMember::SIZE,
+        seeds = [GuildMember::SEED_PREFIX, leader.key().as_ref()],
+        bump
+    )]
+    pub guild_member: Account<'info, GuildMember>,
+
+    /// Guild stats PDA to be created
+    #[account(
+        init,
+        payer = leader,
+        space = GuildStats::SIZE,
+        seeds = [GuildStats::SEED_PREFIX, guild.key().as_ref()],
+        bump
+    )]
+    pub guild_stats: Account<'info, GuildStats>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Found a guild with the signer as leader.
+///
+/// # Arguments
+/// * `name` - Guild name (32 bytes, UTF-8, null-padded)
+/// * `winnings_share_bps` - Share of members' POINTS routed to the vault
+/// * `sponsor_tier_fees` - Whether the vault pays members' tier fees
+pub fn handler(
+    ctx: Context<CreateGuild>,
+    name: [u8; 32],
+    winnings_share_bps: u16,
+    sponsor_tier_fees: bool,
+) -> Result<()> {
+    Guild::validate(&name, winnings_share_bps)?;
+    let now = Clock::get()?.unix_timestamp;
+    let leader = ctx.accounts.leader.key();
+
+    let guild = &mut ctx.accounts.guild;
+    guild.name = name;
+    guild.leader = leader;
+    guild.officers = [Pubkey::default(); Guild::MAX_OFFICERS];
+    guild.member_count = 1;
+    guild.winnings_share_bps = winnings_share_bps;
+    guild.sponsor_tier_fees = sponsor_tier_fees;
+    guild.vault = ctx.accounts.guild_vault.key();
+    guild.created_at = now;
+    guild.bump = ctx.bumps.guild;
+
+    let guild_vault = &mut ctx.accounts.guild_vault;
+    guild_vault.guild = guild.key();
+    guild_vault.total_deposited = 0;
+    guild_vault.total_fees_paid = 0;
+    guild_vault.bump = ctx.bumps.guild_vault;
+
+    let guild_member = &mut ctx.accounts.guild_member;
+    guild_member.guild = guild.key();
+    guild_member.wallet = leader;
+    guild_member.joined_at = now;
+    guild_member.bump = ctx.bumps.guild_member;
+
+    let guild_stats = &mut ctx.accounts.guild_stats;
+    guild_stats.guild = guild.key();
+    guild_stats.member_results = 0;
+    guild_stats.total_points = 0;
+    guild_stats.member_wins = 0;
+    guild_stats.best_finish = 0;
+    guild_stats.guild_event_wins = 0;
+    guild_stats.last_updated = now;
+    guild_stats.bump = ctx.bumps.guild_stats;
+
+    msg!("Guild {} founded by {}", guild.key(), leader);
+
+    Ok(())
+}
@@ -0,0 +1,152 @@
+use anchor_lang::prelude::*;
+
+use crate::blinds::BlindPreset;
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::instructions::create_tournament::init_tournament;
+use crate::state::{ArenaConfig, AuditLog, FeeEscrow, Host, PrizeDenomination, Tournament};
+
+/// Accounts required for a host creating its own tournament.
+#[derive(Accounts)]
+pub struct CreateHostTournament<'info> {
+    /// Host's signing key; pays rent and operates the tournament
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Host entry - must be active and hold the required bond
+    #[account(
+        mut,
+        seeds = [Host::SEED_PREFIX, authority.key().as_ref()],
+        bump = host.bump,
+        constraint = host.active @ ArenaError::Unauthorized,
+        constraint = host.bond_lamports >= arena_config.host_bond_lamports @ ArenaError::HostBondTooLow
+    )]
+    pub host: Account<'info, Host>,
+
+    /// Arena config for the required bond, creation limits and blind presets
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament PDA to be created, seeded by the host and its next sequence number
+    #[account(
+        init,
+        payer = authority,
+        space = Tournament::SIZE,
+        seeds = [
+            Tournament::SEED_PREFIX,
+            host.key().as_ref(),
+            host.next_tournament_id()?.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Fee escrow PDA holding the tournament's tier fees until it starts
+    #[account(
+        init,
+        payer = authority,
+        space = FeeEscrow::SIZE,
+        seeds = [FeeEscrow::SEED_PREFIX, tournament.key().as_ref()],
+        bump
+    )]
+    pub fee_escrow: Account<'info, FeeEscrow>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Create a tournament run by an approved, bonded host (host only).
+///
+/// This instruction:
+/// 1. Creates the tournament like `create_tournament`, with the host's key
+///    as its admin and the host's next sequence number as its ID
+/// 2. Creates its FeeEscrow, so tier fees wait until it starts and are then
+///    split between the arena's platform fee and the host's treasury
+/// 3. Counts it against the arena's creation limits, applied per host
+///
+/// The host runs the tournament and records its results, but is not its
+/// operator: settling POINTS awards and funding POINTS prize pools stay with
+/// the arena admin.
+///
+/// Hosted tournaments don't touch the arena's tournament counter or its
+/// creation counts, so one host's volume can't block another's.
+///
+/// # Arguments
+/// * `max_players` - Maximum number of players allowed (at most 1024)
+/// * `starting_stack` - Starting chip stack for each player
+/// * `starts_at` - Unix timestamp when tournament is scheduled to start
+/// * `blind_structure_hash` - SHA-256 hash of the blind structure JSON (zeros for presets)
+/// * `payout_structure_hash` - SHA-256 hash of the host's payout table
+/// * `reserved_seats` - Seats held back for `register_reserved`
+/// * `blind_preset` - Built-in blind structure, or Custom to use `blind_structure_hash`
+/// * `prize_denomination` - Currency the prize pool is paid in (SOL, USDC or POINTS)
+#[allow(clippy::too_many_arguments)]
+pub fn handler(
+    ctx: Context<CreateHostTournament>,
+    max_players: u16,
+    starting_stack: u64,
+    starts_at: i64,
+    blind_structure_hash: [u8; 32],
+    payout_structure_hash: [u8; 32],
+    reserved_seats: u16,
+    blind_preset: BlindPreset,
+    prize_denomination: PrizeDenomination,
+) -> Result<()> {
+    require!(
+        ctx.accounts.arena_config.blind_preset_enabled(blind_preset),
+        ArenaError::InvalidBlindStructure
+    );
+    let now = Clock::get()?.unix_timestamp;
+    let authority = ctx.accounts.authority.key();
+    let tournament = &mut ctx.accounts.tournament;
+    init_tournament(
+        tournament,
+        ctx.accounts.host.next_tournament_id()?,
+        authority,
+        now,
+        max_players,
+        starting_stack,
+        starts_at,
+        blind_structure_hash,
+        payout_structure_hash,
+        reserved_seats,
+        blind_preset,
+        prize_denomination,
+        ctx.bumps.tournament,
+    )?;
+    tournament.host = ctx.accounts.host.key();
+    tournament.fees_escrowed = true;
+
+    let fee_escrow = &mut ctx.accounts.fee_escrow;
+    fee_escrow.tournament = tournament.key();
+    fee_escrow.to_prize_pool = false;
+    fee_escrow.released = false;
+    fee_escrow.bump = ctx.bumps.fee_escrow;
+    fee_escrow.host_slashed = false;
+    fee_escrow.slashed_lamports = 0;
+
+    ctx.accounts
+        .host
+        .record_tournament_created(now, &ctx.accounts.arena_config)?;
+
+    msg!("Tournament {} hosted by {}", tournament.id, authority);
+
+    AuditLog::record_call::<instruction::CreateHostTournament>(
+        &mut ctx.accounts.audit_log,
+        authority,
+        ctx.accounts.tournament.id,
+    )?;
+
+    Ok(())
+}
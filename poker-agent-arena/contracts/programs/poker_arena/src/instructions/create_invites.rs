@@ -0,0 +1,125 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, CreateAccount};
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog, Invite, Tournament, TournamentStatus};
+
+/// Accounts required for creating invites to a private tournament.
+///
+/// The invite PDAs to create are passed as remaining accounts, in the
+/// order of `code_hashes`.
+#[derive(Accounts)]
+pub struct CreateInvites<'info> {
+    /// Arena admin, the tournament's delegated operator or its host; pays the invites' rent
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Arena config for admin verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament to invite to - must not have started
+    #[account(
+        mut,
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.can_run(&authority.key(), &arena_config.admin) @ ArenaError::Unauthorized,
+        constraint = matches!(
+            tournament.status,
+            TournamentStatus::Created | TournamentStatus::Registration
+        ) @ ArenaError::TournamentAlreadyStarted
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Create single-use invites to a tournament in bulk and make it invite-only
+/// (admin, operator or host).
+///
+/// This instruction:
+/// 1. Creates an Invite PDA for each code hash, seeded by the tournament and
+///    the hash, from the matching remaining account
+/// 2. Marks the tournament invite-only, so every registration must pass an
+///    invite redeemed by the registering wallet
+///
+/// # Arguments
+/// * `code_hashes` - SHA-256 hashes of the invite codes (at most
+///   Invite::MAX_PER_CALL per call)
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CreateInvites<'info>>,
+    code_hashes: Vec<[u8; 32]>,
+) -> Result<()> {
+    require!(
+        !code_hashes.is_empty()
+            && code_hashes.len() <= Invite::MAX_PER_CALL
+            && code_hashes.len() == ctx.remaining_accounts.len(),
+        ArenaError::InvalidInvite
+    );
+
+    let tournament_key = ctx.accounts.tournament.key();
+    let rent_lamports = Rent::get()?.minimum_balance(Invite::SIZE);
+    for (code_hash, invite_info) in code_hashes.iter().zip(ctx.remaining_accounts) {
+        let (invite_key, bump) = Pubkey::find_program_address(
+            &[Invite::SEED_PREFIX, tournament_key.as_ref(), code_hash],
+            ctx.program_id,
+        );
+        require_keys_eq!(invite_info.key(), invite_key, ArenaError::InvalidInvite);
+
+        let seeds = &[
+            Invite::SEED_PREFIX,
+            tournament_key.as_ref(),
+            code_hash.as_ref(),
+            &[bump],
+        ];
+        system_program::create_account(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                CreateAccount {
+                    from: ctx.accounts.authority.to_account_info(),
+                    to: invite_info.clone(),
+                },
+                &[&seeds[..]],
+            ),
+            rent_lamports,
+            Invite::SIZE as u64,
+            ctx.program_id,
+        )?;
+
+        let invite = Invite {
+            tournament: tournament_key,
+            code_hash: *code_hash,
+            redeemed_by: Pubkey::default(),
+            bump,
+        };
+        invite.try_serialize(&mut &mut invite_info.try_borrow_mut_data()?[..])?;
+    }
+    ctx.accounts.tournament.invite_only = true;
+
+    msg!(
+        "Created {} invites for tournament {}",
+        code_hashes.len(),
+        ctx.accounts.tournament.id
+    );
+
+    AuditLog::record_call::<instruction::CreateInvites>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.authority.key(),
+        ctx.accounts.tournament.id,
+    )?;
+
+    Ok(())
+}
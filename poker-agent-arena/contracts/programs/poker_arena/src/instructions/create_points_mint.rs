@@ -2,7 +2,8 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{Mint, Token};
 
 use crate::errors::ArenaError;
-use crate::state::{ArenaConfig, PointsMintAuthority};
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog, PointsMintAuthority};
 
 /// Accounts required for creating the POINTS token mint.
 #[derive(Accounts)]
@@ -24,7 +25,7 @@ pub struct CreatePointsMint<'info> {
     #[account(
         init,
         payer = admin,
-        mint::decimals = 9,
+        mint::decimals = ArenaConfig::POINTS_DECIMALS,
         mint::authority = mint_authority,
         mint::freeze_authority = mint_authority
     )]
@@ -48,20 +49,29 @@ pub struct CreatePointsMint<'info> {
 
     /// Rent sysvar
     pub rent: Sysvar<'info, Rent>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
 }
 
 /// Create the POINTS SPL token mint (admin only).
 ///
 /// This instruction:
-/// 1. Creates a new SPL token mint with 9 decimals
+/// 1. Creates a new SPL token mint with `ArenaConfig::POINTS_DECIMALS` decimals
 /// 2. Sets the mint authority to a program PDA
-/// 3. Stores the mint address in arena_config
+/// 3. Stores the mint address and decimals in arena_config
 pub fn handler(ctx: Context<CreatePointsMint>) -> Result<()> {
     let arena_config = &mut ctx.accounts.arena_config;
     let mint_authority = &mut ctx.accounts.mint_authority;
 
     // Store the mint address in arena config
     arena_config.points_mint = ctx.accounts.points_mint.key();
+    arena_config.points_decimals = ctx.accounts.points_mint.decimals;
 
     // Store the bump for future PDA derivation
     mint_authority.bump = ctx.bumps.mint_authority;
@@ -69,5 +79,11 @@ pub fn handler(ctx: Context<CreatePointsMint>) -> Result<()> {
     msg!("POINTS mint created: {}", ctx.accounts.points_mint.key());
     msg!("Mint authority PDA: {}", ctx.accounts.mint_authority.key());
 
+    AuditLog::record_call::<instruction::CreatePointsMint>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        0,
+    )?;
+
     Ok(())
 }
@@ -0,0 +1,118 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{
+    ArenaConfig, AuditLog, DustPolicy, PrizeDenomination, PrizePool, Tournament, TournamentStatus,
+};
+
+/// Accounts required for creating a tournament's prize pool.
+#[derive(Accounts)]
+pub struct CreatePrizePool<'info> {
+    /// Admin, tournament operator or host; pays the pool's rent
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Arena config for operator verification and the prize mints
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament - must not have started
+    #[account(
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.can_run(&authority.key(), &arena_config.admin) @ ArenaError::Unauthorized,
+        constraint = matches!(
+            tournament.status,
+            TournamentStatus::Created | TournamentStatus::Registration
+        ) @ ArenaError::TournamentAlreadyStarted
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Prize pool PDA to be created
+    #[account(
+        init,
+        payer = authority,
+        space = PrizePool::SIZE,
+        seeds = [PrizePool::SEED_PREFIX, tournament.key().as_ref()],
+        bump
+    )]
+    pub prize_pool: Account<'info, PrizePool>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Create an empty prize pool in the tournament's denomination (admin, operator or host).
+///
+/// USDC pools hold their funds in token accounts owned by the pool PDA,
+/// such as its associated token account.
+///
+/// # Arguments
+/// * `payout_bps` - Share of the pool paid to each finishing place, best
+///   first, in basis points (must total 10000)
+/// * `dust_policy` - Where the rounding remainder of the split goes
+pub fn handler(
+    ctx: Context<CreatePrizePool>,
+    payout_bps: [u16; PrizePool::MAX_PAID_PLACES],
+    dust_policy: DustPolicy,
+) -> Result<()> {
+    let total_bps: u32 = payout_bps.iter().map(|bps| *bps as u32).sum();
+    require!(total_bps == 10_000, ArenaError::InvalidPayoutStructure);
+    // Dust owed to an unpaid first place could never be claimed
+    require!(
+        dust_policy != DustPolicy::FirstPlace || payout_bps[0] > 0,
+        ArenaError::InvalidPayoutStructure
+    );
+
+    let arena_config = &ctx.accounts.arena_config;
+    let denomination = ctx.accounts.tournament.prize_denomination;
+    let mint = match denomination {
+        PrizeDenomination::Points => ctx.accounts.tournament.points_mint(arena_config),
+        PrizeDenomination::Sol => Pubkey::default(),
+        PrizeDenomination::Usdc => {
+            require!(
+                arena_config.usdc_mint != Pubkey::default(),
+                ArenaError::InvalidPrizePool
+            );
+            arena_config.usdc_mint
+        }
+    };
+
+    let prize_pool = &mut ctx.accounts.prize_pool;
+    prize_pool.tournament = ctx.accounts.tournament.key();
+    prize_pool.denomination = denomination;
+    prize_pool.mint = mint;
+    prize_pool.total = 0;
+    prize_pool.paid_out = 0;
+    prize_pool.payout_bps = payout_bps;
+    prize_pool.claimed_mask = 0;
+    prize_pool.dust_policy = dust_policy;
+    prize_pool.dust_swept = false;
+    prize_pool.bump = ctx.bumps.prize_pool;
+
+    msg!(
+        "Prize pool created for tournament {} in {:?}",
+        ctx.accounts.tournament.id,
+        denomination
+    );
+
+    AuditLog::record_call::<instruction::CreatePrizePool>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.authority.key(),
+        ctx.accounts.tournament.id,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::state::{Governance, Proposal, ProposalAction, VoteStake};
+
+/// Accounts required for creating a governance proposal.
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    /// Proposer - must have POINTS staked
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    /// Governance config PDA
+    #[account(
+        mut,
+        seeds = [Governance::SEED_PREFIX],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    /// Proposer's vote stake
+    #[account(
+        seeds = [VoteStake::SEED_PREFIX, proposer.key().as_ref()],
+        bump = vote_stake.bump,
+        constraint = vote_stake.amount > 0 @ ArenaError::NoVotingPower
+    )]
+    pub vote_stake: Account<'info, VoteStake>,
+
+    /// New proposal PDA
+    #[account(
+        init,
+        payer = proposer,
+        space = Proposal::SIZE,
+        seeds = [Proposal::SEED_PREFIX, &governance.proposal_count.to_le_bytes()],
+        bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Create a proposal to change an arena parameter.
+///
+/// This instruction:
+/// 1. Rejects parameter values no arena could accept
+/// 2. Snapshots the creation time for stake weighting
+/// 3. Opens voting for VOTING_PERIOD_SECONDS
+/// 4. Sets execution to unlock TIMELOCK_SECONDS after voting ends
+///
+/// # Arguments
+/// * `action` - Parameter change to apply if the proposal passes
+pub fn handler(ctx: Context<CreateProposal>, action: ProposalAction) -> Result<()> {
+    action.validate()?;
+
+    let governance = &mut ctx.accounts.governance;
+    let proposal = &mut ctx.accounts.proposal;
+    let now = Clock::get()?.unix_timestamp;
+
+    proposal.id = governance.proposal_count;
+    proposal.proposer = ctx.accounts.proposer.key();
+    proposal.action = action;
+    proposal.created_at = now;
+    proposal.voting_ends_at = now + Governance::VOTING_PERIOD_SECONDS;
+    proposal.executable_at = proposal.voting_ends_at + Governance::TIMELOCK_SECONDS;
+    proposal.votes_for = 0;
+    proposal.votes_against = 0;
+    proposal.executed = false;
+    proposal.bump = ctx.bumps.proposal;
+
+    governance.proposal_count = governance
+        .proposal_count
+        .checked_add(1)
+        .ok_or(ArenaError::ArithmeticOverflow)?;
+
+    msg!("Proposal {} created: {:?}", proposal.id, action);
+
+    Ok(())
+}
@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog, Season};
+
+/// Accounts required for creating a season.
+#[derive(Accounts)]
+#[instruction(season_id: u32)]
+pub struct CreateSeason<'info> {
+    /// Admin wallet - must match arena_config.admin
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// Arena config for admin verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Season PDA to be created
+    #[account(
+        init,
+        payer = admin,
+        space = Season::SIZE,
+        seeds = [Season::SEED_PREFIX, &season_id.to_le_bytes()],
+        bump
+    )]
+    pub season: Account<'info, Season>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Create a season and put its pass on sale (admin only).
+///
+/// # Arguments
+/// * `season_id` - Season number
+/// * `starts_at` - First tournament start time covered by the season
+/// * `ends_at` - Tournaments starting at or after this time are not covered
+/// * `pass_price_lamports` - Season pass price paid to the treasury
+/// * `points_multiplier_bps` - Points multiplier for pass holders (10000 = 1x)
+pub fn handler(
+    ctx: Context<CreateSeason>,
+    season_id: u32,
+    starts_at: i64,
+    ends_at: i64,
+    pass_price_lamports: u64,
+    points_multiplier_bps: u16,
+) -> Result<()> {
+    require!(ends_at > starts_at, ArenaError::InvalidSeason);
+    require!(points_multiplier_bps >= 10_000, ArenaError::InvalidSeason);
+
+    let season = &mut ctx.accounts.season;
+    season.id = season_id;
+    season.starts_at = starts_at;
+    season.ends_at = ends_at;
+    season.pass_price_lamports = pass_price_lamports;
+    season.points_multiplier_bps = points_multiplier_bps;
+    season.passes_sold = 0;
+    season.revenue_share_bps = 0;
+    season.bump = ctx.bumps.season;
+
+    msg!(
+        "Season {} created: {} to {}, pass {} lamports, {} bps points",
+        season_id,
+        starts_at,
+        ends_at,
+        pass_price_lamports,
+        points_multiplier_bps
+    );
+
+    AuditLog::record_call::<instruction::CreateSeason>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        0,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token};
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog, PointsMintAuthority, Season};
+
+/// Accounts required for creating a season's POINTS mint.
+#[derive(Accounts)]
+pub struct CreateSeasonMint<'info> {
+    /// Admin wallet - must match arena_config.admin
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// Arena config for admin verification and POINTS decimals
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Season the mint belongs to
+    #[account(
+        seeds = [Season::SEED_PREFIX, &season.id.to_le_bytes()],
+        bump = season.bump
+    )]
+    pub season: Account<'info, Season>,
+
+    /// Season POINTS mint PDA to be created
+    #[account(
+        init,
+        payer = admin,
+        seeds = [Season::MINT_SEED_PREFIX, season.key().as_ref()],
+        bump,
+        mint::decimals = arena_config.points_decimals,
+        mint::authority = mint_authority,
+        mint::freeze_authority = mint_authority
+    )]
+    pub season_mint: Account<'info, Mint>,
+
+    /// PDA that holds mint authority for every POINTS mint
+    #[account(
+        seeds = [PointsMintAuthority::SEED_PREFIX],
+        bump = mint_authority.bump
+    )]
+    pub mint_authority: Account<'info, PointsMintAuthority>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+
+    /// Rent sysvar
+    pub rent: Sysvar<'info, Rent>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Create a season-scoped POINTS mint, e.g. S1-POINTS (admin only).
+///
+/// This instruction:
+/// 1. Creates an SPL token mint at a PDA derived from the season, with the
+///    arena's POINTS decimals
+/// 2. Gives mint and freeze authority to the shared mint authority PDA
+///
+/// Tournaments opt in with `set_season_mint`; their awards are then minted
+/// from the season's mint instead of the arena's POINTS mint.
+pub fn handler(ctx: Context<CreateSeasonMint>) -> Result<()> {
+    msg!(
+        "Season {} POINTS mint created: {}",
+        ctx.accounts.season.id,
+        ctx.accounts.season_mint.key()
+    );
+
+    AuditLog::record_call::<instruction::CreateSeasonMint>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        0,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::errors::ArenaError;
+use crate::state::SessionAuthority;
+
+/// Accounts required for authorizing a session key.
+#[derive(Accounts)]
+#[instruction(session_key: Pubkey)]
+pub struct CreateSession<'info> {
+    /// Wallet authorizing the session
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    /// Session authority PDA to be created
+    #[account(
+        init,
+        payer = wallet,
+        space = SessionAuthority::SIZE,
+        seeds = [SessionAuthority::SEED_PREFIX, wallet.key().as_ref(), session_key.as_ref()],
+        bump
+    )]
+    pub session_authority: Account<'info, SessionAuthority>,
+
+    /// Session key account, funded to pay rent and tier fees
+    /// CHECK: Must match the `session_key` argument
+    #[account(
+        mut,
+        constraint = session_signer.key() == session_key @ ArenaError::InvalidSession
+    )]
+    pub session_signer: UncheckedAccount<'info>,
+
+    /// System program for account creation and transfers
+    pub system_program: Program<'info, System>,
+}
+
+/// Authorize a short-lived session key to act for the signing wallet.
+///
+/// # Arguments
+/// * `session_key` - Key the web app will sign with
+/// * `scope` - Bitmask of SessionAuthority::SCOPE_* actions
+/// * `expires_at` - Expiry, at most seven days from now
+/// * `fund_lamports` - Lamports moved to the session key for rent and fees
+pub fn handler(
+    ctx: Context<CreateSession>,
+    session_key: Pubkey,
+    scope: u8,
+    expires_at: i64,
+    fund_lamports: u64,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        scope != 0
+            && expires_at > now
+            && expires_at - now <= SessionAuthority::MAX_DURATION_SECONDS,
+        ArenaError::InvalidSession
+    );
+
+    if fund_lamports > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.wallet.to_account_info(),
+                    to: ctx.accounts.session_signer.to_account_info(),
+                },
+            ),
+            fund_lamports,
+        )?;
+    }
+
+    let session_authority = &mut ctx.accounts.session_authority;
+    session_authority.wallet = ctx.accounts.wallet.key();
+    session_authority.session_key = session_key;
+    session_authority.scope = scope;
+    session_authority.expires_at = expires_at;
+    session_authority.created_at = now;
+    session_authority.bump = ctx.bumps.session_authority;
+
+    msg!(
+        "Session {} authorized for {} until {}",
+        session_key,
+        session_authority.wallet,
+        expires_at
+    );
+
+    Ok(())
+}
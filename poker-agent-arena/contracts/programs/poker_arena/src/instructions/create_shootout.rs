@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{
+    ArenaConfig, AuditLog, PlayerRegistration, Shootout, Tournament, TournamentFormat,
+    TournamentStatus,
+};
+
+/// Accounts required for creating a shootout schedule.
+#[derive(Accounts)]
+pub struct CreateShootout<'info> {
+    /// Arena admin, the tournament's delegated operator or its host; pays rent
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Arena config for admin verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Started shootout tournament
+    #[account(
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.can_run(&authority.key(), &arena_config.admin) @ ArenaError::Unauthorized,
+        constraint = tournament.status == TournamentStatus::InProgress @ ArenaError::TournamentNotInProgress,
+        constraint = tournament.format == TournamentFormat::Shootout @ ArenaError::InvalidTournamentFormat
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Shootout schedule PDA to create
+    #[account(
+        init,
+        payer = authority,
+        space = Shootout::SIZE,
+        seeds = [Shootout::SEED_PREFIX, tournament.key().as_ref()],
+        bump
+    )]
+    pub shootout: Box<Account<'info, Shootout>>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Create the shootout schedule and its first round (admin, operator or host).
+///
+/// This instruction:
+/// 1. Rebuilds the roster from the registrations in remaining accounts
+/// 2. Requires the roster to be complete (matches registered_players)
+/// 3. Makes it the field of round 1, sized to as few tables as seat it
+///
+/// Round tables are then opened with `open_shootout_table`.
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, CreateShootout<'info>>) -> Result<()> {
+    let tournament = &ctx.accounts.tournament;
+    let tournament_key = tournament.key();
+
+    let mut roster = Vec::with_capacity(ctx.remaining_accounts.len());
+    for info in ctx.remaining_accounts {
+        let registration = Account::<PlayerRegistration>::try_from(info)?;
+        require!(
+            registration.tournament == tournament_key,
+            ArenaError::TournamentNotFound
+        );
+        if !registration.disqualified {
+            roster.push(registration.wallet);
+        }
+    }
+
+    roster.sort();
+    roster.dedup();
+    require!(
+        roster.len() == tournament.registered_players as usize
+            && (2..=Shootout::MAX_PLAYERS).contains(&roster.len()),
+        ArenaError::InvalidShootoutTable
+    );
+
+    let shootout = &mut ctx.accounts.shootout;
+    shootout.tournament = tournament_key;
+    shootout.first_table = 1;
+    shootout.start_round(1, roster);
+    shootout.champion = None;
+    shootout.bump = ctx.bumps.shootout;
+
+    msg!(
+        "Shootout created for tournament {}: {} players at {} tables",
+        tournament.id,
+        shootout.players.len(),
+        shootout.winners.len()
+    );
+
+    AuditLog::record_call::<instruction::CreateShootout>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.authority.key(),
+        ctx.accounts.tournament.id,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{
+    ArenaConfig, AuditLog, SwissEvent, Tournament, TournamentFormat, TournamentStatus,
+};
+
+/// Accounts required for creating a Swiss schedule.
+#[derive(Accounts)]
+pub struct CreateSwissEvent<'info> {
+    /// Arena admin, the tournament's delegated operator or its host; pays rent
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Arena config for admin verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Started Swiss tournament
+    #[account(
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.can_run(&authority.key(), &arena_config.admin) @ ArenaError::Unauthorized,
+        constraint = tournament.status == TournamentStatus::InProgress @ ArenaError::TournamentNotInProgress,
+        constraint = tournament.format == TournamentFormat::Swiss @ ArenaError::InvalidTournamentFormat
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Swiss schedule PDA to create
+    #[account(
+        init,
+        payer = authority,
+        space = SwissEvent::SIZE,
+        seeds = [SwissEvent::SEED_PREFIX, tournament.key().as_ref()],
+        bump
+    )]
+    pub swiss_event: Box<Account<'info, SwissEvent>>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Create a Swiss schedule of `rounds` rounds (admin, operator or host).
+///
+/// Each player then opens a standing with `init_swiss_standing` before the
+/// first round is paired.
+///
+/// # Arguments
+/// * `rounds` - Number of rounds every player plays
+pub fn handler(ctx: Context<CreateSwissEvent>, rounds: u8) -> Result<()> {
+    require!(
+        rounds > 0
+            && ctx.accounts.tournament.registered_players as usize <= SwissEvent::MAX_PLAYERS,
+        ArenaError::InvalidSwissRound
+    );
+
+    let swiss_event = &mut ctx.accounts.swiss_event;
+    swiss_event.tournament = ctx.accounts.tournament.key();
+    swiss_event.rounds = rounds;
+    swiss_event.round = 0;
+    swiss_event.pairings = Vec::new();
+    swiss_event.results_recorded = 0;
+    swiss_event.bump = ctx.bumps.swiss_event;
+
+    msg!(
+        "Swiss schedule of {} rounds created for tournament {}",
+        rounds,
+        ctx.accounts.tournament.id
+    );
+
+    AuditLog::record_call::<instruction::CreateSwissEvent>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.authority.key(),
+        ctx.accounts.tournament.id,
+    )?;
+
+    Ok(())
+}
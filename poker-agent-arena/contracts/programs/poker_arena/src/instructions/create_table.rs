@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog, Table, Tournament, TournamentStatus};
+
+/// Accounts required for creating a tournament table.
+#[derive(Accounts)]
+#[instruction(table_number: u16)]
+pub struct CreateTable<'info> {
+    /// Arena admin, the tournament's delegated operator or its host
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Arena config for admin verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament - must be InProgress (seed captured)
+    #[account(
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.can_run(&authority.key(), &arena_config.admin) @ ArenaError::Unauthorized,
+        constraint = tournament.status == TournamentStatus::InProgress @ ArenaError::TournamentNotInProgress
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Table PDA
+    #[account(
+        init,
+        payer = authority,
+        space = Table::SIZE,
+        seeds = [Table::SEED_PREFIX, tournament.key().as_ref(), &table_number.to_le_bytes()],
+        bump
+    )]
+    pub table: Account<'info, Table>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Create a table with its initial seating (admin, operator or host).
+///
+/// The initial seats come from the seat draw derived from the tournament's
+/// committed `seed_blockhash`; anyone can check them with `verify_seat_draw`.
+///
+/// # Arguments
+/// * `table_number` - Table number within the tournament (1-based)
+/// * `seats` - Player wallet in each seat (Pubkey::default() if empty)
+pub fn handler(
+    ctx: Context<CreateTable>,
+    table_number: u16,
+    seats: [Pubkey; Table::MAX_SEATS],
+) -> Result<()> {
+    Table::validate_seats(&seats)?;
+
+    let table = &mut ctx.accounts.table;
+    table.tournament = ctx.accounts.tournament.key();
+    table.table_number = table_number;
+    table.seats = seats;
+    table.hand_count = 0;
+    table.updated_at = Clock::get()?.unix_timestamp;
+    table.draw_verified = false;
+    table.round = 0;
+    table.winner = None;
+    table.bump = ctx.bumps.table;
+
+    msg!(
+        "Table {} created with {} players",
+        table_number,
+        table.player_count()
+    );
+
+    AuditLog::record_call::<instruction::CreateTable>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.authority.key(),
+        ctx.accounts.tournament.id,
+    )?;
+
+    Ok(())
+}
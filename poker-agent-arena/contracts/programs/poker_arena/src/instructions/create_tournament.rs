@@ -1,7 +1,11 @@
 use anchor_lang::prelude::*;
 
+use crate::blinds::{self, BlindPreset};
 use crate::errors::ArenaError;
-use crate::state::{ArenaConfig, Tournament, TournamentStatus};
+use crate::instruction;
+use crate::state::{
+    ArenaConfig, AuditLog, PrizeDenomination, Tournament, TournamentFormat, TournamentStatus,
+};
 
 /// Accounts required for creating a tournament.
 #[derive(Accounts)]
@@ -34,17 +38,29 @@ pub struct CreateTournament<'info> {
 
     /// System program for account creation
     pub system_program: Program<'info, System>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
 }
 
 /// Create a new tournament (admin only).
 ///
 /// # Arguments
 /// * `ctx` - The context containing all accounts
-/// * `max_players` - Maximum number of players allowed (typically 27 or 54)
+/// * `max_players` - Maximum number of players allowed (typically 27 or 54, at most 1024)
 /// * `starting_stack` - Starting chip stack for each player
 /// * `starts_at` - Unix timestamp when tournament is scheduled to start
-/// * `blind_structure_hash` - SHA-256 hash of the blind structure JSON
+/// * `blind_structure_hash` - SHA-256 hash of the blind structure JSON (zeros for presets)
 /// * `payout_structure_hash` - SHA-256 hash of the admin-customized payout table
+/// * `reserved_seats` - Seats held back for `register_reserved` (sponsor and exhibition agents)
+/// * `blind_preset` - Built-in blind structure, or Custom to use `blind_structure_hash`
+/// * `prize_denomination` - Currency the prize pool is paid in (SOL, USDC or POINTS)
+#[allow(clippy::too_many_arguments)]
 pub fn handler(
     ctx: Context<CreateTournament>,
     max_players: u16,
@@ -52,21 +68,85 @@ pub fn handler(
     starts_at: i64,
     blind_structure_hash: [u8; 32],
     payout_structure_hash: [u8; 32],
+    reserved_seats: u16,
+    blind_preset: BlindPreset,
+    prize_denomination: PrizeDenomination,
 ) -> Result<()> {
     let arena_config = &mut ctx.accounts.arena_config;
-    let tournament = &mut ctx.accounts.tournament;
+    require!(
+        arena_config.blind_preset_enabled(blind_preset),
+        ArenaError::InvalidBlindStructure
+    );
 
     // Increment tournament count
-    arena_config.tournament_count += 1;
+    arena_config.tournament_count = arena_config
+        .tournament_count
+        .checked_add(1)
+        .ok_or(ArenaError::ArithmeticOverflow)?;
 
     // Get current timestamp
     let clock = Clock::get()?;
+    arena_config.record_tournament_created(clock.unix_timestamp)?;
+
+    init_tournament(
+        &mut ctx.accounts.tournament,
+        arena_config.tournament_count,
+        ctx.accounts.admin.key(),
+        clock.unix_timestamp,
+        max_players,
+        starting_stack,
+        starts_at,
+        blind_structure_hash,
+        payout_structure_hash,
+        reserved_seats,
+        blind_preset,
+        prize_denomination,
+        ctx.bumps.tournament,
+    )?;
+
+    AuditLog::record_call::<instruction::CreateTournament>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        ctx.accounts.tournament.id,
+    )?;
+
+    Ok(())
+}
+
+/// Validate the tournament parameters and initialize a freshly created
+/// tournament account in Created status, run by the arena.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn init_tournament(
+    tournament: &mut Tournament,
+    id: u64,
+    creator: Pubkey,
+    now: i64,
+    max_players: u16,
+    starting_stack: u64,
+    starts_at: i64,
+    blind_structure_hash: [u8; 32],
+    payout_structure_hash: [u8; 32],
+    reserved_seats: u16,
+    blind_preset: BlindPreset,
+    prize_denomination: PrizeDenomination,
+    bump: u8,
+) -> Result<()> {
+    Tournament::validate_max_players(max_players)?;
+    require!(reserved_seats <= max_players, ArenaError::InvalidReservedSeats);
+    // A preset's levels are fixed, so only a Custom structure carries a hash
+    if blind_preset != BlindPreset::Custom {
+        require!(
+            blind_structure_hash == [0; 32],
+            ArenaError::InvalidBlindStructure
+        );
+        blinds::validate_levels(blind_preset.levels())?;
+    }
 
     // Initialize tournament
-    tournament.id = arena_config.tournament_count;
-    tournament.admin = ctx.accounts.admin.key();
+    tournament.id = id;
+    tournament.admin = creator;
     tournament.status = TournamentStatus::Created;
-    tournament.created_at = clock.unix_timestamp;
+    tournament.created_at = now;
     tournament.starts_at = starts_at;
     tournament.completed_at = None;
     tournament.max_players = max_players;
@@ -78,7 +158,38 @@ pub fn handler(
     tournament.winner = None;
     tournament.seed_slot = 0;
     tournament.seed_blockhash = [0u8; 32];
-    tournament.bump = ctx.bumps.tournament;
+    tournament.operator = Pubkey::default();
+    tournament.points_root = None;
+    tournament.trophies_enabled = false;
+    tournament.fees_collected_lamports = 0;
+    tournament.fees_refunded_lamports = 0;
+    tournament.engine_version_hash = [0; 32];
+    tournament.model_id_hash = [0; 32];
+    tournament.game_log_head = [0; 32];
+    tournament.game_log_batches = 0;
+    tournament.disputed = false;
+    tournament.guild_event = false;
+    tournament.guild_prize_points = 0;
+    tournament.guild_standings_final = false;
+    tournament.tier_opens_at = [0; 3];
+    tournament.reserved_seats = reserved_seats;
+    tournament.reserved_registered = 0;
+    tournament.dispute_window_ends_at = 0;
+    tournament.dispute_resolved = false;
+    tournament.tier_counts = [0; 3];
+    tournament.hand_history_root = None;
+    tournament.division = None;
+    tournament.ladder_recorded = false;
+    tournament.format = TournamentFormat::RingGame;
+    tournament.blind_preset = blind_preset;
+    tournament.prize_denomination = prize_denomination;
+    tournament.late_registration_secs = 0;
+    tournament.points_formula = None;
+    tournament.fees_escrowed = false;
+    tournament.invite_only = false;
+    tournament.season_mint = None;
+    tournament.host = Pubkey::default();
+    tournament.bump = bump;
 
     msg!(
         "Tournament {} created with {} max players",
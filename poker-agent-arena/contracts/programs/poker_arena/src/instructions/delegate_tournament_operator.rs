@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::events::OperatorDelegated;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog, Tournament, TournamentStatus};
+
+/// Accounts required for delegating a tournament operator.
+#[derive(Accounts)]
+pub struct DelegateTournamentOperator<'info> {
+    /// Admin wallet - must match arena_config.admin
+    pub admin: Signer<'info>,
+
+    /// Arena config for admin verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament to delegate - must not be Completed, Cancelled or Voided
+    #[account(
+        mut,
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.status != TournamentStatus::Completed
+            && tournament.status != TournamentStatus::Cancelled
+            && tournament.status != TournamentStatus::Voided @ ArenaError::TournamentNotInProgress
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Delegate a key to operate a single tournament (admin only).
+///
+/// The operator may start, checkpoint, finalize and record results for this
+/// tournament only. Passing `Pubkey::default()` revokes the delegation.
+///
+/// # Arguments
+/// * `operator` - Key to authorize for this tournament
+pub fn handler(ctx: Context<DelegateTournamentOperator>, operator: Pubkey) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+
+    tournament.operator = operator;
+
+    emit!(OperatorDelegated {
+        tournament: tournament.key(),
+        operator,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Tournament {} operator set to: {}", tournament.id, operator);
+
+    AuditLog::record_call::<instruction::DelegateTournamentOperator>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        ctx.accounts.tournament.id,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,147 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::state::{
+    ArenaConfig, BackingPool, PlayerRegistration, PlayerStats, PlayerStatsV2, PointsEscrow,
+    Tournament, TournamentStatus,
+};
+
+/// Accounts required for discarding a result recorded before a tournament
+/// was voided.
+#[derive(Accounts)]
+pub struct DiscardVoidedResult<'info> {
+    /// Anyone may crank the discard once the tournament is voided
+    pub caller: Signer<'info>,
+
+    /// Arena config for the loyalty thresholds
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament - must be Voided
+    #[account(
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.status == TournamentStatus::Voided @ ArenaError::TournamentNotVoided
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Registration whose result is discarded - must have one recorded
+    #[account(
+        mut,
+        seeds = [PlayerRegistration::SEED_PREFIX, tournament.key().as_ref(), registration.wallet.as_ref()],
+        bump = registration.bump,
+        constraint = registration.tournament == tournament.key() @ ArenaError::TournamentNotFound,
+        constraint = registration.final_rank.is_some() @ ArenaError::NoResultRecorded
+    )]
+    pub registration: Account<'info, PlayerRegistration>,
+
+    /// Player's lifetime stats the result was recorded into
+    #[account(
+        mut,
+        seeds = [PlayerStats::SEED_PREFIX, registration.wallet.as_ref()],
+        bump = player_stats.bump
+    )]
+    pub player_stats: Account<'info, PlayerStats>,
+
+    /// Player's extended stats the result was recorded into
+    #[account(
+        mut,
+        seeds = [PlayerStatsV2::SEED_PREFIX, registration.wallet.as_ref()],
+        bump = player_stats_v2.bump
+    )]
+    pub player_stats_v2: Account<'info, PlayerStatsV2>,
+
+    /// Escrow holding POINTS distributed for the result (may be uninitialized)
+    /// CHECK: Address derived from seeds; only deserialized if it holds data
+    #[account(
+        mut,
+        seeds = [PointsEscrow::SEED_PREFIX, tournament.key().as_ref(), registration.wallet.as_ref()],
+        bump
+    )]
+    pub points_escrow: UncheckedAccount<'info>,
+
+    /// Player's backing pool PDA (may be uninitialized if the player was not backed)
+    /// CHECK: Address derived from seeds; only deserialized if it holds data
+    #[account(
+        mut,
+        seeds = [BackingPool::SEED_PREFIX, tournament.key().as_ref(), registration.wallet.as_ref()],
+        bump
+    )]
+    pub backing_pool: UncheckedAccount<'info>,
+}
+
+/// Discard a result recorded for a busted player before the tournament was
+/// voided (permissionless).
+///
+/// This instruction:
+/// 1. Clears the registration's rank, award, hands, eliminations and metrics
+/// 2. Takes the result back out of the player's PlayerStats and
+///    PlayerStatsV2, re-evaluating the loyalty level
+/// 3. Zeroes the POINTS escrowed for it, and the award backers and action
+///    buyers would have claimed a share of
+///
+/// Best finishes are left as they are, since the previous best is not kept.
+pub fn handler(ctx: Context<DiscardVoidedResult>) -> Result<()> {
+    let registration = &mut ctx.accounts.registration;
+    let final_rank = registration.final_rank.take().unwrap();
+    let points_awarded = registration.points_awarded.take().unwrap_or_default();
+    let hands_played = registration.hands_played.take().unwrap_or_default();
+    let eliminations = registration.eliminations.take().unwrap_or_default();
+    registration.metrics = None;
+    registration.points_distributed = false;
+
+    let player_stats = &mut ctx.accounts.player_stats;
+    player_stats.discard_result(final_rank, points_awarded, hands_played, eliminations);
+    let player_stats_v2 = &mut ctx.accounts.player_stats_v2;
+    player_stats_v2.discard_result(
+        registration.tier,
+        final_rank,
+        ctx.accounts.tournament.registered_players,
+        points_awarded,
+    );
+    player_stats_v2.loyalty_level = ctx
+        .accounts
+        .arena_config
+        .loyalty_level(player_stats.tournaments_played);
+
+    // Points for a busted player are escrowed until results are confirmed,
+    // which a voided tournament never reaches
+    let escrow_info = ctx.accounts.points_escrow.to_account_info();
+    if !escrow_info.data_is_empty() {
+        require_keys_eq!(
+            *escrow_info.owner,
+            crate::ID,
+            ErrorCode::AccountOwnedByWrongProgram
+        );
+        let mut data = escrow_info.try_borrow_mut_data()?;
+        let mut points_escrow = PointsEscrow::try_deserialize(&mut &data[..])?;
+        points_escrow.points = 0;
+        points_escrow.guild_points = 0;
+        points_escrow.try_serialize(&mut &mut data[..])?;
+    }
+
+    let backing_info = ctx.accounts.backing_pool.to_account_info();
+    if !backing_info.data_is_empty() {
+        require_keys_eq!(
+            *backing_info.owner,
+            crate::ID,
+            ErrorCode::AccountOwnedByWrongProgram
+        );
+        let mut data = backing_info.try_borrow_mut_data()?;
+        let mut backing_pool = BackingPool::try_deserialize(&mut &data[..])?;
+        backing_pool.points_awarded = 0;
+        backing_pool.try_serialize(&mut &mut data[..])?;
+    }
+
+    msg!(
+        "Discarded rank {} result of player {} in voided tournament {}",
+        final_rank,
+        registration.wallet,
+        ctx.accounts.tournament.id
+    );
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::events::TournamentDisputed;
+use crate::state::{PlayerRegistration, Tournament, TournamentStatus};
+
+/// Accounts required for disputing a tournament's results.
+#[derive(Accounts)]
+pub struct DisputeTournament<'info> {
+    /// Registered player raising the dispute
+    pub player: Signer<'info>,
+
+    /// Tournament - must be InProgress, or have results submitted within its
+    /// dispute window
+    #[account(
+        mut,
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Player's registration in this tournament - must still be seated and
+    /// not have disputed before
+    #[account(
+        mut,
+        seeds = [PlayerRegistration::SEED_PREFIX, tournament.key().as_ref(), player.key().as_ref()],
+        bump = registration.bump,
+        constraint = !registration.unregistered @ ArenaError::AlreadyForfeited,
+        constraint = !registration.disqualified @ ArenaError::PlayerDisqualified,
+        constraint = !registration.disputed @ ArenaError::AlreadyDisputed
+    )]
+    pub registration: Account<'info, PlayerRegistration>,
+}
+
+/// Dispute a tournament's results (registered players only, once each).
+///
+/// Once disputed, results can only be submitted with the attestation of the
+/// arbiter committee. Submitted results can still be disputed until the
+/// dispute window closes; they then cannot be confirmed until
+/// `resolve_dispute`.
+///
+/// # Arguments
+/// * `reason_hash` - SHA-256 hash of the off-chain dispute statement
+pub fn handler(ctx: Context<DisputeTournament>, reason_hash: [u8; 32]) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let tournament = &mut ctx.accounts.tournament;
+    match tournament.status {
+        TournamentStatus::InProgress => {}
+        TournamentStatus::ResultsSubmitted => {
+            require!(
+                now < tournament.dispute_window_ends_at,
+                ArenaError::DisputeWindowClosed
+            );
+            tournament.dispute_resolved = false;
+        }
+        _ => return err!(ArenaError::TournamentNotInProgress),
+    }
+    tournament.disputed = true;
+    ctx.accounts.registration.disputed = true;
+
+    emit!(TournamentDisputed {
+        tournament: tournament.key(),
+        wallet: ctx.accounts.player.key(),
+        reason_hash,
+        timestamp: now,
+    });
+
+    msg!(
+        "Tournament {} disputed by {}",
+        tournament.id,
+        ctx.accounts.player.key()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,223 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::errors::ArenaError;
+use crate::events::PlayerDisqualified;
+use crate::instruction;
+use crate::state::{
+    ArenaConfig, AuditLog, FeeEscrow, LedgerCategory, LedgerDirection, PlayerRegistration,
+    PointsEscrow, Tournament, TournamentStatus, TreasuryLedger,
+};
+
+/// Accounts required for disqualifying a player.
+#[derive(Accounts)]
+pub struct DisqualifyPlayer<'info> {
+    /// Arena admin, the tournament's delegated operator or its host
+    pub authority: Signer<'info>,
+
+    /// Arena config for admin verification and treasury address
+    #[account(
+        mut,
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament - must be in Registration or InProgress
+    #[account(
+        mut,
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.can_run(&authority.key(), &arena_config.admin) @ ArenaError::Unauthorized,
+        constraint = tournament.status == TournamentStatus::Registration
+            || tournament.status == TournamentStatus::InProgress @ ArenaError::TournamentNotInProgress
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Registration of the player being disqualified
+    #[account(
+        mut,
+        seeds = [PlayerRegistration::SEED_PREFIX, tournament.key().as_ref(), registration.wallet.as_ref()],
+        bump = registration.bump,
+        constraint = registration.tournament == tournament.key() @ ArenaError::TournamentNotFound,
+        constraint = !registration.unregistered @ ArenaError::AlreadyForfeited,
+        constraint = !registration.disqualified @ ArenaError::PlayerDisqualified
+    )]
+    pub registration: Account<'info, PlayerRegistration>,
+
+    /// Account that paid the tier fee, receiving any refund
+    /// CHECK: Verified against registration.fee_payer
+    #[account(
+        mut,
+        address = registration.fee_payer @ ArenaError::Unauthorized
+    )]
+    pub fee_payer: UncheckedAccount<'info>,
+
+    /// Treasury wallet (must sign when a refund is paid from it)
+    #[account(mut)]
+    pub treasury: Option<Signer<'info>>,
+
+    /// System program for the refund transfer
+    pub system_program: Program<'info, System>,
+
+    /// Tournament's fee escrow, required while its tier fees are escrowed
+    #[account(
+        mut,
+        seeds = [FeeEscrow::SEED_PREFIX, tournament.key().as_ref()],
+        bump = fee_escrow.bump
+    )]
+    pub fee_escrow: Option<Account<'info, FeeEscrow>>,
+
+    /// Treasury ledger recording a refund from the fee escrow (omit to skip)
+    #[account(
+        mut,
+        seeds = [TreasuryLedger::SEED_PREFIX],
+        bump = treasury_ledger.bump
+    )]
+    pub treasury_ledger: Option<Box<Account<'info, TreasuryLedger>>>,
+
+    /// Escrow holding POINTS distributed to the player after busting (may be
+    /// uninitialized)
+    /// CHECK: Address derived from seeds; only deserialized if it holds data
+    #[account(
+        mut,
+        seeds = [PointsEscrow::SEED_PREFIX, tournament.key().as_ref(), registration.wallet.as_ref()],
+        bump
+    )]
+    pub points_escrow: UncheckedAccount<'info>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Disqualify a player from a tournament (admin, operator or host).
+///
+/// This instruction:
+/// 1. Marks the registration as disqualified, excluding it from result recording
+/// 2. Optionally refunds a share of the tier fee, capped at what is still
+///    unrefunded, to whoever paid it: from the fee escrow while the fees are
+///    escrowed and unreleased, otherwise from the treasury (which must sign)
+/// 3. Forfeits any POINTS already awarded: the pending award, or the
+///    player's POINTS escrowed after busting, counting them as penalty
+///    POINTS so a granted appeal restores them
+/// 4. Removes the player from the roster count
+///
+/// # Arguments
+/// * `refund_bps` - Share of the tier fee to refund, in basis points (0-10000)
+/// * `reason_hash` - SHA-256 hash of the off-chain reason document
+pub fn handler(ctx: Context<DisqualifyPlayer>, refund_bps: u16, reason_hash: [u8; 32]) -> Result<()> {
+    require!(refund_bps <= 10_000, ArenaError::InvalidBasisPoints);
+
+    let tournament = &mut ctx.accounts.tournament;
+    let registration = &mut ctx.accounts.registration;
+
+    let refund_share = (registration.fee_paid_lamports as u128 * refund_bps as u128 / 10_000) as u64;
+    let refund_lamports = refund_share.min(registration.unsettled_fee_lamports());
+
+    let fee_escrow = match ctx.accounts.fee_escrow.as_ref() {
+        _ if !tournament.fees_escrowed => None,
+        Some(fee_escrow) => (!fee_escrow.released).then(|| fee_escrow.to_account_info()),
+        None => return err!(ArenaError::FeeEscrowRequired),
+    };
+
+    if let Some(fee_escrow) = fee_escrow.filter(|_| refund_lamports > 0) {
+        **fee_escrow.try_borrow_mut_lamports()? -= refund_lamports;
+        **ctx.accounts.fee_payer.try_borrow_mut_lamports()? += refund_lamports;
+        if let Some(treasury_ledger) = ctx.accounts.treasury_ledger.as_mut() {
+            treasury_ledger.record(
+                LedgerCategory::FeeEscrow,
+                LedgerDirection::Outflow,
+                fee_escrow.key(),
+                refund_lamports,
+                Clock::get()?.unix_timestamp,
+            );
+        }
+    } else if refund_lamports > 0 {
+        let treasury = ctx
+            .accounts
+            .treasury
+            .as_ref()
+            .ok_or(ArenaError::InvalidTierPayment)?;
+        require!(
+            treasury.key() == ctx.accounts.arena_config.treasury,
+            ArenaError::InvalidTierPayment
+        );
+
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: treasury.to_account_info(),
+                    to: ctx.accounts.fee_payer.to_account_info(),
+                },
+            ),
+            refund_lamports,
+        )?;
+    }
+
+    let (award_forfeited, escrow_forfeited) = if !registration.points_distributed {
+        let awarded = registration.points_awarded.unwrap_or_default();
+        registration.points_awarded = registration.points_awarded.map(|_| 0);
+        (awarded, 0)
+    } else {
+        let escrow_info = ctx.accounts.points_escrow.to_account_info();
+        let mut escrowed = 0;
+        if !escrow_info.data_is_empty() {
+            require_keys_eq!(
+                *escrow_info.owner,
+                crate::ID,
+                ErrorCode::AccountOwnedByWrongProgram
+            );
+            let mut data = escrow_info.try_borrow_mut_data()?;
+            let mut points_escrow = PointsEscrow::try_deserialize(&mut &data[..])?;
+            if !points_escrow.released {
+                escrowed = points_escrow.points;
+                points_escrow.points = 0;
+                points_escrow.try_serialize(&mut &mut data[..])?;
+            }
+        }
+        (0, escrowed)
+    };
+    let points_forfeited = award_forfeited + escrow_forfeited;
+    registration.penalty_points = registration
+        .penalty_points
+        .checked_add(points_forfeited)
+        .ok_or(ArenaError::ArithmeticOverflow)?;
+
+    let clock = Clock::get()?;
+    let sanction = registration.record_sanction(clock.unix_timestamp)?;
+    sanction.disqualified = true;
+    sanction.award_points = sanction.award_points.saturating_add(award_forfeited);
+    sanction.escrowed_points = sanction.escrowed_points.saturating_add(escrow_forfeited);
+
+    registration.disqualified = true;
+    registration.refunded_lamports = registration.refunded_lamports.saturating_add(refund_lamports);
+    tournament.record_refund(refund_lamports);
+    ctx.accounts.arena_config.record_refund(refund_lamports);
+    tournament.record_unregistration(registration.tier);
+
+    emit!(PlayerDisqualified {
+        tournament: tournament.key(),
+        wallet: registration.wallet,
+        refund_lamports,
+        reason_hash,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Player {} disqualified from tournament {}", registration.wallet, tournament.id);
+    msg!("Refunded: {} lamports", refund_lamports);
+    msg!("POINTS forfeited: {}", points_forfeited);
+
+    AuditLog::record_call::<instruction::DisqualifyPlayer>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.authority.key(),
+        ctx.accounts.tournament.id,
+    )?;
+
+    Ok(())
+}
@@ -2,12 +2,17 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
 
 use crate::errors::ArenaError;
-use crate::state::{ArenaConfig, PlayerRegistration, PointsMintAuthority, Tournament, TournamentStatus};
+use crate::instruction;
+use crate::state::{
+    ArenaConfig, AuditLog, BackingPool, Guild, GuildMember, PlayerRegistration, PointsEscrow,
+    PointsMintAuthority, Tournament, VestingAccount,
+};
 
 /// Accounts required for distributing POINTS tokens to a player.
 #[derive(Accounts)]
 pub struct DistributePoints<'info> {
-    /// Admin wallet - must match arena_config.admin
+    /// Admin wallet - must match arena_config.admin; pays vesting account rent
+    #[account(mut)]
     pub admin: Signer<'info>,
 
     /// Arena config for admin verification and points mint
@@ -18,29 +23,32 @@ pub struct DistributePoints<'info> {
     )]
     pub arena_config: Account<'info, ArenaConfig>,
 
-    /// Tournament - must be Completed
+    /// Tournament - must be Completed, or InProgress for a busted player
     #[account(
-        seeds = [Tournament::SEED_PREFIX, &tournament.id.to_le_bytes()],
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
         bump = tournament.bump,
-        constraint = tournament.status == TournamentStatus::Completed @ ArenaError::TournamentNotCompleted
+        constraint = tournament.accepts_result(&registration) @ ArenaError::TournamentNotCompleted,
+        constraint = tournament.points_root.is_none() @ ArenaError::PointsSettledByMerkleRoot
     )]
     pub tournament: Account<'info, Tournament>,
 
-    /// Player's registration - must have points awarded
+    /// Player's registration - must have points awarded and not have been
+    /// disqualified
     #[account(
         mut,
         seeds = [PlayerRegistration::SEED_PREFIX, tournament.key().as_ref(), registration.wallet.as_ref()],
         bump = registration.bump,
         constraint = registration.tournament == tournament.key() @ ArenaError::TournamentNotFound,
         constraint = registration.points_awarded.is_some() @ ArenaError::NoPointsToDistribute,
-        constraint = !registration.points_distributed @ ArenaError::PointsAlreadyDistributed
+        constraint = !registration.points_distributed @ ArenaError::PointsAlreadyDistributed,
+        constraint = !registration.disqualified @ ArenaError::PlayerDisqualified
     )]
     pub registration: Account<'info, PlayerRegistration>,
 
     /// POINTS SPL token mint
     #[account(
         mut,
-        constraint = points_mint.key() == arena_config.points_mint @ ArenaError::InvalidTierPayment
+        constraint = points_mint.key() == tournament.points_mint(&arena_config) @ ArenaError::InvalidTierPayment
     )]
     pub points_mint: Account<'info, Mint>,
 
@@ -61,47 +69,212 @@ pub struct DistributePoints<'info> {
 
     /// Token program
     pub token_program: Program<'info, Token>,
+
+    /// Player's backing pool PDA (may be uninitialized if the player was not backed)
+    /// CHECK: Address derived from seeds; only deserialized if it holds data
+    #[account(
+        mut,
+        seeds = [BackingPool::SEED_PREFIX, tournament.key().as_ref(), registration.wallet.as_ref()],
+        bump
+    )]
+    pub backing_pool: UncheckedAccount<'info>,
+
+    /// Player's guild membership, for the guild's share of winnings (omit if none)
+    #[account(
+        seeds = [GuildMember::SEED_PREFIX, registration.wallet.as_ref()],
+        bump = guild_member.bump
+    )]
+    pub guild_member: Option<Account<'info, GuildMember>>,
+
+    /// Player's guild
+    #[account(
+        seeds = [Guild::SEED_PREFIX, guild.name.as_ref()],
+        bump = guild.bump
+    )]
+    pub guild: Option<Account<'info, Guild>>,
+
+    /// Guild vault's token account for POINTS
+    #[account(
+        mut,
+        constraint = guild_token_account.mint == points_mint.key() @ ArenaError::InvalidTierPayment
+    )]
+    pub guild_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Vesting account to create when the player's award vests (omit otherwise)
+    #[account(
+        init,
+        payer = admin,
+        space = VestingAccount::SIZE,
+        seeds = [VestingAccount::SEED_PREFIX, tournament.key().as_ref(), registration.wallet.as_ref()],
+        bump
+    )]
+    pub vesting_account: Option<Account<'info, VestingAccount>>,
+
+    /// System program, required with vesting_account or points_escrow
+    pub system_program: Option<Program<'info, System>>,
+
+    /// Escrow to create while the dispute window is open (omit otherwise)
+    #[account(
+        init,
+        payer = admin,
+        space = PointsEscrow::SIZE,
+        seeds = [PointsEscrow::SEED_PREFIX, tournament.key().as_ref(), registration.wallet.as_ref()],
+        bump
+    )]
+    pub points_escrow: Option<Account<'info, PointsEscrow>>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+impl<'info> DistributePoints<'info> {
+    /// Mint `amount` POINTS to `to`, signed by the mint authority PDA
+    fn mint_points(&self, to: AccountInfo<'info>, amount: u64) -> Result<()> {
+        let seeds = &[PointsMintAuthority::SEED_PREFIX, &[self.mint_authority.bump]];
+        let signer_seeds = &[&seeds[..]];
+
+        token::mint_to(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                MintTo {
+                    mint: self.points_mint.to_account_info(),
+                    to,
+                    authority: self.mint_authority.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )
+    }
 }
 
 /// Distribute POINTS tokens to a tournament player (admin only).
 ///
 /// This instruction:
-/// 1. Validates the tournament is completed and player has points awarded
-/// 2. Mints the awarded POINTS tokens to the player's token account
-/// 3. Marks the registration as having received points
+/// 1. Validates the tournament is completed, or the player busted mid-event,
+///    and the player has points awarded and no sanction that can still be
+///    appealed or awaits its appeal
+/// 2. Reserves sold action shares and settles the backing pool, if any
+/// 3. Mints the guild's share of the player's portion to the guild vault, if
+///    any, and the rest to the player's token account; while the dispute
+///    window is open both are held in a PointsEscrow instead, and otherwise
+///    a player's portion meeting the vesting threshold is recorded on a
+///    VestingAccount
+/// 4. Marks the registration as having received points
+///
+/// Points for a player who busted while the tournament is InProgress are
+/// always escrowed until results are confirmed.
 pub fn handler(ctx: Context<DistributePoints>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
     let registration = &mut ctx.accounts.registration;
-    let points_to_mint = registration.points_awarded.unwrap();
+    require!(
+        !registration.sanction_pending(now),
+        ArenaError::SanctionPending
+    );
+    let points_awarded = registration.points_awarded.unwrap();
+    let mut points_to_mint = points_awarded;
+
+    // Action buyers and backers who funded a withdrawn pool claim their shares separately
+    points_to_mint = points_to_mint.saturating_sub(BackingPool::points_for_share(points_awarded, registration.action_sold_bps));
+    let backing_info = ctx.accounts.backing_pool.to_account_info();
+    if !backing_info.data_is_empty() {
+        require_keys_eq!(*backing_info.owner, crate::ID, ErrorCode::AccountOwnedByWrongProgram);
+        let mut data = backing_info.try_borrow_mut_data()?;
+        let mut backing_pool = BackingPool::try_deserialize(&mut &data[..])?;
+        if backing_pool.withdrawn {
+            points_to_mint = points_to_mint.saturating_sub(BackingPool::points_for_share(points_awarded, backing_pool.sold_share_bps));
+            backing_pool.points_awarded = points_awarded;
+            backing_pool.points_settled = true;
+            backing_pool.try_serialize(&mut &mut data[..])?;
+        }
+    }
+
+    // The player's guild takes its policy share of what remains
+    let mut guild_vault = Pubkey::default();
+    let guild_share = match (
+        &ctx.accounts.guild_member,
+        &ctx.accounts.guild,
+        &ctx.accounts.guild_token_account,
+    ) {
+        (Some(guild_member), Some(guild), Some(guild_token_account)) => {
+            require_keys_eq!(guild_member.guild, guild.key(), ArenaError::InvalidGuild);
+            require_keys_eq!(guild_token_account.owner, guild.vault, ArenaError::InvalidGuild);
+            guild_vault = guild.vault;
+            guild.share_of(points_to_mint)
+        }
+        _ => 0,
+    };
+    points_to_mint -= guild_share;
 
     // Skip if no points to mint
-    if points_to_mint == 0 {
-        registration.points_distributed = true;
-        msg!("No points to distribute for player: {}", registration.wallet);
-        return Ok(());
+    if points_to_mint == 0 && guild_share == 0 {
+        ctx.accounts.registration.points_distributed = true;
+        msg!("No points to distribute for player: {}", ctx.accounts.registration.wallet);
+        return AuditLog::record_call::<instruction::DistributePoints>(
+            &mut ctx.accounts.audit_log,
+            ctx.accounts.admin.key(),
+            ctx.accounts.tournament.id,
+        );
     }
 
-    // Build PDA signer seeds
-    let seeds = &[
-        PointsMintAuthority::SEED_PREFIX,
-        &[ctx.accounts.mint_authority.bump],
-    ];
-    let signer_seeds = &[&seeds[..]];
-
-    // Mint POINTS tokens to player
-    token::mint_to(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            MintTo {
-                mint: ctx.accounts.points_mint.to_account_info(),
-                to: ctx.accounts.player_token_account.to_account_info(),
-                authority: ctx.accounts.mint_authority.to_account_info(),
-            },
-            signer_seeds,
-        ),
-        points_to_mint,
-    )?;
+    // While results can still be disputed the player's portion and the
+    // guild's share are escrowed; otherwise large awards vest instead of
+    // minting right away
+    let arena_config = &ctx.accounts.arena_config;
+    let escrowed = !ctx.accounts.tournament.points_releasable(now);
+    let vests = !escrowed && arena_config.vests(points_to_mint);
+
+    if guild_share > 0 && !escrowed {
+        let guild_token_account = ctx.accounts.guild_token_account.as_ref().unwrap();
+        ctx.accounts
+            .mint_points(guild_token_account.to_account_info(), guild_share)?;
+        msg!("Minted {} POINTS to the player's guild", guild_share);
+    }
+
+    require!(
+        ctx.accounts.points_escrow.is_some() == escrowed,
+        ArenaError::PointsEscrowMismatch
+    );
+    require!(
+        ctx.accounts.vesting_account.is_some() == vests,
+        ArenaError::VestingAccountMismatch
+    );
+    if let Some(points_escrow) = ctx.accounts.points_escrow.as_mut() {
+        points_escrow.wallet = ctx.accounts.registration.wallet;
+        points_escrow.tournament = ctx.accounts.tournament.key();
+        points_escrow.points = points_to_mint;
+        points_escrow.guild_points = guild_share;
+        points_escrow.guild_vault = guild_vault;
+        points_escrow.released = false;
+        points_escrow.bump = ctx.bumps.points_escrow.unwrap();
+        msg!(
+            "{} POINTS and {} guild POINTS held in escrow during the dispute window",
+            points_to_mint,
+            guild_share
+        );
+    } else if let Some(vesting_account) = ctx.accounts.vesting_account.as_mut() {
+        vesting_account.start(
+            ctx.accounts.registration.wallet,
+            ctx.accounts.tournament.key(),
+            points_to_mint,
+            arena_config,
+            now,
+            ctx.bumps.vesting_account.unwrap(),
+        );
+        msg!("{} POINTS vest until {}", points_to_mint, vesting_account.end_at);
+    } else if points_to_mint > 0 {
+        // Mint POINTS tokens to player
+        ctx.accounts
+            .mint_points(ctx.accounts.player_token_account.to_account_info(), points_to_mint)?;
+    }
 
     // Mark as distributed
+    let registration = &mut ctx.accounts.registration;
     registration.points_distributed = true;
 
     msg!(
@@ -110,5 +283,11 @@ pub fn handler(ctx: Context<DistributePoints>) -> Result<()> {
         registration.wallet
     );
 
+    AuditLog::record_call::<instruction::DistributePoints>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        ctx.accounts.tournament.id,
+    )?;
+
     Ok(())
 }
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::state::{Guild, GuildEventEntry, Tournament};
+
+/// Accounts required for entering a guild into a guild event.
+#[derive(Accounts)]
+pub struct EnterGuildEvent<'info> {
+    /// Guild leader or officer; pays the entry rent
+    #[account(mut)]
+    pub officer: Signer<'info>,
+
+    /// Guild entering the event
+    #[account(
+        seeds = [Guild::SEED_PREFIX, guild.name.as_ref()],
+        bump = guild.bump,
+        constraint = guild.is_officer(&officer.key()) @ ArenaError::Unauthorized
+    )]
+    pub guild: Account<'info, Guild>,
+
+    /// Guild event tournament - registration must be open
+    #[account(
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.guild_event @ ArenaError::InvalidGuild,
+        constraint = tournament.is_registration_open() @ ArenaError::RegistrationNotOpen
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Guild's event entry PDA to be created
+    #[account(
+        init,
+        payer = officer,
+        space = GuildEventEntry::SIZE,
+        seeds = [GuildEventEntry::SEED_PREFIX, tournament.key().as_ref(), guild.key().as_ref()],
+        bump
+    )]
+    pub guild_event_entry: Account<'info, GuildEventEntry>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Enter a guild into a guild-vs-guild tournament.
+pub fn handler(ctx: Context<EnterGuildEvent>) -> Result<()> {
+    let guild_event_entry = &mut ctx.accounts.guild_event_entry;
+    guild_event_entry.tournament = ctx.accounts.tournament.key();
+    guild_event_entry.guild = ctx.accounts.guild.key();
+    guild_event_entry.member_results = 0;
+    guild_event_entry.total_points = 0;
+    guild_event_entry.rank = 0;
+    guild_event_entry.prize_claimed = false;
+    guild_event_entry.bump = ctx.bumps.guild_event_entry;
+
+    msg!(
+        "Guild {} entered tournament {}",
+        guild_event_entry.guild,
+        ctx.accounts.tournament.id
+    );
+
+    Ok(())
+}
@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::spl_token::instruction::AuthorityType;
+use anchor_spl::token::{self, Mint, SetAuthority, Token};
+
+use crate::errors::ArenaError;
+use crate::events::MintAuthorityChanged;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog, MintAuthorityChange, PointsMintAuthority};
+
+/// Accounts required for executing a queued POINTS mint authority change.
+#[derive(Accounts)]
+pub struct ExecuteMintAuthorityChange<'info> {
+    /// Admin wallet - must match arena_config.admin; receives the queue's rent
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// Arena config for admin verification and points mint
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Queued change - its timelock must have passed
+    #[account(
+        mut,
+        close = admin,
+        seeds = [MintAuthorityChange::SEED_PREFIX],
+        bump = mint_authority_change.bump
+    )]
+    pub mint_authority_change: Account<'info, MintAuthorityChange>,
+
+    /// PDA currently holding mint authority
+    #[account(
+        seeds = [PointsMintAuthority::SEED_PREFIX],
+        bump = mint_authority.bump
+    )]
+    pub mint_authority: Account<'info, PointsMintAuthority>,
+
+    /// POINTS SPL token mint
+    #[account(
+        mut,
+        constraint = points_mint.key() == arena_config.points_mint @ ArenaError::InvalidTierPayment
+    )]
+    pub points_mint: Account<'info, Mint>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Execute a queued POINTS mint authority change (admin only).
+///
+/// This instruction:
+/// 1. Requires the change's timelock to have passed
+/// 2. Hands the mint authority to the queued authority, or revokes it
+///    permanently
+/// 3. Closes the queued change and emits MintAuthorityChanged
+///
+/// Freeze authority stays with the mint authority PDA, so sanctions keep
+/// working after a migration or revocation. Once minting moves away,
+/// instructions that mint POINTS fail until it is handed back.
+pub fn handler(ctx: Context<ExecuteMintAuthorityChange>) -> Result<()> {
+    let mint_authority_change = &ctx.accounts.mint_authority_change;
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        now >= mint_authority_change.executable_at,
+        ArenaError::InvalidMintAuthorityChange
+    );
+    let new_authority = mint_authority_change.new_authority;
+
+    let seeds = &[
+        PointsMintAuthority::SEED_PREFIX,
+        &[ctx.accounts.mint_authority.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    token::set_authority(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            SetAuthority {
+                current_authority: ctx.accounts.mint_authority.to_account_info(),
+                account_or_mint: ctx.accounts.points_mint.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        AuthorityType::MintTokens,
+        new_authority,
+    )?;
+
+    emit!(MintAuthorityChanged {
+        mint: ctx.accounts.points_mint.key(),
+        new_authority,
+        timestamp: now,
+    });
+
+    match new_authority {
+        Some(authority) => msg!("POINTS mint authority handed to {}", authority),
+        None => msg!("POINTS minting permanently revoked"),
+    }
+
+    AuditLog::record_call::<instruction::ExecuteMintAuthorityChange>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        0,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,145 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::state::{
+    ArenaConfig, BuybackLedger, Governance, Proposal, ProposalAction, RedemptionVault,
+};
+
+/// Accounts required for executing a passed proposal.
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    /// Anyone may execute a passed proposal after the timelock
+    pub executor: Signer<'info>,
+
+    /// Governance config PDA
+    #[account(
+        seeds = [Governance::SEED_PREFIX],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    /// Proposal to execute
+    #[account(
+        mut,
+        seeds = [Proposal::SEED_PREFIX, &proposal.id.to_le_bytes()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    /// Arena config (target of SetCrankBounty, SetRakeSplit, SetTierFeesUsd
+    /// and SetDisabledBlindPresets)
+    #[account(
+        mut,
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Buyback ledger (required for SetBuybackBps)
+    #[account(
+        mut,
+        seeds = [BuybackLedger::SEED_PREFIX],
+        bump = buyback_ledger.bump
+    )]
+    pub buyback_ledger: Option<Account<'info, BuybackLedger>>,
+
+    /// Redemption vault (required for SetRedemptionRate)
+    #[account(
+        mut,
+        seeds = [RedemptionVault::SEED_PREFIX],
+        bump = redemption_vault.bump
+    )]
+    pub redemption_vault: Option<Account<'info, RedemptionVault>>,
+}
+
+/// Execute a passed proposal after its timelock (permissionless).
+///
+/// This instruction:
+/// 1. Validates voting and the timelock have elapsed
+/// 2. Validates quorum and majority
+/// 3. Applies the parameter change and marks the proposal executed
+///
+/// Rates and prices (crank bounty, redemption rate, USD tier fees) may move
+/// at most MAX_RATE_CHANGE_BPS from their value at execution, so a passed
+/// proposal can't drain the vaults or reprice the arena in one step. The
+/// rake split may only give shares to recipients the admin configured.
+pub fn handler(ctx: Context<ExecuteProposal>) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(
+        !proposal.executed && now >= proposal.executable_at,
+        ArenaError::ProposalNotExecutable
+    );
+    require!(
+        proposal.has_passed(ctx.accounts.governance.quorum_points),
+        ArenaError::ProposalRejected
+    );
+
+    proposal.action.validate()?;
+    let arena_config = &mut ctx.accounts.arena_config;
+    match proposal.action {
+        ProposalAction::SetCrankBounty(bounty_lamports) => {
+            require!(
+                Governance::within_rate_bound(arena_config.crank_bounty_lamports, bounty_lamports),
+                ArenaError::ProposalChangeOutOfBounds
+            );
+            arena_config.crank_bounty_lamports = bounty_lamports;
+        }
+        ProposalAction::SetBuybackBps(buyback_bps) => {
+            ctx.accounts
+                .buyback_ledger
+                .as_mut()
+                .ok_or(ErrorCode::AccountNotEnoughKeys)?
+                .buyback_bps = buyback_bps;
+        }
+        ProposalAction::SetRedemptionRate(lamports_per_point) => {
+            let redemption_vault = ctx
+                .accounts
+                .redemption_vault
+                .as_mut()
+                .ok_or(ErrorCode::AccountNotEnoughKeys)?;
+            require!(
+                Governance::within_rate_bound(
+                    redemption_vault.lamports_per_point,
+                    lamports_per_point
+                ),
+                ArenaError::ProposalChangeOutOfBounds
+            );
+            redemption_vault.lamports_per_point = lamports_per_point;
+        }
+        ProposalAction::SetRakeSplit(recipient_bps) => {
+            require!(
+                arena_config
+                    .fee_recipients
+                    .iter()
+                    .zip(recipient_bps)
+                    .all(|(recipient, bps)| *recipient != Pubkey::default() || bps == 0),
+                ArenaError::InvalidFeeRecipients
+            );
+            arena_config.fee_recipient_bps = recipient_bps;
+        }
+        ProposalAction::SetTierFeesUsd(tier_fee_usd_cents) => {
+            require!(
+                arena_config
+                    .tier_fee_usd_cents
+                    .iter()
+                    .zip(tier_fee_usd_cents)
+                    .all(|(current, proposed)| {
+                        Governance::within_rate_bound(*current as u64, proposed as u64)
+                    }),
+                ArenaError::ProposalChangeOutOfBounds
+            );
+            arena_config.tier_fee_usd_cents = tier_fee_usd_cents;
+        }
+        ProposalAction::SetDisabledBlindPresets(disabled_blind_presets) => {
+            arena_config.disabled_blind_presets = disabled_blind_presets;
+        }
+    }
+
+    proposal.executed = true;
+
+    msg!("Proposal {} executed: {:?}", proposal.id, proposal.action);
+
+    Ok(())
+}
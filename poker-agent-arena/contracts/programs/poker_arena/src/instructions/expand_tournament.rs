@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::events::TournamentExpanded;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog, Tournament};
+
+/// Accounts required for resizing a tournament during registration.
+#[derive(Accounts)]
+pub struct ExpandTournament<'info> {
+    /// Admin wallet - must match arena_config.admin
+    pub admin: Signer<'info>,
+
+    /// Arena config for admin verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament - registration must be open
+    #[account(
+        mut,
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.is_registration_open() @ ArenaError::RegistrationNotOpen
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Change a tournament's capacity while registration is open (admin only).
+///
+/// Capacity can never drop below current registrations or reserved seats.
+///
+/// # Arguments
+/// * `max_players` - New maximum number of players
+pub fn handler(ctx: Context<ExpandTournament>, max_players: u16) -> Result<()> {
+    Tournament::validate_max_players(max_players)?;
+    let tournament = &mut ctx.accounts.tournament;
+    let public_registered = tournament
+        .registered_players
+        .saturating_sub(tournament.reserved_registered);
+    require!(
+        max_players >= tournament.reserved_seats
+            && max_players - tournament.reserved_seats >= public_registered,
+        ArenaError::InvalidMaxPlayers
+    );
+
+    let previous_max_players = tournament.max_players;
+    tournament.max_players = max_players;
+
+    emit!(TournamentExpanded {
+        tournament: tournament.key(),
+        previous_max_players,
+        max_players,
+        registered_players: tournament.registered_players,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "Tournament {} capacity changed from {} to {}",
+        tournament.id,
+        previous_max_players,
+        max_players
+    );
+
+    AuditLog::record_call::<instruction::ExpandTournament>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        ctx.accounts.tournament.id,
+    )?;
+
+    Ok(())
+}
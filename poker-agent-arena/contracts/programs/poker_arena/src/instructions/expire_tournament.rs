@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::events::TournamentExpired;
+use crate::state::{ArenaConfig, Host, Tournament, TournamentStatus};
+
+/// Accounts required for expiring a tournament that never started.
+#[derive(Accounts)]
+pub struct ExpireTournament<'info> {
+    /// Any wallet running the crank
+    pub caller: Signer<'info>,
+
+    /// Arena config for the staleness period
+    #[account(
+        mut,
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament to expire - must not have started
+    #[account(
+        mut,
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = matches!(
+            tournament.status,
+            TournamentStatus::Created | TournamentStatus::Registration
+        ) @ ArenaError::CrankNotReady
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Tournament's host, required if the tournament is hosted
+    #[account(
+        mut,
+        seeds = [Host::SEED_PREFIX, host.authority.as_ref()],
+        bump = host.bump
+    )]
+    pub host: Option<Account<'info, Host>>,
+}
+
+/// Cancel a tournament that never started (permissionless).
+///
+/// This instruction:
+/// 1. Validates the tournament is still Created or in Registration, more than
+///    the configured staleness period past `starts_at`
+/// 2. Moves the tournament to Cancelled, unlocking refunds of backing pools,
+///    tier fees and other escrow tied to it, and frees its open slot
+pub fn handler(ctx: Context<ExpireTournament>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let tournament = &mut ctx.accounts.tournament;
+
+    require!(
+        tournament.is_stale(now, ctx.accounts.arena_config.stale_tournament_seconds),
+        ArenaError::CrankNotReady
+    );
+
+    tournament.status = TournamentStatus::Cancelled;
+    match Host::for_tournament(ctx.accounts.host.as_mut(), tournament)? {
+        Some(host) => host.record_cancelled(),
+        None => ctx.accounts.arena_config.record_tournament_closed(),
+    }
+
+    emit!(TournamentExpired {
+        tournament: tournament.key(),
+        caller: ctx.accounts.caller.key(),
+        starts_at: tournament.starts_at,
+        timestamp: now,
+    });
+
+    msg!("Tournament {} expired by crank", tournament.id);
+
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog, GuildEventEntry, Tournament, TournamentStatus};
+
+/// Accounts required for finalizing guild event standings.
+#[derive(Accounts)]
+pub struct FinalizeGuildStandings<'info> {
+    /// Arena admin, the tournament's delegated operator or its host
+    pub authority: Signer<'info>,
+
+    /// Arena config for admin verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Guild event tournament - must be Completed
+    #[account(
+        mut,
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.can_run(&authority.key(), &arena_config.admin) @ ArenaError::Unauthorized,
+        constraint = tournament.status == TournamentStatus::Completed @ ArenaError::TournamentNotCompleted,
+        constraint = tournament.guild_event @ ArenaError::InvalidGuild,
+        constraint = !tournament.guild_standings_final @ ArenaError::InvalidGuild
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Rank the guilds of a guild event once all results are recorded
+/// (admin, operator or host).
+///
+/// The top guilds' GuildEventEntry accounts are passed as remaining accounts
+/// in rank order; their totals must not increase down the list.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, FinalizeGuildStandings<'info>>,
+) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+    require!(
+        ctx.remaining_accounts.len() <= GuildEventEntry::PRIZE_SPLIT_BPS.len(),
+        ArenaError::InvalidGuild
+    );
+
+    let mut previous_points = u64::MAX;
+    for (index, info) in ctx.remaining_accounts.iter().enumerate() {
+        let mut entry = Account::<GuildEventEntry>::try_from(info)?;
+        require_keys_eq!(entry.tournament, tournament.key(), ArenaError::InvalidGuild);
+        require!(entry.rank == 0, ArenaError::InvalidGuild);
+        require!(
+            entry.total_points <= previous_points,
+            ArenaError::InvalidGuild
+        );
+        previous_points = entry.total_points;
+
+        entry.rank = index as u8 + 1;
+        entry.exit(&crate::ID)?;
+    }
+    tournament.guild_standings_final = true;
+
+    msg!(
+        "Tournament {} guild standings finalized with {} ranked guilds",
+        tournament.id,
+        ctx.remaining_accounts.len()
+    );
+
+    AuditLog::record_call::<instruction::FinalizeGuildStandings>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.authority.key(),
+        ctx.accounts.tournament.id,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::events::PlayerForfeited;
+use crate::state::{PlayerRegistration, Tournament, TournamentStatus};
+
+/// Accounts required for forfeiting a running tournament.
+#[derive(Accounts)]
+pub struct Forfeit<'info> {
+    /// Registered wallet withdrawing from play
+    pub player: Signer<'info>,
+
+    /// Tournament - must be InProgress
+    #[account(
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.status == TournamentStatus::InProgress @ ArenaError::TournamentNotInProgress
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Player's registration
+    #[account(
+        mut,
+        seeds = [PlayerRegistration::SEED_PREFIX, tournament.key().as_ref(), player.key().as_ref()],
+        bump = registration.bump,
+        constraint = !registration.forfeited && !registration.disqualified @ ArenaError::AlreadyForfeited
+    )]
+    pub registration: Account<'info, PlayerRegistration>,
+}
+
+/// Voluntarily forfeit a running tournament.
+///
+/// Flags the registration and emits PlayerForfeited so the engine blinds the
+/// agent out immediately. The player keeps their seat in the standings and
+/// is ranked where they bust; no tier fee is refunded.
+pub fn handler(ctx: Context<Forfeit>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let registration = &mut ctx.accounts.registration;
+
+    registration.forfeited = true;
+    registration.forfeited_at = now;
+
+    emit!(PlayerForfeited {
+        tournament: ctx.accounts.tournament.key(),
+        wallet: registration.wallet,
+        timestamp: now,
+    });
+
+    msg!(
+        "Player {} forfeited tournament {}",
+        registration.wallet,
+        ctx.accounts.tournament.id
+    );
+
+    Ok(())
+}
@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{
+    ArenaConfig, AuditLog, LedgerCategory, LedgerDirection, PlayerRegistration, PromptEscrow,
+    Tournament, TournamentStatus, TreasuryLedger,
+};
+
+/// Accounts required for forfeiting an unrevealed prompt bond.
+#[derive(Accounts)]
+pub struct ForfeitPromptBond<'info> {
+    /// Admin wallet - must match arena_config.admin
+    pub admin: Signer<'info>,
+
+    /// Arena config for admin verification and treasury address
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament - must be Completed; bonds posted for cancelled or voided
+    /// tournaments are only ever refunded
+    #[account(
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.status == TournamentStatus::Completed @ ArenaError::TournamentNotCompleted
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Registration the escrow belongs to
+    #[account(
+        seeds = [PlayerRegistration::SEED_PREFIX, tournament.key().as_ref(), registration.wallet.as_ref()],
+        bump = registration.bump,
+        constraint = registration.tournament == tournament.key() @ ArenaError::TournamentNotFound
+    )]
+    pub registration: Account<'info, PlayerRegistration>,
+
+    /// Prompt escrow whose key was never revealed
+    #[account(
+        mut,
+        seeds = [PromptEscrow::SEED_PREFIX, registration.key().as_ref()],
+        bump = prompt_escrow.bump,
+        constraint = !prompt_escrow.key_revealed @ ArenaError::KeyAlreadyRevealed
+    )]
+    pub prompt_escrow: Account<'info, PromptEscrow>,
+
+    /// Treasury wallet receiving the forfeited bond
+    /// CHECK: This is verified against arena_config.treasury
+    #[account(
+        mut,
+        constraint = treasury.key() == arena_config.treasury @ ArenaError::InvalidTierPayment
+    )]
+    pub treasury: AccountInfo<'info>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+
+    /// Treasury ledger recording the bond movement (omit to skip)
+    #[account(
+        mut,
+        seeds = [TreasuryLedger::SEED_PREFIX],
+        bump = treasury_ledger.bump
+    )]
+    pub treasury_ledger: Option<Box<Account<'info, TreasuryLedger>>>,
+}
+
+/// Forfeit the bond of a player who did not reveal their prompt key (admin only).
+///
+/// This instruction:
+/// 1. Validates the reveal window after tournament completion has closed
+/// 2. Moves the bond from the escrow PDA to the treasury
+pub fn handler(ctx: Context<ForfeitPromptBond>) -> Result<()> {
+    let prompt_escrow = &mut ctx.accounts.prompt_escrow;
+    let completed_at = ctx.accounts.tournament.completed_at.unwrap_or_default();
+    let clock = Clock::get()?;
+
+    require!(
+        !PromptEscrow::is_reveal_window_open(completed_at, clock.unix_timestamp),
+        ArenaError::RevealWindowOpen
+    );
+
+    let bond = prompt_escrow.bond_lamports;
+    prompt_escrow.bond_lamports = 0;
+    **prompt_escrow.to_account_info().try_borrow_mut_lamports()? -= bond;
+    **ctx.accounts.treasury.try_borrow_mut_lamports()? += bond;
+    if let Some(treasury_ledger) = ctx.accounts.treasury_ledger.as_mut() {
+        treasury_ledger.record(
+            LedgerCategory::PromptBond,
+            LedgerDirection::Outflow,
+            prompt_escrow.key(),
+            bond,
+            clock.unix_timestamp,
+        );
+    }
+
+    msg!("Prompt bond forfeited for player: {}", prompt_escrow.wallet);
+    msg!("Forfeited: {} lamports", bond);
+
+    AuditLog::record_call::<instruction::ForfeitPromptBond>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        ctx.accounts.tournament.id,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, FreezeAccount, Mint, Token, TokenAccount};
+
+use crate::errors::ArenaError;
+use crate::events::PointsFreezeUpdated;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog, PointsMintAuthority};
+
+/// Accounts required for freezing a player's POINTS token account.
+#[derive(Accounts)]
+pub struct FreezePlayerPoints<'info> {
+    /// Admin wallet - must match arena_config.admin
+    pub admin: Signer<'info>,
+
+    /// Arena config for admin verification and points mint
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// PDA that holds mint and freeze authority
+    #[account(
+        seeds = [PointsMintAuthority::SEED_PREFIX],
+        bump = mint_authority.bump
+    )]
+    pub mint_authority: Account<'info, PointsMintAuthority>,
+
+    /// POINTS SPL token mint
+    #[account(
+        constraint = points_mint.key() == arena_config.points_mint @ ArenaError::InvalidTierPayment
+    )]
+    pub points_mint: Account<'info, Mint>,
+
+    /// Player's POINTS token account to freeze
+    #[account(
+        mut,
+        constraint = player_token_account.mint == points_mint.key() @ ArenaError::InvalidTierPayment,
+        constraint = !player_token_account.is_frozen() @ ArenaError::TokenAccountAlreadyFrozen
+    )]
+    pub player_token_account: Account<'info, TokenAccount>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Freeze a player's POINTS token account during an investigation (admin only).
+///
+/// This instruction:
+/// 1. Freezes the token account using the mint authority PDA, which also
+///    holds the mint's freeze authority
+/// 2. Emits a PointsFreezeUpdated event
+///
+/// A frozen account can neither send nor receive POINTS until thawed.
+///
+/// # Arguments
+/// * `reason_hash` - SHA-256 hash of the off-chain investigation record
+pub fn handler(ctx: Context<FreezePlayerPoints>, reason_hash: [u8; 32]) -> Result<()> {
+    require!(reason_hash != [0u8; 32], ArenaError::InvalidPenalty);
+
+    let seeds = &[
+        PointsMintAuthority::SEED_PREFIX,
+        &[ctx.accounts.mint_authority.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    token::freeze_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        FreezeAccount {
+            account: ctx.accounts.player_token_account.to_account_info(),
+            mint: ctx.accounts.points_mint.to_account_info(),
+            authority: ctx.accounts.mint_authority.to_account_info(),
+        },
+        signer_seeds,
+    ))?;
+
+    emit!(PointsFreezeUpdated {
+        wallet: ctx.accounts.player_token_account.owner,
+        token_account: ctx.accounts.player_token_account.key(),
+        frozen: true,
+        reason_hash,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "Froze POINTS account {} of {}",
+        ctx.accounts.player_token_account.key(),
+        ctx.accounts.player_token_account.owner
+    );
+
+    AuditLog::record_call::<instruction::FreezePlayerPoints>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        0,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::state::{CrankVault, LedgerCategory, LedgerDirection, TreasuryLedger};
+
+/// Accounts required for funding the crank vault.
+#[derive(Accounts)]
+pub struct FundCrankVault<'info> {
+    /// Wallet providing the funds (typically the treasury)
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    /// Crank vault PDA (created on first funding)
+    #[account(
+        init_if_needed,
+        payer = funder,
+        space = CrankVault::SIZE,
+        seeds = [CrankVault::SEED_PREFIX],
+        bump
+    )]
+    pub crank_vault: Account<'info, CrankVault>,
+
+    /// System program for account creation and transfers
+    pub system_program: Program<'info, System>,
+
+    /// Treasury ledger recording the movement (omit to skip)
+    #[account(
+        mut,
+        seeds = [TreasuryLedger::SEED_PREFIX],
+        bump = treasury_ledger.bump
+    )]
+    pub treasury_ledger: Option<Box<Account<'info, TreasuryLedger>>>,
+}
+
+/// Fund the crank vault that pays permissionless crank bounties.
+///
+/// # Arguments
+/// * `amount` - Lamports to deposit
+pub fn handler(ctx: Context<FundCrankVault>, amount: u64) -> Result<()> {
+    let crank_vault = &mut ctx.accounts.crank_vault;
+    crank_vault.bump = ctx.bumps.crank_vault;
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.funder.to_account_info(),
+                to: crank_vault.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    if let Some(treasury_ledger) = ctx.accounts.treasury_ledger.as_mut() {
+        treasury_ledger.record(
+            LedgerCategory::CrankVault,
+            LedgerDirection::Inflow,
+            ctx.accounts.crank_vault.key(),
+            amount,
+            Clock::get()?.unix_timestamp,
+        );
+    }
+
+    msg!("Crank vault funded with {} lamports", amount);
+
+    Ok(())
+}
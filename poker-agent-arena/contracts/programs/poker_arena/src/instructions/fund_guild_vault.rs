@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::state::{Guild, GuildVault, LedgerCategory, LedgerDirection, TreasuryLedger};
+
+/// Accounts required for funding a guild vault.
+#[derive(Accounts)]
+pub struct FundGuildVault<'info> {
+    /// Wallet providing the funds
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    /// Guild owning the vault
+    #[account(
+        seeds = [Guild::SEED_PREFIX, guild.name.as_ref()],
+        bump = guild.bump
+    )]
+    pub guild: Account<'info, Guild>,
+
+    /// Guild vault PDA
+    #[account(
+        mut,
+        seeds = [GuildVault::SEED_PREFIX, guild.key().as_ref()],
+        bump = guild_vault.bump
+    )]
+    pub guild_vault: Account<'info, GuildVault>,
+
+    /// System program for transfers
+    pub system_program: Program<'info, System>,
+
+    /// Treasury ledger recording the movement (omit to skip)
+    #[account(
+        mut,
+        seeds = [TreasuryLedger::SEED_PREFIX],
+        bump = treasury_ledger.bump
+    )]
+    pub treasury_ledger: Option<Box<Account<'info, TreasuryLedger>>>,
+}
+
+/// Deposit SOL into a guild vault.
+///
+/// # Arguments
+/// * `amount` - Lamports to deposit
+pub fn handler(ctx: Context<FundGuildVault>, amount: u64) -> Result<()> {
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.funder.to_account_info(),
+                to: ctx.accounts.guild_vault.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    if let Some(treasury_ledger) = ctx.accounts.treasury_ledger.as_mut() {
+        treasury_ledger.record(
+            LedgerCategory::GuildVault,
+            LedgerDirection::Inflow,
+            ctx.accounts.guild_vault.key(),
+            amount,
+            Clock::get()?.unix_timestamp,
+        );
+    }
+
+    let guild_vault = &mut ctx.accounts.guild_vault;
+    guild_vault.total_deposited = guild_vault.total_deposited.saturating_add(amount);
+
+    msg!(
+        "Guild vault {} funded with {} lamports",
+        guild_vault.key(),
+        amount
+    );
+
+    Ok(())
+}
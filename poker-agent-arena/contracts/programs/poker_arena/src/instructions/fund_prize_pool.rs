@@ -0,0 +1,169 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::errors::ArenaError;
+use crate::events::PrizePoolFunded;
+use crate::state::{
+    ArenaConfig, LedgerCategory, LedgerDirection, PrizeDenomination, PrizePool, Tournament,
+    TournamentStatus, TreasuryLedger,
+};
+
+/// Accounts required for funding a tournament's prize pool.
+///
+/// The token accounts are required only for USDC pools.
+#[derive(Accounts)]
+pub struct FundPrizePool<'info> {
+    /// Wallet providing the funds
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    /// Arena config for admin verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament - results must not have been submitted
+    #[account(
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = matches!(
+            tournament.status,
+            TournamentStatus::Created | TournamentStatus::Registration | TournamentStatus::InProgress
+        ) @ ArenaError::TournamentNotInProgress
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Tournament's prize pool
+    #[account(
+        mut,
+        seeds = [PrizePool::SEED_PREFIX, tournament.key().as_ref()],
+        bump = prize_pool.bump
+    )]
+    pub prize_pool: Account<'info, PrizePool>,
+
+    /// System program for SOL transfers
+    pub system_program: Program<'info, System>,
+
+    /// Funder's USDC token account
+    #[account(
+        mut,
+        token::authority = funder
+    )]
+    pub funder_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Pool's USDC token account
+    #[account(
+        mut,
+        token::authority = prize_pool
+    )]
+    pub pool_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// SPL Token program
+    pub token_program: Option<Program<'info, Token>>,
+
+    /// Treasury ledger recording SOL funding (omit to skip)
+    #[account(
+        mut,
+        seeds = [TreasuryLedger::SEED_PREFIX],
+        bump = treasury_ledger.bump
+    )]
+    pub treasury_ledger: Option<Box<Account<'info, TreasuryLedger>>>,
+}
+
+/// Add funds to a tournament's prize pool.
+///
+/// This instruction:
+/// 1. Moves lamports into the pool PDA for SOL pools, or USDC from the
+///    funder into a pool-owned token account for USDC pools
+/// 2. For POINTS pools, which are minted on claim, only commits the amount
+///    and requires the admin, as it mints POINTS outside any award formula
+/// 3. Adds the amount to the pool total and emits PrizePoolFunded
+///
+/// # Arguments
+/// * `amount` - Amount to add, in the pool denomination's base units
+pub fn handler(ctx: Context<FundPrizePool>, amount: u64) -> Result<()> {
+    let funder = ctx.accounts.funder.key();
+    let prize_pool = &ctx.accounts.prize_pool;
+
+    match prize_pool.denomination {
+        PrizeDenomination::Points => {
+            require_keys_eq!(
+                funder,
+                ctx.accounts.arena_config.admin,
+                ArenaError::Unauthorized
+            );
+        }
+        PrizeDenomination::Sol => {
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.funder.to_account_info(),
+                        to: prize_pool.to_account_info(),
+                    },
+                ),
+                amount,
+            )?;
+            if let Some(treasury_ledger) = ctx.accounts.treasury_ledger.as_mut() {
+                treasury_ledger.record(
+                    LedgerCategory::PrizePool,
+                    LedgerDirection::Inflow,
+                    prize_pool.key(),
+                    amount,
+                    Clock::get()?.unix_timestamp,
+                );
+            }
+        }
+        PrizeDenomination::Usdc => {
+            let (Some(source), Some(destination), Some(token_program)) = (
+                ctx.accounts.funder_token_account.as_ref(),
+                ctx.accounts.pool_token_account.as_ref(),
+                ctx.accounts.token_program.as_ref(),
+            ) else {
+                return err!(ArenaError::InvalidPrizePool);
+            };
+            require!(
+                source.mint == prize_pool.mint && destination.mint == prize_pool.mint,
+                ArenaError::InvalidPrizePool
+            );
+
+            token::transfer(
+                CpiContext::new(
+                    token_program.to_account_info(),
+                    Transfer {
+                        from: source.to_account_info(),
+                        to: destination.to_account_info(),
+                        authority: ctx.accounts.funder.to_account_info(),
+                    },
+                ),
+                amount,
+            )?;
+        }
+    }
+
+    let prize_pool = &mut ctx.accounts.prize_pool;
+    prize_pool.total = prize_pool
+        .total
+        .checked_add(amount)
+        .ok_or(ArenaError::InvalidPrizePool)?;
+
+    emit!(PrizePoolFunded {
+        tournament: prize_pool.tournament,
+        denomination: prize_pool.denomination,
+        funder,
+        amount,
+        total: prize_pool.total,
+    });
+
+    msg!(
+        "Prize pool for tournament {} funded with {}, total {}",
+        ctx.accounts.tournament.id,
+        amount,
+        prize_pool.total
+    );
+
+    Ok(())
+}
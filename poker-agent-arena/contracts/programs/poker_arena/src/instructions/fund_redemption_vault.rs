@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::errors::ArenaError;
+use crate::state::{ArenaConfig, LedgerCategory, LedgerDirection, RedemptionVault, TreasuryLedger};
+
+/// Accounts required for funding the redemption vault.
+#[derive(Accounts)]
+pub struct FundRedemptionVault<'info> {
+    /// Treasury wallet - must match arena_config.treasury
+    #[account(mut)]
+    pub treasury: Signer<'info>,
+
+    /// Arena config for treasury verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.treasury == treasury.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Redemption vault PDA (created on first funding)
+    #[account(
+        init_if_needed,
+        payer = treasury,
+        space = RedemptionVault::SIZE,
+        seeds = [RedemptionVault::SEED_PREFIX],
+        bump
+    )]
+    pub redemption_vault: Account<'info, RedemptionVault>,
+
+    /// System program for account creation and transfers
+    pub system_program: Program<'info, System>,
+
+    /// Treasury ledger recording the movement (omit to skip)
+    #[account(
+        mut,
+        seeds = [TreasuryLedger::SEED_PREFIX],
+        bump = treasury_ledger.bump
+    )]
+    pub treasury_ledger: Option<Box<Account<'info, TreasuryLedger>>>,
+}
+
+/// Fund the POINTS redemption vault from the treasury.
+///
+/// # Arguments
+/// * `amount` - Lamports to deposit
+pub fn handler(ctx: Context<FundRedemptionVault>, amount: u64) -> Result<()> {
+    let redemption_vault = &mut ctx.accounts.redemption_vault;
+    redemption_vault.bump = ctx.bumps.redemption_vault;
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.treasury.to_account_info(),
+                to: redemption_vault.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    if let Some(treasury_ledger) = ctx.accounts.treasury_ledger.as_mut() {
+        treasury_ledger.record(
+            LedgerCategory::RedemptionVault,
+            LedgerDirection::Inflow,
+            ctx.accounts.redemption_vault.key(),
+            amount,
+            Clock::get()?.unix_timestamp,
+        );
+    }
+
+    msg!("Redemption vault funded with {} lamports", amount);
+
+    Ok(())
+}
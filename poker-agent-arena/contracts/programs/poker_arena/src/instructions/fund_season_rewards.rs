@@ -0,0 +1,130 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::errors::ArenaError;
+use crate::state::{ArenaConfig, LedgerCategory, LedgerDirection, Season, SeasonRewards, TreasuryLedger};
+
+/// Accounts required for funding a season's revenue-share rewards.
+#[derive(Accounts)]
+pub struct FundSeasonRewards<'info> {
+    /// Treasury wallet - must match arena_config.treasury
+    #[account(mut)]
+    pub treasury: Signer<'info>,
+
+    /// Arena config for treasury verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.treasury == treasury.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Season being settled - must have ended
+    #[account(
+        seeds = [Season::SEED_PREFIX, &season.id.to_le_bytes()],
+        bump = season.bump
+    )]
+    pub season: Account<'info, Season>,
+
+    /// Season rewards vault PDA to be created
+    #[account(
+        init,
+        payer = treasury,
+        space = SeasonRewards::SIZE,
+        seeds = [SeasonRewards::SEED_PREFIX, season.key().as_ref()],
+        bump
+    )]
+    pub season_rewards: Account<'info, SeasonRewards>,
+
+    /// System program for account creation and transfers
+    pub system_program: Program<'info, System>,
+
+    /// Treasury ledger recording the movement (omit to skip)
+    #[account(
+        mut,
+        seeds = [TreasuryLedger::SEED_PREFIX],
+        bump = treasury_ledger.bump
+    )]
+    pub treasury_ledger: Option<Box<Account<'info, TreasuryLedger>>>,
+}
+
+/// Fund the revenue-share vault for a season's top agents (treasury only).
+///
+/// This instruction:
+/// 1. Validates the season has ended and the winner shares total 10000 bps
+/// 2. Deposits `revenue_share_bps` of the seasonal rake into the vault
+/// 3. Records the top K winners and their shares for claiming
+///
+/// # Arguments
+/// * `seasonal_rake_lamports` - Fees collected over the season
+/// * `winners` - Top agents on the season leaderboard, best first
+/// * `share_bps` - Each winner's share of the vault, in basis points
+pub fn handler(
+    ctx: Context<FundSeasonRewards>,
+    seasonal_rake_lamports: u64,
+    winners: [Pubkey; SeasonRewards::MAX_WINNERS],
+    share_bps: [u16; SeasonRewards::MAX_WINNERS],
+) -> Result<()> {
+    let season = &ctx.accounts.season;
+    require!(
+        Clock::get()?.unix_timestamp >= season.ends_at,
+        ArenaError::SeasonNotEnded
+    );
+
+    let mut total_bps: u32 = 0;
+    for (index, (winner, bps)) in winners.iter().zip(share_bps).enumerate() {
+        if *winner == Pubkey::default() {
+            require!(bps == 0, ArenaError::InvalidPayoutStructure);
+            continue;
+        }
+        require!(
+            !winners[..index].contains(winner),
+            ArenaError::InvalidPayoutStructure
+        );
+        total_bps += bps as u32;
+    }
+    require!(total_bps == 10_000, ArenaError::InvalidPayoutStructure);
+
+    let total_lamports =
+        (seasonal_rake_lamports as u128 * season.revenue_share_bps as u128 / 10_000) as u64;
+    if total_lamports > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.treasury.to_account_info(),
+                    to: ctx.accounts.season_rewards.to_account_info(),
+                },
+            ),
+            total_lamports,
+        )?;
+    }
+
+    if let Some(treasury_ledger) = ctx.accounts.treasury_ledger.as_mut() {
+        treasury_ledger.record(
+            LedgerCategory::SeasonRewards,
+            LedgerDirection::Inflow,
+            ctx.accounts.season_rewards.key(),
+            total_lamports,
+            Clock::get()?.unix_timestamp,
+        );
+    }
+
+    let season_rewards = &mut ctx.accounts.season_rewards;
+    season_rewards.season = season.key();
+    season_rewards.seasonal_rake_lamports = seasonal_rake_lamports;
+    season_rewards.total_lamports = total_lamports;
+    season_rewards.winners = winners;
+    season_rewards.share_bps = share_bps;
+    season_rewards.claimed_mask = 0;
+    season_rewards.bump = ctx.bumps.season_rewards;
+
+    msg!(
+        "Season {} rewards funded: {} lamports from {} lamports of rake",
+        season.id,
+        total_lamports,
+        seasonal_rake_lamports
+    );
+
+    Ok(())
+}
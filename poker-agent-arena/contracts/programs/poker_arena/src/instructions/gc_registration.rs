@@ -0,0 +1,211 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::state::{
+    ArenaConfig, BackingPool, PlayerRegistration, PrizePool, PromptEscrow, RegistrationIndex,
+    Tournament,
+};
+
+/// Accounts required for garbage collecting registrations of a closed tournament.
+#[derive(Accounts)]
+pub struct GcRegistration<'info> {
+    /// Any wallet running the collection
+    pub caller: Signer<'info>,
+
+    /// Arena config for the forfeited rent recipient
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Closed tournament (Completed, Cancelled or Voided)
+    #[account(
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Tournament's prize pool PDA (may be uninitialized if it has none)
+    /// CHECK: Address derived from seeds; only deserialized if it holds data
+    #[account(
+        seeds = [PrizePool::SEED_PREFIX, tournament.key().as_ref()],
+        bump
+    )]
+    pub prize_pool: UncheckedAccount<'info>,
+
+    /// Registration to close
+    #[account(
+        mut,
+        seeds = [PlayerRegistration::SEED_PREFIX, tournament.key().as_ref(), registration.wallet.as_ref()],
+        bump = registration.bump
+    )]
+    pub registration: Account<'info, PlayerRegistration>,
+
+    /// Wallet receiving the registration's rent
+    /// CHECK: Verified against PlayerRegistration::rent_recipient
+    #[account(mut)]
+    pub rent_recipient: UncheckedAccount<'info>,
+
+    /// Registration's prompt escrow PDA (may be uninitialized)
+    /// CHECK: Address verified by `collect`; only deserialized if it holds data
+    pub prompt_escrow: UncheckedAccount<'info>,
+
+    /// Player's backing pool PDA (may be uninitialized)
+    /// CHECK: Address verified by `collect`; only deserialized if it holds data
+    pub backing_pool: UncheckedAccount<'info>,
+
+    /// Registering wallet's index, pruned of this tournament (omit if none)
+    #[account(
+        mut,
+        seeds = [RegistrationIndex::SEED_PREFIX, registration.wallet.as_ref()],
+        bump = registration_index.bump
+    )]
+    pub registration_index: Option<Account<'info, RegistrationIndex>>,
+}
+
+/// Deserialize the program account at `info`, if it has been created
+fn load_if_created<T: AccountDeserialize>(info: &AccountInfo) -> Result<Option<T>> {
+    if info.data_is_empty() {
+        return Ok(None);
+    }
+    require_keys_eq!(
+        *info.owner,
+        crate::ID,
+        ErrorCode::AccountOwnedByWrongProgram
+    );
+    Ok(Some(T::try_deserialize(&mut &info.try_borrow_data()?[..])?))
+}
+
+/// Close a settled registration, returning its rent
+fn collect<'info>(
+    tournament: &Account<'info, Tournament>,
+    arena_config: &ArenaConfig,
+    prize_pool: Option<&PrizePool>,
+    registration: &Account<'info, PlayerRegistration>,
+    rent_recipient: &AccountInfo<'info>,
+    prompt_escrow: &AccountInfo<'info>,
+    backing_pool: &AccountInfo<'info>,
+) -> Result<()> {
+    require_keys_eq!(
+        registration.tournament,
+        tournament.key(),
+        ArenaError::TournamentNotFound
+    );
+    require!(
+        registration.is_collectable(tournament),
+        ArenaError::RegistrationNotCollectable
+    );
+    require_keys_eq!(
+        rent_recipient.key(),
+        registration.rent_recipient(tournament, arena_config),
+        ArenaError::InvalidRentRecipient
+    );
+
+    // The bond is refunded or forfeited against the registration
+    let (prompt_escrow_key, _) = Pubkey::find_program_address(
+        &[PromptEscrow::SEED_PREFIX, registration.key().as_ref()],
+        &crate::ID,
+    );
+    require_keys_eq!(
+        prompt_escrow.key(),
+        prompt_escrow_key,
+        ErrorCode::ConstraintSeeds
+    );
+    if let Some(prompt_escrow) = load_if_created::<PromptEscrow>(prompt_escrow)? {
+        require!(
+            prompt_escrow.bond_lamports == 0,
+            ArenaError::RegistrationNotCollectable
+        );
+    }
+
+    // The player, and backers who funded a withdrawn pool, claim their prize
+    // against the registration
+    let (backing_pool_key, _) = Pubkey::find_program_address(
+        &[
+            BackingPool::SEED_PREFIX,
+            tournament.key().as_ref(),
+            registration.wallet.as_ref(),
+        ],
+        &crate::ID,
+    );
+    require_keys_eq!(
+        backing_pool.key(),
+        backing_pool_key,
+        ErrorCode::ConstraintSeeds
+    );
+    if let (Some(prize_pool), Some(final_rank)) = (prize_pool, registration.final_rank) {
+        let prize_unclaimed = !registration.disqualified
+            && !registration.forfeited
+            && prize_pool.unclaimed(final_rank).is_some();
+        let backers_paid = prize_pool.prize(final_rank).is_some()
+            && load_if_created::<BackingPool>(backing_pool)?.is_some_and(|backing_pool| {
+                backing_pool.withdrawn && backing_pool.sold_share_bps > 0
+            });
+        require!(
+            !prize_unclaimed && !backers_paid,
+            ArenaError::RegistrationNotCollectable
+        );
+    }
+
+    registration.close(rent_recipient.clone())?;
+
+    msg!(
+        "Collected registration of {} (rent to {})",
+        registration.wallet,
+        rent_recipient.key()
+    );
+
+    Ok(())
+}
+
+/// Close abandoned registrations of a closed tournament (permissionless).
+///
+/// This instruction:
+/// 1. Validates each registration is settled: fees refunded for voided
+///    tournaments, points distributed and no action shares, appeals, prize
+///    claims or prompt bonds outstanding for completed ones
+/// 2. Closes it, routing rent to whoever paid it, or to the forfeited rent
+///    recipient for voided tournaments and disqualified players
+/// 3. Removes the tournament from the wallet's registration index, if passed
+///
+/// Further registrations may be collected in the same transaction by passing
+/// (registration, rent recipient, prompt escrow, backing pool) groups as
+/// remaining accounts; their wallets' indexes are not pruned.
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, GcRegistration<'info>>) -> Result<()> {
+    let tournament = &ctx.accounts.tournament;
+    let arena_config = &ctx.accounts.arena_config;
+    let prize_pool = load_if_created::<PrizePool>(&ctx.accounts.prize_pool)?;
+
+    collect(
+        tournament,
+        arena_config,
+        prize_pool.as_ref(),
+        &ctx.accounts.registration,
+        &ctx.accounts.rent_recipient.to_account_info(),
+        &ctx.accounts.prompt_escrow.to_account_info(),
+        &ctx.accounts.backing_pool.to_account_info(),
+    )?;
+    if let Some(registration_index) = ctx.accounts.registration_index.as_mut() {
+        registration_index.remove(tournament.id);
+    }
+
+    require!(
+        ctx.remaining_accounts.len() % 4 == 0,
+        ArenaError::InvalidRentRecipient
+    );
+    for group in ctx.remaining_accounts.chunks(4) {
+        let registration = Account::<PlayerRegistration>::try_from(&group[0])?;
+        collect(
+            tournament,
+            arena_config,
+            prize_pool.as_ref(),
+            &registration,
+            &group[1],
+            &group[2],
+            &group[3],
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,124 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::seating;
+use crate::state::{
+    ArenaConfig, AuditLog, Bracket, PlayerRegistration, Tournament, TournamentFormat,
+    TournamentStatus,
+};
+
+/// Accounts required for generating a tournament's bracket.
+#[derive(Accounts)]
+pub struct GenerateBracket<'info> {
+    /// Arena admin, the tournament's delegated operator or its host; pays rent
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Arena config for admin verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Started bracket tournament
+    #[account(
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.can_run(&authority.key(), &arena_config.admin) @ ArenaError::Unauthorized,
+        constraint = tournament.status == TournamentStatus::InProgress @ ArenaError::TournamentNotInProgress,
+        constraint = tournament.is_bracket() @ ArenaError::InvalidTournamentFormat
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Bracket PDA to create
+    #[account(
+        init,
+        payer = authority,
+        space = Bracket::SIZE,
+        seeds = [Bracket::SEED_PREFIX, tournament.key().as_ref()],
+        bump
+    )]
+    pub bracket: Box<Account<'info, Bracket>>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Generate the bracket from the seeded draw (admin, operator or host).
+///
+/// This instruction:
+/// 1. Rebuilds the roster from the registrations in remaining accounts
+/// 2. Requires the roster to be complete (matches registered_players)
+/// 3. Orders it by the seat draw keyed on seed_blockhash
+/// 4. Sizes the bracket to the next power of two; the earliest drawn
+///    players receive any byes
+///
+/// Double elimination needs a full bracket: a power of two of at least 4.
+///
+/// First-round matches are then opened with `open_bracket_match`.
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, GenerateBracket<'info>>) -> Result<()> {
+    let tournament = &ctx.accounts.tournament;
+    let tournament_key = tournament.key();
+
+    let mut roster = Vec::with_capacity(ctx.remaining_accounts.len());
+    for info in ctx.remaining_accounts {
+        let registration = Account::<PlayerRegistration>::try_from(info)?;
+        require!(
+            registration.tournament == tournament_key,
+            ArenaError::TournamentNotFound
+        );
+        if !registration.disqualified {
+            roster.push(registration.wallet);
+        }
+    }
+
+    roster.sort();
+    roster.dedup();
+    require!(
+        roster.len() == tournament.registered_players as usize
+            && (2..=Bracket::MAX_ENTRANTS).contains(&roster.len()),
+        ArenaError::InvalidBracketMatch
+    );
+
+    let double_elimination = tournament.format == TournamentFormat::DoubleElimination;
+    require!(
+        !double_elimination || (roster.len() >= 4 && roster.len().is_power_of_two()),
+        ArenaError::InvalidBracketMatch
+    );
+
+    let size = roster.len().next_power_of_two();
+    let bracket = &mut ctx.accounts.bracket;
+    bracket.tournament = tournament_key;
+    bracket.size = size as u16;
+    bracket.rounds = size.trailing_zeros() as u8;
+    bracket.double_elimination = double_elimination;
+    bracket.draw = seating::draw_order(&tournament.seed_blockhash, &roster);
+    bracket.matches_completed = 0;
+    bracket.champion = None;
+    bracket.bump = ctx.bumps.bracket;
+
+    msg!(
+        "Bracket generated for tournament {}: {} entrants, {} rounds",
+        tournament.id,
+        roster.len(),
+        bracket.rounds
+    );
+
+    AuditLog::record_call::<instruction::GenerateBracket>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.authority.key(),
+        ctx.accounts.tournament.id,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditEntry, AuditLog};
+
+/// Accounts required for creating the audit log.
+#[derive(Accounts)]
+pub struct InitAuditLog<'info> {
+    /// Admin wallet (must match arena config admin)
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// Arena config for admin verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Audit log PDA to be created
+    #[account(
+        init,
+        payer = admin,
+        space = AuditLog::SIZE,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the log of admin and operator calls for an arena initialized
+/// without one (admin only).
+///
+/// Every admin and operator instruction requires the log as its trailing
+/// `audit_log` account and records the signer, instruction, tournament and
+/// slot into it, so none of them can run until it exists.
+pub fn handler(ctx: Context<InitAuditLog>) -> Result<()> {
+    let audit_log = &mut ctx.accounts.audit_log;
+    audit_log.next_sequence = 0;
+    audit_log.entries = [AuditEntry::default(); AuditLog::MAX_ENTRIES];
+    audit_log.bump = ctx.bumps.audit_log;
+
+    AuditLog::record_call::<instruction::InitAuditLog>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        0,
+    )?;
+
+    msg!("Audit log initialized");
+
+    Ok(())
+}
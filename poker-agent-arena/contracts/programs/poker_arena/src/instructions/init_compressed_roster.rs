@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+
+use crate::compression::{self, TreeAccounts, SPL_ACCOUNT_COMPRESSION_ID, SPL_NOOP_ID};
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog, CompressedRoster, Tournament, TournamentStatus};
+
+/// Accounts required for enabling compressed registrations on a tournament.
+#[derive(Accounts)]
+pub struct InitCompressedRoster<'info> {
+    /// Admin wallet - must match arena_config.admin
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// Arena config for admin verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament - must be Created (before registration opens)
+    #[account(
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.status == TournamentStatus::Created @ ArenaError::TournamentAlreadyStarted
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Compressed roster PDA to be created (tree authority)
+    #[account(
+        init,
+        payer = admin,
+        space = CompressedRoster::SIZE,
+        seeds = [CompressedRoster::SEED_PREFIX, tournament.key().as_ref()],
+        bump
+    )]
+    pub compressed_roster: Account<'info, CompressedRoster>,
+
+    /// Pre-allocated, zeroed tree account owned by the compression program
+    /// CHECK: Validated by the account compression program during initialization
+    #[account(mut)]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    /// SPL account compression program
+    /// CHECK: Validated by address
+    #[account(address = SPL_ACCOUNT_COMPRESSION_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+
+    /// SPL noop program
+    /// CHECK: Validated by address
+    #[account(address = SPL_NOOP_ID)]
+    pub noop_program: UncheckedAccount<'info>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Enable compressed registrations for a tournament (admin only).
+///
+/// This instruction:
+/// 1. Creates the CompressedRoster PDA that acts as tree authority
+/// 2. Initializes the empty concurrent Merkle tree via CPI
+///
+/// # Arguments
+/// * `max_depth` - Tree depth (capacity is 2^max_depth registrations)
+/// * `max_buffer_size` - Concurrent changelog buffer size
+pub fn handler(ctx: Context<InitCompressedRoster>, max_depth: u32, max_buffer_size: u32) -> Result<()> {
+    let tournament_key = ctx.accounts.tournament.key();
+    let compressed_roster = &mut ctx.accounts.compressed_roster;
+
+    compressed_roster.tournament = tournament_key;
+    compressed_roster.merkle_tree = ctx.accounts.merkle_tree.key();
+    compressed_roster.leaf_count = 0;
+    compressed_roster.bump = ctx.bumps.compressed_roster;
+
+    let seeds = &[
+        CompressedRoster::SEED_PREFIX,
+        tournament_key.as_ref(),
+        &[compressed_roster.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    compression::init_empty_merkle_tree(
+        &TreeAccounts {
+            compression_program: &ctx.accounts.compression_program.to_account_info(),
+            merkle_tree: &ctx.accounts.merkle_tree.to_account_info(),
+            authority: &compressed_roster.to_account_info(),
+            noop: &ctx.accounts.noop_program.to_account_info(),
+        },
+        max_depth,
+        max_buffer_size,
+        signer_seeds,
+    )?;
+
+    msg!("Compressed roster enabled for tournament {}", ctx.accounts.tournament.id);
+    msg!("Merkle tree: {}", compressed_roster.merkle_tree);
+
+    AuditLog::record_call::<instruction::InitCompressedRoster>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        ctx.accounts.tournament.id,
+    )?;
+
+    Ok(())
+}
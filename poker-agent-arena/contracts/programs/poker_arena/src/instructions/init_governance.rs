@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog, Governance};
+
+/// Accounts required for initializing governance.
+#[derive(Accounts)]
+pub struct InitGovernance<'info> {
+    /// Admin wallet - must match arena_config.admin
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// Arena config for admin verification and points mint
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Governance config PDA
+    #[account(
+        init,
+        payer = admin,
+        space = Governance::SIZE,
+        seeds = [Governance::SEED_PREFIX],
+        bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    /// Token account holding staked POINTS (owned by governance PDA)
+    #[account(
+        init,
+        payer = admin,
+        token::mint = points_mint,
+        token::authority = governance,
+        seeds = [Governance::VAULT_SEED_PREFIX],
+        bump
+    )]
+    pub stake_vault: Account<'info, TokenAccount>,
+
+    /// POINTS SPL token mint
+    #[account(
+        constraint = points_mint.key() == arena_config.points_mint @ ArenaError::InvalidTierPayment
+    )]
+    pub points_mint: Account<'info, Mint>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+
+    /// Rent sysvar
+    pub rent: Sysvar<'info, Rent>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Initialize POINTS-weighted governance (admin only).
+///
+/// # Arguments
+/// * `quorum_points` - Minimum POINTS in favor for a proposal to pass (base units)
+pub fn handler(ctx: Context<InitGovernance>, quorum_points: u64) -> Result<()> {
+    let governance = &mut ctx.accounts.governance;
+
+    governance.proposal_count = 0;
+    governance.quorum_points = quorum_points;
+    governance.bump = ctx.bumps.governance;
+
+    msg!(
+        "Governance initialized with quorum of {} POINTS",
+        quorum_points
+    );
+
+    AuditLog::record_call::<instruction::InitGovernance>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        0,
+    )?;
+
+    Ok(())
+}
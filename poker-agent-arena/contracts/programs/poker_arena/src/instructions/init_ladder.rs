@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog, Ladder};
+
+/// Accounts required for creating the heads-up ladder.
+#[derive(Accounts)]
+pub struct InitLadder<'info> {
+    /// Admin wallet (must match arena config admin)
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// Arena config for admin verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Ladder PDA to create
+    #[account(
+        init,
+        payer = admin,
+        space = Ladder::SIZE,
+        seeds = [Ladder::SEED_PREFIX],
+        bump
+    )]
+    pub ladder: Account<'info, Ladder>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Create the heads-up ladder in its first season (admin only).
+///
+/// # Arguments
+/// * `division_floors` - Minimum ELO for Silver, Gold, Platinum, Diamond and Master
+/// * `k_factor` - ELO K-factor applied to each match
+pub fn handler(ctx: Context<InitLadder>, division_floors: [u16; 5], k_factor: u16) -> Result<()> {
+    let ladder = &mut ctx.accounts.ladder;
+    ladder.configure(division_floors, k_factor)?;
+    ladder.season = 1;
+    ladder.season_started_at = Clock::get()?.unix_timestamp;
+    ladder.bump = ctx.bumps.ladder;
+
+    msg!(
+        "Ladder created with floors {:?}, K-factor {}",
+        division_floors,
+        k_factor
+    );
+
+    AuditLog::record_call::<instruction::InitLadder>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        0,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::state::RegistrationIndex;
+
+/// Accounts required for creating a wallet's registration index.
+#[derive(Accounts)]
+pub struct InitRegistrationIndex<'info> {
+    /// Wallet the index is for; pays rent
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    /// Registration index PDA to be created
+    #[account(
+        init,
+        payer = wallet,
+        space = RegistrationIndex::SIZE,
+        seeds = [RegistrationIndex::SEED_PREFIX, wallet.key().as_ref()],
+        bump
+    )]
+    pub registration_index: Account<'info, RegistrationIndex>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Create an empty registration index for the signing wallet.
+///
+/// Registrations passing the index append their tournament to it, and
+/// `gc_registration` prunes it when the registration is collected.
+pub fn handler(ctx: Context<InitRegistrationIndex>) -> Result<()> {
+    let registration_index = &mut ctx.accounts.registration_index;
+    registration_index.wallet = ctx.accounts.wallet.key();
+    registration_index.count = 0;
+    registration_index.tournament_ids = [0; RegistrationIndex::MAX_ENTRIES];
+    registration_index.bump = ctx.bumps.registration_index;
+
+    msg!(
+        "Registration index created for {}",
+        registration_index.wallet
+    );
+
+    Ok(())
+}
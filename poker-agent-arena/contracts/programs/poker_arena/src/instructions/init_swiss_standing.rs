@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::state::{PlayerRegistration, SwissEvent, SwissStanding};
+
+/// Accounts required for opening a player's Swiss standing.
+#[derive(Accounts)]
+pub struct InitSwissStanding<'info> {
+    /// Anyone may open a standing; pays rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Swiss schedule - standings open only before the first pairing
+    #[account(
+        seeds = [SwissEvent::SEED_PREFIX, swiss_event.tournament.as_ref()],
+        bump = swiss_event.bump,
+        constraint = swiss_event.round == 0 @ ArenaError::InvalidSwissRound
+    )]
+    pub swiss_event: Box<Account<'info, SwissEvent>>,
+
+    /// Player's registration for the tournament
+    #[account(
+        seeds = [PlayerRegistration::SEED_PREFIX, swiss_event.tournament.as_ref(), registration.wallet.as_ref()],
+        bump = registration.bump,
+        constraint = !registration.disqualified @ ArenaError::PlayerDisqualified
+    )]
+    pub registration: Account<'info, PlayerRegistration>,
+
+    /// Standing PDA to create
+    #[account(
+        init,
+        payer = payer,
+        space = SwissStanding::SIZE,
+        seeds = [SwissStanding::SEED_PREFIX, swiss_event.tournament.as_ref(), registration.wallet.as_ref()],
+        bump
+    )]
+    pub swiss_standing: Account<'info, SwissStanding>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Open a registered player's Swiss standing (permissionless).
+pub fn handler(ctx: Context<InitSwissStanding>) -> Result<()> {
+    let swiss_standing = &mut ctx.accounts.swiss_standing;
+    swiss_standing.tournament = ctx.accounts.swiss_event.tournament;
+    swiss_standing.wallet = ctx.accounts.registration.wallet;
+    swiss_standing.wins = 0;
+    swiss_standing.losses = 0;
+    swiss_standing.byes = 0;
+    swiss_standing.last_round = 0;
+    swiss_standing.bump = ctx.bumps.swiss_standing;
+
+    msg!("Swiss standing opened for {}", swiss_standing.wallet);
+
+    Ok(())
+}
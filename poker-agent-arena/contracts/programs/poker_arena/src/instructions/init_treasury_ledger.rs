@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog, LedgerEntry, TreasuryLedger};
+
+/// Accounts required for creating the treasury ledger.
+#[derive(Accounts)]
+pub struct InitTreasuryLedger<'info> {
+    /// Admin wallet (must match arena config admin)
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// Arena config for admin verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Treasury ledger PDA to be created
+    #[account(
+        init,
+        payer = admin,
+        space = TreasuryLedger::SIZE,
+        seeds = [TreasuryLedger::SEED_PREFIX],
+        bump
+    )]
+    pub treasury_ledger: Box<Account<'info, TreasuryLedger>>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Create the ledger of program-owned vault inflows and outflows (admin only).
+///
+/// Vault instructions record into it when it is passed as their trailing
+/// `treasury_ledger` account.
+pub fn handler(ctx: Context<InitTreasuryLedger>) -> Result<()> {
+    let treasury_ledger = &mut ctx.accounts.treasury_ledger;
+    treasury_ledger.next_sequence = 0;
+    treasury_ledger.total_inflow_lamports = 0;
+    treasury_ledger.total_outflow_lamports = 0;
+    treasury_ledger.entries = [LedgerEntry::default(); TreasuryLedger::MAX_ENTRIES];
+    treasury_ledger.bump = ctx.bumps.treasury_ledger;
+
+    msg!("Treasury ledger initialized");
+
+    AuditLog::record_call::<instruction::InitTreasuryLedger>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        0,
+    )?;
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 
-use crate::state::ArenaConfig;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditEntry, AuditLog, CreationRateLimit};
 
 /// Accounts required for initializing the arena.
 #[derive(Accounts)]
@@ -19,11 +20,21 @@ pub struct Initialize<'info> {
     )]
     pub arena_config: Account<'info, ArenaConfig>,
 
+    /// Audit log PDA to be created, so admin calls are audited from the start
+    #[account(
+        init,
+        payer = admin,
+        space = AuditLog::SIZE,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+
     /// System program for account creation
     pub system_program: Program<'info, System>,
 }
 
-/// Initialize the arena configuration (one-time setup).
+/// Initialize the arena configuration and its audit log (one-time setup).
 ///
 /// # Arguments
 /// * `ctx` - The context containing all accounts
@@ -36,11 +47,55 @@ pub fn handler(ctx: Context<Initialize>, treasury: Pubkey, points_mint: Pubkey)
     arena_config.treasury = treasury;
     arena_config.points_mint = points_mint;
     arena_config.tournament_count = 0;
+    arena_config.crank_bounty_lamports = 0;
+    arena_config.loyalty_thresholds = [0; 3];
+    arena_config.loyalty_discount_bps = [0; 3];
+    arena_config.total_fees_collected_lamports = 0;
+    arena_config.total_fees_refunded_lamports = 0;
+    arena_config.fee_recipients = [Pubkey::default(); ArenaConfig::MAX_FEE_RECIPIENTS];
+    arena_config.fee_recipient_bps = [0; ArenaConfig::MAX_FEE_RECIPIENTS];
+    arena_config.metadata_uri = [0; 128];
+    arena_config.vesting_threshold_points = 0;
+    arena_config.vesting_cliff_seconds = 0;
+    arena_config.vesting_duration_seconds = 0;
+    arena_config.dispute_window_seconds = 0;
+    arena_config.stale_tournament_seconds = ArenaConfig::DEFAULT_STALE_TOURNAMENT_SECONDS;
+    arena_config.forfeited_rent_recipient = treasury;
+    arena_config.snapshot_count = 0;
+    arena_config.holder_discount_min_points = 0;
+    arena_config.holder_discount_bps = 0;
+    arena_config.tier_upgrade_points = [0; 2];
+    arena_config.ticket_points_per_sol = 0;
+    arena_config.timeout_penalty_threshold = 0;
+    arena_config.timeout_penalty_points = 0;
+    arena_config.feature_flags = ArenaConfig::DEFAULT_FEATURE_FLAGS;
+    arena_config.results_authority = Pubkey::default();
+    arena_config.usdc_mint = Pubkey::default();
+    arena_config.tier_fee_usd_cents = [0; 3];
+    arena_config.sol_usd_feed_id = [0; 32];
+    arena_config.late_unregister_refund_bps = 10_000;
+    arena_config.points_decimals = ArenaConfig::POINTS_DECIMALS;
+    arena_config.host_bond_lamports = 0;
+    arena_config.disabled_blind_presets = 0;
+    arena_config.max_tournaments_per_day = 0;
+    arena_config.max_open_tournaments = 0;
+    arena_config.creation_rate = CreationRateLimit::default();
+    arena_config.open_tournaments = 0;
     arena_config.bump = ctx.bumps.arena_config;
 
     msg!("Arena initialized with admin: {}", arena_config.admin);
     msg!("Treasury: {}", treasury);
     msg!("Points mint: {}", points_mint);
 
+    let audit_log = &mut ctx.accounts.audit_log;
+    audit_log.next_sequence = 0;
+    audit_log.entries = [AuditEntry::default(); AuditLog::MAX_ENTRIES];
+    audit_log.bump = ctx.bumps.audit_log;
+    AuditLog::record_call::<instruction::Initialize>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        0,
+    )?;
+
     Ok(())
 }
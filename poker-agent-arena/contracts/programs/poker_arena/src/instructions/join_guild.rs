@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::state::{Guild, GuildMember};
+
+/// Accounts required for joining a guild.
+#[derive(Accounts)]
+pub struct JoinGuild<'info> {
+    /// Wallet joining the guild; pays membership rent
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    /// Leader or officer admitting the wallet
+    pub officer: Signer<'info>,
+
+    /// Guild to join
+    #[account(
+        mut,
+        seeds = [Guild::SEED_PREFIX, guild.name.as_ref()],
+        bump = guild.bump,
+        constraint = guild.is_officer(&officer.key()) @ ArenaError::Unauthorized,
+        constraint = guild.member_count < Guild::MAX_MEMBERS @ ArenaError::GuildFull
+    )]
+    pub guild: Account<'info, Guild>,
+
+    /// Membership PDA to be created (fails if the wallet is in a guild)
+    #[account(
+        init,
+        payer = wallet,
+        space = GuildMember::SIZE,
+        seeds = [GuildMember::SEED_PREFIX, wallet.key().as_ref()],
+        bump
+    )]
+    pub guild_member: Account<'info, GuildMember>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Join a guild, admitted by its leader or an officer.
+pub fn handler(ctx: Context<JoinGuild>) -> Result<()> {
+    let guild = &mut ctx.accounts.guild;
+    guild.member_count = guild
+        .member_count
+        .checked_add(1)
+        .ok_or(ArenaError::ArithmeticOverflow)?;
+
+    let guild_member = &mut ctx.accounts.guild_member;
+    guild_member.guild = guild.key();
+    guild_member.wallet = ctx.accounts.wallet.key();
+    guild_member.joined_at = Clock::get()?.unix_timestamp;
+    guild_member.bump = ctx.bumps.guild_member;
+
+    msg!(
+        "{} joined guild {} ({}/{})",
+        guild_member.wallet,
+        guild.key(),
+        guild.member_count,
+        Guild::MAX_MEMBERS
+    );
+
+    Ok(())
+}
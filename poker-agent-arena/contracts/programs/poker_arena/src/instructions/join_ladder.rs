@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Division, Ladder, LadderRating};
+
+/// Accounts required for joining the heads-up ladder.
+#[derive(Accounts)]
+pub struct JoinLadder<'info> {
+    /// Wallet joining the ladder; pays rent for its rating
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    /// Ladder to join
+    #[account(
+        seeds = [Ladder::SEED_PREFIX],
+        bump = ladder.bump
+    )]
+    pub ladder: Account<'info, Ladder>,
+
+    /// Player's rating PDA to create
+    #[account(
+        init,
+        payer = player,
+        space = LadderRating::SIZE,
+        seeds = [LadderRating::SEED_PREFIX, player.key().as_ref()],
+        bump
+    )]
+    pub ladder_rating: Account<'info, LadderRating>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Join the heads-up ladder at the initial rating, in Bronze.
+pub fn handler(ctx: Context<JoinLadder>) -> Result<()> {
+    let ladder_rating = &mut ctx.accounts.ladder_rating;
+    ladder_rating.wallet = ctx.accounts.player.key();
+    ladder_rating.rating = Ladder::INITIAL_RATING;
+    ladder_rating.peak_rating = Ladder::INITIAL_RATING;
+    ladder_rating.division = Division::Bronze;
+    ladder_rating.season = ctx.accounts.ladder.season;
+    ladder_rating.matches_played = 0;
+    ladder_rating.matches_won = 0;
+    ladder_rating.bump = ctx.bumps.ladder_rating;
+
+    msg!("{} joined the ladder", ladder_rating.wallet);
+
+    Ok(())
+}
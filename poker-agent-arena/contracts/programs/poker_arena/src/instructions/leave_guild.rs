@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::state::{Guild, GuildMember};
+
+/// Accounts required for leaving a guild.
+#[derive(Accounts)]
+pub struct LeaveGuild<'info> {
+    /// Member wallet, or a guild officer removing them
+    pub authority: Signer<'info>,
+
+    /// Member wallet receiving the membership rent
+    /// CHECK: Must match guild_member.wallet
+    #[account(mut, address = guild_member.wallet @ ArenaError::Unauthorized)]
+    pub wallet: UncheckedAccount<'info>,
+
+    /// Guild being left
+    #[account(
+        mut,
+        seeds = [Guild::SEED_PREFIX, guild.name.as_ref()],
+        bump = guild.bump,
+        constraint = guild.leader != wallet.key() @ ArenaError::InvalidGuild
+    )]
+    pub guild: Account<'info, Guild>,
+
+    /// Membership PDA to close
+    #[account(
+        mut,
+        close = wallet,
+        seeds = [GuildMember::SEED_PREFIX, guild_member.wallet.as_ref()],
+        bump = guild_member.bump,
+        constraint = guild_member.guild == guild.key() @ ArenaError::InvalidGuild
+    )]
+    pub guild_member: Account<'info, GuildMember>,
+}
+
+/// Leave a guild, or remove a member as leader or officer.
+/// The leader cannot leave their own guild.
+pub fn handler(ctx: Context<LeaveGuild>) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    let guild = &mut ctx.accounts.guild;
+    require!(
+        authority == ctx.accounts.wallet.key() || guild.is_officer(&authority),
+        ArenaError::Unauthorized
+    );
+
+    guild.member_count = guild.member_count.saturating_sub(1);
+
+    msg!("{} left guild {}", ctx.accounts.wallet.key(), guild.key());
+
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::state::{ActionListing, ArenaConfig, PlayerRegistration, Tournament};
+
+/// Accounts required for listing a share of a player's action.
+#[derive(Accounts)]
+pub struct ListAction<'info> {
+    /// Registered player selling action
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    /// Arena config - the marketplace must be enabled
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.is_enabled(ArenaConfig::FEATURE_MARKETPLACE) @ ArenaError::FeatureDisabled
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament - registration must still be open
+    #[account(
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.is_registration_open() @ ArenaError::RegistrationNotOpen
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Seller's registration
+    #[account(
+        seeds = [PlayerRegistration::SEED_PREFIX, tournament.key().as_ref(), seller.key().as_ref()],
+        bump = registration.bump
+    )]
+    pub registration: Account<'info, PlayerRegistration>,
+
+    /// Action listing (created on first listing, replaced on relisting)
+    #[account(
+        init_if_needed,
+        payer = seller,
+        space = ActionListing::SIZE,
+        seeds = [ActionListing::SEED_PREFIX, registration.key().as_ref()],
+        bump
+    )]
+    pub action_listing: Account<'info, ActionListing>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// List a percentage of potential winnings for sale.
+///
+/// # Arguments
+/// * `available_bps` - Share offered, in basis points (replaces any unsold share)
+/// * `price_lamports_per_bps` - Price per basis point, in lamports
+pub fn handler(
+    ctx: Context<ListAction>,
+    available_bps: u16,
+    price_lamports_per_bps: u64,
+) -> Result<()> {
+    let registration = &ctx.accounts.registration;
+    require!(
+        available_bps as u32 + registration.action_sold_bps as u32 <= 10_000,
+        ArenaError::InvalidBasisPoints
+    );
+
+    let action_listing = &mut ctx.accounts.action_listing;
+    action_listing.registration = registration.key();
+    action_listing.seller = ctx.accounts.seller.key();
+    action_listing.available_bps = available_bps;
+    action_listing.price_lamports_per_bps = price_lamports_per_bps;
+    action_listing.bump = ctx.bumps.action_listing;
+
+    msg!(
+        "Listed {} bps of action at {} lamports per bps",
+        available_bps,
+        price_lamports_per_bps
+    );
+
+    Ok(())
+}
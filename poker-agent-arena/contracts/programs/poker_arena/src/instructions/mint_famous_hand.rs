@@ -0,0 +1,211 @@
+use anchor_lang::prelude::*;
+
+use crate::bubblegum::{
+    self, MetadataArgs, MintV1Accounts, TokenProgramVersion, TokenStandard, MPL_BUBBLEGUM_ID,
+};
+use crate::compression::{SPL_ACCOUNT_COMPRESSION_ID, SPL_NOOP_ID};
+use crate::errors::ArenaError;
+use crate::events::FamousHandMinted;
+use crate::instruction;
+use crate::merkle;
+use crate::state::{
+    ArenaConfig, AuditLog, FamousHand, Table, Tournament, TournamentStatus, TrophyAuthority,
+};
+
+/// Accounts required for minting a famous hand commemorative.
+#[derive(Accounts)]
+#[instruction(hand_index: u32)]
+pub struct MintFamousHand<'info> {
+    /// Arena admin, the tournament's delegated operator or its host
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Arena config for admin verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament - must be Completed with a committed hand-history root
+    #[account(
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.can_run(&authority.key(), &arena_config.admin) @ ArenaError::Unauthorized,
+        constraint = tournament.status == TournamentStatus::Completed @ ArenaError::TournamentNotCompleted
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Record of the hand (one per hand)
+    #[account(
+        init,
+        payer = authority,
+        space = FamousHand::SIZE,
+        seeds = [FamousHand::SEED_PREFIX, tournament.key().as_ref(), &hand_index.to_le_bytes()],
+        bump
+    )]
+    pub famous_hand: Account<'info, FamousHand>,
+
+    /// Trophy authority PDA (Bubblegum tree delegate)
+    #[account(
+        mut,
+        seeds = [TrophyAuthority::SEED_PREFIX],
+        bump = trophy_authority.bump
+    )]
+    pub trophy_authority: Account<'info, TrophyAuthority>,
+
+    /// Bubblegum tree config for trophy_tree
+    /// CHECK: Validated by the Bubblegum program
+    #[account(mut)]
+    pub tree_config: UncheckedAccount<'info>,
+
+    /// Bubblegum Merkle tree holding trophies
+    /// CHECK: Validated by the Bubblegum program
+    #[account(mut)]
+    pub trophy_tree: UncheckedAccount<'info>,
+
+    /// SPL noop program
+    /// CHECK: Validated by address
+    #[account(address = SPL_NOOP_ID)]
+    pub log_wrapper: UncheckedAccount<'info>,
+
+    /// SPL account compression program
+    /// CHECK: Validated by address
+    #[account(address = SPL_ACCOUNT_COMPRESSION_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+
+    /// Metaplex Bubblegum program
+    /// CHECK: Validated by address
+    #[account(address = MPL_BUBBLEGUM_ID)]
+    pub bubblegum_program: UncheckedAccount<'info>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Mint a "hand of the tournament" commemorative to its players (admin, operator or host).
+///
+/// This instruction:
+/// 1. Verifies the hand, including its participants, against the committed
+///    hand-history root
+/// 2. Records the hand index, participants and proof path on a FamousHand account
+/// 3. Mints a commemorative cNFT, whose metadata points at that account, to
+///    each participant wallet passed as remaining accounts in `participants` order
+///
+/// # Arguments
+/// * `hand_index` - Index of the hand within the tournament
+/// * `hand_hash` - SHA-256 hash of the hand history
+/// * `participants` - Wallets of the players involved in the hand
+/// * `proof` - Merkle proof path to the hand-history root
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, MintFamousHand<'info>>,
+    hand_index: u32,
+    hand_hash: [u8; 32],
+    participants: Vec<Pubkey>,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    require!(
+        !participants.is_empty()
+            && participants.len() <= Table::MAX_SEATS
+            && proof.len() <= FamousHand::MAX_PROOF_DEPTH
+            && ctx.remaining_accounts.len() == participants.len(),
+        ArenaError::InvalidFamousHand
+    );
+
+    let tournament = &ctx.accounts.tournament;
+    let root = tournament
+        .hand_history_root
+        .ok_or(ArenaError::HandHistoryRootNotCommitted)?;
+    let leaf = FamousHand::leaf(&tournament.key(), hand_index, &hand_hash, &participants);
+    require!(
+        merkle::verify_proof(&proof, &root, leaf),
+        ArenaError::InvalidMerkleProof
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let famous_hand = &mut ctx.accounts.famous_hand;
+    famous_hand.tournament = tournament.key();
+    famous_hand.hand_index = hand_index;
+    famous_hand.hand_hash = hand_hash;
+    famous_hand.participants = [Pubkey::default(); Table::MAX_SEATS];
+    famous_hand.participants[..participants.len()].copy_from_slice(&participants);
+    famous_hand.proof = [[0; 32]; FamousHand::MAX_PROOF_DEPTH];
+    famous_hand.proof[..proof.len()].copy_from_slice(&proof);
+    famous_hand.proof_len = proof.len() as u8;
+    famous_hand.minted_at = now;
+    famous_hand.bump = ctx.bumps.famous_hand;
+
+    let trophy_authority = &mut ctx.accounts.trophy_authority;
+    let metadata = MetadataArgs {
+        name: format!("Arena #{} Hand {}", tournament.id, hand_index),
+        symbol: TrophyAuthority::SYMBOL.to_string(),
+        uri: trophy_authority.famous_hand_uri(tournament.id, hand_index, &famous_hand.key()),
+        seller_fee_basis_points: 0,
+        primary_sale_happened: false,
+        is_mutable: false,
+        edition_nonce: None,
+        token_standard: Some(TokenStandard::NonFungible),
+        collection: None,
+        uses: None,
+        token_program_version: TokenProgramVersion::Original,
+        creators: vec![],
+    };
+
+    let seeds = &[TrophyAuthority::SEED_PREFIX, &[trophy_authority.bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    for (leaf_owner, participant) in ctx.remaining_accounts.iter().zip(&participants) {
+        require_keys_eq!(
+            leaf_owner.key(),
+            *participant,
+            ArenaError::InvalidFamousHand
+        );
+
+        bubblegum::mint_v1(
+            &MintV1Accounts {
+                bubblegum_program: &ctx.accounts.bubblegum_program.to_account_info(),
+                tree_config: &ctx.accounts.tree_config.to_account_info(),
+                leaf_owner,
+                merkle_tree: &ctx.accounts.trophy_tree.to_account_info(),
+                payer: &ctx.accounts.authority.to_account_info(),
+                tree_delegate: &trophy_authority.to_account_info(),
+                log_wrapper: &ctx.accounts.log_wrapper.to_account_info(),
+                compression_program: &ctx.accounts.compression_program.to_account_info(),
+                system_program: &ctx.accounts.system_program.to_account_info(),
+            },
+            &metadata,
+            signer_seeds,
+        )?;
+
+        trophy_authority.trophies_minted = trophy_authority.trophies_minted.saturating_add(1);
+    }
+
+    emit!(FamousHandMinted {
+        tournament: tournament.key(),
+        hand_index,
+        famous_hand: famous_hand.key(),
+        participants,
+    });
+
+    msg!(
+        "Famous hand {} of tournament {} minted",
+        hand_index,
+        tournament.id
+    );
+
+    AuditLog::record_call::<instruction::MintFamousHand>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.authority.key(),
+        ctx.accounts.tournament.id,
+    )?;
+
+    Ok(())
+}
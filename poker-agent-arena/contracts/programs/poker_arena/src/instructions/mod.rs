@@ -1,19 +1,361 @@
+pub mod advance_ladder_season;
+pub mod advance_shootout_round;
+pub mod apply_penalty;
+pub mod approve_gift;
+pub mod attest_results;
+pub mod back_player;
+pub mod burn_expired_airdrop;
+pub mod buy_action;
+pub mod buy_season_pass;
+pub mod buy_ticket_with_points;
+pub mod buyback_and_burn;
+pub mod cast_vote;
+pub mod claim_action_prize;
+pub mod claim_action_share;
+pub mod claim_airdrop;
+pub mod claim_backer_points;
+pub mod claim_backer_prize;
+pub mod claim_compressed_points;
+pub mod claim_daily_ticket;
+pub mod claim_guild_prize;
+pub mod claim_points_with_proof;
+pub mod claim_prize;
+pub mod claim_season_reward;
+pub mod claim_vested_points;
+pub mod close_payment_receipt;
+pub mod commit_action_log;
+pub mod commit_encrypted_prompt;
+pub mod commit_game_log;
+pub mod commit_hand_history_root;
+pub mod commit_points_root;
+pub mod commit_time_bank;
+pub mod confirm_results;
+pub mod crank_start_tournament;
+pub mod create_airdrop;
+pub mod create_fee_escrow;
+pub mod create_guild;
+pub mod create_host_tournament;
+pub mod create_invites;
 pub mod create_points_mint;
+pub mod create_prize_pool;
+pub mod create_proposal;
+pub mod create_season;
+pub mod create_season_mint;
+pub mod create_session;
+pub mod create_shootout;
+pub mod create_swiss_event;
+pub mod create_table;
 pub mod create_tournament;
+pub mod delegate_tournament_operator;
+pub mod discard_voided_result;
+pub mod dispute_tournament;
+pub mod disqualify_player;
 pub mod distribute_points;
-pub mod finalize_tournament;
+pub mod enter_guild_event;
+pub mod execute_mint_authority_change;
+pub mod execute_proposal;
+pub mod expand_tournament;
+pub mod expire_tournament;
+pub mod finalize_guild_standings;
+pub mod forfeit;
+pub mod forfeit_prompt_bond;
+pub mod freeze_player_points;
+pub mod fund_crank_vault;
+pub mod fund_guild_vault;
+pub mod fund_prize_pool;
+pub mod fund_redemption_vault;
+pub mod fund_season_rewards;
+pub mod gc_registration;
+pub mod generate_bracket;
+pub mod gift_registration;
+pub mod init_audit_log;
+pub mod init_compressed_roster;
+pub mod init_governance;
+pub mod init_ladder;
+pub mod init_registration_index;
+pub mod init_swiss_standing;
+pub mod init_treasury_ledger;
 pub mod initialize;
+pub mod join_guild;
+pub mod join_ladder;
+pub mod leave_guild;
+pub mod list_action;
+pub mod mint_famous_hand;
+pub mod open_appeal;
+pub mod open_backing;
+pub mod open_bracket_match;
 pub mod open_registration;
+pub mod open_shootout_table;
+pub mod pair_swiss_round;
+pub mod post_commentary;
+pub mod post_host_bond;
+pub mod publish_results_attestation;
+pub mod queue_asset_recovery;
+pub mod queue_mint_authority_change;
+pub mod record_bracket_match;
+pub mod record_compressed_result;
+pub mod record_elimination;
+pub mod record_highlight;
+pub mod record_ladder_match;
 pub mod record_player_result;
+pub mod record_shootout_table;
+pub mod record_swiss_result;
+pub mod recover_stranded_assets;
+pub mod redeem_invite;
+pub mod redeem_points;
+pub mod refund_backing;
+pub mod refund_escrowed_fee;
+pub mod refund_voided_registration;
 pub mod register_player;
+pub mod register_player_compressed;
+pub mod register_player_with_session;
+pub mod register_reserved;
+pub mod release_fee_escrow;
+pub mod release_points;
+pub mod resolve_appeal;
+pub mod resolve_dispute;
+pub mod reveal_prompt;
+pub mod reveal_prompt_key;
+pub mod revoke_gift_approval;
+pub mod revoke_invite;
+pub mod revoke_session;
+pub mod roll_over_ladder_rating;
+pub mod set_arbiter_set;
+pub mod set_arena_metadata_uri;
+pub mod set_buyback_bps;
+pub mod set_commentator;
+pub mod set_cpi_partner;
+pub mod set_crank_bounty;
+pub mod set_dispute_window;
+pub mod set_feature_flags;
+pub mod set_fee_recipients;
+pub mod set_forfeited_rent_recipient;
+pub mod set_guild_event;
+pub mod set_holder_discount;
+pub mod set_host;
+pub mod set_host_bond;
+pub mod set_ladder_config;
+pub mod set_late_registration;
+pub mod set_late_unregister_refund;
+pub mod set_loyalty_config;
+pub mod set_model_approval;
+pub mod set_participation_trophies;
+pub mod set_player_profile;
+pub mod set_points_formula;
+pub mod set_redemption_rate;
+pub mod set_registration_windows;
+pub mod set_results_authority;
+pub mod set_season_mint;
+pub mod set_season_revenue_share;
+pub mod set_stale_tournament_period;
+pub mod set_ticket_rate;
+pub mod set_tier_upgrade_points;
+pub mod set_timeout_penalty;
+pub mod set_tournament_division;
+pub mod set_tournament_format;
+pub mod set_tournament_rate_limits;
+pub mod set_trophy_metadata_uri;
+pub mod set_usd_tier_fees;
+pub mod set_usdc_mint;
+pub mod set_vesting_policy;
+pub mod slash_host_bond;
+pub mod snapshot_leaderboard;
+pub mod stake_points;
 pub mod start_tournament;
+pub mod submit_results;
+pub mod sweep_prize_dust;
+pub mod sync_progress;
+pub mod thaw_player_points;
+pub mod unregister_player;
+pub mod unstake_points;
+pub mod update_agent;
+pub mod update_guild;
+pub mod update_table;
+pub mod upgrade_tier_with_points;
+pub mod verify_seat_draw;
+pub mod void_tournament;
+pub mod vote_on_appeal;
+pub mod withdraw_backing;
+pub mod withdraw_guild_vault;
+pub mod withdraw_host_bond;
 
+pub use advance_ladder_season::*;
+pub use advance_shootout_round::*;
+pub use apply_penalty::*;
+pub use approve_gift::*;
+pub use attest_results::*;
+pub use back_player::*;
+pub use burn_expired_airdrop::*;
+pub use buy_action::*;
+pub use buy_season_pass::*;
+pub use buy_ticket_with_points::*;
+pub use buyback_and_burn::*;
+pub use cast_vote::*;
+pub use claim_action_prize::*;
+pub use claim_action_share::*;
+pub use claim_airdrop::*;
+pub use claim_backer_points::*;
+pub use claim_backer_prize::*;
+pub use claim_compressed_points::*;
+pub use claim_daily_ticket::*;
+pub use claim_guild_prize::*;
+pub use claim_points_with_proof::*;
+pub use claim_prize::*;
+pub use claim_season_reward::*;
+pub use claim_vested_points::*;
+pub use close_payment_receipt::*;
+pub use commit_action_log::*;
+pub use commit_encrypted_prompt::*;
+pub use commit_game_log::*;
+pub use commit_hand_history_root::*;
+pub use commit_points_root::*;
+pub use commit_time_bank::*;
+pub use confirm_results::*;
+pub use crank_start_tournament::*;
+pub use create_airdrop::*;
+pub use create_fee_escrow::*;
+pub use create_guild::*;
+pub use create_host_tournament::*;
+pub use create_invites::*;
 pub use create_points_mint::*;
+pub use create_prize_pool::*;
+pub use create_proposal::*;
+pub use create_season::*;
+pub use create_season_mint::*;
+pub use create_session::*;
+pub use create_shootout::*;
+pub use create_swiss_event::*;
+pub use create_table::*;
 pub use create_tournament::*;
+pub use delegate_tournament_operator::*;
+pub use discard_voided_result::*;
+pub use dispute_tournament::*;
+pub use disqualify_player::*;
 pub use distribute_points::*;
-pub use finalize_tournament::*;
+pub use enter_guild_event::*;
+pub use execute_mint_authority_change::*;
+pub use execute_proposal::*;
+pub use expand_tournament::*;
+pub use expire_tournament::*;
+pub use finalize_guild_standings::*;
+pub use forfeit::*;
+pub use forfeit_prompt_bond::*;
+pub use freeze_player_points::*;
+pub use fund_crank_vault::*;
+pub use fund_guild_vault::*;
+pub use fund_prize_pool::*;
+pub use fund_redemption_vault::*;
+pub use fund_season_rewards::*;
+pub use gc_registration::*;
+pub use generate_bracket::*;
+pub use gift_registration::*;
+pub use init_audit_log::*;
+pub use init_compressed_roster::*;
+pub use init_governance::*;
+pub use init_ladder::*;
+pub use init_registration_index::*;
+pub use init_swiss_standing::*;
+pub use init_treasury_ledger::*;
 pub use initialize::*;
+pub use join_guild::*;
+pub use join_ladder::*;
+pub use leave_guild::*;
+pub use list_action::*;
+pub use mint_famous_hand::*;
+pub use open_appeal::*;
+pub use open_backing::*;
+pub use open_bracket_match::*;
 pub use open_registration::*;
+pub use open_shootout_table::*;
+pub use pair_swiss_round::*;
+pub use post_commentary::*;
+pub use post_host_bond::*;
+pub use publish_results_attestation::*;
+pub use queue_asset_recovery::*;
+pub use queue_mint_authority_change::*;
+pub use record_bracket_match::*;
+pub use record_compressed_result::*;
+pub use record_elimination::*;
+pub use record_highlight::*;
+pub use record_ladder_match::*;
 pub use record_player_result::*;
+pub use record_shootout_table::*;
+pub use record_swiss_result::*;
+pub use recover_stranded_assets::*;
+pub use redeem_invite::*;
+pub use redeem_points::*;
+pub use refund_backing::*;
+pub use refund_escrowed_fee::*;
+pub use refund_voided_registration::*;
 pub use register_player::*;
+pub use register_player_compressed::*;
+pub use register_player_with_session::*;
+pub use register_reserved::*;
+pub use release_fee_escrow::*;
+pub use release_points::*;
+pub use resolve_appeal::*;
+pub use resolve_dispute::*;
+pub use reveal_prompt::*;
+pub use reveal_prompt_key::*;
+pub use revoke_gift_approval::*;
+pub use revoke_invite::*;
+pub use revoke_session::*;
+pub use roll_over_ladder_rating::*;
+pub use set_arbiter_set::*;
+pub use set_arena_metadata_uri::*;
+pub use set_buyback_bps::*;
+pub use set_commentator::*;
+pub use set_cpi_partner::*;
+pub use set_crank_bounty::*;
+pub use set_dispute_window::*;
+pub use set_feature_flags::*;
+pub use set_fee_recipients::*;
+pub use set_forfeited_rent_recipient::*;
+pub use set_guild_event::*;
+pub use set_holder_discount::*;
+pub use set_host::*;
+pub use set_host_bond::*;
+pub use set_ladder_config::*;
+pub use set_late_registration::*;
+pub use set_late_unregister_refund::*;
+pub use set_loyalty_config::*;
+pub use set_model_approval::*;
+pub use set_participation_trophies::*;
+pub use set_player_profile::*;
+pub use set_points_formula::*;
+pub use set_redemption_rate::*;
+pub use set_registration_windows::*;
+pub use set_results_authority::*;
+pub use set_season_mint::*;
+pub use set_season_revenue_share::*;
+pub use set_stale_tournament_period::*;
+pub use set_ticket_rate::*;
+pub use set_tier_upgrade_points::*;
+pub use set_timeout_penalty::*;
+pub use set_tournament_division::*;
+pub use set_tournament_format::*;
+pub use set_tournament_rate_limits::*;
+pub use set_trophy_metadata_uri::*;
+pub use set_usd_tier_fees::*;
+pub use set_usdc_mint::*;
+pub use set_vesting_policy::*;
+pub use slash_host_bond::*;
+pub use snapshot_leaderboard::*;
+pub use stake_points::*;
 pub use start_tournament::*;
+pub use submit_results::*;
+pub use sweep_prize_dust::*;
+pub use sync_progress::*;
+pub use thaw_player_points::*;
+pub use unregister_player::*;
+pub use unstake_points::*;
+pub use update_agent::*;
+pub use update_guild::*;
+pub use update_table::*;
+pub use upgrade_tier_with_points::*;
+pub use verify_seat_draw::*;
+pub use void_tournament::*;
+pub use vote_on_appeal::*;
+pub use withdraw_backing::*;
+pub use withdraw_guild_vault::*;
+pub use withdraw_host_bond::*;
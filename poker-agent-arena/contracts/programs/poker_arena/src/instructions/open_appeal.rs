@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::events::AppealOpened;
+use crate::state::{Appeal, AppealStatus, PlayerRegistration, Tournament};
+
+/// Accounts required for opening an appeal.
+#[derive(Accounts)]
+pub struct OpenAppeal<'info> {
+    /// Sanctioned player
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    /// Tournament the sanction relates to
+    #[account(
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Player's sanctioned registration
+    #[account(
+        mut,
+        seeds = [PlayerRegistration::SEED_PREFIX, tournament.key().as_ref(), player.key().as_ref()],
+        bump = registration.bump,
+        constraint = registration.tournament == tournament.key() @ ArenaError::TournamentNotFound,
+        constraint = registration.sanctioned_at != 0 @ ArenaError::AppealNotAllowed,
+        constraint = !registration.appeal_pending @ ArenaError::AppealPending
+    )]
+    pub registration: Account<'info, PlayerRegistration>,
+
+    /// Appeal PDA to be created for the latest sanction
+    #[account(
+        init,
+        payer = player,
+        space = Appeal::SIZE,
+        seeds = [Appeal::SEED_PREFIX, registration.key().as_ref(), &registration.sanction_nonce.to_le_bytes()],
+        bump
+    )]
+    pub appeal: Account<'info, Appeal>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Open an appeal against a penalty or disqualification.
+///
+/// This instruction:
+/// 1. Validates the appeal window since the last sanction is still open
+/// 2. Creates the Appeal account in Open status for the registration's
+///    current sanction nonce, covering every sanction since the last appeal
+///    decision
+/// 3. Flags the registration so further slashing, and the player's POINTS,
+///    stall until resolution
+///
+/// # Arguments
+/// * `reason_hash` - SHA-256 hash of the off-chain appeal statement
+pub fn handler(ctx: Context<OpenAppeal>, reason_hash: [u8; 32]) -> Result<()> {
+    let registration = &mut ctx.accounts.registration;
+    let appeal = &mut ctx.accounts.appeal;
+    let clock = Clock::get()?;
+
+    require!(
+        registration.sanction_appealable(clock.unix_timestamp),
+        ArenaError::AppealNotAllowed
+    );
+
+    appeal.tournament = ctx.accounts.tournament.key();
+    appeal.registration = registration.key();
+    appeal.wallet = registration.wallet;
+    appeal.reason_hash = reason_hash;
+    appeal.status = AppealStatus::Open;
+    appeal.opened_at = clock.unix_timestamp;
+    appeal.resolved_at = None;
+    appeal.sanction_nonce = registration.sanction_nonce;
+    appeal.arbiter_set_version = 0;
+    appeal.grant_mask = 0;
+    appeal.deny_mask = 0;
+    appeal.bump = ctx.bumps.appeal;
+
+    registration.appeal_pending = true;
+
+    emit!(AppealOpened {
+        tournament: appeal.tournament,
+        wallet: appeal.wallet,
+        reason_hash,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Appeal opened by player: {}", appeal.wallet);
+
+    Ok(())
+}
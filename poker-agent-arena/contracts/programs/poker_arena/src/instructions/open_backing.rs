@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::state::{BackingPool, Tournament, TournamentStatus};
+
+/// Accounts required for opening a backing pool.
+#[derive(Accounts)]
+pub struct OpenBacking<'info> {
+    /// Player seeking backing
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    /// Tournament - must be Created or in Registration
+    #[account(
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.status == TournamentStatus::Created
+            || tournament.status == TournamentStatus::Registration @ ArenaError::RegistrationNotOpen
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Backing pool PDA
+    #[account(
+        init,
+        payer = player,
+        space = BackingPool::SIZE,
+        seeds = [BackingPool::SEED_PREFIX, tournament.key().as_ref(), player.key().as_ref()],
+        bump
+    )]
+    pub backing_pool: Account<'info, BackingPool>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Open a backing pool for a tournament entry.
+///
+/// # Arguments
+/// * `target_lamports` - Lamports to raise (typically the tier fee)
+/// * `offered_share_bps` - Share of winnings sold for the full target (basis points)
+pub fn handler(
+    ctx: Context<OpenBacking>,
+    target_lamports: u64,
+    offered_share_bps: u16,
+) -> Result<()> {
+    require!(
+        offered_share_bps > 0 && offered_share_bps <= 10_000,
+        ArenaError::InvalidBasisPoints
+    );
+    require!(target_lamports > 0, ArenaError::InvalidTierPayment);
+
+    let backing_pool = &mut ctx.accounts.backing_pool;
+    backing_pool.tournament = ctx.accounts.tournament.key();
+    backing_pool.player = ctx.accounts.player.key();
+    backing_pool.target_lamports = target_lamports;
+    backing_pool.offered_share_bps = offered_share_bps;
+    backing_pool.raised_lamports = 0;
+    backing_pool.sold_share_bps = 0;
+    backing_pool.withdrawn = false;
+    backing_pool.points_awarded = 0;
+    backing_pool.points_settled = false;
+    backing_pool.bump = ctx.bumps.backing_pool;
+
+    msg!(
+        "Backing pool opened: {} lamports for {} bps",
+        target_lamports,
+        offered_share_bps
+    );
+
+    Ok(())
+}
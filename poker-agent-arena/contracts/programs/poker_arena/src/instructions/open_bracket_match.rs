@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instructions::record_bracket_match::seat_in;
+use crate::seating;
+use crate::state::{Bracket, BracketMatch, BracketSide, MatchSlot, MatchStatus};
+
+/// Accounts required for opening a first-round bracket match.
+#[derive(Accounts)]
+#[instruction(index: u16)]
+pub struct OpenBracketMatch<'info> {
+    /// Anyone may open a match; pays rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Bracket the match belongs to
+    #[account(
+        mut,
+        seeds = [Bracket::SEED_PREFIX, bracket.tournament.as_ref()],
+        bump = bracket.bump
+    )]
+    pub bracket: Box<Account<'info, Bracket>>,
+
+    /// First-round match PDA to create
+    #[account(
+        init,
+        payer = payer,
+        space = BracketMatch::SIZE,
+        seeds = [
+            BracketMatch::SEED_PREFIX,
+            bracket.key().as_ref(),
+            &BracketMatch::position(BracketSide::Winners, 0, index)
+        ],
+        bump
+    )]
+    pub bracket_match: Account<'info, BracketMatch>,
+
+    /// Second-round match the winner of a bye advances into (omit otherwise)
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = BracketMatch::SIZE,
+        seeds = [
+            BracketMatch::SEED_PREFIX,
+            bracket.key().as_ref(),
+            &BracketMatch::position(BracketSide::Winners, 1, index / 2)
+        ],
+        bump
+    )]
+    pub next_match: Option<Account<'info, BracketMatch>>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Open a first-round match from the seeded draw (permissionless).
+///
+/// A match against a bye completes immediately and its player advances
+/// into `next_match`.
+///
+/// # Arguments
+/// * `index` - Position of the match in the first round
+pub fn handler(ctx: Context<OpenBracketMatch>, index: u16) -> Result<()> {
+    let bracket = &mut ctx.accounts.bracket;
+    require!(
+        index < bracket.matches_in_round(0),
+        ArenaError::InvalidBracketMatch
+    );
+
+    let (player_a, player_b) =
+        seating::bracket_pairing(&bracket.draw, bracket.size as usize, index as usize);
+    let bracket_match = &mut ctx.accounts.bracket_match;
+    bracket_match.init(
+        bracket.key(),
+        MatchSlot::new(BracketSide::Winners, 0, index, 0),
+        ctx.bumps.bracket_match,
+    );
+    bracket_match.seat(0, player_a);
+    bracket_match.seat(1, player_b);
+
+    if bracket_match.status == MatchStatus::Ready {
+        msg!(
+            "Bracket match {} opened: {} vs {}",
+            index,
+            player_a,
+            player_b
+        );
+        return Ok(());
+    }
+
+    // The drawn player has a bye; byes only occur in single elimination,
+    // where the first round is never the final
+    bracket_match.complete(player_a, Clock::get()?.unix_timestamp);
+    bracket.matches_completed = bracket.matches_completed.saturating_add(1);
+    let destination = bracket
+        .winner_destination(BracketSide::Winners, 0, index)
+        .ok_or(ArenaError::InvalidBracketMatch)?;
+    seat_in(
+        bracket.key(),
+        destination,
+        ctx.accounts.next_match.as_mut(),
+        ctx.bumps.next_match,
+        player_a,
+    )?;
+
+    msg!(
+        "Bracket match {} opened: {} advances on a bye",
+        index,
+        player_a
+    );
+
+    Ok(())
+}
@@ -1,19 +1,19 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::ArenaError;
-use crate::state::{ArenaConfig, Tournament, TournamentStatus};
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog, Tournament, TournamentStatus};
 
 /// Accounts required for opening tournament registration.
 #[derive(Accounts)]
 pub struct OpenRegistration<'info> {
-    /// Admin wallet (must match arena config admin)
+    /// Admin wallet, the tournament's operator or its host
     pub admin: Signer<'info>,
 
     /// Arena config account (for admin verification)
     #[account(
         seeds = [ArenaConfig::SEED_PREFIX],
-        bump = arena_config.bump,
-        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+        bump = arena_config.bump
     )]
     pub arena_config: Account<'info, ArenaConfig>,
 
@@ -22,15 +22,25 @@ pub struct OpenRegistration<'info> {
         mut,
         seeds = [
             Tournament::SEED_PREFIX,
+            tournament.host_seed(),
             tournament.id.to_le_bytes().as_ref()
         ],
         bump = tournament.bump,
+        constraint = tournament.can_run(&admin.key(), &arena_config.admin) @ ArenaError::Unauthorized,
         constraint = tournament.status == TournamentStatus::Created @ ArenaError::TournamentAlreadyStarted
     )]
     pub tournament: Account<'info, Tournament>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
 }
 
-/// Open registration for a tournament (admin only).
+/// Open registration for a tournament (admin, operator or host).
 /// Changes tournament status from Created to Registration.
 pub fn handler(ctx: Context<OpenRegistration>) -> Result<()> {
     let tournament = &mut ctx.accounts.tournament;
@@ -43,5 +53,11 @@ pub fn handler(ctx: Context<OpenRegistration>) -> Result<()> {
         tournament.id
     );
 
+    AuditLog::record_call::<instruction::OpenRegistration>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        ctx.accounts.tournament.id,
+    )?;
+
     Ok(())
 }
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::seating;
+use crate::state::{Shootout, Table, Tournament};
+
+/// Accounts required for opening a shootout table.
+#[derive(Accounts)]
+#[instruction(index: u16)]
+pub struct OpenShootoutTable<'info> {
+    /// Anyone may open a table; pays rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Tournament whose seed_blockhash drives the draw
+    #[account(
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Tournament's shootout schedule
+    #[account(
+        seeds = [Shootout::SEED_PREFIX, tournament.key().as_ref()],
+        bump = shootout.bump
+    )]
+    pub shootout: Box<Account<'info, Shootout>>,
+
+    /// Current-round table PDA to create
+    #[account(
+        init,
+        payer = payer,
+        space = Table::SIZE,
+        seeds = [
+            Table::SEED_PREFIX,
+            tournament.key().as_ref(),
+            &shootout.first_table.saturating_add(index).to_le_bytes()
+        ],
+        bump
+    )]
+    pub table: Account<'info, Table>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Open a table of the current shootout round (permissionless).
+///
+/// Seats are drawn on-chain from the round's field and the committed
+/// `seed_blockhash`, so the table's draw is verified on creation.
+///
+/// # Arguments
+/// * `index` - Position of the table in the current round (0-based)
+pub fn handler(ctx: Context<OpenShootoutTable>, index: u16) -> Result<()> {
+    let shootout = &ctx.accounts.shootout;
+    require!(
+        (index as usize) < shootout.winners.len() && shootout.champion.is_none(),
+        ArenaError::InvalidShootoutTable
+    );
+
+    let table = &mut ctx.accounts.table;
+    table.tournament = ctx.accounts.tournament.key();
+    table.table_number = shootout.first_table + index;
+    table.seats = seating::shootout_seats(
+        &ctx.accounts.tournament.seed_blockhash,
+        shootout.round,
+        &shootout.players,
+        index as usize,
+    );
+    table.hand_count = 0;
+    table.updated_at = Clock::get()?.unix_timestamp;
+    table.draw_verified = true;
+    table.round = shootout.round;
+    table.winner = None;
+    table.bump = ctx.bumps.table;
+
+    msg!(
+        "Shootout round {} table {} opened with {} players",
+        shootout.round,
+        table.table_number,
+        table.player_count()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::events::SwissRoundPaired;
+use crate::instruction;
+use crate::seating;
+use crate::state::{
+    ArenaConfig, AuditLog, SwissEvent, SwissStanding, Tournament, TournamentStatus,
+};
+
+/// Accounts required for pairing the next Swiss round.
+#[derive(Accounts)]
+pub struct PairSwissRound<'info> {
+    /// Arena admin, the tournament's delegated operator or its host
+    pub authority: Signer<'info>,
+
+    /// Arena config for admin verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament being played - must be InProgress
+    #[account(
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.can_run(&authority.key(), &arena_config.admin) @ ArenaError::Unauthorized,
+        constraint = tournament.status == TournamentStatus::InProgress @ ArenaError::TournamentNotInProgress
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Tournament's Swiss schedule - the current round must be complete
+    #[account(
+        mut,
+        seeds = [SwissEvent::SEED_PREFIX, tournament.key().as_ref()],
+        bump = swiss_event.bump,
+        constraint = swiss_event.round < swiss_event.rounds @ ArenaError::InvalidSwissRound,
+        constraint = swiss_event.round_complete() @ ArenaError::InvalidSwissRound
+    )]
+    pub swiss_event: Box<Account<'info, SwissEvent>>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Pair the next Swiss round from the standings (admin, operator or host).
+///
+/// This instruction:
+/// 1. Loads every player's standing from remaining accounts, requiring the
+///    field to be complete and up to date with the current round
+/// 2. Orders players by wins, breaking ties with keys derived from
+///    seed_blockhash and the round number
+/// 3. Pairs adjacent players; with an odd field the last player takes a
+///    bye, scored as a win
+///
+/// Anyone can recompute the pairings from the same standings and seed.
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, PairSwissRound<'info>>) -> Result<()> {
+    let tournament = &ctx.accounts.tournament;
+    let swiss_event = &mut ctx.accounts.swiss_event;
+    let round = swiss_event.round + 1;
+
+    let mut standings = Vec::with_capacity(ctx.remaining_accounts.len());
+    for info in ctx.remaining_accounts {
+        let standing = Account::<SwissStanding>::try_from(info)?;
+        require!(
+            standing.tournament == tournament.key() && standing.last_round == swiss_event.round,
+            ArenaError::InvalidSwissRound
+        );
+        standings.push(standing);
+    }
+
+    let mut scores: Vec<(Pubkey, u8)> = standings.iter().map(|s| (s.wallet, s.wins)).collect();
+    scores.sort();
+    scores.dedup_by_key(|(wallet, _)| *wallet);
+    require!(
+        scores.len() == standings.len() && scores.len() == tournament.registered_players as usize,
+        ArenaError::InvalidSwissRound
+    );
+
+    let mut pairings = seating::swiss_order(&tournament.seed_blockhash, round, &scores);
+    let bye = if pairings.len() % 2 == 1 {
+        pairings.pop().unwrap_or_default()
+    } else {
+        Pubkey::default()
+    };
+    if let Some(standing) = standings.iter_mut().find(|s| s.wallet == bye) {
+        standing.wins = standing
+            .wins
+            .checked_add(1)
+            .ok_or(ArenaError::ArithmeticOverflow)?;
+        standing.byes = standing
+            .byes
+            .checked_add(1)
+            .ok_or(ArenaError::ArithmeticOverflow)?;
+        standing.last_round = round;
+        standing.exit(&crate::ID)?;
+    }
+
+    swiss_event.round = round;
+    swiss_event.pairings = pairings;
+    swiss_event.results_recorded = 0;
+
+    emit!(SwissRoundPaired {
+        tournament: tournament.key(),
+        round,
+        pairings: swiss_event.pairings.clone(),
+        bye,
+    });
+
+    msg!(
+        "Swiss round {} paired: {} matches",
+        round,
+        swiss_event.pairings.len() / 2
+    );
+
+    AuditLog::record_call::<instruction::PairSwissRound>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.authority.key(),
+        ctx.accounts.tournament.id,
+    )?;
+
+    Ok(())
+}
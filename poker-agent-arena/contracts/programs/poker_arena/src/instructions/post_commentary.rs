@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::events::CommentaryPosted;
+use crate::state::{ActionLog, CommentaryLog, Commentator, Tournament, TournamentStatus};
+
+/// Accounts required for posting a commentary commitment.
+#[derive(Accounts)]
+pub struct PostCommentary<'info> {
+    /// Appointed commentator; pays rent for the tournament's first entry
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Commentator entry for the signing key - must be active
+    #[account(
+        seeds = [Commentator::SEED_PREFIX, authority.key().as_ref()],
+        bump = commentator.bump,
+        constraint = commentator.active @ ArenaError::Unauthorized
+    )]
+    pub commentator: Account<'info, Commentator>,
+
+    /// Tournament the commentary covers - must have started
+    #[account(
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = matches!(
+            tournament.status,
+            TournamentStatus::InProgress | TournamentStatus::ResultsSubmitted | TournamentStatus::Completed
+        ) @ ArenaError::TournamentNotStarted
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Tournament's commentary chain (created on the first entry)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = CommentaryLog::SIZE,
+        seeds = [CommentaryLog::SEED_PREFIX, tournament.key().as_ref()],
+        bump
+    )]
+    pub commentary_log: Account<'info, CommentaryLog>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Anchor a hash of commentary or highlight metadata to a tournament
+/// (appointed commentators only).
+///
+/// Each head is SHA-256(prev_head || content_hash). The entry records how
+/// many hand-log batches were committed when it was posted, tying it to the
+/// hands it covers.
+///
+/// # Arguments
+/// * `content_hash` - SHA-256 hash of the commentary or highlight metadata
+pub fn handler(ctx: Context<PostCommentary>, content_hash: [u8; 32]) -> Result<()> {
+    let tournament = &ctx.accounts.tournament;
+    let commentary_log = &mut ctx.accounts.commentary_log;
+    commentary_log.tournament = tournament.key();
+    commentary_log.bump = ctx.bumps.commentary_log;
+
+    let sequence = commentary_log.entries;
+    commentary_log.chain_head = ActionLog::next_head(&commentary_log.chain_head, &content_hash);
+    commentary_log.entries = sequence
+        .checked_add(1)
+        .ok_or(ArenaError::ArithmeticOverflow)?;
+
+    emit!(CommentaryPosted {
+        tournament: tournament.key(),
+        commentator: ctx.accounts.authority.key(),
+        sequence,
+        content_hash,
+        game_log_batches: tournament.game_log_batches,
+        chain_head: commentary_log.chain_head,
+    });
+
+    msg!(
+        "Commentary #{} posted for tournament {} by {}",
+        sequence,
+        tournament.id,
+        ctx.accounts.authority.key()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::errors::ArenaError;
+use crate::state::{Host, LedgerCategory, LedgerDirection, TreasuryLedger};
+
+/// Accounts required for a host posting bond.
+#[derive(Accounts)]
+pub struct PostHostBond<'info> {
+    /// Host's signing key, paying the bond
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Host entry holding the bond
+    #[account(
+        mut,
+        seeds = [Host::SEED_PREFIX, authority.key().as_ref()],
+        bump = host.bump
+    )]
+    pub host: Account<'info, Host>,
+
+    /// System program for the transfer
+    pub system_program: Program<'info, System>,
+
+    /// Treasury ledger recording the movement (omit to skip)
+    #[account(
+        mut,
+        seeds = [TreasuryLedger::SEED_PREFIX],
+        bump = treasury_ledger.bump
+    )]
+    pub treasury_ledger: Option<Box<Account<'info, TreasuryLedger>>>,
+}
+
+/// Add SOL to the host's bond (host only).
+///
+/// # Arguments
+/// * `amount` - Lamports to add to the bond
+pub fn handler(ctx: Context<PostHostBond>, amount: u64) -> Result<()> {
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.authority.to_account_info(),
+                to: ctx.accounts.host.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    let host = &mut ctx.accounts.host;
+    host.bond_lamports = host
+        .bond_lamports
+        .checked_add(amount)
+        .ok_or(ArenaError::ArithmeticOverflow)?;
+
+    if let Some(treasury_ledger) = ctx.accounts.treasury_ledger.as_mut() {
+        treasury_ledger.record(
+            LedgerCategory::HostBond,
+            LedgerDirection::Inflow,
+            host.key(),
+            amount,
+            Clock::get()?.unix_timestamp,
+        );
+    }
+
+    msg!(
+        "Host {} bond now {} lamports",
+        host.authority,
+        host.bond_lamports
+    );
+
+    Ok(())
+}
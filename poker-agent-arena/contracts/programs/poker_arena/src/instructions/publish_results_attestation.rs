@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::state::{ResultsAttestation, Tournament, TournamentStatus};
+
+/// Accounts required for publishing a tournament's results attestation.
+#[derive(Accounts)]
+pub struct PublishResultsAttestation<'info> {
+    /// Anyone may publish; pays the attestation's rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Tournament - results must be confirmed
+    #[account(
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.status == TournamentStatus::Completed @ ArenaError::TournamentNotCompleted
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Attestation PDA to be created
+    #[account(
+        init,
+        payer = payer,
+        space = ResultsAttestation::SIZE,
+        seeds = [ResultsAttestation::SEED_PREFIX, tournament.key().as_ref()],
+        bump
+    )]
+    pub results_attestation: Account<'info, ResultsAttestation>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Publish a completed tournament's results in the fixed
+/// ResultsAttestation layout (permissionless).
+///
+/// Results are final once confirmed, so the attestation is written once and
+/// never changes.
+pub fn handler(ctx: Context<PublishResultsAttestation>) -> Result<()> {
+    let tournament = &ctx.accounts.tournament;
+    let (Some(results_hash), Some(winner)) = (tournament.results_hash, tournament.winner) else {
+        return err!(ArenaError::InvalidResultsHash);
+    };
+
+    let results_attestation = &mut ctx.accounts.results_attestation;
+    results_attestation.version = ResultsAttestation::VERSION;
+    results_attestation.tournament = tournament.key();
+    results_attestation.tournament_id = tournament.id;
+    results_attestation.results_hash = results_hash;
+    results_attestation.winner = winner;
+    results_attestation.entrants = tournament.registered_players;
+    results_attestation.seed_slot = tournament.seed_slot;
+    results_attestation.seed_blockhash = tournament.seed_blockhash;
+    results_attestation.completed_at = tournament.completed_at.unwrap_or_default();
+    results_attestation.bump = ctx.bumps.results_attestation;
+
+    msg!(
+        "Results attestation published for tournament {}",
+        tournament.id
+    );
+
+    Ok(())
+}
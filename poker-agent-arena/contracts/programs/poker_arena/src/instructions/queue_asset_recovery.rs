@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::events::AssetRecoveryQueued;
+use crate::instruction;
+use crate::state::{ArenaConfig, AssetRecovery, AuditLog};
+
+/// Accounts required for queueing a stranded asset sweep.
+#[derive(Accounts)]
+#[instruction(source: Pubkey, mint: Option<Pubkey>)]
+pub struct QueueAssetRecovery<'info> {
+    /// Admin wallet - must match arena_config.admin; pays rent
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// Arena config for admin verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Queued sweep for this source and asset (replaced, restarting the
+    /// timelock, if one is already queued)
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = AssetRecovery::SIZE,
+        seeds = [AssetRecovery::SEED_PREFIX, source.as_ref(), mint.unwrap_or_default().as_ref()],
+        bump
+    )]
+    pub asset_recovery: Account<'info, AssetRecovery>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Queue a sweep of assets stranded on the mint authority or a program vault
+/// (admin only).
+///
+/// The sweep can be executed with `recover_stranded_assets` once
+/// AssetRecovery::TIMELOCK_SECONDS have passed; what the source still holds
+/// for someone is checked then.
+///
+/// # Arguments
+/// * `source` - Mint authority or vault PDA holding the stranded assets
+/// * `mint` - Token mint to sweep, or None for lamports
+/// * `amount` - Amount to sweep (lamports or token base units)
+pub fn handler(
+    ctx: Context<QueueAssetRecovery>,
+    source: Pubkey,
+    mint: Option<Pubkey>,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, ArenaError::InvalidAssetRecovery);
+
+    let now = Clock::get()?.unix_timestamp;
+    let asset_recovery = &mut ctx.accounts.asset_recovery;
+    asset_recovery.source = source;
+    asset_recovery.mint = mint;
+    asset_recovery.amount = amount;
+    asset_recovery.queued_at = now;
+    asset_recovery.executable_at = now + AssetRecovery::TIMELOCK_SECONDS;
+    asset_recovery.bump = ctx.bumps.asset_recovery;
+
+    emit!(AssetRecoveryQueued {
+        source,
+        mint,
+        amount,
+        executable_at: asset_recovery.executable_at,
+    });
+
+    msg!(
+        "Recovery of {} queued, executable at {}",
+        amount,
+        asset_recovery.executable_at
+    );
+
+    AuditLog::record_call::<instruction::QueueAssetRecovery>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        0,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::events::MintAuthorityChangeQueued;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog, MintAuthorityChange, PointsMintAuthority};
+
+/// Accounts required for queueing a POINTS mint authority change.
+#[derive(Accounts)]
+pub struct QueueMintAuthorityChange<'info> {
+    /// Admin wallet - must match arena_config.admin; pays rent
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// Arena config for admin verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// PDA currently holding mint authority
+    #[account(
+        seeds = [PointsMintAuthority::SEED_PREFIX],
+        bump = mint_authority.bump
+    )]
+    pub mint_authority: Account<'info, PointsMintAuthority>,
+
+    /// Queued change (replaced, restarting the timelock, if one is already queued)
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = MintAuthorityChange::SIZE,
+        seeds = [MintAuthorityChange::SEED_PREFIX],
+        bump
+    )]
+    pub mint_authority_change: Account<'info, MintAuthorityChange>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Queue a transfer or revocation of the POINTS mint authority (admin only).
+///
+/// The change can be executed with `execute_mint_authority_change` once
+/// MintAuthorityChange::TIMELOCK_SECONDS have passed.
+///
+/// # Arguments
+/// * `new_authority` - PDA or program to hand minting to, or None to
+///   revoke minting permanently and fix the supply
+pub fn handler(
+    ctx: Context<QueueMintAuthorityChange>,
+    new_authority: Option<Pubkey>,
+) -> Result<()> {
+    require!(
+        new_authority != Some(ctx.accounts.mint_authority.key()),
+        ArenaError::InvalidMintAuthorityChange
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let mint_authority_change = &mut ctx.accounts.mint_authority_change;
+    mint_authority_change.new_authority = new_authority;
+    mint_authority_change.queued_at = now;
+    mint_authority_change.executable_at = now + MintAuthorityChange::TIMELOCK_SECONDS;
+    mint_authority_change.bump = ctx.bumps.mint_authority_change;
+
+    emit!(MintAuthorityChangeQueued {
+        new_authority,
+        executable_at: mint_authority_change.executable_at,
+    });
+
+    msg!(
+        "Mint authority change to {:?} queued, executable at {}",
+        new_authority,
+        mint_authority_change.executable_at
+    );
+
+    AuditLog::record_call::<instruction::QueueMintAuthorityChange>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        0,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,210 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::events::BracketMatchCompleted;
+use crate::instruction;
+use crate::state::{
+    ArenaConfig, AuditLog, Bracket, BracketMatch, BracketSide, MatchSlot, MatchStatus, Tournament,
+    TournamentStatus,
+};
+
+/// Accounts required for recording a bracket match result.
+#[derive(Accounts)]
+#[instruction(side: BracketSide, round: u8, index: u16)]
+pub struct RecordBracketMatch<'info> {
+    /// Results authority, or the arena admin or tournament's operator if none is set; pays rent
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Arena config for results authority verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament the bracket belongs to - must be InProgress
+    #[account(
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = arena_config.can_record_results(&authority.key(), &tournament) @ ArenaError::Unauthorized,
+        constraint = tournament.status == TournamentStatus::InProgress @ ArenaError::TournamentNotInProgress
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Tournament's bracket
+    #[account(
+        mut,
+        seeds = [Bracket::SEED_PREFIX, tournament.key().as_ref()],
+        bump = bracket.bump
+    )]
+    pub bracket: Box<Account<'info, Bracket>>,
+
+    /// Match being decided - must be Ready
+    #[account(
+        mut,
+        seeds = [
+            BracketMatch::SEED_PREFIX,
+            bracket.key().as_ref(),
+            &BracketMatch::position(side, round, index)
+        ],
+        bump = bracket_match.bump,
+        constraint = bracket_match.status == MatchStatus::Ready @ ArenaError::InvalidBracketMatch
+    )]
+    pub bracket_match: Account<'info, BracketMatch>,
+
+    /// Match the winner advances into (omit when the winner is champion)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = BracketMatch::SIZE,
+        seeds = [
+            BracketMatch::SEED_PREFIX,
+            bracket.key().as_ref(),
+            &bracket.winner_destination(side, round, index).map(|slot| slot.position()).unwrap_or_default()
+        ],
+        bump
+    )]
+    pub next_match: Option<Account<'info, BracketMatch>>,
+
+    /// Losers-bracket match the loser drops into (double elimination
+    /// winners-bracket matches only; omit otherwise)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = BracketMatch::SIZE,
+        seeds = [
+            BracketMatch::SEED_PREFIX,
+            bracket.key().as_ref(),
+            &bracket.loser_destination(side, round, index).map(|slot| slot.position()).unwrap_or_default()
+        ],
+        bump
+    )]
+    pub drop_match: Option<Account<'info, BracketMatch>>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Record the winner of a bracket match (results authority, or admin or operator if unset).
+///
+/// This instruction:
+/// 1. Completes the match with `winner`, who must be one of its players
+/// 2. Advances the winner into the next match, creating it if needed
+/// 3. In double elimination, drops a winners-bracket loser into the losers
+///    bracket
+/// 4. Crowns the bracket champion when the last match is decided; a grand
+///    final won by the losers-bracket champion is reset and replayed
+///
+/// # Arguments
+/// * `side` - Part of the bracket the match is in
+/// * `round` - Round of the match within its part
+/// * `index` - Position of the match in its round
+/// * `winner` - Winning wallet
+pub fn handler(
+    ctx: Context<RecordBracketMatch>,
+    side: BracketSide,
+    round: u8,
+    index: u16,
+    winner: Pubkey,
+) -> Result<()> {
+    let bracket_match = &mut ctx.accounts.bracket_match;
+    let loser = bracket_match
+        .opponent_of(&winner)
+        .ok_or(ArenaError::InvalidBracketMatch)?;
+    bracket_match.complete(winner, Clock::get()?.unix_timestamp);
+
+    let bracket = &mut ctx.accounts.bracket;
+    bracket.matches_completed = bracket.matches_completed.saturating_add(1);
+    let bracket_key = bracket.key();
+
+    // The winners-bracket champion takes the grand final outright; the
+    // losers-bracket champion must win it twice
+    let reset = side == BracketSide::GrandFinal && winner == bracket_match.player_b;
+    match bracket.winner_destination(side, round, index) {
+        Some(destination) if side != BracketSide::GrandFinal || reset => {
+            let next_match = ctx
+                .accounts
+                .next_match
+                .as_mut()
+                .ok_or(ArenaError::InvalidBracketMatch)?;
+            seat_in(
+                bracket_key,
+                destination,
+                Some(&mut *next_match),
+                ctx.bumps.next_match,
+                winner,
+            )?;
+            if reset {
+                next_match.seat(1, loser);
+            }
+        }
+        _ => {
+            bracket.champion = Some(winner);
+            msg!("Bracket champion: {}", winner);
+        }
+    }
+
+    if let Some(destination) = bracket.loser_destination(side, round, index) {
+        seat_in(
+            bracket_key,
+            destination,
+            ctx.accounts.drop_match.as_mut(),
+            ctx.bumps.drop_match,
+            loser,
+        )?;
+    }
+
+    emit!(BracketMatchCompleted {
+        tournament: ctx.accounts.tournament.key(),
+        side,
+        round,
+        index,
+        winner,
+        loser,
+    });
+
+    msg!(
+        "Bracket match {:?} {}/{} won by {}",
+        side,
+        round,
+        index,
+        winner
+    );
+
+    AuditLog::record_call::<instruction::RecordBracketMatch>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.authority.key(),
+        ctx.accounts.tournament.id,
+    )?;
+
+    Ok(())
+}
+
+/// Seat `wallet` at `destination`, creating the destination match if this
+/// is the first player to reach it.
+pub(crate) fn seat_in(
+    bracket: Pubkey,
+    destination: MatchSlot,
+    next_match: Option<&mut Account<BracketMatch>>,
+    next_bump: Option<u8>,
+    wallet: Pubkey,
+) -> Result<()> {
+    let (Some(next_match), Some(next_bump)) = (next_match, next_bump) else {
+        return err!(ArenaError::InvalidBracketMatch);
+    };
+    if next_match.bracket == Pubkey::default() {
+        next_match.init(bracket, destination, next_bump);
+    }
+    next_match.seat(destination.slot, wallet);
+
+    Ok(())
+}
@@ -0,0 +1,218 @@
+use anchor_lang::prelude::*;
+
+use crate::compression::{self, TreeAccounts, SPL_ACCOUNT_COMPRESSION_ID, SPL_NOOP_ID};
+use crate::errors::ArenaError;
+use crate::events::CompressedRegistrationUpdated;
+use crate::instruction;
+use crate::state::{
+    ArenaConfig, AuditLog, CompressedRegistration, CompressedRoster, PlayerStats, PlayerStatsV2,
+    Tournament, TournamentStatus,
+};
+
+/// Accounts required for recording a compressed registration's result.
+/// The Merkle proof for the leaf is passed as remaining accounts.
+#[derive(Accounts)]
+#[instruction(root: [u8; 32], leaf_index: u32, registration: CompressedRegistration)]
+pub struct RecordCompressedResult<'info> {
+    /// Results authority, or the arena admin or tournament's operator if none is set
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Arena config for results authority verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament - must be Completed
+    #[account(
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = arena_config.can_record_results(&authority.key(), &tournament) @ ArenaError::Unauthorized,
+        constraint = tournament.status == TournamentStatus::Completed @ ArenaError::TournamentNotInProgress
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Compressed roster (tree authority)
+    #[account(
+        seeds = [CompressedRoster::SEED_PREFIX, tournament.key().as_ref()],
+        bump = compressed_roster.bump
+    )]
+    pub compressed_roster: Account<'info, CompressedRoster>,
+
+    /// Concurrent Merkle tree holding registration leaves
+    /// CHECK: Verified against compressed_roster.merkle_tree
+    #[account(
+        mut,
+        address = compressed_roster.merkle_tree
+    )]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    /// Player's lifetime stats (created if doesn't exist)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = PlayerStats::SIZE,
+        seeds = [PlayerStats::SEED_PREFIX, registration.wallet.as_ref()],
+        bump
+    )]
+    pub player_stats: Account<'info, PlayerStats>,
+
+    /// Player's extended stats (created from player_stats on first use)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = PlayerStatsV2::SIZE,
+        seeds = [PlayerStatsV2::SEED_PREFIX, registration.wallet.as_ref()],
+        bump
+    )]
+    pub player_stats_v2: Account<'info, PlayerStatsV2>,
+
+    /// SPL account compression program
+    /// CHECK: Validated by address
+    #[account(address = SPL_ACCOUNT_COMPRESSION_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+
+    /// SPL noop program
+    /// CHECK: Validated by address
+    #[account(address = SPL_NOOP_ID)]
+    pub noop_program: UncheckedAccount<'info>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Record a compressed registration's tournament result (results
+/// authority, or admin or operator if unset).
+///
+/// This instruction:
+/// 1. Proves the current leaf against `root` (proof in remaining accounts)
+/// 2. Replaces it with a leaf carrying the result
+/// 3. Creates or updates the player's lifetime statistics and the extended
+///    PlayerStatsV2, including the loyalty level and performance score
+///
+/// The award must be a whole number of POINTS and match the tournament's
+/// committed points formula, if any.
+///
+/// # Arguments
+/// * `root` - Current tree root the proof was generated against
+/// * `leaf_index` - Index of the registration leaf
+/// * `registration` - Current leaf data (must hash to the stored leaf)
+/// * `final_rank` - Player's finishing position (1 = winner)
+/// * `points_awarded` - POINTS to award in base units; must be a whole
+///   number of POINTS
+/// * `hands_played` - Number of hands the player participated in
+/// * `eliminations` - Number of other players eliminated
+#[allow(clippy::too_many_arguments)]
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RecordCompressedResult<'info>>,
+    root: [u8; 32],
+    leaf_index: u32,
+    registration: CompressedRegistration,
+    final_rank: u16,
+    points_awarded: u64,
+    hands_played: u32,
+    eliminations: u8,
+) -> Result<()> {
+    let tournament_key = ctx.accounts.tournament.key();
+
+    require!(
+        registration.tournament == tournament_key,
+        ArenaError::TournamentNotFound
+    );
+    require!(
+        registration.final_rank.is_none(),
+        ArenaError::AlreadyRegistered
+    );
+    ctx.accounts.tournament.check_points_award(
+        &ctx.accounts.arena_config,
+        final_rank,
+        registration.tier,
+        points_awarded,
+    )?;
+
+    let previous_leaf = registration.leaf_hash()?;
+    let mut updated = registration;
+    updated.final_rank = Some(final_rank);
+    updated.points_awarded = Some(points_awarded);
+    updated.hands_played = Some(hands_played);
+    updated.eliminations = Some(eliminations);
+
+    let compressed_roster = &ctx.accounts.compressed_roster;
+    let seeds = &[
+        CompressedRoster::SEED_PREFIX,
+        tournament_key.as_ref(),
+        &[compressed_roster.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    compression::replace_leaf(
+        &TreeAccounts {
+            compression_program: &ctx.accounts.compression_program.to_account_info(),
+            merkle_tree: &ctx.accounts.merkle_tree.to_account_info(),
+            authority: &compressed_roster.to_account_info(),
+            noop: &ctx.accounts.noop_program.to_account_info(),
+        },
+        root,
+        previous_leaf,
+        updated.leaf_hash()?,
+        leaf_index,
+        ctx.remaining_accounts,
+        signer_seeds,
+    )?;
+
+    let player_stats = &mut ctx.accounts.player_stats;
+    let player_stats_v2 = &mut ctx.accounts.player_stats_v2;
+    player_stats_v2.migrate(
+        updated.wallet,
+        player_stats.tournaments_played,
+        ctx.bumps.player_stats_v2,
+    );
+    player_stats.record_result(
+        updated.wallet,
+        tournament_key,
+        final_rank,
+        points_awarded,
+        hands_played,
+        eliminations,
+        Clock::get()?.unix_timestamp,
+        ctx.bumps.player_stats,
+    );
+    player_stats_v2.record_result(
+        tournament_key,
+        updated.tier,
+        final_rank,
+        ctx.accounts.tournament.registered_players,
+        points_awarded,
+    );
+    player_stats_v2.loyalty_level = ctx
+        .accounts
+        .arena_config
+        .loyalty_level(player_stats.tournaments_played);
+
+    msg!("Recorded compressed result for player: {}", updated.wallet);
+    msg!("Rank: {}, Points: {}", final_rank, points_awarded);
+
+    emit!(CompressedRegistrationUpdated {
+        merkle_tree: compressed_roster.merkle_tree,
+        leaf_index,
+        registration: updated,
+    });
+
+    AuditLog::record_call::<instruction::RecordCompressedResult>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.authority.key(),
+        ctx.accounts.tournament.id,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,159 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::events::PlayerEliminated;
+use crate::instruction;
+use crate::state::{
+    ArenaConfig, AuditLog, HeadToHead, PlayerRegistration, Tournament, TournamentProgress,
+    TournamentStatus,
+};
+
+/// Accounts required for recording an elimination.
+#[derive(Accounts)]
+pub struct RecordElimination<'info> {
+    /// Results authority, or the arena admin or tournament's operator if none is set
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Arena config for results authority verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament - must be InProgress
+    #[account(
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = arena_config.can_record_results(&authority.key(), &tournament) @ ArenaError::Unauthorized,
+        constraint = tournament.status == TournamentStatus::InProgress @ ArenaError::TournamentNotInProgress
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Registration of the player who took the last chips - must still be in
+    #[account(
+        mut,
+        seeds = [PlayerRegistration::SEED_PREFIX, tournament.key().as_ref(), eliminator_registration.wallet.as_ref()],
+        bump = eliminator_registration.bump,
+        constraint = eliminator_registration.bust_position.is_none() @ ArenaError::InvalidElimination
+    )]
+    pub eliminator_registration: Box<Account<'info, PlayerRegistration>>,
+
+    /// Registration of the player knocked out - must still be in
+    #[account(
+        mut,
+        seeds = [PlayerRegistration::SEED_PREFIX, tournament.key().as_ref(), victim_registration.wallet.as_ref()],
+        bump = victim_registration.bump,
+        constraint = victim_registration.wallet != eliminator_registration.wallet @ ArenaError::InvalidElimination,
+        constraint = victim_registration.bust_position.is_none() @ ArenaError::InvalidElimination
+    )]
+    pub victim_registration: Box<Account<'info, PlayerRegistration>>,
+
+    /// Lifetime head-to-head record for the pair (created on first elimination)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = HeadToHead::SIZE,
+        seeds = [
+            HeadToHead::SEED_PREFIX,
+            eliminator_registration.wallet.as_ref(),
+            victim_registration.wallet.as_ref()
+        ],
+        bump
+    )]
+    pub head_to_head: Account<'info, HeadToHead>,
+
+    /// Live progress tracking players remaining (created on the first sync or elimination)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = TournamentProgress::SIZE,
+        seeds = [TournamentProgress::SEED_PREFIX, tournament.key().as_ref()],
+        bump
+    )]
+    pub progress: Account<'info, TournamentProgress>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Record that one player eliminated another (results authority, or admin or operator if unset).
+///
+/// This instruction:
+/// 1. Fixes the victim's bust position at the number of players remaining
+/// 2. Decrements players remaining on the tournament's live progress
+/// 3. Credits the eliminator with a bounty
+/// 4. Increments the pair's lifetime head-to-head count and emits
+///    PlayerEliminated
+///
+/// Rivalry stats on PlayerStatsV2 are refreshed from the head-to-head records
+/// at result recording.
+pub fn handler(ctx: Context<RecordElimination>) -> Result<()> {
+    let tournament = &ctx.accounts.tournament;
+    let progress = &mut ctx.accounts.progress;
+    if progress.tournament == Pubkey::default() {
+        progress.tournament = tournament.key();
+        progress.players_remaining = tournament.registered_players;
+        progress.bump = ctx.bumps.progress;
+    }
+    // The last player holding chips wins; they are never eliminated
+    require!(
+        progress.players_remaining > 1,
+        ArenaError::InvalidElimination
+    );
+    let bust_position = progress.players_remaining;
+    progress.players_remaining -= 1;
+    let now = Clock::get()?.unix_timestamp;
+    progress.updated_at = now;
+
+    ctx.accounts.victim_registration.bust_position = Some(bust_position);
+    let eliminator_registration = &mut ctx.accounts.eliminator_registration;
+    eliminator_registration.bounties = eliminator_registration
+        .bounties
+        .checked_add(1)
+        .ok_or(ArenaError::ArithmeticOverflow)?;
+
+    let head_to_head = &mut ctx.accounts.head_to_head;
+    if head_to_head.eliminator == Pubkey::default() {
+        head_to_head.eliminator = eliminator_registration.wallet;
+        head_to_head.victim = ctx.accounts.victim_registration.wallet;
+        head_to_head.bump = ctx.bumps.head_to_head;
+    }
+    head_to_head.count = head_to_head.count.saturating_add(1);
+    head_to_head.last_tournament = tournament.key();
+
+    emit!(PlayerEliminated {
+        tournament: tournament.key(),
+        eliminator: head_to_head.eliminator,
+        victim: head_to_head.victim,
+        bust_position,
+        players_remaining: progress.players_remaining,
+        eliminator_bounties: eliminator_registration.bounties,
+        timestamp: now,
+    });
+
+    msg!(
+        "Player {} eliminated {} in position {} ({} lifetime)",
+        head_to_head.eliminator,
+        head_to_head.victim,
+        bust_position,
+        head_to_head.count
+    );
+
+    AuditLog::record_call::<instruction::RecordElimination>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.authority.key(),
+        ctx.accounts.tournament.id,
+    )?;
+
+    Ok(())
+}
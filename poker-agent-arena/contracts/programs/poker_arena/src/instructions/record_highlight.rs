@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{
+    ArenaConfig, AuditLog, Highlight, HighlightTag, Highlights, Tournament, TournamentStatus,
+};
+
+/// Accounts required for bookmarking a notable hand.
+#[derive(Accounts)]
+pub struct RecordHighlight<'info> {
+    /// Admin, tournament operator or host; pays rent for the first highlight
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Arena config for admin verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament the hand was played in - must have started
+    #[account(
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.can_run(&authority.key(), &arena_config.admin) @ ArenaError::Unauthorized,
+        constraint = matches!(
+            tournament.status,
+            TournamentStatus::InProgress | TournamentStatus::ResultsSubmitted | TournamentStatus::Completed
+        ) @ ArenaError::TournamentNotStarted
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Tournament's highlights (created on the first bookmark)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = Highlights::SIZE,
+        seeds = [Highlights::SEED_PREFIX, tournament.key().as_ref()],
+        bump
+    )]
+    pub highlights: Box<Account<'info, Highlights>>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Bookmark a notable hand on the tournament's highlights (admin, operator or host).
+///
+/// # Arguments
+/// * `hand_index` - Index of the hand within the tournament
+/// * `tag` - Why the hand is notable
+pub fn handler(ctx: Context<RecordHighlight>, hand_index: u32, tag: HighlightTag) -> Result<()> {
+    let highlights = &mut ctx.accounts.highlights;
+    highlights.tournament = ctx.accounts.tournament.key();
+    highlights.bump = ctx.bumps.highlights;
+    highlights.record(Highlight {
+        hand_index,
+        tag,
+        recorded_at: Clock::get()?.unix_timestamp,
+    })?;
+
+    msg!(
+        "Hand {} of tournament {} highlighted as {:?}",
+        hand_index,
+        ctx.accounts.tournament.id,
+        tag
+    );
+
+    AuditLog::record_call::<instruction::RecordHighlight>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.authority.key(),
+        ctx.accounts.tournament.id,
+    )?;
+
+    Ok(())
+}
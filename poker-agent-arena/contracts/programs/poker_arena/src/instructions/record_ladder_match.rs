@@ -0,0 +1,118 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::events::LadderMatchRecorded;
+use crate::instruction;
+use crate::state::{
+    ArenaConfig, AuditLog, Ladder, LadderRating, PlayerRegistration, Tournament, TournamentStatus,
+};
+
+/// Accounts required for rating a heads-up ladder match.
+#[derive(Accounts)]
+pub struct RecordLadderMatch<'info> {
+    /// Arena admin, the tournament's delegated operator or its host
+    pub authority: Signer<'info>,
+
+    /// Arena config for admin verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Ladder for the K-factor
+    #[account(
+        seeds = [Ladder::SEED_PREFIX],
+        bump = ladder.bump
+    )]
+    pub ladder: Account<'info, Ladder>,
+
+    /// Completed heads-up tournament the match was played in
+    #[account(
+        mut,
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.can_run(&authority.key(), &arena_config.admin) @ ArenaError::Unauthorized,
+        constraint = tournament.status == TournamentStatus::Completed @ ArenaError::InvalidLadderMatch,
+        constraint = tournament.registered_players == 2 @ ArenaError::InvalidLadderMatch,
+        constraint = !tournament.ladder_recorded @ ArenaError::InvalidLadderMatch,
+        constraint = tournament.winner == Some(winner_rating.wallet) @ ArenaError::InvalidLadderMatch
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Winner's ladder rating
+    #[account(
+        mut,
+        seeds = [LadderRating::SEED_PREFIX, winner_rating.wallet.as_ref()],
+        bump = winner_rating.bump
+    )]
+    pub winner_rating: Account<'info, LadderRating>,
+
+    /// Loser's ladder rating
+    #[account(
+        mut,
+        seeds = [LadderRating::SEED_PREFIX, loser_rating.wallet.as_ref()],
+        bump = loser_rating.bump,
+        constraint = loser_rating.wallet != winner_rating.wallet @ ArenaError::InvalidLadderMatch
+    )]
+    pub loser_rating: Account<'info, LadderRating>,
+
+    /// Loser's registration, proving they played the tournament
+    #[account(
+        seeds = [PlayerRegistration::SEED_PREFIX, tournament.key().as_ref(), loser_rating.wallet.as_ref()],
+        bump = loser_registration.bump
+    )]
+    pub loser_registration: Account<'info, PlayerRegistration>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Apply a completed heads-up tournament to both players' ELO (admin, operator or host).
+///
+/// This instruction:
+/// 1. Checks the tournament was a completed, unrated two-player event won by
+///    the winner and played by the loser
+/// 2. Moves both ratings by the ELO delta for the result
+/// 3. Marks the tournament as rated
+///
+/// Divisions do not change until the next season rollover.
+pub fn handler(ctx: Context<RecordLadderMatch>) -> Result<()> {
+    let delta = ctx.accounts.ladder.rating_delta(
+        ctx.accounts.winner_rating.rating,
+        ctx.accounts.loser_rating.rating,
+    );
+
+    ctx.accounts.winner_rating.record_match(true, delta);
+    ctx.accounts.loser_rating.record_match(false, delta);
+    ctx.accounts.tournament.ladder_recorded = true;
+
+    emit!(LadderMatchRecorded {
+        tournament: ctx.accounts.tournament.key(),
+        winner: ctx.accounts.winner_rating.wallet,
+        loser: ctx.accounts.loser_rating.wallet,
+        winner_rating: ctx.accounts.winner_rating.rating,
+        loser_rating: ctx.accounts.loser_rating.rating,
+    });
+
+    msg!(
+        "Ladder match rated: {} +{} / {} -{}",
+        ctx.accounts.winner_rating.wallet,
+        delta,
+        ctx.accounts.loser_rating.wallet,
+        delta
+    );
+
+    AuditLog::record_call::<instruction::RecordLadderMatch>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.authority.key(),
+        ctx.accounts.tournament.id,
+    )?;
+
+    Ok(())
+}
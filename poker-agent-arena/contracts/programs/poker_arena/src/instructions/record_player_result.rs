@@ -1,28 +1,36 @@
 use anchor_lang::prelude::*;
 
+use crate::bubblegum::{self, MetadataArgs, MintV1Accounts, TokenProgramVersion, TokenStandard, MPL_BUBBLEGUM_ID};
+use crate::compression::{SPL_ACCOUNT_COMPRESSION_ID, SPL_NOOP_ID};
 use crate::errors::ArenaError;
-use crate::state::{ArenaConfig, PlayerRegistration, PlayerStats, Tournament, TournamentStatus};
+use crate::events::PenaltyApplied;
+use crate::instruction;
+use crate::state::{
+    AgentMetrics, ArenaConfig, AuditLog, GuildEventEntry, GuildMember, GuildStats, HeadToHead,
+    PlayerRegistration, PlayerStats, PlayerStatsV2, Season, SeasonPass, Tournament,
+    TrophyAuthority,
+};
 
 /// Accounts required for recording a player's tournament result.
 #[derive(Accounts)]
 pub struct RecordPlayerResult<'info> {
-    /// Admin wallet - must match arena_config.admin
+    /// Results authority, or the arena admin or tournament's operator if none is set
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub authority: Signer<'info>,
 
     /// Arena config for admin verification
     #[account(
         seeds = [ArenaConfig::SEED_PREFIX],
-        bump = arena_config.bump,
-        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+        bump = arena_config.bump
     )]
     pub arena_config: Account<'info, ArenaConfig>,
 
-    /// Tournament - must be Completed
+    /// Tournament - must be Completed, or InProgress for a busted player
     #[account(
-        seeds = [Tournament::SEED_PREFIX, &tournament.id.to_le_bytes()],
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
         bump = tournament.bump,
-        constraint = tournament.status == TournamentStatus::Completed @ ArenaError::TournamentNotInProgress
+        constraint = arena_config.can_record_results(&authority.key(), &tournament) @ ArenaError::Unauthorized,
+        constraint = tournament.accepts_result(&registration) @ ArenaError::TournamentNotCompleted
     )]
     pub tournament: Account<'info, Tournament>,
 
@@ -31,14 +39,15 @@ pub struct RecordPlayerResult<'info> {
         mut,
         seeds = [PlayerRegistration::SEED_PREFIX, tournament.key().as_ref(), registration.wallet.as_ref()],
         bump = registration.bump,
-        constraint = registration.tournament == tournament.key() @ ArenaError::TournamentNotFound
+        constraint = registration.tournament == tournament.key() @ ArenaError::TournamentNotFound,
+        constraint = !registration.disqualified @ ArenaError::PlayerDisqualified
     )]
     pub registration: Account<'info, PlayerRegistration>,
 
     /// Player's lifetime stats (created if doesn't exist)
     #[account(
         init_if_needed,
-        payer = admin,
+        payer = authority,
         space = PlayerStats::SIZE,
         seeds = [PlayerStats::SEED_PREFIX, registration.wallet.as_ref()],
         bump
@@ -47,75 +56,320 @@ pub struct RecordPlayerResult<'info> {
 
     /// System program for account creation
     pub system_program: Program<'info, System>,
+
+    /// Trophy authority PDA (Bubblegum tree delegate); required if trophies are enabled
+    #[account(
+        mut,
+        seeds = [TrophyAuthority::SEED_PREFIX],
+        bump = trophy_authority.bump
+    )]
+    pub trophy_authority: Option<Account<'info, TrophyAuthority>>,
+
+    /// Player wallet receiving the trophy
+    /// CHECK: Must match registration.wallet
+    #[account(address = registration.wallet @ ArenaError::Unauthorized)]
+    pub leaf_owner: Option<UncheckedAccount<'info>>,
+
+    /// Bubblegum tree config for trophy_tree
+    /// CHECK: Validated by the Bubblegum program
+    #[account(mut)]
+    pub tree_config: Option<UncheckedAccount<'info>>,
+
+    /// Bubblegum Merkle tree holding trophies
+    /// CHECK: Validated by the Bubblegum program
+    #[account(mut)]
+    pub trophy_tree: Option<UncheckedAccount<'info>>,
+
+    /// SPL noop program
+    /// CHECK: Validated by address
+    #[account(address = SPL_NOOP_ID)]
+    pub log_wrapper: Option<UncheckedAccount<'info>>,
+
+    /// SPL account compression program
+    /// CHECK: Validated by address
+    #[account(address = SPL_ACCOUNT_COMPRESSION_ID)]
+    pub compression_program: Option<UncheckedAccount<'info>>,
+
+    /// Metaplex Bubblegum program
+    /// CHECK: Validated by address
+    #[account(address = MPL_BUBBLEGUM_ID)]
+    pub bubblegum_program: Option<UncheckedAccount<'info>>,
+
+    /// Season covering this tournament (omit if the player has no pass)
+    pub season: Option<Account<'info, Season>>,
+
+    /// Player's season pass, multiplying awarded points
+    pub season_pass: Option<Account<'info, SeasonPass>>,
+
+    /// Player's guild membership (omit if the player has no guild)
+    #[account(
+        seeds = [GuildMember::SEED_PREFIX, registration.wallet.as_ref()],
+        bump = guild_member.bump
+    )]
+    pub guild_member: Option<Account<'info, GuildMember>>,
+
+    /// Player's guild stats, aggregating member results
+    #[account(
+        mut,
+        seeds = [GuildStats::SEED_PREFIX, guild_stats.guild.as_ref()],
+        bump = guild_stats.bump
+    )]
+    pub guild_stats: Option<Account<'info, GuildStats>>,
+
+    /// Player's guild entry, if this is a guild event the guild entered
+    #[account(
+        mut,
+        seeds = [GuildEventEntry::SEED_PREFIX, tournament.key().as_ref(), guild_event_entry.guild.as_ref()],
+        bump = guild_event_entry.bump
+    )]
+    pub guild_event_entry: Option<Account<'info, GuildEventEntry>>,
+
+    /// Player's extended stats (created from player_stats on first use)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = PlayerStatsV2::SIZE,
+        seeds = [PlayerStatsV2::SEED_PREFIX, registration.wallet.as_ref()],
+        bump
+    )]
+    pub player_stats_v2: Account<'info, PlayerStatsV2>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
 }
 
-/// Record a player's tournament result (admin only).
+impl<'info> RecordPlayerResult<'info> {
+    /// Mint a participation trophy cNFT for `final_rank` to the player
+    fn mint_trophy(&mut self, final_rank: u16) -> Result<()> {
+        let (
+            Some(trophy_authority),
+            Some(leaf_owner),
+            Some(tree_config),
+            Some(trophy_tree),
+            Some(log_wrapper),
+            Some(compression_program),
+            Some(bubblegum_program),
+        ) = (
+            self.trophy_authority.as_mut(),
+            self.leaf_owner.as_ref(),
+            self.tree_config.as_ref(),
+            self.trophy_tree.as_ref(),
+            self.log_wrapper.as_ref(),
+            self.compression_program.as_ref(),
+            self.bubblegum_program.as_ref(),
+        )
+        else {
+            return err!(ArenaError::TrophyAccountsMissing);
+        };
+
+        let tournament_id = self.tournament.id;
+        let metadata = MetadataArgs {
+            name: format!("Arena #{} Rank {}", tournament_id, final_rank),
+            symbol: TrophyAuthority::SYMBOL.to_string(),
+            uri: trophy_authority.trophy_uri(tournament_id, final_rank),
+            seller_fee_basis_points: 0,
+            primary_sale_happened: false,
+            is_mutable: false,
+            edition_nonce: None,
+            token_standard: Some(TokenStandard::NonFungible),
+            collection: None,
+            uses: None,
+            token_program_version: TokenProgramVersion::Original,
+            creators: vec![],
+        };
+
+        let seeds = &[TrophyAuthority::SEED_PREFIX, &[trophy_authority.bump]];
+        let signer_seeds = &[&seeds[..]];
+
+        bubblegum::mint_v1(
+            &MintV1Accounts {
+                bubblegum_program: &bubblegum_program.to_account_info(),
+                tree_config: &tree_config.to_account_info(),
+                leaf_owner: &leaf_owner.to_account_info(),
+                merkle_tree: &trophy_tree.to_account_info(),
+                payer: &self.authority.to_account_info(),
+                tree_delegate: &trophy_authority.to_account_info(),
+                log_wrapper: &log_wrapper.to_account_info(),
+                compression_program: &compression_program.to_account_info(),
+                system_program: &self.system_program.to_account_info(),
+            },
+            &metadata,
+            signer_seeds,
+        )?;
+
+        trophy_authority.trophies_minted = trophy_authority.trophies_minted.saturating_add(1);
+
+        msg!("Minted participation trophy for rank {}", final_rank);
+
+        Ok(())
+    }
+}
+
+/// Record a player's tournament result (results authority, or admin or operator if unset).
 ///
 /// This instruction:
-/// 1. Records the player's final rank, points, hands played, and eliminations
-/// 2. Creates or updates the player's lifetime statistics
+/// 1. Checks the award against the tournament's committed points formula, if any
+/// 2. Records the player's final rank, points, hands played, eliminations and
+///    behavioral metrics, multiplying points for season pass holders and
+///    deducting the timeout penalty if the player's committed timeouts
+///    exceed the configured threshold
+/// 3. Creates or updates the player's lifetime statistics and the extended
+///    PlayerStatsV2 (migrating it from PlayerStats on first use), including
+///    the loyalty level and performance score
+/// 4. Refreshes nemesis and top-victim stats from HeadToHead records passed
+///    as remaining accounts
+/// 5. Aggregates the result into the player's guild stats and, for guild
+///    events, the guild's standing
+/// 6. Mints a participation trophy cNFT if the tournament has trophies enabled
+///
+/// Players who already busted may be recorded while the tournament is still
+/// InProgress, at the position fixed when they were eliminated.
 ///
 /// # Arguments
 /// * `final_rank` - Player's finishing position (1 = winner)
-/// * `points_awarded` - POINTS tokens to award
+/// * `points_awarded` - POINTS to award in base units; must be a whole
+///   number of POINTS
 /// * `hands_played` - Number of hands the player participated in
 /// * `eliminations` - Number of other players eliminated
-pub fn handler(
-    ctx: Context<RecordPlayerResult>,
+/// * `metrics` - Engine behavioral metrics (VPIP, aggression factor, showdown win rate)
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RecordPlayerResult<'info>>,
     final_rank: u16,
     points_awarded: u64,
     hands_played: u32,
     eliminations: u8,
+    metrics: AgentMetrics,
 ) -> Result<()> {
+    metrics.validate()?;
+
     let registration = &mut ctx.accounts.registration;
     let player_stats = &mut ctx.accounts.player_stats;
     let tournament = &ctx.accounts.tournament;
+    tournament.check_points_award(
+        &ctx.accounts.arena_config,
+        final_rank,
+        registration.tier,
+        points_awarded,
+    )?;
+
+    // Season pass holders earn the season's points multiplier, rounded down
+    // so the multiplied award stays a whole number of POINTS
+    let points_awarded = match (&ctx.accounts.season, &ctx.accounts.season_pass) {
+        (Some(season), Some(season_pass))
+            if season_pass.applies(season, &registration.wallet, tournament.starts_at) =>
+        {
+            ctx.accounts
+                .arena_config
+                .round_down_to_whole_points(season.multiply_points(points_awarded))
+        }
+        _ => points_awarded,
+    };
 
     // Check if result already recorded
     require!(
         registration.final_rank.is_none(),
         ArenaError::AlreadyRegistered
     );
+    if let Some(bust_position) = registration.bust_position {
+        require!(final_rank == bust_position, ArenaError::RankMismatch);
+    }
+
+    let timeout_penalty = ctx
+        .accounts
+        .arena_config
+        .timeout_penalty(registration.timeouts)
+        .min(points_awarded);
+    let points_awarded = points_awarded - timeout_penalty;
+    if timeout_penalty > 0 {
+        let now = Clock::get()?.unix_timestamp;
+        registration.penalty_points = registration.penalty_points.saturating_add(timeout_penalty);
+        let sanction = registration.record_sanction(now)?;
+        sanction.award_points = sanction.award_points.saturating_add(timeout_penalty);
+        emit!(PenaltyApplied {
+            tournament: tournament.key(),
+            wallet: registration.wallet,
+            amount: timeout_penalty,
+            burned: false,
+            reason_hash: [0; 32],
+            timestamp: now,
+        });
+        msg!("Timeout penalty of {} POINTS applied", timeout_penalty);
+    }
 
     // Update registration with tournament result
     registration.final_rank = Some(final_rank);
     registration.points_awarded = Some(points_awarded);
     registration.hands_played = Some(hands_played);
     registration.eliminations = Some(eliminations);
+    registration.metrics = Some(metrics);
+
+    let player_stats_v2 = &mut ctx.accounts.player_stats_v2;
+    player_stats_v2.migrate(
+        registration.wallet,
+        player_stats.tournaments_played,
+        ctx.bumps.player_stats_v2,
+    );
 
     // Initialize or update player stats
-    let is_new_stats = player_stats.wallet == Pubkey::default();
-
-    if is_new_stats {
-        // First time this player has stats recorded
-        player_stats.wallet = registration.wallet;
-        player_stats.tournaments_played = 1;
-        player_stats.tournaments_won = if final_rank == 1 { 1 } else { 0 };
-        player_stats.total_points = points_awarded;
-        player_stats.best_finish = final_rank;
-        player_stats.total_hands_played = hands_played as u64;
-        player_stats.total_eliminations = eliminations as u32;
-        player_stats.last_tournament = tournament.key();
-        player_stats.last_played_at = Clock::get()?.unix_timestamp;
-        player_stats.bump = ctx.bumps.player_stats;
-    } else {
-        // Update existing stats
-        player_stats.tournaments_played = player_stats.tournaments_played.saturating_add(1);
-        if final_rank == 1 {
-            player_stats.tournaments_won = player_stats.tournaments_won.saturating_add(1);
+    player_stats.record_result(
+        registration.wallet,
+        tournament.key(),
+        final_rank,
+        points_awarded,
+        hands_played,
+        eliminations,
+        Clock::get()?.unix_timestamp,
+        ctx.bumps.player_stats,
+    );
+
+    player_stats_v2.record_result(
+        tournament.key(),
+        registration.tier,
+        final_rank,
+        tournament.registered_players,
+        points_awarded,
+    );
+    player_stats_v2.loyalty_level = ctx
+        .accounts
+        .arena_config
+        .loyalty_level(player_stats.tournaments_played);
+
+    for info in ctx.remaining_accounts {
+        let head_to_head = Account::<HeadToHead>::try_from(info)?;
+        player_stats_v2.observe_head_to_head(&head_to_head);
+    }
+
+    if let Some(guild_member) = &ctx.accounts.guild_member {
+        let now = Clock::get()?.unix_timestamp;
+        if let Some(guild_stats) = ctx.accounts.guild_stats.as_mut() {
+            require_keys_eq!(guild_stats.guild, guild_member.guild, ArenaError::InvalidGuild);
+            guild_stats.record_result(final_rank, points_awarded, now);
         }
-        player_stats.total_points = player_stats.total_points.saturating_add(points_awarded);
-        if final_rank < player_stats.best_finish || player_stats.best_finish == 0 {
-            player_stats.best_finish = final_rank;
+        if let Some(guild_event_entry) = ctx.accounts.guild_event_entry.as_mut() {
+            require!(tournament.guild_event, ArenaError::InvalidGuild);
+            require_keys_eq!(guild_event_entry.guild, guild_member.guild, ArenaError::InvalidGuild);
+            guild_event_entry.member_results = guild_event_entry.member_results.saturating_add(1);
+            guild_event_entry.total_points = guild_event_entry.total_points.saturating_add(points_awarded);
         }
-        player_stats.total_hands_played = player_stats.total_hands_played.saturating_add(hands_played as u64);
-        player_stats.total_eliminations = player_stats.total_eliminations.saturating_add(eliminations as u32);
-        player_stats.last_tournament = tournament.key();
-        player_stats.last_played_at = Clock::get()?.unix_timestamp;
     }
 
     msg!("Recorded result for player: {}", registration.wallet);
     msg!("Rank: {}, Points: {}", final_rank, points_awarded);
 
+    if ctx.accounts.tournament.trophies_enabled {
+        ctx.accounts.mint_trophy(final_rank)?;
+    }
+
+    AuditLog::record_call::<instruction::RecordPlayerResult>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.authority.key(),
+        ctx.accounts.tournament.id,
+    )?;
+
     Ok(())
 }
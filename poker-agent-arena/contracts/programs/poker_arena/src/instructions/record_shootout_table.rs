@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::events::ShootoutTableWon;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog, Shootout, Table, Tournament, TournamentStatus};
+
+/// Accounts required for recording a shootout table's winner.
+#[derive(Accounts)]
+pub struct RecordShootoutTable<'info> {
+    /// Results authority, or the arena admin or tournament's operator if none is set
+    pub authority: Signer<'info>,
+
+    /// Arena config for results authority verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament being played - must be InProgress
+    #[account(
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = arena_config.can_record_results(&authority.key(), &tournament) @ ArenaError::Unauthorized,
+        constraint = tournament.status == TournamentStatus::InProgress @ ArenaError::TournamentNotInProgress
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Tournament's shootout schedule
+    #[account(
+        mut,
+        seeds = [Shootout::SEED_PREFIX, tournament.key().as_ref()],
+        bump = shootout.bump
+    )]
+    pub shootout: Box<Account<'info, Shootout>>,
+
+    /// Current-round table that was played out - must not have a winner yet
+    #[account(
+        mut,
+        seeds = [Table::SEED_PREFIX, tournament.key().as_ref(), &table.table_number.to_le_bytes()],
+        bump = table.bump,
+        constraint = table.round == shootout.round @ ArenaError::InvalidShootoutTable,
+        constraint = table.winner.is_none() @ ArenaError::InvalidShootoutTable
+    )]
+    pub table: Account<'info, Table>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Record the player who won a shootout table (results authority, or admin or operator if unset).
+///
+/// The winner advances to the next round; the winner of a round's only
+/// table is the shootout champion.
+///
+/// # Arguments
+/// * `winner` - Wallet that won the table
+pub fn handler(ctx: Context<RecordShootoutTable>, winner: Pubkey) -> Result<()> {
+    let shootout = &mut ctx.accounts.shootout;
+    let table = &mut ctx.accounts.table;
+    let index = shootout
+        .table_index(table.table_number)
+        .ok_or(ArenaError::InvalidShootoutTable)?;
+    require!(table.is_seated(&winner), ArenaError::InvalidShootoutTable);
+
+    table.winner = Some(winner);
+    table.updated_at = Clock::get()?.unix_timestamp;
+    shootout.winners[index] = winner;
+    if shootout.winners.len() == 1 {
+        shootout.champion = Some(winner);
+        msg!("Shootout champion: {}", winner);
+    }
+
+    emit!(ShootoutTableWon {
+        tournament: ctx.accounts.tournament.key(),
+        round: shootout.round,
+        table_number: table.table_number,
+        winner,
+    });
+
+    msg!(
+        "Shootout round {} table {} won by {}",
+        shootout.round,
+        table.table_number,
+        winner
+    );
+
+    AuditLog::record_call::<instruction::RecordShootoutTable>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.authority.key(),
+        ctx.accounts.tournament.id,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{
+    ArenaConfig, AuditLog, SwissEvent, SwissStanding, Tournament, TournamentStatus,
+};
+
+/// Accounts required for recording a Swiss match result.
+#[derive(Accounts)]
+pub struct RecordSwissResult<'info> {
+    /// Results authority, or the arena admin or tournament's operator if none is set
+    pub authority: Signer<'info>,
+
+    /// Arena config for results authority verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament being played - must be InProgress
+    #[account(
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = arena_config.can_record_results(&authority.key(), &tournament) @ ArenaError::Unauthorized,
+        constraint = tournament.status == TournamentStatus::InProgress @ ArenaError::TournamentNotInProgress
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Tournament's Swiss schedule
+    #[account(
+        mut,
+        seeds = [SwissEvent::SEED_PREFIX, tournament.key().as_ref()],
+        bump = swiss_event.bump,
+        constraint = swiss_event.are_paired(&winner_standing.wallet, &loser_standing.wallet) @ ArenaError::InvalidSwissRound
+    )]
+    pub swiss_event: Box<Account<'info, SwissEvent>>,
+
+    /// Winner's standing - must not have a result this round yet
+    #[account(
+        mut,
+        seeds = [SwissStanding::SEED_PREFIX, tournament.key().as_ref(), winner_standing.wallet.as_ref()],
+        bump = winner_standing.bump,
+        constraint = winner_standing.last_round < swiss_event.round @ ArenaError::InvalidSwissRound
+    )]
+    pub winner_standing: Account<'info, SwissStanding>,
+
+    /// Loser's standing - must not have a result this round yet
+    #[account(
+        mut,
+        seeds = [SwissStanding::SEED_PREFIX, tournament.key().as_ref(), loser_standing.wallet.as_ref()],
+        bump = loser_standing.bump,
+        constraint = loser_standing.last_round < swiss_event.round @ ArenaError::InvalidSwissRound,
+        constraint = loser_standing.wallet != winner_standing.wallet @ ArenaError::InvalidSwissRound
+    )]
+    pub loser_standing: Account<'info, SwissStanding>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Record the result of a current-round Swiss match (results
+/// authority, or admin or operator if unset).
+pub fn handler(ctx: Context<RecordSwissResult>) -> Result<()> {
+    let round = ctx.accounts.swiss_event.round;
+
+    let winner_standing = &mut ctx.accounts.winner_standing;
+    winner_standing.wins = winner_standing
+        .wins
+        .checked_add(1)
+        .ok_or(ArenaError::ArithmeticOverflow)?;
+    winner_standing.last_round = round;
+
+    let loser_standing = &mut ctx.accounts.loser_standing;
+    loser_standing.losses = loser_standing
+        .losses
+        .checked_add(1)
+        .ok_or(ArenaError::ArithmeticOverflow)?;
+    loser_standing.last_round = round;
+
+    ctx.accounts.swiss_event.results_recorded = ctx
+        .accounts
+        .swiss_event
+        .results_recorded
+        .checked_add(1)
+        .ok_or(ArenaError::ArithmeticOverflow)?;
+
+    msg!(
+        "Swiss round {}: {} beat {}",
+        round,
+        ctx.accounts.winner_standing.wallet,
+        ctx.accounts.loser_standing.wallet
+    );
+
+    AuditLog::record_call::<instruction::RecordSwissResult>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.authority.key(),
+        ctx.accounts.tournament.id,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,366 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::errors::ArenaError;
+use crate::events::StrandedAssetsRecovered;
+use crate::instruction;
+use crate::state::{
+    ArenaConfig, AssetRecovery, AuditLog, CrankVault, FeeEscrow, GuildVault, LedgerCategory,
+    LedgerDirection, PointsMintAuthority, PrizeDenomination, PrizePool, RedemptionVault,
+    TreasuryLedger,
+};
+
+/// Accounts required for executing a queued stranded asset sweep.
+///
+/// The token accounts are required only when sweeping tokens.
+#[derive(Accounts)]
+pub struct RecoverStrandedAssets<'info> {
+    /// Admin wallet - must match arena_config.admin; receives the request's rent
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// Arena config for admin verification and the treasury address
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Queued sweep - its timelock must have passed
+    #[account(
+        mut,
+        close = admin,
+        seeds = [
+            AssetRecovery::SEED_PREFIX,
+            asset_recovery.source.as_ref(),
+            asset_recovery.mint.unwrap_or_default().as_ref()
+        ],
+        bump = asset_recovery.bump
+    )]
+    pub asset_recovery: Account<'info, AssetRecovery>,
+
+    /// Mint authority or program vault holding the stranded assets
+    /// CHECK: Verified against asset_recovery.source; its type is read from
+    /// its discriminator by RecoverySource::load
+    #[account(
+        mut,
+        address = asset_recovery.source @ ArenaError::InvalidAssetRecovery
+    )]
+    pub source: UncheckedAccount<'info>,
+
+    /// Treasury wallet receiving swept lamports
+    /// CHECK: Verified against arena_config.treasury
+    #[account(
+        mut,
+        address = arena_config.treasury @ ArenaError::InvalidTierPayment
+    )]
+    pub treasury: UncheckedAccount<'info>,
+
+    /// Token account owned by the source holding stranded tokens
+    #[account(
+        mut,
+        token::authority = source
+    )]
+    pub stranded_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Treasury token account receiving swept tokens
+    #[account(
+        mut,
+        token::authority = treasury
+    )]
+    pub treasury_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// SPL Token program
+    pub token_program: Option<Program<'info, Token>>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+
+    /// Treasury ledger recording swept lamports (omit to skip)
+    #[account(
+        mut,
+        seeds = [TreasuryLedger::SEED_PREFIX],
+        bump = treasury_ledger.bump
+    )]
+    pub treasury_ledger: Option<Box<Account<'info, TreasuryLedger>>>,
+}
+
+/// Program PDA a sweep draws from, and what it still holds for someone.
+enum RecoverySource {
+    /// POINTS mint authority; never holds funds for anyone
+    MintAuthority { bump: u8 },
+    /// Crank bounty vault; funded from the treasury, so it may all go back
+    CrankVault { bump: u8 },
+    /// Redemption vault; its lamports back the POINTS floor value
+    RedemptionVault { bump: u8 },
+    /// Guild vault; its lamports belong to the guild
+    GuildVault { guild: Pubkey, bump: u8 },
+    /// Fee escrow; its lamports are owed to players or fee recipients until
+    /// the fees are released, and a slashed host bond to players after
+    FeeEscrow {
+        tournament: Pubkey,
+        released: bool,
+        slashed_lamports: u64,
+        bump: u8,
+    },
+    /// Prize pool; unpaid prizes stay for the winners
+    PrizePool {
+        tournament: Pubkey,
+        denomination: PrizeDenomination,
+        mint: Pubkey,
+        unpaid: u64,
+        bump: u8,
+    },
+}
+
+impl RecoverySource {
+    /// Identify a program-owned source account by its discriminator
+    fn load(info: &AccountInfo) -> Result<Self> {
+        require_keys_eq!(*info.owner, crate::ID, ArenaError::InvalidAssetRecovery);
+        let data = info.try_borrow_data()?;
+        let discriminator: [u8; 8] = data
+            .get(..8)
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or(ArenaError::InvalidAssetRecovery)?;
+        let bytes = &mut &data[..];
+
+        Ok(match discriminator {
+            PointsMintAuthority::DISCRIMINATOR => Self::MintAuthority {
+                bump: PointsMintAuthority::try_deserialize(bytes)?.bump,
+            },
+            CrankVault::DISCRIMINATOR => Self::CrankVault {
+                bump: CrankVault::try_deserialize(bytes)?.bump,
+            },
+            RedemptionVault::DISCRIMINATOR => Self::RedemptionVault {
+                bump: RedemptionVault::try_deserialize(bytes)?.bump,
+            },
+            GuildVault::DISCRIMINATOR => {
+                let vault = GuildVault::try_deserialize(bytes)?;
+                Self::GuildVault {
+                    guild: vault.guild,
+                    bump: vault.bump,
+                }
+            }
+            FeeEscrow::DISCRIMINATOR => {
+                let escrow = FeeEscrow::try_deserialize(bytes)?;
+                Self::FeeEscrow {
+                    tournament: escrow.tournament,
+                    released: escrow.released,
+                    slashed_lamports: escrow.slashed_lamports,
+                    bump: escrow.bump,
+                }
+            }
+            PrizePool::DISCRIMINATOR => {
+                let pool = PrizePool::try_deserialize(bytes)?;
+                Self::PrizePool {
+                    tournament: pool.tournament,
+                    denomination: pool.denomination,
+                    mint: pool.mint,
+                    unpaid: pool.total.saturating_sub(pool.paid_out),
+                    bump: pool.bump,
+                }
+            }
+            _ => return err!(ArenaError::InvalidAssetRecovery),
+        })
+    }
+
+    /// Lamports above rent exemption the source must keep, or None if none
+    /// of its lamports may be swept
+    fn reserved_lamports(&self) -> Option<u64> {
+        match self {
+            Self::MintAuthority { .. } | Self::CrankVault { .. } => Some(0),
+            Self::FeeEscrow {
+                released,
+                slashed_lamports,
+                ..
+            } => released.then_some(*slashed_lamports),
+            Self::PrizePool {
+                denomination,
+                unpaid,
+                ..
+            } => Some(if *denomination == PrizeDenomination::Sol {
+                *unpaid
+            } else {
+                0
+            }),
+            Self::RedemptionVault { .. } | Self::GuildVault { .. } => None,
+        }
+    }
+
+    /// Treasury ledger category of the source, or None if it is not a vault
+    fn ledger_category(&self) -> Option<LedgerCategory> {
+        match self {
+            Self::MintAuthority { .. } => None,
+            Self::CrankVault { .. } => Some(LedgerCategory::CrankVault),
+            Self::RedemptionVault { .. } => Some(LedgerCategory::RedemptionVault),
+            Self::GuildVault { .. } => Some(LedgerCategory::GuildVault),
+            Self::FeeEscrow { .. } => Some(LedgerCategory::FeeEscrow),
+            Self::PrizePool { .. } => Some(LedgerCategory::PrizePool),
+        }
+    }
+
+    /// Tokens of `mint` the source must keep
+    fn reserved_tokens(&self, mint: &Pubkey) -> u64 {
+        match self {
+            Self::PrizePool {
+                denomination: PrizeDenomination::Usdc,
+                mint: pool_mint,
+                unpaid,
+                ..
+            } if pool_mint == mint => *unpaid,
+            _ => 0,
+        }
+    }
+
+    /// Seeds the source signs token transfers with
+    fn signer_seeds(&self) -> Vec<Vec<u8>> {
+        match self {
+            Self::MintAuthority { bump } => {
+                vec![PointsMintAuthority::SEED_PREFIX.to_vec(), vec![*bump]]
+            }
+            Self::CrankVault { bump } => vec![CrankVault::SEED_PREFIX.to_vec(), vec![*bump]],
+            Self::RedemptionVault { bump } => {
+                vec![RedemptionVault::SEED_PREFIX.to_vec(), vec![*bump]]
+            }
+            Self::GuildVault { guild, bump } => vec![
+                GuildVault::SEED_PREFIX.to_vec(),
+                guild.to_bytes().to_vec(),
+                vec![*bump],
+            ],
+            Self::FeeEscrow {
+                tournament, bump, ..
+            } => vec![
+                FeeEscrow::SEED_PREFIX.to_vec(),
+                tournament.to_bytes().to_vec(),
+                vec![*bump],
+            ],
+            Self::PrizePool {
+                tournament, bump, ..
+            } => vec![
+                PrizePool::SEED_PREFIX.to_vec(),
+                tournament.to_bytes().to_vec(),
+                vec![*bump],
+            ],
+        }
+    }
+}
+
+/// Execute a queued sweep of stranded assets to the treasury (admin only).
+///
+/// This instruction:
+/// 1. Requires the sweep's timelock to have passed
+/// 2. Identifies the source: the mint authority, or the redemption, crank,
+///    guild, fee escrow or prize pool vault
+/// 3. Moves lamports above the source's rent exemption and anything it
+///    still holds for someone to the treasury, or tokens from a source
+///    token account to a treasury token account of the same mint
+/// 4. Closes the queued sweep and emits StrandedAssetsRecovered
+///
+/// Held back from the sweep: all lamports of the redemption vault (they
+/// back redemptions) and of guild vaults (they belong to the guild), fee
+/// escrow lamports until the fees are released and any slashed host bond
+/// after, and a prize pool's unpaid prizes in its own denomination.
+pub fn handler(ctx: Context<RecoverStrandedAssets>) -> Result<()> {
+    let asset_recovery = &ctx.accounts.asset_recovery;
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        now >= asset_recovery.executable_at,
+        ArenaError::InvalidAssetRecovery
+    );
+    let amount = asset_recovery.amount;
+    let source_info = ctx.accounts.source.to_account_info();
+    let source = RecoverySource::load(&source_info)?;
+
+    match asset_recovery.mint {
+        None => {
+            let reserved = source
+                .reserved_lamports()
+                .ok_or(ArenaError::InvalidAssetRecovery)?;
+            let rent_floor = Rent::get()?.minimum_balance(source_info.data_len());
+            require!(
+                source_info
+                    .lamports()
+                    .saturating_sub(rent_floor)
+                    .saturating_sub(reserved)
+                    >= amount,
+                ArenaError::InvalidAssetRecovery
+            );
+            **source_info.try_borrow_mut_lamports()? -= amount;
+            **ctx.accounts.treasury.try_borrow_mut_lamports()? += amount;
+            if let (Some(treasury_ledger), Some(category)) = (
+                ctx.accounts.treasury_ledger.as_mut(),
+                source.ledger_category(),
+            ) {
+                treasury_ledger.record(
+                    category,
+                    LedgerDirection::Outflow,
+                    source_info.key(),
+                    amount,
+                    now,
+                );
+            }
+        }
+        Some(mint) => {
+            let (Some(stranded), Some(destination), Some(token_program)) = (
+                ctx.accounts.stranded_token_account.as_ref(),
+                ctx.accounts.treasury_token_account.as_ref(),
+                ctx.accounts.token_program.as_ref(),
+            ) else {
+                return err!(ArenaError::InvalidAssetRecovery);
+            };
+            let available = stranded
+                .amount
+                .saturating_sub(source.reserved_tokens(&mint));
+            require!(
+                stranded.mint == mint && destination.mint == mint && available >= amount,
+                ArenaError::InvalidAssetRecovery
+            );
+
+            let seeds = source.signer_seeds();
+            let seeds: Vec<&[u8]> = seeds.iter().map(Vec::as_slice).collect();
+            let signer_seeds = &[&seeds[..]];
+
+            token::transfer(
+                CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    Transfer {
+                        from: stranded.to_account_info(),
+                        to: destination.to_account_info(),
+                        authority: source_info.clone(),
+                    },
+                    signer_seeds,
+                ),
+                amount,
+            )?;
+        }
+    }
+
+    emit!(StrandedAssetsRecovered {
+        source: asset_recovery.source,
+        mint: asset_recovery.mint,
+        amount,
+        treasury: ctx.accounts.treasury.key(),
+        timestamp: now,
+    });
+
+    msg!(
+        "Swept {} stranded on {} to the treasury",
+        amount,
+        asset_recovery.source
+    );
+
+    AuditLog::record_call::<instruction::RecoverStrandedAssets>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        0,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::state::{Invite, Tournament};
+
+/// Accounts required for redeeming an invite.
+#[derive(Accounts)]
+#[instruction(code: Vec<u8>)]
+pub struct RedeemInvite<'info> {
+    /// Wallet claiming the invite
+    pub player: Signer<'info>,
+
+    /// Tournament the invite admits to
+    #[account(
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.is_registration_open() @ ArenaError::RegistrationNotOpen
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Invite for the hash of `code` - must be unused
+    #[account(
+        mut,
+        seeds = [Invite::SEED_PREFIX, tournament.key().as_ref(), &Invite::hash_code(&code)],
+        bump = invite.bump,
+        constraint = !invite.is_redeemed() @ ArenaError::InvalidInvite
+    )]
+    pub invite: Account<'info, Invite>,
+}
+
+/// Claim an invite by revealing its code, binding it to the player's wallet.
+///
+/// Send it in the same transaction as the registration, which then passes
+/// the invite.
+///
+/// # Arguments
+/// * `code` - Invite code whose SHA-256 hash the invite was created with
+pub fn handler(ctx: Context<RedeemInvite>, code: Vec<u8>) -> Result<()> {
+    let invite = &mut ctx.accounts.invite;
+    require!(
+        Invite::hash_code(&code) == invite.code_hash,
+        ArenaError::InvalidInvite
+    );
+    invite.redeemed_by = ctx.accounts.player.key();
+
+    msg!(
+        "Invite {} redeemed by {} for tournament {}",
+        invite.key(),
+        invite.redeemed_by,
+        ctx.accounts.tournament.id
+    );
+
+    Ok(())
+}
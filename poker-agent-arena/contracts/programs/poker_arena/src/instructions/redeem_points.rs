@@ -0,0 +1,124 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount};
+
+use crate::errors::ArenaError;
+use crate::state::{ArenaConfig, LedgerCategory, LedgerDirection, RedemptionVault, TreasuryLedger};
+
+/// Accounts required for redeeming POINTS for SOL.
+#[derive(Accounts)]
+pub struct RedeemPoints<'info> {
+    /// POINTS holder redeeming tokens
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    /// Arena config for the points mint
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Redemption vault paying out SOL
+    #[account(
+        mut,
+        seeds = [RedemptionVault::SEED_PREFIX],
+        bump = redemption_vault.bump
+    )]
+    pub redemption_vault: Account<'info, RedemptionVault>,
+
+    /// POINTS SPL token mint
+    #[account(
+        mut,
+        constraint = points_mint.key() == arena_config.points_mint @ ArenaError::InvalidTierPayment
+    )]
+    pub points_mint: Account<'info, Mint>,
+
+    /// Holder's POINTS token account to burn from
+    #[account(
+        mut,
+        constraint = holder_token_account.mint == points_mint.key() @ ArenaError::InvalidTierPayment,
+        constraint = holder_token_account.owner == holder.key() @ ArenaError::Unauthorized
+    )]
+    pub holder_token_account: Account<'info, TokenAccount>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+
+    /// Treasury ledger recording the movement (omit to skip)
+    #[account(
+        mut,
+        seeds = [TreasuryLedger::SEED_PREFIX],
+        bump = treasury_ledger.bump
+    )]
+    pub treasury_ledger: Option<Box<Account<'info, TreasuryLedger>>>,
+}
+
+/// Burn POINTS and receive SOL from the redemption vault.
+///
+/// This instruction:
+/// 1. Quotes the payout at the fixed rate, or pro-rata against circulating supply
+/// 2. Burns the POINTS from the holder's token account
+/// 3. Pays the quoted lamports from the vault, never dipping below rent exemption
+///
+/// # Arguments
+/// * `amount` - POINTS to redeem (base units)
+pub fn handler(ctx: Context<RedeemPoints>, amount: u64) -> Result<()> {
+    let vault_info = ctx.accounts.redemption_vault.to_account_info();
+    let rent_floor = Rent::get()?.minimum_balance(vault_info.data_len());
+    let available = vault_info.lamports().saturating_sub(rent_floor);
+
+    let payout = ctx
+        .accounts
+        .redemption_vault
+        .quote(
+            amount,
+            available,
+            ctx.accounts.points_mint.supply,
+            ctx.accounts.arena_config.base_units_per_point(),
+        )
+        .ok_or(ArenaError::InsufficientRedemptionFunds)?;
+    require!(
+        payout > 0 && payout <= available,
+        ArenaError::InsufficientRedemptionFunds
+    );
+
+    token::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.points_mint.to_account_info(),
+                from: ctx.accounts.holder_token_account.to_account_info(),
+                authority: ctx.accounts.holder.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    **vault_info.try_borrow_mut_lamports()? -= payout;
+    **ctx
+        .accounts
+        .holder
+        .to_account_info()
+        .try_borrow_mut_lamports()? += payout;
+
+    let redemption_vault = &mut ctx.accounts.redemption_vault;
+    redemption_vault.total_points_redeemed = redemption_vault
+        .total_points_redeemed
+        .saturating_add(amount);
+    redemption_vault.total_lamports_paid =
+        redemption_vault.total_lamports_paid.saturating_add(payout);
+
+    if let Some(treasury_ledger) = ctx.accounts.treasury_ledger.as_mut() {
+        treasury_ledger.record(
+            LedgerCategory::RedemptionVault,
+            LedgerDirection::Outflow,
+            ctx.accounts.redemption_vault.key(),
+            payout,
+            Clock::get()?.unix_timestamp,
+        );
+    }
+
+    msg!("Redeemed {} POINTS for {} lamports", amount, payout);
+
+    Ok(())
+}
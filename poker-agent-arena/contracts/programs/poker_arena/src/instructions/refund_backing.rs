@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::state::{
+    BackerShare, BackingPool, LedgerCategory, LedgerDirection, Tournament, TournamentStatus,
+    TreasuryLedger,
+};
+
+/// Accounts required for refunding a backer.
+#[derive(Accounts)]
+pub struct RefundBacking<'info> {
+    /// Backer reclaiming their contribution
+    #[account(mut)]
+    pub backer: Signer<'info>,
+
+    /// Tournament the pool was raised for
+    #[account(
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Backing pool - must not have been withdrawn
+    #[account(
+        mut,
+        seeds = [BackingPool::SEED_PREFIX, tournament.key().as_ref(), backing_pool.player.as_ref()],
+        bump = backing_pool.bump,
+        constraint = !backing_pool.withdrawn @ ArenaError::BackingAlreadyWithdrawn
+    )]
+    pub backing_pool: Account<'info, BackingPool>,
+
+    /// Backer's share (closed on refund)
+    #[account(
+        mut,
+        close = backer,
+        seeds = [BackerShare::SEED_PREFIX, backing_pool.key().as_ref(), backer.key().as_ref()],
+        bump = backer_share.bump
+    )]
+    pub backer_share: Account<'info, BackerShare>,
+
+    /// Treasury ledger recording the refund (omit to skip)
+    #[account(
+        mut,
+        seeds = [TreasuryLedger::SEED_PREFIX],
+        bump = treasury_ledger.bump
+    )]
+    pub treasury_ledger: Option<Box<Account<'info, TreasuryLedger>>>,
+}
+
+/// Refund a backer whose player never withdrew the pool.
+///
+/// Allowed once registration has closed (or the tournament was cancelled
+/// or voided) without the player withdrawing.
+pub fn handler(ctx: Context<RefundBacking>) -> Result<()> {
+    let status = ctx.accounts.tournament.status;
+    require!(
+        status != TournamentStatus::Created && status != TournamentStatus::Registration,
+        ArenaError::BackingNotRefundable
+    );
+
+    let backing_pool = &mut ctx.accounts.backing_pool;
+    let backer_share = &ctx.accounts.backer_share;
+    let amount = backer_share.lamports;
+
+    **backing_pool.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **ctx
+        .accounts
+        .backer
+        .to_account_info()
+        .try_borrow_mut_lamports()? += amount;
+    if let Some(treasury_ledger) = ctx.accounts.treasury_ledger.as_mut() {
+        treasury_ledger.record(
+            LedgerCategory::BackingEscrow,
+            LedgerDirection::Outflow,
+            backing_pool.key(),
+            amount,
+            Clock::get()?.unix_timestamp,
+        );
+    }
+
+    backing_pool.raised_lamports -= amount;
+    backing_pool.sold_share_bps -= backer_share.share_bps;
+
+    msg!("Refunded {} lamports of backing", amount);
+
+    Ok(())
+}
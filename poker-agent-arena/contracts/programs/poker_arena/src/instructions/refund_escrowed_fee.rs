@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::state::{
+    ArenaConfig, FeeEscrow, LedgerCategory, LedgerDirection, PlayerRegistration, Tournament,
+    TournamentStatus, TreasuryLedger,
+};
+
+/// Accounts required for refunding a tier fee from a tournament's fee escrow.
+#[derive(Accounts)]
+pub struct RefundEscrowedFee<'info> {
+    /// Anyone may crank the refund; it is paid to the registered wallet
+    pub caller: Signer<'info>,
+
+    /// Arena config for the lifetime refund counter
+    #[account(
+        mut,
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament - must be Cancelled or Voided
+    #[account(
+        mut,
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = matches!(
+            tournament.status,
+            TournamentStatus::Cancelled | TournamentStatus::Voided
+        ) @ ArenaError::TournamentNotVoided
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Tournament's fee escrow - must not have been released, unless it
+    /// still holds a slashed host bond
+    #[account(
+        mut,
+        seeds = [FeeEscrow::SEED_PREFIX, tournament.key().as_ref()],
+        bump = fee_escrow.bump,
+        constraint = !fee_escrow.released || fee_escrow.slashed_lamports > 0 @ ArenaError::FeeEscrowReleased
+    )]
+    pub fee_escrow: Account<'info, FeeEscrow>,
+
+    /// Registration being refunded
+    #[account(
+        mut,
+        seeds = [PlayerRegistration::SEED_PREFIX, tournament.key().as_ref(), registration.wallet.as_ref()],
+        bump = registration.bump,
+        constraint = registration.tournament == tournament.key() @ ArenaError::TournamentNotFound
+    )]
+    pub registration: Account<'info, PlayerRegistration>,
+
+    /// Account that paid the tier fee, receiving the refund
+    /// CHECK: Verified against registration.fee_payer
+    #[account(
+        mut,
+        address = registration.fee_payer @ ArenaError::Unauthorized
+    )]
+    pub fee_payer: UncheckedAccount<'info>,
+
+    /// Treasury ledger recording the refund (omit to skip)
+    #[account(
+        mut,
+        seeds = [TreasuryLedger::SEED_PREFIX],
+        bump = treasury_ledger.bump
+    )]
+    pub treasury_ledger: Option<Box<Account<'info, TreasuryLedger>>>,
+}
+
+/// Refund a tier fee straight from the fee escrow of a tournament that was
+/// cancelled or voided before its fees were released, or from the host bond
+/// slashed into it (permissionless). The refund goes to the account that
+/// paid the fee.
+///
+/// This instruction:
+/// 1. Computes the fee paid minus any earlier partial refund, or the
+///    portion retained when the player unregistered
+/// 2. Moves it from the fee escrow back to whoever paid it; once the fees
+///    are released, only as much as is left of the slashed bond, with the
+///    rest still refundable from the treasury
+/// 3. Records the refund on the registration so it cannot be paid twice
+pub fn handler(ctx: Context<RefundEscrowedFee>) -> Result<()> {
+    let registration = &mut ctx.accounts.registration;
+
+    let fee_escrow = &mut ctx.accounts.fee_escrow;
+    let refund_lamports = if fee_escrow.released {
+        let refund_lamports = registration
+            .unsettled_fee_lamports()
+            .min(fee_escrow.slashed_lamports);
+        fee_escrow.slashed_lamports -= refund_lamports;
+        refund_lamports
+    } else {
+        registration.unsettled_fee_lamports()
+    };
+    require!(refund_lamports > 0, ArenaError::NothingToRefund);
+
+    **fee_escrow.to_account_info().try_borrow_mut_lamports()? -= refund_lamports;
+    **ctx.accounts.fee_payer.try_borrow_mut_lamports()? += refund_lamports;
+    if let Some(treasury_ledger) = ctx.accounts.treasury_ledger.as_mut() {
+        treasury_ledger.record(
+            LedgerCategory::FeeEscrow,
+            LedgerDirection::Outflow,
+            ctx.accounts.fee_escrow.key(),
+            refund_lamports,
+            Clock::get()?.unix_timestamp,
+        );
+    }
+
+    registration.refunded_lamports += refund_lamports;
+    ctx.accounts.tournament.record_refund(refund_lamports);
+    ctx.accounts.arena_config.record_refund(refund_lamports);
+
+    msg!(
+        "Refunded {} escrowed lamports to player: {}",
+        refund_lamports,
+        registration.wallet
+    );
+
+    Ok(())
+}
@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instructions::register_player::refund_tier_fee;
+use crate::state::{
+    ArenaConfig, FeeEscrow, Host, PlayerRegistration, Tournament, TournamentStatus,
+};
+
+/// Accounts required for refunding a registration in a voided tournament, or
+/// a cancelled one whose fees were paid to the treasury.
+#[derive(Accounts)]
+pub struct RefundVoidedRegistration<'info> {
+    /// Treasury wallet - must match arena_config.treasury
+    #[account(mut)]
+    pub treasury: Signer<'info>,
+
+    /// Arena config for treasury verification
+    #[account(
+        mut,
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.treasury == treasury.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament - must be Voided, or Cancelled without escrowed fees
+    #[account(
+        mut,
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.status == TournamentStatus::Voided
+            || (tournament.status == TournamentStatus::Cancelled && !tournament.fees_escrowed)
+            @ ArenaError::TournamentNotVoided
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Registration being refunded
+    #[account(
+        mut,
+        seeds = [PlayerRegistration::SEED_PREFIX, tournament.key().as_ref(), registration.wallet.as_ref()],
+        bump = registration.bump,
+        constraint = registration.tournament == tournament.key() @ ArenaError::TournamentNotFound
+    )]
+    pub registration: Account<'info, PlayerRegistration>,
+
+    /// Account that paid the tier fee, receiving the refund
+    /// CHECK: Verified against registration.fee_payer
+    #[account(
+        mut,
+        address = registration.fee_payer @ ArenaError::Unauthorized
+    )]
+    pub fee_payer: UncheckedAccount<'info>,
+
+    /// Tournament's fee escrow, required if the tournament escrows fees -
+    /// must have been released
+    #[account(
+        seeds = [FeeEscrow::SEED_PREFIX, tournament.key().as_ref()],
+        bump = fee_escrow.bump
+    )]
+    pub fee_escrow: Option<Account<'info, FeeEscrow>>,
+
+    /// Tournament's host, required if the tournament is hosted
+    #[account(
+        seeds = [Host::SEED_PREFIX, host.authority.as_ref()],
+        bump = host.bump
+    )]
+    pub host: Option<Account<'info, Host>>,
+
+    /// System program for the refund transfers
+    pub system_program: Program<'info, System>,
+}
+
+/// Refund the unrefunded remainder of a tier fee in a voided tournament, or a
+/// cancelled one whose fees went to the treasury (treasury only).
+///
+/// Fee recipients are passed as remaining accounts, in slot order, and sign
+/// to return their share like the treasury.
+///
+/// This instruction:
+/// 1. Computes the fee paid minus any earlier partial refund, or the
+///    portion retained when the player unregistered; for a hosted
+///    tournament, only the platform fee the arena received from it
+/// 2. Returns it to whoever paid it, split between the fee recipients and
+///    the treasury in the shares they were paid
+/// 3. Records the refund on the registration so it cannot be paid twice
+///
+/// Fees still held in the tournament's fee escrow are refunded from it with
+/// `refund_escrowed_fee`, as is a host's share from its slashed bond.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RefundVoidedRegistration<'info>>,
+) -> Result<()> {
+    if ctx.accounts.tournament.fees_escrowed {
+        let fee_escrow = ctx
+            .accounts
+            .fee_escrow
+            .as_ref()
+            .ok_or(ArenaError::FeeEscrowRequired)?;
+        require!(fee_escrow.released, ArenaError::FeeEscrowNotReleased);
+    }
+
+    let registration = &mut ctx.accounts.registration;
+    let unsettled_lamports = registration.unsettled_fee_lamports();
+    let refund_lamports =
+        match Host::for_tournament(ctx.accounts.host.as_mut(), &ctx.accounts.tournament)? {
+            Some(host) => host.platform_fee(unsettled_lamports),
+            None => unsettled_lamports,
+        };
+    require!(refund_lamports > 0, ArenaError::NothingToRefund);
+
+    refund_tier_fee(
+        &ctx.accounts.system_program,
+        &ctx.accounts.treasury,
+        &ctx.accounts.fee_payer,
+        &ctx.accounts.arena_config,
+        ctx.remaining_accounts,
+        refund_lamports,
+    )?;
+
+    registration.refunded_lamports = registration
+        .refunded_lamports
+        .checked_add(refund_lamports)
+        .ok_or(ArenaError::ArithmeticOverflow)?;
+    ctx.accounts.tournament.record_refund(refund_lamports);
+    ctx.accounts.arena_config.record_refund(refund_lamports);
+
+    msg!("Refunded {} lamports to player: {}", refund_lamports, registration.wallet);
+
+    Ok(())
+}
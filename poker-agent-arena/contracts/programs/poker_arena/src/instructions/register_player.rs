@@ -1,18 +1,29 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{get_stack_height, TRANSACTION_LEVEL_STACK_HEIGHT};
+use anchor_lang::solana_program::sysvar;
+use anchor_lang::solana_program::sysvar::instructions::{load_current_index_checked, load_instruction_at_checked};
 use anchor_lang::system_program;
 
 use crate::errors::ArenaError;
-use crate::state::{AgentTier, ArenaConfig, PlayerRegistration, Tournament};
+use crate::events::PlayerRegistered;
+use crate::oracle::Price;
+use crate::state::{AgentTier, AppealableSanction, ApprovedModel, ArenaConfig, CpiPartner, DailyTicket, FeeEscrow, Guild, GuildMember, GuildVault, Invite, LadderRating, LedgerCategory, LedgerDirection, PaymentKind, PaymentReceipt, PlayerRegistration, PlayerStats, RegistrationIndex, Season, SeasonPass, Ticket, Tournament, TreasuryLedger, VoteStake};
 
 /// Accounts required for player registration.
 #[derive(Accounts)]
 pub struct RegisterPlayer<'info> {
-    /// Player wallet registering for the tournament
+    /// Player wallet registering for the tournament; pays the tier fee
     #[account(mut)]
     pub player: Signer<'info>,
 
-    /// Arena config account (for treasury address)
+    /// Pays rent for the registration; a relayer for sponsored
+    /// registrations, otherwise the player
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Arena config account (for treasury address and fee counters)
     #[account(
+        mut,
         seeds = [ArenaConfig::SEED_PREFIX],
         bump = arena_config.bump
     )]
@@ -23,6 +34,7 @@ pub struct RegisterPlayer<'info> {
         mut,
         seeds = [
             Tournament::SEED_PREFIX,
+            tournament.host_seed(),
             tournament.id.to_le_bytes().as_ref()
         ],
         bump = tournament.bump,
@@ -34,7 +46,7 @@ pub struct RegisterPlayer<'info> {
     /// Player registration PDA to be created
     #[account(
         init,
-        payer = player,
+        payer = payer,
         space = PlayerRegistration::SIZE,
         seeds = [
             PlayerRegistration::SEED_PREFIX,
@@ -55,66 +67,229 @@ pub struct RegisterPlayer<'info> {
 
     /// System program for account creation and transfers
     pub system_program: Program<'info, System>,
+
+    /// Player's lifetime stats, for the loyalty discount (omit if none)
+    #[account(
+        seeds = [PlayerStats::SEED_PREFIX, player.key().as_ref()],
+        bump = player_stats.bump
+    )]
+    pub player_stats: Option<Account<'info, PlayerStats>>,
+
+    /// Season covering this tournament (omit if the player has no pass)
+    pub season: Option<Account<'info, Season>>,
+
+    /// Player's season pass, waiving BASIC tier fees
+    pub season_pass: Option<Account<'info, SeasonPass>>,
+
+    /// Player's daily freeroll tickets; one is spent to waive a BASIC tier fee
+    #[account(
+        mut,
+        seeds = [DailyTicket::SEED_PREFIX, player.key().as_ref()],
+        bump = daily_ticket.bump
+    )]
+    pub daily_ticket: Option<Account<'info, DailyTicket>>,
+
+    /// Approved model backing the agent (required for PRO tier)
+    #[account(
+        seeds = [ApprovedModel::SEED_PREFIX, approved_model.model_id_hash.as_ref()],
+        bump = approved_model.bump
+    )]
+    pub approved_model: Option<Account<'info, ApprovedModel>>,
+
+    /// Player's guild membership, for guild-sponsored tier fees (omit if none)
+    #[account(
+        seeds = [GuildMember::SEED_PREFIX, player.key().as_ref()],
+        bump = guild_member.bump
+    )]
+    pub guild_member: Option<Account<'info, GuildMember>>,
+
+    /// Player's guild
+    #[account(
+        seeds = [Guild::SEED_PREFIX, guild.name.as_ref()],
+        bump = guild.bump
+    )]
+    pub guild: Option<Account<'info, Guild>>,
+
+    /// Guild vault paying the tier fee when the guild sponsors members
+    #[account(
+        mut,
+        seeds = [GuildVault::SEED_PREFIX, guild_vault.guild.as_ref()],
+        bump = guild_vault.bump
+    )]
+    pub guild_vault: Option<Account<'info, GuildVault>>,
+
+    /// Instructions sysvar, required when called via CPI
+    /// CHECK: Validated by address
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+
+    /// Allowlist entry for the calling program, required when called via CPI
+    #[account(
+        seeds = [CpiPartner::SEED_PREFIX, cpi_partner.program_id.as_ref()],
+        bump = cpi_partner.bump
+    )]
+    pub cpi_partner: Option<Account<'info, CpiPartner>>,
+
+    /// Solana Pay reference key identifying this payment (omit if none)
+    /// CHECK: Read-only marker; only its address is used
+    pub reference: Option<UncheckedAccount<'info>>,
+
+    /// Receipt for the tier fee (omit to skip)
+    #[account(
+        init,
+        payer = payer,
+        space = PaymentReceipt::SIZE,
+        seeds = [PaymentReceipt::SEED_PREFIX, registration.key().as_ref(), &[PaymentKind::Tier as u8]],
+        bump
+    )]
+    pub payment_receipt: Option<Account<'info, PaymentReceipt>>,
+
+    /// Treasury ledger recording a guild-sponsored or escrowed fee (omit to skip)
+    #[account(
+        mut,
+        seeds = [TreasuryLedger::SEED_PREFIX],
+        bump = treasury_ledger.bump
+    )]
+    pub treasury_ledger: Option<Box<Account<'info, TreasuryLedger>>>,
+
+    /// Registering wallet's governance stake, for the holder discount (omit if none)
+    #[account(
+        seeds = [VoteStake::SEED_PREFIX, player.key().as_ref()],
+        bump = vote_stake.bump
+    )]
+    pub vote_stake: Option<Box<Account<'info, VoteStake>>>,
+
+    /// Tickets bought with POINTS; one is spent to waive the fee of their tier (omit if none)
+    #[account(
+        mut,
+        seeds = [Ticket::SEED_PREFIX, player.key().as_ref(), &[ticket.tier as u8]],
+        bump = ticket.bump
+    )]
+    pub ticket: Option<Box<Account<'info, Ticket>>>,
+
+    /// Player's ladder rating, required for division-restricted tournaments
+    #[account(
+        seeds = [LadderRating::SEED_PREFIX, player.key().as_ref()],
+        bump = ladder_rating.bump
+    )]
+    pub ladder_rating: Option<Box<Account<'info, LadderRating>>>,
+
+    /// Pyth SOL/USD price update, required while tier fees are priced in USD
+    /// CHECK: Owner, layout and feed id are validated by oracle::Price::load
+    pub price_update: Option<UncheckedAccount<'info>>,
+
+    /// Tournament's fee escrow, required while its tier fees are escrowed
+    #[account(
+        mut,
+        seeds = [FeeEscrow::SEED_PREFIX, tournament.key().as_ref()],
+        bump = fee_escrow.bump
+    )]
+    pub fee_escrow: Option<Box<Account<'info, FeeEscrow>>>,
+
+    /// Invite redeemed by the registering wallet, required for invite-only tournaments
+    pub invite: Option<Box<Account<'info, Invite>>>,
+
+    /// Wallet's registration index, appended with this tournament (omit if none)
+    #[account(
+        mut,
+        seeds = [RegistrationIndex::SEED_PREFIX, player.key().as_ref()],
+        bump = registration_index.bump
+    )]
+    pub registration_index: Option<Box<Account<'info, RegistrationIndex>>>,
 }
 
 /// Register a player for a tournament.
 ///
+/// Rent is paid by `payer`, so a relayer can sponsor players who hold no SOL;
+/// the tier fee is debited from the player unless their guild sponsors it
+/// from the guild vault. Configured fee recipients are passed as remaining
+/// accounts, in slot order.
+///
+/// Other programs may only call this via CPI once allowlisted with
+/// `set_cpi_partner`, passing the instructions sysvar and their CpiPartner entry.
+///
+/// Point-of-sale flows may pass a Solana Pay reference key, which is echoed
+/// in the PlayerRegistered event so the payment can be reconciled. Passing a
+/// PaymentReceipt account records an on-chain receipt of the tier fee, and
+/// passing the TreasuryLedger records a guild-sponsored fee.
+///
+/// Clients may pass a correlation id, echoed in the PlayerRegistered event,
+/// to match the registration to their off-chain agent-builder session.
+///
 /// # Arguments
 /// * `ctx` - The context containing all accounts
 /// * `tier` - The agent tier (FREE, BASIC, or PRO)
 /// * `agent_prompt_hash` - SHA-256 hash of the custom prompt (for verification)
-/// * `agent_name` - Display name for the agent (32 bytes, UTF-8)
-/// * `agent_image_uri` - URI for agent avatar image (128 bytes)
-pub fn handler(
-    ctx: Context<RegisterPlayer>,
+/// * `agent_name` - Display name for the agent (32 bytes, UTF-8, null-padded)
+/// * `agent_image_uri` - URI for agent avatar image (128 bytes, ipfs:// or https://, or all zeros for none)
+/// * `correlation_id` - Client-supplied id echoed in the event (0 if unused)
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RegisterPlayer<'info>>,
     tier: AgentTier,
     agent_prompt_hash: [u8; 32],
     agent_name: [u8; 32],
     agent_image_uri: [u8; 128],
+    correlation_id: u64,
 ) -> Result<()> {
-    let tournament = &mut ctx.accounts.tournament;
-    let registration = &mut ctx.accounts.registration;
     let player = &ctx.accounts.player;
-    let treasury = &ctx.accounts.treasury;
-
-    // Get tier cost
-    let tier_cost = tier.cost_lamports();
-
-    // Transfer tier fee to treasury (if not FREE)
-    if tier_cost > 0 {
-        system_program::transfer(
-            CpiContext::new(
-                ctx.accounts.system_program.to_account_info(),
-                system_program::Transfer {
-                    from: player.to_account_info(),
-                    to: treasury.to_account_info(),
-                },
-            ),
-            tier_cost,
-        )?;
-    }
+    let entry = AgentEntry {
+        tier,
+        agent_prompt_hash,
+        agent_name,
+        agent_image_uri,
+    };
 
-    // Get current timestamp
-    let clock = Clock::get()?;
+    // The tier fee comes from the guild vault if it sponsors the player
+    let fee_source = match sponsoring_guild_vault(
+        ctx.accounts.guild_member.as_ref(),
+        ctx.accounts.guild.as_ref(),
+        ctx.accounts.guild_vault.as_mut(),
+    )? {
+        Some(guild_vault) => FeeSource::GuildVault(guild_vault),
+        None => FeeSource::Wallet(player),
+    };
+    let fee_payer = fee_source.key();
 
-    // Initialize registration
-    registration.tournament = tournament.key();
-    registration.wallet = player.key();
-    registration.tier = tier;
-    registration.registered_at = clock.unix_timestamp;
-    registration.agent_prompt_hash = agent_prompt_hash;
-    registration.agent_name = agent_name;
-    registration.agent_image_uri = agent_image_uri;
-    registration.final_rank = None;
-    registration.points_awarded = None;
-    registration.hands_played = None;
-    registration.eliminations = None;
-    registration.points_distributed = false;
-    registration.bump = ctx.bumps.registration;
-
-    // Increment registered players count
-    tournament.registered_players += 1;
+    NewRegistration {
+        arena_config: &mut ctx.accounts.arena_config,
+        tournament: &mut ctx.accounts.tournament,
+        registration: &mut ctx.accounts.registration,
+        registration_bump: ctx.bumps.registration,
+        rent_payer: ctx.accounts.payer.key(),
+        treasury: &ctx.accounts.treasury,
+        system_program: &ctx.accounts.system_program,
+        fee_recipients: ctx.remaining_accounts,
+        instructions_sysvar: ctx.accounts.instructions_sysvar.as_ref(),
+        cpi_partner: ctx.accounts.cpi_partner.as_ref(),
+        ladder_rating: ctx.accounts.ladder_rating.as_deref(),
+        invite: ctx.accounts.invite.as_deref(),
+        approved_model: ctx.accounts.approved_model.as_ref(),
+        price_update: ctx.accounts.price_update.as_ref(),
+        fee_escrow: ctx.accounts.fee_escrow.as_deref(),
+        discounts: FeeDiscounts {
+            player_stats: ctx.accounts.player_stats.as_ref(),
+            season: ctx.accounts.season.as_ref(),
+            season_pass: ctx.accounts.season_pass.as_ref(),
+            daily_ticket: ctx.accounts.daily_ticket.as_mut(),
+            vote_stake: ctx.accounts.vote_stake.as_deref(),
+            ticket: ctx.accounts.ticket.as_deref_mut(),
+        },
+        registration_index: ctx.accounts.registration_index.as_deref_mut(),
+        payment_receipt: ctx
+            .accounts
+            .payment_receipt
+            .as_mut()
+            .zip(ctx.bumps.payment_receipt),
+        reference: ctx
+            .accounts
+            .reference
+            .as_ref()
+            .map(|reference| reference.key()),
+        treasury_ledger: ctx.accounts.treasury_ledger.as_deref_mut(),
+    }
+    .register(player.key(), &entry, fee_source, fee_payer, correlation_id)?;
 
+    let tournament = &ctx.accounts.tournament;
     msg!(
         "Player {} registered for tournament {} with {:?} tier",
         player.key(),
@@ -129,3 +304,604 @@ pub fn handler(
 
     Ok(())
 }
+
+/// Account paying a registration's tier fee.
+pub(crate) enum FeeSource<'a, 'info> {
+    /// A signing wallet, debited with a system transfer
+    Wallet(&'a Signer<'info>),
+    /// A guild vault sponsoring its member
+    GuildVault(&'a mut Account<'info, GuildVault>),
+}
+
+impl FeeSource<'_, '_> {
+    /// Address the fee is debited from
+    pub(crate) fn key(&self) -> Pubkey {
+        match self {
+            FeeSource::Wallet(wallet) => wallet.key(),
+            FeeSource::GuildVault(guild_vault) => guild_vault.key(),
+        }
+    }
+}
+
+/// Accounts shared by every way of registering a player.
+pub(crate) struct NewRegistration<'a, 'info> {
+    /// Arena config (for fee recipients and fee counters)
+    pub arena_config: &'a mut Account<'info, ArenaConfig>,
+    /// Tournament registered for
+    pub tournament: &'a mut Account<'info, Tournament>,
+    /// Newly created registration
+    pub registration: &'a mut Account<'info, PlayerRegistration>,
+    /// Bump of the registration PDA
+    pub registration_bump: u8,
+    /// Account paying the registration's rent
+    pub rent_payer: Pubkey,
+    /// Treasury wallet receiving tier fees
+    pub treasury: &'a AccountInfo<'info>,
+    /// System program for fee transfers
+    pub system_program: &'a Program<'info, System>,
+    /// Configured fee recipient accounts, in slot order
+    pub fee_recipients: &'a [AccountInfo<'info>],
+    /// Instructions sysvar, required when called via CPI
+    pub instructions_sysvar: Option<&'a UncheckedAccount<'info>>,
+    /// Allowlist entry for the calling program, required when called via CPI
+    pub cpi_partner: Option<&'a Account<'info, CpiPartner>>,
+    /// Player's ladder rating, for division-restricted tournaments
+    pub ladder_rating: Option<&'a Account<'info, LadderRating>>,
+    /// Invite redeemed by the player, for invite-only tournaments
+    pub invite: Option<&'a Account<'info, Invite>>,
+    /// Approved model backing a PRO agent
+    pub approved_model: Option<&'a Account<'info, ApprovedModel>>,
+    /// Pyth SOL/USD price update, while tier fees are priced in USD
+    pub price_update: Option<&'a UncheckedAccount<'info>>,
+    /// Tournament's fee escrow, while its tier fees are escrowed
+    pub fee_escrow: Option<&'a Account<'info, FeeEscrow>>,
+    /// Accounts reducing the tier fee
+    pub discounts: FeeDiscounts<'a, 'info>,
+    /// Player's registration index, appended with this tournament
+    pub registration_index: Option<&'a mut Account<'info, RegistrationIndex>>,
+    /// Receipt for the tier fee and its bump
+    pub payment_receipt: Option<(&'a mut Account<'info, PaymentReceipt>, u8)>,
+    /// Solana Pay reference key echoed in the event
+    pub reference: Option<Pubkey>,
+    /// Treasury ledger recording guild vault and fee escrow movements
+    pub treasury_ledger: Option<&'a mut Account<'info, TreasuryLedger>>,
+}
+
+impl<'info> NewRegistration<'_, 'info> {
+    /// Register `wallet` with the agent in `entry`, debiting the discounted
+    /// tier fee from `fee_source`, and emit PlayerRegistered.
+    ///
+    /// `fee_payer` is recorded on the registration and holds the receipt.
+    pub(crate) fn register(
+        self,
+        wallet: Pubkey,
+        entry: &AgentEntry,
+        fee_source: FeeSource<'_, 'info>,
+        fee_payer: Pubkey,
+        correlation_id: u64,
+    ) -> Result<()> {
+        let tournament = self.tournament;
+        let tier = entry.tier;
+
+        // Reject CPI from programs that are not allowlisted partners
+        verify_cpi_caller(self.instructions_sysvar, self.cpi_partner)?;
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            !tournament.is_registration_closed(now),
+            ArenaError::RegistrationClosed
+        );
+        require!(tournament.is_tier_open(tier, now), ArenaError::TierNotOpen);
+        check_division(tournament, self.ladder_rating, &wallet)?;
+        check_invite(tournament, self.invite, &wallet)?;
+
+        // Reject malformed agent metadata before taking any fee
+        entry.validate()?;
+        let model_id_hash = approved_model_hash(tier, self.approved_model)?;
+
+        // Get tier cost after loyalty, POINTS holder, season pass and ticket discounts
+        let base_fee = tier_base_fee(self.arena_config, tier, self.price_update, now)?;
+        let (tier_cost, holder_discount_bps) = self.discounts.apply(
+            self.arena_config,
+            tier,
+            base_fee,
+            &wallet,
+            tournament,
+        );
+
+        // Transfer tier fee to treasury or the fee escrow (if not FREE)
+        let fee_escrow = escrowed_fee_destination(tournament, self.fee_escrow)?;
+        let sponsoring_vault = match &fee_source {
+            FeeSource::GuildVault(guild_vault) => Some(guild_vault.key()),
+            FeeSource::Wallet(_) => None,
+        };
+        match fee_source {
+            FeeSource::Wallet(payer) => collect_tier_fee(
+                self.system_program,
+                payer,
+                self.treasury,
+                fee_escrow.as_ref(),
+                self.arena_config,
+                self.fee_recipients,
+                tier_cost,
+            )?,
+            FeeSource::GuildVault(guild_vault) => {
+                collect_tier_fee_from_vault(
+                    &guild_vault.to_account_info(),
+                    self.treasury,
+                    fee_escrow.as_ref(),
+                    self.arena_config,
+                    self.fee_recipients,
+                    tier_cost,
+                )?;
+                guild_vault.total_fees_paid = guild_vault.total_fees_paid.saturating_add(tier_cost);
+            }
+        }
+        if let Some(treasury_ledger) = self.treasury_ledger {
+            if let Some(guild_vault) = sponsoring_vault {
+                treasury_ledger.record(
+                    LedgerCategory::GuildVault,
+                    LedgerDirection::Outflow,
+                    guild_vault,
+                    tier_cost,
+                    now,
+                );
+            }
+            if let Some(fee_escrow) = fee_escrow.as_ref() {
+                treasury_ledger.record(
+                    LedgerCategory::FeeEscrow,
+                    LedgerDirection::Inflow,
+                    fee_escrow.key(),
+                    tier_cost,
+                    now,
+                );
+            }
+        }
+        tournament.record_fee(tier_cost);
+        self.arena_config.record_fee(tier_cost);
+
+        // Initialize registration
+        initialize_registration(
+            self.registration,
+            tournament.key(),
+            wallet,
+            entry,
+            tier_cost,
+            fee_payer,
+            self.rent_payer,
+            model_id_hash,
+            self.registration_bump,
+        )?;
+        self.registration.holder_discount_bps = holder_discount_bps;
+
+        // Increment registered players count
+        tournament.record_registration(tier)?;
+        if let Some(registration_index) = self.registration_index {
+            registration_index.append(tournament.id)?;
+        }
+
+        if let Some((payment_receipt, bump)) = self.payment_receipt {
+            payment_receipt.record(
+                fee_payer,
+                tournament.key(),
+                self.registration.key(),
+                tier_cost,
+                PaymentKind::Tier,
+                now,
+                bump,
+            );
+        }
+
+        emit!(PlayerRegistered {
+            tournament: tournament.key(),
+            wallet,
+            tier,
+            fee_lamports: tier_cost,
+            reference: self.reference,
+            correlation_id,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+}
+
+/// Agent metadata submitted with a registration.
+pub(crate) struct AgentEntry {
+    /// The agent tier (FREE, BASIC, or PRO)
+    pub tier: AgentTier,
+    /// SHA-256 hash of the custom prompt
+    pub agent_prompt_hash: [u8; 32],
+    /// Display name for the agent (32 bytes, UTF-8, null-padded)
+    pub agent_name: [u8; 32],
+    /// URI for agent avatar image (128 bytes, or all zeros for none)
+    pub agent_image_uri: [u8; 128],
+}
+
+impl AgentEntry {
+    /// Reject malformed agent metadata
+    pub(crate) fn validate(&self) -> Result<()> {
+        PlayerRegistration::validate_agent_name(&self.agent_name)?;
+        PlayerRegistration::validate_agent_image_uri(&self.agent_image_uri)
+    }
+}
+
+/// Optional accounts that reduce a registration's tier fee.
+pub(crate) struct FeeDiscounts<'a, 'info> {
+    /// Player's lifetime stats, for the loyalty discount
+    pub player_stats: Option<&'a Account<'info, PlayerStats>>,
+    /// Season covering the tournament
+    pub season: Option<&'a Account<'info, Season>>,
+    /// Player's season pass, waiving BASIC tier fees
+    pub season_pass: Option<&'a Account<'info, SeasonPass>>,
+    /// Player's daily freeroll tickets
+    pub daily_ticket: Option<&'a mut Account<'info, DailyTicket>>,
+    /// Player's governance stake, for the holder discount
+    pub vote_stake: Option<&'a Account<'info, VoteStake>>,
+    /// Player's POINTS-bought tickets
+    pub ticket: Option<&'a mut Account<'info, Ticket>>,
+}
+
+impl FeeDiscounts<'_, '_> {
+    /// Tier fee owed by `wallet` for `tournament`, discounted from
+    /// `base_fee`, and the POINTS holder discount applied to it in basis
+    /// points. Spends a freeroll ticket if one covers the fee.
+    pub(crate) fn apply(
+        self,
+        arena_config: &ArenaConfig,
+        tier: AgentTier,
+        base_fee: u64,
+        wallet: &Pubkey,
+        tournament: &Tournament,
+    ) -> (u64, u16) {
+        // Discount by the loyalty level earned from tournaments played
+        let loyalty_level = self
+            .player_stats
+            .map(|stats| arena_config.loyalty_level(stats.tournaments_played))
+            .unwrap_or_default();
+        let tier_cost = arena_config.discounted_fee(base_fee, loyalty_level);
+        if tier_cost == 0 {
+            return (0, 0);
+        }
+
+        // Stack the holder discount if the wallet staked enough POINTS before
+        // the tournament was created, so one balance cannot be moved between
+        // wallets to discount each of them
+        let holder_discount_bps = self
+            .vote_stake
+            .map(|stake| arena_config.holder_discount(stake.weight_at(tournament.created_at)))
+            .unwrap_or_default();
+        let tier_cost = tier_cost - (tier_cost as u128 * holder_discount_bps as u128 / 10_000) as u64;
+        if tier_cost == 0 {
+            return (0, holder_discount_bps);
+        }
+
+        if tier == AgentTier::Basic {
+            // A season pass covering this tournament waives BASIC tier fees
+            if let (Some(season), Some(season_pass)) = (self.season, self.season_pass) {
+                if season_pass.applies(season, wallet, tournament.starts_at) {
+                    return (0, 0);
+                }
+            }
+
+            // Otherwise a daily freeroll ticket covers a BASIC tier fee
+            if let Some(daily_ticket) = self.daily_ticket {
+                if daily_ticket.tickets > 0 {
+                    daily_ticket.tickets -= 1;
+                    daily_ticket.total_used = daily_ticket.total_used.saturating_add(1);
+                    return (0, 0);
+                }
+            }
+        }
+
+        // Finally a ticket bought with POINTS covers the fee of its tier
+        if let Some(ticket) = self.ticket {
+            if ticket.redeem(tier) {
+                return (0, 0);
+            }
+        }
+
+        (tier_cost, holder_discount_bps)
+    }
+}
+
+/// Undiscounted tier fee in lamports: the tier's fixed cost, or its USD fee
+/// converted at the SOL/USD price in `price_update` while USD pricing is on.
+pub(crate) fn tier_base_fee(
+    arena_config: &ArenaConfig,
+    tier: AgentTier,
+    price_update: Option<&UncheckedAccount>,
+    now: i64,
+) -> Result<u64> {
+    if !arena_config.uses_usd_tier_fees() {
+        return Ok(tier.cost_lamports());
+    }
+    let price_update = price_update.ok_or(ArenaError::InvalidPriceFeed)?;
+    Price::load(price_update, &arena_config.sol_usd_feed_id, now)?
+        .usd_cents_to_lamports(arena_config.tier_fee_usd_cents[tier as usize] as u64)
+}
+
+/// Fill in a newly created registration for `wallet`.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn initialize_registration(
+    registration: &mut PlayerRegistration,
+    tournament: Pubkey,
+    wallet: Pubkey,
+    entry: &AgentEntry,
+    fee_paid_lamports: u64,
+    fee_payer: Pubkey,
+    rent_payer: Pubkey,
+    model_id_hash: [u8; 32],
+    bump: u8,
+) -> Result<()> {
+    registration.tournament = tournament;
+    registration.wallet = wallet;
+    registration.tier = entry.tier;
+    registration.registered_at = Clock::get()?.unix_timestamp;
+    registration.agent_prompt_hash = entry.agent_prompt_hash;
+    registration.agent_name = entry.agent_name;
+    registration.agent_image_uri = entry.agent_image_uri;
+    registration.final_rank = None;
+    registration.points_awarded = None;
+    registration.hands_played = None;
+    registration.eliminations = None;
+    registration.points_distributed = false;
+    registration.prompt_verified = false;
+    registration.penalty_points = 0;
+    registration.disqualified = false;
+    registration.sanctioned_at = 0;
+    registration.appeal_pending = false;
+    registration.refunded_lamports = 0;
+    registration.action_sold_bps = 0;
+    registration.fee_paid_lamports = fee_paid_lamports;
+    registration.model_id_hash = model_id_hash;
+    registration.metrics = None;
+    registration.holder_discount_bps = 0;
+    registration.forfeited = false;
+    registration.forfeited_at = 0;
+    registration.timeouts = 0;
+    registration.time_bank_used_secs = 0;
+    registration.bust_position = None;
+    registration.bounties = 0;
+    registration.retained_lamports = 0;
+    registration.unregistered = false;
+    registration.fee_payer = fee_payer;
+    registration.disputed = false;
+    registration.sanction_nonce = 0;
+    registration.appealable_sanction = AppealableSanction::default();
+    registration.rent_payer = rent_payer;
+    registration.bump = bump;
+
+    Ok(())
+}
+
+/// Reject registrations for division-restricted tournaments unless
+/// `wallet`'s ladder rating is in the tournament's division.
+pub(crate) fn check_division(
+    tournament: &Tournament,
+    ladder_rating: Option<&Account<LadderRating>>,
+    wallet: &Pubkey,
+) -> Result<()> {
+    let Some(division) = tournament.division else {
+        return Ok(());
+    };
+    let ladder_rating = ladder_rating.ok_or(ArenaError::DivisionMismatch)?;
+    require!(
+        ladder_rating.wallet == *wallet && ladder_rating.division == division,
+        ArenaError::DivisionMismatch
+    );
+    Ok(())
+}
+
+/// Reject registrations for invite-only tournaments unless `invite` is an
+/// invite to the tournament redeemed by `wallet`.
+pub(crate) fn check_invite(
+    tournament: &Account<Tournament>,
+    invite: Option<&Account<Invite>>,
+    wallet: &Pubkey,
+) -> Result<()> {
+    if !tournament.invite_only {
+        return Ok(());
+    }
+    let invite = invite.ok_or(ArenaError::InviteRequired)?;
+    require!(
+        invite.tournament == tournament.key() && invite.redeemed_by == *wallet,
+        ArenaError::InviteRequired
+    );
+    Ok(())
+}
+
+/// Model hash to record for `tier`: PRO agents must reference an active
+/// approved model, other tiers record zeros.
+pub(crate) fn approved_model_hash(
+    tier: AgentTier,
+    approved_model: Option<&Account<ApprovedModel>>,
+) -> Result<[u8; 32]> {
+    if tier != AgentTier::Pro {
+        return Ok([0; 32]);
+    }
+
+    let approved_model = approved_model.ok_or(ArenaError::ModelNotApproved)?;
+    require!(approved_model.active, ArenaError::ModelNotApproved);
+
+    Ok(approved_model.model_id_hash)
+}
+
+/// Allow direct calls, and CPI only from an active allowlisted partner program.
+///
+/// The partner must invoke registration directly from its top-level
+/// instruction, so the instructions sysvar identifies it as the caller.
+pub(crate) fn verify_cpi_caller(
+    instructions_sysvar: Option<&UncheckedAccount>,
+    cpi_partner: Option<&Account<CpiPartner>>,
+) -> Result<()> {
+    let stack_height = get_stack_height();
+    if stack_height <= TRANSACTION_LEVEL_STACK_HEIGHT {
+        return Ok(());
+    }
+    require!(
+        stack_height == TRANSACTION_LEVEL_STACK_HEIGHT + 1,
+        ArenaError::CallerNotAllowed
+    );
+
+    let instructions_sysvar = instructions_sysvar.ok_or(ArenaError::CallerNotAllowed)?;
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    let caller = load_instruction_at_checked(current_index as usize, instructions_sysvar)?.program_id;
+
+    let cpi_partner = cpi_partner.ok_or(ArenaError::CallerNotAllowed)?;
+    require_keys_eq!(cpi_partner.program_id, caller, ArenaError::CallerNotAllowed);
+    require!(cpi_partner.active, ArenaError::CallerNotAllowed);
+
+    Ok(())
+}
+
+/// Guild vault that pays the player's tier fee, if their guild sponsors members.
+/// Rejects guild accounts that do not belong together.
+pub(crate) fn sponsoring_guild_vault<'a, 'info>(
+    guild_member: Option<&Account<'info, GuildMember>>,
+    guild: Option<&Account<'info, Guild>>,
+    guild_vault: Option<&'a mut Account<'info, GuildVault>>,
+) -> Result<Option<&'a mut Account<'info, GuildVault>>> {
+    let (Some(guild_member), Some(guild), Some(guild_vault)) = (guild_member, guild, guild_vault) else {
+        return Ok(None);
+    };
+    require_keys_eq!(guild_member.guild, guild.key(), ArenaError::InvalidGuild);
+    require_keys_eq!(guild_vault.guild, guild.key(), ArenaError::InvalidGuild);
+
+    Ok(guild.sponsor_tier_fees.then_some(guild_vault))
+}
+
+/// Fee escrow a registration's tier fee must be paid into, if the
+/// tournament escrows its fees.
+pub(crate) fn escrowed_fee_destination<'info>(
+    tournament: &Tournament,
+    fee_escrow: Option<&Account<'info, FeeEscrow>>,
+) -> Result<Option<AccountInfo<'info>>> {
+    if !tournament.fees_escrowed {
+        return Ok(None);
+    }
+    let fee_escrow = fee_escrow.ok_or(ArenaError::FeeEscrowRequired)?;
+    Ok(Some(fee_escrow.to_account_info()))
+}
+
+/// Split a tier fee between the configured fee recipients and the treasury,
+/// or hold all of it in `fee_escrow` until the tournament starts.
+///
+/// `recipients` must hold the configured fee recipient accounts in slot order.
+fn fee_payouts<'a, 'info>(
+    treasury: &'a AccountInfo<'info>,
+    fee_escrow: Option<&'a AccountInfo<'info>>,
+    arena_config: &ArenaConfig,
+    recipients: &'a [AccountInfo<'info>],
+    tier_cost: u64,
+) -> Result<Vec<(&'a AccountInfo<'info>, u64)>> {
+    if let Some(fee_escrow) = fee_escrow {
+        return Ok(vec![(fee_escrow, tier_cost)]);
+    }
+
+    let mut recipients = recipients.iter();
+    let mut treasury_share = tier_cost;
+    let mut payouts = Vec::with_capacity(ArenaConfig::MAX_FEE_RECIPIENTS + 1);
+    for (recipient, share) in arena_config.fee_shares(tier_cost) {
+        let info = recipients.next().ok_or(ArenaError::InvalidFeeRecipients)?;
+        require_keys_eq!(info.key(), recipient, ArenaError::InvalidFeeRecipients);
+        treasury_share -= share;
+        payouts.push((info, share));
+    }
+    payouts.push((treasury, treasury_share));
+
+    Ok(payouts)
+}
+
+/// Transfer a tier fee from the player, split between the configured fee
+/// recipients and the treasury, or into `fee_escrow` (no-op for zero cost).
+///
+/// `recipients` must hold the configured fee recipient accounts in slot order.
+pub(crate) fn collect_tier_fee<'info>(
+    system_program: &Program<'info, System>,
+    player: &Signer<'info>,
+    treasury: &AccountInfo<'info>,
+    fee_escrow: Option<&AccountInfo<'info>>,
+    arena_config: &ArenaConfig,
+    recipients: &[AccountInfo<'info>],
+    tier_cost: u64,
+) -> Result<()> {
+    if tier_cost == 0 {
+        return Ok(());
+    }
+
+    for (to, amount) in fee_payouts(treasury, fee_escrow, arena_config, recipients, tier_cost)? {
+        if amount > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: player.to_account_info(),
+                        to: to.to_account_info(),
+                    },
+                ),
+                amount,
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Refund `amount` of a tier fee to `fee_payer`, with the fee recipients and
+/// the treasury each returning the share `collect_tier_fee` paid them.
+///
+/// `recipients` must hold the configured fee recipient accounts in slot
+/// order, each signing for its share.
+pub(crate) fn refund_tier_fee<'info>(
+    system_program: &Program<'info, System>,
+    treasury: &Signer<'info>,
+    fee_payer: &AccountInfo<'info>,
+    arena_config: &ArenaConfig,
+    recipients: &[AccountInfo<'info>],
+    amount: u64,
+) -> Result<()> {
+    let treasury = treasury.to_account_info();
+    for (from, share) in fee_payouts(&treasury, None, arena_config, recipients, amount)? {
+        if share > 0 {
+            require!(from.is_signer, ArenaError::InvalidFeeRecipients);
+            system_program::transfer(
+                CpiContext::new(
+                    system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: from.to_account_info(),
+                        to: fee_payer.to_account_info(),
+                    },
+                ),
+                share,
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Pay a tier fee out of a program-owned vault, split like `collect_tier_fee`.
+/// The vault must stay rent exempt.
+pub(crate) fn collect_tier_fee_from_vault<'info>(
+    vault: &AccountInfo<'info>,
+    treasury: &AccountInfo<'info>,
+    fee_escrow: Option<&AccountInfo<'info>>,
+    arena_config: &ArenaConfig,
+    recipients: &[AccountInfo<'info>],
+    tier_cost: u64,
+) -> Result<()> {
+    if tier_cost == 0 {
+        return Ok(());
+    }
+    require!(
+        tier_cost <= GuildVault::available(vault)?,
+        ArenaError::InsufficientBalance
+    );
+
+    for (to, amount) in fee_payouts(treasury, fee_escrow, arena_config, recipients, tier_cost)? {
+        **vault.try_borrow_mut_lamports()? -= amount;
+        **to.try_borrow_mut_lamports()? += amount;
+    }
+
+    Ok(())
+}
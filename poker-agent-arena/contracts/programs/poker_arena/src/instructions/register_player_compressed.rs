@@ -0,0 +1,246 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar;
+
+use crate::compression::{self, TreeAccounts, SPL_ACCOUNT_COMPRESSION_ID, SPL_NOOP_ID};
+use crate::errors::ArenaError;
+use crate::events::CompressedRegistrationUpdated;
+use crate::instructions::register_player::{
+    approved_model_hash, check_division, check_invite, collect_tier_fee, tier_base_fee,
+    verify_cpi_caller,
+};
+use crate::state::{AgentTier, ApprovedModel, ArenaConfig, CompressedRegistration, CompressedRoster, CompressedSeat, CpiPartner, Invite, LadderRating, PlayerRegistration, Tournament};
+
+/// Accounts required for compressed player registration.
+#[derive(Accounts)]
+pub struct RegisterPlayerCompressed<'info> {
+    /// Player wallet registering for the tournament
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    /// Arena config account (for treasury address)
+    #[account(
+        mut,
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament to register for
+    #[account(
+        mut,
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.is_registration_open() @ ArenaError::RegistrationNotOpen,
+        constraint = !tournament.is_full() @ ArenaError::TournamentFull
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Compressed roster (tree authority)
+    #[account(
+        mut,
+        seeds = [CompressedRoster::SEED_PREFIX, tournament.key().as_ref()],
+        bump = compressed_roster.bump
+    )]
+    pub compressed_roster: Account<'info, CompressedRoster>,
+
+    /// Marker for the player's seat; fails to initialize if the wallet
+    /// already registered
+    #[account(
+        init,
+        payer = player,
+        space = CompressedSeat::SIZE,
+        seeds = [CompressedSeat::SEED_PREFIX, tournament.key().as_ref(), player.key().as_ref()],
+        bump
+    )]
+    pub compressed_seat: Account<'info, CompressedSeat>,
+
+    /// Concurrent Merkle tree holding registration leaves
+    /// CHECK: Verified against compressed_roster.merkle_tree
+    #[account(
+        mut,
+        address = compressed_roster.merkle_tree
+    )]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    /// Treasury wallet to receive tier fees
+    /// CHECK: This is verified against arena_config.treasury
+    #[account(
+        mut,
+        constraint = treasury.key() == arena_config.treasury @ ArenaError::InvalidTierPayment
+    )]
+    pub treasury: AccountInfo<'info>,
+
+    /// SPL account compression program
+    /// CHECK: Validated by address
+    #[account(address = SPL_ACCOUNT_COMPRESSION_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+
+    /// SPL noop program
+    /// CHECK: Validated by address
+    #[account(address = SPL_NOOP_ID)]
+    pub noop_program: UncheckedAccount<'info>,
+
+    /// System program for transfers
+    pub system_program: Program<'info, System>,
+
+    /// Approved model backing the agent (required for PRO tier)
+    #[account(
+        seeds = [ApprovedModel::SEED_PREFIX, approved_model.model_id_hash.as_ref()],
+        bump = approved_model.bump
+    )]
+    pub approved_model: Option<Account<'info, ApprovedModel>>,
+
+    /// Instructions sysvar, required when called via CPI
+    /// CHECK: Validated by address
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+
+    /// Allowlist entry for the calling program, required when called via CPI
+    #[account(
+        seeds = [CpiPartner::SEED_PREFIX, cpi_partner.program_id.as_ref()],
+        bump = cpi_partner.bump
+    )]
+    pub cpi_partner: Option<Account<'info, CpiPartner>>,
+
+    /// Player's ladder rating, required for division-restricted tournaments
+    #[account(
+        seeds = [LadderRating::SEED_PREFIX, player.key().as_ref()],
+        bump = ladder_rating.bump
+    )]
+    pub ladder_rating: Option<Box<Account<'info, LadderRating>>>,
+
+    /// Pyth SOL/USD price update, required while tier fees are priced in USD
+    /// CHECK: Owner, layout and feed id are validated by oracle::Price::load
+    pub price_update: Option<UncheckedAccount<'info>>,
+
+    /// Invite redeemed by the registering wallet, required for invite-only tournaments
+    pub invite: Option<Box<Account<'info, Invite>>>,
+}
+
+/// Register a player as a compressed leaf instead of a PlayerRegistration PDA.
+///
+/// Each wallet may register once: a small CompressedSeat marker is created
+/// alongside its leaf. Indexers rebuild the roster from
+/// CompressedRegistrationUpdated events.
+///
+/// # Arguments
+/// * `tier` - The agent tier (FREE, BASIC, or PRO)
+/// * `agent_prompt_hash` - SHA-256 hash of the custom prompt (for verification)
+/// * `agent_name` - Display name for the agent (32 bytes, UTF-8, null-padded)
+/// * `agent_image_uri` - URI for agent avatar image (128 bytes, ipfs:// or https://, or all zeros for none)
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RegisterPlayerCompressed<'info>>,
+    tier: AgentTier,
+    agent_prompt_hash: [u8; 32],
+    agent_name: [u8; 32],
+    agent_image_uri: [u8; 128],
+) -> Result<()> {
+    verify_cpi_caller(
+        ctx.accounts.instructions_sysvar.as_ref(),
+        ctx.accounts.cpi_partner.as_ref(),
+    )?;
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        !ctx.accounts.tournament.is_registration_closed(now),
+        ArenaError::RegistrationClosed
+    );
+    require!(ctx.accounts.tournament.is_tier_open(tier, now), ArenaError::TierNotOpen);
+    check_division(
+        &ctx.accounts.tournament,
+        ctx.accounts.ladder_rating.as_deref(),
+        &ctx.accounts.player.key(),
+    )?;
+    check_invite(
+        &ctx.accounts.tournament,
+        ctx.accounts.invite.as_deref(),
+        &ctx.accounts.player.key(),
+    )?;
+    PlayerRegistration::validate_agent_name(&agent_name)?;
+    PlayerRegistration::validate_agent_image_uri(&agent_image_uri)?;
+    approved_model_hash(tier, ctx.accounts.approved_model.as_ref())?;
+    // Escrowed fees are refunded against registration PDAs, which leaves have none
+    require!(
+        !ctx.accounts.tournament.fees_escrowed,
+        ArenaError::FeeEscrowUnsupported
+    );
+    let tier_cost = tier_base_fee(
+        &ctx.accounts.arena_config,
+        tier,
+        ctx.accounts.price_update.as_ref(),
+        now,
+    )?;
+
+    collect_tier_fee(
+        &ctx.accounts.system_program,
+        &ctx.accounts.player,
+        &ctx.accounts.treasury,
+        None,
+        &ctx.accounts.arena_config,
+        ctx.remaining_accounts,
+        tier_cost,
+    )?;
+    ctx.accounts.arena_config.record_fee(tier_cost);
+
+    let tournament = &mut ctx.accounts.tournament;
+    tournament.record_fee(tier_cost);
+    let compressed_roster = &mut ctx.accounts.compressed_roster;
+    let tournament_key = tournament.key();
+
+    let registration = CompressedRegistration {
+        tournament: tournament_key,
+        wallet: ctx.accounts.player.key(),
+        tier,
+        registered_at: Clock::get()?.unix_timestamp,
+        agent_prompt_hash,
+        agent_name,
+        agent_image_uri,
+        final_rank: None,
+        points_awarded: None,
+        hands_played: None,
+        eliminations: None,
+        points_distributed: false,
+    };
+
+    let seeds = &[
+        CompressedRoster::SEED_PREFIX,
+        tournament_key.as_ref(),
+        &[compressed_roster.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    compression::append(
+        &TreeAccounts {
+            compression_program: &ctx.accounts.compression_program.to_account_info(),
+            merkle_tree: &ctx.accounts.merkle_tree.to_account_info(),
+            authority: &compressed_roster.to_account_info(),
+            noop: &ctx.accounts.noop_program.to_account_info(),
+        },
+        registration.leaf_hash()?,
+        signer_seeds,
+    )?;
+
+    let leaf_index = compressed_roster.leaf_count;
+    compressed_roster.leaf_count = compressed_roster
+        .leaf_count
+        .checked_add(1)
+        .ok_or(ArenaError::ArithmeticOverflow)?;
+    tournament.record_registration(tier)?;
+    let compressed_seat = &mut ctx.accounts.compressed_seat;
+    compressed_seat.leaf_index = leaf_index;
+    compressed_seat.bump = ctx.bumps.compressed_seat;
+
+    emit!(CompressedRegistrationUpdated {
+        merkle_tree: compressed_roster.merkle_tree,
+        leaf_index,
+        registration,
+    });
+
+    msg!(
+        "Player {} registered (compressed leaf {}) for tournament {}",
+        ctx.accounts.player.key(),
+        leaf_index,
+        tournament.id
+    );
+
+    Ok(())
+}
@@ -0,0 +1,266 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar;
+
+use crate::errors::ArenaError;
+use crate::instructions::register_player::{AgentEntry, FeeDiscounts, FeeSource, NewRegistration};
+use crate::state::{AgentTier, ApprovedModel, ArenaConfig, CpiPartner, DailyTicket, FeeEscrow, Invite, LadderRating, PaymentKind, PaymentReceipt, PlayerRegistration, PlayerStats, RegistrationIndex, Season, SeasonPass, SessionAuthority, Ticket, Tournament, TreasuryLedger, VoteStake};
+
+/// Accounts required for registering a player through a session key.
+#[derive(Accounts)]
+pub struct RegisterPlayerWithSession<'info> {
+    /// Session key signing for the wallet; pays rent and the tier fee
+    #[account(mut)]
+    pub session_key: Signer<'info>,
+
+    /// Session authorizing the key to register
+    #[account(
+        seeds = [SessionAuthority::SEED_PREFIX, session_authority.wallet.as_ref(), session_key.key().as_ref()],
+        bump = session_authority.bump
+    )]
+    pub session_authority: Account<'info, SessionAuthority>,
+
+    /// Arena config account (for treasury address and fee counters)
+    #[account(
+        mut,
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament to register for
+    #[account(
+        mut,
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), tournament.id.to_le_bytes().as_ref()],
+        bump = tournament.bump,
+        constraint = tournament.is_registration_open() @ ArenaError::RegistrationNotOpen,
+        constraint = !tournament.is_full() @ ArenaError::TournamentFull
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Player registration PDA to be created for the session's wallet
+    #[account(
+        init,
+        payer = session_key,
+        space = PlayerRegistration::SIZE,
+        seeds = [
+            PlayerRegistration::SEED_PREFIX,
+            tournament.key().as_ref(),
+            session_authority.wallet.as_ref()
+        ],
+        bump
+    )]
+    pub registration: Account<'info, PlayerRegistration>,
+
+    /// Treasury wallet to receive tier fees
+    /// CHECK: This is verified against arena_config.treasury
+    #[account(
+        mut,
+        constraint = treasury.key() == arena_config.treasury @ ArenaError::InvalidTierPayment
+    )]
+    pub treasury: AccountInfo<'info>,
+
+    /// System program for account creation and transfers
+    pub system_program: Program<'info, System>,
+
+    /// Wallet's lifetime stats, for the loyalty discount (omit if none)
+    #[account(
+        seeds = [PlayerStats::SEED_PREFIX, session_authority.wallet.as_ref()],
+        bump = player_stats.bump
+    )]
+    pub player_stats: Option<Account<'info, PlayerStats>>,
+
+    /// Season covering this tournament (omit if the wallet has no pass)
+    pub season: Option<Account<'info, Season>>,
+
+    /// Wallet's season pass, waiving BASIC tier fees
+    pub season_pass: Option<Account<'info, SeasonPass>>,
+
+    /// Wallet's daily freeroll tickets; one is spent to waive a BASIC tier fee
+    #[account(
+        mut,
+        seeds = [DailyTicket::SEED_PREFIX, session_authority.wallet.as_ref()],
+        bump = daily_ticket.bump
+    )]
+    pub daily_ticket: Option<Account<'info, DailyTicket>>,
+
+    /// Approved model backing the agent (required for PRO tier)
+    #[account(
+        seeds = [ApprovedModel::SEED_PREFIX, approved_model.model_id_hash.as_ref()],
+        bump = approved_model.bump
+    )]
+    pub approved_model: Option<Account<'info, ApprovedModel>>,
+
+    /// Instructions sysvar, required when called via CPI
+    /// CHECK: Validated by address
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+
+    /// Allowlist entry for the calling program, required when called via CPI
+    #[account(
+        seeds = [CpiPartner::SEED_PREFIX, cpi_partner.program_id.as_ref()],
+        bump = cpi_partner.bump
+    )]
+    pub cpi_partner: Option<Account<'info, CpiPartner>>,
+
+    /// Solana Pay reference key identifying this payment (omit if none)
+    /// CHECK: Read-only marker; only its address is used
+    pub reference: Option<UncheckedAccount<'info>>,
+
+    /// Receipt for the tier fee (omit to skip)
+    #[account(
+        init,
+        payer = session_key,
+        space = PaymentReceipt::SIZE,
+        seeds = [PaymentReceipt::SEED_PREFIX, registration.key().as_ref(), &[PaymentKind::Tier as u8]],
+        bump
+    )]
+    pub payment_receipt: Option<Account<'info, PaymentReceipt>>,
+
+    /// Registering wallet's governance stake, for the holder discount (omit if none)
+    #[account(
+        seeds = [VoteStake::SEED_PREFIX, session_authority.wallet.as_ref()],
+        bump = vote_stake.bump
+    )]
+    pub vote_stake: Option<Box<Account<'info, VoteStake>>>,
+
+    /// Tickets bought with POINTS; one is spent to waive the fee of their tier (omit if none)
+    #[account(
+        mut,
+        seeds = [Ticket::SEED_PREFIX, session_authority.wallet.as_ref(), &[ticket.tier as u8]],
+        bump = ticket.bump
+    )]
+    pub ticket: Option<Box<Account<'info, Ticket>>>,
+
+    /// Player's ladder rating, required for division-restricted tournaments
+    #[account(
+        seeds = [LadderRating::SEED_PREFIX, session_authority.wallet.as_ref()],
+        bump = ladder_rating.bump
+    )]
+    pub ladder_rating: Option<Box<Account<'info, LadderRating>>>,
+
+    /// Pyth SOL/USD price update, required while tier fees are priced in USD
+    /// CHECK: Owner, layout and feed id are validated by oracle::Price::load
+    pub price_update: Option<UncheckedAccount<'info>>,
+
+    /// Tournament's fee escrow, required while its tier fees are escrowed
+    #[account(
+        mut,
+        seeds = [FeeEscrow::SEED_PREFIX, tournament.key().as_ref()],
+        bump = fee_escrow.bump
+    )]
+    pub fee_escrow: Option<Box<Account<'info, FeeEscrow>>>,
+
+    /// Invite redeemed by the registering wallet, required for invite-only tournaments
+    pub invite: Option<Box<Account<'info, Invite>>>,
+
+    /// Wallet's registration index, appended with this tournament (omit if none)
+    #[account(
+        mut,
+        seeds = [RegistrationIndex::SEED_PREFIX, session_authority.wallet.as_ref()],
+        bump = registration_index.bump
+    )]
+    pub registration_index: Option<Box<Account<'info, RegistrationIndex>>>,
+
+    /// Treasury ledger recording an escrowed fee (omit to skip)
+    #[account(
+        mut,
+        seeds = [TreasuryLedger::SEED_PREFIX],
+        bump = treasury_ledger.bump
+    )]
+    pub treasury_ledger: Option<Box<Account<'info, TreasuryLedger>>>,
+}
+
+/// Register the session's wallet for a tournament, signed by the session key.
+///
+/// Behaves like `register_player`, but the tier fee and rent come from the
+/// session key. Configured fee recipients are passed as remaining accounts.
+/// A PaymentReceipt, if passed, is issued to the session's wallet, and the
+/// Solana Pay reference key and correlation id are echoed in the
+/// PlayerRegistered event.
+///
+/// # Arguments
+/// * `tier` - The agent tier (FREE, BASIC, or PRO)
+/// * `agent_prompt_hash` - SHA-256 hash of the custom prompt (for verification)
+/// * `agent_name` - Display name for the agent (32 bytes, UTF-8, null-padded)
+/// * `agent_image_uri` - URI for agent avatar image (128 bytes, ipfs:// or https://, or all zeros for none)
+/// * `correlation_id` - Client-supplied id echoed in the event (0 if unused)
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RegisterPlayerWithSession<'info>>,
+    tier: AgentTier,
+    agent_prompt_hash: [u8; 32],
+    agent_name: [u8; 32],
+    agent_image_uri: [u8; 128],
+    correlation_id: u64,
+) -> Result<()> {
+    let session_key = &ctx.accounts.session_key;
+    let wallet = ctx.accounts.session_authority.wallet;
+    require!(
+        ctx.accounts.session_authority.allows(
+            &session_key.key(),
+            SessionAuthority::SCOPE_REGISTER,
+            Clock::get()?.unix_timestamp
+        ),
+        ArenaError::InvalidSession
+    );
+
+    let entry = AgentEntry {
+        tier,
+        agent_prompt_hash,
+        agent_name,
+        agent_image_uri,
+    };
+    // The session's wallet funds the session key, so the fee and receipt are theirs
+    NewRegistration {
+        arena_config: &mut ctx.accounts.arena_config,
+        tournament: &mut ctx.accounts.tournament,
+        registration: &mut ctx.accounts.registration,
+        registration_bump: ctx.bumps.registration,
+        rent_payer: session_key.key(),
+        treasury: &ctx.accounts.treasury,
+        system_program: &ctx.accounts.system_program,
+        fee_recipients: ctx.remaining_accounts,
+        instructions_sysvar: ctx.accounts.instructions_sysvar.as_ref(),
+        cpi_partner: ctx.accounts.cpi_partner.as_ref(),
+        ladder_rating: ctx.accounts.ladder_rating.as_deref(),
+        invite: ctx.accounts.invite.as_deref(),
+        approved_model: ctx.accounts.approved_model.as_ref(),
+        price_update: ctx.accounts.price_update.as_ref(),
+        fee_escrow: ctx.accounts.fee_escrow.as_deref(),
+        discounts: FeeDiscounts {
+            player_stats: ctx.accounts.player_stats.as_ref(),
+            season: ctx.accounts.season.as_ref(),
+            season_pass: ctx.accounts.season_pass.as_ref(),
+            daily_ticket: ctx.accounts.daily_ticket.as_mut(),
+            vote_stake: ctx.accounts.vote_stake.as_deref(),
+            ticket: ctx.accounts.ticket.as_deref_mut(),
+        },
+        registration_index: ctx.accounts.registration_index.as_deref_mut(),
+        payment_receipt: ctx
+            .accounts
+            .payment_receipt
+            .as_mut()
+            .zip(ctx.bumps.payment_receipt),
+        reference: ctx
+            .accounts
+            .reference
+            .as_ref()
+            .map(|reference| reference.key()),
+        treasury_ledger: ctx.accounts.treasury_ledger.as_deref_mut(),
+    }
+    .register(
+        wallet,
+        &entry,
+        FeeSource::Wallet(session_key),
+        wallet,
+        correlation_id,
+    )?;
+
+    msg!(
+        "Player {} registered for tournament {} via session {}",
+        wallet,
+        ctx.accounts.tournament.id,
+        session_key.key()
+    );
+
+    Ok(())
+}
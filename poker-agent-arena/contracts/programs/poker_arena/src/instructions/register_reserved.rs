@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::instructions::register_player::{initialize_registration, AgentEntry};
+use crate::state::{AgentTier, ArenaConfig, AuditLog, PlayerRegistration, Tournament};
+
+/// Accounts required for filling a reserved seat.
+#[derive(Accounts)]
+pub struct RegisterReserved<'info> {
+    /// Admin wallet - must match arena_config.admin; pays rent
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// Arena config for admin verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament to seat the agent in
+    #[account(
+        mut,
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), tournament.id.to_le_bytes().as_ref()],
+        bump = tournament.bump,
+        constraint = tournament.is_registration_open() @ ArenaError::RegistrationNotOpen,
+        constraint = !tournament.is_reserved_full() @ ArenaError::TournamentFull
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Wallet of the sponsor or exhibition agent
+    /// CHECK: Only used as the registration owner
+    pub wallet: UncheckedAccount<'info>,
+
+    /// Player registration PDA to be created
+    #[account(
+        init,
+        payer = admin,
+        space = PlayerRegistration::SIZE,
+        seeds = [
+            PlayerRegistration::SEED_PREFIX,
+            tournament.key().as_ref(),
+            wallet.key().as_ref()
+        ],
+        bump
+    )]
+    pub registration: Account<'info, PlayerRegistration>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Seat a sponsor or exhibition agent in a reserved seat (admin only).
+/// Reserved seats carry no tier fee.
+///
+/// # Arguments
+/// * `tier` - The agent tier (FREE, BASIC, or PRO)
+/// * `agent_prompt_hash` - SHA-256 hash of the custom prompt (for verification)
+/// * `agent_name` - Display name for the agent (32 bytes, UTF-8, null-padded)
+/// * `agent_image_uri` - URI for agent avatar image (128 bytes, ipfs:// or https://, or all zeros for none)
+pub fn handler(
+    ctx: Context<RegisterReserved>,
+    tier: AgentTier,
+    agent_prompt_hash: [u8; 32],
+    agent_name: [u8; 32],
+    agent_image_uri: [u8; 128],
+) -> Result<()> {
+    let entry = AgentEntry {
+        tier,
+        agent_prompt_hash,
+        agent_name,
+        agent_image_uri,
+    };
+    entry.validate()?;
+
+    let tournament = &mut ctx.accounts.tournament;
+    let wallet = ctx.accounts.wallet.key();
+    initialize_registration(
+        &mut ctx.accounts.registration,
+        tournament.key(),
+        wallet,
+        &entry,
+        0,
+        wallet,
+        ctx.accounts.admin.key(),
+        [0; 32],
+        ctx.bumps.registration,
+    )?;
+    tournament.record_registration(tier)?;
+    tournament.reserved_registered = tournament
+        .reserved_registered
+        .checked_add(1)
+        .ok_or(ArenaError::ArithmeticOverflow)?;
+
+    msg!(
+        "Reserved seat {}/{} in tournament {} filled by {}",
+        tournament.reserved_registered,
+        tournament.reserved_seats,
+        tournament.id,
+        wallet
+    );
+
+    AuditLog::record_call::<instruction::RegisterReserved>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        ctx.accounts.tournament.id,
+    )?;
+
+    Ok(())
+}
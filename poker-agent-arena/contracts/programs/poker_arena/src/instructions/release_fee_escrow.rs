@@ -0,0 +1,187 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::events::FeeEscrowReleased;
+use crate::instructions::register_player::collect_tier_fee_from_vault;
+use crate::state::{
+    ArenaConfig, FeeEscrow, GuildVault, Host, LedgerCategory, LedgerDirection, PrizeDenomination,
+    PrizePool, Tournament, TournamentStatus, TreasuryLedger,
+};
+
+/// Accounts required for releasing a tournament's escrowed tier fees.
+#[derive(Accounts)]
+pub struct ReleaseFeeEscrow<'info> {
+    /// Anyone may crank the release once the tournament has started
+    pub caller: Signer<'info>,
+
+    /// Arena config for the treasury address and fee recipients
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament - must have started and not been voided
+    #[account(
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = matches!(
+            tournament.status,
+            TournamentStatus::InProgress | TournamentStatus::ResultsSubmitted | TournamentStatus::Completed
+        ) @ ArenaError::TournamentNotStarted
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Tournament's fee escrow - must not be released yet
+    #[account(
+        mut,
+        seeds = [FeeEscrow::SEED_PREFIX, tournament.key().as_ref()],
+        bump = fee_escrow.bump,
+        constraint = !fee_escrow.released @ ArenaError::FeeEscrowReleased
+    )]
+    pub fee_escrow: Account<'info, FeeEscrow>,
+
+    /// Treasury wallet receiving its share of the fees
+    /// CHECK: Verified against arena_config.treasury
+    #[account(
+        mut,
+        address = arena_config.treasury @ ArenaError::InvalidTierPayment
+    )]
+    pub treasury: UncheckedAccount<'info>,
+
+    /// Tournament's SOL prize pool, required if the escrow releases into it
+    #[account(
+        mut,
+        seeds = [PrizePool::SEED_PREFIX, tournament.key().as_ref()],
+        bump = prize_pool.bump
+    )]
+    pub prize_pool: Option<Account<'info, PrizePool>>,
+
+    /// Tournament's host, required if the tournament is hosted
+    #[account(
+        seeds = [Host::SEED_PREFIX, host.authority.as_ref()],
+        bump = host.bump,
+        constraint = host.key() == tournament.host @ ArenaError::Unauthorized
+    )]
+    pub host: Option<Account<'info, Host>>,
+
+    /// Host's treasury receiving the host's share of the fees
+    /// CHECK: Verified against host.treasury
+    #[account(mut)]
+    pub host_treasury: Option<UncheckedAccount<'info>>,
+
+    /// Treasury ledger recording the release (omit to skip)
+    #[account(
+        mut,
+        seeds = [TreasuryLedger::SEED_PREFIX],
+        bump = treasury_ledger.bump
+    )]
+    pub treasury_ledger: Option<Box<Account<'info, TreasuryLedger>>>,
+}
+
+/// Release a started tournament's escrowed tier fees (permissionless).
+///
+/// Configured fee recipients are passed as remaining accounts, in slot
+/// order, unless the escrow releases into the prize pool.
+///
+/// This instruction:
+/// 1. Moves everything the escrow holds above rent into the SOL prize pool,
+///    adding it to the pool total, or splits it between the fee recipients
+///    and the treasury like a directly paid tier fee
+/// 2. For a hosted tournament, splits only the host's platform fee that way
+///    and pays the rest to the host's treasury
+/// 3. Marks the escrow released so only a slashed host bond, which stays
+///    behind, can still be refunded from it
+/// 4. Records the movement on the treasury ledger, if passed
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ReleaseFeeEscrow<'info>>) -> Result<()> {
+    let escrow_info = ctx.accounts.fee_escrow.to_account_info();
+    let amount = GuildVault::available(&escrow_info)?
+        .saturating_sub(ctx.accounts.fee_escrow.slashed_lamports);
+
+    if ctx.accounts.fee_escrow.to_prize_pool {
+        let prize_pool = ctx
+            .accounts
+            .prize_pool
+            .as_mut()
+            .ok_or(ArenaError::InvalidPrizePool)?;
+        require!(
+            prize_pool.denomination == PrizeDenomination::Sol,
+            ArenaError::InvalidPrizePool
+        );
+        **escrow_info.try_borrow_mut_lamports()? -= amount;
+        **prize_pool.to_account_info().try_borrow_mut_lamports()? += amount;
+        prize_pool.total = prize_pool
+            .total
+            .checked_add(amount)
+            .ok_or(ArenaError::ArithmeticOverflow)?;
+    } else if ctx.accounts.tournament.is_hosted() {
+        let (Some(host), Some(host_treasury)) = (
+            ctx.accounts.host.as_ref(),
+            ctx.accounts.host_treasury.as_ref(),
+        ) else {
+            return err!(ArenaError::Unauthorized);
+        };
+        require_keys_eq!(
+            host_treasury.key(),
+            host.treasury,
+            ArenaError::InvalidTierPayment
+        );
+        let platform_fee = host.platform_fee(amount);
+        collect_tier_fee_from_vault(
+            &escrow_info,
+            &ctx.accounts.treasury,
+            None,
+            &ctx.accounts.arena_config,
+            ctx.remaining_accounts,
+            platform_fee,
+        )?;
+        **escrow_info.try_borrow_mut_lamports()? -= amount - platform_fee;
+        **host_treasury.try_borrow_mut_lamports()? += amount - platform_fee;
+    } else {
+        collect_tier_fee_from_vault(
+            &escrow_info,
+            &ctx.accounts.treasury,
+            None,
+            &ctx.accounts.arena_config,
+            ctx.remaining_accounts,
+            amount,
+        )?;
+    }
+    ctx.accounts.fee_escrow.released = true;
+
+    if let Some(treasury_ledger) = ctx.accounts.treasury_ledger.as_mut() {
+        let now = Clock::get()?.unix_timestamp;
+        treasury_ledger.record(
+            LedgerCategory::FeeEscrow,
+            LedgerDirection::Outflow,
+            escrow_info.key(),
+            amount,
+            now,
+        );
+        if let Some(prize_pool) = ctx.accounts.prize_pool.as_ref() {
+            if ctx.accounts.fee_escrow.to_prize_pool {
+                treasury_ledger.record(
+                    LedgerCategory::PrizePool,
+                    LedgerDirection::Inflow,
+                    prize_pool.key(),
+                    amount,
+                    now,
+                );
+            }
+        }
+    }
+
+    emit!(FeeEscrowReleased {
+        tournament: ctx.accounts.tournament.key(),
+        amount,
+        to_prize_pool: ctx.accounts.fee_escrow.to_prize_pool,
+    });
+
+    msg!(
+        "Released {} escrowed lamports for tournament {}",
+        amount,
+        ctx.accounts.tournament.id
+    );
+
+    Ok(())
+}
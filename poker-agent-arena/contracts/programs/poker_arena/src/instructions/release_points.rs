@@ -0,0 +1,190 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
+
+use crate::errors::ArenaError;
+use crate::state::{
+    ArenaConfig, PlayerRegistration, PointsEscrow, PointsMintAuthority, Tournament, VestingAccount,
+};
+
+/// Accounts required for releasing escrowed POINTS.
+#[derive(Accounts)]
+pub struct ReleasePoints<'info> {
+    /// Anyone may crank the release; pays rent for a vesting account
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    /// Arena config for the points mint and vesting policy
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament the escrowed POINTS were awarded in
+    #[account(
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Escrow holding the player's POINTS
+    #[account(
+        mut,
+        seeds = [PointsEscrow::SEED_PREFIX, tournament.key().as_ref(), points_escrow.wallet.as_ref()],
+        bump = points_escrow.bump,
+        constraint = !points_escrow.released @ ArenaError::PointsAlreadyDistributed
+    )]
+    pub points_escrow: Account<'info, PointsEscrow>,
+
+    /// Player's registration
+    #[account(
+        seeds = [PlayerRegistration::SEED_PREFIX, tournament.key().as_ref(), points_escrow.wallet.as_ref()],
+        bump = registration.bump
+    )]
+    pub registration: Account<'info, PlayerRegistration>,
+
+    /// POINTS SPL token mint
+    #[account(
+        mut,
+        constraint = points_mint.key() == tournament.points_mint(&arena_config) @ ArenaError::InvalidTierPayment
+    )]
+    pub points_mint: Account<'info, Mint>,
+
+    /// PDA that holds mint authority
+    #[account(
+        seeds = [PointsMintAuthority::SEED_PREFIX],
+        bump = mint_authority.bump
+    )]
+    pub mint_authority: Account<'info, PointsMintAuthority>,
+
+    /// Player's token account for POINTS
+    #[account(
+        mut,
+        constraint = player_token_account.mint == points_mint.key() @ ArenaError::InvalidTierPayment,
+        constraint = player_token_account.owner == points_escrow.wallet @ ArenaError::Unauthorized
+    )]
+    pub player_token_account: Account<'info, TokenAccount>,
+
+    /// Guild vault's token account for POINTS, required when the escrow
+    /// holds a guild share
+    #[account(
+        mut,
+        constraint = guild_token_account.mint == points_mint.key() @ ArenaError::InvalidTierPayment,
+        constraint = guild_token_account.owner == points_escrow.guild_vault @ ArenaError::InvalidGuild
+    )]
+    pub guild_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+
+    /// Vesting account to create when the award meets the vesting threshold (omit otherwise)
+    #[account(
+        init,
+        payer = caller,
+        space = VestingAccount::SIZE,
+        seeds = [VestingAccount::SEED_PREFIX, tournament.key().as_ref(), points_escrow.wallet.as_ref()],
+        bump
+    )]
+    pub vesting_account: Option<Account<'info, VestingAccount>>,
+
+    /// System program, required with vesting_account
+    pub system_program: Option<Program<'info, System>>,
+}
+
+impl<'info> ReleasePoints<'info> {
+    /// Mint `amount` POINTS to `to`, signed by the mint authority PDA
+    fn mint_points(&self, to: AccountInfo<'info>, amount: u64) -> Result<()> {
+        let seeds = &[
+            PointsMintAuthority::SEED_PREFIX,
+            &[self.mint_authority.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        token::mint_to(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                MintTo {
+                    mint: self.points_mint.to_account_info(),
+                    to,
+                    authority: self.mint_authority.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )
+    }
+}
+
+/// Release escrowed POINTS to the player and their guild (permissionless).
+///
+/// This instruction:
+/// 1. Requires the dispute window to have closed undisputed, or the dispute
+///    to have been resolved, and no sanction on the player that can still be
+///    appealed or awaits its appeal
+/// 2. Mints the escrowed guild share to the guild vault, if any
+/// 3. Mints the player's escrowed POINTS to them, or records them on a
+///    VestingAccount if they meet the vesting threshold
+/// 4. Marks the escrow released
+///
+/// Penalties applied while the POINTS are escrowed have already been taken
+/// out of the escrow by `apply_penalty`, and a disqualified player's own
+/// POINTS by `disqualify_player`, leaving only the guild's share.
+pub fn handler(ctx: Context<ReleasePoints>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        ctx.accounts.tournament.points_releasable(now),
+        ArenaError::PointsEscrowLocked
+    );
+    require!(
+        !ctx.accounts.registration.sanction_pending(now),
+        ArenaError::SanctionPending
+    );
+
+    let guild_points = ctx.accounts.points_escrow.guild_points;
+    if guild_points > 0 {
+        let guild_token_account = ctx
+            .accounts
+            .guild_token_account
+            .as_ref()
+            .ok_or(ArenaError::InvalidGuild)?;
+        ctx.accounts
+            .mint_points(guild_token_account.to_account_info(), guild_points)?;
+        msg!(
+            "Released {} escrowed POINTS to the player's guild",
+            guild_points
+        );
+    }
+
+    let points = ctx.accounts.points_escrow.points;
+    let vests = ctx.accounts.arena_config.vests(points);
+    require!(
+        ctx.accounts.vesting_account.is_some() == vests,
+        ArenaError::VestingAccountMismatch
+    );
+
+    if let Some(vesting_account) = ctx.accounts.vesting_account.as_mut() {
+        vesting_account.start(
+            ctx.accounts.points_escrow.wallet,
+            ctx.accounts.tournament.key(),
+            points,
+            &ctx.accounts.arena_config,
+            now,
+            ctx.bumps.vesting_account.unwrap(),
+        );
+        msg!("{} escrowed POINTS vesting", points);
+    } else if points > 0 {
+        ctx.accounts
+            .mint_points(ctx.accounts.player_token_account.to_account_info(), points)?;
+    }
+
+    let points_escrow = &mut ctx.accounts.points_escrow;
+    points_escrow.released = true;
+
+    msg!(
+        "Released {} escrowed POINTS to {}",
+        points,
+        points_escrow.wallet
+    );
+
+    Ok(())
+}
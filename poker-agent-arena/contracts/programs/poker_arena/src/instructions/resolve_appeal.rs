@@ -0,0 +1,237 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
+
+use crate::errors::ArenaError;
+use crate::events::AppealResolved;
+use crate::instruction;
+use crate::state::{
+    Appeal, AppealStatus, AppealableSanction, ArbiterSet, ArenaConfig, AuditLog,
+    PlayerRegistration, PointsEscrow, PointsMintAuthority, Tournament,
+};
+
+/// Accounts required for resolving an appeal.
+#[derive(Accounts)]
+pub struct ResolveAppeal<'info> {
+    /// Arena admin, or anyone once the arbiter committee has decided
+    pub caller: Signer<'info>,
+
+    /// Arena config for admin verification and points mint
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament the sanction relates to
+    #[account(
+        mut,
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Sanctioned registration
+    #[account(
+        mut,
+        seeds = [PlayerRegistration::SEED_PREFIX, tournament.key().as_ref(), registration.wallet.as_ref()],
+        bump = registration.bump,
+        constraint = registration.tournament == tournament.key() @ ArenaError::TournamentNotFound
+    )]
+    pub registration: Account<'info, PlayerRegistration>,
+
+    /// Appeal to resolve - must be Open
+    #[account(
+        mut,
+        seeds = [Appeal::SEED_PREFIX, registration.key().as_ref(), &appeal.sanction_nonce.to_le_bytes()],
+        bump = appeal.bump,
+        constraint = appeal.status == AppealStatus::Open @ ArenaError::AppealAlreadyResolved
+    )]
+    pub appeal: Account<'info, Appeal>,
+
+    /// Arbiter committee whose votes decide the appeal (omit for an admin ruling)
+    #[account(
+        seeds = [ArbiterSet::SEED_PREFIX],
+        bump = arbiter_set.bump
+    )]
+    pub arbiter_set: Option<Account<'info, ArbiterSet>>,
+
+    /// Escrow the sanction took POINTS from, required to restore them
+    #[account(
+        mut,
+        seeds = [PointsEscrow::SEED_PREFIX, tournament.key().as_ref(), registration.wallet.as_ref()],
+        bump = points_escrow.bump,
+        constraint = !points_escrow.released @ ArenaError::PointsAlreadyDistributed
+    )]
+    pub points_escrow: Option<Account<'info, PointsEscrow>>,
+
+    /// PDA that holds mint authority, required to re-mint burned POINTS
+    #[account(
+        seeds = [PointsMintAuthority::SEED_PREFIX],
+        bump = mint_authority.bump
+    )]
+    pub mint_authority: Option<Account<'info, PointsMintAuthority>>,
+
+    /// POINTS SPL token mint, required to re-mint burned POINTS
+    #[account(
+        mut,
+        constraint = points_mint.key() == tournament.points_mint(&arena_config) @ ArenaError::InvalidTierPayment
+    )]
+    pub points_mint: Option<Account<'info, Mint>>,
+
+    /// Player's POINTS token account, required to re-mint burned POINTS
+    #[account(
+        mut,
+        constraint = player_token_account.owner == registration.wallet @ ArenaError::Unauthorized
+    )]
+    pub player_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Token program, required to re-mint burned POINTS
+    pub token_program: Option<Program<'info, Token>>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+impl<'info> ResolveAppeal<'info> {
+    /// Re-mint `amount` burned POINTS to the player, signed by the mint authority PDA
+    fn remint_points(&self, amount: u64) -> Result<()> {
+        let (
+            Some(mint_authority),
+            Some(points_mint),
+            Some(player_token_account),
+            Some(token_program),
+        ) = (
+            self.mint_authority.as_ref(),
+            self.points_mint.as_ref(),
+            self.player_token_account.as_ref(),
+            self.token_program.as_ref(),
+        )
+        else {
+            return err!(ArenaError::SanctionRestoreAccountsMissing);
+        };
+        require_keys_eq!(
+            player_token_account.mint,
+            points_mint.key(),
+            ArenaError::InvalidTierPayment
+        );
+
+        let seeds = &[PointsMintAuthority::SEED_PREFIX, &[mint_authority.bump]];
+        let signer_seeds = &[&seeds[..]];
+
+        token::mint_to(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                MintTo {
+                    mint: points_mint.to_account_info(),
+                    to: player_token_account.to_account_info(),
+                    authority: mint_authority.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )
+    }
+}
+
+/// Resolve a player's appeal (admin, or anyone once the arbiter committee decided).
+///
+/// This instruction:
+/// 1. Requires the admin, or the arbiter committee's threshold of votes for
+///    the ruling
+/// 2. Marks the appeal Granted or Denied, clears the pending flag and the
+///    sanction's appeal window, releasing the player's held POINTS
+/// 3. On grant, restores exactly what the appealed sanctions took: POINTS
+///    removed from the award or the PointsEscrow are put back, and burned
+///    POINTS are re-minted to the player
+/// 4. On grant, lifts any disqualification and restores the roster slot
+///
+/// # Arguments
+/// * `granted` - Whether the appeal is upheld in the player's favor
+pub fn handler(ctx: Context<ResolveAppeal>, granted: bool) -> Result<()> {
+    let caller = ctx.accounts.caller.key();
+    let committee_decided = ctx
+        .accounts
+        .arbiter_set
+        .as_ref()
+        .is_some_and(|arbiter_set| ctx.accounts.appeal.committee_decided(arbiter_set, granted));
+    require!(
+        caller == ctx.accounts.arena_config.admin || committee_decided,
+        ArenaError::Unauthorized
+    );
+
+    let clock = Clock::get()?;
+    let sanction = ctx.accounts.registration.appealable_sanction;
+    let mut points_restored = 0;
+
+    if granted {
+        if sanction.burned_points > 0 {
+            ctx.accounts.remint_points(sanction.burned_points)?;
+        }
+        if sanction.escrowed_points > 0 {
+            let points_escrow = ctx
+                .accounts
+                .points_escrow
+                .as_mut()
+                .ok_or(ArenaError::SanctionRestoreAccountsMissing)?;
+            points_escrow.points = points_escrow
+                .points
+                .saturating_add(sanction.escrowed_points);
+        }
+
+        let registration = &mut ctx.accounts.registration;
+        if sanction.award_points > 0 {
+            let awarded = registration.points_awarded.unwrap_or_default();
+            registration.points_awarded = Some(awarded.saturating_add(sanction.award_points));
+        }
+        points_restored = sanction.points();
+        registration.penalty_points = registration.penalty_points.saturating_sub(points_restored);
+
+        if sanction.disqualified && registration.disqualified {
+            let tournament = &mut ctx.accounts.tournament;
+            registration.disqualified = false;
+            tournament.registered_players = tournament.registered_players.saturating_add(1);
+            let tier_count = &mut tournament.tier_counts[registration.tier as usize];
+            *tier_count = tier_count.saturating_add(1);
+        }
+    }
+
+    let registration = &mut ctx.accounts.registration;
+    registration.appeal_pending = false;
+    registration.sanctioned_at = 0;
+    registration.appealable_sanction = AppealableSanction::default();
+
+    let appeal = &mut ctx.accounts.appeal;
+    appeal.status = if granted {
+        AppealStatus::Granted
+    } else {
+        AppealStatus::Denied
+    };
+    appeal.resolved_at = Some(clock.unix_timestamp);
+
+    emit!(AppealResolved {
+        tournament: ctx.accounts.tournament.key(),
+        wallet: registration.wallet,
+        granted,
+        points_restored,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Appeal for player {} resolved: granted = {}",
+        registration.wallet,
+        granted
+    );
+
+    AuditLog::record_call::<instruction::ResolveAppeal>(
+        &mut ctx.accounts.audit_log,
+        caller,
+        ctx.accounts.tournament.id,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{
+    ArbiterAttestation, ArbiterSet, ArenaConfig, AuditLog, Tournament, TournamentStatus,
+};
+
+/// Accounts required for resolving a dispute raised against submitted results.
+#[derive(Accounts)]
+pub struct ResolveDispute<'info> {
+    /// Arena admin or the tournament's delegated operator
+    pub authority: Signer<'info>,
+
+    /// Arena config for admin verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament - must have results submitted with an unresolved dispute
+    #[account(
+        mut,
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.can_operate(&authority.key(), &arena_config.admin) @ ArenaError::Unauthorized,
+        constraint = tournament.status == TournamentStatus::ResultsSubmitted @ ArenaError::TournamentNotCompleted,
+        constraint = tournament.disputed && !tournament.dispute_resolved @ ArenaError::InvalidResultsHash
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Current arbiter committee
+    #[account(
+        seeds = [ArbiterSet::SEED_PREFIX],
+        bump = arbiter_set.bump
+    )]
+    pub arbiter_set: Account<'info, ArbiterSet>,
+
+    /// Arbiter attestation for the submitted results
+    #[account(
+        seeds = [
+            ArbiterAttestation::SEED_PREFIX,
+            tournament.key().as_ref(),
+            tournament.results_hash.ok_or(ArenaError::InvalidResultsHash)?.as_ref()
+        ],
+        bump = arbiter_attestation.bump
+    )]
+    pub arbiter_attestation: Account<'info, ArbiterAttestation>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Resolve a dispute raised during the dispute window (admin or operator).
+///
+/// This instruction:
+/// 1. Requires threshold arbiter attestations upholding the submitted results
+///    and winner
+/// 2. Marks the dispute resolved, allowing the results to be confirmed
+pub fn handler(ctx: Context<ResolveDispute>) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+    let attestation = &ctx.accounts.arbiter_attestation;
+    require!(
+        attestation.meets_threshold(&ctx.accounts.arbiter_set)
+            && Some(attestation.winner) == tournament.winner,
+        ArenaError::ArbiterThresholdNotMet
+    );
+
+    tournament.dispute_resolved = true;
+
+    msg!("Dispute on tournament {} resolved", tournament.id);
+
+    AuditLog::record_call::<instruction::ResolveDispute>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.authority.key(),
+        ctx.accounts.tournament.id,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+
+use crate::errors::ArenaError;
+use crate::state::{ArenaConfig, PlayerRegistration, Tournament, TournamentStatus};
+
+/// Accounts required for revealing an agent prompt.
+#[derive(Accounts)]
+pub struct RevealPrompt<'info> {
+    /// Player who owns the registration, or the arena admin
+    pub authority: Signer<'info>,
+
+    /// Arena config for admin verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament - must be Completed
+    #[account(
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.status == TournamentStatus::Completed @ ArenaError::TournamentNotCompleted
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Registration whose prompt is being revealed
+    #[account(
+        mut,
+        seeds = [PlayerRegistration::SEED_PREFIX, tournament.key().as_ref(), registration.wallet.as_ref()],
+        bump = registration.bump,
+        constraint = registration.tournament == tournament.key() @ ArenaError::TournamentNotFound,
+        constraint = authority.key() == registration.wallet
+            || authority.key() == arena_config.admin @ ArenaError::Unauthorized,
+        constraint = !registration.prompt_verified @ ArenaError::PromptAlreadyVerified
+    )]
+    pub registration: Account<'info, PlayerRegistration>,
+}
+
+/// Reveal the prompt an agent played with after the tournament completes.
+///
+/// This instruction:
+/// 1. Hashes the submitted prompt bytes with SHA-256
+/// 2. Compares the digest to the agent_prompt_hash committed at registration
+/// 3. Marks the registration as verified on a match
+///
+/// # Arguments
+/// * `prompt` - The exact prompt bytes that were hashed at registration
+pub fn handler(ctx: Context<RevealPrompt>, prompt: Vec<u8>) -> Result<()> {
+    let registration = &mut ctx.accounts.registration;
+
+    let digest = hash(&prompt).to_bytes();
+    require!(
+        digest == registration.agent_prompt_hash,
+        ArenaError::PromptHashMismatch
+    );
+
+    registration.prompt_verified = true;
+
+    msg!("Prompt verified for player: {}", registration.wallet);
+    msg!("Revealed by: {}", ctx.accounts.authority.key());
+
+    Ok(())
+}
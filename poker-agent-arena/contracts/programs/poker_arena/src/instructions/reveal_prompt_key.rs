@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+
+use crate::errors::ArenaError;
+use crate::state::{
+    ArenaConfig, LedgerCategory, LedgerDirection, PlayerRegistration, PromptEscrow, Tournament,
+    TournamentStatus, TreasuryLedger,
+};
+
+/// Accounts required for revealing an encrypted prompt's decryption key.
+#[derive(Accounts)]
+pub struct RevealPromptKey<'info> {
+    /// Player who owns the registration, or the arena admin
+    pub authority: Signer<'info>,
+
+    /// Arena config for admin verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament - must be Completed, Cancelled or Voided
+    #[account(
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = matches!(
+            tournament.status,
+            TournamentStatus::Completed | TournamentStatus::Cancelled | TournamentStatus::Voided
+        ) @ ArenaError::TournamentNotCompleted
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Registration the escrow belongs to
+    #[account(
+        seeds = [PlayerRegistration::SEED_PREFIX, tournament.key().as_ref(), registration.wallet.as_ref()],
+        bump = registration.bump,
+        constraint = registration.tournament == tournament.key() @ ArenaError::TournamentNotFound,
+        constraint = authority.key() == registration.wallet
+            || authority.key() == arena_config.admin @ ArenaError::Unauthorized
+    )]
+    pub registration: Account<'info, PlayerRegistration>,
+
+    /// Prompt escrow holding the commitment and bond
+    #[account(
+        mut,
+        seeds = [PromptEscrow::SEED_PREFIX, registration.key().as_ref()],
+        bump = prompt_escrow.bump,
+        constraint = !prompt_escrow.key_revealed @ ArenaError::KeyAlreadyRevealed
+    )]
+    pub prompt_escrow: Account<'info, PromptEscrow>,
+
+    /// Player wallet receiving the bond refund
+    /// CHECK: Verified against registration.wallet
+    #[account(
+        mut,
+        address = registration.wallet @ ArenaError::Unauthorized
+    )]
+    pub player: UncheckedAccount<'info>,
+
+    /// Treasury ledger recording the bond movement (omit to skip)
+    #[account(
+        mut,
+        seeds = [TreasuryLedger::SEED_PREFIX],
+        bump = treasury_ledger.bump
+    )]
+    pub treasury_ledger: Option<Box<Account<'info, TreasuryLedger>>>,
+}
+
+/// Reveal the decryption key for an encrypted prompt commitment.
+///
+/// This instruction:
+/// 1. Validates the reveal window after tournament completion is still open;
+///    players of a cancelled or voided tournament may always reveal, as the
+///    bond is only forfeited over completed tournaments
+/// 2. Checks SHA-256(key) against the key commitment
+/// 3. Stores the key on-chain and refunds the bond to the player
+///
+/// # Arguments
+/// * `decryption_key` - The key that decrypts the committed prompt blob
+pub fn handler(ctx: Context<RevealPromptKey>, decryption_key: [u8; 32]) -> Result<()> {
+    let prompt_escrow = &mut ctx.accounts.prompt_escrow;
+    let tournament = &ctx.accounts.tournament;
+    let clock = Clock::get()?;
+
+    require!(
+        tournament.status != TournamentStatus::Completed
+            || PromptEscrow::is_reveal_window_open(
+                tournament.completed_at.unwrap_or_default(),
+                clock.unix_timestamp
+            ),
+        ArenaError::RevealWindowClosed
+    );
+    require!(
+        hash(&decryption_key).to_bytes() == prompt_escrow.key_commitment,
+        ArenaError::KeyCommitmentMismatch
+    );
+
+    prompt_escrow.decryption_key = decryption_key;
+    prompt_escrow.key_revealed = true;
+
+    // Refund the bond (escrow is program-owned, so lamports move directly)
+    let bond = prompt_escrow.bond_lamports;
+    prompt_escrow.bond_lamports = 0;
+    **prompt_escrow.to_account_info().try_borrow_mut_lamports()? -= bond;
+    **ctx.accounts.player.to_account_info().try_borrow_mut_lamports()? += bond;
+    if let Some(treasury_ledger) = ctx.accounts.treasury_ledger.as_mut() {
+        treasury_ledger.record(
+            LedgerCategory::PromptBond,
+            LedgerDirection::Outflow,
+            prompt_escrow.key(),
+            bond,
+            clock.unix_timestamp,
+        );
+    }
+
+    msg!("Prompt key revealed for player: {}", prompt_escrow.wallet);
+    msg!("Bond refunded: {} lamports", bond);
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::state::GiftConsent;
+
+/// Accounts required for withdrawing consent to a gifted registration.
+#[derive(Accounts)]
+pub struct RevokeGiftApproval<'info> {
+    /// Beneficiary wallet that gave consent
+    #[account(mut)]
+    pub beneficiary: Signer<'info>,
+
+    /// Consent PDA to close
+    #[account(
+        mut,
+        close = beneficiary,
+        seeds = [GiftConsent::SEED_PREFIX, gift_consent.tournament.as_ref(), beneficiary.key().as_ref()],
+        bump = gift_consent.bump
+    )]
+    pub gift_consent: Account<'info, GiftConsent>,
+}
+
+/// Withdraw consent to a gifted registration, reclaiming its rent.
+pub fn handler(ctx: Context<RevokeGiftApproval>) -> Result<()> {
+    msg!(
+        "{} revoked gift approval for tournament {}",
+        ctx.accounts.beneficiary.key(),
+        ctx.accounts.gift_consent.tournament
+    );
+
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog, Invite, Tournament};
+
+/// Accounts required for revoking an unused invite.
+#[derive(Accounts)]
+pub struct RevokeInvite<'info> {
+    /// Arena admin, the tournament's delegated operator or its host; receives the invite's rent
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Arena config for admin verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament the invite admits to
+    #[account(
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.can_run(&authority.key(), &arena_config.admin) @ ArenaError::Unauthorized
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Invite to revoke - must not have been redeemed
+    #[account(
+        mut,
+        close = authority,
+        seeds = [Invite::SEED_PREFIX, tournament.key().as_ref(), invite.code_hash.as_ref()],
+        bump = invite.bump,
+        constraint = !invite.is_redeemed() @ ArenaError::InvalidInvite
+    )]
+    pub invite: Account<'info, Invite>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Revoke an unused invite, closing it and returning its rent (admin, operator or host).
+pub fn handler(ctx: Context<RevokeInvite>) -> Result<()> {
+    msg!(
+        "Revoked invite {} for tournament {}",
+        ctx.accounts.invite.key(),
+        ctx.accounts.tournament.id
+    );
+
+    AuditLog::record_call::<instruction::RevokeInvite>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.authority.key(),
+        ctx.accounts.tournament.id,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::state::SessionAuthority;
+
+/// Accounts required for revoking a session key.
+#[derive(Accounts)]
+pub struct RevokeSession<'info> {
+    /// Wallet that authorized the session
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    /// Session authority to close
+    #[account(
+        mut,
+        close = wallet,
+        seeds = [SessionAuthority::SEED_PREFIX, wallet.key().as_ref(), session_authority.session_key.as_ref()],
+        bump = session_authority.bump
+    )]
+    pub session_authority: Account<'info, SessionAuthority>,
+}
+
+/// Revoke a session key before it expires, reclaiming its rent.
+pub fn handler(ctx: Context<RevokeSession>) -> Result<()> {
+    msg!(
+        "Session {} revoked for {}",
+        ctx.accounts.session_authority.session_key,
+        ctx.accounts.wallet.key()
+    );
+
+    Ok(())
+}
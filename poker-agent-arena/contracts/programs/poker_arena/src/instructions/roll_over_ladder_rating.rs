@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::events::LadderRatingRolledOver;
+use crate::state::{Ladder, LadderRating};
+
+/// Accounts required for rolling a ladder rating into the current season.
+#[derive(Accounts)]
+pub struct RollOverLadderRating<'info> {
+    /// Anyone may crank a rollover
+    pub caller: Signer<'info>,
+
+    /// Ladder for the current season and division floors
+    #[account(
+        seeds = [Ladder::SEED_PREFIX],
+        bump = ladder.bump
+    )]
+    pub ladder: Account<'info, Ladder>,
+
+    /// Rating due a rollover
+    #[account(
+        mut,
+        seeds = [LadderRating::SEED_PREFIX, ladder_rating.wallet.as_ref()],
+        bump = ladder_rating.bump,
+        constraint = ladder_rating.season < ladder.season @ ArenaError::CrankNotReady
+    )]
+    pub ladder_rating: Account<'info, LadderRating>,
+}
+
+/// Apply season-rollover promotion or relegation to a rating (permissionless).
+///
+/// The player moves one division toward the one their ELO qualifies for,
+/// however many seasons they missed.
+pub fn handler(ctx: Context<RollOverLadderRating>) -> Result<()> {
+    let ladder_rating = &mut ctx.accounts.ladder_rating;
+    let from_division = ladder_rating.division;
+    let to_division = ladder_rating.roll_over(&ctx.accounts.ladder);
+
+    emit!(LadderRatingRolledOver {
+        wallet: ladder_rating.wallet,
+        season: ladder_rating.season,
+        from_division,
+        to_division,
+        rating: ladder_rating.rating,
+    });
+
+    msg!(
+        "Ladder rating {} rolled over: {:?} -> {:?}",
+        ladder_rating.wallet,
+        from_division,
+        to_division
+    );
+
+    Ok(())
+}
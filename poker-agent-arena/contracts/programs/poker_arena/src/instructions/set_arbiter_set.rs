@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{ArbiterSet, ArenaConfig, AuditLog};
+
+/// Accounts required for appointing the arbiter committee.
+#[derive(Accounts)]
+pub struct SetArbiterSet<'info> {
+    /// Admin wallet - must match arena_config.admin
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// Arena config for admin verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Arbiter set PDA (created on first appointment)
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = ArbiterSet::SIZE,
+        seeds = [ArbiterSet::SEED_PREFIX],
+        bump
+    )]
+    pub arbiter_set: Account<'info, ArbiterSet>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Appoint the arbiter committee and its threshold (admin only).
+///
+/// Bumps the set version, so attestations collected under the previous
+/// committee no longer count.
+///
+/// # Arguments
+/// * `members` - Arbiter keys (Pubkey::default() for unused slots)
+/// * `threshold` - Attestations required, between 1 and the number of members
+pub fn handler(
+    ctx: Context<SetArbiterSet>,
+    members: [Pubkey; ArbiterSet::MAX_MEMBERS],
+    threshold: u8,
+) -> Result<()> {
+    let appointed: Vec<&Pubkey> = members
+        .iter()
+        .filter(|m| **m != Pubkey::default())
+        .collect();
+    require!(
+        threshold > 0 && threshold as usize <= appointed.len(),
+        ArenaError::InvalidArbiterSet
+    );
+    require!(
+        appointed
+            .iter()
+            .enumerate()
+            .all(|(i, member)| !appointed[..i].contains(member)),
+        ArenaError::InvalidArbiterSet
+    );
+
+    let arbiter_set = &mut ctx.accounts.arbiter_set;
+    arbiter_set.members = members;
+    arbiter_set.threshold = threshold;
+    arbiter_set.version = arbiter_set.version.wrapping_add(1);
+    arbiter_set.bump = ctx.bumps.arbiter_set;
+
+    msg!(
+        "Arbiter set v{}: {} members, threshold {}",
+        arbiter_set.version,
+        appointed.len(),
+        threshold
+    );
+
+    AuditLog::record_call::<instruction::SetArbiterSet>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        0,
+    )?;
+
+    Ok(())
+}
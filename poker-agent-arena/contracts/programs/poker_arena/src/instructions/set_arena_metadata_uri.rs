@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::events::ArenaMetadataUpdated;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog, PlayerRegistration};
+
+/// Accounts required for updating the arena metadata URI.
+#[derive(Accounts)]
+pub struct SetArenaMetadataUri<'info> {
+    /// Admin wallet - must match arena_config.admin
+    pub admin: Signer<'info>,
+
+    /// Arena config to update
+    #[account(
+        mut,
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Set the URI clients read arena branding, API endpoints and rules from
+/// (admin only).
+///
+/// # Arguments
+/// * `metadata_uri` - ipfs:// or https:// URI, zero-padded to 128 bytes (all zeros to clear)
+pub fn handler(ctx: Context<SetArenaMetadataUri>, metadata_uri: [u8; 128]) -> Result<()> {
+    PlayerRegistration::validate_agent_image_uri(&metadata_uri)?;
+
+    ctx.accounts.arena_config.metadata_uri = metadata_uri;
+
+    emit!(ArenaMetadataUpdated {
+        metadata_uri,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Arena metadata URI updated");
+
+    AuditLog::record_call::<instruction::SetArenaMetadataUri>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        0,
+    )?;
+
+    Ok(())
+}
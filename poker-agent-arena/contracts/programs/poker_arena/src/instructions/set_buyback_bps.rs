@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog, BuybackLedger};
+
+/// Accounts required for configuring the rake buyback share.
+#[derive(Accounts)]
+pub struct SetBuybackBps<'info> {
+    /// Admin wallet - must match arena_config.admin
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// Arena config for admin verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Buyback ledger PDA (created on first call)
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = BuybackLedger::SIZE,
+        seeds = [BuybackLedger::SEED_PREFIX],
+        bump
+    )]
+    pub buyback_ledger: Account<'info, BuybackLedger>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Set the share of collected rake spent on POINTS buybacks (admin only).
+///
+/// # Arguments
+/// * `buyback_bps` - Share of rake in basis points (0-10000)
+pub fn handler(ctx: Context<SetBuybackBps>, buyback_bps: u16) -> Result<()> {
+    require!(buyback_bps <= 10_000, ArenaError::InvalidBasisPoints);
+
+    let buyback_ledger = &mut ctx.accounts.buyback_ledger;
+    buyback_ledger.buyback_bps = buyback_bps;
+    buyback_ledger.bump = ctx.bumps.buyback_ledger;
+
+    msg!("Buyback share set to {} bps", buyback_bps);
+
+    AuditLog::record_call::<instruction::SetBuybackBps>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        0,
+    )?;
+
+    Ok(())
+}
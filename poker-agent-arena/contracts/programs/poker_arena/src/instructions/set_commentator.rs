@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog, Commentator};
+
+/// Accounts required for appointing a commentator.
+#[derive(Accounts)]
+#[instruction(key: Pubkey)]
+pub struct SetCommentator<'info> {
+    /// Admin wallet - must match arena_config.admin
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// Arena config for admin verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Commentator entry for the key (created on first appointment)
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = Commentator::SIZE,
+        seeds = [Commentator::SEED_PREFIX, key.as_ref()],
+        bump
+    )]
+    pub commentator: Account<'info, Commentator>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Appoint or remove a commentator (admin only).
+///
+/// # Arguments
+/// * `key` - Commentator's signing key
+/// * `active` - Whether the key may post commentary
+pub fn handler(ctx: Context<SetCommentator>, key: Pubkey, active: bool) -> Result<()> {
+    let commentator = &mut ctx.accounts.commentator;
+    commentator.key = key;
+    commentator.active = active;
+    commentator.updated_at = Clock::get()?.unix_timestamp;
+    commentator.bump = ctx.bumps.commentator;
+
+    msg!("Commentator {} set to {}", key, active);
+
+    AuditLog::record_call::<instruction::SetCommentator>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        0,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog, CpiPartner};
+
+/// Accounts required for allowlisting a partner program.
+#[derive(Accounts)]
+#[instruction(program_id: Pubkey)]
+pub struct SetCpiPartner<'info> {
+    /// Admin wallet - must match arena_config.admin
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// Arena config for admin verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Allowlist entry for the program (created on first approval)
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = CpiPartner::SIZE,
+        seeds = [CpiPartner::SEED_PREFIX, program_id.as_ref()],
+        bump
+    )]
+    pub cpi_partner: Account<'info, CpiPartner>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Allow or block a partner program registering players via CPI (admin only).
+///
+/// # Arguments
+/// * `program_id` - Partner program ID
+/// * `active` - Whether the program may register players
+pub fn handler(ctx: Context<SetCpiPartner>, program_id: Pubkey, active: bool) -> Result<()> {
+    let cpi_partner = &mut ctx.accounts.cpi_partner;
+    cpi_partner.program_id = program_id;
+    cpi_partner.active = active;
+    cpi_partner.updated_at = Clock::get()?.unix_timestamp;
+    cpi_partner.bump = ctx.bumps.cpi_partner;
+
+    msg!("CPI partner {} set to {}", program_id, active);
+
+    AuditLog::record_call::<instruction::SetCpiPartner>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        0,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog};
+
+/// Accounts required for setting the crank bounty.
+#[derive(Accounts)]
+pub struct SetCrankBounty<'info> {
+    /// Admin wallet - must match arena_config.admin
+    pub admin: Signer<'info>,
+
+    /// Arena config to update
+    #[account(
+        mut,
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Set the lamport bounty paid per permissionless crank (admin only).
+///
+/// # Arguments
+/// * `bounty_lamports` - Lamports paid to each crank caller (0 disables bounties)
+pub fn handler(ctx: Context<SetCrankBounty>, bounty_lamports: u64) -> Result<()> {
+    ctx.accounts.arena_config.crank_bounty_lamports = bounty_lamports;
+
+    msg!("Crank bounty set to {} lamports", bounty_lamports);
+
+    AuditLog::record_call::<instruction::SetCrankBounty>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        0,
+    )?;
+
+    Ok(())
+}
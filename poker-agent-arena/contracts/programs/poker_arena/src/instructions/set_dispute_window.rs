@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog};
+
+/// Accounts required for configuring the dispute window on submitted results.
+#[derive(Accounts)]
+pub struct SetDisputeWindow<'info> {
+    /// Admin wallet - must match arena_config.admin
+    pub admin: Signer<'info>,
+
+    /// Arena config to update
+    #[account(
+        mut,
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Set how long submitted results stay disputable (admin only).
+///
+/// Results cannot be confirmed, and so POINTS not distributed, while the
+/// window is open. Applies to results submitted after the change.
+///
+/// # Arguments
+/// * `seconds` - Dispute window length after submission (0 allows immediate confirmation)
+pub fn handler(ctx: Context<SetDisputeWindow>, seconds: u32) -> Result<()> {
+    ctx.accounts.arena_config.dispute_window_seconds = seconds as i64;
+
+    msg!("Dispute window set to {}s", seconds);
+
+    AuditLog::record_call::<instruction::SetDisputeWindow>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        0,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog};
+
+/// Accounts required for setting the arena's feature flags.
+#[derive(Accounts)]
+pub struct SetFeatureFlags<'info> {
+    /// Admin wallet - must match arena_config.admin
+    pub admin: Signer<'info>,
+
+    /// Arena config to update
+    #[account(
+        mut,
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Turn optional subsystems on or off (admin only).
+///
+/// Replaces the whole bitmask; instructions of a subsystem whose flag is
+/// clear fail with FeatureDisabled.
+///
+/// # Arguments
+/// * `feature_flags` - Bitmask of `ArenaConfig::FEATURE_*` flags to enable
+pub fn handler(ctx: Context<SetFeatureFlags>, feature_flags: u64) -> Result<()> {
+    let arena_config = &mut ctx.accounts.arena_config;
+    let previous = arena_config.feature_flags;
+    arena_config.feature_flags = feature_flags;
+
+    msg!(
+        "Feature flags changed from {:#x} to {:#x}",
+        previous,
+        feature_flags
+    );
+
+    AuditLog::record_call::<instruction::SetFeatureFlags>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        0,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog};
+
+/// Accounts required for configuring the tier fee split.
+#[derive(Accounts)]
+pub struct SetFeeRecipients<'info> {
+    /// Admin wallet - must match arena_config.admin
+    pub admin: Signer<'info>,
+
+    /// Arena config to update
+    #[account(
+        mut,
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Split tier fees between up to four recipients and the treasury (admin only).
+///
+/// The treasury receives whatever the recipients' shares leave over.
+/// Clear a slot with Pubkey::default() and 0 bps.
+///
+/// # Arguments
+/// * `recipients` - Recipient wallets (e.g. operations, prize reserve, buyback)
+/// * `recipient_bps` - Each recipient's share in basis points (total <= 10000)
+pub fn handler(
+    ctx: Context<SetFeeRecipients>,
+    recipients: [Pubkey; ArenaConfig::MAX_FEE_RECIPIENTS],
+    recipient_bps: [u16; ArenaConfig::MAX_FEE_RECIPIENTS],
+) -> Result<()> {
+    let total_bps: u32 = recipient_bps.iter().map(|bps| *bps as u32).sum();
+    require!(total_bps <= 10_000, ArenaError::InvalidBasisPoints);
+    require!(
+        recipients
+            .iter()
+            .zip(recipient_bps)
+            .all(|(recipient, bps)| *recipient != Pubkey::default() || bps == 0),
+        ArenaError::InvalidFeeRecipients
+    );
+
+    let arena_config = &mut ctx.accounts.arena_config;
+    arena_config.fee_recipients = recipients;
+    arena_config.fee_recipient_bps = recipient_bps;
+
+    msg!("Fee recipients updated: {} bps split from the treasury", total_bps);
+
+    AuditLog::record_call::<instruction::SetFeeRecipients>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        0,
+    )?;
+
+    Ok(())
+}
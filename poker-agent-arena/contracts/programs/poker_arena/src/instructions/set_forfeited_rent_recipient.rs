@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog};
+
+/// Accounts required for configuring the forfeited rent recipient.
+#[derive(Accounts)]
+pub struct SetForfeitedRentRecipient<'info> {
+    /// Admin wallet - must match arena_config.admin
+    pub admin: Signer<'info>,
+
+    /// Arena config to update
+    #[account(
+        mut,
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Set who receives rent from collected registrations of voided tournaments
+/// and disqualified players (admin only).
+///
+/// # Arguments
+/// * `recipient` - Wallet receiving forfeited registration rent
+pub fn handler(ctx: Context<SetForfeitedRentRecipient>, recipient: Pubkey) -> Result<()> {
+    require_keys_neq!(
+        recipient,
+        Pubkey::default(),
+        ArenaError::InvalidRentRecipient
+    );
+
+    ctx.accounts.arena_config.forfeited_rent_recipient = recipient;
+
+    msg!("Forfeited rent recipient set to {}", recipient);
+
+    AuditLog::record_call::<instruction::SetForfeitedRentRecipient>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        0,
+    )?;
+
+    Ok(())
+}
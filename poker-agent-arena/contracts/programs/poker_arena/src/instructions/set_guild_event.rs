@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog, Tournament, TournamentStatus};
+
+/// Accounts required for flagging a tournament as a guild event.
+#[derive(Accounts)]
+pub struct SetGuildEvent<'info> {
+    /// Admin wallet - must match arena_config.admin
+    pub admin: Signer<'info>,
+
+    /// Arena config for admin verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament - must not have started
+    #[account(
+        mut,
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.status == TournamentStatus::Created
+            || tournament.status == TournamentStatus::Registration @ ArenaError::TournamentAlreadyStarted
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Flag a tournament as a guild-vs-guild event (admin only).
+///
+/// # Arguments
+/// * `enabled` - Aggregate member results into per-guild standings
+/// * `guild_prize_points` - POINTS split between the top guilds
+pub fn handler(ctx: Context<SetGuildEvent>, enabled: bool, guild_prize_points: u64) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+
+    tournament.guild_event = enabled;
+    tournament.guild_prize_points = if enabled { guild_prize_points } else { 0 };
+
+    msg!(
+        "Tournament {} guild event: {} ({} prize POINTS)",
+        tournament.id,
+        enabled,
+        tournament.guild_prize_points
+    );
+
+    AuditLog::record_call::<instruction::SetGuildEvent>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        ctx.accounts.tournament.id,
+    )?;
+
+    Ok(())
+}
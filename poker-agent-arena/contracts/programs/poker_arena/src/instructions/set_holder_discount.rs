@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog};
+
+/// Accounts required for configuring the POINTS holder discount.
+#[derive(Accounts)]
+pub struct SetHolderDiscount<'info> {
+    /// Admin wallet - must match arena_config.admin
+    pub admin: Signer<'info>,
+
+    /// Arena config to update
+    #[account(
+        mut,
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Set the tier fee discount for POINTS holders (admin only).
+///
+/// A registration passing the wallet's governance stake with at least
+/// `min_points` staked before the tournament was created earns the discount,
+/// stacked on any loyalty discount and recorded on the registration.
+///
+/// # Arguments
+/// * `min_points` - POINTS the registering wallet must have staked
+/// * `discount_bps` - Tier fee discount in basis points (0 disables it)
+pub fn handler(ctx: Context<SetHolderDiscount>, min_points: u64, discount_bps: u16) -> Result<()> {
+    require!(discount_bps <= 10_000, ArenaError::InvalidBasisPoints);
+
+    let arena_config = &mut ctx.accounts.arena_config;
+    arena_config.holder_discount_min_points = min_points;
+    arena_config.holder_discount_bps = discount_bps;
+
+    msg!(
+        "Holder discount of {} bps for wallets with {} POINTS",
+        discount_bps,
+        min_points
+    );
+
+    AuditLog::record_call::<instruction::SetHolderDiscount>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        0,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog, Host};
+
+/// Accounts required for approving a tournament host.
+#[derive(Accounts)]
+#[instruction(authority: Pubkey)]
+pub struct SetHost<'info> {
+    /// Admin wallet - must match arena_config.admin
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// Arena config for admin verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Host entry for the key (created on first approval)
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = Host::SIZE,
+        seeds = [Host::SEED_PREFIX, authority.as_ref()],
+        bump
+    )]
+    pub host: Account<'info, Host>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Approve, update or suspend a third-party tournament host (admin only).
+///
+/// Suspending a host stops it creating tournaments; tournaments it already
+/// created keep running.
+///
+/// # Arguments
+/// * `authority` - Host's signing key
+/// * `treasury` - Wallet receiving the host's share of tier fees
+/// * `platform_fee_bps` - Arena's share of hosted tier fees, in basis points
+/// * `active` - Whether the host may create tournaments
+pub fn handler(
+    ctx: Context<SetHost>,
+    authority: Pubkey,
+    treasury: Pubkey,
+    platform_fee_bps: u16,
+    active: bool,
+) -> Result<()> {
+    require!(platform_fee_bps <= 10_000, ArenaError::InvalidBasisPoints);
+    require!(
+        treasury != Pubkey::default(),
+        ArenaError::InvalidTierPayment
+    );
+
+    let host = &mut ctx.accounts.host;
+    host.authority = authority;
+    host.treasury = treasury;
+    host.platform_fee_bps = platform_fee_bps;
+    host.active = active;
+    host.updated_at = Clock::get()?.unix_timestamp;
+    host.bump = ctx.bumps.host;
+
+    msg!(
+        "Host {} set to {} with a {} bps platform fee",
+        authority,
+        active,
+        platform_fee_bps
+    );
+
+    AuditLog::record_call::<instruction::SetHost>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        0,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog};
+
+/// Accounts required for configuring the required host bond.
+#[derive(Accounts)]
+pub struct SetHostBond<'info> {
+    /// Admin wallet - must match arena_config.admin
+    pub admin: Signer<'info>,
+
+    /// Arena config to update
+    #[account(
+        mut,
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Set the bond a host must hold to create tournaments (admin only).
+///
+/// Raising the bond does not affect tournaments already created.
+///
+/// # Arguments
+/// * `bond_lamports` - Required bond in lamports (0 = no bond)
+pub fn handler(ctx: Context<SetHostBond>, bond_lamports: u64) -> Result<()> {
+    ctx.accounts.arena_config.host_bond_lamports = bond_lamports;
+
+    msg!("Host bond set to {} lamports", bond_lamports);
+
+    AuditLog::record_call::<instruction::SetHostBond>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        0,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog, Ladder};
+
+/// Accounts required for tuning the heads-up ladder.
+#[derive(Accounts)]
+pub struct SetLadderConfig<'info> {
+    /// Admin wallet - must match arena_config.admin
+    pub admin: Signer<'info>,
+
+    /// Arena config for admin verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Ladder to update
+    #[account(
+        mut,
+        seeds = [Ladder::SEED_PREFIX],
+        bump = ladder.bump
+    )]
+    pub ladder: Account<'info, Ladder>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Set the ladder's division floors and K-factor (admin only).
+///
+/// New floors apply to divisions at the next season rollover.
+///
+/// # Arguments
+/// * `division_floors` - Minimum ELO for Silver, Gold, Platinum, Diamond and Master
+/// * `k_factor` - ELO K-factor applied to each match
+pub fn handler(
+    ctx: Context<SetLadderConfig>,
+    division_floors: [u16; 5],
+    k_factor: u16,
+) -> Result<()> {
+    ctx.accounts.ladder.configure(division_floors, k_factor)?;
+
+    msg!(
+        "Ladder floors set to {:?}, K-factor {}",
+        division_floors,
+        k_factor
+    );
+
+    AuditLog::record_call::<instruction::SetLadderConfig>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        0,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog, Tournament, TournamentStatus};
+
+/// Accounts required for setting a tournament's late registration period.
+#[derive(Accounts)]
+pub struct SetLateRegistration<'info> {
+    /// Arena admin, the tournament's delegated operator or its host
+    pub authority: Signer<'info>,
+
+    /// Arena config for admin verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament - must not have started
+    #[account(
+        mut,
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.can_run(&authority.key(), &arena_config.admin) @ ArenaError::Unauthorized,
+        constraint = tournament.status == TournamentStatus::Created
+            || tournament.status == TournamentStatus::Registration @ ArenaError::TournamentAlreadyStarted
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Keep registration open for a period past the scheduled start (admin, operator or host).
+///
+/// Registrations are otherwise rejected from `starts_at` on, even while the
+/// tournament is still in Registration status.
+///
+/// # Arguments
+/// * `late_registration_secs` - Seconds past `starts_at` registration stays open (0 to close at the start)
+pub fn handler(ctx: Context<SetLateRegistration>, late_registration_secs: u32) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+    tournament.late_registration_secs = late_registration_secs;
+
+    msg!(
+        "Tournament {} registration closes {} seconds after its start",
+        tournament.id,
+        late_registration_secs
+    );
+
+    AuditLog::record_call::<instruction::SetLateRegistration>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.authority.key(),
+        ctx.accounts.tournament.id,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog};
+
+/// Accounts required for configuring the late unregistration refund.
+#[derive(Accounts)]
+pub struct SetLateUnregisterRefund<'info> {
+    /// Admin wallet - must match arena_config.admin
+    pub admin: Signer<'info>,
+
+    /// Arena config to update
+    #[account(
+        mut,
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Set the share of the tier fee refunded to players who unregister after
+/// registration has closed; the rest is retained by the treasury (admin only).
+///
+/// # Arguments
+/// * `refund_bps` - Share refunded, in basis points (10000 = full refund)
+pub fn handler(ctx: Context<SetLateUnregisterRefund>, refund_bps: u16) -> Result<()> {
+    require!(refund_bps <= 10_000, ArenaError::InvalidBasisPoints);
+    ctx.accounts.arena_config.late_unregister_refund_bps = refund_bps;
+
+    msg!("Late unregistration refund set to {} bps", refund_bps);
+
+    AuditLog::record_call::<instruction::SetLateUnregisterRefund>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        0,
+    )?;
+
+    Ok(())
+}
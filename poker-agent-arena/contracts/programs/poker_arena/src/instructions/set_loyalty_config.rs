@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog};
+
+/// Accounts required for configuring loyalty levels.
+#[derive(Accounts)]
+pub struct SetLoyaltyConfig<'info> {
+    /// Admin wallet - must match arena_config.admin
+    pub admin: Signer<'info>,
+
+    /// Arena config to update
+    #[account(
+        mut,
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Set loyalty thresholds and tier fee discounts (admin only).
+///
+/// Registration discounts use the level earned from PlayerStats at the time,
+/// so new thresholds apply right away; the level shown on PlayerStatsV2 is
+/// re-evaluated at each player's next result.
+///
+/// # Arguments
+/// * `thresholds` - Tournaments played for Bronze, Silver and Gold (0 disables a level)
+/// * `discount_bps` - Tier fee discount for Bronze, Silver and Gold
+pub fn handler(
+    ctx: Context<SetLoyaltyConfig>,
+    thresholds: [u32; 3],
+    discount_bps: [u16; 3],
+) -> Result<()> {
+    require!(
+        discount_bps.iter().all(|bps| *bps <= 10_000),
+        ArenaError::InvalidBasisPoints
+    );
+
+    let enabled: Vec<u32> = thresholds.iter().copied().filter(|t| *t > 0).collect();
+    require!(
+        enabled.windows(2).all(|pair| pair[0] < pair[1]),
+        ArenaError::InvalidLoyaltyThresholds
+    );
+
+    let arena_config = &mut ctx.accounts.arena_config;
+    arena_config.loyalty_thresholds = thresholds;
+    arena_config.loyalty_discount_bps = discount_bps;
+
+    msg!(
+        "Loyalty thresholds {:?}, discounts {:?} bps",
+        thresholds,
+        discount_bps
+    );
+
+    AuditLog::record_call::<instruction::SetLoyaltyConfig>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        0,
+    )?;
+
+    Ok(())
+}
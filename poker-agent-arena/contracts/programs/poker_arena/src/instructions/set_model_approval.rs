@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{ApprovedModel, ArenaConfig, AuditLog};
+
+/// Accounts required for approving or revoking a model.
+#[derive(Accounts)]
+#[instruction(model_id_hash: [u8; 32])]
+pub struct SetModelApproval<'info> {
+    /// Admin wallet - must match arena_config.admin
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// Arena config for admin verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Registry entry for the model (created on first approval)
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = ApprovedModel::SIZE,
+        seeds = [ApprovedModel::SEED_PREFIX, model_id_hash.as_ref()],
+        bump
+    )]
+    pub approved_model: Account<'info, ApprovedModel>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Approve or revoke a model for PRO agents (admin only).
+///
+/// Revoking only blocks new registrations; existing ones keep their model.
+///
+/// # Arguments
+/// * `model_id_hash` - SHA-256 hash of the model identifier
+/// * `active` - Whether the model is approved
+pub fn handler(ctx: Context<SetModelApproval>, model_id_hash: [u8; 32], active: bool) -> Result<()> {
+    let approved_model = &mut ctx.accounts.approved_model;
+    approved_model.model_id_hash = model_id_hash;
+    approved_model.active = active;
+    approved_model.updated_at = Clock::get()?.unix_timestamp;
+    approved_model.bump = ctx.bumps.approved_model;
+
+    msg!("Model approval set to {}", active);
+
+    AuditLog::record_call::<instruction::SetModelApproval>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        0,
+    )?;
+
+    Ok(())
+}
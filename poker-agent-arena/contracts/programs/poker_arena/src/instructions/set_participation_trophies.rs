@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog, Tournament, TournamentStatus};
+
+/// Accounts required for toggling participation trophies.
+#[derive(Accounts)]
+pub struct SetParticipationTrophies<'info> {
+    /// Admin wallet - must match arena_config.admin
+    pub admin: Signer<'info>,
+
+    /// Arena config for admin verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament - must not have started
+    #[account(
+        mut,
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.status == TournamentStatus::Created
+            || tournament.status == TournamentStatus::Registration @ ArenaError::TournamentAlreadyStarted
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Opt a tournament in or out of participation trophies (admin only).
+///
+/// # Arguments
+/// * `enabled` - Mint a trophy cNFT to each player when results are recorded
+pub fn handler(ctx: Context<SetParticipationTrophies>, enabled: bool) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+
+    tournament.trophies_enabled = enabled;
+
+    msg!(
+        "Tournament {} participation trophies: {}",
+        tournament.id,
+        enabled
+    );
+
+    AuditLog::record_call::<instruction::SetParticipationTrophies>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        ctx.accounts.tournament.id,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::state::PlayerProfile;
+
+/// Accounts required for creating or updating a player profile.
+#[derive(Accounts)]
+pub struct SetPlayerProfile<'info> {
+    /// Profile owner
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    /// Player's profile PDA (created on first use)
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = PlayerProfile::SIZE,
+        seeds = [PlayerProfile::SEED_PREFIX, player.key().as_ref()],
+        bump
+    )]
+    pub player_profile: Account<'info, PlayerProfile>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Create or update the signer's player profile.
+///
+/// # Arguments
+/// * `display_name` - Display name (32 bytes, UTF-8, null-padded)
+/// * `avatar_uri` - Avatar URI (128 bytes, ipfs:// or https://, or all zeros for none)
+/// * `bio_hash` - SHA-256 hash of the off-chain bio
+/// * `country_code` - ISO 3166-1 alpha-2 code in uppercase ASCII, or all zeros
+pub fn handler(
+    ctx: Context<SetPlayerProfile>,
+    display_name: [u8; 32],
+    avatar_uri: [u8; 128],
+    bio_hash: [u8; 32],
+    country_code: [u8; 2],
+) -> Result<()> {
+    PlayerProfile::validate(&display_name, &avatar_uri, &country_code)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let player_profile = &mut ctx.accounts.player_profile;
+    if player_profile.wallet == Pubkey::default() {
+        player_profile.wallet = ctx.accounts.player.key();
+        player_profile.created_at = now;
+        player_profile.bump = ctx.bumps.player_profile;
+    }
+    player_profile.display_name = display_name;
+    player_profile.avatar_uri = avatar_uri;
+    player_profile.bio_hash = bio_hash;
+    player_profile.country_code = country_code;
+    player_profile.updated_at = now;
+
+    msg!("Profile updated for player: {}", player_profile.wallet);
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog, PointsFormula, Tournament, TournamentStatus};
+
+/// Accounts required for committing a tournament's points formula.
+#[derive(Accounts)]
+pub struct SetPointsFormula<'info> {
+    /// Arena admin, the tournament's delegated operator or its host
+    pub authority: Signer<'info>,
+
+    /// Arena config for admin verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament - must not have started
+    #[account(
+        mut,
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.can_run(&authority.key(), &arena_config.admin) @ ArenaError::Unauthorized,
+        constraint = tournament.status == TournamentStatus::Created
+            || tournament.status == TournamentStatus::Registration @ ArenaError::TournamentAlreadyStarted
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Commit the points-award formula results are checked against (admin, operator or host).
+///
+/// Once committed, `record_player_result` and `record_compressed_result`
+/// reject any award that differs from the formula's, computed from the
+/// entrant count, finishing place and tier. The formula is fixed once the
+/// tournament starts.
+///
+/// # Arguments
+/// * `points_formula` - Formula to commit, or None to stop checking awards
+pub fn handler(
+    ctx: Context<SetPointsFormula>,
+    points_formula: Option<PointsFormula>,
+) -> Result<()> {
+    if let Some(formula) = &points_formula {
+        formula.validate()?;
+    }
+
+    let tournament = &mut ctx.accounts.tournament;
+    tournament.points_formula = points_formula;
+
+    msg!(
+        "Tournament {} points formula {}",
+        tournament.id,
+        if points_formula.is_some() {
+            "committed"
+        } else {
+            "cleared"
+        }
+    );
+
+    AuditLog::record_call::<instruction::SetPointsFormula>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.authority.key(),
+        ctx.accounts.tournament.id,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog, RedemptionVault};
+
+/// Accounts required for setting the POINTS redemption rate.
+#[derive(Accounts)]
+pub struct SetRedemptionRate<'info> {
+    /// Admin wallet - must match arena_config.admin
+    pub admin: Signer<'info>,
+
+    /// Arena config for admin verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Redemption vault to update
+    #[account(
+        mut,
+        seeds = [RedemptionVault::SEED_PREFIX],
+        bump = redemption_vault.bump
+    )]
+    pub redemption_vault: Account<'info, RedemptionVault>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Set the POINTS redemption rate (admin only).
+///
+/// # Arguments
+/// * `lamports_per_point` - Lamports paid per whole POINT (0 = pro-rata share of the vault)
+pub fn handler(ctx: Context<SetRedemptionRate>, lamports_per_point: u64) -> Result<()> {
+    ctx.accounts.redemption_vault.lamports_per_point = lamports_per_point;
+
+    msg!(
+        "Redemption rate set to {} lamports per POINT",
+        lamports_per_point
+    );
+
+    AuditLog::record_call::<instruction::SetRedemptionRate>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        0,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog, Tournament, TournamentStatus};
+
+/// Accounts required for setting per-tier registration windows.
+#[derive(Accounts)]
+pub struct SetRegistrationWindows<'info> {
+    /// Arena admin, the tournament's delegated operator or its host
+    pub authority: Signer<'info>,
+
+    /// Arena config for admin verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament - must not have started
+    #[account(
+        mut,
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.can_run(&authority.key(), &arena_config.admin) @ ArenaError::Unauthorized,
+        constraint = tournament.status == TournamentStatus::Created
+            || tournament.status == TournamentStatus::Registration @ ArenaError::TournamentAlreadyStarted
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Set priority registration windows so PRO, then BASIC, agents can register
+/// before general registration opens (admin, operator or host).
+///
+/// # Arguments
+/// * `pro_opens_at` - When PRO registrations are accepted
+/// * `basic_opens_at` - When BASIC registrations are accepted
+/// * `general_opens_at` - When FREE registrations are accepted
+pub fn handler(
+    ctx: Context<SetRegistrationWindows>,
+    pro_opens_at: i64,
+    basic_opens_at: i64,
+    general_opens_at: i64,
+) -> Result<()> {
+    require!(
+        pro_opens_at <= basic_opens_at && basic_opens_at <= general_opens_at,
+        ArenaError::InvalidRegistrationWindows
+    );
+
+    let tournament = &mut ctx.accounts.tournament;
+    tournament.tier_opens_at = [general_opens_at, basic_opens_at, pro_opens_at];
+
+    msg!(
+        "Tournament {} opens to PRO at {}, BASIC at {}, everyone at {}",
+        tournament.id,
+        pro_opens_at,
+        basic_opens_at,
+        general_opens_at
+    );
+
+    AuditLog::record_call::<instruction::SetRegistrationWindows>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.authority.key(),
+        ctx.accounts.tournament.id,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog};
+
+/// Accounts required for setting the results authority.
+#[derive(Accounts)]
+pub struct SetResultsAuthority<'info> {
+    /// Admin wallet - must match arena_config.admin
+    pub admin: Signer<'info>,
+
+    /// Arena config to update
+    #[account(
+        mut,
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Set the key that records results and finalizes tournaments (admin only).
+///
+/// Once set, only this key may call `record_player_result`,
+/// `submit_results` and `confirm_results`, so the engine can hold it while the admin key
+/// stays offline. Pubkey::default() hands both back to the admin and
+/// tournament operators.
+///
+/// # Arguments
+/// * `results_authority` - Key allowed to record results
+pub fn handler(ctx: Context<SetResultsAuthority>, results_authority: Pubkey) -> Result<()> {
+    ctx.accounts.arena_config.results_authority = results_authority;
+
+    msg!("Results authority set to {}", results_authority);
+
+    AuditLog::record_call::<instruction::SetResultsAuthority>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        0,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{
+    ArenaConfig, AuditLog, PrizeDenomination, PrizePool, Season, Tournament, TournamentStatus,
+};
+
+/// Accounts required for awarding a tournament's POINTS in its season's mint.
+#[derive(Accounts)]
+pub struct SetSeasonMint<'info> {
+    /// Admin or tournament operator
+    pub authority: Signer<'info>,
+
+    /// Arena config for operator verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament - must not have started
+    #[account(
+        mut,
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.can_operate(&authority.key(), &arena_config.admin) @ ArenaError::Unauthorized,
+        constraint = matches!(
+            tournament.status,
+            TournamentStatus::Created | TournamentStatus::Registration
+        ) @ ArenaError::TournamentAlreadyStarted
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Season the tournament belongs to
+    #[account(
+        seeds = [Season::SEED_PREFIX, &season.id.to_le_bytes()],
+        bump = season.bump,
+        constraint = season.covers(tournament.starts_at) @ ArenaError::InvalidSeason
+    )]
+    pub season: Account<'info, Season>,
+
+    /// Season's POINTS mint, created with `create_season_mint`
+    #[account(
+        seeds = [Season::MINT_SEED_PREFIX, season.key().as_ref()],
+        bump
+    )]
+    pub season_mint: Account<'info, Mint>,
+
+    /// Tournament's prize pool PDA (may be uninitialized if it has none)
+    /// CHECK: Address derived from seeds; only deserialized if it holds data
+    #[account(
+        mut,
+        seeds = [PrizePool::SEED_PREFIX, tournament.key().as_ref()],
+        bump
+    )]
+    pub prize_pool: UncheckedAccount<'info>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Award a tournament's POINTS in its season's mint (admin or operator).
+///
+/// Every instruction minting POINTS for the tournament, from distribution
+/// through vesting and backer claims, then requires the season mint. A
+/// POINTS prize pool created beforehand is switched to it as well.
+pub fn handler(ctx: Context<SetSeasonMint>) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+    tournament.season_mint = Some(ctx.accounts.season_mint.key());
+
+    let pool_info = ctx.accounts.prize_pool.to_account_info();
+    if !pool_info.data_is_empty() {
+        require_keys_eq!(
+            *pool_info.owner,
+            crate::ID,
+            ErrorCode::AccountOwnedByWrongProgram
+        );
+        let mut data = pool_info.try_borrow_mut_data()?;
+        let mut prize_pool = PrizePool::try_deserialize(&mut &data[..])?;
+        if prize_pool.denomination == PrizeDenomination::Points {
+            prize_pool.mint = ctx.accounts.season_mint.key();
+            prize_pool.try_serialize(&mut &mut data[..])?;
+        }
+    }
+
+    msg!(
+        "Tournament {} awards POINTS in season {} mint {}",
+        tournament.id,
+        ctx.accounts.season.id,
+        ctx.accounts.season_mint.key()
+    );
+
+    AuditLog::record_call::<instruction::SetSeasonMint>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.authority.key(),
+        ctx.accounts.tournament.id,
+    )?;
+
+    Ok(())
+}
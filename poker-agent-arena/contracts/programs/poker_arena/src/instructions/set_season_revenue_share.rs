@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog, Season};
+
+/// Accounts required for setting a season's revenue share.
+#[derive(Accounts)]
+pub struct SetSeasonRevenueShare<'info> {
+    /// Admin wallet - must match arena_config.admin
+    pub admin: Signer<'info>,
+
+    /// Arena config for admin verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Season to update
+    #[account(
+        mut,
+        seeds = [Season::SEED_PREFIX, &season.id.to_le_bytes()],
+        bump = season.bump
+    )]
+    pub season: Account<'info, Season>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Set the share of seasonal rake paid to the season's top agents (admin only).
+///
+/// # Arguments
+/// * `revenue_share_bps` - Share of seasonal rake, in basis points (0-10000)
+pub fn handler(ctx: Context<SetSeasonRevenueShare>, revenue_share_bps: u16) -> Result<()> {
+    require!(revenue_share_bps <= 10_000, ArenaError::InvalidBasisPoints);
+
+    let season = &mut ctx.accounts.season;
+    season.revenue_share_bps = revenue_share_bps;
+
+    msg!("Season {} revenue share set to {} bps", season.id, revenue_share_bps);
+
+    AuditLog::record_call::<instruction::SetSeasonRevenueShare>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        0,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog};
+
+/// Accounts required for configuring the stale tournament period.
+#[derive(Accounts)]
+pub struct SetStaleTournamentPeriod<'info> {
+    /// Admin wallet - must match arena_config.admin
+    pub admin: Signer<'info>,
+
+    /// Arena config to update
+    #[account(
+        mut,
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Set how long past `starts_at` a tournament may sit in Created before
+/// anyone can expire it (admin only).
+///
+/// # Arguments
+/// * `seconds` - Staleness period after the scheduled start
+pub fn handler(ctx: Context<SetStaleTournamentPeriod>, seconds: u32) -> Result<()> {
+    ctx.accounts.arena_config.stale_tournament_seconds = seconds as i64;
+
+    msg!("Stale tournament period set to {}s", seconds);
+
+    AuditLog::record_call::<instruction::SetStaleTournamentPeriod>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        0,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog};
+
+/// Accounts required for pricing tickets in POINTS.
+#[derive(Accounts)]
+pub struct SetTicketRate<'info> {
+    /// Admin wallet - must match arena_config.admin
+    pub admin: Signer<'info>,
+
+    /// Arena config to update
+    #[account(
+        mut,
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Set the POINTS burned per SOL of tier fee when buying tickets (admin only).
+///
+/// # Arguments
+/// * `points_per_sol` - POINTS (base units) per SOL of tier fee (0 disables tickets)
+pub fn handler(ctx: Context<SetTicketRate>, points_per_sol: u64) -> Result<()> {
+    ctx.accounts.arena_config.ticket_points_per_sol = points_per_sol;
+
+    msg!("Ticket rate set to {} POINTS per SOL", points_per_sol);
+
+    AuditLog::record_call::<instruction::SetTicketRate>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        0,
+    )?;
+
+    Ok(())
+}
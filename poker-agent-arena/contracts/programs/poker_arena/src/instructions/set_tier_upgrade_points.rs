@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog};
+
+/// Accounts required for pricing tier upgrades in POINTS.
+#[derive(Accounts)]
+pub struct SetTierUpgradePoints<'info> {
+    /// Admin wallet - must match arena_config.admin
+    pub admin: Signer<'info>,
+
+    /// Arena config to update
+    #[account(
+        mut,
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Set the POINTS burned per tier upgrade step (admin only).
+///
+/// A FREE to PRO upgrade burns the sum of both steps.
+///
+/// # Arguments
+/// * `points` - POINTS for FREE to BASIC and BASIC to PRO (0 disables a step)
+pub fn handler(ctx: Context<SetTierUpgradePoints>, points: [u64; 2]) -> Result<()> {
+    ctx.accounts.arena_config.tier_upgrade_points = points;
+
+    msg!("Tier upgrade POINTS set to {:?}", points);
+
+    AuditLog::record_call::<instruction::SetTierUpgradePoints>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        0,
+    )?;
+
+    Ok(())
+}
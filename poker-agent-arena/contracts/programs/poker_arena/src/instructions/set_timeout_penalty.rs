@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog};
+
+/// Accounts required for configuring the timeout penalty.
+#[derive(Accounts)]
+pub struct SetTimeoutPenalty<'info> {
+    /// Admin wallet - must match arena_config.admin
+    pub admin: Signer<'info>,
+
+    /// Arena config to update
+    #[account(
+        mut,
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Set the POINTS penalty for excessive decision timeouts (admin only).
+///
+/// A player whose committed timeouts exceed `threshold` has `penalty_points`
+/// deducted from their award when the result is recorded.
+///
+/// # Arguments
+/// * `threshold` - Timeouts allowed before the penalty applies
+/// * `penalty_points` - POINTS deducted (0 disables the penalty)
+pub fn handler(ctx: Context<SetTimeoutPenalty>, threshold: u16, penalty_points: u64) -> Result<()> {
+    let arena_config = &mut ctx.accounts.arena_config;
+    arena_config.timeout_penalty_threshold = threshold;
+    arena_config.timeout_penalty_points = penalty_points;
+
+    msg!(
+        "Timeout penalty of {} POINTS above {} timeouts",
+        penalty_points,
+        threshold
+    );
+
+    AuditLog::record_call::<instruction::SetTimeoutPenalty>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        0,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog, Division, Tournament, TournamentStatus};
+
+/// Accounts required for restricting a tournament to a ladder division.
+#[derive(Accounts)]
+pub struct SetTournamentDivision<'info> {
+    /// Arena admin, the tournament's delegated operator or its host
+    pub authority: Signer<'info>,
+
+    /// Arena config for admin verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament to restrict - must not have opened registration
+    #[account(
+        mut,
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.can_run(&authority.key(), &arena_config.admin) @ ArenaError::Unauthorized,
+        constraint = tournament.status == TournamentStatus::Created @ ArenaError::TournamentAlreadyStarted
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Restrict registration to one ladder division (admin, operator or host).
+///
+/// Players then pass their LadderRating when registering.
+///
+/// # Arguments
+/// * `division` - Division allowed to register (None opens the tournament to all)
+pub fn handler(ctx: Context<SetTournamentDivision>, division: Option<Division>) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+    tournament.division = division;
+
+    msg!(
+        "Tournament {} division set to {:?}",
+        tournament.id,
+        division
+    );
+
+    AuditLog::record_call::<instruction::SetTournamentDivision>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.authority.key(),
+        ctx.accounts.tournament.id,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog, Tournament, TournamentFormat, TournamentStatus};
+
+/// Accounts required for choosing a tournament's format.
+#[derive(Accounts)]
+pub struct SetTournamentFormat<'info> {
+    /// Arena admin, the tournament's delegated operator or its host
+    pub authority: Signer<'info>,
+
+    /// Arena config for admin verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament to update - must not have opened registration
+    #[account(
+        mut,
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.can_run(&authority.key(), &arena_config.admin) @ ArenaError::Unauthorized,
+        constraint = tournament.status == TournamentStatus::Created @ ArenaError::TournamentAlreadyStarted
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Set the format a tournament is played in (admin, operator or host).
+///
+/// # Arguments
+/// * `format` - Ring game or bracket format
+pub fn handler(ctx: Context<SetTournamentFormat>, format: TournamentFormat) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+    tournament.format = format;
+
+    msg!("Tournament {} format set to {:?}", tournament.id, format);
+
+    AuditLog::record_call::<instruction::SetTournamentFormat>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.authority.key(),
+        ctx.accounts.tournament.id,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog};
+
+/// Accounts required for configuring tournament creation rate limits.
+#[derive(Accounts)]
+pub struct SetTournamentRateLimits<'info> {
+    /// Admin wallet - must match arena_config.admin
+    pub admin: Signer<'info>,
+
+    /// Arena config to update
+    #[account(
+        mut,
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Cap how many tournaments may be created per day and how many may be open
+/// at once, bounding the PDAs a compromised operator key can create (admin only).
+///
+/// # Arguments
+/// * `max_per_day` - Tournaments that may be created per day (0 = unlimited)
+/// * `max_open` - Tournaments that may be open at once (0 = unlimited)
+pub fn handler(
+    ctx: Context<SetTournamentRateLimits>,
+    max_per_day: u16,
+    max_open: u16,
+) -> Result<()> {
+    let arena_config = &mut ctx.accounts.arena_config;
+    arena_config.max_tournaments_per_day = max_per_day;
+    arena_config.max_open_tournaments = max_open;
+
+    msg!(
+        "Tournament rate limits set: {} per day, {} open",
+        max_per_day,
+        max_open
+    );
+
+    AuditLog::record_call::<instruction::SetTournamentRateLimits>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        0,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog, PlayerRegistration, TrophyAuthority};
+
+/// Accounts required for configuring the trophy authority.
+#[derive(Accounts)]
+pub struct SetTrophyMetadataUri<'info> {
+    /// Admin wallet - must match arena_config.admin
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// Arena config for admin verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Trophy authority PDA (created on first call)
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = TrophyAuthority::SIZE,
+        seeds = [TrophyAuthority::SEED_PREFIX],
+        bump
+    )]
+    pub trophy_authority: Account<'info, TrophyAuthority>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Set the base URI for trophy metadata (admin only).
+///
+/// The trophy authority PDA must also be made the tree delegate of the
+/// Bubblegum tree used for participation trophies.
+///
+/// # Arguments
+/// * `metadata_base_uri` - ipfs:// or https:// base, zero-padded to 128 bytes
+pub fn handler(ctx: Context<SetTrophyMetadataUri>, metadata_base_uri: [u8; 128]) -> Result<()> {
+    PlayerRegistration::validate_agent_image_uri(&metadata_base_uri)?;
+
+    let trophy_authority = &mut ctx.accounts.trophy_authority;
+    trophy_authority.metadata_base_uri = metadata_base_uri;
+    trophy_authority.bump = ctx.bumps.trophy_authority;
+
+    msg!("Trophy authority: {}", trophy_authority.key());
+
+    AuditLog::record_call::<instruction::SetTrophyMetadataUri>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        0,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog};
+
+/// Accounts required for configuring USD-priced tier fees.
+#[derive(Accounts)]
+pub struct SetUsdTierFees<'info> {
+    /// Admin wallet - must match arena_config.admin
+    pub admin: Signer<'info>,
+
+    /// Arena config to update
+    #[account(
+        mut,
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Price tier fees in USD cents, converted to lamports at registration time
+/// from a Pyth SOL/USD price update (admin only).
+///
+/// A zeroed `sol_usd_feed_id` reverts to the fixed lamport tier fees.
+///
+/// # Arguments
+/// * `tier_fee_usd_cents` - Fee per tier in USD cents, indexed FREE, BASIC, PRO
+/// * `sol_usd_feed_id` - Pyth SOL/USD price feed id
+pub fn handler(
+    ctx: Context<SetUsdTierFees>,
+    tier_fee_usd_cents: [u32; 3],
+    sol_usd_feed_id: [u8; 32],
+) -> Result<()> {
+    let arena_config = &mut ctx.accounts.arena_config;
+    arena_config.tier_fee_usd_cents = tier_fee_usd_cents;
+    arena_config.sol_usd_feed_id = sol_usd_feed_id;
+
+    msg!(
+        "USD tier fees set to {:?} cents (enabled: {})",
+        tier_fee_usd_cents,
+        arena_config.uses_usd_tier_fees()
+    );
+
+    AuditLog::record_call::<instruction::SetUsdTierFees>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        0,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog};
+
+/// Accounts required for setting the USDC mint.
+#[derive(Accounts)]
+pub struct SetUsdcMint<'info> {
+    /// Admin wallet - must match arena_config.admin
+    pub admin: Signer<'info>,
+
+    /// Arena config to update
+    #[account(
+        mut,
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// USDC SPL token mint for the cluster
+    pub usdc_mint: Account<'info, Mint>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Set the mint USDC-denominated prize pools are paid in (admin only).
+///
+/// Pools already created keep the mint they were created with.
+pub fn handler(ctx: Context<SetUsdcMint>) -> Result<()> {
+    let usdc_mint = ctx.accounts.usdc_mint.key();
+    ctx.accounts.arena_config.usdc_mint = usdc_mint;
+
+    msg!("USDC mint set to {}", usdc_mint);
+
+    AuditLog::record_call::<instruction::SetUsdcMint>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        0,
+    )?;
+
+    Ok(())
+}
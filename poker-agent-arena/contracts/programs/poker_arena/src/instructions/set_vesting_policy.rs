@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog};
+
+/// Accounts required for configuring points vesting.
+#[derive(Accounts)]
+pub struct SetVestingPolicy<'info> {
+    /// Admin wallet - must match arena_config.admin
+    pub admin: Signer<'info>,
+
+    /// Arena config to update
+    #[account(
+        mut,
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Set when large POINTS awards vest and on what schedule (admin only).
+///
+/// Applies to awards distributed after the change.
+///
+/// # Arguments
+/// * `threshold_points` - Awards at or above this vest (0 disables vesting)
+/// * `cliff_seconds` - Seconds before any vested POINTS unlock
+/// * `duration_seconds` - Seconds until vested POINTS are fully unlocked
+pub fn handler(
+    ctx: Context<SetVestingPolicy>,
+    threshold_points: u64,
+    cliff_seconds: i64,
+    duration_seconds: i64,
+) -> Result<()> {
+    require!(
+        threshold_points == 0
+            || (duration_seconds > 0 && (0..=duration_seconds).contains(&cliff_seconds)),
+        ArenaError::InvalidVestingSchedule
+    );
+
+    let arena_config = &mut ctx.accounts.arena_config;
+    arena_config.vesting_threshold_points = threshold_points;
+    arena_config.vesting_cliff_seconds = cliff_seconds;
+    arena_config.vesting_duration_seconds = duration_seconds;
+
+    msg!(
+        "Awards of {}+ POINTS vest over {}s with a {}s cliff",
+        threshold_points,
+        duration_seconds,
+        cliff_seconds
+    );
+
+    AuditLog::record_call::<instruction::SetVestingPolicy>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        0,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,149 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::events::HostSlashed;
+use crate::instruction;
+use crate::state::{
+    ArenaConfig, AuditLog, FeeEscrow, Host, LedgerCategory, LedgerDirection, Tournament,
+    TournamentStatus, TreasuryLedger,
+};
+
+/// Accounts required for slashing a host's bond.
+#[derive(Accounts)]
+pub struct SlashHostBond<'info> {
+    /// Admin wallet - must match arena_config.admin
+    pub admin: Signer<'info>,
+
+    /// Arena config for admin verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Hosted tournament the host failed - must have been disputed
+    #[account(
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.disputed @ ArenaError::HostNotSlashable
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Tournament's host
+    #[account(
+        mut,
+        seeds = [Host::SEED_PREFIX, host.authority.as_ref()],
+        bump = host.bump,
+        constraint = host.key() == tournament.host @ ArenaError::InvalidHost
+    )]
+    pub host: Account<'info, Host>,
+
+    /// Tournament's fee escrow receiving the slashed bond - the host must
+    /// not have been slashed over this tournament before
+    #[account(
+        mut,
+        seeds = [FeeEscrow::SEED_PREFIX, tournament.key().as_ref()],
+        bump = fee_escrow.bump,
+        constraint = !fee_escrow.host_slashed @ ArenaError::HostNotSlashable
+    )]
+    pub fee_escrow: Account<'info, FeeEscrow>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+
+    /// Treasury ledger recording the slash (omit to skip)
+    #[account(
+        mut,
+        seeds = [TreasuryLedger::SEED_PREFIX],
+        bump = treasury_ledger.bump
+    )]
+    pub treasury_ledger: Option<Box<Account<'info, TreasuryLedger>>>,
+}
+
+/// Slash a host's bond for a disputed tournament it failed (admin only).
+///
+/// This instruction:
+/// 1. Requires a player to have disputed the tournament, and the tournament
+///    to have been voided, or left unfinalized
+///    Host::FINALIZE_DEADLINE_SECONDS past its scheduled start
+/// 2. Moves up to `amount` of the bond into the tournament's fee escrow,
+///    where it funds `refund_escrowed_fee` once the tournament is voided
+/// 3. Marks the tournament slashed, so its host is slashed over it only once
+/// 4. Counts the slash against the host's reputation and emits HostSlashed
+///
+/// # Arguments
+/// * `amount` - Lamports to slash (capped at the posted bond)
+/// * `reason_hash` - SHA-256 hash of the off-chain dispute ruling
+pub fn handler(ctx: Context<SlashHostBond>, amount: u64, reason_hash: [u8; 32]) -> Result<()> {
+    let tournament = &ctx.accounts.tournament;
+    let now = Clock::get()?.unix_timestamp;
+    let unfinalized = matches!(
+        tournament.status,
+        TournamentStatus::InProgress | TournamentStatus::ResultsSubmitted
+    ) && now
+        >= tournament
+            .starts_at
+            .saturating_add(Host::FINALIZE_DEADLINE_SECONDS);
+    require!(
+        unfinalized || tournament.status == TournamentStatus::Voided,
+        ArenaError::HostNotSlashable
+    );
+
+    let host = &mut ctx.accounts.host;
+    let slashed = host.slash(amount);
+    **host.to_account_info().try_borrow_mut_lamports()? -= slashed;
+    let fee_escrow = &mut ctx.accounts.fee_escrow;
+    **fee_escrow.to_account_info().try_borrow_mut_lamports()? += slashed;
+    fee_escrow.host_slashed = true;
+    fee_escrow.slashed_lamports = fee_escrow
+        .slashed_lamports
+        .checked_add(slashed)
+        .ok_or(ArenaError::ArithmeticOverflow)?;
+
+    if let Some(treasury_ledger) = ctx.accounts.treasury_ledger.as_mut() {
+        treasury_ledger.record(
+            LedgerCategory::HostBond,
+            LedgerDirection::Outflow,
+            host.key(),
+            slashed,
+            now,
+        );
+        treasury_ledger.record(
+            LedgerCategory::FeeEscrow,
+            LedgerDirection::Inflow,
+            fee_escrow.key(),
+            slashed,
+            now,
+        );
+    }
+
+    emit!(HostSlashed {
+        host: host.key(),
+        tournament: tournament.key(),
+        amount: slashed,
+        reason_hash,
+        reputation_bps: host.reputation_bps,
+        timestamp: now,
+    });
+
+    msg!(
+        "Slashed {} lamports from host {} over tournament {}",
+        slashed,
+        host.authority,
+        tournament.id
+    );
+
+    AuditLog::record_call::<instruction::SlashHostBond>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        ctx.accounts.tournament.id,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::events::LeaderboardSnapshotted;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog, Snapshot};
+
+/// Accounts required for snapshotting the leaderboard.
+#[derive(Accounts)]
+pub struct SnapshotLeaderboard<'info> {
+    /// Admin wallet (must match arena config admin)
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// Arena config account
+    #[account(
+        mut,
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Snapshot PDA to be created
+    #[account(
+        init,
+        payer = admin,
+        space = Snapshot::SIZE,
+        seeds = [
+            Snapshot::SEED_PREFIX,
+            (arena_config.snapshot_count + 1).to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub snapshot: Account<'info, Snapshot>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Commit a Merkle root of (wallet, total_points) for every ranked player (admin only).
+///
+/// Partner projects can run proof-based airdrops against the snapshot
+/// without trusting the off-chain database.
+///
+/// # Arguments
+/// * `root` - Root over leaves SHA-256(0x00 || wallet || total_points_le), built
+///   from PlayerStats.total_points
+/// * `wallet_count` - Number of wallets included
+/// * `total_points` - Sum of total_points across included wallets
+pub fn handler(
+    ctx: Context<SnapshotLeaderboard>,
+    root: [u8; 32],
+    wallet_count: u32,
+    total_points: u64,
+) -> Result<()> {
+    let arena_config = &mut ctx.accounts.arena_config;
+    arena_config.snapshot_count = arena_config
+        .snapshot_count
+        .checked_add(1)
+        .ok_or(ArenaError::ArithmeticOverflow)?;
+
+    let clock = Clock::get()?;
+    let snapshot = &mut ctx.accounts.snapshot;
+    snapshot.id = arena_config.snapshot_count;
+    snapshot.root = root;
+    snapshot.wallet_count = wallet_count;
+    snapshot.total_points = total_points;
+    snapshot.slot = clock.slot;
+    snapshot.taken_at = clock.unix_timestamp;
+    snapshot.bump = ctx.bumps.snapshot;
+
+    emit!(LeaderboardSnapshotted {
+        snapshot: snapshot.key(),
+        id: snapshot.id,
+        root,
+        wallet_count,
+        slot: clock.slot,
+    });
+
+    msg!(
+        "Leaderboard snapshot {} taken ({} wallets)",
+        snapshot.id,
+        wallet_count
+    );
+
+    AuditLog::record_call::<instruction::SnapshotLeaderboard>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        0,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::errors::ArenaError;
+use crate::state::{Governance, VoteStake};
+
+/// Accounts required for staking POINTS for governance.
+#[derive(Accounts)]
+pub struct StakePoints<'info> {
+    /// Wallet staking POINTS
+    #[account(mut)]
+    pub staker: Signer<'info>,
+
+    /// Governance config PDA
+    #[account(
+        seeds = [Governance::SEED_PREFIX],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    /// Staker's vote stake (created on first stake)
+    #[account(
+        init_if_needed,
+        payer = staker,
+        space = VoteStake::SIZE,
+        seeds = [VoteStake::SEED_PREFIX, staker.key().as_ref()],
+        bump
+    )]
+    pub vote_stake: Account<'info, VoteStake>,
+
+    /// Governance stake vault
+    #[account(
+        mut,
+        seeds = [Governance::VAULT_SEED_PREFIX],
+        bump
+    )]
+    pub stake_vault: Account<'info, TokenAccount>,
+
+    /// Staker's POINTS token account
+    #[account(
+        mut,
+        constraint = staker_token_account.mint == stake_vault.mint @ ArenaError::InvalidTierPayment,
+        constraint = staker_token_account.owner == staker.key() @ ArenaError::Unauthorized
+    )]
+    pub staker_token_account: Account<'info, TokenAccount>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Stake POINTS to gain governance voting power.
+///
+/// Staking resets the stake's snapshot time, so the new total only counts
+/// toward proposals created afterwards.
+///
+/// # Arguments
+/// * `amount` - POINTS to stake (base units)
+pub fn handler(ctx: Context<StakePoints>, amount: u64) -> Result<()> {
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.staker_token_account.to_account_info(),
+                to: ctx.accounts.stake_vault.to_account_info(),
+                authority: ctx.accounts.staker.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    let vote_stake = &mut ctx.accounts.vote_stake;
+    vote_stake.wallet = ctx.accounts.staker.key();
+    vote_stake.amount = vote_stake.amount.saturating_add(amount);
+    vote_stake.staked_at = Clock::get()?.unix_timestamp;
+    vote_stake.bump = ctx.bumps.vote_stake;
+
+    msg!("Staked {} POINTS, total {}", amount, vote_stake.amount);
+
+    Ok(())
+}
@@ -1,27 +1,28 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::ArenaError;
-use crate::state::{ArenaConfig, Tournament, TournamentStatus};
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog, Tournament, TournamentStatus};
 
 /// Accounts required for starting a tournament.
 #[derive(Accounts)]
 pub struct StartTournament<'info> {
-    /// Admin wallet - must match arena_config.admin
-    pub admin: Signer<'info>,
+    /// Arena admin, the tournament's delegated operator or its host
+    pub authority: Signer<'info>,
 
     /// Arena config for admin verification
     #[account(
         seeds = [ArenaConfig::SEED_PREFIX],
-        bump = arena_config.bump,
-        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+        bump = arena_config.bump
     )]
     pub arena_config: Account<'info, ArenaConfig>,
 
     /// Tournament to start - must be in Registration status
     #[account(
         mut,
-        seeds = [Tournament::SEED_PREFIX, &tournament.id.to_le_bytes()],
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
         bump = tournament.bump,
+        constraint = tournament.can_run(&authority.key(), &arena_config.admin) @ ArenaError::Unauthorized,
         constraint = tournament.status == TournamentStatus::Registration @ ArenaError::RegistrationNotOpen,
         constraint = tournament.registered_players >= 2 @ ArenaError::TournamentNotStarted
     )]
@@ -31,24 +32,64 @@ pub struct StartTournament<'info> {
     /// CHECK: This is the SlotHashes sysvar, validated by address
     #[account(address = anchor_lang::solana_program::sysvar::slot_hashes::id())]
     pub recent_slothashes: UncheckedAccount<'info>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
 }
 
-/// Start a tournament (admin only).
+/// Start a tournament (admin, operator or host).
 ///
 /// This instruction:
 /// 1. Validates the tournament is in Registration status with >= 2 players
 /// 2. Captures the current slot and recent blockhash for provably fair RNG
-/// 3. Updates the tournament status to InProgress
-pub fn handler(ctx: Context<StartTournament>) -> Result<()> {
+/// 3. Commits the engine build and model snapshot that will produce results
+/// 4. Updates the tournament status to InProgress
+///
+/// # Arguments
+/// * `engine_version_hash` - SHA-256 hash of the game engine build
+/// * `model_id_hash` - SHA-256 hash of the LLM model identifier
+pub fn handler(
+    ctx: Context<StartTournament>,
+    engine_version_hash: [u8; 32],
+    model_id_hash: [u8; 32],
+) -> Result<()> {
     let tournament = &mut ctx.accounts.tournament;
 
+    begin_tournament(tournament, &ctx.accounts.recent_slothashes)?;
+    tournament.engine_version_hash = engine_version_hash;
+    tournament.model_id_hash = model_id_hash;
+
+    msg!("Tournament {} started", tournament.id);
+    msg!("Seed slot: {}", tournament.seed_slot);
+    msg!("Registered players: {}", tournament.registered_players);
+
+    AuditLog::record_call::<instruction::StartTournament>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.authority.key(),
+        ctx.accounts.tournament.id,
+    )?;
+
+    Ok(())
+}
+
+/// Capture the RNG seed commitment and move the tournament to InProgress.
+/// Shared by the admin start and the permissionless start crank.
+pub(crate) fn begin_tournament(
+    tournament: &mut Tournament,
+    recent_slothashes: &UncheckedAccount,
+) -> Result<()> {
     // Get current slot from Clock sysvar
     let clock = Clock::get()?;
     let current_slot = clock.slot;
 
     // Extract seed blockhash from recent slot hashes
     // The SlotHashes sysvar contains recent slot hashes we can use for randomness
-    let slot_hashes_data = ctx.accounts.recent_slothashes.try_borrow_data()?;
+    let slot_hashes_data = recent_slothashes.try_borrow_data()?;
 
     // SlotHashes structure: [count (8 bytes)][entries...]
     // Each entry: [slot (8 bytes)][hash (32 bytes)]
@@ -64,9 +105,5 @@ pub fn handler(ctx: Context<StartTournament>) -> Result<()> {
     tournament.seed_blockhash = seed_blockhash;
     tournament.status = TournamentStatus::InProgress;
 
-    msg!("Tournament {} started", tournament.id);
-    msg!("Seed slot: {}", current_slot);
-    msg!("Registered players: {}", tournament.registered_players);
-
     Ok(())
 }
@@ -0,0 +1,195 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::events::ResultsSubmitted;
+use crate::instruction;
+use crate::state::{
+    ArbiterAttestation, ArbiterSet, ArenaConfig, AuditLog, Bracket, PlayerRegistration, Shootout,
+    SwissEvent, Tournament, TournamentFormat, TournamentStatus,
+};
+
+/// Accounts required for submitting a tournament's provisional results.
+#[derive(Accounts)]
+#[instruction(results_hash: [u8; 32], winner: Pubkey)]
+pub struct SubmitResults<'info> {
+    /// Results authority, or the arena admin or tournament's operator if none is set
+    pub authority: Signer<'info>,
+
+    /// Arena config for admin verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament whose results are submitted - must be InProgress
+    #[account(
+        mut,
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = arena_config.can_record_results(&authority.key(), &tournament) @ ArenaError::Unauthorized,
+        constraint = tournament.status == TournamentStatus::InProgress @ ArenaError::TournamentNotInProgress
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Winner's registration for this tournament - must still be in good
+    /// standing, with no rank other than first recorded
+    #[account(
+        seeds = [PlayerRegistration::SEED_PREFIX, tournament.key().as_ref(), winner.as_ref()],
+        bump = winner_registration.bump,
+        constraint = !winner_registration.disqualified && !winner_registration.forfeited @ ArenaError::InvalidWinner,
+        constraint = winner_registration.final_rank.unwrap_or(1) == 1 @ ArenaError::InvalidWinner
+    )]
+    pub winner_registration: Box<Account<'info, PlayerRegistration>>,
+
+    /// Arbiter committee (required if the tournament is disputed)
+    #[account(
+        seeds = [ArbiterSet::SEED_PREFIX],
+        bump = arbiter_set.bump
+    )]
+    pub arbiter_set: Option<Account<'info, ArbiterSet>>,
+
+    /// Arbiter attestation for these results (required if the tournament is disputed)
+    #[account(
+        seeds = [ArbiterAttestation::SEED_PREFIX, tournament.key().as_ref(), results_hash.as_ref()],
+        bump = arbiter_attestation.bump
+    )]
+    pub arbiter_attestation: Option<Account<'info, ArbiterAttestation>>,
+
+    /// Tournament's bracket (required for bracket formats)
+    #[account(
+        seeds = [Bracket::SEED_PREFIX, tournament.key().as_ref()],
+        bump = bracket.bump
+    )]
+    pub bracket: Option<Box<Account<'info, Bracket>>>,
+
+    /// Tournament's Swiss schedule (required for the Swiss format)
+    #[account(
+        seeds = [SwissEvent::SEED_PREFIX, tournament.key().as_ref()],
+        bump = swiss_event.bump
+    )]
+    pub swiss_event: Option<Box<Account<'info, SwissEvent>>>,
+
+    /// Tournament's shootout schedule (required for the shootout format)
+    #[account(
+        seeds = [Shootout::SEED_PREFIX, tournament.key().as_ref()],
+        bump = shootout.bump
+    )]
+    pub shootout: Option<Box<Account<'info, Shootout>>>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Submit a tournament's provisional results (results authority, or admin or
+/// operator if unset).
+///
+/// This instruction:
+/// 1. Validates the tournament is in InProgress status, that the winner is
+///    a registered player in good standing, that disputed
+///    results carry threshold arbiter attestations, that a bracket
+///    tournament's winner is its bracket champion, that a Swiss
+///    tournament has played every round, and that a shootout's winner
+///    won its final table
+/// 2. Checks the results hash against the game log chain head, if one was committed,
+///    and stores it
+/// 3. Records the winner's wallet address
+/// 4. Updates status to ResultsSubmitted and starts the dispute window, if
+///    one is configured
+///
+/// The results take effect once `confirm_results` is called after the window.
+///
+/// # Arguments
+/// * `results_hash` - SHA-256 hash of the final standings JSON
+/// * `winner` - Winner's wallet address (1st place)
+pub fn handler(ctx: Context<SubmitResults>, results_hash: [u8; 32], winner: Pubkey) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+    let clock = Clock::get()?;
+
+    require!(
+        tournament.results_match_game_log(&results_hash),
+        ArenaError::InvalidResultsHash
+    );
+
+    if tournament.disputed {
+        let (Some(arbiter_set), Some(attestation)) = (
+            ctx.accounts.arbiter_set.as_ref(),
+            ctx.accounts.arbiter_attestation.as_ref(),
+        ) else {
+            return err!(ArenaError::ArbiterThresholdNotMet);
+        };
+        require!(
+            attestation.meets_threshold(arbiter_set) && attestation.winner == winner,
+            ArenaError::ArbiterThresholdNotMet
+        );
+        tournament.dispute_resolved = true;
+    }
+
+    if tournament.is_bracket() {
+        let bracket = ctx
+            .accounts
+            .bracket
+            .as_ref()
+            .ok_or(ArenaError::InvalidTournamentFormat)?;
+        require!(
+            bracket.champion == Some(winner),
+            ArenaError::InvalidBracketMatch
+        );
+    }
+    if tournament.format == TournamentFormat::Swiss {
+        let swiss_event = ctx
+            .accounts
+            .swiss_event
+            .as_ref()
+            .ok_or(ArenaError::InvalidTournamentFormat)?;
+        require!(swiss_event.is_complete(), ArenaError::InvalidSwissRound);
+    }
+    if tournament.format == TournamentFormat::Shootout {
+        let shootout = ctx
+            .accounts
+            .shootout
+            .as_ref()
+            .ok_or(ArenaError::InvalidTournamentFormat)?;
+        require!(
+            shootout.champion == Some(winner),
+            ArenaError::InvalidShootoutTable
+        );
+    }
+
+    // Start the dispute clock; results are confirmed once it runs out
+    tournament.dispute_window_ends_at =
+        clock.unix_timestamp + ctx.accounts.arena_config.dispute_window_seconds;
+
+    // Record the provisional results
+    tournament.results_hash = Some(results_hash);
+    tournament.winner = Some(winner);
+    tournament.status = TournamentStatus::ResultsSubmitted;
+
+    emit!(ResultsSubmitted {
+        tournament: tournament.key(),
+        winner,
+        results_hash,
+        dispute_window_ends_at: tournament.dispute_window_ends_at,
+        submitted_by: ctx.accounts.authority.key(),
+    });
+
+    msg!("Tournament {} results submitted", tournament.id);
+    msg!("Winner: {}", winner);
+    msg!(
+        "Dispute window ends at: {}",
+        tournament.dispute_window_ends_at
+    );
+
+    AuditLog::record_call::<instruction::SubmitResults>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.authority.key(),
+        ctx.accounts.tournament.id,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,211 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount, Transfer};
+
+use crate::errors::ArenaError;
+use crate::state::{
+    ArenaConfig, DustPolicy, LedgerCategory, LedgerDirection, PointsMintAuthority,
+    PrizeDenomination, PrizePool, Tournament, TournamentStatus, TreasuryLedger,
+};
+
+/// Accounts required for sweeping a prize pool's rounding remainder.
+///
+/// The token accounts are required for USDC and POINTS pools; the points
+/// mint and mint authority only for POINTS pools.
+#[derive(Accounts)]
+pub struct SweepPrizeDust<'info> {
+    /// Anyone may crank the sweep; the dust goes to the treasury
+    pub caller: Signer<'info>,
+
+    /// Arena config for the treasury address
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament - results must be confirmed
+    #[account(
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.status == TournamentStatus::Completed @ ArenaError::TournamentNotCompleted
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Tournament's prize pool - must send its dust to the treasury
+    #[account(
+        mut,
+        seeds = [PrizePool::SEED_PREFIX, tournament.key().as_ref()],
+        bump = prize_pool.bump,
+        constraint = prize_pool.dust_policy == DustPolicy::Treasury @ ArenaError::CrankNotReady,
+        constraint = !prize_pool.dust_swept @ ArenaError::CrankNotReady
+    )]
+    pub prize_pool: Account<'info, PrizePool>,
+
+    /// Treasury wallet receiving SOL dust
+    /// CHECK: Verified against arena_config.treasury
+    #[account(
+        mut,
+        address = arena_config.treasury @ ArenaError::InvalidTierPayment
+    )]
+    pub treasury: UncheckedAccount<'info>,
+
+    /// Pool's USDC token account
+    #[account(
+        mut,
+        token::authority = prize_pool
+    )]
+    pub pool_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Treasury token account for the pool's mint
+    #[account(
+        mut,
+        token::authority = treasury
+    )]
+    pub treasury_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// POINTS SPL token mint
+    #[account(mut)]
+    pub points_mint: Option<Account<'info, Mint>>,
+
+    /// PDA that holds mint authority
+    #[account(
+        seeds = [PointsMintAuthority::SEED_PREFIX],
+        bump = mint_authority.bump
+    )]
+    pub mint_authority: Option<Account<'info, PointsMintAuthority>>,
+
+    /// SPL Token program
+    pub token_program: Option<Program<'info, Token>>,
+
+    /// Treasury ledger recording SOL dust (omit to skip)
+    #[account(
+        mut,
+        seeds = [TreasuryLedger::SEED_PREFIX],
+        bump = treasury_ledger.bump
+    )]
+    pub treasury_ledger: Option<Box<Account<'info, TreasuryLedger>>>,
+}
+
+/// Pay a prize pool's rounding remainder to the treasury (permissionless).
+///
+/// This instruction:
+/// 1. Requires confirmed results and a pool whose dust policy is Treasury
+/// 2. Pays the remainder left by rounding every place's share down in the
+///    pool's denomination
+/// 3. Marks the dust swept, so the shares and the dust together pay out
+///    exactly the pool total
+pub fn handler(ctx: Context<SweepPrizeDust>) -> Result<()> {
+    let prize_pool = &ctx.accounts.prize_pool;
+    let amount = prize_pool.dust();
+    let paid_out = prize_pool
+        .paid_out
+        .checked_add(amount)
+        .filter(|paid_out| *paid_out <= prize_pool.total)
+        .ok_or(ArenaError::InvalidPrizePool)?;
+
+    if amount > 0 {
+        match prize_pool.denomination {
+            PrizeDenomination::Sol => {
+                let source = prize_pool.to_account_info();
+                let rent_floor = Rent::get()?.minimum_balance(source.data_len());
+                require!(
+                    source.lamports().saturating_sub(rent_floor) >= amount,
+                    ArenaError::InvalidPrizePool
+                );
+                **source.try_borrow_mut_lamports()? -= amount;
+                **ctx.accounts.treasury.try_borrow_mut_lamports()? += amount;
+                if let Some(treasury_ledger) = ctx.accounts.treasury_ledger.as_mut() {
+                    treasury_ledger.record(
+                        LedgerCategory::PrizePool,
+                        LedgerDirection::Outflow,
+                        source.key(),
+                        amount,
+                        Clock::get()?.unix_timestamp,
+                    );
+                }
+            }
+            PrizeDenomination::Usdc => {
+                let (Some(source), Some(destination), Some(token_program)) = (
+                    ctx.accounts.pool_token_account.as_ref(),
+                    ctx.accounts.treasury_token_account.as_ref(),
+                    ctx.accounts.token_program.as_ref(),
+                ) else {
+                    return err!(ArenaError::InvalidPrizePool);
+                };
+                require!(
+                    source.mint == prize_pool.mint && destination.mint == prize_pool.mint,
+                    ArenaError::InvalidPrizePool
+                );
+
+                let tournament_key = ctx.accounts.tournament.key();
+                let seeds = &[
+                    PrizePool::SEED_PREFIX,
+                    tournament_key.as_ref(),
+                    &[prize_pool.bump],
+                ];
+                let signer_seeds = &[&seeds[..]];
+
+                token::transfer(
+                    CpiContext::new_with_signer(
+                        token_program.to_account_info(),
+                        Transfer {
+                            from: source.to_account_info(),
+                            to: destination.to_account_info(),
+                            authority: prize_pool.to_account_info(),
+                        },
+                        signer_seeds,
+                    ),
+                    amount,
+                )?;
+            }
+            PrizeDenomination::Points => {
+                let (
+                    Some(points_mint),
+                    Some(mint_authority),
+                    Some(destination),
+                    Some(token_program),
+                ) = (
+                    ctx.accounts.points_mint.as_ref(),
+                    ctx.accounts.mint_authority.as_ref(),
+                    ctx.accounts.treasury_token_account.as_ref(),
+                    ctx.accounts.token_program.as_ref(),
+                )
+                else {
+                    return err!(ArenaError::InvalidPrizePool);
+                };
+                require!(
+                    points_mint.key() == prize_pool.mint && destination.mint == prize_pool.mint,
+                    ArenaError::InvalidPrizePool
+                );
+
+                let seeds = &[PointsMintAuthority::SEED_PREFIX, &[mint_authority.bump]];
+                let signer_seeds = &[&seeds[..]];
+
+                token::mint_to(
+                    CpiContext::new_with_signer(
+                        token_program.to_account_info(),
+                        MintTo {
+                            mint: points_mint.to_account_info(),
+                            to: destination.to_account_info(),
+                            authority: mint_authority.to_account_info(),
+                        },
+                        signer_seeds,
+                    ),
+                    amount,
+                )?;
+            }
+        }
+    }
+
+    let prize_pool = &mut ctx.accounts.prize_pool;
+    prize_pool.paid_out = paid_out;
+    prize_pool.dust_swept = true;
+
+    msg!(
+        "Swept {} of prize dust for tournament {} to the treasury",
+        amount,
+        ctx.accounts.tournament.id
+    );
+
+    Ok(())
+}
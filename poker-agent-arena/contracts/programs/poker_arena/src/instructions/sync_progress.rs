@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog, Tournament, TournamentProgress, TournamentStatus};
+
+/// Accounts required for syncing live tournament progress.
+#[derive(Accounts)]
+pub struct SyncProgress<'info> {
+    /// Arena admin, the tournament's delegated operator or its host
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Arena config for admin verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament - must be InProgress
+    #[account(
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.can_run(&authority.key(), &arena_config.admin) @ ArenaError::Unauthorized,
+        constraint = tournament.status == TournamentStatus::InProgress @ ArenaError::TournamentNotInProgress
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Progress account (created on the first sync)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = TournamentProgress::SIZE,
+        seeds = [TournamentProgress::SEED_PREFIX, tournament.key().as_ref()],
+        bump
+    )]
+    pub progress: Account<'info, TournamentProgress>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Sync live tournament progress (admin, operator or host).
+///
+/// Called every few minutes during play. Hand number and pot totals may
+/// never move backwards, and players remaining may never grow.
+///
+/// # Arguments
+/// * `hand_number` - Most recent hand number dealt
+/// * `players_remaining` - Players still holding chips
+/// * `total_pot_dealt` - Cumulative chips pushed into pots
+pub fn handler(
+    ctx: Context<SyncProgress>,
+    hand_number: u64,
+    players_remaining: u16,
+    total_pot_dealt: u64,
+) -> Result<()> {
+    let tournament = &ctx.accounts.tournament;
+    let progress = &mut ctx.accounts.progress;
+
+    if progress.tournament == Pubkey::default() {
+        progress.tournament = tournament.key();
+        progress.players_remaining = tournament.registered_players;
+        progress.bump = ctx.bumps.progress;
+    }
+
+    require!(
+        hand_number >= progress.hand_number
+            && total_pot_dealt >= progress.total_pot_dealt
+            && players_remaining <= progress.players_remaining,
+        ArenaError::CommitmentOutOfOrder
+    );
+
+    progress.hand_number = hand_number;
+    progress.players_remaining = players_remaining;
+    progress.total_pot_dealt = total_pot_dealt;
+    progress.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "Tournament {} progress: hand {}, {} players remaining",
+        tournament.id,
+        hand_number,
+        players_remaining
+    );
+
+    AuditLog::record_call::<instruction::SyncProgress>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.authority.key(),
+        ctx.accounts.tournament.id,
+    )?;
+
+    Ok(())
+}
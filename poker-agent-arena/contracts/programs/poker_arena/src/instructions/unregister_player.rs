@@ -0,0 +1,211 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::errors::ArenaError;
+use crate::events::PlayerUnregistered;
+use crate::instructions::register_player::collect_tier_fee_from_vault;
+use crate::state::{
+    ArenaConfig, FeeEscrow, Host, LedgerCategory, LedgerDirection, PlayerRegistration, Tournament,
+    TournamentStatus, TreasuryLedger,
+};
+
+/// Accounts required for unregistering from a tournament.
+#[derive(Accounts)]
+pub struct UnregisterPlayer<'info> {
+    /// Registered wallet withdrawing
+    pub player: Signer<'info>,
+
+    /// Arena config for the refund schedule and treasury address
+    #[account(
+        mut,
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament - must not have started
+    #[account(
+        mut,
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.status == TournamentStatus::Registration @ ArenaError::RegistrationNotOpen
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Player's registration
+    #[account(
+        mut,
+        seeds = [PlayerRegistration::SEED_PREFIX, tournament.key().as_ref(), player.key().as_ref()],
+        bump = registration.bump,
+        constraint = !registration.unregistered @ ArenaError::AlreadyForfeited,
+        constraint = !registration.disqualified @ ArenaError::PlayerDisqualified
+    )]
+    pub registration: Account<'info, PlayerRegistration>,
+
+    /// Account that paid the tier fee, receiving the refund: the player, a
+    /// gifting sponsor or the sponsoring guild vault
+    /// CHECK: Verified against registration.fee_payer
+    #[account(
+        mut,
+        address = registration.fee_payer @ ArenaError::Unauthorized
+    )]
+    pub fee_payer: UncheckedAccount<'info>,
+
+    /// Treasury wallet; must sign to refund fees it holds, and receives the
+    /// retained portion of escrowed fees
+    /// CHECK: Verified against arena_config.treasury
+    #[account(
+        mut,
+        address = arena_config.treasury @ ArenaError::InvalidTierPayment
+    )]
+    pub treasury: UncheckedAccount<'info>,
+
+    /// System program for refunds from the treasury
+    pub system_program: Program<'info, System>,
+
+    /// Tournament's fee escrow, required while its tier fees are escrowed
+    #[account(
+        mut,
+        seeds = [FeeEscrow::SEED_PREFIX, tournament.key().as_ref()],
+        bump = fee_escrow.bump
+    )]
+    pub fee_escrow: Option<Account<'info, FeeEscrow>>,
+
+    /// Tournament's host, required if the tournament is hosted
+    #[account(
+        seeds = [Host::SEED_PREFIX, host.authority.as_ref()],
+        bump = host.bump,
+        constraint = host.key() == tournament.host @ ArenaError::Unauthorized
+    )]
+    pub host: Option<Account<'info, Host>>,
+
+    /// Host's treasury receiving the host's share of the retained portion
+    /// CHECK: Verified against host.treasury
+    #[account(mut)]
+    pub host_treasury: Option<UncheckedAccount<'info>>,
+
+    /// Treasury ledger recording the fee escrow payout (omit to skip)
+    #[account(
+        mut,
+        seeds = [TreasuryLedger::SEED_PREFIX],
+        bump = treasury_ledger.bump
+    )]
+    pub treasury_ledger: Option<Box<Account<'info, TreasuryLedger>>>,
+}
+
+/// Withdraw from a tournament before it starts, freeing the seat.
+///
+/// Configured fee recipients are passed as remaining accounts, in slot
+/// order, when escrowed fees are partly retained.
+///
+/// This instruction:
+/// 1. Splits the unrefunded tier fee by the refund schedule: in full while
+///    registration is open, `late_unregister_refund_bps` of it after
+/// 2. Pays the refund back to whoever paid the fee, from the fee escrow
+///    (splitting the retained portion like `release_fee_escrow`: between the
+///    fee recipients and the treasury, or for a hosted tournament between
+///    the arena's platform fee and the host's treasury), or from the
+///    treasury (which must sign) when fees are not escrowed, in which case
+///    the retained portion simply stays there
+/// 3. Records the refund and retained portion on the registration and
+///    releases the seat; the registration can then be collected with
+///    `gc_registration`, after which the wallet may register again
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, UnregisterPlayer<'info>>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let registration = &mut ctx.accounts.registration;
+    let tournament = &mut ctx.accounts.tournament;
+
+    let unrefunded = registration.unsettled_fee_lamports();
+    let (refund_lamports, retained_lamports) = ctx
+        .accounts
+        .arena_config
+        .unregister_refund(unrefunded, tournament.is_registration_closed(now));
+
+    if tournament.fees_escrowed {
+        let fee_escrow = ctx
+            .accounts
+            .fee_escrow
+            .as_ref()
+            .ok_or(ArenaError::FeeEscrowRequired)?
+            .to_account_info();
+        **fee_escrow.try_borrow_mut_lamports()? -= refund_lamports;
+        **ctx.accounts.fee_payer.try_borrow_mut_lamports()? += refund_lamports;
+
+        let arena_share = if tournament.is_hosted() {
+            let (Some(host), Some(host_treasury)) = (
+                ctx.accounts.host.as_ref(),
+                ctx.accounts.host_treasury.as_ref(),
+            ) else {
+                return err!(ArenaError::Unauthorized);
+            };
+            require_keys_eq!(
+                host_treasury.key(),
+                host.treasury,
+                ArenaError::InvalidTierPayment
+            );
+            let platform_fee = host.platform_fee(retained_lamports);
+            **fee_escrow.try_borrow_mut_lamports()? -= retained_lamports - platform_fee;
+            **host_treasury.try_borrow_mut_lamports()? += retained_lamports - platform_fee;
+            platform_fee
+        } else {
+            retained_lamports
+        };
+        collect_tier_fee_from_vault(
+            &fee_escrow,
+            &ctx.accounts.treasury,
+            None,
+            &ctx.accounts.arena_config,
+            ctx.remaining_accounts,
+            arena_share,
+        )?;
+        if let Some(treasury_ledger) = ctx.accounts.treasury_ledger.as_mut() {
+            treasury_ledger.record(
+                LedgerCategory::FeeEscrow,
+                LedgerDirection::Outflow,
+                fee_escrow.key(),
+                refund_lamports + retained_lamports,
+                now,
+            );
+        }
+    } else if refund_lamports > 0 {
+        require!(ctx.accounts.treasury.is_signer, ArenaError::Unauthorized);
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.treasury.to_account_info(),
+                    to: ctx.accounts.fee_payer.to_account_info(),
+                },
+            ),
+            refund_lamports,
+        )?;
+    }
+
+    registration.refunded_lamports = registration
+        .refunded_lamports
+        .checked_add(refund_lamports)
+        .ok_or(ArenaError::ArithmeticOverflow)?;
+    registration.retained_lamports = retained_lamports;
+    registration.unregistered = true;
+    tournament.record_unregistration(registration.tier);
+    tournament.record_refund(refund_lamports);
+    ctx.accounts.arena_config.record_refund(refund_lamports);
+
+    emit!(PlayerUnregistered {
+        tournament: tournament.key(),
+        wallet: registration.wallet,
+        refund_lamports,
+        retained_lamports,
+        timestamp: now,
+    });
+
+    msg!(
+        "Player {} unregistered from tournament {}: refunded {} lamports, retained {}",
+        registration.wallet,
+        tournament.id,
+        refund_lamports,
+        retained_lamports
+    );
+
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::errors::ArenaError;
+use crate::state::{Governance, VoteStake};
+
+/// Accounts required for withdrawing staked POINTS.
+#[derive(Accounts)]
+pub struct UnstakePoints<'info> {
+    /// Wallet that owns the stake
+    pub staker: Signer<'info>,
+
+    /// Governance config PDA (stake vault authority)
+    #[account(
+        seeds = [Governance::SEED_PREFIX],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    /// Staker's vote stake
+    #[account(
+        mut,
+        seeds = [VoteStake::SEED_PREFIX, staker.key().as_ref()],
+        bump = vote_stake.bump
+    )]
+    pub vote_stake: Account<'info, VoteStake>,
+
+    /// Governance stake vault
+    #[account(
+        mut,
+        seeds = [Governance::VAULT_SEED_PREFIX],
+        bump
+    )]
+    pub stake_vault: Account<'info, TokenAccount>,
+
+    /// Staker's POINTS token account
+    #[account(
+        mut,
+        constraint = staker_token_account.mint == stake_vault.mint @ ArenaError::InvalidTierPayment,
+        constraint = staker_token_account.owner == staker.key() @ ArenaError::Unauthorized
+    )]
+    pub staker_token_account: Account<'info, TokenAccount>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+/// Withdraw staked POINTS once no vote holds them locked.
+///
+/// # Arguments
+/// * `amount` - POINTS to withdraw (base units)
+pub fn handler(ctx: Context<UnstakePoints>, amount: u64) -> Result<()> {
+    let vote_stake = &mut ctx.accounts.vote_stake;
+
+    require!(
+        Clock::get()?.unix_timestamp >= vote_stake.locked_until,
+        ArenaError::StakeLocked
+    );
+    require!(amount <= vote_stake.amount, ArenaError::InsufficientBalance);
+
+    vote_stake.amount -= amount;
+
+    let seeds = &[Governance::SEED_PREFIX, &[ctx.accounts.governance.bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.stake_vault.to_account_info(),
+                to: ctx.accounts.staker_token_account.to_account_info(),
+                authority: ctx.accounts.governance.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )?;
+
+    msg!(
+        "Unstaked {} POINTS, {} remaining",
+        amount,
+        vote_stake.amount
+    );
+
+    Ok(())
+}
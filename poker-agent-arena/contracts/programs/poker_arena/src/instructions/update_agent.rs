@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::state::{PlayerRegistration, SessionAuthority, Tournament, TournamentStatus};
+
+/// Accounts required for updating an agent's display metadata.
+#[derive(Accounts)]
+pub struct UpdateAgent<'info> {
+    /// Registered wallet, or a session key acting for it
+    pub authority: Signer<'info>,
+
+    /// Tournament - agents lock once it starts
+    #[account(
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.status == TournamentStatus::Created
+            || tournament.status == TournamentStatus::Registration @ ArenaError::AgentLocked
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Registration whose agent is updated
+    #[account(
+        mut,
+        seeds = [PlayerRegistration::SEED_PREFIX, tournament.key().as_ref(), registration.wallet.as_ref()],
+        bump = registration.bump
+    )]
+    pub registration: Account<'info, PlayerRegistration>,
+
+    /// Session authorizing `authority` (omit when the wallet signs)
+    #[account(
+        seeds = [SessionAuthority::SEED_PREFIX, registration.wallet.as_ref(), authority.key().as_ref()],
+        bump = session_authority.bump
+    )]
+    pub session_authority: Option<Account<'info, SessionAuthority>>,
+}
+
+/// Update an agent's name and avatar before the tournament starts.
+///
+/// # Arguments
+/// * `agent_name` - Display name for the agent (32 bytes, UTF-8, null-padded)
+/// * `agent_image_uri` - URI for agent avatar image (128 bytes, ipfs:// or https://, or all zeros for none)
+pub fn handler(
+    ctx: Context<UpdateAgent>,
+    agent_name: [u8; 32],
+    agent_image_uri: [u8; 128],
+) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    let registration = &mut ctx.accounts.registration;
+
+    if authority != registration.wallet {
+        let session_authority = ctx
+            .accounts
+            .session_authority
+            .as_ref()
+            .ok_or(ArenaError::Unauthorized)?;
+        require!(
+            session_authority.allows(
+                &authority,
+                SessionAuthority::SCOPE_UPDATE_AGENT,
+                Clock::get()?.unix_timestamp
+            ),
+            ArenaError::InvalidSession
+        );
+    }
+
+    PlayerRegistration::validate_agent_name(&agent_name)?;
+    PlayerRegistration::validate_agent_image_uri(&agent_image_uri)?;
+
+    registration.agent_name = agent_name;
+    registration.agent_image_uri = agent_image_uri;
+
+    msg!("Agent updated for player: {}", registration.wallet);
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::state::Guild;
+
+/// Accounts required for updating a guild's officers and policy.
+#[derive(Accounts)]
+pub struct UpdateGuild<'info> {
+    /// Guild leader
+    pub leader: Signer<'info>,
+
+    /// Guild to update
+    #[account(
+        mut,
+        seeds = [Guild::SEED_PREFIX, guild.name.as_ref()],
+        bump = guild.bump,
+        constraint = guild.leader == leader.key() @ ArenaError::Unauthorized
+    )]
+    pub guild: Account<'info, Guild>,
+}
+
+/// Replace a guild's officers and vault policy (leader only).
+///
+/// # Arguments
+/// * `officers` - Officer wallets (default pubkey = empty slot)
+/// * `winnings_share_bps` - Share of members' POINTS routed to the vault
+/// * `sponsor_tier_fees` - Whether the vault pays members' tier fees
+pub fn handler(
+    ctx: Context<UpdateGuild>,
+    officers: [Pubkey; Guild::MAX_OFFICERS],
+    winnings_share_bps: u16,
+    sponsor_tier_fees: bool,
+) -> Result<()> {
+    let guild = &mut ctx.accounts.guild;
+    Guild::validate(&guild.name, winnings_share_bps)?;
+
+    guild.officers = officers;
+    guild.winnings_share_bps = winnings_share_bps;
+    guild.sponsor_tier_fees = sponsor_tier_fees;
+
+    msg!(
+        "Guild {} policy: {} bps of winnings, tier fees sponsored: {}",
+        guild.key(),
+        winnings_share_bps,
+        sponsor_tier_fees
+    );
+
+    Ok(())
+}
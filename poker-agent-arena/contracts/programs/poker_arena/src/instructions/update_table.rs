@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::events::TableUpdated;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog, Table, Tournament, TournamentStatus};
+
+/// Accounts required for updating a table's seating.
+#[derive(Accounts)]
+pub struct UpdateTable<'info> {
+    /// Arena admin, the tournament's delegated operator or its host
+    pub authority: Signer<'info>,
+
+    /// Arena config for admin verification
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament - must be InProgress
+    #[account(
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.can_run(&authority.key(), &arena_config.admin) @ ArenaError::Unauthorized,
+        constraint = tournament.status == TournamentStatus::InProgress @ ArenaError::TournamentNotInProgress
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Table to update
+    #[account(
+        mut,
+        seeds = [Table::SEED_PREFIX, tournament.key().as_ref(), &table.table_number.to_le_bytes()],
+        bump = table.bump,
+        constraint = table.tournament == tournament.key() @ ArenaError::TournamentNotFound
+    )]
+    pub table: Account<'info, Table>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Update a table's seating after a break or balancing move (admin, operator or host).
+///
+/// # Arguments
+/// * `seats` - New player wallet in each seat (all empty when the table breaks)
+/// * `hand_count` - Hands dealt at this table so far (never decreases)
+pub fn handler(
+    ctx: Context<UpdateTable>,
+    seats: [Pubkey; Table::MAX_SEATS],
+    hand_count: u64,
+) -> Result<()> {
+    Table::validate_seats(&seats)?;
+
+    let table = &mut ctx.accounts.table;
+    require!(
+        hand_count >= table.hand_count,
+        ArenaError::CommitmentOutOfOrder
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    table.seats = seats;
+    table.hand_count = hand_count;
+    table.updated_at = now;
+
+    emit!(TableUpdated {
+        tournament: table.tournament,
+        table_number: table.table_number,
+        seats,
+        hand_count,
+        timestamp: now,
+    });
+
+    msg!(
+        "Table {} updated: {} players, {} hands",
+        table.table_number,
+        table.player_count(),
+        hand_count
+    );
+
+    AuditLog::record_call::<instruction::UpdateTable>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.authority.key(),
+        ctx.accounts.tournament.id,
+    )?;
+
+    Ok(())
+}
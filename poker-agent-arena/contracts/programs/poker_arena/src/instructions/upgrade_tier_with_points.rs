@@ -0,0 +1,133 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount};
+
+use crate::errors::ArenaError;
+use crate::events::TierUpgraded;
+use crate::instructions::register_player::approved_model_hash;
+use crate::state::{AgentTier, ApprovedModel, ArenaConfig, PlayerRegistration, Tournament};
+
+/// Accounts required for upgrading a registration's tier with POINTS.
+#[derive(Accounts)]
+pub struct UpgradeTierWithPoints<'info> {
+    /// Registered wallet burning POINTS for the upgrade
+    pub player: Signer<'info>,
+
+    /// Arena config for the points mint and upgrade prices
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament - must still be open for registration
+    #[account(
+        mut,
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.is_registration_open() @ ArenaError::RegistrationNotOpen
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Player's registration to upgrade
+    #[account(
+        mut,
+        seeds = [PlayerRegistration::SEED_PREFIX, tournament.key().as_ref(), player.key().as_ref()],
+        bump = registration.bump
+    )]
+    pub registration: Account<'info, PlayerRegistration>,
+
+    /// POINTS SPL token mint
+    #[account(
+        mut,
+        constraint = points_mint.key() == arena_config.points_mint @ ArenaError::InvalidTierPayment
+    )]
+    pub points_mint: Account<'info, Mint>,
+
+    /// Player's POINTS token account to burn from
+    #[account(
+        mut,
+        constraint = player_token_account.mint == points_mint.key() @ ArenaError::InvalidTierPayment,
+        constraint = player_token_account.owner == player.key() @ ArenaError::Unauthorized
+    )]
+    pub player_token_account: Account<'info, TokenAccount>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+
+    /// Approved model backing the agent (required when upgrading to PRO)
+    #[account(
+        seeds = [ApprovedModel::SEED_PREFIX, approved_model.model_id_hash.as_ref()],
+        bump = approved_model.bump
+    )]
+    pub approved_model: Option<Account<'info, ApprovedModel>>,
+}
+
+/// Upgrade a registration's agent tier by burning POINTS instead of paying
+/// the SOL fee difference.
+///
+/// This instruction:
+/// 1. Prices the upgrade from the configured per-step POINTS costs
+/// 2. Burns the POINTS from the player's token account
+/// 3. Moves the registration to the new tier, recording the new prompt hash
+///    and, for PRO, the approved model
+///
+/// The tier fee already paid is unchanged, so refunds never exceed it.
+///
+/// # Arguments
+/// * `tier` - Tier to upgrade to (must be higher than the current tier)
+/// * `agent_prompt_hash` - SHA-256 hash of the custom prompt for the new tier
+pub fn handler(
+    ctx: Context<UpgradeTierWithPoints>,
+    tier: AgentTier,
+    agent_prompt_hash: [u8; 32],
+) -> Result<()> {
+    let from_tier = ctx.accounts.registration.tier;
+    let points = ctx
+        .accounts
+        .arena_config
+        .tier_upgrade_cost(from_tier, tier)
+        .ok_or(ArenaError::TierUpgradeNotAvailable)?;
+    let model_id_hash = approved_model_hash(tier, ctx.accounts.approved_model.as_ref())?;
+
+    token::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.points_mint.to_account_info(),
+                from: ctx.accounts.player_token_account.to_account_info(),
+                authority: ctx.accounts.player.to_account_info(),
+            },
+        ),
+        points,
+    )?;
+
+    let tournament = &mut ctx.accounts.tournament;
+    tournament.tier_counts[from_tier as usize] = tournament.tier_counts[from_tier as usize]
+        .checked_sub(1)
+        .ok_or(ArenaError::ArithmeticOverflow)?;
+    tournament.tier_counts[tier as usize] = tournament.tier_counts[tier as usize]
+        .checked_add(1)
+        .ok_or(ArenaError::ArithmeticOverflow)?;
+
+    let registration = &mut ctx.accounts.registration;
+    registration.tier = tier;
+    registration.agent_prompt_hash = agent_prompt_hash;
+    registration.model_id_hash = model_id_hash;
+
+    emit!(TierUpgraded {
+        tournament: tournament.key(),
+        wallet: registration.wallet,
+        from_tier,
+        to_tier: tier,
+        points_burned: points,
+    });
+
+    msg!(
+        "Registration {} upgraded to {:?} for {} POINTS",
+        registration.key(),
+        tier,
+        points
+    );
+
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::seating;
+use crate::state::{PlayerRegistration, Table, Tournament};
+
+/// Accounts required for verifying a table's initial seat draw.
+/// Every active registration of the tournament is passed as remaining accounts.
+#[derive(Accounts)]
+pub struct VerifySeatDraw<'info> {
+    /// Anyone may verify a seat draw
+    pub verifier: Signer<'info>,
+
+    /// Tournament whose seed_blockhash drives the draw
+    #[account(
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Table whose seating is checked
+    #[account(
+        mut,
+        seeds = [Table::SEED_PREFIX, tournament.key().as_ref(), &table.table_number.to_le_bytes()],
+        bump = table.bump,
+        constraint = table.tournament == tournament.key() @ ArenaError::TournamentNotFound
+    )]
+    pub table: Account<'info, Table>,
+}
+
+/// Verify a table's initial seating against the seeded draw (permissionless).
+///
+/// This instruction:
+/// 1. Rebuilds the roster from the registrations in remaining accounts
+/// 2. Requires the roster to be complete (matches registered_players)
+/// 3. Derives the table's seats from seed_blockhash and the sorted roster
+/// 4. Fails if they differ, otherwise marks the table's draw as verified
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, VerifySeatDraw<'info>>) -> Result<()> {
+    let tournament = &ctx.accounts.tournament;
+    let tournament_key = tournament.key();
+
+    let mut roster = Vec::with_capacity(ctx.remaining_accounts.len());
+    for info in ctx.remaining_accounts {
+        let registration = Account::<PlayerRegistration>::try_from(info)?;
+        require!(
+            registration.tournament == tournament_key,
+            ArenaError::TournamentNotFound
+        );
+        if !registration.disqualified {
+            roster.push(registration.wallet);
+        }
+    }
+
+    roster.sort();
+    roster.dedup();
+    require!(
+        roster.len() == tournament.registered_players as usize,
+        ArenaError::InvalidSeating
+    );
+
+    let table = &mut ctx.accounts.table;
+    let expected =
+        seating::initial_table_seats(&tournament.seed_blockhash, &roster, table.table_number);
+    require!(table.seats == expected, ArenaError::InvalidSeating);
+
+    table.draw_verified = true;
+
+    msg!("Table {} seat draw verified", table.table_number);
+
+    Ok(())
+}
@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::events::TournamentVoided;
+use crate::instruction;
+use crate::state::{ArenaConfig, AuditLog, Host, Tournament, TournamentStatus};
+
+/// Accounts required for voiding a tournament.
+#[derive(Accounts)]
+pub struct VoidTournament<'info> {
+    /// Admin wallet - must match arena_config.admin
+    pub admin: Signer<'info>,
+
+    /// Arena config for admin verification
+    #[account(
+        mut,
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump,
+        constraint = arena_config.admin == admin.key() @ ArenaError::Unauthorized
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Tournament to void - must be InProgress
+    #[account(
+        mut,
+        seeds = [Tournament::SEED_PREFIX, tournament.host_seed(), &tournament.id.to_le_bytes()],
+        bump = tournament.bump,
+        constraint = tournament.status == TournamentStatus::InProgress @ ArenaError::TournamentNotInProgress
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// Tournament's host, required if the tournament is hosted
+    #[account(
+        mut,
+        seeds = [Host::SEED_PREFIX, host.authority.as_ref()],
+        bump = host.bump
+    )]
+    pub host: Option<Account<'info, Host>>,
+
+    /// Audit log recording this call
+    #[account(
+        mut,
+        seeds = [AuditLog::SEED_PREFIX],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// Void a tournament after an unrecoverable engine failure (admin only).
+///
+/// Unlike Cancelled, Voided marks a tournament that started and then failed,
+/// so analytics can tell the two apart.
+///
+/// This instruction:
+/// 1. Discards any partial results (results hash, winner, points root)
+/// 2. Moves the tournament to Voided, making every tier fee refundable
+///
+/// Results already recorded for busted players, and the stats they fed,
+/// are taken back per registration with `discard_voided_result`.
+///
+/// # Arguments
+/// * `reason_hash` - SHA-256 hash of the off-chain incident report
+pub fn handler(ctx: Context<VoidTournament>, reason_hash: [u8; 32]) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+    let clock = Clock::get()?;
+
+    tournament.results_hash = None;
+    tournament.winner = None;
+    tournament.points_root = None;
+    tournament.status = TournamentStatus::Voided;
+    match Host::for_tournament(ctx.accounts.host.as_mut(), tournament)? {
+        Some(host) => host.record_voided(),
+        None => ctx.accounts.arena_config.record_tournament_closed(),
+    }
+    tournament.completed_at = Some(clock.unix_timestamp);
+
+    emit!(TournamentVoided {
+        tournament: tournament.key(),
+        reason_hash,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Tournament {} voided", tournament.id);
+
+    AuditLog::record_call::<instruction::VoidTournament>(
+        &mut ctx.accounts.audit_log,
+        ctx.accounts.admin.key(),
+        ctx.accounts.tournament.id,
+    )?;
+
+    Ok(())
+}
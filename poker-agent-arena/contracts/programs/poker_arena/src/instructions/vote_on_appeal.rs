@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::state::{Appeal, AppealStatus, ArbiterSet};
+
+/// Accounts required for an arbiter to vote on an appeal.
+#[derive(Accounts)]
+pub struct VoteOnAppeal<'info> {
+    /// Arbiter casting the vote
+    pub arbiter: Signer<'info>,
+
+    /// Current arbiter committee
+    #[account(
+        seeds = [ArbiterSet::SEED_PREFIX],
+        bump = arbiter_set.bump
+    )]
+    pub arbiter_set: Account<'info, ArbiterSet>,
+
+    /// Appeal being voted on - must be Open
+    #[account(
+        mut,
+        seeds = [Appeal::SEED_PREFIX, appeal.registration.as_ref(), &appeal.sanction_nonce.to_le_bytes()],
+        bump = appeal.bump,
+        constraint = appeal.status == AppealStatus::Open @ ArenaError::AppealAlreadyResolved
+    )]
+    pub appeal: Account<'info, Appeal>,
+}
+
+/// Vote to grant or deny an open appeal (arbiters only).
+///
+/// Once the committee threshold agrees, anyone may resolve the appeal that
+/// way with `resolve_appeal`. An arbiter voting again replaces their vote.
+///
+/// # Arguments
+/// * `granted` - Whether the arbiter upholds the appeal
+pub fn handler(ctx: Context<VoteOnAppeal>, granted: bool) -> Result<()> {
+    let arbiter_set = &ctx.accounts.arbiter_set;
+    let index = arbiter_set
+        .member_index(&ctx.accounts.arbiter.key())
+        .ok_or(ArenaError::NotArbiter)?;
+
+    let appeal = &mut ctx.accounts.appeal;
+
+    // Votes from a previous committee no longer count
+    if appeal.arbiter_set_version != arbiter_set.version {
+        appeal.arbiter_set_version = arbiter_set.version;
+        appeal.grant_mask = 0;
+        appeal.deny_mask = 0;
+    }
+    if granted {
+        appeal.grant_mask |= 1 << index;
+        appeal.deny_mask &= !(1 << index);
+    } else {
+        appeal.deny_mask |= 1 << index;
+        appeal.grant_mask &= !(1 << index);
+    }
+
+    let (outcome, votes) = if granted {
+        ("grant", appeal.grant_mask)
+    } else {
+        ("deny", appeal.deny_mask)
+    };
+    msg!(
+        "Arbiter {} voted to {} the appeal of {} ({}/{})",
+        ctx.accounts.arbiter.key(),
+        outcome,
+        appeal.wallet,
+        votes.count_ones(),
+        arbiter_set.threshold
+    );
+
+    Ok(())
+}
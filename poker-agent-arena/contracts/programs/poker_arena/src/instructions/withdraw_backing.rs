@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::state::{BackingPool, LedgerCategory, LedgerDirection, PlayerRegistration, TreasuryLedger};
+
+/// Accounts required for withdrawing raised backing.
+#[derive(Accounts)]
+pub struct WithdrawBacking<'info> {
+    /// Backed player
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    /// Backing pool - must belong to the player and not be withdrawn
+    #[account(
+        mut,
+        seeds = [BackingPool::SEED_PREFIX, backing_pool.tournament.as_ref(), player.key().as_ref()],
+        bump = backing_pool.bump,
+        constraint = !backing_pool.withdrawn @ ArenaError::BackingAlreadyWithdrawn
+    )]
+    pub backing_pool: Account<'info, BackingPool>,
+
+    /// Player's registration - proves the backed entry was made
+    #[account(
+        seeds = [PlayerRegistration::SEED_PREFIX, backing_pool.tournament.as_ref(), player.key().as_ref()],
+        bump = registration.bump
+    )]
+    pub registration: Account<'info, PlayerRegistration>,
+
+    /// Treasury ledger recording the withdrawal (omit to skip)
+    #[account(
+        mut,
+        seeds = [TreasuryLedger::SEED_PREFIX],
+        bump = treasury_ledger.bump
+    )]
+    pub treasury_ledger: Option<Box<Account<'info, TreasuryLedger>>>,
+}
+
+/// Withdraw the raised backing once registered.
+///
+/// After withdrawal the sold shares are locked in and are paid out of the
+/// player's POINTS at distribution.
+pub fn handler(ctx: Context<WithdrawBacking>) -> Result<()> {
+    let backing_pool = &mut ctx.accounts.backing_pool;
+    let amount = backing_pool.raised_lamports;
+
+    **backing_pool.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **ctx
+        .accounts
+        .player
+        .to_account_info()
+        .try_borrow_mut_lamports()? += amount;
+    if let Some(treasury_ledger) = ctx.accounts.treasury_ledger.as_mut() {
+        treasury_ledger.record(
+            LedgerCategory::BackingEscrow,
+            LedgerDirection::Outflow,
+            backing_pool.key(),
+            amount,
+            Clock::get()?.unix_timestamp,
+        );
+    }
+
+    backing_pool.withdrawn = true;
+
+    msg!("Withdrew {} lamports of backing", amount);
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::state::{Guild, GuildVault, LedgerCategory, LedgerDirection, TreasuryLedger};
+
+/// Accounts required for withdrawing from a guild vault.
+#[derive(Accounts)]
+pub struct WithdrawGuildVault<'info> {
+    /// Guild leader
+    pub leader: Signer<'info>,
+
+    /// Guild owning the vault
+    #[account(
+        seeds = [Guild::SEED_PREFIX, guild.name.as_ref()],
+        bump = guild.bump,
+        constraint = guild.leader == leader.key() @ ArenaError::Unauthorized
+    )]
+    pub guild: Account<'info, Guild>,
+
+    /// Guild vault PDA
+    #[account(
+        mut,
+        seeds = [GuildVault::SEED_PREFIX, guild.key().as_ref()],
+        bump = guild_vault.bump
+    )]
+    pub guild_vault: Account<'info, GuildVault>,
+
+    /// Recipient of the withdrawn SOL
+    /// CHECK: Any account chosen by the leader
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+
+    /// Treasury ledger recording the movement (omit to skip)
+    #[account(
+        mut,
+        seeds = [TreasuryLedger::SEED_PREFIX],
+        bump = treasury_ledger.bump
+    )]
+    pub treasury_ledger: Option<Box<Account<'info, TreasuryLedger>>>,
+}
+
+/// Withdraw SOL from a guild vault (leader only), keeping it rent exempt.
+///
+/// # Arguments
+/// * `amount` - Lamports to withdraw
+pub fn handler(ctx: Context<WithdrawGuildVault>, amount: u64) -> Result<()> {
+    let vault_info = ctx.accounts.guild_vault.to_account_info();
+    require!(
+        amount <= GuildVault::available(&vault_info)?,
+        ArenaError::InsufficientBalance
+    );
+
+    **vault_info.try_borrow_mut_lamports()? -= amount;
+    **ctx
+        .accounts
+        .recipient
+        .to_account_info()
+        .try_borrow_mut_lamports()? += amount;
+
+    if let Some(treasury_ledger) = ctx.accounts.treasury_ledger.as_mut() {
+        treasury_ledger.record(
+            LedgerCategory::GuildVault,
+            LedgerDirection::Outflow,
+            vault_info.key(),
+            amount,
+            Clock::get()?.unix_timestamp,
+        );
+    }
+
+    msg!(
+        "Withdrew {} lamports from guild vault {}",
+        amount,
+        vault_info.key()
+    );
+
+    Ok(())
+}
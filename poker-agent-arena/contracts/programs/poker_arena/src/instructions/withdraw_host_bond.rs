@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::state::{ArenaConfig, Host, LedgerCategory, LedgerDirection, TreasuryLedger};
+
+/// Accounts required for a host withdrawing bond.
+#[derive(Accounts)]
+pub struct WithdrawHostBond<'info> {
+    /// Host's signing key, receiving the withdrawn bond
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Arena config for the required bond
+    #[account(
+        seeds = [ArenaConfig::SEED_PREFIX],
+        bump = arena_config.bump
+    )]
+    pub arena_config: Account<'info, ArenaConfig>,
+
+    /// Host entry holding the bond
+    #[account(
+        mut,
+        seeds = [Host::SEED_PREFIX, authority.key().as_ref()],
+        bump = host.bump
+    )]
+    pub host: Account<'info, Host>,
+
+    /// Treasury ledger recording the movement (omit to skip)
+    #[account(
+        mut,
+        seeds = [TreasuryLedger::SEED_PREFIX],
+        bump = treasury_ledger.bump
+    )]
+    pub treasury_ledger: Option<Box<Account<'info, TreasuryLedger>>>,
+}
+
+/// Withdraw SOL from the host's bond (host only).
+///
+/// While any hosted tournament is still open the bond cannot drop below the
+/// arena's required bond, so it stays slashable until every event closes.
+///
+/// # Arguments
+/// * `amount` - Lamports to withdraw
+pub fn handler(ctx: Context<WithdrawHostBond>, amount: u64) -> Result<()> {
+    let host = &mut ctx.accounts.host;
+    let locked = if host.open_tournaments > 0 {
+        ctx.accounts.arena_config.host_bond_lamports
+    } else {
+        0
+    };
+    require!(
+        amount > 0 && amount <= host.bond_lamports.saturating_sub(locked),
+        ArenaError::HostBondTooLow
+    );
+
+    host.bond_lamports -= amount;
+    **host.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **ctx
+        .accounts
+        .authority
+        .to_account_info()
+        .try_borrow_mut_lamports()? += amount;
+
+    if let Some(treasury_ledger) = ctx.accounts.treasury_ledger.as_mut() {
+        treasury_ledger.record(
+            LedgerCategory::HostBond,
+            LedgerDirection::Outflow,
+            host.key(),
+            amount,
+            Clock::get()?.unix_timestamp,
+        );
+    }
+
+    msg!(
+        "Host {} bond now {} lamports",
+        host.authority,
+        host.bond_lamports
+    );
+
+    Ok(())
+}
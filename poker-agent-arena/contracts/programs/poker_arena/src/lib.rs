@@ -1,11 +1,23 @@
 use anchor_lang::prelude::*;
 
+pub mod blinds;
+pub mod bubblegum;
+pub mod compression;
 pub mod errors;
+pub mod events;
 pub mod instructions;
+pub mod merkle;
+pub mod oracle;
+pub mod seating;
 pub mod state;
 
+use blinds::BlindPreset;
 use instructions::*;
-use state::AgentTier;
+use state::{
+    AgentMetrics, AgentTier, BracketSide, CompressedRegistration, Division, DustPolicy,
+    HighlightTag, PointsFormula, PrizeDenomination, PrizePool, ProposalAction, Table,
+    TournamentFormat,
+};
 
 declare_id!("E6tuNWDutZ7Npsb6UU6GmV5H4B3ucpAwzzGeqJzrUUJz");
 
@@ -30,8 +42,11 @@ pub mod poker_arena {
     /// * `max_players` - Maximum number of players allowed
     /// * `starting_stack` - Starting chip stack for each player
     /// * `starts_at` - Unix timestamp when tournament is scheduled to start
-    /// * `blind_structure_hash` - SHA-256 hash of the blind structure JSON
+    /// * `blind_structure_hash` - SHA-256 hash of the blind structure JSON (zeros for presets)
     /// * `payout_structure_hash` - SHA-256 hash of the admin-customized payout table
+    /// * `reserved_seats` - Seats held back for sponsor and exhibition agents
+    /// * `blind_preset` - Built-in blind structure, or Custom to use `blind_structure_hash`
+    #[allow(clippy::too_many_arguments)]
     pub fn create_tournament(
         ctx: Context<CreateTournament>,
         max_players: u16,
@@ -39,6 +54,9 @@ pub mod poker_arena {
         starts_at: i64,
         blind_structure_hash: [u8; 32],
         payout_structure_hash: [u8; 32],
+        reserved_seats: u16,
+        blind_preset: BlindPreset,
+        prize_denomination: PrizeDenomination,
     ) -> Result<()> {
         instructions::create_tournament::handler(
             ctx,
@@ -47,16 +65,20 @@ pub mod poker_arena {
             starts_at,
             blind_structure_hash,
             payout_structure_hash,
+            reserved_seats,
+            blind_preset,
+            prize_denomination,
         )
     }
 
-    /// Open registration for a tournament (admin only).
+    /// Open registration for a tournament (admin, operator or host).
     /// Changes tournament status from Created to Registration.
     pub fn open_registration(ctx: Context<OpenRegistration>) -> Result<()> {
         instructions::open_registration::handler(ctx)
     }
 
     /// Register a player for a tournament.
+    /// Rent may be sponsored by a separate payer; the player pays the tier fee.
     ///
     /// # Arguments
     /// * `ctx` - The context containing all accounts
@@ -64,14 +86,15 @@ pub mod poker_arena {
     /// * `agent_prompt_hash` - SHA-256 hash of the custom prompt
     /// * `agent_name` - Display name for the agent (32 bytes)
     /// * `agent_image_uri` - URI for agent avatar image (128 bytes)
-    pub fn register_player(
-        ctx: Context<RegisterPlayer>,
+    pub fn register_player<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RegisterPlayer<'info>>,
         tier: AgentTier,
         agent_prompt_hash: [u8; 32],
         agent_name: [u8; 32],
         agent_image_uri: [u8; 128],
+        correlation_id: u64,
     ) -> Result<()> {
-        instructions::register_player::handler(ctx, tier, agent_prompt_hash, agent_name, agent_image_uri)
+        instructions::register_player::handler(ctx, tier, agent_prompt_hash, agent_name, agent_image_uri, correlation_id)
     }
 
     /// Create the POINTS SPL token mint (admin only, one-time setup).
@@ -80,45 +103,59 @@ pub mod poker_arena {
         instructions::create_points_mint::handler(ctx)
     }
 
-    /// Start a tournament (admin only).
+    /// Start a tournament (admin, operator or host).
     /// Captures RNG seed and changes status to InProgress.
-    pub fn start_tournament(ctx: Context<StartTournament>) -> Result<()> {
-        instructions::start_tournament::handler(ctx)
+    pub fn start_tournament(
+        ctx: Context<StartTournament>,
+        engine_version_hash: [u8; 32],
+        model_id_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::start_tournament::handler(ctx, engine_version_hash, model_id_hash)
     }
 
-    /// Finalize a tournament (admin only).
-    /// Records the results hash and winner, changes status to Completed.
+    /// Submit a tournament's provisional results (results authority).
+    /// Records the results hash and winner and starts the dispute window.
     ///
     /// # Arguments
     /// * `ctx` - The context containing all accounts
     /// * `results_hash` - SHA-256 hash of final standings JSON
     /// * `winner` - Winner's wallet address
-    pub fn finalize_tournament(
-        ctx: Context<FinalizeTournament>,
+    pub fn submit_results(
+        ctx: Context<SubmitResults>,
         results_hash: [u8; 32],
         winner: Pubkey,
     ) -> Result<()> {
-        instructions::finalize_tournament::handler(ctx, results_hash, winner)
+        instructions::submit_results::handler(ctx, results_hash, winner)
+    }
+
+    /// Confirm submitted results once the dispute window closes or the
+    /// dispute is resolved (results authority).
+    /// Changes status to Completed, unlocking results recording and distribution.
+    pub fn confirm_results(ctx: Context<ConfirmResults>) -> Result<()> {
+        instructions::confirm_results::handler(ctx)
     }
 
-    /// Record a player's tournament result (admin only).
+    /// Record a player's tournament result (results authority).
     /// Updates the player's registration with final rank, points, etc.
     /// Creates or updates the player's lifetime statistics.
     ///
     /// # Arguments
     /// * `ctx` - The context containing all accounts
     /// * `final_rank` - Player's finishing position (1 = winner)
-    /// * `points_awarded` - POINTS tokens to award
+    /// * `points_awarded` - POINTS to award in base units; must be a whole
+    ///   number of POINTS
     /// * `hands_played` - Number of hands played
     /// * `eliminations` - Number of players eliminated
-    pub fn record_player_result(
-        ctx: Context<RecordPlayerResult>,
+    /// * `metrics` - Engine behavioral metrics for the agent
+    pub fn record_player_result<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RecordPlayerResult<'info>>,
         final_rank: u16,
         points_awarded: u64,
         hands_played: u32,
         eliminations: u8,
+        metrics: AgentMetrics,
     ) -> Result<()> {
-        instructions::record_player_result::handler(ctx, final_rank, points_awarded, hands_played, eliminations)
+        instructions::record_player_result::handler(ctx, final_rank, points_awarded, hands_played, eliminations, metrics)
     }
 
     /// Distribute POINTS tokens to a player (admin only).
@@ -126,4 +163,1509 @@ pub mod poker_arena {
     pub fn distribute_points(ctx: Context<DistributePoints>) -> Result<()> {
         instructions::distribute_points::handler(ctx)
     }
+
+    /// Reveal an agent's prompt after the tournament completes.
+    /// Verifies the prompt against the hash committed at registration.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing all accounts
+    /// * `prompt` - The prompt bytes that were hashed at registration
+    pub fn reveal_prompt(ctx: Context<RevealPrompt>, prompt: Vec<u8>) -> Result<()> {
+        instructions::reveal_prompt::handler(ctx, prompt)
+    }
+
+    /// Commit an encrypted prompt blob and decryption key commitment (PRO tier).
+    /// Posts a bond that is refunded when the key is revealed after the tournament.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing all accounts
+    /// * `encrypted_prompt_hash` - SHA-256 hash of the encrypted prompt blob
+    /// * `key_commitment` - SHA-256 hash of the decryption key
+    pub fn commit_encrypted_prompt(
+        ctx: Context<CommitEncryptedPrompt>,
+        encrypted_prompt_hash: [u8; 32],
+        key_commitment: [u8; 32],
+    ) -> Result<()> {
+        instructions::commit_encrypted_prompt::handler(ctx, encrypted_prompt_hash, key_commitment)
+    }
+
+    /// Reveal the decryption key for an encrypted prompt commitment.
+    /// Must be called within the reveal window after completion, or any time once
+    /// the tournament is cancelled or voided; refunds the bond.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing all accounts
+    /// * `decryption_key` - Key whose SHA-256 hash matches the commitment
+    pub fn reveal_prompt_key(ctx: Context<RevealPromptKey>, decryption_key: [u8; 32]) -> Result<()> {
+        instructions::reveal_prompt_key::handler(ctx, decryption_key)
+    }
+
+    /// Forfeit an unrevealed prompt bond to the treasury (admin only).
+    /// Only callable after the reveal window has closed.
+    pub fn forfeit_prompt_bond(ctx: Context<ForfeitPromptBond>) -> Result<()> {
+        instructions::forfeit_prompt_bond::handler(ctx)
+    }
+
+    /// Commit a batch of an agent's decisions to its action log (admin, operator or host).
+    /// Extends a per-registration hash chain used for anti-cheat audits.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing all accounts
+    /// * `batch_index` - Zero-based batch index (each batch covers 100 hands)
+    /// * `batch_hash` - SHA-256 hash of the agent's decisions in this batch
+    pub fn commit_action_log(
+        ctx: Context<CommitActionLog>,
+        batch_index: u32,
+        batch_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::commit_action_log::handler(ctx, batch_index, batch_hash)
+    }
+
+    /// Penalize a player's POINTS (admin only).
+    /// Reduces the pending award, or burns distributed POINTS via delegate.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing all accounts
+    /// * `amount` - POINTS to remove (base units)
+    /// * `reason_hash` - SHA-256 hash of the off-chain reason document
+    pub fn apply_penalty(ctx: Context<ApplyPenalty>, amount: u64, reason_hash: [u8; 32]) -> Result<()> {
+        instructions::apply_penalty::handler(ctx, amount, reason_hash)
+    }
+
+    /// Disqualify a player during Registration or InProgress (admin, operator or host).
+    /// Optionally refunds part of the tier fee and frees the roster slot.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing all accounts
+    /// * `refund_bps` - Share of the tier fee to refund, in basis points
+    /// * `reason_hash` - SHA-256 hash of the off-chain reason document
+    pub fn disqualify_player(
+        ctx: Context<DisqualifyPlayer>,
+        refund_bps: u16,
+        reason_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::disqualify_player::handler(ctx, refund_bps, reason_hash)
+    }
+
+    /// Appeal a penalty or disqualification within the appeal window.
+    /// Stalls further point slashing until the appeal is resolved.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing all accounts
+    /// * `reason_hash` - SHA-256 hash of the off-chain appeal statement
+    pub fn open_appeal(ctx: Context<OpenAppeal>, reason_hash: [u8; 32]) -> Result<()> {
+        instructions::open_appeal::handler(ctx, reason_hash)
+    }
+
+    /// Resolve a player's appeal (admin, or anyone once the arbiter committee decided).
+    /// A granted appeal restores the sanctioned POINTS, re-minting burned ones,
+    /// and lifts disqualification.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing all accounts
+    /// * `granted` - Whether the appeal is upheld
+    pub fn resolve_appeal(ctx: Context<ResolveAppeal>, granted: bool) -> Result<()> {
+        instructions::resolve_appeal::handler(ctx, granted)
+    }
+
+    /// Vote to grant or deny an open appeal (arbiters only).
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing all accounts
+    /// * `granted` - Whether the arbiter upholds the appeal
+    pub fn vote_on_appeal(ctx: Context<VoteOnAppeal>, granted: bool) -> Result<()> {
+        instructions::vote_on_appeal::handler(ctx, granted)
+    }
+
+    /// Delegate a key to operate a single tournament (admin only).
+    /// Pass `Pubkey::default()` to revoke the delegation.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing all accounts
+    /// * `operator` - Key authorized to run this tournament
+    pub fn delegate_tournament_operator(
+        ctx: Context<DelegateTournamentOperator>,
+        operator: Pubkey,
+    ) -> Result<()> {
+        instructions::delegate_tournament_operator::handler(ctx, operator)
+    }
+
+    /// Fund the crank vault that pays permissionless crank bounties.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing all accounts
+    /// * `amount` - Lamports to deposit
+    pub fn fund_crank_vault(ctx: Context<FundCrankVault>, amount: u64) -> Result<()> {
+        instructions::fund_crank_vault::handler(ctx, amount)
+    }
+
+    /// Set the bounty paid per permissionless crank (admin only).
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing all accounts
+    /// * `bounty_lamports` - Lamports paid to each crank caller
+    pub fn set_crank_bounty(ctx: Context<SetCrankBounty>, bounty_lamports: u64) -> Result<()> {
+        instructions::set_crank_bounty::handler(ctx, bounty_lamports)
+    }
+
+    /// Start a tournament past its scheduled start time (permissionless).
+    /// Pays the caller the configured crank bounty.
+    pub fn crank_start_tournament(ctx: Context<CrankStartTournament>) -> Result<()> {
+        instructions::crank_start_tournament::handler(ctx)
+    }
+
+    /// Commit a Merkle root of (wallet, points) awards (results authority).
+    /// Switches the tournament to self-serve claim settlement.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing all accounts
+    /// * `points_root` - Merkle root over the tournament's point awards
+    pub fn commit_points_root(ctx: Context<CommitPointsRoot>, points_root: [u8; 32]) -> Result<()> {
+        instructions::commit_points_root::handler(ctx, points_root)
+    }
+
+    /// Claim awarded POINTS with a Merkle proof against the committed root,
+    /// vesting, share splits and sanctions applying as in `distribute_points`.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing all accounts
+    /// * `amount` - POINTS awarded to the claimant (base units)
+    /// * `proof` - Sibling hashes from the leaf up to the root
+    pub fn claim_points_with_proof(
+        ctx: Context<ClaimPointsWithProof>,
+        amount: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::claim_points_with_proof::handler(ctx, amount, proof)
+    }
+
+    /// Enable compressed registrations for a tournament (admin only).
+    /// Initializes a concurrent Merkle tree with a program PDA as authority.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing all accounts
+    /// * `max_depth` - Tree depth (capacity is 2^max_depth registrations)
+    /// * `max_buffer_size` - Concurrent changelog buffer size
+    pub fn init_compressed_roster(
+        ctx: Context<InitCompressedRoster>,
+        max_depth: u32,
+        max_buffer_size: u32,
+    ) -> Result<()> {
+        instructions::init_compressed_roster::handler(ctx, max_depth, max_buffer_size)
+    }
+
+    /// Register a player as a compressed Merkle leaf.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing all accounts
+    /// * `tier` - The agent tier (FREE, BASIC, or PRO)
+    /// * `agent_prompt_hash` - SHA-256 hash of the custom prompt
+    /// * `agent_name` - Display name for the agent (32 bytes)
+    /// * `agent_image_uri` - URI for agent avatar image (128 bytes)
+    pub fn register_player_compressed<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RegisterPlayerCompressed<'info>>,
+        tier: AgentTier,
+        agent_prompt_hash: [u8; 32],
+        agent_name: [u8; 32],
+        agent_image_uri: [u8; 128],
+    ) -> Result<()> {
+        instructions::register_player_compressed::handler(ctx, tier, agent_prompt_hash, agent_name, agent_image_uri)
+    }
+
+    /// Record a compressed registration's result (results authority).
+    /// The leaf's Merkle proof is passed as remaining accounts.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing all accounts
+    /// * `root` - Current tree root the proof was generated against
+    /// * `leaf_index` - Index of the registration leaf
+    /// * `registration` - Current leaf data
+    /// * `final_rank` - Player's finishing position (1 = winner)
+    /// * `points_awarded` - POINTS to award in base units; must be a whole
+    ///   number of POINTS
+    /// * `hands_played` - Number of hands played
+    /// * `eliminations` - Number of players eliminated
+    #[allow(clippy::too_many_arguments)]
+    pub fn record_compressed_result<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RecordCompressedResult<'info>>,
+        root: [u8; 32],
+        leaf_index: u32,
+        registration: CompressedRegistration,
+        final_rank: u16,
+        points_awarded: u64,
+        hands_played: u32,
+        eliminations: u8,
+    ) -> Result<()> {
+        instructions::record_compressed_result::handler(
+            ctx,
+            root,
+            leaf_index,
+            registration,
+            final_rank,
+            points_awarded,
+            hands_played,
+            eliminations,
+        )
+    }
+
+    /// Claim POINTS recorded on a compressed registration.
+    /// The leaf's Merkle proof is passed as remaining accounts.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing all accounts
+    /// * `root` - Current tree root the proof was generated against
+    /// * `leaf_index` - Index of the registration leaf
+    /// * `registration` - Current leaf data
+    pub fn claim_compressed_points<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimCompressedPoints<'info>>,
+        root: [u8; 32],
+        leaf_index: u32,
+        registration: CompressedRegistration,
+    ) -> Result<()> {
+        instructions::claim_compressed_points::handler(ctx, root, leaf_index, registration)
+    }
+
+    /// Set the base URI for trophy metadata (admin only).
+    /// Creates the trophy authority PDA on first call.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing all accounts
+    /// * `metadata_base_uri` - ipfs:// or https:// base (128 bytes)
+    pub fn set_trophy_metadata_uri(
+        ctx: Context<SetTrophyMetadataUri>,
+        metadata_base_uri: [u8; 128],
+    ) -> Result<()> {
+        instructions::set_trophy_metadata_uri::handler(ctx, metadata_base_uri)
+    }
+
+    /// Opt a tournament in or out of participation trophy cNFTs (admin only).
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing all accounts
+    /// * `enabled` - Mint a trophy to each player when results are recorded
+    pub fn set_participation_trophies(
+        ctx: Context<SetParticipationTrophies>,
+        enabled: bool,
+    ) -> Result<()> {
+        instructions::set_participation_trophies::handler(ctx, enabled)
+    }
+
+    /// Fund the POINTS redemption vault from the treasury.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing all accounts
+    /// * `amount` - Lamports to deposit
+    pub fn fund_redemption_vault(ctx: Context<FundRedemptionVault>, amount: u64) -> Result<()> {
+        instructions::fund_redemption_vault::handler(ctx, amount)
+    }
+
+    /// Set the POINTS redemption rate (admin only).
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing all accounts
+    /// * `lamports_per_point` - Lamports per whole POINT (0 = pro-rata share)
+    pub fn set_redemption_rate(ctx: Context<SetRedemptionRate>, lamports_per_point: u64) -> Result<()> {
+        instructions::set_redemption_rate::handler(ctx, lamports_per_point)
+    }
+
+    /// Burn POINTS and receive SOL from the redemption vault.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing all accounts
+    /// * `amount` - POINTS to redeem (base units)
+    pub fn redeem_points(ctx: Context<RedeemPoints>, amount: u64) -> Result<()> {
+        instructions::redeem_points::handler(ctx, amount)
+    }
+
+    /// Set the share of collected rake spent on POINTS buybacks (admin only).
+    /// Creates the buyback ledger on first call.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing all accounts
+    /// * `buyback_bps` - Share of rake in basis points (0-10000)
+    pub fn set_buyback_bps(ctx: Context<SetBuybackBps>, buyback_bps: u16) -> Result<()> {
+        instructions::set_buyback_bps::handler(ctx, buyback_bps)
+    }
+
+    /// Burn POINTS bought back with the configured slice of rake (treasury only).
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing all accounts
+    /// * `rake_lamports` - Rake collected since the last burn
+    /// * `points_amount` - POINTS bought with the budget (base units)
+    pub fn buyback_and_burn(
+        ctx: Context<BuybackAndBurn>,
+        rake_lamports: u64,
+        points_amount: u64,
+    ) -> Result<()> {
+        instructions::buyback_and_burn::handler(ctx, rake_lamports, points_amount)
+    }
+
+    /// Initialize POINTS-weighted governance (admin only).
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing all accounts
+    /// * `quorum_points` - Minimum POINTS in favor for a proposal to pass
+    pub fn init_governance(ctx: Context<InitGovernance>, quorum_points: u64) -> Result<()> {
+        instructions::init_governance::handler(ctx, quorum_points)
+    }
+
+    /// Stake POINTS to gain governance voting power.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing all accounts
+    /// * `amount` - POINTS to stake (base units)
+    pub fn stake_points(ctx: Context<StakePoints>, amount: u64) -> Result<()> {
+        instructions::stake_points::handler(ctx, amount)
+    }
+
+    /// Withdraw staked POINTS once no vote holds them locked.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing all accounts
+    /// * `amount` - POINTS to withdraw (base units)
+    pub fn unstake_points(ctx: Context<UnstakePoints>, amount: u64) -> Result<()> {
+        instructions::unstake_points::handler(ctx, amount)
+    }
+
+    /// Create a proposal to change an arena parameter (stakers only).
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing all accounts
+    /// * `action` - Parameter change to apply if the proposal passes
+    pub fn create_proposal(ctx: Context<CreateProposal>, action: ProposalAction) -> Result<()> {
+        instructions::create_proposal::handler(ctx, action)
+    }
+
+    /// Vote on a proposal, weighted by POINTS staked before its creation.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing all accounts
+    /// * `support` - True to vote in favor
+    pub fn cast_vote(ctx: Context<CastVote>, support: bool) -> Result<()> {
+        instructions::cast_vote::handler(ctx, support)
+    }
+
+    /// Execute a passed proposal after its timelock (permissionless).
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing all accounts
+    pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+        instructions::execute_proposal::handler(ctx)
+    }
+
+    /// Void an in-progress tournament after an engine failure (admin only).
+    /// Discards partial results and makes all tier fees refundable; results
+    /// already recorded for busted players are discarded with
+    /// `discard_voided_result`.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing all accounts
+    /// * `reason_hash` - SHA-256 hash of the off-chain incident report
+    pub fn void_tournament(ctx: Context<VoidTournament>, reason_hash: [u8; 32]) -> Result<()> {
+        instructions::void_tournament::handler(ctx, reason_hash)
+    }
+
+    /// Refund a registration's tier fee in a voided tournament (treasury only).
+    /// Fee recipients return their shares; a host's share is refunded from its
+    /// slashed bond instead.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing all accounts
+    pub fn refund_voided_registration<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RefundVoidedRegistration<'info>>,
+    ) -> Result<()> {
+        instructions::refund_voided_registration::handler(ctx)
+    }
+
+    /// Discard a busted player's result recorded before the tournament was
+    /// voided, and take it back out of their stats (permissionless).
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing all accounts
+    pub fn discard_voided_result(ctx: Context<DiscardVoidedResult>) -> Result<()> {
+        instructions::discard_voided_result::handler(ctx)
+    }
+
+    /// Create a tournament table with its initial seating (admin, operator or host).
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing all accounts
+    /// * `table_number` - Table number within the tournament (1-based)
+    /// * `seats` - Player wallet in each seat (Pubkey::default() if empty)
+    pub fn create_table(
+        ctx: Context<CreateTable>,
+        table_number: u16,
+        seats: [Pubkey; Table::MAX_SEATS],
+    ) -> Result<()> {
+        instructions::create_table::handler(ctx, table_number, seats)
+    }
+
+    /// Update a table's seating on breaks or balancing (admin, operator or host).
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing all accounts
+    /// * `seats` - New player wallet in each seat
+    /// * `hand_count` - Hands dealt at this table so far
+    pub fn update_table(
+        ctx: Context<UpdateTable>,
+        seats: [Pubkey; Table::MAX_SEATS],
+        hand_count: u64,
+    ) -> Result<()> {
+        instructions::update_table::handler(ctx, seats, hand_count)
+    }
+
+    /// Verify a table's initial seating against the seeded draw (permissionless).
+    /// All registrations of the tournament are passed as remaining accounts.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing all accounts
+    pub fn verify_seat_draw<'info>(
+        ctx: Context<'_, '_, 'info, 'info, VerifySeatDraw<'info>>,
+    ) -> Result<()> {
+        instructions::verify_seat_draw::handler(ctx)
+    }
+
+    /// Sync live tournament progress (admin, operator or host).
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing all accounts
+    /// * `hand_number` - Most recent hand number dealt
+    /// * `players_remaining` - Players still holding chips
+    /// * `total_pot_dealt` - Cumulative chips pushed into pots
+    pub fn sync_progress(
+        ctx: Context<SyncProgress>,
+        hand_number: u64,
+        players_remaining: u16,
+        total_pot_dealt: u64,
+    ) -> Result<()> {
+        instructions::sync_progress::handler(ctx, hand_number, players_remaining, total_pot_dealt)
+    }
+
+    /// Open a backing pool so backers can fund a player's entry for a share.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing all accounts
+    /// * `target_lamports` - Lamports to raise (typically the tier fee)
+    /// * `offered_share_bps` - Share of winnings sold for the full target
+    pub fn open_backing(
+        ctx: Context<OpenBacking>,
+        target_lamports: u64,
+        offered_share_bps: u16,
+    ) -> Result<()> {
+        instructions::open_backing::handler(ctx, target_lamports, offered_share_bps)
+    }
+
+    /// Contribute lamports to a player's backing pool for a share of winnings.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing all accounts
+    /// * `lamports` - Lamports to contribute
+    pub fn back_player(ctx: Context<BackPlayer>, lamports: u64) -> Result<()> {
+        instructions::back_player::handler(ctx, lamports)
+    }
+
+    /// Withdraw raised backing once registered, locking in the sold shares.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing all accounts
+    pub fn withdraw_backing(ctx: Context<WithdrawBacking>) -> Result<()> {
+        instructions::withdraw_backing::handler(ctx)
+    }
+
+    /// Refund a backer whose player never withdrew the pool.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing all accounts
+    pub fn refund_backing(ctx: Context<RefundBacking>) -> Result<()> {
+        instructions::refund_backing::handler(ctx)
+    }
+
+    /// Claim a backer's share of the player's distributed POINTS.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing all accounts
+    pub fn claim_backer_points(ctx: Context<ClaimBackerPoints>) -> Result<()> {
+        instructions::claim_backer_points::handler(ctx)
+    }
+
+    /// List a percentage of a registered player's winnings for sale.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing all accounts
+    /// * `available_bps` - Share offered, in basis points
+    /// * `price_lamports_per_bps` - Price per basis point, in lamports
+    pub fn list_action(
+        ctx: Context<ListAction>,
+        available_bps: u16,
+        price_lamports_per_bps: u64,
+    ) -> Result<()> {
+        instructions::list_action::handler(ctx, available_bps, price_lamports_per_bps)
+    }
+
+    /// Buy a share of a player's winnings from their action listing.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing all accounts
+    /// * `share_bps` - Share to buy, in basis points
+    pub fn buy_action(ctx: Context<BuyAction>, share_bps: u16) -> Result<()> {
+        instructions::buy_action::handler(ctx, share_bps)
+    }
+
+    /// Claim the POINTS owed on an action share after distribution.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing all accounts
+    pub fn claim_action_share(ctx: Context<ClaimActionShare>) -> Result<()> {
+        instructions::claim_action_share::handler(ctx)
+    }
+
+    /// Configure loyalty levels and their tier fee discounts (admin only).
+    pub fn set_loyalty_config(
+        ctx: Context<SetLoyaltyConfig>,
+        thresholds: [u32; 3],
+        discount_bps: [u16; 3],
+    ) -> Result<()> {
+        instructions::set_loyalty_config::handler(ctx, thresholds, discount_bps)
+    }
+
+    /// Create a season with a pass for sale (admin only).
+    pub fn create_season(
+        ctx: Context<CreateSeason>,
+        season_id: u32,
+        starts_at: i64,
+        ends_at: i64,
+        pass_price_lamports: u64,
+        points_multiplier_bps: u16,
+    ) -> Result<()> {
+        instructions::create_season::handler(
+            ctx,
+            season_id,
+            starts_at,
+            ends_at,
+            pass_price_lamports,
+            points_multiplier_bps,
+        )
+    }
+
+    /// Buy a season pass, waiving BASIC fees and multiplying points in the season.
+    pub fn buy_season_pass(ctx: Context<BuySeasonPass>) -> Result<()> {
+        instructions::buy_season_pass::handler(ctx)
+    }
+
+    /// Claim a daily freeroll ticket (once per 24 hours per wallet).
+    pub fn claim_daily_ticket(ctx: Context<ClaimDailyTicket>) -> Result<()> {
+        instructions::claim_daily_ticket::handler(ctx)
+    }
+
+    /// Create or update the signer's display profile.
+    pub fn set_player_profile(
+        ctx: Context<SetPlayerProfile>,
+        display_name: [u8; 32],
+        avatar_uri: [u8; 128],
+        bio_hash: [u8; 32],
+        country_code: [u8; 2],
+    ) -> Result<()> {
+        instructions::set_player_profile::handler(ctx, display_name, avatar_uri, bio_hash, country_code)
+    }
+
+    /// Record that one player eliminated another (results authority).
+    pub fn record_elimination(ctx: Context<RecordElimination>) -> Result<()> {
+        instructions::record_elimination::handler(ctx)
+    }
+
+    /// Configure the tier fee split between recipients and the treasury (admin only).
+    pub fn set_fee_recipients(
+        ctx: Context<SetFeeRecipients>,
+        recipients: [Pubkey; 4],
+        recipient_bps: [u16; 4],
+    ) -> Result<()> {
+        instructions::set_fee_recipients::handler(ctx, recipients, recipient_bps)
+    }
+
+    /// Set the share of seasonal rake paid to the top agents (admin only).
+    pub fn set_season_revenue_share(
+        ctx: Context<SetSeasonRevenueShare>,
+        revenue_share_bps: u16,
+    ) -> Result<()> {
+        instructions::set_season_revenue_share::handler(ctx, revenue_share_bps)
+    }
+
+    /// Fund a season's revenue-share vault for its top agents (treasury only).
+    pub fn fund_season_rewards(
+        ctx: Context<FundSeasonRewards>,
+        seasonal_rake_lamports: u64,
+        winners: [Pubkey; 10],
+        share_bps: [u16; 10],
+    ) -> Result<()> {
+        instructions::fund_season_rewards::handler(ctx, seasonal_rake_lamports, winners, share_bps)
+    }
+
+    /// Claim a season revenue-share reward.
+    pub fn claim_season_reward(ctx: Context<ClaimSeasonReward>) -> Result<()> {
+        instructions::claim_season_reward::handler(ctx)
+    }
+
+    /// Approve or revoke an LLM model for PRO agents (admin only).
+    pub fn set_model_approval(
+        ctx: Context<SetModelApproval>,
+        model_id_hash: [u8; 32],
+        active: bool,
+    ) -> Result<()> {
+        instructions::set_model_approval::handler(ctx, model_id_hash, active)
+    }
+
+    /// Extend the tournament's hand-log hash chain (admin, operator or host).
+    ///
+    /// # Arguments
+    /// * `batch_index` - Zero-based index of this batch
+    /// * `prev_hash` - Current chain head
+    /// * `hand_log_hash` - SHA-256 hash of the batch's hand logs
+    pub fn commit_game_log(
+        ctx: Context<CommitGameLog>,
+        batch_index: u32,
+        prev_hash: [u8; 32],
+        hand_log_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::commit_game_log::handler(ctx, batch_index, prev_hash, hand_log_hash)
+    }
+
+    /// Appoint the arbiter committee and threshold (admin only).
+    pub fn set_arbiter_set(
+        ctx: Context<SetArbiterSet>,
+        members: [Pubkey; 7],
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::set_arbiter_set::handler(ctx, members, threshold)
+    }
+
+    /// Dispute a tournament's results (registered players only).
+    pub fn dispute_tournament(ctx: Context<DisputeTournament>, reason_hash: [u8; 32]) -> Result<()> {
+        instructions::dispute_tournament::handler(ctx, reason_hash)
+    }
+
+    /// Attest a disputed tournament's results (arbiters only).
+    pub fn attest_results(
+        ctx: Context<AttestResults>,
+        results_hash: [u8; 32],
+        winner: Pubkey,
+    ) -> Result<()> {
+        instructions::attest_results::handler(ctx, results_hash, winner)
+    }
+
+    /// Authorize a short-lived session key for the signing wallet.
+    pub fn create_session(
+        ctx: Context<CreateSession>,
+        session_key: Pubkey,
+        scope: u8,
+        expires_at: i64,
+        fund_lamports: u64,
+    ) -> Result<()> {
+        instructions::create_session::handler(ctx, session_key, scope, expires_at, fund_lamports)
+    }
+
+    /// Revoke a session key.
+    pub fn revoke_session(ctx: Context<RevokeSession>) -> Result<()> {
+        instructions::revoke_session::handler(ctx)
+    }
+
+    /// Register the session's wallet for a tournament, signed by a session key.
+    pub fn register_player_with_session<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RegisterPlayerWithSession<'info>>,
+        tier: AgentTier,
+        agent_prompt_hash: [u8; 32],
+        agent_name: [u8; 32],
+        agent_image_uri: [u8; 128],
+        correlation_id: u64,
+    ) -> Result<()> {
+        instructions::register_player_with_session::handler(
+            ctx,
+            tier,
+            agent_prompt_hash,
+            agent_name,
+            agent_image_uri,
+            correlation_id,
+        )
+    }
+
+    /// Update an agent's name and avatar before the tournament starts.
+    /// Signed by the wallet or a session key with the update scope.
+    pub fn update_agent(
+        ctx: Context<UpdateAgent>,
+        agent_name: [u8; 32],
+        agent_image_uri: [u8; 128],
+    ) -> Result<()> {
+        instructions::update_agent::handler(ctx, agent_name, agent_image_uri)
+    }
+
+    /// Consent to be registered for a tournament by a sponsor.
+    pub fn approve_gift(ctx: Context<ApproveGift>, sponsor: Pubkey) -> Result<()> {
+        instructions::approve_gift::handler(ctx, sponsor)
+    }
+
+    /// Withdraw consent to a gifted registration.
+    pub fn revoke_gift_approval(ctx: Context<RevokeGiftApproval>) -> Result<()> {
+        instructions::revoke_gift_approval::handler(ctx)
+    }
+
+    /// Register a consenting beneficiary, with the sponsor paying fee and rent.
+    pub fn gift_registration<'info>(
+        ctx: Context<'_, '_, 'info, 'info, GiftRegistration<'info>>,
+        tier: AgentTier,
+        agent_prompt_hash: [u8; 32],
+        agent_name: [u8; 32],
+        agent_image_uri: [u8; 128],
+        correlation_id: u64,
+    ) -> Result<()> {
+        instructions::gift_registration::handler(
+            ctx,
+            tier,
+            agent_prompt_hash,
+            agent_name,
+            agent_image_uri,
+            correlation_id,
+        )
+    }
+
+    /// Found a guild with the signer as leader.
+    pub fn create_guild(
+        ctx: Context<CreateGuild>,
+        name: [u8; 32],
+        winnings_share_bps: u16,
+        sponsor_tier_fees: bool,
+    ) -> Result<()> {
+        instructions::create_guild::handler(ctx, name, winnings_share_bps, sponsor_tier_fees)
+    }
+
+    /// Replace a guild's officers and vault policy (leader only).
+    pub fn update_guild(
+        ctx: Context<UpdateGuild>,
+        officers: [Pubkey; 4],
+        winnings_share_bps: u16,
+        sponsor_tier_fees: bool,
+    ) -> Result<()> {
+        instructions::update_guild::handler(ctx, officers, winnings_share_bps, sponsor_tier_fees)
+    }
+
+    /// Join a guild, admitted by its leader or an officer.
+    pub fn join_guild(ctx: Context<JoinGuild>) -> Result<()> {
+        instructions::join_guild::handler(ctx)
+    }
+
+    /// Leave a guild, or remove a member as leader or officer.
+    pub fn leave_guild(ctx: Context<LeaveGuild>) -> Result<()> {
+        instructions::leave_guild::handler(ctx)
+    }
+
+    /// Deposit SOL into a guild vault.
+    pub fn fund_guild_vault(ctx: Context<FundGuildVault>, amount: u64) -> Result<()> {
+        instructions::fund_guild_vault::handler(ctx, amount)
+    }
+
+    /// Withdraw SOL from a guild vault (leader only).
+    pub fn withdraw_guild_vault(ctx: Context<WithdrawGuildVault>, amount: u64) -> Result<()> {
+        instructions::withdraw_guild_vault::handler(ctx, amount)
+    }
+
+    /// Flag a tournament as a guild-vs-guild event (admin only).
+    pub fn set_guild_event(
+        ctx: Context<SetGuildEvent>,
+        enabled: bool,
+        guild_prize_points: u64,
+    ) -> Result<()> {
+        instructions::set_guild_event::handler(ctx, enabled, guild_prize_points)
+    }
+
+    /// Enter a guild into a guild-vs-guild tournament (leader or officer).
+    pub fn enter_guild_event(ctx: Context<EnterGuildEvent>) -> Result<()> {
+        instructions::enter_guild_event::handler(ctx)
+    }
+
+    /// Rank the guilds of a guild event (admin, operator or host).
+    /// Ranked GuildEventEntry accounts are passed as remaining accounts.
+    pub fn finalize_guild_standings<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FinalizeGuildStandings<'info>>,
+    ) -> Result<()> {
+        instructions::finalize_guild_standings::handler(ctx)
+    }
+
+    /// Mint a ranked guild's share of the guild event prize to its vault.
+    pub fn claim_guild_prize(ctx: Context<ClaimGuildPrize>) -> Result<()> {
+        instructions::claim_guild_prize::handler(ctx)
+    }
+
+    /// Allow or block a partner program registering players via CPI (admin only).
+    pub fn set_cpi_partner(ctx: Context<SetCpiPartner>, program_id: Pubkey, active: bool) -> Result<()> {
+        instructions::set_cpi_partner::handler(ctx, program_id, active)
+    }
+
+    /// Set priority registration windows per tier (admin, operator or host).
+    pub fn set_registration_windows(
+        ctx: Context<SetRegistrationWindows>,
+        pro_opens_at: i64,
+        basic_opens_at: i64,
+        general_opens_at: i64,
+    ) -> Result<()> {
+        instructions::set_registration_windows::handler(ctx, pro_opens_at, basic_opens_at, general_opens_at)
+    }
+
+    /// Seat a sponsor or exhibition agent in a reserved seat (admin only).
+    pub fn register_reserved(
+        ctx: Context<RegisterReserved>,
+        tier: AgentTier,
+        agent_prompt_hash: [u8; 32],
+        agent_name: [u8; 32],
+        agent_image_uri: [u8; 128],
+    ) -> Result<()> {
+        instructions::register_reserved::handler(ctx, tier, agent_prompt_hash, agent_name, agent_image_uri)
+    }
+
+    /// Change a tournament's capacity while registration is open (admin only).
+    pub fn expand_tournament(ctx: Context<ExpandTournament>, max_players: u16) -> Result<()> {
+        instructions::expand_tournament::handler(ctx, max_players)
+    }
+
+    /// Set the arena metadata URI clients self-configure from (admin only).
+    pub fn set_arena_metadata_uri(ctx: Context<SetArenaMetadataUri>, metadata_uri: [u8; 128]) -> Result<()> {
+        instructions::set_arena_metadata_uri::handler(ctx, metadata_uri)
+    }
+
+    /// Set when large POINTS awards vest and on what schedule (admin only).
+    pub fn set_vesting_policy(
+        ctx: Context<SetVestingPolicy>,
+        threshold_points: u64,
+        cliff_seconds: i64,
+        duration_seconds: i64,
+    ) -> Result<()> {
+        instructions::set_vesting_policy::handler(ctx, threshold_points, cliff_seconds, duration_seconds)
+    }
+
+    /// Mint the unlocked portion of a vesting POINTS award to the player.
+    pub fn claim_vested_points(ctx: Context<ClaimVestedPoints>) -> Result<()> {
+        instructions::claim_vested_points::handler(ctx)
+    }
+
+
+    /// Set how long submitted results stay disputable before they can be confirmed.
+    pub fn set_dispute_window(ctx: Context<SetDisputeWindow>, seconds: u32) -> Result<()> {
+        instructions::set_dispute_window::handler(ctx, seconds)
+    }
+
+    /// Resolve a dispute on submitted results with threshold arbiter attestations.
+    pub fn resolve_dispute(ctx: Context<ResolveDispute>) -> Result<()> {
+        instructions::resolve_dispute::handler(ctx)
+    }
+
+    /// Release escrowed POINTS once the dispute window closes or the dispute is resolved.
+    pub fn release_points(ctx: Context<ReleasePoints>) -> Result<()> {
+        instructions::release_points::handler(ctx)
+    }
+
+
+    /// Set how long a tournament may sit in Created past its start before it can expire.
+    pub fn set_stale_tournament_period(ctx: Context<SetStaleTournamentPeriod>, seconds: u32) -> Result<()> {
+        instructions::set_stale_tournament_period::handler(ctx, seconds)
+    }
+
+    /// Cancel a tournament that never started past the staleness period (permissionless).
+    pub fn expire_tournament(ctx: Context<ExpireTournament>) -> Result<()> {
+        instructions::expire_tournament::handler(ctx)
+    }
+
+
+    /// Set who receives rent from collected voided or disqualified registrations.
+    pub fn set_forfeited_rent_recipient(ctx: Context<SetForfeitedRentRecipient>, recipient: Pubkey) -> Result<()> {
+        instructions::set_forfeited_rent_recipient::handler(ctx, recipient)
+    }
+
+    /// Close settled registrations of a closed tournament, routing their rent (permissionless).
+    pub fn gc_registration<'info>(ctx: Context<'_, '_, 'info, 'info, GcRegistration<'info>>) -> Result<()> {
+        instructions::gc_registration::handler(ctx)
+    }
+
+
+    /// Commit the Merkle root over a completed tournament's hand histories.
+    pub fn commit_hand_history_root(ctx: Context<CommitHandHistoryRoot>, hand_history_root: [u8; 32]) -> Result<()> {
+        instructions::commit_hand_history_root::handler(ctx, hand_history_root)
+    }
+
+    /// Mint a "hand of the tournament" commemorative cNFT to the hand's participants.
+    pub fn mint_famous_hand<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MintFamousHand<'info>>,
+        hand_index: u32,
+        hand_hash: [u8; 32],
+        participants: Vec<Pubkey>,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::mint_famous_hand::handler(ctx, hand_index, hand_hash, participants, proof)
+    }
+
+
+    /// Commit a Merkle root of (wallet, total_points) for proof-based partner airdrops.
+    pub fn snapshot_leaderboard(
+        ctx: Context<SnapshotLeaderboard>,
+        root: [u8; 32],
+        wallet_count: u32,
+        total_points: u64,
+    ) -> Result<()> {
+        instructions::snapshot_leaderboard::handler(ctx, root, wallet_count, total_points)
+    }
+
+
+    /// Create a claim-based POINTS airdrop, minting its allocation into a vault.
+    pub fn create_airdrop(
+        ctx: Context<CreateAirdrop>,
+        airdrop_id: u64,
+        root: [u8; 32],
+        total_allocation: u64,
+        expires_at: i64,
+    ) -> Result<()> {
+        instructions::create_airdrop::handler(ctx, airdrop_id, root, total_allocation, expires_at)
+    }
+
+    /// Claim an airdrop allocation with a Merkle proof.
+    pub fn claim_airdrop(ctx: Context<ClaimAirdrop>, amount: u64, proof: Vec<[u8; 32]>) -> Result<()> {
+        instructions::claim_airdrop::handler(ctx, amount, proof)
+    }
+
+    /// Burn an expired airdrop's unclaimed POINTS (permissionless).
+    pub fn burn_expired_airdrop(ctx: Context<BurnExpiredAirdrop>) -> Result<()> {
+        instructions::burn_expired_airdrop::handler(ctx)
+    }
+
+
+    /// Close a fee payment receipt, returning its rent to the payer.
+    pub fn close_payment_receipt(ctx: Context<ClosePaymentReceipt>) -> Result<()> {
+        instructions::close_payment_receipt::handler(ctx)
+    }
+
+
+    /// Create the ledger recording inflows and outflows of program-owned vaults.
+    pub fn init_treasury_ledger(ctx: Context<InitTreasuryLedger>) -> Result<()> {
+        instructions::init_treasury_ledger::handler(ctx)
+    }
+
+    /// Configure the tier fee discount for POINTS holders (admin only).
+    pub fn set_holder_discount(
+        ctx: Context<SetHolderDiscount>,
+        min_points: u64,
+        discount_bps: u16,
+    ) -> Result<()> {
+        instructions::set_holder_discount::handler(ctx, min_points, discount_bps)
+    }
+
+    /// Price tier upgrades in POINTS (admin only).
+    pub fn set_tier_upgrade_points(
+        ctx: Context<SetTierUpgradePoints>,
+        points: [u64; 2],
+    ) -> Result<()> {
+        instructions::set_tier_upgrade_points::handler(ctx, points)
+    }
+
+    /// Upgrade a registration's tier by burning POINTS.
+    pub fn upgrade_tier_with_points(
+        ctx: Context<UpgradeTierWithPoints>,
+        tier: AgentTier,
+        agent_prompt_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::upgrade_tier_with_points::handler(ctx, tier, agent_prompt_hash)
+    }
+
+    /// Set the POINTS rate for tickets (admin only).
+    pub fn set_ticket_rate(ctx: Context<SetTicketRate>, points_per_sol: u64) -> Result<()> {
+        instructions::set_ticket_rate::handler(ctx, points_per_sol)
+    }
+
+    /// Burn POINTS for tickets that waive a tier fee.
+    pub fn buy_ticket_with_points(
+        ctx: Context<BuyTicketWithPoints>,
+        tier: AgentTier,
+        quantity: u16,
+    ) -> Result<()> {
+        instructions::buy_ticket_with_points::handler(ctx, tier, quantity)
+    }
+
+    /// Voluntarily forfeit a running tournament.
+    pub fn forfeit(ctx: Context<Forfeit>) -> Result<()> {
+        instructions::forfeit::handler(ctx)
+    }
+
+    /// Create the heads-up ladder (admin only).
+    pub fn init_ladder(
+        ctx: Context<InitLadder>,
+        division_floors: [u16; 5],
+        k_factor: u16,
+    ) -> Result<()> {
+        instructions::init_ladder::handler(ctx, division_floors, k_factor)
+    }
+
+    /// Set the ladder's division floors and K-factor (admin only).
+    pub fn set_ladder_config(
+        ctx: Context<SetLadderConfig>,
+        division_floors: [u16; 5],
+        k_factor: u16,
+    ) -> Result<()> {
+        instructions::set_ladder_config::handler(ctx, division_floors, k_factor)
+    }
+
+    /// Start the next ladder season (admin only).
+    pub fn advance_ladder_season(ctx: Context<AdvanceLadderSeason>) -> Result<()> {
+        instructions::advance_ladder_season::handler(ctx)
+    }
+
+    /// Join the heads-up ladder.
+    pub fn join_ladder(ctx: Context<JoinLadder>) -> Result<()> {
+        instructions::join_ladder::handler(ctx)
+    }
+
+    /// Rate a completed heads-up tournament on the ladder (admin, operator or host).
+    pub fn record_ladder_match(ctx: Context<RecordLadderMatch>) -> Result<()> {
+        instructions::record_ladder_match::handler(ctx)
+    }
+
+    /// Apply season-rollover promotion or relegation to a rating (permissionless).
+    pub fn roll_over_ladder_rating(ctx: Context<RollOverLadderRating>) -> Result<()> {
+        instructions::roll_over_ladder_rating::handler(ctx)
+    }
+
+    /// Restrict a tournament to one ladder division (admin, operator or host).
+    pub fn set_tournament_division(
+        ctx: Context<SetTournamentDivision>,
+        division: Option<Division>,
+    ) -> Result<()> {
+        instructions::set_tournament_division::handler(ctx, division)
+    }
+
+    /// Set a tournament's format (admin, operator or host).
+    pub fn set_tournament_format(
+        ctx: Context<SetTournamentFormat>,
+        format: TournamentFormat,
+    ) -> Result<()> {
+        instructions::set_tournament_format::handler(ctx, format)
+    }
+
+    /// Generate a bracket from the seeded draw (admin, operator or host).
+    pub fn generate_bracket<'info>(
+        ctx: Context<'_, '_, 'info, 'info, GenerateBracket<'info>>,
+    ) -> Result<()> {
+        instructions::generate_bracket::handler(ctx)
+    }
+
+    /// Open a first-round bracket match (permissionless).
+    pub fn open_bracket_match(ctx: Context<OpenBracketMatch>, index: u16) -> Result<()> {
+        instructions::open_bracket_match::handler(ctx, index)
+    }
+
+    /// Record the winner of a bracket match (results authority).
+    pub fn record_bracket_match(
+        ctx: Context<RecordBracketMatch>,
+        side: BracketSide,
+        round: u8,
+        index: u16,
+        winner: Pubkey,
+    ) -> Result<()> {
+        instructions::record_bracket_match::handler(ctx, side, round, index, winner)
+    }
+
+    /// Create a Swiss schedule for a tournament (admin, operator or host).
+    pub fn create_swiss_event(ctx: Context<CreateSwissEvent>, rounds: u8) -> Result<()> {
+        instructions::create_swiss_event::handler(ctx, rounds)
+    }
+
+    /// Open a player's Swiss standing (permissionless).
+    pub fn init_swiss_standing(ctx: Context<InitSwissStanding>) -> Result<()> {
+        instructions::init_swiss_standing::handler(ctx)
+    }
+
+    /// Pair the next Swiss round from the standings (admin, operator or host).
+    pub fn pair_swiss_round<'info>(
+        ctx: Context<'_, '_, 'info, 'info, PairSwissRound<'info>>,
+    ) -> Result<()> {
+        instructions::pair_swiss_round::handler(ctx)
+    }
+
+    /// Record a Swiss match result (results authority).
+    pub fn record_swiss_result(ctx: Context<RecordSwissResult>) -> Result<()> {
+        instructions::record_swiss_result::handler(ctx)
+    }
+
+
+    /// Create a shootout's schedule and first round (admin, operator or host).
+    /// All registrations of the tournament are passed as remaining accounts.
+    pub fn create_shootout<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateShootout<'info>>,
+    ) -> Result<()> {
+        instructions::create_shootout::handler(ctx)
+    }
+
+    /// Open a current-round shootout table with seats drawn from the seed (permissionless).
+    ///
+    /// # Arguments
+    /// * `index` - Position of the table in the current round (0-based)
+    pub fn open_shootout_table(ctx: Context<OpenShootoutTable>, index: u16) -> Result<()> {
+        instructions::open_shootout_table::handler(ctx, index)
+    }
+
+    /// Record the winner of a shootout table (results authority).
+    ///
+    /// # Arguments
+    /// * `winner` - Wallet that won the table
+    pub fn record_shootout_table(ctx: Context<RecordShootoutTable>, winner: Pubkey) -> Result<()> {
+        instructions::record_shootout_table::handler(ctx, winner)
+    }
+
+    /// Advance a shootout whose tables are all decided to its next round (permissionless).
+    pub fn advance_shootout_round(ctx: Context<AdvanceShootoutRound>) -> Result<()> {
+        instructions::advance_shootout_round::handler(ctx)
+    }
+
+
+    /// Commit an agent's decision timeouts and time-bank usage (admin, operator or host).
+    ///
+    /// # Arguments
+    /// * `timeouts` - Decisions the agent has timed out on so far
+    /// * `time_bank_used_secs` - Seconds of time bank the agent has used so far
+    pub fn commit_time_bank(
+        ctx: Context<CommitTimeBank>,
+        timeouts: u16,
+        time_bank_used_secs: u32,
+    ) -> Result<()> {
+        instructions::commit_time_bank::handler(ctx, timeouts, time_bank_used_secs)
+    }
+
+    /// Set the POINTS penalty for excessive decision timeouts (admin only).
+    ///
+    /// # Arguments
+    /// * `threshold` - Timeouts allowed before the penalty applies
+    /// * `penalty_points` - POINTS deducted (0 disables the penalty)
+    pub fn set_timeout_penalty(
+        ctx: Context<SetTimeoutPenalty>,
+        threshold: u16,
+        penalty_points: u64,
+    ) -> Result<()> {
+        instructions::set_timeout_penalty::handler(ctx, threshold, penalty_points)
+    }
+
+
+    /// Turn optional subsystems on or off (admin only).
+    ///
+    /// # Arguments
+    /// * `feature_flags` - Bitmask of `ArenaConfig::FEATURE_*` flags to enable
+    pub fn set_feature_flags(ctx: Context<SetFeatureFlags>, feature_flags: u64) -> Result<()> {
+        instructions::set_feature_flags::handler(ctx, feature_flags)
+    }
+
+
+    /// Queue a timelocked sweep of assets stranded on the mint authority or a program vault (admin only).
+    ///
+    /// # Arguments
+    /// * `source` - Mint authority or vault PDA holding the stranded assets
+    /// * `mint` - Token mint to sweep, or None for lamports
+    /// * `amount` - Amount to sweep (lamports or token base units)
+    pub fn queue_asset_recovery(
+        ctx: Context<QueueAssetRecovery>,
+        source: Pubkey,
+        mint: Option<Pubkey>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::queue_asset_recovery::handler(ctx, source, mint, amount)
+    }
+
+    /// Sweep stranded assets to the treasury once the queued sweep's timelock passes (admin only).
+    pub fn recover_stranded_assets(ctx: Context<RecoverStrandedAssets>) -> Result<()> {
+        instructions::recover_stranded_assets::handler(ctx)
+    }
+
+
+    /// Set the key that records results and finalizes tournaments (admin only).
+    ///
+    /// # Arguments
+    /// * `results_authority` - Key allowed to record results (Pubkey::default() to unset)
+    pub fn set_results_authority(
+        ctx: Context<SetResultsAuthority>,
+        results_authority: Pubkey,
+    ) -> Result<()> {
+        instructions::set_results_authority::handler(ctx, results_authority)
+    }
+
+    /// Set the mint USDC-denominated prize pools are paid in (admin only).
+    pub fn set_usdc_mint(ctx: Context<SetUsdcMint>) -> Result<()> {
+        instructions::set_usdc_mint::handler(ctx)
+    }
+
+    /// Create an empty prize pool in the tournament's denomination (admin, operator or host).
+    ///
+    /// # Arguments
+    /// * `payout_bps` - Share of the pool paid to each finishing place, best first
+    /// * `dust_policy` - Where the rounding remainder of the split goes
+    pub fn create_prize_pool(
+        ctx: Context<CreatePrizePool>,
+        payout_bps: [u16; PrizePool::MAX_PAID_PLACES],
+        dust_policy: DustPolicy,
+    ) -> Result<()> {
+        instructions::create_prize_pool::handler(ctx, payout_bps, dust_policy)
+    }
+
+    /// Add SOL or USDC to a tournament's prize pool, or commit POINTS to be
+    /// minted on claim (POINTS commitments are admin only).
+    ///
+    /// # Arguments
+    /// * `amount` - Amount to add, in the pool denomination's base units
+    pub fn fund_prize_pool(ctx: Context<FundPrizePool>, amount: u64) -> Result<()> {
+        instructions::fund_prize_pool::handler(ctx, amount)
+    }
+
+    /// Pay a finishing place its share of the prize pool once results are
+    /// confirmed (permissionless).
+    pub fn claim_prize(ctx: Context<ClaimPrize>) -> Result<()> {
+        instructions::claim_prize::handler(ctx)
+    }
+
+    /// Pay an action buyer their cut of the seller's prize once results are
+    /// confirmed.
+    pub fn claim_action_prize(ctx: Context<ClaimActionPrize>) -> Result<()> {
+        instructions::claim_action_prize::handler(ctx)
+    }
+
+    /// Pay a backer their cut of a withdrawn-backing player's prize once
+    /// results are confirmed.
+    pub fn claim_backer_prize(ctx: Context<ClaimBackerPrize>) -> Result<()> {
+        instructions::claim_backer_prize::handler(ctx)
+    }
+
+    /// Pay a prize pool's rounding remainder to the treasury once results
+    /// are confirmed (permissionless).
+    pub fn sweep_prize_dust(ctx: Context<SweepPrizeDust>) -> Result<()> {
+        instructions::sweep_prize_dust::handler(ctx)
+    }
+
+    /// Keep registration open for a period past the scheduled start (admin, operator or host).
+    ///
+    /// # Arguments
+    /// * `late_registration_secs` - Seconds past `starts_at` registration stays open
+    pub fn set_late_registration(
+        ctx: Context<SetLateRegistration>,
+        late_registration_secs: u32,
+    ) -> Result<()> {
+        instructions::set_late_registration::handler(ctx, late_registration_secs)
+    }
+
+    /// Commit the points-award formula recorded results must match (admin, operator or host).
+    ///
+    /// # Arguments
+    /// * `points_formula` - Formula to commit, or None to stop checking awards
+    pub fn set_points_formula(
+        ctx: Context<SetPointsFormula>,
+        points_formula: Option<PointsFormula>,
+    ) -> Result<()> {
+        instructions::set_points_formula::handler(ctx, points_formula)
+    }
+
+    /// Create the log of admin and operator calls and turn auditing on (admin only).
+    pub fn init_audit_log(ctx: Context<InitAuditLog>) -> Result<()> {
+        instructions::init_audit_log::handler(ctx)
+    }
+
+    /// Cap tournaments created per day and open at once (admin only; 0 = unlimited).
+    pub fn set_tournament_rate_limits(ctx: Context<SetTournamentRateLimits>, max_per_day: u16, max_open: u16) -> Result<()> {
+        instructions::set_tournament_rate_limits::handler(ctx, max_per_day, max_open)
+    }
+
+    /// Price tier fees in USD cents via the Pyth SOL/USD feed (admin only; zero feed id disables).
+    pub fn set_usd_tier_fees(ctx: Context<SetUsdTierFees>, tier_fee_usd_cents: [u32; 3], sol_usd_feed_id: [u8; 32]) -> Result<()> {
+        instructions::set_usd_tier_fees::handler(ctx, tier_fee_usd_cents, sol_usd_feed_id)
+    }
+
+    /// Escrow a tournament's tier fees until it starts (admin, operator or host).
+    pub fn create_fee_escrow(ctx: Context<CreateFeeEscrow>, to_prize_pool: bool) -> Result<()> {
+        instructions::create_fee_escrow::handler(ctx, to_prize_pool)
+    }
+
+    /// Release a started tournament's escrowed tier fees (permissionless).
+    pub fn release_fee_escrow<'info>(ctx: Context<'_, '_, 'info, 'info, ReleaseFeeEscrow<'info>>) -> Result<()> {
+        instructions::release_fee_escrow::handler(ctx)
+    }
+
+    /// Refund a tier fee from the escrow of a cancelled or voided tournament (permissionless).
+    pub fn refund_escrowed_fee(ctx: Context<RefundEscrowedFee>) -> Result<()> {
+        instructions::refund_escrowed_fee::handler(ctx)
+    }
+
+    /// Set the refund share for unregistering after registration closes (admin only).
+    pub fn set_late_unregister_refund(ctx: Context<SetLateUnregisterRefund>, refund_bps: u16) -> Result<()> {
+        instructions::set_late_unregister_refund::handler(ctx, refund_bps)
+    }
+
+    /// Unregister from a tournament before it starts, refunded by the refund schedule.
+    pub fn unregister_player<'info>(ctx: Context<'_, '_, 'info, 'info, UnregisterPlayer<'info>>) -> Result<()> {
+        instructions::unregister_player::handler(ctx)
+    }
+
+    /// Create invites to a tournament in bulk, making it invite-only (admin, operator or host).
+    pub fn create_invites<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateInvites<'info>>,
+        code_hashes: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::create_invites::handler(ctx, code_hashes)
+    }
+
+    /// Revoke an unused invite (admin, operator or host).
+    pub fn revoke_invite(ctx: Context<RevokeInvite>) -> Result<()> {
+        instructions::revoke_invite::handler(ctx)
+    }
+
+    /// Redeem an invite by revealing its code.
+    pub fn redeem_invite(ctx: Context<RedeemInvite>, code: Vec<u8>) -> Result<()> {
+        instructions::redeem_invite::handler(ctx, code)
+    }
+
+    /// Create the signing wallet's registration index.
+    pub fn init_registration_index(ctx: Context<InitRegistrationIndex>) -> Result<()> {
+        instructions::init_registration_index::handler(ctx)
+    }
+
+    /// Appoint or remove a commentator (admin only).
+    pub fn set_commentator(ctx: Context<SetCommentator>, key: Pubkey, active: bool) -> Result<()> {
+        instructions::set_commentator::handler(ctx, key, active)
+    }
+
+    /// Anchor a commentary or highlight metadata hash to a tournament (commentators only).
+    pub fn post_commentary(ctx: Context<PostCommentary>, content_hash: [u8; 32]) -> Result<()> {
+        instructions::post_commentary::handler(ctx, content_hash)
+    }
+
+    /// Bookmark a notable hand on the tournament's highlights (admin, operator or host).
+    pub fn record_highlight(ctx: Context<RecordHighlight>, hand_index: u32, tag: HighlightTag) -> Result<()> {
+        instructions::record_highlight::handler(ctx, hand_index, tag)
+    }
+
+    /// Freeze a player's POINTS token account during an investigation (admin only).
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing all accounts
+    /// * `reason_hash` - SHA-256 hash of the off-chain investigation record
+    pub fn freeze_player_points(ctx: Context<FreezePlayerPoints>, reason_hash: [u8; 32]) -> Result<()> {
+        instructions::freeze_player_points::handler(ctx, reason_hash)
+    }
+
+    /// Thaw a player's frozen POINTS token account (admin only).
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing all accounts
+    /// * `reason_hash` - SHA-256 hash of the off-chain record closing the investigation
+    pub fn thaw_player_points(ctx: Context<ThawPlayerPoints>, reason_hash: [u8; 32]) -> Result<()> {
+        instructions::thaw_player_points::handler(ctx, reason_hash)
+    }
+
+    /// Queue a timelocked transfer or revocation of the POINTS mint authority (admin only).
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing all accounts
+    /// * `new_authority` - PDA or program to hand minting to, or None to revoke minting
+    pub fn queue_mint_authority_change(
+        ctx: Context<QueueMintAuthorityChange>,
+        new_authority: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::queue_mint_authority_change::handler(ctx, new_authority)
+    }
+
+    /// Hand over or revoke the POINTS mint authority once the queued change's timelock passes (admin only).
+    pub fn execute_mint_authority_change(ctx: Context<ExecuteMintAuthorityChange>) -> Result<()> {
+        instructions::execute_mint_authority_change::handler(ctx)
+    }
+
+    /// Create a season-scoped POINTS mint managed by the mint authority PDA (admin only).
+    pub fn create_season_mint(ctx: Context<CreateSeasonMint>) -> Result<()> {
+        instructions::create_season_mint::handler(ctx)
+    }
+
+    /// Award a tournament's POINTS in its season's mint (admin or operator).
+    pub fn set_season_mint(ctx: Context<SetSeasonMint>) -> Result<()> {
+        instructions::set_season_mint::handler(ctx)
+    }
+
+    /// Publish a completed tournament's results in a fixed layout for external programs (permissionless).
+    pub fn publish_results_attestation(ctx: Context<PublishResultsAttestation>) -> Result<()> {
+        instructions::publish_results_attestation::handler(ctx)
+    }
+
+    /// Approve, update or suspend a third-party tournament host (admin only).
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing all accounts
+    /// * `authority` - Host's signing key
+    /// * `treasury` - Wallet receiving the host's share of tier fees
+    /// * `platform_fee_bps` - Arena's share of hosted tier fees, in basis points
+    /// * `active` - Whether the host may create tournaments
+    pub fn set_host(
+        ctx: Context<SetHost>,
+        authority: Pubkey,
+        treasury: Pubkey,
+        platform_fee_bps: u16,
+        active: bool,
+    ) -> Result<()> {
+        instructions::set_host::handler(ctx, authority, treasury, platform_fee_bps, active)
+    }
+
+    /// Create a tournament run by an approved host, with its fees escrowed (host only).
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing all accounts
+    /// * `max_players` - Maximum number of players allowed
+    /// * `starting_stack` - Starting chip stack for each player
+    /// * `starts_at` - Unix timestamp when tournament is scheduled to start
+    /// * `blind_structure_hash` - SHA-256 hash of the blind structure JSON (zeros for presets)
+    /// * `payout_structure_hash` - SHA-256 hash of the host's payout table
+    /// * `reserved_seats` - Seats held back for sponsor and exhibition agents
+    /// * `blind_preset` - Built-in blind structure, or Custom to use `blind_structure_hash`
+    /// * `prize_denomination` - Currency the prize pool is paid in
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_host_tournament(
+        ctx: Context<CreateHostTournament>,
+        max_players: u16,
+        starting_stack: u64,
+        starts_at: i64,
+        blind_structure_hash: [u8; 32],
+        payout_structure_hash: [u8; 32],
+        reserved_seats: u16,
+        blind_preset: BlindPreset,
+        prize_denomination: PrizeDenomination,
+    ) -> Result<()> {
+        instructions::create_host_tournament::handler(
+            ctx,
+            max_players,
+            starting_stack,
+            starts_at,
+            blind_structure_hash,
+            payout_structure_hash,
+            reserved_seats,
+            blind_preset,
+            prize_denomination,
+        )
+    }
+
+    /// Set the bond a host must hold to create tournaments (admin only).
+    pub fn set_host_bond(ctx: Context<SetHostBond>, bond_lamports: u64) -> Result<()> {
+        instructions::set_host_bond::handler(ctx, bond_lamports)
+    }
+
+    /// Add SOL to a host's bond (host only).
+    pub fn post_host_bond(ctx: Context<PostHostBond>, amount: u64) -> Result<()> {
+        instructions::post_host_bond::handler(ctx, amount)
+    }
+
+    /// Withdraw SOL from a host's bond, keeping the required bond while events are open (host only).
+    pub fn withdraw_host_bond(ctx: Context<WithdrawHostBond>, amount: u64) -> Result<()> {
+        instructions::withdraw_host_bond::handler(ctx, amount)
+    }
+
+    /// Slash a host's bond for a disputed tournament it failed to finalize or that was voided (admin only).
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing all accounts
+    /// * `amount` - Lamports to slash (capped at the posted bond)
+    /// * `reason_hash` - SHA-256 hash of the off-chain dispute ruling
+    pub fn slash_host_bond(ctx: Context<SlashHostBond>, amount: u64, reason_hash: [u8; 32]) -> Result<()> {
+        instructions::slash_host_bond::handler(ctx, amount, reason_hash)
+    }
 }
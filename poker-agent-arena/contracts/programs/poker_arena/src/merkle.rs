@@ -0,0 +1,32 @@
+use anchor_lang::solana_program::hash::hashv;
+
+/// Domain separation prefix for leaf hashes
+const LEAF_PREFIX: &[u8] = &[0];
+
+/// Domain separation prefix for internal node hashes
+const NODE_PREFIX: &[u8] = &[1];
+
+/// Hash a leaf from its serialized parts: SHA-256(0x00 || parts...)
+pub fn leaf_hash(parts: &[&[u8]]) -> [u8; 32] {
+    let mut data: Vec<&[u8]> = Vec::with_capacity(parts.len() + 1);
+    data.push(LEAF_PREFIX);
+    data.extend_from_slice(parts);
+    hashv(&data).to_bytes()
+}
+
+/// Hash two sibling nodes in sorted order: SHA-256(0x01 || min || max)
+pub fn node_hash(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    if a <= b {
+        hashv(&[NODE_PREFIX, a, b]).to_bytes()
+    } else {
+        hashv(&[NODE_PREFIX, b, a]).to_bytes()
+    }
+}
+
+/// Verify a sorted-pair Merkle proof for `leaf` against `root`
+pub fn verify_proof(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+    let computed = proof
+        .iter()
+        .fold(leaf, |node, sibling| node_hash(&node, sibling));
+    computed == *root
+}
@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+
+/// Pyth pull-oracle receiver program, owner of posted price updates
+pub mod pyth_receiver {
+    anchor_lang::declare_id!("rec5EKMGg6MxZYaMdyBfgwp4d5rB9T1VQH5pJv5LtFJ");
+}
+
+/// Pyth receiver program ID
+pub const PYTH_RECEIVER_ID: Pubkey = pyth_receiver::ID;
+
+/// Anchor discriminator of the receiver's PriceUpdateV2 account
+const PRICE_UPDATE_V2_DISCRIMINATOR: [u8; 8] = [34, 241, 35, 99, 157, 126, 244, 205];
+
+/// Oldest price update accepted, in seconds
+pub const MAX_PRICE_AGE_SECONDS: i64 = 60;
+
+/// Lamports per SOL
+const LAMPORTS_PER_SOL: u128 = 1_000_000_000;
+
+/// Price read from a posted Pyth price update
+#[derive(Clone, Copy, Debug)]
+pub struct Price {
+    /// Price mantissa
+    pub price: i64,
+    /// Power of ten the mantissa is scaled by
+    pub exponent: i32,
+    /// Unix timestamp the price was published
+    pub publish_time: i64,
+}
+
+impl Price {
+    /// Load the price for `feed_id` from a PriceUpdateV2 account, rejecting
+    /// updates that are partially verified, non-positive, or stale at `now`.
+    ///
+    /// Layout: discriminator (8), write authority (32), verification level
+    /// (Partial = 0 + u8, Full = 1), then the price message: feed id (32),
+    /// price (i64), confidence (u64), exponent (i32), publish time (i64).
+    pub fn load(account: &AccountInfo, feed_id: &[u8; 32], now: i64) -> Result<Self> {
+        require_keys_eq!(
+            *account.owner,
+            PYTH_RECEIVER_ID,
+            ArenaError::InvalidPriceFeed
+        );
+        let data = account.try_borrow_data()?;
+
+        // Only fully verified updates; partial ones skip the Wormhole quorum
+        let message = data
+            .get(..8)
+            .filter(|discriminator| *discriminator == PRICE_UPDATE_V2_DISCRIMINATOR)
+            .and_then(|_| data.get(40))
+            .filter(|verification_level| **verification_level == 1)
+            .and_then(|_| data.get(41..41 + 32 + 8 + 8 + 4 + 8))
+            .ok_or(ArenaError::InvalidPriceFeed)?;
+        require!(message[..32] == feed_id[..], ArenaError::InvalidPriceFeed);
+
+        let price = Self {
+            price: i64::from_le_bytes(message[32..40].try_into().unwrap()),
+            exponent: i32::from_le_bytes(message[48..52].try_into().unwrap()),
+            publish_time: i64::from_le_bytes(message[52..60].try_into().unwrap()),
+        };
+        require!(price.price > 0, ArenaError::InvalidPriceFeed);
+        require!(
+            now.saturating_sub(price.publish_time) <= MAX_PRICE_AGE_SECONDS,
+            ArenaError::StalePriceFeed
+        );
+        Ok(price)
+    }
+
+    /// Convert a USD amount in cents to lamports at this SOL/USD price,
+    /// rounded down
+    pub fn usd_cents_to_lamports(&self, cents: u64) -> Result<u64> {
+        // lamports = cents / 100 * LAMPORTS_PER_SOL / (price * 10^exponent)
+        let scale = 10u128
+            .checked_pow(self.exponent.unsigned_abs())
+            .ok_or(ArenaError::ArithmeticOverflow)?;
+        let numerator = cents as u128 * LAMPORTS_PER_SOL / 100;
+        let lamports = if self.exponent < 0 {
+            numerator.checked_mul(scale).map(|n| n / self.price as u128)
+        } else {
+            (self.price as u128)
+                .checked_mul(scale)
+                .map(|denominator| numerator / denominator)
+        };
+        lamports
+            .and_then(|lamports| u64::try_from(lamports).ok())
+            .ok_or(ArenaError::ArithmeticOverflow.into())
+    }
+}
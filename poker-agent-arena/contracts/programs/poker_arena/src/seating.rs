@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+use crate::state::Table;
+
+/// Domain separation tag for seat draw keys
+const SEATING_TAG: &[u8] = b"seating";
+
+/// Domain separation tag for Swiss pairing keys
+const SWISS_TAG: &[u8] = b"swiss";
+
+/// Domain separation tag for shootout seat draw keys
+const SHOOTOUT_TAG: &[u8] = b"shootout";
+
+/// Number of tables needed to seat `player_count` players
+pub fn table_count(player_count: usize) -> usize {
+    player_count.div_ceil(Table::MAX_SEATS)
+}
+
+/// Draw key for a player: SHA-256(seed || "seating" || wallet)
+pub fn draw_key(seed: &[u8; 32], wallet: &Pubkey) -> [u8; 32] {
+    hashv(&[seed, SEATING_TAG, wallet.as_ref()]).to_bytes()
+}
+
+/// Order the roster by draw key.
+///
+/// The roster is sorted first so the draw does not depend on the order
+/// registrations were supplied in.
+pub fn draw_order(seed: &[u8; 32], roster: &[Pubkey]) -> Vec<Pubkey> {
+    let mut order = roster.to_vec();
+    order.sort();
+    order.sort_by_cached_key(|wallet| draw_key(seed, wallet));
+    order
+}
+
+/// Players meeting in first-round bracket match `index` of a `size` bracket.
+///
+/// The i-th drawn player meets the (size - 1 - i)-th, so when the field does
+/// not fill the bracket the earliest drawn players receive the byes
+/// (Pubkey::default()).
+pub fn bracket_pairing(draw: &[Pubkey], size: usize, index: usize) -> (Pubkey, Pubkey) {
+    let player = |position: usize| draw.get(position).copied().unwrap_or_default();
+    (player(index), player(size - 1 - index))
+}
+
+/// Pairing order for Swiss `round`: by score (descending), ties broken by
+/// SHA-256(seed || "swiss" || round || wallet). Adjacent players are paired;
+/// with an odd field the last player receives the bye.
+pub fn swiss_order(seed: &[u8; 32], round: u8, standings: &[(Pubkey, u8)]) -> Vec<Pubkey> {
+    let mut order = standings.to_vec();
+    order.sort();
+    order.sort_by_cached_key(|(wallet, score)| {
+        (
+            core::cmp::Reverse(*score),
+            hashv(&[seed, SWISS_TAG, &[round], wallet.as_ref()]).to_bytes(),
+        )
+    });
+    order.into_iter().map(|(wallet, _)| wallet).collect()
+}
+
+/// Initial seats at `table_number` (1-based) for the seeded draw.
+///
+/// Players are dealt round-robin across tables in draw order, so the
+/// i-th drawn player sits at table `i % tables`, seat `i / tables`.
+pub fn initial_table_seats(
+    seed: &[u8; 32],
+    roster: &[Pubkey],
+    table_number: u16,
+) -> [Pubkey; Table::MAX_SEATS] {
+    let tables = table_count(roster.len());
+    if table_number == 0 || table_number as usize > tables {
+        return [Pubkey::default(); Table::MAX_SEATS];
+    }
+
+    deal_seats(&draw_order(seed, roster), tables, table_number as usize - 1)
+}
+
+/// Seats at table `table_index` (0-based) of shootout `round`.
+///
+/// The round's field is ordered by SHA-256(seed || "shootout" || round ||
+/// wallet), so every round gets a fresh draw, then dealt round-robin across
+/// the round's tables like the initial seating.
+pub fn shootout_seats(
+    seed: &[u8; 32],
+    round: u8,
+    players: &[Pubkey],
+    table_index: usize,
+) -> [Pubkey; Table::MAX_SEATS] {
+    let mut order = players.to_vec();
+    order.sort();
+    order.sort_by_cached_key(|wallet| {
+        hashv(&[seed, SHOOTOUT_TAG, &[round], wallet.as_ref()]).to_bytes()
+    });
+    deal_seats(&order, table_count(players.len()), table_index)
+}
+
+/// Seats at table `table_index` when `order` is dealt round-robin across
+/// `tables` tables: the i-th player sits at table `i % tables`, seat `i / tables`.
+fn deal_seats(order: &[Pubkey], tables: usize, table_index: usize) -> [Pubkey; Table::MAX_SEATS] {
+    let mut seats = [Pubkey::default(); Table::MAX_SEATS];
+    for (i, wallet) in order.iter().enumerate() {
+        if i % tables == table_index {
+            seats[i / tables] = *wallet;
+        }
+    }
+    seats
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+/// Hash chain of an agent's decisions, committed by the engine during play.
+/// Each batch covers HANDS_PER_BATCH hands; the head commits to every batch so far.
+#[account]
+pub struct ActionLog {
+    /// Registration whose decisions are logged
+    pub registration: Pubkey,
+
+    /// Tournament the registration belongs to
+    pub tournament: Pubkey,
+
+    /// Current head of the hash chain (zeroed before the first batch)
+    pub chain_head: [u8; 32],
+
+    /// Number of batches committed so far
+    pub batches_committed: u32,
+
+    /// Unix timestamp of the most recent commitment
+    pub last_committed_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ActionLog {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 32 + 32 + 32 + 4 + 8 + 1 = 117 bytes
+    pub const SIZE: usize = 8 + 32 + 32 + 32 + 4 + 8 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"action_log";
+
+    /// Number of hands covered by each committed batch
+    pub const HANDS_PER_BATCH: u32 = 100;
+
+    /// Compute the next chain head: SHA-256(prev_head || batch_hash)
+    pub fn next_head(prev_head: &[u8; 32], batch_hash: &[u8; 32]) -> [u8; 32] {
+        hashv(&[prev_head, batch_hash]).to_bytes()
+    }
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+/// A registered player's offer to sell a percentage of their winnings.
+#[account]
+pub struct ActionListing {
+    /// Registration whose action is for sale
+    pub registration: Pubkey,
+
+    /// Seller's wallet (the registered player)
+    pub seller: Pubkey,
+
+    /// Share still for sale, in basis points
+    pub available_bps: u16,
+
+    /// Price per basis point of share, in lamports
+    pub price_lamports_per_bps: u64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ActionListing {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 32 + 32 + 2 + 8 + 1 = 83 bytes
+    pub const SIZE: usize = 8 + 32 + 32 + 2 + 8 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"action_listing";
+
+    /// Marketplace fee taken by the treasury on each purchase, in basis points
+    pub const MARKETPLACE_FEE_BPS: u64 = 250;
+}
+
+/// Share of a player's winnings bought from an action listing.
+#[account]
+pub struct ActionShare {
+    /// Registration the share is in
+    pub registration: Pubkey,
+
+    /// Buyer's wallet
+    pub buyer: Pubkey,
+
+    /// Share of the player's winnings, in basis points
+    pub share_bps: u16,
+
+    /// Whether the buyer has claimed their POINTS
+    pub claimed: bool,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ActionShare {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 32 + 32 + 2 + 1 + 1 = 76 bytes
+    pub const SIZE: usize = 8 + 32 + 32 + 2 + 1 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"action_share";
+}
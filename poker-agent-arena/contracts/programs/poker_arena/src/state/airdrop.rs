@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+
+use crate::merkle;
+
+/// Claim-based POINTS airdrop to a Merkle-committed set of wallets.
+/// The full allocation is minted into the airdrop vault up front; whatever
+/// is unclaimed at expiry is burned.
+#[account]
+pub struct Airdrop {
+    /// Admin-chosen airdrop ID
+    pub id: u64,
+
+    /// Merkle root over leaves SHA-256(0x00 || airdrop || wallet || amount_le)
+    pub root: [u8; 32],
+
+    /// POINTS minted into the vault for claims
+    pub total_allocation: u64,
+
+    /// POINTS claimed so far
+    pub claimed: u64,
+
+    /// Unix timestamp after which claims close and the remainder can be burned
+    pub expires_at: i64,
+
+    /// POINTS burned at expiry
+    pub burned: u64,
+
+    /// Unclaimed allocation has been burned
+    pub finalized: bool,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Airdrop {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 8 + 32 + 8 + 8 + 8 + 8 + 1 + 1 = 82 bytes
+    pub const SIZE: usize = 8 + 8 + 32 + 8 + 8 + 8 + 8 + 1 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"airdrop";
+
+    /// PDA seeds prefix for an airdrop's POINTS vault
+    pub const VAULT_SEED_PREFIX: &'static [u8] = b"airdrop_vault";
+
+    /// Whether claims are still open at `now`
+    pub fn is_open(&self, now: i64) -> bool {
+        now < self.expires_at
+    }
+
+    /// Whether `wallet` is allocated `amount` by this airdrop
+    pub fn verify(
+        &self,
+        airdrop: &Pubkey,
+        wallet: &Pubkey,
+        amount: u64,
+        proof: &[[u8; 32]],
+    ) -> bool {
+        let leaf = merkle::leaf_hash(&[airdrop.as_ref(), wallet.as_ref(), &amount.to_le_bytes()]);
+        merkle::verify_proof(proof, &self.root, leaf)
+    }
+}
+
+/// Marker recording that a wallet claimed its airdrop allocation.
+/// Its existence prevents double claims.
+#[account]
+pub struct AirdropClaim {
+    /// Airdrop the claim was made against
+    pub airdrop: Pubkey,
+
+    /// Claiming wallet
+    pub wallet: Pubkey,
+
+    /// POINTS claimed (base units)
+    pub amount: u64,
+
+    /// Unix timestamp of the claim
+    pub claimed_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl AirdropClaim {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 32 + 32 + 8 + 8 + 1 = 89 bytes
+    pub const SIZE: usize = 8 + 32 + 32 + 8 + 8 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"airdrop_claim";
+}
@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+
+use crate::state::ArbiterSet;
+
+/// Appeal status enum
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum AppealStatus {
+    /// Awaiting resolution; further slashing is stalled
+    #[default]
+    Open,
+    /// Resolved in the player's favor
+    Granted,
+    /// Resolved against the player
+    Denied,
+}
+
+/// What the sanctions an appeal may still overturn took from a player, so
+/// that granting the appeal restores exactly what was applied.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct AppealableSanction {
+    /// POINTS removed from the award before distribution
+    pub award_points: u64,
+
+    /// POINTS removed from the player's PointsEscrow
+    pub escrowed_points: u64,
+
+    /// Distributed POINTS burned from the player's token account
+    pub burned_points: u64,
+
+    /// Whether the player was disqualified
+    pub disqualified: bool,
+}
+
+impl AppealableSanction {
+    /// Serialized size
+    /// 8 + 8 + 8 + 1 = 25 bytes
+    pub const SIZE: usize = 8 + 8 + 8 + 1;
+
+    /// Total POINTS the sanctions removed
+    pub fn points(&self) -> u64 {
+        self.award_points
+            .saturating_add(self.escrowed_points)
+            .saturating_add(self.burned_points)
+    }
+}
+
+/// Player appeal against a penalty or disqualification. One appeal may be
+/// opened per sanction, identified by the registration's sanction nonce;
+/// it is resolved by the admin or by the arbiter committee.
+#[account]
+pub struct Appeal {
+    /// Tournament the sanction relates to
+    pub tournament: Pubkey,
+
+    /// Registration that was sanctioned
+    pub registration: Pubkey,
+
+    /// Appealing player's wallet
+    pub wallet: Pubkey,
+
+    /// SHA-256 hash of the off-chain appeal statement
+    pub reason_hash: [u8; 32],
+
+    /// Current appeal status
+    pub status: AppealStatus,
+
+    /// Unix timestamp when the appeal was opened
+    pub opened_at: i64,
+
+    /// Unix timestamp when the appeal was resolved (None while open)
+    pub resolved_at: Option<i64>,
+
+    /// Registration's sanction nonce the appeal was opened against
+    pub sanction_nonce: u16,
+
+    /// ArbiterSet version the votes were cast under
+    pub arbiter_set_version: u32,
+
+    /// Bit i set once arbiter members[i] has voted to grant the appeal
+    pub grant_mask: u8,
+
+    /// Bit i set once arbiter members[i] has voted to deny the appeal
+    pub deny_mask: u8,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Appeal {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 32 + 32 + 32 + 32 + 1 + 8 + 9 + 2 + 4 + 1 + 1 + 1 = 163 bytes
+    pub const SIZE: usize = 8 + 32 + 32 + 32 + 32 + 1 + 8 + 9 + 2 + 4 + 1 + 1 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"appeal";
+
+    /// Seconds after a sanction during which the player may appeal (48 hours)
+    pub const WINDOW_SECONDS: i64 = 48 * 60 * 60;
+
+    /// Whether the current arbiter committee has reached its threshold on
+    /// granting (or denying) the appeal
+    pub fn committee_decided(&self, arbiter_set: &ArbiterSet, granted: bool) -> bool {
+        let mask = if granted {
+            self.grant_mask
+        } else {
+            self.deny_mask
+        };
+        self.arbiter_set_version == arbiter_set.version
+            && arbiter_set.threshold > 0
+            && mask.count_ones() >= arbiter_set.threshold as u32
+    }
+}
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+/// Registry entry for an LLM model approved for PRO agents.
+#[account]
+pub struct ApprovedModel {
+    /// SHA-256 hash of the model identifier
+    pub model_id_hash: [u8; 32],
+
+    /// Whether new PRO registrations may use this model
+    pub active: bool,
+
+    /// Unix timestamp of the last approval change
+    pub updated_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ApprovedModel {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 32 + 1 + 8 + 1 = 50 bytes
+    pub const SIZE: usize = 8 + 32 + 1 + 8 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"approved_model";
+}
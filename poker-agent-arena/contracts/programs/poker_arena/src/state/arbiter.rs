@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+
+/// Committee of arbiters that attests results of disputed tournaments.
+#[account]
+pub struct ArbiterSet {
+    /// Arbiter keys (Pubkey::default() for unused slots)
+    pub members: [Pubkey; ArbiterSet::MAX_MEMBERS],
+
+    /// Attestations required to finalize a disputed tournament
+    pub threshold: u8,
+
+    /// Incremented on every change so stale attestations stop counting
+    pub version: u32,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ArbiterSet {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 224 + 1 + 4 + 1 = 238 bytes
+    pub const SIZE: usize = 8 + 224 + 1 + 4 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"arbiter_set";
+
+    /// Maximum number of arbiters
+    pub const MAX_MEMBERS: usize = 7;
+
+    /// Slot index of `key` in the committee
+    pub fn member_index(&self, key: &Pubkey) -> Option<usize> {
+        if *key == Pubkey::default() {
+            return None;
+        }
+        self.members.iter().position(|member| member == key)
+    }
+}
+
+/// Arbiter signatures collected for one proposed result of a tournament.
+#[account]
+pub struct ArbiterAttestation {
+    /// Tournament being attested
+    pub tournament: Pubkey,
+
+    /// Results hash the arbiters agree on
+    pub results_hash: [u8; 32],
+
+    /// Winner the arbiters agree on
+    pub winner: Pubkey,
+
+    /// ArbiterSet version the signatures were collected under
+    pub arbiter_set_version: u32,
+
+    /// Bit i set once members[i] has attested
+    pub signer_mask: u8,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ArbiterAttestation {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 32 + 32 + 32 + 4 + 1 + 1 = 110 bytes
+    pub const SIZE: usize = 8 + 32 + 32 + 32 + 4 + 1 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"arbiter_attestation";
+
+    /// Whether enough current arbiters have attested
+    pub fn meets_threshold(&self, arbiter_set: &ArbiterSet) -> bool {
+        self.arbiter_set_version == arbiter_set.version
+            && self.signer_mask.count_ones() >= arbiter_set.threshold as u32
+    }
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+/// Queued sweep of assets stranded on a program-owned PDA: the POINTS mint
+/// authority or one of the program's vaults (redemption, crank, guild, fee
+/// escrow, prize pool). Only what the source does not still hold for
+/// someone can be swept; the sweep waits out a timelock so watchers can see
+/// it coming.
+#[account]
+pub struct AssetRecovery {
+    /// PDA holding the stranded assets
+    pub source: Pubkey,
+
+    /// Token mint to sweep (None = lamports above rent exemption)
+    pub mint: Option<Pubkey>,
+
+    /// Amount to sweep (lamports or token base units)
+    pub amount: u64,
+
+    /// Unix timestamp the sweep was queued
+    pub queued_at: i64,
+
+    /// Unix timestamp after which the sweep may be executed
+    pub executable_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl AssetRecovery {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 32 + 33 + 8 + 8 + 8 + 1 = 98 bytes
+    pub const SIZE: usize = 8 + 32 + 33 + 8 + 8 + 8 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"asset_recovery";
+
+    /// Delay between queueing and executing a sweep
+    pub const TIMELOCK_SECONDS: i64 = 2 * 24 * 60 * 60;
+}
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+/// One admin or operator call that changed program state.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct AuditEntry {
+    /// Position in the full audit history
+    pub sequence: u64,
+
+    /// Admin, operator or results authority that signed the call
+    pub actor: Pubkey,
+
+    /// Anchor discriminator of the instruction called
+    pub instruction: [u8; 8],
+
+    /// Tournament the call acted on (0 for arena-wide calls)
+    pub tournament_id: u64,
+
+    /// Slot the call landed in
+    pub slot: u64,
+}
+
+/// Append-only ring of the most recent admin and operator calls.
+/// Every entry is also logged, so the full history can be rebuilt from
+/// transaction logs by sequence number.
+#[account]
+pub struct AuditLog {
+    /// Sequence number the next entry receives (total entries ever recorded)
+    pub next_sequence: u64,
+
+    /// Most recent entries; entry `sequence` lives at `sequence % MAX_ENTRIES`
+    pub entries: [AuditEntry; AuditLog::MAX_ENTRIES],
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl AuditLog {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 8 + (64 * 64) + 1 = 4113 bytes
+    pub const SIZE: usize = 8 + 8 + (64 * Self::MAX_ENTRIES) + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"audit_log";
+
+    /// Number of entries kept in the ring buffer
+    pub const MAX_ENTRIES: usize = 64;
+
+    /// Append a call, overwriting the oldest entry once full
+    pub fn record(&mut self, actor: Pubkey, instruction: [u8; 8], tournament_id: u64, slot: u64) {
+        let sequence = self.next_sequence;
+        self.entries[(sequence % Self::MAX_ENTRIES as u64) as usize] = AuditEntry {
+            sequence,
+            actor,
+            instruction,
+            tournament_id,
+            slot,
+        };
+        self.next_sequence = sequence.saturating_add(1);
+
+        msg!(
+            "Audit #{}: {} called {:?} on tournament {}",
+            sequence,
+            actor,
+            instruction,
+            tournament_id
+        );
+    }
+
+    /// Record a call to instruction `I` into `audit_log`
+    pub fn record_call<I: Discriminator>(
+        audit_log: &mut Account<AuditLog>,
+        actor: Pubkey,
+        tournament_id: u64,
+    ) -> Result<()> {
+        audit_log.record(actor, I::DISCRIMINATOR, tournament_id, Clock::get()?.slot);
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+
+/// Escrow where backers fund a player's tier fee for a share of their POINTS.
+#[account]
+pub struct BackingPool {
+    /// Tournament the player is entering
+    pub tournament: Pubkey,
+
+    /// Player being backed
+    pub player: Pubkey,
+
+    /// Lamports the player is raising (typically the tier fee)
+    pub target_lamports: u64,
+
+    /// Share of winnings offered for the full target, in basis points
+    pub offered_share_bps: u16,
+
+    /// Lamports raised so far
+    pub raised_lamports: u64,
+
+    /// Share of winnings sold so far, in basis points
+    pub sold_share_bps: u16,
+
+    /// Whether the player has withdrawn the raised funds
+    pub withdrawn: bool,
+
+    /// POINTS awarded to the player, recorded at distribution
+    pub points_awarded: u64,
+
+    /// Whether the player's points have been distributed and split
+    pub points_settled: bool,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl BackingPool {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 32 + 32 + 8 + 2 + 8 + 2 + 1 + 8 + 1 + 1 = 103 bytes
+    pub const SIZE: usize = 8 + 32 + 32 + 8 + 2 + 8 + 2 + 1 + 8 + 1 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"backing_pool";
+
+    /// Share of winnings bought by `lamports` of backing, in basis points
+    pub fn share_for(&self, lamports: u64) -> u16 {
+        (lamports as u128 * self.offered_share_bps as u128 / self.target_lamports.max(1) as u128)
+            as u16
+    }
+
+    /// Portion of `points` owed for `share_bps`
+    pub fn points_for_share(points: u64, share_bps: u16) -> u64 {
+        (points as u128 * share_bps as u128 / 10_000) as u64
+    }
+}
+
+/// A backer's contribution to a backing pool and the share it bought.
+#[account]
+pub struct BackerShare {
+    /// Backing pool this share belongs to
+    pub pool: Pubkey,
+
+    /// Backer's wallet
+    pub backer: Pubkey,
+
+    /// Lamports contributed
+    pub lamports: u64,
+
+    /// Share of the player's winnings, in basis points
+    pub share_bps: u16,
+
+    /// Whether the backer has claimed their POINTS
+    pub claimed: bool,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl BackerShare {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 32 + 32 + 8 + 2 + 1 + 1 = 84 bytes
+    pub const SIZE: usize = 8 + 32 + 32 + 8 + 2 + 1 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"backer_share";
+}
@@ -0,0 +1,255 @@
+use anchor_lang::prelude::*;
+
+/// Seeded elimination bracket for a tournament.
+/// Entrants are stored in draw order; first-round pairings derive from it.
+///
+/// In double elimination, a winners-bracket loser drops into the losers
+/// bracket, whose 2 * (rounds - 1) rounds alternate between playing down its
+/// own field (even rounds) and taking in the next winners-round losers (odd
+/// rounds). The grand final pits the two bracket champions against each
+/// other and is replayed once if the losers-bracket champion wins it.
+#[account]
+pub struct Bracket {
+    /// Tournament the bracket belongs to
+    pub tournament: Pubkey,
+
+    /// Slots in the first round (entrants rounded up to a power of two)
+    pub size: u16,
+
+    /// Winners-bracket rounds until its final
+    pub rounds: u8,
+
+    /// Losers drop into a losers bracket instead of being eliminated
+    pub double_elimination: bool,
+
+    /// Entrants in seeded draw order
+    pub draw: Vec<Pubkey>,
+
+    /// Matches completed so far, including byes
+    pub matches_completed: u16,
+
+    /// Winner of the final (None until decided)
+    pub champion: Option<Pubkey>,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Bracket {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 32 + 2 + 1 + 1 + (4 + 32 * 64) + 2 + 33 + 1 = 2132 bytes
+    pub const SIZE: usize = 8 + 32 + 2 + 1 + 1 + (4 + 32 * Self::MAX_ENTRANTS) + 2 + 33 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"bracket";
+
+    /// Maximum entrants in a bracket
+    pub const MAX_ENTRANTS: usize = 64;
+
+    /// Whether winners-bracket `round` is its final
+    pub fn is_final(&self, round: u8) -> bool {
+        round + 1 == self.rounds
+    }
+
+    /// Number of matches in winners-bracket `round`
+    pub fn matches_in_round(&self, round: u8) -> u16 {
+        self.size >> (round + 1)
+    }
+
+    /// Number of losers-bracket rounds
+    pub fn losers_rounds(&self) -> u8 {
+        2 * (self.rounds - 1)
+    }
+
+    /// Where the winner of a match goes next, or None if they are champion.
+    /// A grand-final winner only plays the reset if they came from the
+    /// losers bracket.
+    pub fn winner_destination(
+        &self,
+        side: BracketSide,
+        round: u8,
+        index: u16,
+    ) -> Option<MatchSlot> {
+        match side {
+            BracketSide::Winners if !self.is_final(round) => {
+                Some(MatchSlot::feeding(BracketSide::Winners, round + 1, index))
+            }
+            BracketSide::Winners => {
+                self.double_elimination
+                    .then_some(MatchSlot::new(BracketSide::GrandFinal, 0, 0, 0))
+            }
+            BracketSide::Losers if round + 1 == self.losers_rounds() => {
+                Some(MatchSlot::new(BracketSide::GrandFinal, 0, 0, 1))
+            }
+            BracketSide::Losers if round % 2 == 0 => {
+                Some(MatchSlot::new(BracketSide::Losers, round + 1, index, 0))
+            }
+            BracketSide::Losers => Some(MatchSlot::feeding(BracketSide::Losers, round + 1, index)),
+            BracketSide::GrandFinal => {
+                (round == 0).then_some(MatchSlot::new(BracketSide::GrandFinal, 1, 0, 0))
+            }
+        }
+    }
+
+    /// Where the loser of a match goes next, or None if they are eliminated
+    pub fn loser_destination(&self, side: BracketSide, round: u8, index: u16) -> Option<MatchSlot> {
+        if !self.double_elimination || side != BracketSide::Winners {
+            return None;
+        }
+        if round == 0 {
+            Some(MatchSlot::feeding(BracketSide::Losers, 0, index))
+        } else {
+            Some(MatchSlot::new(BracketSide::Losers, 2 * round - 1, index, 1))
+        }
+    }
+}
+
+/// Part of a bracket a match is played in
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum BracketSide {
+    /// Winners (or only) bracket
+    #[default]
+    Winners,
+    /// Losers bracket (double elimination)
+    Losers,
+    /// Grand final and its reset (double elimination)
+    GrandFinal,
+}
+
+/// A player slot in a bracket match
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct MatchSlot {
+    /// Part of the bracket
+    pub side: BracketSide,
+    /// Round within that part
+    pub round: u8,
+    /// Position within the round
+    pub index: u16,
+    /// 0 for player A, 1 for player B
+    pub slot: u8,
+}
+
+impl MatchSlot {
+    /// Slot in the given match
+    pub fn new(side: BracketSide, round: u8, index: u16, slot: u8) -> Self {
+        Self {
+            side,
+            round,
+            index,
+            slot,
+        }
+    }
+
+    /// Slot fed by match `feeder_index` of the previous round, when two
+    /// matches feed each match of `round`
+    pub fn feeding(side: BracketSide, round: u8, feeder_index: u16) -> Self {
+        Self::new(side, round, feeder_index / 2, (feeder_index % 2) as u8)
+    }
+
+    /// PDA seed identifying the match this slot belongs to
+    pub fn position(&self) -> [u8; 4] {
+        BracketMatch::position(self.side, self.round, self.index)
+    }
+}
+
+/// Lifecycle of a bracket match
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum MatchStatus {
+    /// Waiting for a feeder match to produce a player
+    #[default]
+    Pending,
+    /// Both players known; ready to be played
+    Ready,
+    /// Result recorded
+    Completed,
+}
+
+/// One heads-up match in a bracket.
+#[account]
+pub struct BracketMatch {
+    /// Bracket the match belongs to
+    pub bracket: Pubkey,
+
+    /// Part of the bracket the match is played in
+    pub side: BracketSide,
+
+    /// Round within its part, 0 for the first round
+    pub round: u8,
+
+    /// Position within the round
+    pub index: u16,
+
+    /// Player in slot A (Pubkey::default() until known or for a bye)
+    pub player_a: Pubkey,
+
+    /// Player in slot B (Pubkey::default() until known or for a bye)
+    pub player_b: Pubkey,
+
+    /// Match state
+    pub status: MatchStatus,
+
+    /// Winner (None until completed)
+    pub winner: Option<Pubkey>,
+
+    /// Unix timestamp the result was recorded (0 until completed)
+    pub completed_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl BracketMatch {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 32 + 1 + 1 + 2 + 32 + 32 + 1 + 33 + 8 + 1 = 151 bytes
+    pub const SIZE: usize = 8 + 32 + 1 + 1 + 2 + 32 + 32 + 1 + 33 + 8 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"bracket_match";
+
+    /// PDA seed identifying a match: side, round and little-endian index
+    pub fn position(side: BracketSide, round: u8, index: u16) -> [u8; 4] {
+        let index = index.to_le_bytes();
+        [side as u8, round, index[0], index[1]]
+    }
+
+    /// Set up a newly created match at `slot`'s position
+    pub fn init(&mut self, bracket: Pubkey, slot: MatchSlot, bump: u8) {
+        self.bracket = bracket;
+        self.side = slot.side;
+        self.round = slot.round;
+        self.index = slot.index;
+        self.status = MatchStatus::Pending;
+        self.bump = bump;
+    }
+
+    /// Seat `wallet` in slot A (0) or B (1), marking the match ready once
+    /// both players are known
+    pub fn seat(&mut self, slot: u8, wallet: Pubkey) {
+        if slot == 0 {
+            self.player_a = wallet;
+        } else {
+            self.player_b = wallet;
+        }
+        if self.player_a != Pubkey::default() && self.player_b != Pubkey::default() {
+            self.status = MatchStatus::Ready;
+        }
+    }
+
+    /// The loser, if `winner` is one of the match's players
+    pub fn opponent_of(&self, winner: &Pubkey) -> Option<Pubkey> {
+        if *winner == self.player_a {
+            Some(self.player_b)
+        } else if *winner == self.player_b {
+            Some(self.player_a)
+        } else {
+            None
+        }
+    }
+
+    /// Record `winner` as the result
+    pub fn complete(&mut self, winner: Pubkey, now: i64) {
+        self.winner = Some(winner);
+        self.status = MatchStatus::Completed;
+        self.completed_at = now;
+    }
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+/// Ledger of rake-funded POINTS buyback-and-burns.
+/// Also the owner of the designated buyback vault token account.
+#[account]
+pub struct BuybackLedger {
+    /// Share of collected rake spent on buybacks, in basis points
+    pub buyback_bps: u16,
+
+    /// Lifetime rake reported to buybacks (lamports)
+    pub total_rake_lamports: u64,
+
+    /// Lifetime lamports spent buying back POINTS
+    pub total_lamports_spent: u64,
+
+    /// Lifetime POINTS burned (base units)
+    pub total_points_burned: u64,
+
+    /// Number of buyback-and-burns executed
+    pub burn_count: u64,
+
+    /// Unix timestamp of the last burn (0 if never)
+    pub last_burn_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl BuybackLedger {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 2 + 8 + 8 + 8 + 8 + 8 + 1 = 51 bytes
+    pub const SIZE: usize = 8 + 2 + 8 + 8 + 8 + 8 + 8 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"buyback_ledger";
+
+    /// Minimum time between buyback-and-burns
+    pub const MIN_INTERVAL_SECONDS: i64 = 24 * 60 * 60;
+
+    /// Lamports of `rake_lamports` allocated to buybacks
+    pub fn buyback_budget(&self, rake_lamports: u64) -> u64 {
+        ((rake_lamports as u128 * self.buyback_bps as u128) / 10_000) as u64
+    }
+
+    /// Check if enough time has passed since the last burn
+    pub fn is_due(&self, now: i64) -> bool {
+        self.burn_count == 0 || now >= self.last_burn_at + Self::MIN_INTERVAL_SECONDS
+    }
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+/// Key appointed by the admin to post commentary commitments for tournaments.
+#[account]
+pub struct Commentator {
+    /// Commentator's signing key
+    pub key: Pubkey,
+
+    /// Whether the key may currently post commentary
+    pub active: bool,
+
+    /// Unix timestamp of the last appointment change
+    pub updated_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Commentator {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 32 + 1 + 8 + 1 = 50 bytes
+    pub const SIZE: usize = 8 + 32 + 1 + 8 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"commentator";
+}
+
+/// Hash chain of commentary and highlight metadata posted for a tournament.
+/// The head commits to every entry so far, so stream overlays can be checked
+/// against the chain alongside the hand-log commitments.
+#[account]
+pub struct CommentaryLog {
+    /// Tournament the commentary covers
+    pub tournament: Pubkey,
+
+    /// Current head of the hash chain (zeroed before the first entry)
+    pub chain_head: [u8; 32],
+
+    /// Number of entries posted so far
+    pub entries: u32,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl CommentaryLog {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 32 + 32 + 4 + 1 = 77 bytes
+    pub const SIZE: usize = 8 + 32 + 32 + 4 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"commentary_log";
+}
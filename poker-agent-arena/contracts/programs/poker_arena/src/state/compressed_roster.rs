@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+
+use crate::state::AgentTier;
+
+/// Compressed registration roster for a tournament.
+/// Registrations live as leaves of an SPL concurrent Merkle tree instead of
+/// one PlayerRegistration PDA each; this PDA is the tree's authority.
+#[account]
+pub struct CompressedRoster {
+    /// Tournament this roster belongs to
+    pub tournament: Pubkey,
+
+    /// Concurrent Merkle tree holding registration leaves
+    pub merkle_tree: Pubkey,
+
+    /// Number of leaves appended so far (next leaf index)
+    pub leaf_count: u32,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl CompressedRoster {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 32 + 32 + 4 + 1 = 77 bytes
+    pub const SIZE: usize = 8 + 32 + 32 + 4 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"compressed_roster";
+}
+
+/// Marker that a wallet holds a compressed seat in a tournament.
+/// Its creation fails if the wallet already registered, so each wallet
+/// appends at most one leaf per roster.
+#[account]
+pub struct CompressedSeat {
+    /// Leaf index of the wallet's registration in the roster's tree
+    pub leaf_index: u32,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl CompressedSeat {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 4 + 1 = 13 bytes
+    pub const SIZE: usize = 8 + 4 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"compressed_seat";
+}
+
+/// Registration data stored as a compressed leaf.
+/// Mirrors the result-relevant fields of PlayerRegistration; the leaf is its SHA-256 hash.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct CompressedRegistration {
+    /// Tournament this registration is for
+    pub tournament: Pubkey,
+
+    /// Player's wallet address
+    pub wallet: Pubkey,
+
+    /// Selected agent tier
+    pub tier: AgentTier,
+
+    /// Unix timestamp when player registered
+    pub registered_at: i64,
+
+    /// SHA-256 hash of custom prompt (for verification)
+    pub agent_prompt_hash: [u8; 32],
+
+    /// Agent display name (UTF-8, null-padded)
+    pub agent_name: [u8; 32],
+
+    /// JPEG image URI for agent avatar (off-chain storage)
+    pub agent_image_uri: [u8; 128],
+
+    /// Final rank in tournament (None if not recorded)
+    pub final_rank: Option<u16>,
+
+    /// POINTS earned in tournament (None if not recorded)
+    pub points_awarded: Option<u64>,
+
+    /// Total hands played (None if not recorded)
+    pub hands_played: Option<u32>,
+
+    /// Number of players eliminated (None if not recorded)
+    pub eliminations: Option<u8>,
+
+    /// Whether POINTS tokens have been distributed to this player
+    pub points_distributed: bool,
+}
+
+impl CompressedRegistration {
+    /// Leaf hash committed to the Merkle tree: SHA-256(borsh(self))
+    pub fn leaf_hash(&self) -> Result<[u8; 32]> {
+        Ok(hash(&self.try_to_vec()?).to_bytes())
+    }
+}
@@ -1,4 +1,9 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::native_token::LAMPORTS_PER_SOL;
+
+use crate::blinds::BlindPreset;
+use crate::errors::ArenaError;
+use crate::state::{AgentTier, CreationRateLimit, LoyaltyLevel, Tournament};
 
 /// Arena configuration account.
 /// Single global config for the entire poker arena.
@@ -16,15 +21,331 @@ pub struct ArenaConfig {
     /// Total number of tournaments created
     pub tournament_count: u64,
 
+    /// Lamports paid from the crank vault to callers of permissionless cranks
+    pub crank_bounty_lamports: u64,
+
+    /// Tournaments played required for Bronze, Silver and Gold (0 disables a level)
+    pub loyalty_thresholds: [u32; 3],
+
+    /// Tier fee discount for Bronze, Silver and Gold, in basis points
+    pub loyalty_discount_bps: [u16; 3],
+
+    /// Lifetime lamports collected by the treasury through program fees
+    pub total_fees_collected_lamports: u64,
+
+    /// Lifetime lamports refunded by the treasury
+    pub total_fees_refunded_lamports: u64,
+
+    /// Wallets receiving a share of each tier fee (Pubkey::default() for unused slots)
+    pub fee_recipients: [Pubkey; ArenaConfig::MAX_FEE_RECIPIENTS],
+
+    /// Share of each tier fee per recipient, in basis points; the treasury keeps the rest
+    pub fee_recipient_bps: [u16; ArenaConfig::MAX_FEE_RECIPIENTS],
+
+    /// URI of the arena metadata document (branding, API endpoints, rules),
+    /// zero-padded to 128 bytes; all zeros until set
+    pub metadata_uri: [u8; 128],
+
+    /// Player POINTS awards at or above this amount vest instead of minting (0 disables vesting)
+    pub vesting_threshold_points: u64,
+
+    /// Seconds after distribution before any vested POINTS unlock
+    pub vesting_cliff_seconds: i64,
+
+    /// Seconds after distribution until vested POINTS are fully unlocked
+    pub vesting_duration_seconds: i64,
+
+    /// Seconds after results are submitted during which they can be disputed
+    /// before being confirmed (0 disables the window)
+    pub dispute_window_seconds: i64,
+
+    /// Seconds past `starts_at` after which a tournament still in Created can
+    /// be expired by anyone
+    pub stale_tournament_seconds: i64,
+
+    /// Receives rent from collected registrations of voided tournaments and
+    /// disqualified players (the treasury by default)
+    pub forfeited_rent_recipient: Pubkey,
+
+    /// Total number of leaderboard snapshots taken
+    pub snapshot_count: u64,
+
+    /// POINTS a registering wallet must have staked for the holder discount
+    pub holder_discount_min_points: u64,
+
+    /// Tier fee discount for POINTS holders, in basis points (0 disables it)
+    pub holder_discount_bps: u16,
+
+    /// POINTS burned to upgrade a registration FREE to BASIC and BASIC to PRO
+    /// (0 disables a step)
+    pub tier_upgrade_points: [u64; 2],
+
+    /// POINTS burned per SOL of tier fee when buying tickets (0 disables tickets)
+    pub ticket_points_per_sol: u64,
+
+    /// Decision timeouts a player may take before the timeout penalty applies
+    pub timeout_penalty_threshold: u16,
+
+    /// POINTS deducted from the award of a player over the timeout threshold
+    /// (0 disables the penalty)
+    pub timeout_penalty_points: u64,
+
+    /// Bitmask of optional subsystems enabled at runtime (FEATURE_* flags)
+    pub feature_flags: u64,
+
+    /// Only key allowed to record results and finalize tournaments
+    /// (Pubkey::default() leaves it to the admin and tournament operators)
+    pub results_authority: Pubkey,
+
+    /// USDC mint accepted for USDC-denominated prize pools
+    /// (Pubkey::default() until configured)
+    pub usdc_mint: Pubkey,
+
+    /// Tier fees in USD cents, indexed FREE, BASIC, PRO; charged in lamports
+    /// at the oracle price while `sol_usd_feed_id` is set
+    pub tier_fee_usd_cents: [u32; 3],
+
+    /// Pyth SOL/USD feed id pricing the tier fees (zeros = fixed lamport fees)
+    pub sol_usd_feed_id: [u8; 32],
+
+    /// Share of the tier fee refunded to players who unregister after
+    /// registration has closed, in basis points (earlier unregistrations are
+    /// refunded in full)
+    pub late_unregister_refund_bps: u16,
+
+    /// Tournaments that may be created over any trailing day (0 = unlimited)
+    pub max_tournaments_per_day: u16,
+
+    /// Tournaments that may be open (not yet completed, cancelled or voided)
+    /// at once (0 = unlimited)
+    pub max_open_tournaments: u16,
+
+    /// Tournaments created over the trailing day
+    pub creation_rate: CreationRateLimit,
+
+    /// Tournaments created and not yet completed, cancelled or voided
+    pub open_tournaments: u16,
+
+    /// Decimals of the POINTS mint; awards are stored in base units
+    pub points_decimals: u8,
+
+    /// Minimum bond, in lamports, a host must hold to create tournaments
+    pub host_bond_lamports: u64,
+
+    /// Blind presets new tournaments may not use (BlindPreset::flag bits)
+    pub disabled_blind_presets: u8,
+
     /// PDA bump seed
     pub bump: u8,
 }
 
 impl ArenaConfig {
     /// Account size for rent calculation
-    /// 8 (discriminator) + 32 + 32 + 32 + 8 + 1 = 113 bytes
-    pub const SIZE: usize = 8 + 32 + 32 + 32 + 8 + 1;
+    /// 8 (discriminator) + 32 + 32 + 32 + 8 + 8 + 12 + 6 + 8 + 8 + 128 + 8 + 128 + 8 + 8 + 8 + 8 + 8 + 32 + 8 + 8 + 2 + 16 + 8 + 2 + 8 + 8 + 32 + 32 + 12 + 32 + 2 + 2 + 2 + 56 + 2 + 1 + 8 + 1 + 1 = 733 bytes
+    pub const SIZE: usize = 8 + 32 + 32 + 32 + 8 + 8 + 12 + 6 + 8 + 8 + 128 + 8 + 128 + 8 + 8 + 8 + 8 + 8 + 32 + 8 + 8 + 2 + 16 + 8 + 2 + 8 + 8 + 32 + 32 + 12 + 32 + 2 + 2 + 2 + 56 + 2 + 1 + 8 + 1 + 1;
 
     /// PDA seeds
     pub const SEED_PREFIX: &'static [u8] = b"arena_config";
+
+    /// Decimals the POINTS mint is created with
+    pub const POINTS_DECIMALS: u8 = 9;
+
+    /// Maximum number of fee split recipients besides the treasury
+    pub const MAX_FEE_RECIPIENTS: usize = 4;
+
+    /// Default staleness period for tournaments stuck in Created (1 day)
+    pub const DEFAULT_STALE_TOURNAMENT_SECONDS: i64 = 86_400;
+
+    /// Action marketplace (list_action, buy_action)
+    pub const FEATURE_MARKETPLACE: u64 = 1 << 0;
+
+    /// Features enabled on a fresh arena; subsystems added later ship dark
+    pub const DEFAULT_FEATURE_FLAGS: u64 = Self::FEATURE_MARKETPLACE;
+
+    /// Count a tournament created at `now` against the creation rate limits
+    pub fn record_tournament_created(&mut self, now: i64) -> Result<()> {
+        require!(
+            self.max_open_tournaments == 0 || self.open_tournaments < self.max_open_tournaments,
+            ArenaError::TournamentRateLimited
+        );
+        self.creation_rate.record(now, self.max_tournaments_per_day)?;
+        self.open_tournaments = self
+            .open_tournaments
+            .checked_add(1)
+            .ok_or(ArenaError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Free an open tournament slot once a tournament is completed,
+    /// cancelled or voided
+    pub fn record_tournament_closed(&mut self) {
+        self.open_tournaments = self.open_tournaments.saturating_sub(1);
+    }
+
+    /// Whether new tournaments may use `preset`
+    pub fn blind_preset_enabled(&self, preset: BlindPreset) -> bool {
+        self.disabled_blind_presets & preset.flag() == 0
+    }
+
+    /// Whether every subsystem in `flags` is enabled
+    pub fn is_enabled(&self, flags: u64) -> bool {
+        self.feature_flags & flags == flags
+    }
+
+    /// Configured fee recipients and their share of `amount`, in slot order
+    pub fn fee_shares(&self, amount: u64) -> impl Iterator<Item = (Pubkey, u64)> + '_ {
+        self.fee_recipients
+            .iter()
+            .zip(self.fee_recipient_bps)
+            .filter(|(recipient, _)| **recipient != Pubkey::default())
+            .map(move |(recipient, bps)| (*recipient, (amount as u128 * bps as u128 / 10_000) as u64))
+    }
+
+    /// Add `lamports` paid to the treasury to the lifetime fee counter
+    pub fn record_fee(&mut self, lamports: u64) {
+        self.total_fees_collected_lamports = self.total_fees_collected_lamports.saturating_add(lamports);
+    }
+
+    /// Add `lamports` refunded by the treasury to the lifetime refund counter
+    pub fn record_refund(&mut self, lamports: u64) {
+        self.total_fees_refunded_lamports = self.total_fees_refunded_lamports.saturating_add(lamports);
+    }
+
+    /// Loyalty level earned after `tournaments_played` tournaments
+    pub fn loyalty_level(&self, tournaments_played: u32) -> LoyaltyLevel {
+        let levels = [LoyaltyLevel::Bronze, LoyaltyLevel::Silver, LoyaltyLevel::Gold];
+        levels
+            .into_iter()
+            .zip(self.loyalty_thresholds)
+            .filter(|(_, threshold)| *threshold > 0 && tournaments_played >= *threshold)
+            .map(|(level, _)| level)
+            .next_back()
+            .unwrap_or_default()
+    }
+
+    /// Whether a player award of `points` must vest
+    pub fn vests(&self, points: u64) -> bool {
+        self.vesting_threshold_points > 0 && points >= self.vesting_threshold_points
+    }
+
+    /// Whether tier fees are priced in USD through the SOL/USD oracle
+    pub fn uses_usd_tier_fees(&self) -> bool {
+        self.sol_usd_feed_id != [0; 32]
+    }
+
+    /// Split the unrefunded tier fee of an unregistering player into the
+    /// refund and the portion retained for the treasury: refunded in full
+    /// while registration is open, `late_unregister_refund_bps` of it after
+    pub fn unregister_refund(&self, fee_lamports: u64, registration_closed: bool) -> (u64, u64) {
+        if !registration_closed {
+            return (fee_lamports, 0);
+        }
+        let refund =
+            (fee_lamports as u128 * self.late_unregister_refund_bps as u128 / 10_000) as u64;
+        (refund, fee_lamports - refund)
+    }
+
+    /// Tier fee after the discount for `level`
+    pub fn discounted_fee(&self, tier_cost: u64, level: LoyaltyLevel) -> u64 {
+        let discount_bps = match level {
+            LoyaltyLevel::None => 0,
+            LoyaltyLevel::Bronze => self.loyalty_discount_bps[0],
+            LoyaltyLevel::Silver => self.loyalty_discount_bps[1],
+            LoyaltyLevel::Gold => self.loyalty_discount_bps[2],
+        };
+        tier_cost - (tier_cost as u128 * discount_bps as u128 / 10_000) as u64
+    }
+
+    /// Holder discount earned by a wallet with `staked_points` POINTS staked, in basis points
+    pub fn holder_discount(&self, staked_points: u64) -> u16 {
+        if staked_points >= self.holder_discount_min_points {
+            self.holder_discount_bps
+        } else {
+            0
+        }
+    }
+
+    /// POINTS burned to upgrade a registration from `from` to `to`, or None if
+    /// `to` is not a higher tier or a step along the way is disabled
+    pub fn tier_upgrade_cost(&self, from: AgentTier, to: AgentTier) -> Option<u64> {
+        let steps = self
+            .tier_upgrade_points
+            .get(from as usize..to as usize)
+            .filter(|steps| !steps.is_empty())?;
+        steps
+            .iter()
+            .try_fold(0u64, |total, step| (*step > 0).then(|| total.saturating_add(*step)))
+    }
+
+    /// Whether `key` may record results for and finalize `tournament`: the
+    /// results authority if one is set, otherwise the admin or operator.
+    /// Hosts always run their own tournaments, staking their bond on them.
+    pub fn can_record_results(&self, key: &Pubkey, tournament: &Tournament) -> bool {
+        if tournament.is_hosted() && *key == tournament.admin {
+            true
+        } else if self.results_authority != Pubkey::default() {
+            *key == self.results_authority
+        } else {
+            tournament.can_operate(key, &self.admin)
+        }
+    }
+
+    /// Whether `key` may settle `tournament`'s POINTS awards: whoever may
+    /// record its results, except that hosted tournaments are settled by the
+    /// admin alone, since hosts may not mint POINTS
+    pub fn can_award_points(&self, key: &Pubkey, tournament: &Tournament) -> bool {
+        if tournament.is_hosted() {
+            *key == self.admin
+        } else {
+            self.can_record_results(key, tournament)
+        }
+    }
+
+    /// POINTS deducted for a player who took `timeouts` decision timeouts
+    pub fn timeout_penalty(&self, timeouts: u16) -> u64 {
+        if timeouts > self.timeout_penalty_threshold {
+            self.timeout_penalty_points
+        } else {
+            0
+        }
+    }
+
+    /// POINTS base units per whole POINT
+    pub fn base_units_per_point(&self) -> u64 {
+        10u64.pow(self.points_decimals as u32)
+    }
+
+    /// Convert whole POINTS to base units
+    pub fn points_to_base_units(&self, points: u64) -> Result<u64> {
+        points
+            .checked_mul(self.base_units_per_point())
+            .ok_or_else(|| error!(ArenaError::ArithmeticOverflow))
+    }
+
+    /// Convert base units to whole POINTS, dropping any fraction
+    pub fn base_units_to_points(&self, base_units: u64) -> u64 {
+        base_units / self.base_units_per_point()
+    }
+
+    /// Round base units down to a whole number of POINTS
+    pub fn round_down_to_whole_points(&self, base_units: u64) -> u64 {
+        base_units - base_units % self.base_units_per_point()
+    }
+
+    /// Require `base_units` to be a whole number of POINTS
+    pub fn require_whole_points(&self, base_units: u64) -> Result<()> {
+        require!(
+            base_units % self.base_units_per_point() == 0,
+            ArenaError::FractionalPointsAward
+        );
+        Ok(())
+    }
+
+    /// POINTS burned for one `tier` ticket, or None if tickets are disabled
+    /// or the tier is free
+    pub fn ticket_price_points(&self, tier: AgentTier) -> Option<u64> {
+        let points = tier.cost_lamports() as u128 * self.ticket_points_per_sol as u128
+            / LAMPORTS_PER_SOL as u128;
+        u64::try_from(points).ok().filter(|points| *points > 0)
+    }
 }
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+/// Allowlist entry for a partner program permitted to register players via CPI.
+#[account]
+pub struct CpiPartner {
+    /// Partner program ID
+    pub program_id: Pubkey,
+
+    /// Whether the program may currently register players
+    pub active: bool,
+
+    /// Unix timestamp of the last allowlist change
+    pub updated_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl CpiPartner {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 32 + 1 + 8 + 1 = 50 bytes
+    pub const SIZE: usize = 8 + 32 + 1 + 8 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"cpi_partner";
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+/// Program-owned vault that pays bounties to callers of permissionless cranks.
+/// Funded from the treasury; the bounty size lives in ArenaConfig.
+#[account]
+pub struct CrankVault {
+    /// Lifetime lamports paid out as crank bounties
+    pub total_paid: u64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl CrankVault {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 8 + 1 = 17 bytes
+    pub const SIZE: usize = 8 + 8 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"crank_vault";
+
+    /// Pay up to `bounty` lamports to `caller`, never dipping below rent exemption.
+    /// Returns the amount actually paid (0 if the vault is drained).
+    pub fn pay_bounty(
+        vault: &mut Account<CrankVault>,
+        caller: &AccountInfo,
+        bounty: u64,
+    ) -> Result<u64> {
+        let vault_info = vault.to_account_info();
+        let rent_floor = Rent::get()?.minimum_balance(vault_info.data_len());
+        let available = vault_info.lamports().saturating_sub(rent_floor);
+        let amount = bounty.min(available);
+
+        if amount > 0 {
+            **vault_info.try_borrow_mut_lamports()? -= amount;
+            **caller.try_borrow_mut_lamports()? += amount;
+            vault.total_paid = vault.total_paid.saturating_add(amount);
+        }
+
+        Ok(amount)
+    }
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+/// Freeroll tickets dripped to a wallet once per day.
+/// A ticket waives the BASIC tier fee for one registration.
+#[account]
+pub struct DailyTicket {
+    /// Wallet holding the tickets
+    pub wallet: Pubkey,
+
+    /// Unused tickets
+    pub tickets: u16,
+
+    /// Unix timestamp of the last claim
+    pub last_claimed_at: i64,
+
+    /// Lifetime tickets claimed
+    pub total_claimed: u32,
+
+    /// Lifetime tickets spent on registrations
+    pub total_used: u32,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl DailyTicket {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 32 + 2 + 8 + 4 + 4 + 1 = 59 bytes
+    pub const SIZE: usize = 8 + 32 + 2 + 8 + 4 + 4 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"daily_ticket";
+
+    /// Minimum time between claims
+    pub const CLAIM_INTERVAL_SECONDS: i64 = 24 * 60 * 60;
+
+    /// Whether a new ticket can be claimed at `now`
+    pub fn can_claim(&self, now: i64) -> bool {
+        self.total_claimed == 0 || now >= self.last_claimed_at + Self::CLAIM_INTERVAL_SECONDS
+    }
+}
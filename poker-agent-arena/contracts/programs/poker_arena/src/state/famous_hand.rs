@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::merkle;
+use crate::state::Table;
+
+/// A "hand of the tournament", proven against the committed hand-history root.
+/// Commemorative cNFTs minted to the participants point at this account.
+#[account]
+pub struct FamousHand {
+    /// Tournament the hand was played in
+    pub tournament: Pubkey,
+
+    /// Index of the hand within the tournament
+    pub hand_index: u32,
+
+    /// SHA-256 hash of the hand history
+    pub hand_hash: [u8; 32],
+
+    /// Players involved in the hand (Pubkey::default() for unused slots)
+    pub participants: [Pubkey; Table::MAX_SEATS],
+
+    /// Merkle proof path from the hand leaf to the hand-history root
+    pub proof: [[u8; 32]; FamousHand::MAX_PROOF_DEPTH],
+
+    /// Number of proof nodes in use
+    pub proof_len: u8,
+
+    /// Unix timestamp the commemorative was minted
+    pub minted_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl FamousHand {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 32 + 4 + 32 + 288 + 512 + 1 + 8 + 1 = 886 bytes
+    pub const SIZE: usize = 8 + 32 + 4 + 32 + 288 + 512 + 1 + 8 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"famous_hand";
+
+    /// Maximum Merkle proof depth (hand-history trees of up to 65536 hands)
+    pub const MAX_PROOF_DEPTH: usize = 16;
+
+    /// Leaf for a hand: SHA-256(0x00 || tournament || hand_index_le || hand_hash || participants...)
+    pub fn leaf(
+        tournament: &Pubkey,
+        hand_index: u32,
+        hand_hash: &[u8; 32],
+        participants: &[Pubkey],
+    ) -> [u8; 32] {
+        let index = hand_index.to_le_bytes();
+        let mut parts: Vec<&[u8]> = vec![tournament.as_ref(), &index, hand_hash];
+        parts.extend(participants.iter().map(|p| p.as_ref()));
+        merkle::leaf_hash(&parts)
+    }
+}
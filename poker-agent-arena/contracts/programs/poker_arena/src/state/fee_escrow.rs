@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+/// Per-tournament escrow holding tier fees until the tournament starts.
+/// Lamports are held on the PDA itself; `release_fee_escrow` sweeps them to
+/// the fee recipients and treasury (or the SOL prize pool) once the
+/// tournament has started, and `refund_escrowed_fee` pays them back to
+/// players if it is cancelled or voided first. A hosted tournament's
+/// escrow also holds any bond slashed from its host, which stays behind when
+/// the fees are released and tops up refunds once the tournament is voided.
+#[account]
+pub struct FeeEscrow {
+    /// Tournament whose fees are held
+    pub tournament: Pubkey,
+
+    /// Release the fees into the tournament's SOL prize pool instead of the treasury
+    pub to_prize_pool: bool,
+
+    /// Whether the fees have been released
+    pub released: bool,
+
+    /// Whether the tournament's host has been slashed over it
+    pub host_slashed: bool,
+
+    /// Lamports slashed from the host's bond not yet paid out in refunds
+    pub slashed_lamports: u64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl FeeEscrow {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 32 + 1 + 1 + 1 + 8 + 1 = 52 bytes
+    pub const SIZE: usize = 8 + 32 + 1 + 1 + 1 + 8 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"fee_escrow";
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+/// Beneficiary's consent to be registered for a tournament by a sponsor.
+/// Consumed by `gift_registration`.
+#[account]
+pub struct GiftConsent {
+    /// Wallet that will own the gifted registration
+    pub beneficiary: Pubkey,
+
+    /// Tournament the consent applies to
+    pub tournament: Pubkey,
+
+    /// Sponsor allowed to gift the entry (default pubkey = any sponsor)
+    pub sponsor: Pubkey,
+
+    /// Unix timestamp when consent was given
+    pub created_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl GiftConsent {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 32 + 32 + 32 + 8 + 1 = 113 bytes
+    pub const SIZE: usize = 8 + 32 + 32 + 32 + 8 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"gift_consent";
+
+    /// Whether `sponsor` may gift this entry
+    pub fn allows(&self, sponsor: &Pubkey) -> bool {
+        self.sponsor == Pubkey::default() || self.sponsor == *sponsor
+    }
+}
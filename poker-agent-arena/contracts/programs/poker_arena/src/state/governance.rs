@@ -0,0 +1,144 @@
+use anchor_lang::prelude::*;
+
+use crate::blinds::BlindPreset;
+use crate::errors::ArenaError;
+use crate::state::ArenaConfig;
+
+/// Arena parameter change a proposal applies when executed
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ProposalAction {
+    /// Set ArenaConfig.crank_bounty_lamports
+    SetCrankBounty(u64),
+    /// Set BuybackLedger.buyback_bps (share of rake)
+    SetBuybackBps(u16),
+    /// Set RedemptionVault.lamports_per_point
+    SetRedemptionRate(u64),
+    /// Set ArenaConfig.fee_recipient_bps (each recipient's share of the rake)
+    SetRakeSplit([u16; ArenaConfig::MAX_FEE_RECIPIENTS]),
+    /// Set ArenaConfig.tier_fee_usd_cents
+    SetTierFeesUsd([u32; 3]),
+    /// Set ArenaConfig.disabled_blind_presets (BlindPreset::flag bits)
+    SetDisabledBlindPresets(u8),
+}
+
+impl ProposalAction {
+    /// Reject parameter values no arena could accept, whatever its
+    /// current configuration
+    pub fn validate(&self) -> Result<()> {
+        match self {
+            ProposalAction::SetBuybackBps(buyback_bps) => {
+                require!(*buyback_bps <= 10_000, ArenaError::InvalidBasisPoints);
+            }
+            ProposalAction::SetRakeSplit(recipient_bps) => {
+                let total_bps: u32 = recipient_bps.iter().map(|bps| *bps as u32).sum();
+                require!(total_bps <= 10_000, ArenaError::InvalidBasisPoints);
+            }
+            ProposalAction::SetDisabledBlindPresets(disabled) => {
+                // At least one preset must stay available to create tournaments
+                require!(
+                    *disabled & !BlindPreset::ALL_FLAGS == 0 && *disabled != BlindPreset::ALL_FLAGS,
+                    ArenaError::InvalidBlindStructure
+                );
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+/// Global governance configuration.
+/// Also the owner of the POINTS stake vault.
+#[account]
+pub struct Governance {
+    /// Total number of proposals created
+    pub proposal_count: u64,
+
+    /// Minimum POINTS voting in favor for a proposal to pass (base units)
+    pub quorum_points: u64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Governance {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 8 + 8 + 1 = 25 bytes
+    pub const SIZE: usize = 8 + 8 + 8 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"governance";
+
+    /// PDA seeds prefix for the POINTS stake vault
+    pub const VAULT_SEED_PREFIX: &'static [u8] = b"governance_vault";
+
+    /// How long a proposal accepts votes
+    pub const VOTING_PERIOD_SECONDS: i64 = 3 * 24 * 60 * 60;
+
+    /// Delay between the end of voting and execution
+    pub const TIMELOCK_SECONDS: i64 = 2 * 24 * 60 * 60;
+
+    /// Largest change a proposal may make to a rate or price, in basis
+    /// points of its current value
+    pub const MAX_RATE_CHANGE_BPS: u64 = 2_000;
+
+    /// Whether moving a rate or price from `current` to `proposed` stays
+    /// within MAX_RATE_CHANGE_BPS of the current value (a rate at 0 can
+    /// only be changed by the admin)
+    pub fn within_rate_bound(current: u64, proposed: u64) -> bool {
+        let max_change = current as u128 * Self::MAX_RATE_CHANGE_BPS as u128 / 10_000;
+        proposed.abs_diff(current) as u128 <= max_change
+    }
+}
+
+/// Governance proposal account.
+#[account]
+pub struct Proposal {
+    /// Proposal ID (matches proposal_count at creation)
+    pub id: u64,
+
+    /// Wallet that created the proposal
+    pub proposer: Pubkey,
+
+    /// Parameter change to apply
+    pub action: ProposalAction,
+
+    /// Unix timestamp of creation (stake snapshot time)
+    pub created_at: i64,
+
+    /// Unix timestamp when voting ends
+    pub voting_ends_at: i64,
+
+    /// Unix timestamp after which the proposal may be executed
+    pub executable_at: i64,
+
+    /// POINTS voting in favor (base units)
+    pub votes_for: u64,
+
+    /// POINTS voting against (base units)
+    pub votes_against: u64,
+
+    /// Whether the proposal has been executed
+    pub executed: bool,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Proposal {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 8 + 32 + 13 + 8 + 8 + 8 + 8 + 8 + 1 + 1 = 111 bytes
+    pub const SIZE: usize = 8 + 8 + 32 + 13 + 8 + 8 + 8 + 8 + 8 + 1 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"proposal";
+
+    /// Check if voting is open
+    pub fn is_voting_open(&self, now: i64) -> bool {
+        now < self.voting_ends_at
+    }
+
+    /// Check if the proposal reached quorum and a majority
+    pub fn has_passed(&self, quorum_points: u64) -> bool {
+        self.votes_for > self.votes_against && self.votes_for >= quorum_points
+    }
+}
@@ -0,0 +1,221 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::state::PlayerRegistration;
+
+/// A player guild with officers, a member roster and a shared vault.
+#[account]
+pub struct Guild {
+    /// Guild name (32 bytes, UTF-8, null-padded); unique per guild
+    pub name: [u8; 32],
+
+    /// Wallet that founded and manages the guild
+    pub leader: Pubkey,
+
+    /// Officers allowed to manage the roster (default pubkey = empty slot)
+    pub officers: [Pubkey; Guild::MAX_OFFICERS],
+
+    /// Number of current members, including the leader
+    pub member_count: u16,
+
+    /// Share of members' POINTS routed to the guild vault, in basis points
+    pub winnings_share_bps: u16,
+
+    /// Whether the vault pays members' tier fees
+    pub sponsor_tier_fees: bool,
+
+    /// Vault PDA holding the guild's SOL
+    pub vault: Pubkey,
+
+    /// Unix timestamp when the guild was founded
+    pub created_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Guild {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 32 + 32 + 128 + 2 + 2 + 1 + 32 + 8 + 1 = 246 bytes
+    pub const SIZE: usize = 8 + 32 + 32 + 32 * Guild::MAX_OFFICERS + 2 + 2 + 1 + 32 + 8 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"guild";
+
+    /// Maximum number of officers
+    pub const MAX_OFFICERS: usize = 4;
+
+    /// Maximum number of members
+    pub const MAX_MEMBERS: u16 = 100;
+
+    /// Whether `wallet` is the leader or an officer
+    pub fn is_officer(&self, wallet: &Pubkey) -> bool {
+        self.leader == *wallet || (*wallet != Pubkey::default() && self.officers.contains(wallet))
+    }
+
+    /// Validate a guild name and policy
+    pub fn validate(name: &[u8; 32], winnings_share_bps: u16) -> Result<()> {
+        PlayerRegistration::validate_agent_name(name)
+            .map_err(|_| error!(ArenaError::InvalidGuild))?;
+        require!(winnings_share_bps <= 10_000, ArenaError::InvalidBasisPoints);
+
+        Ok(())
+    }
+
+    /// Portion of `points` owed to the guild vault
+    pub fn share_of(&self, points: u64) -> u64 {
+        (points as u128 * self.winnings_share_bps as u128 / 10_000) as u64
+    }
+}
+
+/// A wallet's membership in a guild. A wallet belongs to at most one guild.
+#[account]
+pub struct GuildMember {
+    /// Guild the wallet belongs to
+    pub guild: Pubkey,
+
+    /// Member wallet
+    pub wallet: Pubkey,
+
+    /// Unix timestamp when the wallet joined
+    pub joined_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl GuildMember {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 32 + 32 + 8 + 1 = 81 bytes
+    pub const SIZE: usize = 8 + 32 + 32 + 8 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"guild_member";
+}
+
+/// Program-owned vault holding a guild's SOL.
+/// Funded by anyone; pays members' tier fees when the guild sponsors them.
+#[account]
+pub struct GuildVault {
+    /// Guild owning the vault
+    pub guild: Pubkey,
+
+    /// Lifetime lamports deposited
+    pub total_deposited: u64,
+
+    /// Lifetime lamports spent on members' tier fees
+    pub total_fees_paid: u64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl GuildVault {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 32 + 8 + 8 + 1 = 57 bytes
+    pub const SIZE: usize = 8 + 32 + 8 + 8 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"guild_vault";
+
+    /// Lamports available above rent exemption
+    pub fn available(vault: &AccountInfo) -> Result<u64> {
+        let rent_floor = Rent::get()?.minimum_balance(vault.data_len());
+        Ok(vault.lamports().saturating_sub(rent_floor))
+    }
+}
+
+/// Aggregated results of a guild's members, updated as results are recorded.
+#[account]
+pub struct GuildStats {
+    /// Guild these stats belong to
+    pub guild: Pubkey,
+
+    /// Member tournament results recorded
+    pub member_results: u32,
+
+    /// Total POINTS awarded to members
+    pub total_points: u64,
+
+    /// Tournaments won by a member
+    pub member_wins: u32,
+
+    /// Best finish by any member (0 = none yet)
+    pub best_finish: u16,
+
+    /// Guild events won
+    pub guild_event_wins: u32,
+
+    /// Unix timestamp of the last update
+    pub last_updated: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl GuildStats {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 32 + 4 + 8 + 4 + 2 + 4 + 8 + 1 = 71 bytes
+    pub const SIZE: usize = 8 + 32 + 4 + 8 + 4 + 2 + 4 + 8 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"guild_stats";
+
+    /// Fold a member's tournament result into the guild's stats
+    pub fn record_result(&mut self, final_rank: u16, points_awarded: u64, now: i64) {
+        self.member_results = self.member_results.saturating_add(1);
+        self.total_points = self.total_points.saturating_add(points_awarded);
+        if final_rank == 1 {
+            self.member_wins = self.member_wins.saturating_add(1);
+        }
+        if self.best_finish == 0 || final_rank < self.best_finish {
+            self.best_finish = final_rank;
+        }
+        self.last_updated = now;
+    }
+}
+
+/// A guild's standing in a guild-vs-guild tournament.
+#[account]
+pub struct GuildEventEntry {
+    /// Guild event tournament
+    pub tournament: Pubkey,
+
+    /// Competing guild
+    pub guild: Pubkey,
+
+    /// Member results counted toward the guild
+    pub member_results: u16,
+
+    /// Total POINTS awarded to members in the tournament
+    pub total_points: u64,
+
+    /// Final guild rank (0 = unranked)
+    pub rank: u8,
+
+    /// Whether the guild's prize share has been claimed
+    pub prize_claimed: bool,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl GuildEventEntry {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 32 + 32 + 2 + 8 + 1 + 1 + 1 = 85 bytes
+    pub const SIZE: usize = 8 + 32 + 32 + 2 + 8 + 1 + 1 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"guild_event_entry";
+
+    /// Share of the guild prize for each rank, in basis points
+    pub const PRIZE_SPLIT_BPS: [u16; 3] = [5_000, 3_000, 2_000];
+
+    /// POINTS owed to this guild out of `prize_points`
+    pub fn prize_share(&self, prize_points: u64) -> u64 {
+        match Self::PRIZE_SPLIT_BPS.get((self.rank as usize).wrapping_sub(1)) {
+            Some(bps) => (prize_points as u128 * *bps as u128 / 10_000) as u64,
+            None => 0,
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+/// Lifetime count of one player eliminating another.
+/// Feeds the nemesis and top-victim rivalry stats on PlayerStatsV2.
+#[account]
+pub struct HeadToHead {
+    /// Player who took the last chips
+    pub eliminator: Pubkey,
+
+    /// Player who was knocked out
+    pub victim: Pubkey,
+
+    /// Number of times `eliminator` has knocked out `victim`
+    pub count: u32,
+
+    /// Most recent tournament with an elimination between the pair
+    pub last_tournament: Pubkey,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl HeadToHead {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 32 + 32 + 4 + 32 + 1 = 109 bytes
+    pub const SIZE: usize = 8 + 32 + 32 + 4 + 32 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"head_to_head";
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+
+/// Why a hand was bookmarked
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum HighlightTag {
+    /// Two very strong hands collided
+    #[default]
+    Cooler,
+    /// Largest pot of the tournament
+    BiggestPot,
+    /// A big favourite lost at showdown
+    BadBeat,
+    /// A large bluff got through
+    Bluff,
+    /// A hand that eliminated a player
+    Elimination,
+}
+
+/// One bookmarked hand.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct Highlight {
+    /// Index of the hand within the tournament
+    pub hand_index: u32,
+
+    /// Why the hand was bookmarked
+    pub tag: HighlightTag,
+
+    /// Unix timestamp the bookmark was recorded
+    pub recorded_at: i64,
+}
+
+/// Notable hands of a tournament, bookmarked by the operator for the recap page.
+#[account]
+pub struct Highlights {
+    /// Tournament the hands were played in
+    pub tournament: Pubkey,
+
+    /// Number of highlights in use
+    pub count: u8,
+
+    /// Bookmarked hands in recording order, the first `count` in use
+    pub entries: [Highlight; Highlights::MAX_ENTRIES],
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Highlights {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 32 + 1 + (13 * 32) + 1 = 458 bytes
+    pub const SIZE: usize = 8 + 32 + 1 + 13 * Self::MAX_ENTRIES + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"highlights";
+
+    /// Most hands bookmarked per tournament
+    pub const MAX_ENTRIES: usize = 32;
+
+    /// Bookmark a hand
+    pub fn record(&mut self, highlight: Highlight) -> Result<()> {
+        require!(
+            (self.count as usize) < Self::MAX_ENTRIES,
+            ArenaError::HighlightsFull
+        );
+        self.entries[self.count as usize] = highlight;
+        self.count += 1;
+        Ok(())
+    }
+}
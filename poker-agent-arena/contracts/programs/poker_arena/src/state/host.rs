@@ -0,0 +1,170 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::state::{ArenaConfig, CreationRateLimit, Tournament};
+
+/// Third-party host approved by the admin to create and run its own
+/// tournaments under the arena. Tier fees of hosted tournaments are split
+/// between the host's treasury and the arena's platform fee.
+///
+/// The host's SOL bond is held on this PDA above its rent exemption and can
+/// be slashed if a disputed tournament is never finalized or is voided.
+///
+/// Each host numbers its tournaments with its own counter, and their PDAs
+/// are seeded `[tournament, host, id]`, so hosts never contend on
+/// ArenaConfig.tournament_count. The arena's creation limits apply to each
+/// host separately, so one host's volume can't block another's.
+#[account]
+pub struct Host {
+    /// Host's signing key; operates every tournament it creates
+    pub authority: Pubkey,
+
+    /// Wallet receiving the host's share of tier fees
+    pub treasury: Pubkey,
+
+    /// Arena's share of hosted tier fees, in basis points
+    pub platform_fee_bps: u16,
+
+    /// Whether the host may currently create tournaments
+    pub active: bool,
+
+    /// Number of tournaments the host has created (its last ID sequence)
+    pub tournaments_created: u64,
+
+    /// Lamports of bond posted and not yet withdrawn or slashed
+    pub bond_lamports: u64,
+
+    /// Hosted tournaments not yet completed, cancelled or voided
+    pub open_tournaments: u32,
+
+    /// Hosted tournaments completed
+    pub tournaments_completed: u32,
+
+    /// Hosted tournaments completed after a dispute was raised
+    pub tournaments_disputed: u32,
+
+    /// Hosted tournaments voided after starting
+    pub tournaments_voided: u32,
+
+    /// Number of times the bond has been slashed
+    pub times_slashed: u32,
+
+    /// Share of closed tournaments completed without dispute, in basis points,
+    /// counting slashes as failures (0 until the first outcome)
+    pub reputation_bps: u16,
+
+    /// Unix timestamp of the last approval change
+    pub updated_at: i64,
+
+    /// Tournaments the host created over the trailing day
+    pub creation_rate: CreationRateLimit,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Host {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 32 + 32 + 2 + 1 + 8 + 8 + 4 + 4 + 4 + 4 + 4 + 2 + 8 + 56 + 1 = 178 bytes
+    pub const SIZE: usize = 8 + 32 + 32 + 2 + 1 + 8 + 8 + 4 + 4 + 4 + 4 + 4 + 2 + 8 + 56 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"host";
+
+    /// How long after its scheduled start a hosted tournament may stay
+    /// unfinalized before a disputed host can be slashed
+    pub const FINALIZE_DEADLINE_SECONDS: i64 = 7 * 24 * 60 * 60;
+
+    /// Tournament ID for the host's next tournament
+    pub fn next_tournament_id(&self) -> Result<u64> {
+        Ok(self
+            .tournaments_created
+            .checked_add(1)
+            .ok_or(ArenaError::ArithmeticOverflow)?)
+    }
+
+    /// Count a tournament created at `now` against the arena's creation rate
+    /// limits, applied to this host alone
+    pub fn record_tournament_created(
+        &mut self,
+        now: i64,
+        arena_config: &ArenaConfig,
+    ) -> Result<()> {
+        require!(
+            arena_config.max_open_tournaments == 0
+                || self.open_tournaments < arena_config.max_open_tournaments as u32,
+            ArenaError::TournamentRateLimited
+        );
+        self.creation_rate
+            .record(now, arena_config.max_tournaments_per_day)?;
+        self.tournaments_created = self.next_tournament_id()?;
+        self.open_tournaments = self
+            .open_tournaments
+            .checked_add(1)
+            .ok_or(ArenaError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// The host entry for `tournament`, required if it is hosted
+    pub fn for_tournament<'a, 'info>(
+        host: Option<&'a mut Account<'info, Host>>,
+        tournament: &Account<Tournament>,
+    ) -> Result<Option<&'a mut Account<'info, Host>>> {
+        match host {
+            Some(host) => {
+                require_keys_eq!(host.key(), tournament.host, ArenaError::InvalidHost);
+                Ok(Some(host))
+            }
+            None => {
+                require!(!tournament.is_hosted(), ArenaError::InvalidHost);
+                Ok(None)
+            }
+        }
+    }
+
+    /// Count a hosted tournament as completed
+    pub fn record_completed(&mut self, disputed: bool) {
+        self.open_tournaments = self.open_tournaments.saturating_sub(1);
+        self.tournaments_completed = self.tournaments_completed.saturating_add(1);
+        if disputed {
+            self.tournaments_disputed = self.tournaments_disputed.saturating_add(1);
+        }
+        self.refresh_reputation();
+    }
+
+    /// Count a hosted tournament as voided
+    pub fn record_voided(&mut self) {
+        self.open_tournaments = self.open_tournaments.saturating_sub(1);
+        self.tournaments_voided = self.tournaments_voided.saturating_add(1);
+        self.refresh_reputation();
+    }
+
+    /// Count a hosted tournament as cancelled before it started
+    pub fn record_cancelled(&mut self) {
+        self.open_tournaments = self.open_tournaments.saturating_sub(1);
+    }
+
+    /// Take up to `amount` lamports from the bond, returning what was slashed
+    pub fn slash(&mut self, amount: u64) -> u64 {
+        let slashed = amount.min(self.bond_lamports);
+        self.bond_lamports -= slashed;
+        self.times_slashed = self.times_slashed.saturating_add(1);
+        self.refresh_reputation();
+        slashed
+    }
+
+    fn refresh_reputation(&mut self) {
+        let clean = self
+            .tournaments_completed
+            .saturating_sub(self.tournaments_disputed) as u64;
+        let outcomes = self.tournaments_completed as u64
+            + self.tournaments_voided as u64
+            + self.times_slashed as u64;
+        self.reputation_bps = (clean * 10_000).checked_div(outcomes).unwrap_or(0) as u16;
+    }
+
+    /// Arena's platform fee out of `amount` hosted tier fees
+    pub fn platform_fee(&self, amount: u64) -> u64 {
+        (amount as u128 * self.platform_fee_bps as u128 / 10_000) as u64
+    }
+}
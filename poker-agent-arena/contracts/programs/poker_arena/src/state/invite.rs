@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+
+/// Single-use invite to a private tournament, keyed by the hash of its code.
+/// A wallet claims it with `redeem_invite` by revealing the code, and can
+/// then register for the tournament.
+#[account]
+pub struct Invite {
+    /// Tournament the invite admits to
+    pub tournament: Pubkey,
+
+    /// SHA-256 hash of the invite code
+    pub code_hash: [u8; 32],
+
+    /// Wallet that redeemed the invite (Pubkey::default() while unused)
+    pub redeemed_by: Pubkey,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Invite {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 32 + 32 + 32 + 1 = 105 bytes
+    pub const SIZE: usize = 8 + 32 + 32 + 32 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"invite";
+
+    /// Most invites created by one `create_invites` call
+    pub const MAX_PER_CALL: usize = 16;
+
+    /// SHA-256 hash an invite code is keyed by
+    pub fn hash_code(code: &[u8]) -> [u8; 32] {
+        hash(code).to_bytes()
+    }
+
+    /// Whether the invite has been redeemed
+    pub fn is_redeemed(&self) -> bool {
+        self.redeemed_by != Pubkey::default()
+    }
+}
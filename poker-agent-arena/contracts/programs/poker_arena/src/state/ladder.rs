@@ -0,0 +1,189 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+
+/// Ranked ladder division, ordered from lowest to highest
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Default,
+)]
+pub enum Division {
+    /// Entry division
+    #[default]
+    Bronze,
+    /// Silver division
+    Silver,
+    /// Gold division
+    Gold,
+    /// Platinum division
+    Platinum,
+    /// Diamond division
+    Diamond,
+    /// Top division
+    Master,
+}
+
+impl Division {
+    /// Divisions in ascending order
+    pub const ALL: [Division; 6] = [
+        Division::Bronze,
+        Division::Silver,
+        Division::Gold,
+        Division::Platinum,
+        Division::Diamond,
+        Division::Master,
+    ];
+
+    /// The next division up (Master stays Master)
+    pub fn promoted(self) -> Division {
+        Self::ALL[(self as usize + 1).min(Self::ALL.len() - 1)]
+    }
+
+    /// The next division down (Bronze stays Bronze)
+    pub fn relegated(self) -> Division {
+        Self::ALL[(self as usize).saturating_sub(1)]
+    }
+}
+
+/// Global heads-up ladder configuration.
+/// Divisions are re-evaluated against ELO floors once per ladder season.
+#[account]
+pub struct Ladder {
+    /// Current ladder season; ratings from earlier seasons are due a rollover
+    pub season: u32,
+
+    /// Minimum ELO for Silver, Gold, Platinum, Diamond and Master (ascending)
+    pub division_floors: [u16; 5],
+
+    /// ELO K-factor applied to each match
+    pub k_factor: u16,
+
+    /// Unix timestamp the current season started
+    pub season_started_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Ladder {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 4 + 10 + 2 + 8 + 1 = 33 bytes
+    pub const SIZE: usize = 8 + 4 + 10 + 2 + 8 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"ladder";
+
+    /// Rating assigned to new ladder players
+    pub const INITIAL_RATING: u16 = 1200;
+
+    /// Expected score of the higher-rated player, in basis points, for every
+    /// 50 ELO of rating difference up to 800 (interpolated in between)
+    pub const EXPECTED_BPS: [u16; 17] = [
+        5000, 5715, 6401, 7034, 7597, 8083, 8490, 8823, 9091, 9302, 9468, 9595, 9693, 9768, 9825,
+        9868, 9901,
+    ];
+
+    /// Set the division floors and K-factor, rejecting non-ascending floors
+    pub fn configure(&mut self, division_floors: [u16; 5], k_factor: u16) -> Result<()> {
+        require!(
+            k_factor > 0 && division_floors.windows(2).all(|pair| pair[0] < pair[1]),
+            ArenaError::InvalidLadderConfig
+        );
+        self.division_floors = division_floors;
+        self.k_factor = k_factor;
+        Ok(())
+    }
+
+    /// Division a player rated `rating` qualifies for
+    pub fn division_for(&self, rating: u16) -> Division {
+        let index = self
+            .division_floors
+            .iter()
+            .take_while(|floor| rating >= **floor)
+            .count();
+        Division::ALL[index]
+    }
+
+    /// Expected score of a player rated `rating` against `opponent`, in basis points
+    pub fn expected_bps(rating: u16, opponent: u16) -> u64 {
+        let diff = rating.abs_diff(opponent).min(800) as usize;
+        let (step, offset) = (diff / 50, diff % 50);
+        let low = Self::EXPECTED_BPS[step] as usize;
+        let high = Self::EXPECTED_BPS[(step + 1).min(16)] as usize;
+        let favorite = (low + (high - low) * offset / 50) as u64;
+        if rating >= opponent {
+            favorite
+        } else {
+            10_000 - favorite
+        }
+    }
+
+    /// Rating points the winner gains (and the loser drops) for a match
+    pub fn rating_delta(&self, winner: u16, loser: u16) -> u16 {
+        let expected = Self::expected_bps(winner, loser);
+        (self.k_factor as u64 * (10_000 - expected) / 10_000) as u16
+    }
+}
+
+/// A wallet's heads-up ladder rating and division.
+#[account]
+pub struct LadderRating {
+    /// Rated wallet
+    pub wallet: Pubkey,
+
+    /// Current ELO rating
+    pub rating: u16,
+
+    /// Highest rating reached
+    pub peak_rating: u16,
+
+    /// Current division (only changes at season rollover)
+    pub division: Division,
+
+    /// Ladder season this rating was last rolled over into
+    pub season: u32,
+
+    /// Ladder matches played
+    pub matches_played: u32,
+
+    /// Ladder matches won
+    pub matches_won: u32,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl LadderRating {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 32 + 2 + 2 + 1 + 4 + 4 + 4 + 1 = 58 bytes
+    pub const SIZE: usize = 8 + 32 + 2 + 2 + 1 + 4 + 4 + 4 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"ladder_rating";
+
+    /// Apply a match result, moving the rating by `delta`
+    pub fn record_match(&mut self, won: bool, delta: u16) {
+        self.matches_played = self.matches_played.saturating_add(1);
+        if won {
+            self.matches_won = self.matches_won.saturating_add(1);
+            self.rating = self.rating.saturating_add(delta);
+            self.peak_rating = self.peak_rating.max(self.rating);
+        } else {
+            self.rating = self.rating.saturating_sub(delta);
+        }
+    }
+
+    /// Move one division toward the one the rating qualifies for and catch
+    /// up to the ladder's season. Returns the new division.
+    pub fn roll_over(&mut self, ladder: &Ladder) -> Division {
+        let target = ladder.division_for(self.rating);
+        self.division = if target > self.division {
+            self.division.promoted()
+        } else if target < self.division {
+            self.division.relegated()
+        } else {
+            self.division
+        };
+        self.season = ladder.season;
+        self.division
+    }
+}
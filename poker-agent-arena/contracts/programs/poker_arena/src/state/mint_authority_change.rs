@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+/// Queued transfer or revocation of the POINTS mint authority. Token
+/// policy changes wait out a timelock so holders can see them coming.
+#[account]
+pub struct MintAuthorityChange {
+    /// Authority to hand minting to (None = revoke minting permanently)
+    pub new_authority: Option<Pubkey>,
+
+    /// Unix timestamp the change was queued
+    pub queued_at: i64,
+
+    /// Unix timestamp after which the change may be executed
+    pub executable_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl MintAuthorityChange {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 33 + 8 + 8 + 1 = 58 bytes
+    pub const SIZE: usize = 8 + 33 + 8 + 8 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"mint_authority_change";
+
+    /// Delay between queueing and executing a change
+    pub const TIMELOCK_SECONDS: i64 = 2 * 24 * 60 * 60;
+}
@@ -1,9 +1,113 @@
+pub mod action_log;
+pub mod action_share;
+pub mod airdrop;
+pub mod appeal;
+pub mod approved_model;
+pub mod arbiter;
+pub mod asset_recovery;
+pub mod audit_log;
+pub mod backing;
+pub mod bracket;
+pub mod buyback_ledger;
+pub mod commentator;
+pub mod compressed_roster;
 pub mod config;
+pub mod cpi_partner;
+pub mod crank_vault;
+pub mod daily_ticket;
+pub mod famous_hand;
+pub mod fee_escrow;
+pub mod gift_consent;
+pub mod governance;
+pub mod guild;
+pub mod head_to_head;
+pub mod highlights;
+pub mod host;
+pub mod invite;
+pub mod ladder;
 pub mod mint_authority;
+pub mod mint_authority_change;
+pub mod payment_receipt;
 pub mod player;
+pub mod player_stats_v2;
+pub mod points_claim;
+pub mod points_escrow;
+pub mod points_formula;
+pub mod prize_pool;
+pub mod profile;
+pub mod progress;
+pub mod prompt_escrow;
+pub mod rate_limit;
+pub mod redemption_vault;
+pub mod registration_index;
+pub mod results_attestation;
+pub mod season;
+pub mod season_rewards;
+pub mod session;
+pub mod shootout;
+pub mod snapshot;
+pub mod swiss;
+pub mod table;
+pub mod ticket;
 pub mod tournament;
+pub mod treasury_ledger;
+pub mod trophy;
+pub mod vesting;
+pub mod vote;
 
+pub use action_log::*;
+pub use action_share::*;
+pub use airdrop::*;
+pub use appeal::*;
+pub use approved_model::*;
+pub use arbiter::*;
+pub use asset_recovery::*;
+pub use audit_log::*;
+pub use backing::*;
+pub use bracket::*;
+pub use buyback_ledger::*;
+pub use commentator::*;
+pub use compressed_roster::*;
 pub use config::*;
+pub use cpi_partner::*;
+pub use crank_vault::*;
+pub use daily_ticket::*;
+pub use famous_hand::*;
+pub use fee_escrow::*;
+pub use gift_consent::*;
+pub use governance::*;
+pub use guild::*;
+pub use head_to_head::*;
+pub use highlights::*;
+pub use host::*;
+pub use invite::*;
+pub use ladder::*;
 pub use mint_authority::*;
+pub use mint_authority_change::*;
+pub use payment_receipt::*;
 pub use player::*;
+pub use player_stats_v2::*;
+pub use points_claim::*;
+pub use points_escrow::*;
+pub use points_formula::*;
+pub use prize_pool::*;
+pub use profile::*;
+pub use progress::*;
+pub use prompt_escrow::*;
+pub use rate_limit::*;
+pub use redemption_vault::*;
+pub use registration_index::*;
+pub use results_attestation::*;
+pub use season::*;
+pub use season_rewards::*;
+pub use session::*;
+pub use shootout::*;
+pub use snapshot::*;
+pub use swiss::*;
+pub use table::*;
+pub use ticket::*;
 pub use tournament::*;
+pub use treasury_ledger::*;
+pub use trophy::*;
+pub use vesting::*;
+pub use vote::*;
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+
+/// What a fee payment was for
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum PaymentKind {
+    /// Agent tier fee paid at registration
+    #[default]
+    Tier,
+    /// Rebuy into a tournament after busting
+    Rebuy,
+    /// Add-on chips purchased during a tournament
+    Addon,
+    /// Upgrade of an existing registration to a higher tier
+    Upgrade,
+}
+
+/// On-chain proof of a single fee payment, closable by the payer.
+#[account]
+pub struct PaymentReceipt {
+    /// Wallet that paid the fee
+    pub payer: Pubkey,
+
+    /// Tournament the fee was paid for
+    pub tournament: Pubkey,
+
+    /// Registration the fee was paid for
+    pub registration: Pubkey,
+
+    /// Fee paid, in lamports
+    pub amount: u64,
+
+    /// What the fee was for
+    pub kind: PaymentKind,
+
+    /// Unix timestamp of the payment
+    pub paid_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl PaymentReceipt {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 32 + 32 + 32 + 8 + 1 + 8 + 1 = 122 bytes
+    pub const SIZE: usize = 8 + 32 + 32 + 32 + 8 + 1 + 8 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"payment_receipt";
+
+    /// Record a payment on a freshly created receipt
+    #[allow(clippy::too_many_arguments)]
+    pub fn record(
+        &mut self,
+        payer: Pubkey,
+        tournament: Pubkey,
+        registration: Pubkey,
+        amount: u64,
+        kind: PaymentKind,
+        now: i64,
+        bump: u8,
+    ) {
+        self.payer = payer;
+        self.tournament = tournament;
+        self.registration = registration;
+        self.amount = amount;
+        self.kind = kind;
+        self.paid_at = now;
+        self.bump = bump;
+    }
+}
@@ -1,5 +1,8 @@
 use anchor_lang::prelude::*;
 
+use crate::errors::ArenaError;
+use crate::state::{Appeal, AppealableSanction, ArenaConfig, Tournament, TournamentStatus};
+
 /// Agent tier enum
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
 pub enum AgentTier {
@@ -28,6 +31,30 @@ impl Default for AgentTier {
     }
 }
 
+/// Per-player behavioral metrics reported by the game engine, as scaled integers.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct AgentMetrics {
+    /// Voluntarily put money in pot, in basis points of hands dealt
+    pub vpip_bps: u16,
+
+    /// Aggression factor ((bets + raises) / calls) multiplied by 100
+    pub aggression_factor_centi: u16,
+
+    /// Showdowns won, in basis points of showdowns reached
+    pub showdown_win_bps: u16,
+}
+
+impl AgentMetrics {
+    /// Validate that rate metrics are within 0-10000 basis points
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.vpip_bps <= 10_000 && self.showdown_win_bps <= 10_000,
+            ArenaError::InvalidBasisPoints
+        );
+        Ok(())
+    }
+}
+
 /// Player registration for a specific tournament.
 #[account]
 pub struct PlayerRegistration {
@@ -67,17 +94,235 @@ pub struct PlayerRegistration {
     /// Whether POINTS tokens have been distributed to this player
     pub points_distributed: bool,
 
+    /// Whether the revealed prompt matched agent_prompt_hash
+    pub prompt_verified: bool,
+
+    /// Cumulative POINTS removed by penalties
+    pub penalty_points: u64,
+
+    /// Whether the player has been disqualified
+    pub disqualified: bool,
+
+    /// Unix timestamp of the most recent penalty or disqualification not yet
+    /// ruled on by an appeal (0 if none)
+    pub sanctioned_at: i64,
+
+    /// Whether an appeal against a sanction is awaiting resolution
+    pub appeal_pending: bool,
+
+    /// Lamports of the tier fee refunded so far
+    pub refunded_lamports: u64,
+
+    /// Share of winnings sold to action buyers, in basis points
+    pub action_sold_bps: u16,
+
+    /// Tier fee actually paid after any discount
+    pub fee_paid_lamports: u64,
+
+    /// SHA-256 hash of the approved model backing a PRO agent (zeros otherwise)
+    pub model_id_hash: [u8; 32],
+
+    /// Engine behavioral metrics for the agent (None if not completed)
+    pub metrics: Option<AgentMetrics>,
+
+    /// POINTS holder discount applied to the tier fee, in basis points
+    pub holder_discount_bps: u16,
+
+    /// Whether the player voluntarily forfeited mid-tournament
+    pub forfeited: bool,
+
+    /// Unix timestamp of the forfeit (0 if none)
+    pub forfeited_at: i64,
+
+    /// Decisions the agent timed out on, as last committed by the engine
+    pub timeouts: u16,
+
+    /// Seconds of time bank the agent has used, as last committed by the engine
+    pub time_bank_used_secs: u32,
+
+    /// Finishing position fixed when the player busted (None while still in)
+    pub bust_position: Option<u16>,
+
+    /// Bounties credited for players this agent eliminated
+    pub bounties: u16,
+
+    /// Lamports of the tier fee kept by the treasury when the player unregistered late
+    pub retained_lamports: u64,
+
+    /// Whether the player unregistered before the tournament started
+    pub unregistered: bool,
+
+    /// Account that paid the tier fee and receives any refund: the player,
+    /// a gifting sponsor, or the guild vault that sponsored them
+    pub fee_payer: Pubkey,
+
+    /// Whether the player has disputed the tournament's results (once per
+    /// registration)
+    pub disputed: bool,
+
+    /// Number of sanctions applied; seeds the Appeal against the latest one
+    pub sanction_nonce: u16,
+
+    /// What the sanctions since the last appeal decision took, while they
+    /// can still be appealed
+    pub appealable_sanction: AppealableSanction,
+
+    /// Account that paid the registration's rent and receives it back when
+    /// the registration is collected: the player, a relayer, a session key,
+    /// a gifting sponsor or the admin for reserved seats
+    pub rent_payer: Pubkey,
+
     /// PDA bump seed
     pub bump: u8,
 }
 
 impl PlayerRegistration {
     /// Account size for rent calculation
-    /// 8 (discriminator) + 32 + 32 + 1 + 8 + 32 + 32 + 128 + 3 + 9 + 5 + 2 + 1 + 1 = 294 bytes
-    pub const SIZE: usize = 8 + 32 + 32 + 1 + 8 + 32 + 32 + 128 + 3 + 9 + 5 + 2 + 1 + 1;
+    /// 8 (discriminator) + 32 + 32 + 1 + 8 + 32 + 32 + 128 + 3 + 9 + 5 + 2 + 1 + 1 + 8 + 1 + 8 + 1 + 8 + 2 + 8 + 32 + 7 + 2 + 1 + 8 + 2 + 4 + 3 + 2 + 8 + 1 + 32 + 1 + 2 + 25 + 32 + 1 = 493 bytes
+    pub const SIZE: usize = 8 + 32 + 32 + 1 + 8 + 32 + 32 + 128 + 3 + 9 + 5 + 2 + 1 + 1 + 8 + 1 + 8 + 1 + 8 + 2 + 8 + 32 + 7 + 2 + 1 + 8 + 2 + 4 + 3 + 2 + 8 + 1 + 32 + 1 + 2 + 25 + 32 + 1;
 
     /// PDA seeds prefix
     pub const SEED_PREFIX: &'static [u8] = b"registration";
+
+    /// URI schemes accepted for agent avatar images
+    pub const ALLOWED_URI_SCHEMES: [&'static [u8]; 2] = [b"ipfs://", b"https://"];
+
+    /// Validate a null-padded agent name.
+    /// Must be non-empty, valid UTF-8 and free of control characters,
+    /// with nothing but padding after the first null byte.
+    pub fn validate_agent_name(agent_name: &[u8; 32]) -> Result<()> {
+        let name = trim_padding(agent_name).ok_or(ArenaError::InvalidAgentName)?;
+        let name = core::str::from_utf8(name).map_err(|_| ArenaError::InvalidAgentName)?;
+
+        require!(!name.trim().is_empty(), ArenaError::InvalidAgentName);
+        require!(
+            !name.chars().any(char::is_control),
+            ArenaError::InvalidAgentName
+        );
+
+        Ok(())
+    }
+
+    /// Validate a null-padded agent image URI.
+    /// An all-zero value means no avatar; otherwise the URI must be printable
+    /// ASCII without whitespace and use one of the allowed schemes.
+    pub fn validate_agent_image_uri(agent_image_uri: &[u8; 128]) -> Result<()> {
+        let uri = trim_padding(agent_image_uri).ok_or(ArenaError::InvalidAgentImageUri)?;
+
+        if uri.is_empty() {
+            return Ok(());
+        }
+
+        require!(
+            uri.iter().all(|b| b.is_ascii_graphic()),
+            ArenaError::InvalidAgentImageUri
+        );
+        require!(
+            Self::ALLOWED_URI_SCHEMES
+                .iter()
+                .any(|scheme| uri.len() > scheme.len() && uri.starts_with(scheme)),
+            ArenaError::InvalidAgentImageUri
+        );
+
+        Ok(())
+    }
+
+    /// Lamports of the tier fee not yet settled, either refunded to the
+    /// player or kept by the treasury when they unregistered
+    pub fn unsettled_fee_lamports(&self) -> u64 {
+        self.fee_paid_lamports
+            .saturating_sub(self.refunded_lamports)
+            .saturating_sub(self.retained_lamports)
+    }
+
+    /// Whether the latest sanction can still be appealed at `now`
+    pub fn sanction_appealable(&self, now: i64) -> bool {
+        self.sanctioned_at != 0 && now <= self.sanctioned_at.saturating_add(Appeal::WINDOW_SECONDS)
+    }
+
+    /// Whether POINTS awarded to the player are held back at `now` because a
+    /// sanction may still be appealed or an appeal awaits resolution
+    pub fn sanction_pending(&self, now: i64) -> bool {
+        self.appeal_pending || self.sanction_appealable(now)
+    }
+
+    /// Start a sanction at `now`, returning the appealable record to add what
+    /// it takes to. Sanctions applied while the previous one can still be
+    /// appealed are appealed together with it.
+    pub fn record_sanction(&mut self, now: i64) -> Result<&mut AppealableSanction> {
+        require!(!self.appeal_pending, ArenaError::AppealPending);
+        if !self.sanction_appealable(now) {
+            self.appealable_sanction = AppealableSanction::default();
+        }
+        self.sanction_nonce = self
+            .sanction_nonce
+            .checked_add(1)
+            .ok_or(ArenaError::ArithmeticOverflow)?;
+        self.sanctioned_at = now;
+        Ok(&mut self.appealable_sanction)
+    }
+
+    /// Whether the registration can be garbage collected: the tournament is
+    /// closed and nothing (fee refund, points, appeal, action share claims)
+    /// still needs this account. Unregistered players' fees are settled when
+    /// they unregister, so their registrations are collectable at once.
+    pub fn is_collectable(&self, tournament: &Tournament) -> bool {
+        if self.unregistered {
+            return true;
+        }
+        if self.appeal_pending {
+            return false;
+        }
+        match tournament.status {
+            // Fees are refunded against the registration
+            TournamentStatus::Cancelled | TournamentStatus::Voided => {
+                self.unsettled_fee_lamports() == 0
+            }
+            TournamentStatus::Completed => {
+                let points_settled = self.points_distributed
+                    || tournament.points_root.is_some()
+                    || self.points_awarded.unwrap_or_default() == 0;
+                points_settled && self.action_sold_bps == 0
+            }
+            _ => false,
+        }
+    }
+
+    /// Wallet that receives this registration's rent when it is collected:
+    /// whoever paid it, or the forfeited rent recipient for voided
+    /// tournaments and disqualified players
+    pub fn rent_recipient(&self, tournament: &Tournament, arena_config: &ArenaConfig) -> Pubkey {
+        if self.disqualified || tournament.status == TournamentStatus::Voided {
+            arena_config.forfeited_rent_recipient
+        } else {
+            self.rent_payer
+        }
+    }
+}
+
+/// Strip trailing null padding from a fixed-size byte field.
+/// Returns None if a non-null byte appears after the first null byte.
+fn trim_padding(bytes: &[u8]) -> Option<&[u8]> {
+    let len = bytes.iter().position(|b| *b == 0).unwrap_or(bytes.len());
+    if bytes[len..].iter().all(|b| *b == 0) {
+        Some(&bytes[..len])
+    } else {
+        None
+    }
+}
+
+/// Loyalty level earned by playing tournaments, discounting tier fees
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum LoyaltyLevel {
+    /// Below the Bronze threshold
+    #[default]
+    None,
+    /// Bronze level
+    Bronze,
+    /// Silver level
+    Silver,
+    /// Gold level
+    Gold,
 }
 
 /// Lifetime player statistics.
@@ -121,4 +366,65 @@ impl PlayerStats {
 
     /// PDA seeds prefix
     pub const SEED_PREFIX: &'static [u8] = b"player_stats";
+
+    /// Fold a tournament result into lifetime stats, initializing them on first use.
+    #[allow(clippy::too_many_arguments)]
+    pub fn record_result(
+        &mut self,
+        wallet: Pubkey,
+        tournament: Pubkey,
+        final_rank: u16,
+        points_awarded: u64,
+        hands_played: u32,
+        eliminations: u8,
+        now: i64,
+        bump: u8,
+    ) {
+        let is_new_stats = self.wallet == Pubkey::default();
+
+        if is_new_stats {
+            // First time this player has stats recorded
+            self.wallet = wallet;
+            self.tournaments_played = 1;
+            self.tournaments_won = if final_rank == 1 { 1 } else { 0 };
+            self.total_points = points_awarded;
+            self.best_finish = final_rank;
+            self.total_hands_played = hands_played as u64;
+            self.total_eliminations = eliminations as u32;
+            self.bump = bump;
+        } else {
+            // Update existing stats
+            self.tournaments_played = self.tournaments_played.saturating_add(1);
+            if final_rank == 1 {
+                self.tournaments_won = self.tournaments_won.saturating_add(1);
+            }
+            self.total_points = self.total_points.saturating_add(points_awarded);
+            if final_rank < self.best_finish || self.best_finish == 0 {
+                self.best_finish = final_rank;
+            }
+            self.total_hands_played = self.total_hands_played.saturating_add(hands_played as u64);
+            self.total_eliminations = self.total_eliminations.saturating_add(eliminations as u32);
+        }
+
+        self.last_tournament = tournament;
+        self.last_played_at = now;
+    }
+
+    /// Take back a result folded in by `record_result`, for a voided
+    /// tournament. The best finish is kept, as the previous best is not recorded.
+    pub fn discard_result(
+        &mut self,
+        final_rank: u16,
+        points_awarded: u64,
+        hands_played: u32,
+        eliminations: u8,
+    ) {
+        self.tournaments_played = self.tournaments_played.saturating_sub(1);
+        if final_rank == 1 {
+            self.tournaments_won = self.tournaments_won.saturating_sub(1);
+        }
+        self.total_points = self.total_points.saturating_sub(points_awarded);
+        self.total_hands_played = self.total_hands_played.saturating_sub(hands_played as u64);
+        self.total_eliminations = self.total_eliminations.saturating_sub(eliminations as u32);
+    }
 }
@@ -0,0 +1,219 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{AgentTier, HeadToHead, LoyaltyLevel};
+
+/// Lifetime record for tournaments played at one agent tier.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct TierRecord {
+    /// Tournaments played at this tier
+    pub played: u32,
+
+    /// Tournaments won at this tier
+    pub wins: u32,
+
+    /// Best finish at this tier (0 if none)
+    pub best_finish: u16,
+}
+
+/// Extended lifetime player statistics, kept alongside PlayerStats.
+/// Created from the player's existing PlayerStats the first time a result is
+/// recorded with it; results before that are only counted in
+/// `legacy_tournaments_played`. Metrics added after launch live here so
+/// existing PlayerStats accounts keep their original layout.
+#[account]
+pub struct PlayerStatsV2 {
+    /// Player's wallet address
+    pub wallet: Pubkey,
+
+    /// Layout version (PlayerStatsV2::VERSION once initialized)
+    pub version: u8,
+
+    /// Tournaments played before this account existed (from PlayerStats)
+    pub legacy_tournaments_played: u32,
+
+    /// Tournaments tracked by this account
+    pub tournaments_played: u32,
+
+    /// Sum of finishing positions, for the average finish
+    pub total_finish: u64,
+
+    /// Finishes in the money (POINTS awarded)
+    pub itm_finishes: u32,
+
+    /// Cumulative number of players finished ahead of
+    pub field_sizes_beaten: u64,
+
+    /// Records per agent tier, indexed FREE, BASIC, PRO
+    pub tier_records: [TierRecord; 3],
+
+    /// Most recent tournament
+    pub last_tournament: Pubkey,
+
+    /// Loyalty level, re-evaluated from PlayerStats.tournaments_played
+    /// whenever a result is recorded
+    pub loyalty_level: LoyaltyLevel,
+
+    /// Opponent who has eliminated this player most often
+    pub nemesis: Pubkey,
+
+    /// Times the nemesis has eliminated this player
+    pub nemesis_eliminations: u32,
+
+    /// Opponent this player has eliminated most often
+    pub top_victim: Pubkey,
+
+    /// Times this player has eliminated the top victim
+    pub top_victim_eliminations: u32,
+
+    /// Sum of percentile finishes (in bps) weighted by each field's entrants
+    pub weighted_percentile_total: u64,
+
+    /// Sum of entrants across all recorded fields, the weight denominator
+    pub weighted_entrants_total: u64,
+
+    /// Field-size-normalized performance score in basis points (10000 = won every event)
+    pub performance_score_bps: u16,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl PlayerStatsV2 {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 32 + 1 + 4 + 4 + 8 + 4 + 8 + 30 + 32 + 1 + 32 + 4 + 32 + 4 + 8 + 8 + 2 + 1 = 223 bytes
+    pub const SIZE: usize = 8 + 32 + 1 + 4 + 4 + 8 + 4 + 8 + 30 + 32 + 1 + 32 + 4 + 32 + 4 + 8 + 8 + 2 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"player_stats_v2";
+
+    /// Current layout version
+    pub const VERSION: u8 = 2;
+
+    /// Initialize from the player's v1 stats if this account is new
+    pub fn migrate(&mut self, wallet: Pubkey, legacy_tournaments_played: u32, bump: u8) {
+        if self.version != 0 {
+            return;
+        }
+        self.wallet = wallet;
+        self.version = Self::VERSION;
+        self.legacy_tournaments_played = legacy_tournaments_played;
+        self.bump = bump;
+    }
+
+    /// Percentile finish in basis points: 10000 for first place, 0 for last.
+    ///
+    /// A field of one counts as a win.
+    pub fn percentile_bps(final_rank: u16, entrants: u16) -> u64 {
+        if entrants <= 1 {
+            return 10_000;
+        }
+        let rank = final_rank.clamp(1, entrants) as u64;
+        (entrants as u64 - rank) * 10_000 / (entrants as u64 - 1)
+    }
+
+    /// Fold a tournament result of `field_size` players into the stats.
+    ///
+    /// The performance score averages percentile finishes weighted by field
+    /// size, so a deep run in a large field outweighs one in a small field.
+    pub fn record_result(
+        &mut self,
+        tournament: Pubkey,
+        tier: AgentTier,
+        final_rank: u16,
+        field_size: u16,
+        points_awarded: u64,
+    ) {
+        self.tournaments_played = self.tournaments_played.saturating_add(1);
+        self.total_finish = self.total_finish.saturating_add(final_rank as u64);
+        if points_awarded > 0 {
+            self.itm_finishes = self.itm_finishes.saturating_add(1);
+        }
+        self.field_sizes_beaten = self
+            .field_sizes_beaten
+            .saturating_add(field_size.saturating_sub(final_rank) as u64);
+
+        let record = &mut self.tier_records[tier as usize];
+        record.played = record.played.saturating_add(1);
+        if final_rank == 1 {
+            record.wins = record.wins.saturating_add(1);
+        }
+        if record.best_finish == 0 || final_rank < record.best_finish {
+            record.best_finish = final_rank;
+        }
+
+        let weight = field_size.max(1) as u64;
+        self.weighted_percentile_total = self
+            .weighted_percentile_total
+            .saturating_add(Self::percentile_bps(final_rank, field_size) * weight);
+        self.weighted_entrants_total = self.weighted_entrants_total.saturating_add(weight);
+        self.performance_score_bps =
+            (self.weighted_percentile_total / self.weighted_entrants_total) as u16;
+
+        self.last_tournament = tournament;
+    }
+
+    /// Take back a result folded in by `record_result`, for a voided
+    /// tournament. Best finishes are kept, as the previous best is not recorded.
+    pub fn discard_result(
+        &mut self,
+        tier: AgentTier,
+        final_rank: u16,
+        field_size: u16,
+        points_awarded: u64,
+    ) {
+        self.tournaments_played = self.tournaments_played.saturating_sub(1);
+        self.total_finish = self.total_finish.saturating_sub(final_rank as u64);
+        if points_awarded > 0 {
+            self.itm_finishes = self.itm_finishes.saturating_sub(1);
+        }
+        self.field_sizes_beaten = self
+            .field_sizes_beaten
+            .saturating_sub(field_size.saturating_sub(final_rank) as u64);
+
+        let record = &mut self.tier_records[tier as usize];
+        record.played = record.played.saturating_sub(1);
+        if final_rank == 1 {
+            record.wins = record.wins.saturating_sub(1);
+        }
+
+        let weight = field_size.max(1) as u64;
+        self.weighted_percentile_total = self
+            .weighted_percentile_total
+            .saturating_sub(Self::percentile_bps(final_rank, field_size) * weight);
+        self.weighted_entrants_total = self.weighted_entrants_total.saturating_sub(weight);
+        self.performance_score_bps = self
+            .weighted_percentile_total
+            .checked_div(self.weighted_entrants_total)
+            .unwrap_or_default() as u16;
+    }
+
+    /// Update nemesis and top victim from a head-to-head record involving this player.
+    pub fn observe_head_to_head(&mut self, head_to_head: &HeadToHead) {
+        let HeadToHead { eliminator, victim, count, .. } = *head_to_head;
+
+        if victim == self.wallet && (count > self.nemesis_eliminations || eliminator == self.nemesis) {
+            self.nemesis = eliminator;
+            self.nemesis_eliminations = count;
+        }
+        if eliminator == self.wallet && (count > self.top_victim_eliminations || victim == self.top_victim) {
+            self.top_victim = victim;
+            self.top_victim_eliminations = count;
+        }
+    }
+
+    /// Average finishing position multiplied by 100 (0 if none tracked)
+    pub fn average_finish_centi(&self) -> u64 {
+        if self.tournaments_played == 0 {
+            return 0;
+        }
+        self.total_finish.saturating_mul(100) / self.tournaments_played as u64
+    }
+
+    /// Share of tracked tournaments finished in the money, in basis points
+    pub fn itm_bps(&self) -> u16 {
+        if self.tournaments_played == 0 {
+            return 0;
+        }
+        (self.itm_finishes as u64 * 10_000 / self.tournaments_played as u64) as u16
+    }
+}
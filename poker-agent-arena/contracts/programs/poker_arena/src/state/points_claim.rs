@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+/// Marker recording that a wallet claimed its POINTS from a tournament's Merkle root.
+/// Its existence prevents double claims.
+#[account]
+pub struct PointsClaim {
+    /// Tournament the claim was made against
+    pub tournament: Pubkey,
+
+    /// Claiming wallet
+    pub wallet: Pubkey,
+
+    /// POINTS minted by the claim (base units)
+    pub amount: u64,
+
+    /// Unix timestamp of the claim
+    pub claimed_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl PointsClaim {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 32 + 32 + 8 + 8 + 1 = 89 bytes
+    pub const SIZE: usize = 8 + 32 + 32 + 8 + 8 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"points_claim";
+}
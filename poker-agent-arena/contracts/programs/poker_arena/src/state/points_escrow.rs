@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+/// A player's POINTS, and their guild's share of them, held back while the
+/// tournament can still be disputed. Released by `release_points` once the
+/// dispute window closes undisputed or the dispute is resolved.
+#[account]
+pub struct PointsEscrow {
+    /// Player wallet the POINTS belong to
+    pub wallet: Pubkey,
+
+    /// Tournament the award came from
+    pub tournament: Pubkey,
+
+    /// POINTS held in escrow
+    pub points: u64,
+
+    /// Guild's share of the award held in escrow
+    pub guild_points: u64,
+
+    /// Guild vault whose token account receives guild_points (default if none)
+    pub guild_vault: Pubkey,
+
+    /// Whether the POINTS have been released
+    pub released: bool,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl PointsEscrow {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 32 + 32 + 8 + 8 + 32 + 1 + 1 = 122 bytes
+    pub const SIZE: usize = 8 + 32 + 32 + 8 + 8 + 32 + 1 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"points_escrow";
+}
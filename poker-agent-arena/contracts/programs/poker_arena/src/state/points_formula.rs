@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::state::AgentTier;
+
+/// Published points-award formula committed on a tournament. Recorded
+/// awards must match it exactly, so results can't deviate from what
+/// entrants were told.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct PointsFormula {
+    /// Whole POINTS added to the award pool per registered entrant
+    pub base_per_entrant: u64,
+
+    /// Share of the pool awarded to each finishing place, best first, in basis points
+    pub rank_bps: [u16; PointsFormula::MAX_PAID_PLACES],
+
+    /// Bonus on top of the award per tier, indexed FREE, BASIC, PRO, in basis points
+    pub tier_bonus_bps: [u16; 3],
+}
+
+impl PointsFormula {
+    /// Serialized size: 8 + 32 + 6 = 46 bytes
+    pub const SIZE: usize = 8 + 2 * PointsFormula::MAX_PAID_PLACES + 2 * 3;
+
+    /// Maximum number of places the formula pays
+    pub const MAX_PAID_PLACES: usize = 16;
+
+    /// Check the places pay no more than the whole pool, best first, and
+    /// tier bonuses are at most 100%
+    pub fn validate(&self) -> Result<()> {
+        let total_bps: u32 = self.rank_bps.iter().map(|bps| *bps as u32).sum();
+        require!(total_bps <= 10_000, ArenaError::InvalidPointsFormula);
+        require!(
+            self.rank_bps.windows(2).all(|pair| pair[0] >= pair[1]),
+            ArenaError::InvalidPointsFormula
+        );
+        require!(
+            self.tier_bonus_bps.iter().all(|bps| *bps <= 10_000),
+            ArenaError::InvalidPointsFormula
+        );
+        Ok(())
+    }
+
+    /// Whole POINTS the formula awards a `tier` agent finishing at `final_rank`
+    /// in a field of `entrants`, before season multipliers and penalties
+    pub fn award(&self, final_rank: u16, tier: AgentTier, entrants: u16) -> u64 {
+        let Some(rank_bps) = (final_rank as usize)
+            .checked_sub(1)
+            .and_then(|index| self.rank_bps.get(index))
+        else {
+            return 0;
+        };
+        let pool = self.base_per_entrant as u128 * entrants as u128;
+        let award = pool * *rank_bps as u128 / 10_000;
+        let bonus_bps = self.tier_bonus_bps[tier as usize] as u128;
+        (award * (10_000 + bonus_bps) / 10_000) as u64
+    }
+}
@@ -0,0 +1,146 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+
+/// Currency a tournament's prize pool is paid in
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum PrizeDenomination {
+    /// POINTS minted to winners when they claim
+    #[default]
+    Points,
+    /// Lamports held by the prize pool PDA
+    Sol,
+    /// USDC held in the prize pool's token vault
+    Usdc,
+}
+
+/// Where the rounding remainder of a basis-point prize split goes
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum DustPolicy {
+    /// Added to first place's prize
+    #[default]
+    FirstPlace,
+    /// Swept to the treasury by `sweep_prize_dust`
+    Treasury,
+}
+
+/// Escrowed prize pool for a tournament, split across the paid places.
+/// SOL is held on the PDA itself, USDC in token accounts it owns, and
+/// POINTS are minted on claim up to the committed total.
+#[account]
+pub struct PrizePool {
+    /// Tournament the prizes are for
+    pub tournament: Pubkey,
+
+    /// Currency the pool pays out in (copied from the tournament)
+    pub denomination: PrizeDenomination,
+
+    /// Token mint paid out (USDC or POINTS mint; Pubkey::default() for SOL)
+    pub mint: Pubkey,
+
+    /// Total prize committed, in the denomination's base units
+    pub total: u64,
+
+    /// Amount paid out to winners so far
+    pub paid_out: u64,
+
+    /// Share of `total` paid to each finishing place, best first, in basis points
+    pub payout_bps: [u16; PrizePool::MAX_PAID_PLACES],
+
+    /// Bit i set once place i + 1 has claimed
+    pub claimed_mask: u16,
+
+    /// Where the rounding remainder of the split goes
+    pub dust_policy: DustPolicy,
+
+    /// Whether the remainder has been swept to the treasury
+    pub dust_swept: bool,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl PrizePool {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 32 + 1 + 32 + 8 + 8 + 32 + 2 + 1 + 1 + 1 = 126 bytes
+    pub const SIZE: usize = 8 + 32 + 1 + 32 + 8 + 8 + 2 * PrizePool::MAX_PAID_PLACES + 2 + 1 + 1 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"prize_pool";
+
+    /// Maximum number of paid places
+    pub const MAX_PAID_PLACES: usize = 16;
+
+    /// Place `index + 1`'s share of the total, rounded down
+    fn share(&self, index: usize) -> u64 {
+        (self.total as u128 * self.payout_bps[index] as u128 / 10_000) as u64
+    }
+
+    /// Remainder left by rounding every place's share down, so that the
+    /// shares plus the dust always sum to exactly `total`
+    pub fn dust(&self) -> u64 {
+        let shares: u64 = (0..Self::MAX_PAID_PLACES)
+            .map(|index| self.share(index))
+            .sum();
+        self.total - shares
+    }
+
+    /// Paid place for `final_rank` and its prize, including the dust for
+    /// first place under DustPolicy::FirstPlace
+    pub fn prize(&self, final_rank: u16) -> Option<(usize, u64)> {
+        let index = (final_rank as usize).checked_sub(1)?;
+        if index >= Self::MAX_PAID_PLACES || self.payout_bps[index] == 0 {
+            return None;
+        }
+        let mut amount = self.share(index);
+        if index == 0 && self.dust_policy == DustPolicy::FirstPlace {
+            amount += self.dust();
+        }
+        Some((index, amount))
+    }
+
+    /// Count `amount` as paid out, never beyond the pool total
+    pub fn record_payout(&mut self, amount: u64) -> Result<()> {
+        self.paid_out = self
+            .paid_out
+            .checked_add(amount)
+            .filter(|paid_out| *paid_out <= self.total)
+            .ok_or(ArenaError::InvalidPrizePool)?;
+        Ok(())
+    }
+
+    /// Unclaimed prize for `final_rank` and its amount
+    pub fn unclaimed(&self, final_rank: u16) -> Option<(usize, u64)> {
+        self.prize(final_rank)
+            .filter(|(index, _)| self.claimed_mask & (1 << index) == 0)
+    }
+}
+
+/// Marker recording that an action buyer or backer claimed their cut of a
+/// player's prize. Its existence prevents double claims.
+#[account]
+pub struct PrizeShareClaim {
+    /// Prize pool the cut was paid from
+    pub prize_pool: Pubkey,
+
+    /// Action buyer or backer paid
+    pub holder: Pubkey,
+
+    /// Amount paid, in the pool denomination's base units
+    pub amount: u64,
+
+    /// Unix timestamp of the claim
+    pub claimed_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl PrizeShareClaim {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 32 + 32 + 8 + 8 + 1 = 89 bytes
+    pub const SIZE: usize = 8 + 32 + 32 + 8 + 8 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"prize_share_claim";
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+use crate::state::PlayerRegistration;
+
+/// Player's display identity, shared across tournaments.
+/// Derived from the wallet alongside PlayerStats so leaderboards can resolve it.
+#[account]
+pub struct PlayerProfile {
+    /// Player's wallet address
+    pub wallet: Pubkey,
+
+    /// Display name (32 bytes, UTF-8, null-padded)
+    pub display_name: [u8; 32],
+
+    /// Avatar URI (128 bytes, ipfs:// or https://, or all zeros for none)
+    pub avatar_uri: [u8; 128],
+
+    /// SHA-256 hash of the bio text stored off-chain
+    pub bio_hash: [u8; 32],
+
+    /// ISO 3166-1 alpha-2 country code (all zeros for none)
+    pub country_code: [u8; 2],
+
+    /// Unix timestamp when the profile was created
+    pub created_at: i64,
+
+    /// Unix timestamp of the last update
+    pub updated_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl PlayerProfile {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 32 + 32 + 128 + 32 + 2 + 8 + 8 + 1 = 251 bytes
+    pub const SIZE: usize = 8 + 32 + 32 + 128 + 32 + 2 + 8 + 8 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"player_profile";
+
+    /// Validate profile fields, reusing the agent metadata rules for name and avatar
+    pub fn validate(
+        display_name: &[u8; 32],
+        avatar_uri: &[u8; 128],
+        country_code: &[u8; 2],
+    ) -> Result<()> {
+        PlayerRegistration::validate_agent_name(display_name)
+            .map_err(|_| error!(ArenaError::InvalidProfile))?;
+        PlayerRegistration::validate_agent_image_uri(avatar_uri)
+            .map_err(|_| error!(ArenaError::InvalidProfile))?;
+        require!(
+            *country_code == [0; 2] || country_code.iter().all(u8::is_ascii_uppercase),
+            ArenaError::InvalidProfile
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+/// Live progress of an in-progress tournament, synced by the operator.
+/// A trustable progress source for the explorer and frontend.
+#[account]
+pub struct TournamentProgress {
+    /// Tournament being tracked
+    pub tournament: Pubkey,
+
+    /// Most recent hand number dealt across all tables
+    pub hand_number: u64,
+
+    /// Players still holding chips
+    pub players_remaining: u16,
+
+    /// Cumulative chips pushed into pots so far
+    pub total_pot_dealt: u64,
+
+    /// Unix timestamp of the last sync
+    pub updated_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl TournamentProgress {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 32 + 8 + 2 + 8 + 8 + 1 = 67 bytes
+    pub const SIZE: usize = 8 + 32 + 8 + 2 + 8 + 8 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"progress";
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+/// Encrypted prompt commitment with a reveal bond.
+/// The player commits to an encrypted prompt blob and its decryption key;
+/// the bond is returned only if the key is revealed after the tournament.
+#[account]
+pub struct PromptEscrow {
+    /// Registration this commitment belongs to
+    pub registration: Pubkey,
+
+    /// Player's wallet address (bond is refunded here)
+    pub wallet: Pubkey,
+
+    /// SHA-256 hash of the encrypted prompt blob (stored off-chain)
+    pub encrypted_prompt_hash: [u8; 32],
+
+    /// SHA-256 hash of the decryption key
+    pub key_commitment: [u8; 32],
+
+    /// Revealed decryption key (zeroed until revealed)
+    pub decryption_key: [u8; 32],
+
+    /// Bond currently held by this account, in lamports
+    pub bond_lamports: u64,
+
+    /// Whether the decryption key has been revealed
+    pub key_revealed: bool,
+
+    /// Unix timestamp when the commitment was made
+    pub committed_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl PromptEscrow {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 32 + 32 + 32 + 32 + 32 + 8 + 1 + 8 + 1 = 186 bytes
+    pub const SIZE: usize = 8 + 32 + 32 + 32 + 32 + 32 + 8 + 1 + 8 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"prompt_escrow";
+
+    /// Bond posted at commitment time (0.05 SOL)
+    pub const BOND_LAMPORTS: u64 = 50_000_000;
+
+    /// Seconds after tournament completion during which the key must be revealed (3 days)
+    pub const REVEAL_WINDOW_SECONDS: i64 = 3 * 24 * 60 * 60;
+
+    /// Check if the reveal window is still open for a tournament completed at `completed_at`
+    pub fn is_reveal_window_open(completed_at: i64, now: i64) -> bool {
+        now <= completed_at.saturating_add(Self::REVEAL_WINDOW_SECONDS)
+    }
+}
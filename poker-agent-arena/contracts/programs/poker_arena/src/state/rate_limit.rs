@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+
+/// Rolling count of tournaments created over the trailing day, kept in
+/// hourly buckets so the limit frees up an hour at a time instead of
+/// resetting all at once.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct CreationRateLimit {
+    /// Hour (Unix timestamp / BUCKET_SECONDS) of the newest bucket
+    pub current_hour: i64,
+
+    /// Tournaments created per hour of the trailing day, indexed by hour % BUCKETS
+    pub hourly_created: [u16; CreationRateLimit::BUCKETS],
+}
+
+impl CreationRateLimit {
+    /// Serialized size
+    /// 8 + 2 * 24 = 56 bytes
+    pub const SIZE: usize = 8 + 2 * Self::BUCKETS;
+
+    /// Hourly buckets in the rolling window (1 day)
+    pub const BUCKETS: usize = 24;
+
+    /// Length of each bucket
+    pub const BUCKET_SECONDS: i64 = 3_600;
+
+    /// Tournaments created over the trailing day
+    pub fn created_in_window(&self) -> u32 {
+        self.hourly_created.iter().map(|&count| count as u32).sum()
+    }
+
+    /// Advance the window to `now`, clearing buckets more than a day old
+    fn roll(&mut self, now: i64) {
+        let hour = now.div_euclid(Self::BUCKET_SECONDS);
+        let elapsed = hour.saturating_sub(self.current_hour);
+        if elapsed <= 0 {
+            return;
+        }
+        for step in 1..=elapsed.min(Self::BUCKETS as i64) {
+            let bucket = self
+                .current_hour
+                .saturating_add(step)
+                .rem_euclid(Self::BUCKETS as i64) as usize;
+            self.hourly_created[bucket] = 0;
+        }
+        self.current_hour = hour;
+    }
+
+    /// Count a tournament created at `now`, failing once `max_per_day`
+    /// tournaments (0 = unlimited) were already created in the trailing day
+    pub fn record(&mut self, now: i64, max_per_day: u16) -> Result<()> {
+        self.roll(now);
+        require!(
+            max_per_day == 0 || self.created_in_window() < max_per_day as u32,
+            ArenaError::TournamentRateLimited
+        );
+        let bucket = self.current_hour.rem_euclid(Self::BUCKETS as i64) as usize;
+        self.hourly_created[bucket] = self.hourly_created[bucket]
+            .checked_add(1)
+            .ok_or(ArenaError::ArithmeticOverflow)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+/// Program-owned SOL vault that buys back POINTS for burning.
+/// Gives POINTS a floor value without an external market.
+#[account]
+pub struct RedemptionVault {
+    /// Lamports paid per whole POINT (0 = pro-rata share of the vault)
+    pub lamports_per_point: u64,
+
+    /// Lifetime POINTS burned through redemption (base units)
+    pub total_points_redeemed: u64,
+
+    /// Lifetime lamports paid to redeemers
+    pub total_lamports_paid: u64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl RedemptionVault {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 8 + 8 + 8 + 1 = 33 bytes
+    pub const SIZE: usize = 8 + 8 + 8 + 8 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"redemption_vault";
+
+    /// Lamports owed for burning `amount` base units.
+    ///
+    /// Uses the fixed rate if set, otherwise `amount`'s share of `available`
+    /// lamports relative to the circulating `supply`. `base_units_per_point`
+    /// comes from the POINTS mint's decimals.
+    pub fn quote(
+        &self,
+        amount: u64,
+        available: u64,
+        supply: u64,
+        base_units_per_point: u64,
+    ) -> Option<u64> {
+        let lamports = if self.lamports_per_point > 0 {
+            (amount as u128)
+                .checked_mul(self.lamports_per_point as u128)?
+                .checked_div(base_units_per_point as u128)?
+        } else {
+            (amount as u128)
+                .checked_mul(available as u128)?
+                .checked_div(supply as u128)?
+        };
+        u64::try_from(lamports).ok()
+    }
+}
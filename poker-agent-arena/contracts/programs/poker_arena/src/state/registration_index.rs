@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+
+/// Per-wallet list of the tournaments the wallet holds a registration in,
+/// so clients can list a wallet's tournaments without scanning every
+/// registration. Appended when the wallet registers and pruned when the
+/// registration is collected.
+#[account]
+pub struct RegistrationIndex {
+    /// Wallet the index belongs to
+    pub wallet: Pubkey,
+
+    /// Number of entries in use
+    pub count: u8,
+
+    /// Tournament ids, the first `count` in use
+    pub tournament_ids: [u64; RegistrationIndex::MAX_ENTRIES],
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl RegistrationIndex {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 32 + 1 + (8 * 32) + 1 = 298 bytes
+    pub const SIZE: usize = 8 + 32 + 1 + 8 * Self::MAX_ENTRIES + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"registration_index";
+
+    /// Most registrations indexed at once
+    pub const MAX_ENTRIES: usize = 32;
+
+    /// Tournament ids currently indexed
+    pub fn tournament_ids(&self) -> &[u64] {
+        &self.tournament_ids[..self.count as usize]
+    }
+
+    /// Add a tournament the wallet registered for
+    pub fn append(&mut self, tournament_id: u64) -> Result<()> {
+        require!(
+            (self.count as usize) < Self::MAX_ENTRIES,
+            ArenaError::RegistrationIndexFull
+        );
+        self.tournament_ids[self.count as usize] = tournament_id;
+        self.count += 1;
+        Ok(())
+    }
+
+    /// Drop a tournament whose registration was collected (no-op if absent)
+    pub fn remove(&mut self, tournament_id: u64) {
+        if let Some(index) = self
+            .tournament_ids()
+            .iter()
+            .position(|id| *id == tournament_id)
+        {
+            let last = self.count as usize - 1;
+            self.tournament_ids[index] = self.tournament_ids[last];
+            self.tournament_ids[last] = 0;
+            self.count -= 1;
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+/// Final tournament results in a fixed layout for external programs
+/// (betting, lending against trophies) to read or pass through CPI
+/// without depending on the Tournament layout, which grows over time.
+///
+/// Layout (little-endian, offsets include the 8-byte discriminator):
+///
+/// | Offset | Size | Field            |
+/// |--------|------|------------------|
+/// | 0      | 8    | discriminator    |
+/// | 8      | 1    | version          |
+/// | 9      | 32   | tournament       |
+/// | 41     | 8    | tournament_id    |
+/// | 49     | 32   | results_hash     |
+/// | 81     | 32   | winner           |
+/// | 113    | 2    | entrants         |
+/// | 115    | 8    | seed_slot        |
+/// | 123    | 32   | seed_blockhash   |
+/// | 155    | 8    | completed_at     |
+/// | 163    | 1    | bump             |
+///
+/// Fields are only ever appended, under a new `version`.
+#[account]
+pub struct ResultsAttestation {
+    /// Layout version (currently `ResultsAttestation::VERSION`)
+    pub version: u8,
+
+    /// Tournament PDA the results belong to
+    pub tournament: Pubkey,
+
+    /// Tournament ID
+    pub tournament_id: u64,
+
+    /// Confirmed results hash
+    pub results_hash: [u8; 32],
+
+    /// Confirmed winner
+    pub winner: Pubkey,
+
+    /// Registered players when results were confirmed
+    pub entrants: u16,
+
+    /// Solana slot used for the tournament's RNG seed commitment
+    pub seed_slot: u64,
+
+    /// Blockhash commitment for provably fair randomness
+    pub seed_blockhash: [u8; 32],
+
+    /// Unix timestamp the results were confirmed
+    pub completed_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ResultsAttestation {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 1 + 32 + 8 + 32 + 32 + 2 + 8 + 32 + 8 + 1 = 164 bytes
+    pub const SIZE: usize = 8 + 1 + 32 + 8 + 32 + 32 + 2 + 8 + 32 + 8 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"results_attestation";
+
+    /// Current layout version
+    pub const VERSION: u8 = 1;
+}
@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+
+/// A season of tournaments, with an optional pass for sale.
+#[account]
+pub struct Season {
+    /// Season number (admin-chosen, unique)
+    pub id: u32,
+
+    /// Unix timestamp when the season begins (inclusive)
+    pub starts_at: i64,
+
+    /// Unix timestamp when the season ends (exclusive)
+    pub ends_at: i64,
+
+    /// Price of a season pass in lamports, paid to the treasury
+    pub pass_price_lamports: u64,
+
+    /// Points multiplier for pass holders, in basis points (10000 = 1x)
+    pub points_multiplier_bps: u16,
+
+    /// Number of passes sold
+    pub passes_sold: u32,
+
+    /// Share of seasonal rake paid to the top agents, in basis points
+    pub revenue_share_bps: u16,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Season {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 4 + 8 + 8 + 8 + 2 + 4 + 2 + 1 = 45 bytes
+    pub const SIZE: usize = 8 + 4 + 8 + 8 + 8 + 2 + 4 + 2 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"season";
+
+    /// PDA seeds prefix for the season's own POINTS mint
+    pub const MINT_SEED_PREFIX: &'static [u8] = b"season_mint";
+
+    /// Whether a tournament starting at `starts_at` belongs to this season
+    pub fn covers(&self, starts_at: i64) -> bool {
+        starts_at >= self.starts_at && starts_at < self.ends_at
+    }
+
+    /// Apply the pass holder multiplier to `points`
+    pub fn multiply_points(&self, points: u64) -> u64 {
+        let multiplied = points as u128 * self.points_multiplier_bps as u128 / 10_000;
+        u64::try_from(multiplied).unwrap_or(u64::MAX)
+    }
+}
+
+/// Season pass held by a wallet.
+/// Waives BASIC tier fees and multiplies points for tournaments in the season.
+#[account]
+pub struct SeasonPass {
+    /// Season this pass is for
+    pub season: Pubkey,
+
+    /// Pass holder
+    pub wallet: Pubkey,
+
+    /// Unix timestamp when the pass was bought
+    pub purchased_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl SeasonPass {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 32 + 32 + 8 + 1 = 81 bytes
+    pub const SIZE: usize = 8 + 32 + 32 + 8 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"season_pass";
+
+    /// Whether this pass entitles `wallet` to benefits in a tournament
+    /// starting at `starts_at`
+    pub fn applies(&self, season: &Account<Season>, wallet: &Pubkey, starts_at: i64) -> bool {
+        self.season == season.key() && self.wallet == *wallet && season.covers(starts_at)
+    }
+}
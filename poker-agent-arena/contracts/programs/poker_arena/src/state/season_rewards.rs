@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+/// Claimable vault paying a cut of seasonal rake to the season's top agents.
+/// Holds the reward lamports directly; winners claim their share after funding.
+#[account]
+pub struct SeasonRewards {
+    /// Season these rewards are for
+    pub season: Pubkey,
+
+    /// Seasonal rake reported by the treasury when funding
+    pub seasonal_rake_lamports: u64,
+
+    /// Lamports deposited for winners
+    pub total_lamports: u64,
+
+    /// Top agents on the season leaderboard, best first (Pubkey::default() for unused slots)
+    pub winners: [Pubkey; SeasonRewards::MAX_WINNERS],
+
+    /// Each winner's share of `total_lamports`, in basis points
+    pub share_bps: [u16; SeasonRewards::MAX_WINNERS],
+
+    /// Bit i set once winners[i] has claimed
+    pub claimed_mask: u16,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl SeasonRewards {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 32 + 8 + 8 + 320 + 20 + 2 + 1 = 399 bytes
+    pub const SIZE: usize = 8 + 32 + 8 + 8 + 320 + 20 + 2 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"season_rewards";
+
+    /// Maximum number of rewarded agents (K)
+    pub const MAX_WINNERS: usize = 10;
+
+    /// Unclaimed reward slot for `wallet` and its lamport amount
+    pub fn unclaimed(&self, wallet: &Pubkey) -> Option<(usize, u64)> {
+        let index = self.winners.iter().position(|winner| winner == wallet)?;
+        if self.claimed_mask & (1 << index) != 0 {
+            return None;
+        }
+        let amount = self.total_lamports as u128 * self.share_bps[index] as u128 / 10_000;
+        Some((index, amount as u64))
+    }
+}
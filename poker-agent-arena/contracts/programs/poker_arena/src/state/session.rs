@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+/// Short-lived key authorized to act for a wallet within a scope.
+/// Lets the web app register and update agents without a wallet popup.
+#[account]
+pub struct SessionAuthority {
+    /// Wallet the session acts for
+    pub wallet: Pubkey,
+
+    /// Session key allowed to sign
+    pub session_key: Pubkey,
+
+    /// Bitmask of permitted actions (SCOPE_* constants)
+    pub scope: u8,
+
+    /// Unix timestamp after which the session is no longer valid
+    pub expires_at: i64,
+
+    /// Unix timestamp when the session was created
+    pub created_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl SessionAuthority {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 32 + 32 + 1 + 8 + 8 + 1 = 90 bytes
+    pub const SIZE: usize = 8 + 32 + 32 + 1 + 8 + 8 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"session";
+
+    /// Session may sign `register_player_with_session`
+    pub const SCOPE_REGISTER: u8 = 1 << 0;
+
+    /// Session may sign `update_agent`
+    pub const SCOPE_UPDATE_AGENT: u8 = 1 << 1;
+
+    /// Longest allowed session lifetime
+    pub const MAX_DURATION_SECONDS: i64 = 7 * 24 * 60 * 60;
+
+    /// Whether `signer` may act for the wallet in `scope` at `now`
+    pub fn allows(&self, signer: &Pubkey, scope: u8, now: i64) -> bool {
+        self.session_key == *signer && self.scope & scope == scope && now < self.expires_at
+    }
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::seating;
+
+/// Shootout schedule for a tournament.
+/// Each round seats the remaining field at single-table shootouts; every
+/// table plays down to one winner, and the winners form the next round.
+#[account]
+pub struct Shootout {
+    /// Tournament being played
+    pub tournament: Pubkey,
+
+    /// Current round (1-based)
+    pub round: u8,
+
+    /// Current round's field, sorted by wallet
+    pub players: Vec<Pubkey>,
+
+    /// Table number of the current round's first table; its tables are
+    /// numbered consecutively from here
+    pub first_table: u16,
+
+    /// Winner of each current-round table (Pubkey::default() until decided)
+    pub winners: Vec<Pubkey>,
+
+    /// Winner of the final table
+    pub champion: Option<Pubkey>,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Shootout {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 32 + 1 + (4 + 32 * 81) + 2 + (4 + 32 * 9) + 33 + 1 = 2965 bytes
+    pub const SIZE: usize =
+        8 + 32 + 1 + (4 + 32 * Self::MAX_PLAYERS) + 2 + (4 + 32 * Self::MAX_TABLES) + 33 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"shootout";
+
+    /// Maximum players in a shootout
+    pub const MAX_PLAYERS: usize = 81;
+
+    /// Maximum tables in a round
+    pub const MAX_TABLES: usize = 9;
+
+    /// Start a round with `players` as its field
+    pub fn start_round(&mut self, round: u8, mut players: Vec<Pubkey>) {
+        players.sort();
+        self.round = round;
+        self.winners = vec![Pubkey::default(); seating::table_count(players.len())];
+        self.players = players;
+    }
+
+    /// Position within the current round of `table_number`, if it is one
+    /// of the round's tables
+    pub fn table_index(&self, table_number: u16) -> Option<usize> {
+        let index = table_number.checked_sub(self.first_table)? as usize;
+        (index < self.winners.len()).then_some(index)
+    }
+
+    /// Whether every table of the current round has a winner
+    pub fn round_complete(&self) -> bool {
+        !self.winners.contains(&Pubkey::default())
+    }
+}
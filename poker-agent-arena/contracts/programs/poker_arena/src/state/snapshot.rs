@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::merkle;
+
+/// Point-in-time Merkle root of the leaderboard, for proof-based airdrops by
+/// partner projects.
+#[account]
+pub struct Snapshot {
+    /// Sequential snapshot ID (matches snapshot_count at creation)
+    pub id: u64,
+
+    /// Merkle root over leaves SHA-256(0x00 || wallet || total_points_le)
+    pub root: [u8; 32],
+
+    /// Wallets included in the snapshot
+    pub wallet_count: u32,
+
+    /// Sum of total_points across included wallets
+    pub total_points: u64,
+
+    /// Slot the snapshot was taken at
+    pub slot: u64,
+
+    /// Unix timestamp the snapshot was taken at
+    pub taken_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Snapshot {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 8 + 32 + 4 + 8 + 8 + 8 + 1 = 77 bytes
+    pub const SIZE: usize = 8 + 8 + 32 + 4 + 8 + 8 + 8 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"snapshot";
+
+    /// Whether `wallet` held `total_points` at the time of the snapshot
+    pub fn verify(&self, wallet: &Pubkey, total_points: u64, proof: &[[u8; 32]]) -> bool {
+        let leaf = merkle::leaf_hash(&[wallet.as_ref(), &total_points.to_le_bytes()]);
+        merkle::verify_proof(proof, &self.root, leaf)
+    }
+}
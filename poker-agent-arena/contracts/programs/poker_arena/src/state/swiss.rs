@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+
+/// Swiss-system schedule for a tournament.
+/// Holds the current round's pairings, derived on-chain from standings and
+/// the committed seed.
+#[account]
+pub struct SwissEvent {
+    /// Tournament being played
+    pub tournament: Pubkey,
+
+    /// Rounds in the schedule
+    pub rounds: u8,
+
+    /// Current round (1-based; 0 before the first pairing)
+    pub round: u8,
+
+    /// Current round's players in pairing order; players 2k and 2k + 1 meet
+    pub pairings: Vec<Pubkey>,
+
+    /// Results recorded in the current round
+    pub results_recorded: u16,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl SwissEvent {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 32 + 1 + 1 + (4 + 32 * 64) + 2 + 1 = 2097 bytes
+    pub const SIZE: usize = 8 + 32 + 1 + 1 + (4 + 32 * Self::MAX_PLAYERS) + 2 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"swiss";
+
+    /// Maximum players in a Swiss event
+    pub const MAX_PLAYERS: usize = 64;
+
+    /// Whether every match of the current round has a result
+    pub fn round_complete(&self) -> bool {
+        self.results_recorded as usize == self.pairings.len() / 2
+    }
+
+    /// Whether the final round has been played out
+    pub fn is_complete(&self) -> bool {
+        self.round == self.rounds && self.round_complete()
+    }
+
+    /// Whether `winner` and `loser` meet in the current round
+    pub fn are_paired(&self, winner: &Pubkey, loser: &Pubkey) -> bool {
+        self.pairings
+            .chunks_exact(2)
+            .any(|pair| pair.contains(winner) && pair.contains(loser))
+    }
+}
+
+/// A player's standing in a Swiss event.
+#[account]
+pub struct SwissStanding {
+    /// Tournament being played
+    pub tournament: Pubkey,
+
+    /// Player's wallet
+    pub wallet: Pubkey,
+
+    /// Match wins, including byes
+    pub wins: u8,
+
+    /// Match losses
+    pub losses: u8,
+
+    /// Byes received
+    pub byes: u8,
+
+    /// Last round this standing has a result for
+    pub last_round: u8,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl SwissStanding {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 32 + 32 + 1 + 1 + 1 + 1 + 1 = 77 bytes
+    pub const SIZE: usize = 8 + 32 + 32 + 1 + 1 + 1 + 1 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"swiss_standing";
+}
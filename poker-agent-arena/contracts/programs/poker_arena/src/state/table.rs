@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ArenaError;
+
+/// Table account for multi-table tournaments.
+/// Holds the current seat assignments so seat draws and balancing moves
+/// can be checked against the committed RNG seed.
+#[account]
+pub struct Table {
+    /// Tournament this table belongs to
+    pub tournament: Pubkey,
+
+    /// Table number within the tournament (1-based)
+    pub table_number: u16,
+
+    /// Player wallet in each seat (Pubkey::default() if empty)
+    pub seats: [Pubkey; Table::MAX_SEATS],
+
+    /// Hands dealt at this table so far
+    pub hand_count: u64,
+
+    /// Unix timestamp of the last seating change
+    pub updated_at: i64,
+
+    /// Whether the initial seating was verified against the seeded draw
+    pub draw_verified: bool,
+
+    /// Shootout round the table belongs to (0 outside shootouts)
+    pub round: u8,
+
+    /// Player who won the table (shootout tables only)
+    pub winner: Option<Pubkey>,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Table {
+    /// Maximum seats per table
+    pub const MAX_SEATS: usize = 9;
+
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 32 + 2 + (32 * 9) + 8 + 8 + 1 + 1 + 33 + 1 = 382 bytes
+    pub const SIZE: usize = 8 + 32 + 2 + (32 * Self::MAX_SEATS) + 8 + 8 + 1 + 1 + 33 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"table";
+
+    /// Number of occupied seats
+    pub fn player_count(&self) -> usize {
+        self.seats
+            .iter()
+            .filter(|s| **s != Pubkey::default())
+            .count()
+    }
+
+    /// Whether `wallet` occupies a seat
+    pub fn is_seated(&self, wallet: &Pubkey) -> bool {
+        *wallet != Pubkey::default() && self.seats.contains(wallet)
+    }
+
+    /// Validate that no player occupies more than one seat
+    pub fn validate_seats(seats: &[Pubkey; Self::MAX_SEATS]) -> Result<()> {
+        for (i, seat) in seats.iter().enumerate() {
+            if *seat == Pubkey::default() {
+                continue;
+            }
+            require!(!seats[i + 1..].contains(seat), ArenaError::InvalidSeating);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::state::AgentTier;
+
+/// Entry tickets bought with POINTS for one agent tier.
+/// A ticket waives that tier's fee for one registration.
+#[account]
+pub struct Ticket {
+    /// Wallet holding the tickets
+    pub wallet: Pubkey,
+
+    /// Tier the tickets cover
+    pub tier: AgentTier,
+
+    /// Unused tickets
+    pub tickets: u16,
+
+    /// Lifetime tickets bought
+    pub total_bought: u32,
+
+    /// Lifetime tickets spent on registrations
+    pub total_used: u32,
+
+    /// Lifetime POINTS burned for tickets
+    pub total_points_burned: u64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Ticket {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 32 + 1 + 2 + 4 + 4 + 8 + 1 = 60 bytes
+    pub const SIZE: usize = 8 + 32 + 1 + 2 + 4 + 4 + 8 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"ticket";
+
+    /// Spend one ticket on a `tier` registration, if one is held
+    pub fn redeem(&mut self, tier: AgentTier) -> bool {
+        if self.tier != tier || self.tickets == 0 {
+            return false;
+        }
+        self.tickets -= 1;
+        self.total_used = self.total_used.saturating_add(1);
+        true
+    }
+}
@@ -1,5 +1,11 @@
 use anchor_lang::prelude::*;
 
+use crate::blinds::{BlindLevel, BlindPreset};
+use crate::errors::ArenaError;
+use crate::state::{
+    AgentTier, ArenaConfig, Division, PlayerRegistration, PointsFormula, PrizeDenomination,
+};
+
 /// Tournament status enum
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
 pub enum TournamentStatus {
@@ -13,6 +19,10 @@ pub enum TournamentStatus {
     Completed,
     /// Tournament was cancelled
     Cancelled,
+    /// Tournament was voided after an unrecoverable engine failure; fees are refundable
+    Voided,
+    /// Results submitted provisionally; confirmed once the dispute window closes
+    ResultsSubmitted,
 }
 
 impl Default for TournamentStatus {
@@ -21,14 +31,31 @@ impl Default for TournamentStatus {
     }
 }
 
+/// How a tournament's field is played down to a winner
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum TournamentFormat {
+    /// Multi-table ring game, players eliminated as they bust
+    #[default]
+    RingGame,
+    /// Seeded single-elimination bracket of heads-up matches
+    SingleElimination,
+    /// Fixed number of heads-up rounds paired by standings
+    Swiss,
+    /// Seeded double-elimination bracket with winners and losers brackets
+    DoubleElimination,
+    /// Rounds of single-table shootouts; each table's winner advances
+    Shootout,
+}
+
 /// Tournament account.
 /// Stores all tournament configuration and state.
 #[account]
 pub struct Tournament {
-    /// Unique tournament ID (matches tournament_count at creation)
+    /// Tournament ID: tournament_count at creation, or the host's own
+    /// sequence number for hosted tournaments (unique per host)
     pub id: u64,
 
-    /// Admin who created the tournament (must match ArenaConfig.admin)
+    /// Admin or host authority who created the tournament
     pub admin: Pubkey,
 
     /// Current tournament status
@@ -70,26 +97,291 @@ pub struct Tournament {
     /// Blockhash commitment for provably fair randomness
     pub seed_blockhash: [u8; 32],
 
+    /// Key delegated to run this tournament (Pubkey::default() if none)
+    pub operator: Pubkey,
+
+    /// Merkle root of (wallet, points) for claim-based settlement (None if per-player minting)
+    pub points_root: Option<[u8; 32]>,
+
+    /// Mint a participation trophy cNFT to each player when results are recorded
+    pub trophies_enabled: bool,
+
+    /// Lamports paid to the treasury in fees for this tournament
+    pub fees_collected_lamports: u64,
+
+    /// Lamports refunded by the treasury for this tournament
+    pub fees_refunded_lamports: u64,
+
+    /// SHA-256 hash of the game engine build, committed at start (zeros if crank-started)
+    pub engine_version_hash: [u8; 32],
+
+    /// SHA-256 hash of the LLM model identifier, committed at start (zeros if crank-started)
+    pub model_id_hash: [u8; 32],
+
+    /// Head of the hash chain over committed hand-log batches (zeroed before the first batch)
+    pub game_log_head: [u8; 32],
+
+    /// Number of hand-log batches committed
+    pub game_log_batches: u32,
+
+    /// Results are disputed; finalization requires arbiter attestations
+    pub disputed: bool,
+
+    /// Guild-vs-guild event: member results are also aggregated per guild
+    pub guild_event: bool,
+
+    /// POINTS split between the top guilds of a guild event
+    pub guild_prize_points: u64,
+
+    /// Guild standings have been finalized
+    pub guild_standings_final: bool,
+
+    /// Earliest registration time per tier, indexed FREE, BASIC, PRO
+    /// (0 = open with registration). Lets higher tiers register early.
+    pub tier_opens_at: [i64; 3],
+
+    /// Seats held back for sponsor and exhibition agents, filled by the admin
+    pub reserved_seats: u16,
+
+    /// Reserved seats filled so far (included in registered_players)
+    pub reserved_registered: u16,
+
+    /// Unix timestamp the dispute window on submitted results closes; results
+    /// are confirmed once it closes or a dispute is resolved
+    pub dispute_window_ends_at: i64,
+
+    /// A dispute was resolved by arbiter attestation
+    pub dispute_resolved: bool,
+
+    /// Registered players per tier, indexed FREE, BASIC, PRO
+    pub tier_counts: [u16; 3],
+
+    /// Merkle root over the tournament's hand histories (None until committed)
+    pub hand_history_root: Option<[u8; 32]>,
+
+    /// Ladder division registrations are restricted to (None = open to all)
+    pub division: Option<Division>,
+
+    /// The heads-up result has been applied to the players' ladder ratings
+    pub ladder_recorded: bool,
+
+    /// Format the tournament is played in
+    pub format: TournamentFormat,
+
+    /// Built-in blind structure (Custom when committed by blind_structure_hash)
+    pub blind_preset: BlindPreset,
+
+    /// Currency the prize pool is paid in
+    pub prize_denomination: PrizeDenomination,
+
+    /// Seconds past starts_at that registration stays open (0 = closes at starts_at)
+    pub late_registration_secs: u32,
+
+    /// Committed points-award formula recorded awards must match (None = unchecked)
+    pub points_formula: Option<PointsFormula>,
+
+    /// Tier fees are held in the tournament's FeeEscrow until it starts
+    pub fees_escrowed: bool,
+
+    /// Registration requires an invite redeemed by the registering wallet
+    pub invite_only: bool,
+
+    /// Season-scoped mint POINTS are awarded in (None = the arena's POINTS mint)
+    pub season_mint: Option<Pubkey>,
+
+    /// Host that created and runs the tournament (Pubkey::default() if the arena)
+    pub host: Pubkey,
+
     /// PDA bump seed
     pub bump: u8,
 }
 
 impl Tournament {
     /// Account size for rent calculation
-    /// 8 (discriminator) + 8 + 32 + 1 + 8 + 8 + 9 + 2 + 2 + 8 + 32 + 32 + 33 + 33 + 8 + 32 + 1 = 277 bytes
-    pub const SIZE: usize = 8 + 8 + 32 + 1 + 8 + 8 + 9 + 2 + 2 + 8 + 32 + 32 + 33 + 33 + 8 + 32 + 1;
+    /// 8 (discriminator) + 8 + 32 + 1 + 8 + 8 + 9 + 2 + 2 + 8 + 32 + 32 + 33 + 33 + 8 + 32 + 32 + 33 + 1 + 8 + 8 + 32 + 32 + 32 + 4 + 1 + 1 + 8 + 1 + 24 + 2 + 2 + 8 + 1 + 6 + 33 + 2 + 1 + 1 + 1 + 1 + 1 + 4 + 47 + 1 + 1 + 33 + 32 = 670 bytes
+    pub const SIZE: usize = 8 + 8 + 32 + 1 + 8 + 8 + 9 + 2 + 2 + 8 + 32 + 32 + 33 + 33 + 8 + 32 + 32 + 33 + 1 + 8 + 8 + 32 + 32 + 32 + 4 + 1 + 1 + 8 + 1 + 24 + 2 + 2 + 8 + 1 + 6 + 33 + 2 + 1 + 1 + 1 + 1 + 1 + 4 + 1 + PointsFormula::SIZE + 1 + 1 + 33 + 32;
 
     /// PDA seeds prefix
     pub const SEED_PREFIX: &'static [u8] = b"tournament";
 
+    /// Largest field a tournament may be created or expanded to
+    pub const MAX_PLAYERS: u16 = 1024;
+
+    /// Check `max_players` is within 2..=MAX_PLAYERS
+    pub fn validate_max_players(max_players: u16) -> Result<()> {
+        require!(
+            (2..=Self::MAX_PLAYERS).contains(&max_players),
+            ArenaError::InvalidMaxPlayers
+        );
+        Ok(())
+    }
+
     /// Check if registration is open
     pub fn is_registration_open(&self) -> bool {
         self.status == TournamentStatus::Registration
     }
 
-    /// Check if tournament is full
+    /// Check if registration has closed at `now`: the scheduled start plus
+    /// any late registration period has passed, even if the tournament was
+    /// never started
+    pub fn is_registration_closed(&self, now: i64) -> bool {
+        now >= self
+            .starts_at
+            .saturating_add(self.late_registration_secs as i64)
+    }
+
+    /// Check if `tier` may register at `now` under the priority windows
+    pub fn is_tier_open(&self, tier: AgentTier, now: i64) -> bool {
+        now >= self.tier_opens_at[tier as usize]
+    }
+
+    /// Check if the public (non-reserved) seats are full
     pub fn is_full(&self) -> bool {
-        self.registered_players >= self.max_players
+        self.registered_players.saturating_sub(self.reserved_registered)
+            >= self.max_players.saturating_sub(self.reserved_seats)
+    }
+
+    /// Check if every reserved seat is filled
+    pub fn is_reserved_full(&self) -> bool {
+        self.reserved_registered >= self.reserved_seats
+    }
+
+    /// Check if `key` may run this tournament (arena admin or delegated operator)
+    pub fn can_operate(&self, key: &Pubkey, admin: &Pubkey) -> bool {
+        key == admin || (self.operator != Pubkey::default() && *key == self.operator)
+    }
+
+    /// Check if `key` may run this tournament day to day: anyone who may
+    /// operate it, or the host that created it. Hosts may not mint POINTS.
+    pub fn can_run(&self, key: &Pubkey, admin: &Pubkey) -> bool {
+        self.can_operate(key, admin) || (self.is_hosted() && *key == self.admin)
+    }
+
+    /// Count a new registration in `tier`
+    pub fn record_registration(&mut self, tier: AgentTier) -> Result<()> {
+        self.registered_players = self
+            .registered_players
+            .checked_add(1)
+            .ok_or(ArenaError::ArithmeticOverflow)?;
+        let tier_count = &mut self.tier_counts[tier as usize];
+        *tier_count = tier_count
+            .checked_add(1)
+            .ok_or(ArenaError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Release the seat of an unregistering player in `tier`
+    pub fn record_unregistration(&mut self, tier: AgentTier) {
+        self.registered_players = self.registered_players.saturating_sub(1);
+        let tier_count = &mut self.tier_counts[tier as usize];
+        *tier_count = tier_count.saturating_sub(1);
+    }
+
+    /// Add `lamports` paid to the treasury to this tournament's fee counter
+    pub fn record_fee(&mut self, lamports: u64) {
+        self.fees_collected_lamports = self.fees_collected_lamports.saturating_add(lamports);
+    }
+
+    /// Add `lamports` refunded by the treasury to this tournament's refund counter
+    pub fn record_refund(&mut self, lamports: u64) {
+        self.fees_refunded_lamports = self.fees_refunded_lamports.saturating_add(lamports);
+    }
+
+    /// Whether `results_hash` is consistent with the committed game log.
+    /// Once any batch is committed, the final standings must be committed as
+    /// the last batch so the chain head equals the results hash.
+    pub fn results_match_game_log(&self, results_hash: &[u8; 32]) -> bool {
+        self.game_log_batches == 0 || self.game_log_head == *results_hash
+    }
+
+    /// Whether submitted results may be confirmed and escrowed points
+    /// released at `now`: once the dispute window closes undisputed, or once
+    /// a dispute has been resolved. Never before results are submitted, so
+    /// points distributed to busted players mid-event stay escrowed.
+    pub fn points_releasable(&self, now: i64) -> bool {
+        if !matches!(
+            self.status,
+            TournamentStatus::ResultsSubmitted | TournamentStatus::Completed
+        ) {
+            false
+        } else if self.disputed {
+            self.dispute_resolved
+        } else {
+            now >= self.dispute_window_ends_at
+        }
+    }
+
+    /// Whether `registration`'s result may be recorded: once the tournament
+    /// is completed, or mid-event for a player who has already busted
+    pub fn accepts_result(&self, registration: &PlayerRegistration) -> bool {
+        match self.status {
+            TournamentStatus::Completed => true,
+            TournamentStatus::InProgress => registration.bust_position.is_some(),
+            _ => false,
+        }
+    }
+
+    /// Whether a third-party host runs the tournament
+    pub fn is_hosted(&self) -> bool {
+        self.host != Pubkey::default()
+    }
+
+    /// Host PDA seed of the tournament's address: the host's key for hosted
+    /// tournaments, empty for the arena's own
+    pub fn host_seed(&self) -> &[u8] {
+        if self.is_hosted() {
+            self.host.as_ref()
+        } else {
+            &[]
+        }
+    }
+
+    /// Mint the tournament's POINTS are awarded in
+    pub fn points_mint(&self, arena_config: &ArenaConfig) -> Pubkey {
+        self.season_mint.unwrap_or(arena_config.points_mint)
+    }
+
+    /// Check `points_awarded` (base units) is a whole number of POINTS and
+    /// matches the committed points formula, if any
+    pub fn check_points_award(
+        &self,
+        arena_config: &ArenaConfig,
+        final_rank: u16,
+        tier: AgentTier,
+        points_awarded: u64,
+    ) -> Result<()> {
+        arena_config.require_whole_points(points_awarded)?;
+        if let Some(formula) = self.points_formula {
+            let expected = formula.award(final_rank, tier, self.registered_players);
+            require!(
+                points_awarded == arena_config.points_to_base_units(expected)?,
+                ArenaError::PointsFormulaMismatch
+            );
+        }
+        Ok(())
+    }
+
+    /// Whether the tournament is decided by a bracket
+    pub fn is_bracket(&self) -> bool {
+        matches!(
+            self.format,
+            TournamentFormat::SingleElimination | TournamentFormat::DoubleElimination
+        )
+    }
+
+    /// Preset blind level in effect at `now`, timed from starts_at
+    /// (None for a Custom structure)
+    pub fn blind_level_at(&self, now: i64) -> Option<(usize, BlindLevel)> {
+        self.blind_preset.level_at(now.saturating_sub(self.starts_at))
+    }
+
+    /// Whether the tournament never started and is more than
+    /// `stale_seconds` past its scheduled start at `now`
+    pub fn is_stale(&self, now: i64, stale_seconds: i64) -> bool {
+        matches!(
+            self.status,
+            TournamentStatus::Created | TournamentStatus::Registration
+        ) && now > self.starts_at.saturating_add(stale_seconds)
     }
 
     /// Check if tournament can start
@@ -0,0 +1,131 @@
+use anchor_lang::prelude::*;
+
+/// Program-owned vault a ledger entry belongs to
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum LedgerCategory {
+    /// Crank vault funding and bounties
+    #[default]
+    CrankVault,
+    /// Redemption vault funding and POINTS redemptions
+    RedemptionVault,
+    /// Guild vault deposits, withdrawals and sponsored fees
+    GuildVault,
+    /// Season rewards funding and claims
+    SeasonRewards,
+    /// Tier fees escrowed until a tournament starts, and their release or refund
+    FeeEscrow,
+    /// SOL prize pool funding, prize claims and dust sweeps
+    PrizePool,
+    /// Backing escrow contributions, withdrawals and refunds
+    BackingEscrow,
+    /// Host bond postings, withdrawals and slashes
+    HostBond,
+    /// Prompt bond postings, refunds and forfeits
+    PromptBond,
+}
+
+/// Whether lamports entered or left the vault
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum LedgerDirection {
+    /// Lamports deposited into the vault
+    #[default]
+    Inflow,
+    /// Lamports paid out of the vault
+    Outflow,
+}
+
+/// One lamport movement in or out of a program-owned vault.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct LedgerEntry {
+    /// Position in the full ledger history
+    pub sequence: u64,
+
+    /// Vault category
+    pub category: LedgerCategory,
+
+    /// Inflow or outflow
+    pub direction: LedgerDirection,
+
+    /// Vault account that moved lamports
+    pub vault: Pubkey,
+
+    /// Lamports moved
+    pub amount: u64,
+
+    /// Unix timestamp of the movement
+    pub timestamp: i64,
+}
+
+/// Ring buffer of the most recent vault movements, with running totals.
+/// Every entry is also logged, so the full history can be rebuilt from
+/// transaction logs by sequence number.
+#[account]
+pub struct TreasuryLedger {
+    /// Sequence number the next entry receives (total entries ever recorded)
+    pub next_sequence: u64,
+
+    /// Lifetime lamports deposited into program-owned vaults
+    pub total_inflow_lamports: u64,
+
+    /// Lifetime lamports paid out of program-owned vaults
+    pub total_outflow_lamports: u64,
+
+    /// Most recent entries; entry `sequence` lives at `sequence % MAX_ENTRIES`
+    pub entries: [LedgerEntry; TreasuryLedger::MAX_ENTRIES],
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl TreasuryLedger {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 8 + 8 + 8 + (58 * 32) + 1 = 1889 bytes
+    pub const SIZE: usize = 8 + 8 + 8 + 8 + (58 * Self::MAX_ENTRIES) + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"treasury_ledger";
+
+    /// Number of entries kept in the ring buffer
+    pub const MAX_ENTRIES: usize = 32;
+
+    /// Append a vault movement, overwriting the oldest entry once full
+    pub fn record(
+        &mut self,
+        category: LedgerCategory,
+        direction: LedgerDirection,
+        vault: Pubkey,
+        amount: u64,
+        now: i64,
+    ) {
+        if amount == 0 {
+            return;
+        }
+        let sequence = self.next_sequence;
+        self.entries[(sequence % Self::MAX_ENTRIES as u64) as usize] = LedgerEntry {
+            sequence,
+            category,
+            direction,
+            vault,
+            amount,
+            timestamp: now,
+        };
+        self.next_sequence = sequence.saturating_add(1);
+        match direction {
+            LedgerDirection::Inflow => {
+                self.total_inflow_lamports = self.total_inflow_lamports.saturating_add(amount)
+            }
+            LedgerDirection::Outflow => {
+                self.total_outflow_lamports = self.total_outflow_lamports.saturating_add(amount)
+            }
+        }
+
+        msg!(
+            "Ledger #{}: {:?} {:?} {} lamports ({})",
+            sequence,
+            category,
+            direction,
+            amount,
+            vault
+        );
+    }
+}
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+
+/// Program PDA that signs trophy mints.
+/// Acts as the Bubblegum tree delegate for participation trophies.
+#[account]
+pub struct TrophyAuthority {
+    /// Base URI for off-chain trophy metadata, zero-padded
+    /// (trophy JSON lives at `<base><tournament_id>/<rank>.json`)
+    pub metadata_base_uri: [u8; 128],
+
+    /// Total trophies minted
+    pub trophies_minted: u64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl TrophyAuthority {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 128 + 8 + 1 = 145 bytes
+    pub const SIZE: usize = 8 + 128 + 8 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"trophy_authority";
+
+    /// PDA seeds prefix for a tournament's champion trophy mint
+    pub const CHAMPION_MINT_SEED_PREFIX: &'static [u8] = b"champion_trophy";
+
+    /// Symbol shared by all arena trophies
+    pub const SYMBOL: &'static str = "ARENA";
+
+    /// Metadata URI for a trophy at `rank` in `tournament_id`
+    pub fn trophy_uri(&self, tournament_id: u64, rank: u16) -> String {
+        format!("{}{}/{}.json", self.base_uri(), tournament_id, rank)
+    }
+
+    /// Metadata URI for a champion trophy, embedding completion date and field size
+    pub fn champion_uri(&self, tournament_id: u64, completed_at: i64, field_size: u16) -> String {
+        format!(
+            "{}{}/champion.json?date={}&field={}",
+            self.base_uri(),
+            tournament_id,
+            completed_at,
+            field_size
+        )
+    }
+
+    /// Metadata URI for a famous hand commemorative, pointing at its FamousHand account
+    pub fn famous_hand_uri(&self, tournament_id: u64, hand_index: u32, famous_hand: &Pubkey) -> String {
+        format!(
+            "{}{}/hands/{}.json?account={}",
+            self.base_uri(),
+            tournament_id,
+            hand_index,
+            famous_hand
+        )
+    }
+
+    /// Base URI with zero padding stripped
+    fn base_uri(&self) -> String {
+        let len = self
+            .metadata_base_uri
+            .iter()
+            .position(|&b| b == 0)
+            .unwrap_or(self.metadata_base_uri.len());
+        String::from_utf8_lossy(&self.metadata_base_uri[..len]).into_owned()
+    }
+}
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+
+use crate::state::ArenaConfig;
+
+/// A player's POINTS award released on a cliff + linear schedule.
+#[account]
+pub struct VestingAccount {
+    /// Player wallet the POINTS vest to
+    pub wallet: Pubkey,
+
+    /// Tournament the award came from
+    pub tournament: Pubkey,
+
+    /// Total POINTS vesting
+    pub total_points: u64,
+
+    /// POINTS already claimed
+    pub claimed_points: u64,
+
+    /// Unix timestamp vesting started (distribution time)
+    pub start_at: i64,
+
+    /// Unix timestamp before which nothing unlocks
+    pub cliff_at: i64,
+
+    /// Unix timestamp when everything is unlocked
+    pub end_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl VestingAccount {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 32 + 32 + 8 + 8 + 8 + 8 + 8 + 1 = 113 bytes
+    pub const SIZE: usize = 8 + 32 + 32 + 8 + 8 + 8 + 8 + 8 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"vesting";
+
+    /// Start vesting `points` for `wallet` at `now` under the arena's schedule
+    pub fn start(
+        &mut self,
+        wallet: Pubkey,
+        tournament: Pubkey,
+        points: u64,
+        arena_config: &ArenaConfig,
+        now: i64,
+        bump: u8,
+    ) {
+        self.wallet = wallet;
+        self.tournament = tournament;
+        self.total_points = points;
+        self.claimed_points = 0;
+        self.start_at = now;
+        self.cliff_at = now + arena_config.vesting_cliff_seconds;
+        self.end_at = now + arena_config.vesting_duration_seconds;
+        self.bump = bump;
+    }
+
+    /// POINTS unlocked at `now`: nothing before the cliff, then linear from
+    /// the start until fully unlocked at the end
+    pub fn unlocked(&self, now: i64) -> u64 {
+        if now < self.cliff_at {
+            return 0;
+        }
+        if now >= self.end_at {
+            return self.total_points;
+        }
+        let elapsed = (now - self.start_at) as u128;
+        let duration = (self.end_at - self.start_at) as u128;
+        (self.total_points as u128 * elapsed / duration) as u64
+    }
+}
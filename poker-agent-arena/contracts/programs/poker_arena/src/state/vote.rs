@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+/// POINTS staked by a wallet for governance voting.
+#[account]
+pub struct VoteStake {
+    /// Staker's wallet
+    pub wallet: Pubkey,
+
+    /// POINTS staked (base units)
+    pub amount: u64,
+
+    /// Unix timestamp of the last stake increase; only proposals created
+    /// after this count the stake (snapshot against flash staking)
+    pub staked_at: i64,
+
+    /// Stake cannot be withdrawn before this timestamp (end of latest vote)
+    pub locked_until: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl VoteStake {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 32 + 8 + 8 + 8 + 1 = 65 bytes
+    pub const SIZE: usize = 8 + 32 + 8 + 8 + 8 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"vote_stake";
+
+    /// Voting weight for a proposal created at `snapshot`
+    pub fn weight_at(&self, snapshot: i64) -> u64 {
+        if self.staked_at < snapshot {
+            self.amount
+        } else {
+            0
+        }
+    }
+}
+
+/// A wallet's vote on a proposal (one per wallet per proposal).
+#[account]
+pub struct VoteRecord {
+    /// Proposal voted on
+    pub proposal: Pubkey,
+
+    /// Voter's wallet
+    pub voter: Pubkey,
+
+    /// POINTS weight of the vote (base units)
+    pub weight: u64,
+
+    /// True if voting in favor
+    pub support: bool,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl VoteRecord {
+    /// Account size for rent calculation
+    /// 8 (discriminator) + 32 + 32 + 8 + 1 + 1 = 82 bytes
+    pub const SIZE: usize = 8 + 32 + 32 + 8 + 1 + 1;
+
+    /// PDA seeds prefix
+    pub const SEED_PREFIX: &'static [u8] = b"vote";
+}
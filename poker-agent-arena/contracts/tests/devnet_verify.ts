@@ -158,7 +158,8 @@ async function main() {
         { free: {} }, // FREE tier (no payment required)
         Array.from(agentPromptHash),
         Array.from(agentName),
-        Array.from(agentImageUri)
+        Array.from(agentImageUri),
+        new anchor.BN(0)
       )
       .accountsPartial({
         player: player1.publicKey,
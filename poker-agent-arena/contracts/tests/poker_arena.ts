@@ -18,6 +18,8 @@ describe("poker_arena", () => {
   const pointsMint = Keypair.generate();
   const player1 = Keypair.generate();
   const player2 = Keypair.generate();
+  const hostAuthority = Keypair.generate();
+  const hostTreasury = Keypair.generate();
 
   // PDAs
   let arenaConfigPda: PublicKey;
@@ -35,6 +37,8 @@ describe("poker_arena", () => {
   const agentPromptHash = createHash("sha256")
     .update("test prompt")
     .digest();
+  const engineVersionHash = createHash("sha256").update("engine-0.1.0").digest();
+  const modelIdHash = createHash("sha256").update("test-model").digest();
 
   before(async () => {
     // Derive PDAs
@@ -60,6 +64,445 @@ describe("poker_arena", () => {
     );
   });
 
+  // Helpers for the escrow, host and format tests
+  const BASIC_FEE = 0.1 * LAMPORTS_PER_SOL;
+
+  const [auditLogPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("audit_log")],
+    program.programId
+  );
+
+  const sleep = (ms: number) => new Promise((resolve) => setTimeout(resolve, ms));
+
+  const discriminator = (name: string) =>
+    Array.from(createHash("sha256").update(`global:${name}`).digest().subarray(0, 8));
+
+  const u64Le = (value: number) => new anchor.BN(value).toArrayLike(Buffer, "le", 8);
+
+  const u16Le = (value: number) => new anchor.BN(value).toArrayLike(Buffer, "le", 2);
+
+  const pda = (seeds: Buffer[]) => PublicKey.findProgramAddressSync(seeds, program.programId)[0];
+
+  const registrationPda = (tournament: PublicKey, wallet: PublicKey) =>
+    pda([Buffer.from("registration"), tournament.toBuffer(), wallet.toBuffer()]);
+
+  const feeEscrowPda = (tournament: PublicKey) =>
+    pda([Buffer.from("fee_escrow"), tournament.toBuffer()]);
+
+  // Unix timestamp of the validator's clock, which may lag the wall clock
+  async function chainTime(): Promise<number> {
+    for (;;) {
+      const blockTime = await provider.connection.getBlockTime(
+        await provider.connection.getSlot()
+      );
+      if (blockTime !== null) return blockTime;
+      await sleep(200);
+    }
+  }
+
+  async function waitForChainTime(unixTimestamp: number) {
+    while ((await chainTime()) <= unixTimestamp) {
+      await sleep(500);
+    }
+  }
+
+  async function fundedPlayer(sol = 1): Promise<Keypair> {
+    const player = Keypair.generate();
+    await provider.connection.confirmTransaction(
+      await provider.connection.requestAirdrop(player.publicKey, sol * LAMPORTS_PER_SOL)
+    );
+    return player;
+  }
+
+  async function latestAuditEntry() {
+    const auditLog = await program.account.auditLog.fetch(auditLogPda);
+    const sequence = auditLog.nextSequence.toNumber() - 1;
+    return auditLog.entries[sequence % auditLog.entries.length];
+  }
+
+  // Create an arena tournament in Created status, starting at `startsAt`
+  async function createArenaTournament(
+    startsAt?: number,
+    prizeDenomination: any = { points: {} }
+  ): Promise<PublicKey> {
+    const arenaConfig = await program.account.arenaConfig.fetch(arenaConfigPda);
+    const tournament = pda([
+      Buffer.from("tournament"),
+      u64Le(arenaConfig.tournamentCount.toNumber() + 1),
+    ]);
+
+    await program.methods
+      .createTournament(
+        27,
+        new anchor.BN(10000),
+        new anchor.BN(startsAt ?? Math.floor(Date.now() / 1000) + 3600),
+        Array.from(blindStructureHash),
+        Array.from(payoutStructureHash),
+        0,
+        { custom: {} },
+        prizeDenomination
+      )
+      .accounts({
+        admin: admin.publicKey,
+        arenaConfig: arenaConfigPda,
+        tournament,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    return tournament;
+  }
+
+  async function openRegistration(tournament: PublicKey, authority?: Keypair) {
+    await program.methods
+      .openRegistration()
+      .accounts({
+        admin: authority?.publicKey ?? admin.publicKey,
+        arenaConfig: arenaConfigPda,
+        tournament,
+      })
+      .signers(authority ? [authority] : [])
+      .rpc();
+  }
+
+  async function startTournament(tournament: PublicKey, authority?: Keypair) {
+    await program.methods
+      .startTournament(Array.from(engineVersionHash), Array.from(modelIdHash))
+      .accounts({
+        authority: authority?.publicKey ?? admin.publicKey,
+        arenaConfig: arenaConfigPda,
+        tournament,
+        recentSlothashes: anchor.web3.SYSVAR_SLOT_HASHES_PUBKEY,
+      })
+      .signers(authority ? [authority] : [])
+      .rpc();
+  }
+
+  // Register `player` in `tier`, passing any optional accounts in `extra`
+  async function registerPlayer(
+    tournament: PublicKey,
+    player: Keypair,
+    tier: any = { free: {} },
+    extra: Record<string, PublicKey | null> = {}
+  ): Promise<PublicKey> {
+    const registration = registrationPda(tournament, player.publicKey);
+    const agentName = Buffer.alloc(32);
+    agentName.write("TestAgent");
+    const agentImageUri = Buffer.alloc(128);
+
+    await program.methods
+      .registerPlayer(
+        tier,
+        Array.from(agentPromptHash),
+        Array.from(agentName),
+        Array.from(agentImageUri),
+        new anchor.BN(0)
+      )
+      .accounts({
+        player: player.publicKey,
+        payer: player.publicKey,
+        arenaConfig: arenaConfigPda,
+        tournament,
+        registration,
+        treasury: treasury.publicKey,
+        systemProgram: SystemProgram.programId,
+        ...extra,
+      })
+      .signers([player])
+      .rpc();
+
+    return registration;
+  }
+
+  const hostPda = (authority: PublicKey) => pda([Buffer.from("host"), authority.toBuffer()]);
+
+  // Hosted tournaments are keyed by the host and the host's own sequence number
+  const hostTournamentPda = (host: PublicKey, id: number) =>
+    pda([Buffer.from("tournament"), host.toBuffer(), u64Le(id)]);
+
+  // Create the next tournament for the host run by `authority`
+  async function createHostTournament(authority: Keypair): Promise<PublicKey> {
+    const host = hostPda(authority.publicKey);
+    const { tournamentsCreated } = await program.account.host.fetch(host);
+    const tournament = hostTournamentPda(host, tournamentsCreated.toNumber() + 1);
+
+    await program.methods
+      .createHostTournament(
+        27,
+        new anchor.BN(10000),
+        new anchor.BN(Math.floor(Date.now() / 1000) + 3600),
+        Array.from(blindStructureHash),
+        Array.from(payoutStructureHash),
+        0,
+        { custom: {} },
+        { points: {} }
+      )
+      .accounts({
+        authority: authority.publicKey,
+        host,
+        arenaConfig: arenaConfigPda,
+        tournament,
+        feeEscrow: feeEscrowPda(tournament),
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([authority])
+      .rpc();
+
+    return tournament;
+  }
+
+  // Create an arena tournament in `format`, register `players` and start it
+  async function startFormatTournament(format: any, players: Keypair[]) {
+    const tournament = await createArenaTournament();
+    await program.methods
+      .setTournamentFormat(format)
+      .accounts({
+        authority: admin.publicKey,
+        arenaConfig: arenaConfigPda,
+        tournament,
+      })
+      .rpc();
+    await openRegistration(tournament);
+
+    const registrations: PublicKey[] = [];
+    for (const player of players) {
+      registrations.push(await registerPlayer(tournament, player));
+    }
+    await startTournament(tournament);
+
+    const remainingAccounts = registrations.map((pubkey) => ({
+      pubkey,
+      isSigner: false,
+      isWritable: false,
+    }));
+    return { tournament, remainingAccounts };
+  }
+
+  // Helpers for the POINTS settlement tests
+  const { TOKEN_PROGRAM_ID, ASSOCIATED_PROGRAM_ID, associatedAddress } = anchor.utils.token;
+
+  const resultsHash = createHash("sha256").update("results").digest();
+  const reasonHash = Array.from(createHash("sha256").update("rules violation").digest());
+  const metrics = { vpipBps: 2_500, aggressionFactorCenti: 150, showdownWinBps: 5_000 };
+
+  const mintAuthorityPda = pda([Buffer.from("points_mint_authority")]);
+
+  // Whole POINTS in base units (9 decimals)
+  const wholePoints = (amount: number) =>
+    new anchor.BN(amount).mul(new anchor.BN(1_000_000_000));
+
+  const pointsEscrowPda = (tournament: PublicKey, wallet: PublicKey) =>
+    pda([Buffer.from("points_escrow"), tournament.toBuffer(), wallet.toBuffer()]);
+
+  const backingPoolPda = (tournament: PublicKey, wallet: PublicKey) =>
+    pda([Buffer.from("backing_pool"), tournament.toBuffer(), wallet.toBuffer()]);
+
+  // Create the POINTS mint under the arena's mint authority, once
+  async function ensurePointsMint() {
+    if (await provider.connection.getAccountInfo(pointsMint.publicKey)) return;
+
+    await program.methods
+      .createPointsMint()
+      .accounts({
+        admin: admin.publicKey,
+        arenaConfig: arenaConfigPda,
+        pointsMint: pointsMint.publicKey,
+        mintAuthority: mintAuthorityPda,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      })
+      .signers([pointsMint])
+      .rpc();
+  }
+
+  // Associated token account of `owner` for `mint`, created if missing
+  async function tokenAccount(owner: PublicKey, mint = pointsMint.publicKey): Promise<PublicKey> {
+    const address = associatedAddress({ mint, owner });
+    await provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(
+        new anchor.web3.TransactionInstruction({
+          programId: ASSOCIATED_PROGRAM_ID,
+          keys: [
+            { pubkey: admin.publicKey, isSigner: true, isWritable: true },
+            { pubkey: address, isSigner: false, isWritable: true },
+            { pubkey: owner, isSigner: false, isWritable: false },
+            { pubkey: mint, isSigner: false, isWritable: false },
+            { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+            { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+          ],
+          data: Buffer.from([1]), // CreateIdempotent
+        })
+      )
+    );
+    return address;
+  }
+
+  async function tokenBalance(address: PublicKey): Promise<string> {
+    return (await provider.connection.getTokenAccountBalance(address)).value.amount;
+  }
+
+  // Let `delegate` move or burn up to `amount` of `owner`'s POINTS
+  async function approvePoints(owner: Keypair, delegate: PublicKey, amount: anchor.BN) {
+    await provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(
+        new anchor.web3.TransactionInstruction({
+          programId: TOKEN_PROGRAM_ID,
+          keys: [
+            { pubkey: await tokenAccount(owner.publicKey), isSigner: false, isWritable: true },
+            { pubkey: delegate, isSigner: false, isWritable: false },
+            { pubkey: owner.publicKey, isSigner: true, isWritable: false },
+          ],
+          data: Buffer.concat([Buffer.from([4]), amount.toArrayLike(Buffer, "le", 8)]), // Approve
+        })
+      ),
+      [owner]
+    );
+  }
+
+  async function submitResults(tournament: PublicKey, winner: PublicKey) {
+    await program.methods
+      .submitResults(Array.from(resultsHash), winner)
+      .accounts({
+        authority: admin.publicKey,
+        arenaConfig: arenaConfigPda,
+        tournament,
+        winnerRegistration: registrationPda(tournament, winner),
+      })
+      .rpc();
+  }
+
+  async function confirmResults(tournament: PublicKey) {
+    await program.methods
+      .confirmResults()
+      .accounts({
+        authority: admin.publicKey,
+        arenaConfig: arenaConfigPda,
+        tournament,
+      })
+      .rpc();
+  }
+
+  async function recordResult(tournament: PublicKey, wallet: PublicKey, rank: number, points: number) {
+    await program.methods
+      .recordPlayerResult(rank, wholePoints(points), 100, 0, metrics)
+      .accounts({
+        authority: admin.publicKey,
+        arenaConfig: arenaConfigPda,
+        tournament,
+        registration: registrationPda(tournament, wallet),
+        playerStats: pda([Buffer.from("player_stats"), wallet.toBuffer()]),
+        playerStatsV2: pda([Buffer.from("player_stats_v2"), wallet.toBuffer()]),
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  }
+
+  async function recordElimination(tournament: PublicKey, eliminator: PublicKey, victim: PublicKey) {
+    await program.methods
+      .recordElimination()
+      .accounts({
+        authority: admin.publicKey,
+        arenaConfig: arenaConfigPda,
+        tournament,
+        eliminatorRegistration: registrationPda(tournament, eliminator),
+        victimRegistration: registrationPda(tournament, victim),
+        headToHead: pda([Buffer.from("head_to_head"), eliminator.toBuffer(), victim.toBuffer()]),
+        progress: pda([Buffer.from("progress"), tournament.toBuffer()]),
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  }
+
+  // Run an arena tournament for `players` through confirmed results, awarding
+  // players[i] rank i + 1 and `awards[i]` whole POINTS
+  async function completeTournament(players: Keypair[], awards: number[]): Promise<PublicKey> {
+    const tournament = await createArenaTournament();
+    await openRegistration(tournament);
+    for (const player of players) {
+      await registerPlayer(tournament, player);
+    }
+    await startTournament(tournament);
+    await submitResults(tournament, players[0].publicKey);
+    await confirmResults(tournament);
+    for (let i = 0; i < players.length; i++) {
+      await recordResult(tournament, players[i].publicKey, i + 1, awards[i]);
+    }
+    return tournament;
+  }
+
+  // Mint `wallet`'s awarded POINTS, passing any optional accounts in `extra`
+  const distributePoints = async (
+    tournament: PublicKey,
+    wallet: PublicKey,
+    extra: Record<string, PublicKey | null> = {}
+  ) =>
+    program.methods
+      .distributePoints()
+      .accounts({
+        admin: admin.publicKey,
+        arenaConfig: arenaConfigPda,
+        tournament,
+        registration: registrationPda(tournament, wallet),
+        pointsMint: pointsMint.publicKey,
+        mintAuthority: mintAuthorityPda,
+        playerTokenAccount: await tokenAccount(wallet),
+        tokenProgram: TOKEN_PROGRAM_ID,
+        backingPool: backingPoolPda(tournament, wallet),
+        systemProgram: SystemProgram.programId,
+        ...extra,
+      })
+      .rpc();
+
+  // A funded wallet holding `points` whole POINTS won in a tournament of its own
+  async function pointsHolder(points: number): Promise<Keypair> {
+    await ensurePointsMint();
+    const holder = await fundedPlayer();
+    const tournament = await completeTournament([holder, await fundedPlayer()], [points, 0]);
+    await distributePoints(tournament, holder.publicKey);
+    return holder;
+  }
+
+  const governancePda = pda([Buffer.from("governance")]);
+  const stakeVaultPda = pda([Buffer.from("governance_vault")]);
+  const voteStakePda = (wallet: PublicKey) => pda([Buffer.from("vote_stake"), wallet.toBuffer()]);
+
+  // Initialize governance with a 50 POINTS quorum, once
+  async function ensureGovernance() {
+    await ensurePointsMint();
+    if (await provider.connection.getAccountInfo(governancePda)) return;
+
+    await program.methods
+      .initGovernance(wholePoints(50))
+      .accounts({
+        admin: admin.publicKey,
+        arenaConfig: arenaConfigPda,
+        governance: governancePda,
+        stakeVault: stakeVaultPda,
+        pointsMint: pointsMint.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      })
+      .rpc();
+  }
+
+  async function stakePoints(staker: Keypair, points: number) {
+    await program.methods
+      .stakePoints(wholePoints(points))
+      .accounts({
+        staker: staker.publicKey,
+        governance: governancePda,
+        voteStake: voteStakePda(staker.publicKey),
+        stakeVault: stakeVaultPda,
+        stakerTokenAccount: await tokenAccount(staker.publicKey),
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([staker])
+      .rpc();
+  }
+
   describe("initialize", () => {
     it("should initialize the arena config", async () => {
       await program.methods
@@ -92,7 +535,10 @@ describe("poker_arena", () => {
           startingStack,
           startsAt,
           Array.from(blindStructureHash),
-          Array.from(payoutStructureHash)
+          Array.from(payoutStructureHash),
+          0,
+          { custom: {} },
+          { points: {} }
         )
         .accounts({
           admin: admin.publicKey,
@@ -124,7 +570,10 @@ describe("poker_arena", () => {
             new anchor.BN(10000),
             new anchor.BN(Math.floor(Date.now() / 1000) + 3600),
             Array.from(blindStructureHash),
-            Array.from(payoutStructureHash)
+            Array.from(payoutStructureHash),
+            0,
+            { custom: {} },
+            { points: {} }
           )
           .accounts({
             admin: player1.publicKey,
@@ -179,10 +628,12 @@ describe("poker_arena", () => {
           { free: {} },
           Array.from(agentPromptHash),
           Array.from(agentName),
-          Array.from(agentImageUri)
+          Array.from(agentImageUri),
+          new anchor.BN(0)
         )
         .accounts({
           player: player1.publicKey,
+          payer: player1.publicKey,
           arenaConfig: arenaConfigPda,
           tournament: tournamentPda,
           registration: registration1Pda,
@@ -222,10 +673,12 @@ describe("poker_arena", () => {
           { basic: {} },
           Array.from(agentPromptHash),
           Array.from(agentName),
-          Array.from(agentImageUri)
+          Array.from(agentImageUri),
+          new anchor.BN(0)
         )
         .accounts({
           player: player2.publicKey,
+          payer: player2.publicKey,
           arenaConfig: arenaConfigPda,
           tournament: tournamentPda,
           registration: registration2Pda,
@@ -247,6 +700,110 @@ describe("poker_arena", () => {
       expect(treasuryBalanceAfter - treasuryBalanceBefore).to.equal(0.1 * LAMPORTS_PER_SOL);
     });
 
+    it("should fail with a non-printable agent name", async () => {
+      const badPlayer = Keypair.generate();
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(badPlayer.publicKey, 1 * LAMPORTS_PER_SOL)
+      );
+
+      const [badRegPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("registration"), tournamentPda.toBuffer(), badPlayer.publicKey.toBuffer()],
+        program.programId
+      );
+
+      try {
+        const agentName = Buffer.alloc(32);
+        agentName.write("Bad\nAgent");
+        const agentImageUri = Buffer.alloc(128);
+
+        await program.methods
+          .registerPlayer({ free: {} }, Array.from(agentPromptHash), Array.from(agentName), Array.from(agentImageUri), new anchor.BN(0))
+          .accounts({
+            player: badPlayer.publicKey,
+            payer: badPlayer.publicKey,
+            arenaConfig: arenaConfigPda,
+            tournament: tournamentPda,
+            registration: badRegPda,
+            treasury: treasury.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([badPlayer])
+          .rpc();
+
+        expect.fail("Should have thrown InvalidAgentName error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("InvalidAgentName");
+      }
+    });
+
+    it("should fail with a disallowed image URI scheme", async () => {
+      const badPlayer = Keypair.generate();
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(badPlayer.publicKey, 1 * LAMPORTS_PER_SOL)
+      );
+
+      const [badRegPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("registration"), tournamentPda.toBuffer(), badPlayer.publicKey.toBuffer()],
+        program.programId
+      );
+
+      try {
+        const agentName = Buffer.alloc(32);
+        agentName.write("HttpAgent");
+        const agentImageUri = Buffer.alloc(128);
+        agentImageUri.write("http://example.com/avatar.jpg");
+
+        await program.methods
+          .registerPlayer({ free: {} }, Array.from(agentPromptHash), Array.from(agentName), Array.from(agentImageUri), new anchor.BN(0))
+          .accounts({
+            player: badPlayer.publicKey,
+            payer: badPlayer.publicKey,
+            arenaConfig: arenaConfigPda,
+            tournament: tournamentPda,
+            registration: badRegPda,
+            treasury: treasury.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([badPlayer])
+          .rpc();
+
+        expect.fail("Should have thrown InvalidAgentImageUri error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("InvalidAgentImageUri");
+      }
+    });
+
+    it("should register a zero-SOL player sponsored by a relayer", async () => {
+      const sponsoredPlayer = Keypair.generate();
+      const [sponsoredRegPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("registration"), tournamentPda.toBuffer(), sponsoredPlayer.publicKey.toBuffer()],
+        program.programId
+      );
+
+      const agentName = Buffer.alloc(32);
+      agentName.write("SponsoredAgent");
+      const agentImageUri = Buffer.alloc(128);
+
+      // The provider wallet acts as relayer: it pays the transaction fee and rent
+      await program.methods
+        .registerPlayer({ free: {} }, Array.from(agentPromptHash), Array.from(agentName), Array.from(agentImageUri), new anchor.BN(0))
+        .accounts({
+          player: sponsoredPlayer.publicKey,
+          payer: admin.publicKey,
+          arenaConfig: arenaConfigPda,
+          tournament: tournamentPda,
+          registration: sponsoredRegPda,
+          treasury: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([sponsoredPlayer])
+        .rpc();
+
+      const registration = await program.account.playerRegistration.fetch(sponsoredRegPda);
+      expect(registration.wallet.toString()).to.equal(sponsoredPlayer.publicKey.toString());
+      expect(await provider.connection.getBalance(sponsoredPlayer.publicKey)).to.equal(0);
+    });
+
     it("should fail when tournament is full", async () => {
       // This test would require registering max_players first
       // For now, we'll just verify the constraint exists in the contract
@@ -275,6 +832,21 @@ describe("poker_arena", () => {
       const agentImageUri = Buffer.alloc(128);
       agentImageUri.write("https://example.com/pro-avatar.jpg");
 
+      // PRO agents must reference an approved model
+      const [approvedModelPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("approved_model"), modelIdHash],
+        program.programId
+      );
+      await program.methods
+        .setModelApproval(Array.from(modelIdHash), true)
+        .accounts({
+          admin: admin.publicKey,
+          arenaConfig: arenaConfigPda,
+          approvedModel: approvedModelPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
       const treasuryBalanceBefore = await provider.connection.getBalance(treasury.publicKey);
 
       await program.methods
@@ -282,15 +854,18 @@ describe("poker_arena", () => {
           { pro: {} },
           Array.from(agentPromptHash),
           Array.from(agentName),
-          Array.from(agentImageUri)
+          Array.from(agentImageUri),
+          new anchor.BN(0)
         )
         .accounts({
           player: proPlayer.publicKey,
+          payer: proPlayer.publicKey,
           arenaConfig: arenaConfigPda,
           tournament: tournamentPda,
           registration: proRegistrationPda,
           treasury: treasury.publicKey,
           systemProgram: SystemProgram.programId,
+          approvedModel: approvedModelPda,
         })
         .signers([proPlayer])
         .rpc();
@@ -298,6 +873,7 @@ describe("poker_arena", () => {
       const registration = await program.account.playerRegistration.fetch(proRegistrationPda);
       expect(registration.wallet.toString()).to.equal(proPlayer.publicKey.toString());
       expect(registration.tier).to.deep.equal({ pro: {} });
+      expect(Buffer.from(registration.modelIdHash)).to.deep.equal(modelIdHash);
 
       // Verify 1 SOL was transferred to treasury
       const treasuryBalanceAfter = await provider.connection.getBalance(treasury.publicKey);
@@ -317,10 +893,12 @@ describe("poker_arena", () => {
             { free: {} },
             Array.from(agentPromptHash),
             Array.from(agentName),
-            Array.from(agentImageUri)
+            Array.from(agentImageUri),
+            new anchor.BN(0)
           )
           .accounts({
             player: player1.publicKey,
+            payer: player1.publicKey,
             arenaConfig: arenaConfigPda,
             tournament: tournamentPda,
             registration: registration1Pda, // Same PDA as before
@@ -350,7 +928,10 @@ describe("poker_arena", () => {
           new anchor.BN(10000),
           new anchor.BN(Math.floor(Date.now() / 1000) + 7200),
           Array.from(blindStructureHash),
-          Array.from(payoutStructureHash)
+          Array.from(payoutStructureHash),
+          0,
+          { custom: {} },
+          { points: {} }
         )
         .accounts({
           admin: admin.publicKey,
@@ -380,10 +961,12 @@ describe("poker_arena", () => {
             { free: {} },
             Array.from(agentPromptHash),
             Array.from(agentName),
-            Array.from(agentImageUri)
+            Array.from(agentImageUri),
+            new anchor.BN(0)
           )
           .accounts({
             player: player1.publicKey,
+            payer: player1.publicKey,
             arenaConfig: arenaConfigPda,
             tournament: tournament2Pda,
             registration: reg2Pda,
@@ -412,7 +995,10 @@ describe("poker_arena", () => {
           new anchor.BN(10000),
           new anchor.BN(Math.floor(Date.now() / 1000) + 7200),
           Array.from(blindStructureHash),
-          Array.from(payoutStructureHash)
+          Array.from(payoutStructureHash),
+          0,
+          { custom: {} },
+          { points: {} }
         )
         .accounts({
           admin: admin.publicKey,
@@ -451,10 +1037,12 @@ describe("poker_arena", () => {
             { basic: {} }, // BASIC tier requires payment
             Array.from(agentPromptHash),
             Array.from(agentName),
-            Array.from(agentImageUri)
+            Array.from(agentImageUri),
+            new anchor.BN(0)
           )
           .accounts({
             player: player1.publicKey,
+            payer: player1.publicKey,
             arenaConfig: arenaConfigPda,
             tournament: tournament3Pda,
             registration: reg3Pda,
@@ -478,9 +1066,9 @@ describe("poker_arena", () => {
       expect(tournamentBefore.registeredPlayers).to.be.at.least(2);
 
       await program.methods
-        .startTournament()
+        .startTournament(Array.from(engineVersionHash), Array.from(modelIdHash))
         .accounts({
-          admin: admin.publicKey,
+          authority: admin.publicKey,
           arenaConfig: arenaConfigPda,
           tournament: tournamentPda,
           recentSlothashes: anchor.web3.SYSVAR_SLOT_HASHES_PUBKEY,
@@ -490,6 +1078,8 @@ describe("poker_arena", () => {
       const tournamentAfter = await program.account.tournament.fetch(tournamentPda);
       expect(tournamentAfter.status).to.deep.equal({ inProgress: {} });
       expect(tournamentAfter.seedSlot.toNumber()).to.be.greaterThan(0);
+      expect(Buffer.from(tournamentAfter.engineVersionHash)).to.deep.equal(engineVersionHash);
+      expect(Buffer.from(tournamentAfter.modelIdHash)).to.deep.equal(modelIdHash);
     });
 
     it("should fail when called by non-admin", async () => {
@@ -505,7 +1095,10 @@ describe("poker_arena", () => {
           new anchor.BN(10000),
           new anchor.BN(Math.floor(Date.now() / 1000) + 3600),
           Array.from(blindStructureHash),
-          Array.from(payoutStructureHash)
+          Array.from(payoutStructureHash),
+          0,
+          { custom: {} },
+          { points: {} }
         )
         .accounts({
           admin: admin.publicKey,
@@ -544,9 +1137,10 @@ describe("poker_arena", () => {
         const agentImageUri = Buffer.alloc(128);
 
         await program.methods
-          .registerPlayer({ free: {} }, Array.from(agentPromptHash), Array.from(agentName), Array.from(agentImageUri))
+          .registerPlayer({ free: {} }, Array.from(agentPromptHash), Array.from(agentName), Array.from(agentImageUri), new anchor.BN(0))
           .accounts({
             player: p.publicKey,
+            payer: p.publicKey,
             arenaConfig: arenaConfigPda,
             tournament: tournament4Pda,
             registration: regPda,
@@ -560,9 +1154,9 @@ describe("poker_arena", () => {
       // Try to start as non-admin
       try {
         await program.methods
-          .startTournament()
+          .startTournament(Array.from(engineVersionHash), Array.from(modelIdHash))
           .accounts({
-            admin: player1.publicKey, // Not admin!
+            authority: player1.publicKey, // Not admin!
             arenaConfig: arenaConfigPda,
             tournament: tournament4Pda,
             recentSlothashes: anchor.web3.SYSVAR_SLOT_HASHES_PUBKEY,
@@ -580,9 +1174,9 @@ describe("poker_arena", () => {
       // tournamentPda is now InProgress, try to start it again
       try {
         await program.methods
-          .startTournament()
+          .startTournament(Array.from(engineVersionHash), Array.from(modelIdHash))
           .accounts({
-            admin: admin.publicKey,
+            authority: admin.publicKey,
             arenaConfig: arenaConfigPda,
             tournament: tournamentPda, // Already InProgress
             recentSlothashes: anchor.web3.SYSVAR_SLOT_HASHES_PUBKEY,
@@ -610,7 +1204,10 @@ describe("poker_arena", () => {
           new anchor.BN(10000),
           new anchor.BN(Math.floor(Date.now() / 1000) + 3600),
           Array.from(blindStructureHash),
-          Array.from(payoutStructureHash)
+          Array.from(payoutStructureHash),
+          0,
+          { custom: {} },
+          { points: {} }
         )
         .accounts({
           admin: admin.publicKey,
@@ -650,7 +1247,10 @@ describe("poker_arena", () => {
           new anchor.BN(10000),
           new anchor.BN(Math.floor(Date.now() / 1000) + 3600),
           Array.from(blindStructureHash),
-          Array.from(payoutStructureHash)
+          Array.from(payoutStructureHash),
+          0,
+          { custom: {} },
+          { points: {} }
         )
         .accounts({
           admin: admin.publicKey,
@@ -680,6 +1280,11 @@ describe("poker_arena", () => {
         program.programId
       );
 
+      const [approvedModelPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("approved_model"), modelIdHash],
+        program.programId
+      );
+
       try {
         const agentName = Buffer.alloc(32);
         agentName.write("PoorProPlayer");
@@ -690,15 +1295,18 @@ describe("poker_arena", () => {
             { pro: {} }, // PRO requires 1 SOL
             Array.from(agentPromptHash),
             Array.from(agentName),
-            Array.from(agentImageUri)
+            Array.from(agentImageUri),
+            new anchor.BN(0)
           )
           .accounts({
             player: poorPlayer.publicKey,
+            payer: poorPlayer.publicKey,
             arenaConfig: arenaConfigPda,
             tournament: tournament6Pda,
             registration: poorRegPda,
             treasury: treasury.publicKey,
             systemProgram: SystemProgram.programId,
+            approvedModel: approvedModelPda,
           })
           .signers([poorPlayer])
           .rpc();
@@ -710,4 +1318,4255 @@ describe("poker_arena", () => {
       }
     });
   });
+
+  describe("audit_log", () => {
+    it("should record admin calls with the caller and instruction", async () => {
+      const before = await program.account.auditLog.fetch(auditLogPda);
+
+      await program.methods
+        .setLateUnregisterRefund(10_000)
+        .accounts({
+          admin: admin.publicKey,
+          arenaConfig: arenaConfigPda,
+        })
+        .rpc();
+
+      const entry = await latestAuditEntry();
+      expect(entry.sequence.toNumber()).to.equal(before.nextSequence.toNumber());
+      expect(entry.actor.toString()).to.equal(admin.publicKey.toString());
+      expect(entry.instruction).to.deep.equal(discriminator("set_late_unregister_refund"));
+      expect(entry.tournamentId.toNumber()).to.equal(0);
+    });
+
+    it("should record the tournament a call acts on", async () => {
+      const createdPda = await createArenaTournament();
+      const tournament = await program.account.tournament.fetch(createdPda);
+
+      const entry = await latestAuditEntry();
+      expect(entry.instruction).to.deep.equal(discriminator("create_tournament"));
+      expect(entry.tournamentId.toNumber()).to.equal(tournament.id.toNumber());
+      expect(entry.slot.toNumber()).to.be.greaterThan(0);
+    });
+
+    it("should not record rejected calls", async () => {
+      const before = await program.account.auditLog.fetch(auditLogPda);
+
+      try {
+        await program.methods
+          .setLateUnregisterRefund(0)
+          .accounts({
+            admin: player1.publicKey, // Not admin!
+            arenaConfig: arenaConfigPda,
+          })
+          .signers([player1])
+          .rpc();
+
+        expect.fail("Should have thrown Unauthorized error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("Unauthorized");
+      }
+
+      const after = await program.account.auditLog.fetch(auditLogPda);
+      expect(after.nextSequence.toNumber()).to.equal(before.nextSequence.toNumber());
+    });
+  });
+
+  describe("fee_escrow", () => {
+    let escrowTournamentPda: PublicKey;
+    let escrowPda: PublicKey;
+    let escrowPlayers: Keypair[];
+    let escrowRegistrations: PublicKey[];
+
+    async function createFeeEscrow(tournament: PublicKey) {
+      await program.methods
+        .createFeeEscrow(false)
+        .accounts({
+          authority: admin.publicKey,
+          arenaConfig: arenaConfigPda,
+          tournament,
+          feeEscrow: feeEscrowPda(tournament),
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    }
+
+    it("should escrow tier fees until the tournament starts", async () => {
+      escrowTournamentPda = await createArenaTournament();
+      escrowPda = feeEscrowPda(escrowTournamentPda);
+      await createFeeEscrow(escrowTournamentPda);
+
+      const tournament = await program.account.tournament.fetch(escrowTournamentPda);
+      expect(tournament.feesEscrowed).to.equal(true);
+
+      await openRegistration(escrowTournamentPda);
+
+      const escrowBalanceBefore = await provider.connection.getBalance(escrowPda);
+      const treasuryBalanceBefore = await provider.connection.getBalance(treasury.publicKey);
+
+      escrowPlayers = [await fundedPlayer(), await fundedPlayer()];
+      escrowRegistrations = [];
+      for (const player of escrowPlayers) {
+        escrowRegistrations.push(
+          await registerPlayer(escrowTournamentPda, player, { basic: {} }, { feeEscrow: escrowPda })
+        );
+      }
+
+      // Fees are held by the escrow, not paid to the treasury
+      const escrowBalanceAfter = await provider.connection.getBalance(escrowPda);
+      const treasuryBalanceAfter = await provider.connection.getBalance(treasury.publicKey);
+      expect(escrowBalanceAfter - escrowBalanceBefore).to.equal(2 * BASIC_FEE);
+      expect(treasuryBalanceAfter).to.equal(treasuryBalanceBefore);
+    });
+
+    it("should fail to register without the fee escrow", async () => {
+      try {
+        await registerPlayer(escrowTournamentPda, await fundedPlayer(), { basic: {} }, { feeEscrow: null });
+
+        expect.fail("Should have thrown FeeEscrowRequired error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("FeeEscrowRequired");
+      }
+    });
+
+    it("should fail to refund before the tournament is cancelled or voided", async () => {
+      try {
+        await program.methods
+          .refundEscrowedFee()
+          .accounts({
+            caller: admin.publicKey,
+            arenaConfig: arenaConfigPda,
+            tournament: escrowTournamentPda,
+            feeEscrow: escrowPda,
+            registration: escrowRegistrations[0],
+            feePayer: escrowPlayers[0].publicKey,
+          })
+          .rpc();
+
+        expect.fail("Should have thrown TournamentNotVoided error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("TournamentNotVoided");
+      }
+    });
+
+    it("should release escrowed fees to the treasury once started", async () => {
+      await startTournament(escrowTournamentPda);
+
+      const treasuryBalanceBefore = await provider.connection.getBalance(treasury.publicKey);
+
+      await program.methods
+        .releaseFeeEscrow()
+        .accounts({
+          caller: admin.publicKey,
+          arenaConfig: arenaConfigPda,
+          tournament: escrowTournamentPda,
+          feeEscrow: escrowPda,
+          treasury: treasury.publicKey,
+          prizePool: null,
+          host: null,
+          hostTreasury: null,
+        })
+        .rpc();
+
+      const treasuryBalanceAfter = await provider.connection.getBalance(treasury.publicKey);
+      expect(treasuryBalanceAfter - treasuryBalanceBefore).to.equal(2 * BASIC_FEE);
+
+      const feeEscrow = await program.account.feeEscrow.fetch(escrowPda);
+      expect(feeEscrow.released).to.equal(true);
+    });
+
+    it("should fail to release the escrow twice", async () => {
+      try {
+        await program.methods
+          .releaseFeeEscrow()
+          .accounts({
+            caller: admin.publicKey,
+            arenaConfig: arenaConfigPda,
+            tournament: escrowTournamentPda,
+            feeEscrow: escrowPda,
+            treasury: treasury.publicKey,
+            prizePool: null,
+            host: null,
+            hostTreasury: null,
+          })
+          .rpc();
+
+        expect.fail("Should have thrown FeeEscrowReleased error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("FeeEscrowReleased");
+      }
+    });
+
+    describe("when the tournament expires", () => {
+      let expiredTournamentPda: PublicKey;
+      let expiredPlayer: Keypair;
+      let expiredRegistrationPda: PublicKey;
+      let stalePeriod: number;
+
+      before(async () => {
+        const arenaConfig = await program.account.arenaConfig.fetch(arenaConfigPda);
+        stalePeriod = arenaConfig.staleTournamentSeconds.toNumber();
+
+        const startsAt = (await chainTime()) + 10;
+        expiredTournamentPda = await createArenaTournament(startsAt);
+        await createFeeEscrow(expiredTournamentPda);
+        await openRegistration(expiredTournamentPda);
+
+        expiredPlayer = await fundedPlayer();
+        expiredRegistrationPda = await registerPlayer(
+          expiredTournamentPda,
+          expiredPlayer,
+          { basic: {} },
+          { feeEscrow: feeEscrowPda(expiredTournamentPda) }
+        );
+
+        // Expire the tournament as soon as its start has passed
+        await program.methods
+          .setStaleTournamentPeriod(0)
+          .accounts({
+            admin: admin.publicKey,
+            arenaConfig: arenaConfigPda,
+          })
+          .rpc();
+        await waitForChainTime(startsAt);
+        await program.methods
+          .expireTournament()
+          .accounts({
+            caller: admin.publicKey,
+            arenaConfig: arenaConfigPda,
+            tournament: expiredTournamentPda,
+            host: null,
+          })
+          .rpc();
+      });
+
+      after(async () => {
+        await program.methods
+          .setStaleTournamentPeriod(stalePeriod)
+          .accounts({
+            admin: admin.publicKey,
+            arenaConfig: arenaConfigPda,
+          })
+          .rpc();
+      });
+
+      const refund = () =>
+        program.methods
+          .refundEscrowedFee()
+          .accounts({
+            caller: admin.publicKey,
+            arenaConfig: arenaConfigPda,
+            tournament: expiredTournamentPda,
+            feeEscrow: feeEscrowPda(expiredTournamentPda),
+            registration: expiredRegistrationPda,
+            feePayer: expiredPlayer.publicKey,
+          })
+          .rpc();
+
+      it("should refund the escrowed fee to whoever paid it", async () => {
+        const tournament = await program.account.tournament.fetch(expiredTournamentPda);
+        expect(tournament.status).to.deep.equal({ cancelled: {} });
+
+        const playerBalanceBefore = await provider.connection.getBalance(expiredPlayer.publicKey);
+        await refund();
+        const playerBalanceAfter = await provider.connection.getBalance(expiredPlayer.publicKey);
+        expect(playerBalanceAfter - playerBalanceBefore).to.equal(BASIC_FEE);
+
+        const registration = await program.account.playerRegistration.fetch(expiredRegistrationPda);
+        expect(registration.refundedLamports.toNumber()).to.equal(BASIC_FEE);
+      });
+
+      it("should not refund the same fee twice", async () => {
+        try {
+          await refund();
+
+          expect.fail("Should have thrown NothingToRefund error");
+        } catch (error: any) {
+          expect(error.error.errorCode.code).to.equal("NothingToRefund");
+        }
+      });
+    });
+  });
+
+  describe("unregister_player", () => {
+    let unregisterTournamentPda: PublicKey;
+    let unregisterEscrowPda: PublicKey;
+    let startsAt: number;
+    const earlyPlayer = Keypair.generate();
+    const latePlayer = Keypair.generate();
+
+    const unregister = (player: Keypair) =>
+      program.methods
+        .unregisterPlayer()
+        .accounts({
+          player: player.publicKey,
+          arenaConfig: arenaConfigPda,
+          tournament: unregisterTournamentPda,
+          registration: registrationPda(unregisterTournamentPda, player.publicKey),
+          feePayer: player.publicKey,
+          treasury: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          feeEscrow: unregisterEscrowPda,
+        })
+        .signers([player])
+        .rpc();
+
+    before(async () => {
+      startsAt = (await chainTime()) + 15;
+      unregisterTournamentPda = await createArenaTournament(startsAt);
+      unregisterEscrowPda = feeEscrowPda(unregisterTournamentPda);
+      await program.methods
+        .createFeeEscrow(false)
+        .accounts({
+          authority: admin.publicKey,
+          arenaConfig: arenaConfigPda,
+          tournament: unregisterTournamentPda,
+          feeEscrow: unregisterEscrowPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      await openRegistration(unregisterTournamentPda);
+
+      for (const player of [earlyPlayer, latePlayer]) {
+        await provider.connection.confirmTransaction(
+          await provider.connection.requestAirdrop(player.publicKey, 1 * LAMPORTS_PER_SOL)
+        );
+        await registerPlayer(unregisterTournamentPda, player, { basic: {} }, { feeEscrow: unregisterEscrowPda });
+      }
+    });
+
+    it("should refund the full fee while registration is open", async () => {
+      const playerBalanceBefore = await provider.connection.getBalance(earlyPlayer.publicKey);
+      await unregister(earlyPlayer);
+      const playerBalanceAfter = await provider.connection.getBalance(earlyPlayer.publicKey);
+      expect(playerBalanceAfter - playerBalanceBefore).to.equal(BASIC_FEE);
+
+      const registration = await program.account.playerRegistration.fetch(
+        registrationPda(unregisterTournamentPda, earlyPlayer.publicKey)
+      );
+      expect(registration.unregistered).to.equal(true);
+      expect(registration.refundedLamports.toNumber()).to.equal(BASIC_FEE);
+
+      const tournament = await program.account.tournament.fetch(unregisterTournamentPda);
+      expect(tournament.registeredPlayers).to.equal(1);
+    });
+
+    it("should fail to unregister twice", async () => {
+      try {
+        await unregister(earlyPlayer);
+
+        expect.fail("Should have thrown AlreadyForfeited error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("AlreadyForfeited");
+      }
+    });
+
+    it("should retain part of the fee once registration has closed", async () => {
+      await program.methods
+        .setLateUnregisterRefund(5_000)
+        .accounts({
+          admin: admin.publicKey,
+          arenaConfig: arenaConfigPda,
+        })
+        .rpc();
+      await waitForChainTime(startsAt);
+
+      const playerBalanceBefore = await provider.connection.getBalance(latePlayer.publicKey);
+      const treasuryBalanceBefore = await provider.connection.getBalance(treasury.publicKey);
+      try {
+        await unregister(latePlayer);
+      } finally {
+        await program.methods
+          .setLateUnregisterRefund(10_000)
+          .accounts({
+            admin: admin.publicKey,
+            arenaConfig: arenaConfigPda,
+          })
+          .rpc();
+      }
+      const playerBalanceAfter = await provider.connection.getBalance(latePlayer.publicKey);
+      const treasuryBalanceAfter = await provider.connection.getBalance(treasury.publicKey);
+      expect(playerBalanceAfter - playerBalanceBefore).to.equal(BASIC_FEE / 2);
+      expect(treasuryBalanceAfter - treasuryBalanceBefore).to.equal(BASIC_FEE / 2);
+
+      const registration = await program.account.playerRegistration.fetch(
+        registrationPda(unregisterTournamentPda, latePlayer.publicKey)
+      );
+      expect(registration.refundedLamports.toNumber()).to.equal(BASIC_FEE / 2);
+      expect(registration.retainedLamports.toNumber()).to.equal(BASIC_FEE / 2);
+    });
+
+    it("should not refund an unregistered player's fee again once expired", async () => {
+      const arenaConfig = await program.account.arenaConfig.fetch(arenaConfigPda);
+      const stalePeriod = arenaConfig.staleTournamentSeconds.toNumber();
+
+      await program.methods
+        .setStaleTournamentPeriod(0)
+        .accounts({
+          admin: admin.publicKey,
+          arenaConfig: arenaConfigPda,
+        })
+        .rpc();
+      try {
+        await program.methods
+          .expireTournament()
+          .accounts({
+            caller: admin.publicKey,
+            arenaConfig: arenaConfigPda,
+            tournament: unregisterTournamentPda,
+            host: null,
+          })
+          .rpc();
+      } finally {
+        await program.methods
+          .setStaleTournamentPeriod(stalePeriod)
+          .accounts({
+            admin: admin.publicKey,
+            arenaConfig: arenaConfigPda,
+          })
+          .rpc();
+      }
+
+      for (const player of [earlyPlayer, latePlayer]) {
+        try {
+          await program.methods
+            .refundEscrowedFee()
+            .accounts({
+              caller: admin.publicKey,
+              arenaConfig: arenaConfigPda,
+              tournament: unregisterTournamentPda,
+              feeEscrow: unregisterEscrowPda,
+              registration: registrationPda(unregisterTournamentPda, player.publicKey),
+              feePayer: player.publicKey,
+            })
+            .rpc();
+
+          expect.fail("Should have thrown NothingToRefund error");
+        } catch (error: any) {
+          expect(error.error.errorCode.code).to.equal("NothingToRefund");
+        }
+      }
+    });
+  });
+
+  describe("invites", () => {
+    let inviteTournamentPda: PublicKey;
+    const codes = [Buffer.from("invite-code-one"), Buffer.from("invite-code-two")];
+    const codeHashes = codes.map((code) => createHash("sha256").update(code).digest());
+    let invitePdas: PublicKey[];
+    const invitee = Keypair.generate();
+
+    before(async () => {
+      inviteTournamentPda = await createArenaTournament();
+      invitePdas = codeHashes.map((codeHash) =>
+        pda([Buffer.from("invite"), inviteTournamentPda.toBuffer(), codeHash])
+      );
+      await openRegistration(inviteTournamentPda);
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(invitee.publicKey, 1 * LAMPORTS_PER_SOL)
+      );
+    });
+
+    const redeemInvite = (code: Buffer, invite: PublicKey) =>
+      program.methods
+        .redeemInvite(code)
+        .accounts({
+          player: invitee.publicKey,
+          tournament: inviteTournamentPda,
+          invite,
+        });
+
+    it("should create invites and make the tournament invite-only", async () => {
+      await program.methods
+        .createInvites(codeHashes.map((codeHash) => Array.from(codeHash)))
+        .accounts({
+          authority: admin.publicKey,
+          arenaConfig: arenaConfigPda,
+          tournament: inviteTournamentPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(
+          invitePdas.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true }))
+        )
+        .rpc();
+
+      const tournament = await program.account.tournament.fetch(inviteTournamentPda);
+      expect(tournament.inviteOnly).to.equal(true);
+
+      const invite = await program.account.invite.fetch(invitePdas[0]);
+      expect(Buffer.from(invite.codeHash)).to.deep.equal(codeHashes[0]);
+      expect(invite.redeemedBy.toString()).to.equal(PublicKey.default.toString());
+    });
+
+    it("should fail to register without an invite", async () => {
+      try {
+        await registerPlayer(inviteTournamentPda, invitee);
+
+        expect.fail("Should have thrown InviteRequired error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("InviteRequired");
+      }
+    });
+
+    it("should fail to redeem an invite with the wrong code", async () => {
+      try {
+        await redeemInvite(Buffer.from("wrong-code"), invitePdas[0]).signers([invitee]).rpc();
+
+        expect.fail("Should have thrown ConstraintSeeds error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("ConstraintSeeds");
+      }
+    });
+
+    it("should register with an invite redeemed in the same transaction", async () => {
+      const registration = registrationPda(inviteTournamentPda, invitee.publicKey);
+      const agentName = Buffer.alloc(32);
+      agentName.write("InvitedAgent");
+      const agentImageUri = Buffer.alloc(128);
+
+      await program.methods
+        .registerPlayer(
+          { free: {} },
+          Array.from(agentPromptHash),
+          Array.from(agentName),
+          Array.from(agentImageUri),
+          new anchor.BN(0)
+        )
+        .accounts({
+          player: invitee.publicKey,
+          payer: invitee.publicKey,
+          arenaConfig: arenaConfigPda,
+          tournament: inviteTournamentPda,
+          registration,
+          treasury: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          invite: invitePdas[0],
+        })
+        .preInstructions([await redeemInvite(codes[0], invitePdas[0]).instruction()])
+        .signers([invitee])
+        .rpc();
+
+      const invite = await program.account.invite.fetch(invitePdas[0]);
+      expect(invite.redeemedBy.toString()).to.equal(invitee.publicKey.toString());
+
+      const tournament = await program.account.tournament.fetch(inviteTournamentPda);
+      expect(tournament.registeredPlayers).to.equal(1);
+    });
+
+    it("should revoke an unused invite, closing its account", async () => {
+      await program.methods
+        .revokeInvite()
+        .accounts({
+          authority: admin.publicKey,
+          arenaConfig: arenaConfigPda,
+          tournament: inviteTournamentPda,
+          invite: invitePdas[1],
+        })
+        .rpc();
+
+      expect(await program.account.invite.fetchNullable(invitePdas[1])).to.equal(null);
+    });
+
+    it("should fail to revoke a redeemed invite", async () => {
+      try {
+        await program.methods
+          .revokeInvite()
+          .accounts({
+            authority: admin.publicKey,
+            arenaConfig: arenaConfigPda,
+            tournament: inviteTournamentPda,
+            invite: invitePdas[0],
+          })
+          .rpc();
+
+        expect.fail("Should have thrown InvalidInvite error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("InvalidInvite");
+      }
+    });
+  });
+
+  describe("hosts", () => {
+    const host = hostPda(hostAuthority.publicKey);
+    const requiredBond = 0.1 * LAMPORTS_PER_SOL;
+    const postedBond = 0.15 * LAMPORTS_PER_SOL;
+    let hostedTournamentPda: PublicKey;
+
+    const setHostBond = (bondLamports: number) =>
+      program.methods
+        .setHostBond(new anchor.BN(bondLamports))
+        .accounts({
+          admin: admin.publicKey,
+          arenaConfig: arenaConfigPda,
+        })
+        .rpc();
+
+    const setTournamentRateLimits = (maxPerDay: number, maxOpen: number) =>
+      program.methods
+        .setTournamentRateLimits(maxPerDay, maxOpen)
+        .accounts({
+          admin: admin.publicKey,
+          arenaConfig: arenaConfigPda,
+        })
+        .rpc();
+
+    it("should register a host and post its bond", async () => {
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(hostAuthority.publicKey, 2 * LAMPORTS_PER_SOL)
+      );
+
+      await program.methods
+        .setHost(hostAuthority.publicKey, hostTreasury.publicKey, 1_000, true)
+        .accounts({
+          admin: admin.publicKey,
+          arenaConfig: arenaConfigPda,
+          host,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      await setHostBond(requiredBond);
+
+      await program.methods
+        .postHostBond(new anchor.BN(postedBond))
+        .accounts({
+          authority: hostAuthority.publicKey,
+          host,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([hostAuthority])
+        .rpc();
+
+      const hostAccount = await program.account.host.fetch(host);
+      expect(hostAccount.authority.toString()).to.equal(hostAuthority.publicKey.toString());
+      expect(hostAccount.treasury.toString()).to.equal(hostTreasury.publicKey.toString());
+      expect(hostAccount.platformFeeBps).to.equal(1_000);
+      expect(hostAccount.bondLamports.toNumber()).to.equal(postedBond);
+    });
+
+    it("should fail to create a tournament while under-bonded", async () => {
+      await setHostBond(postedBond + 1);
+      try {
+        await createHostTournament(hostAuthority);
+
+        expect.fail("Should have thrown HostBondTooLow error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("HostBondTooLow");
+      } finally {
+        await setHostBond(requiredBond);
+      }
+    });
+
+    it("should create a tournament at the host's own PDA", async () => {
+      hostedTournamentPda = await createHostTournament(hostAuthority);
+      expect(hostedTournamentPda.toString()).to.equal(hostTournamentPda(host, 1).toString());
+
+      const tournament = await program.account.tournament.fetch(hostedTournamentPda);
+      expect(tournament.id.toNumber()).to.equal(1);
+      expect(tournament.host.toString()).to.equal(host.toString());
+      expect(tournament.admin.toString()).to.equal(hostAuthority.publicKey.toString());
+      expect(tournament.operator.toString()).to.equal(PublicKey.default.toString());
+      expect(tournament.feesEscrowed).to.equal(true);
+
+      const feeEscrow = await program.account.feeEscrow.fetch(feeEscrowPda(hostedTournamentPda));
+      expect(feeEscrow.tournament.toString()).to.equal(hostedTournamentPda.toString());
+
+      const hostAccount = await program.account.host.fetch(host);
+      expect(hostAccount.tournamentsCreated.toNumber()).to.equal(1);
+      expect(hostAccount.openTournaments).to.equal(1);
+
+      const entry = await latestAuditEntry();
+      expect(entry.actor.toString()).to.equal(hostAuthority.publicKey.toString());
+      expect(entry.instruction).to.deep.equal(discriminator("create_host_tournament"));
+      expect(entry.tournamentId.toNumber()).to.equal(1);
+    });
+
+    it("should not let the host settle POINTS awards", async () => {
+      try {
+        await program.methods
+          .commitPointsRoot(Array.from(Buffer.alloc(32, 1)))
+          .accounts({
+            authority: hostAuthority.publicKey,
+            arenaConfig: arenaConfigPda,
+            tournament: hostedTournamentPda,
+          })
+          .signers([hostAuthority])
+          .rpc();
+
+        expect.fail("Should have thrown Unauthorized error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("Unauthorized");
+      }
+    });
+
+    it("should rate limit the host's tournament creation", async () => {
+      try {
+        // The host already created one tournament today, and has one open
+        for (const [maxPerDay, maxOpen] of [
+          [1, 0],
+          [0, 1],
+        ]) {
+          await setTournamentRateLimits(maxPerDay, maxOpen);
+          try {
+            await createHostTournament(hostAuthority);
+
+            expect.fail("Should have thrown TournamentRateLimited error");
+          } catch (error: any) {
+            expect(error.error.errorCode.code).to.equal("TournamentRateLimited");
+          }
+        }
+      } finally {
+        await setTournamentRateLimits(0, 0);
+      }
+
+      const hostAccount = await program.account.host.fetch(host);
+      expect(hostAccount.tournamentsCreated.toNumber()).to.equal(1);
+    });
+
+    it("should split released fees between the arena and the host", async () => {
+      await openRegistration(hostedTournamentPda, hostAuthority);
+      const hostedEscrowPda = feeEscrowPda(hostedTournamentPda);
+      for (let i = 0; i < 2; i++) {
+        await registerPlayer(hostedTournamentPda, await fundedPlayer(), { basic: {} }, { feeEscrow: hostedEscrowPda });
+      }
+      await startTournament(hostedTournamentPda, hostAuthority);
+
+      const treasuryBalanceBefore = await provider.connection.getBalance(treasury.publicKey);
+      const hostTreasuryBalanceBefore = await provider.connection.getBalance(hostTreasury.publicKey);
+
+      await program.methods
+        .releaseFeeEscrow()
+        .accounts({
+          caller: admin.publicKey,
+          arenaConfig: arenaConfigPda,
+          tournament: hostedTournamentPda,
+          feeEscrow: hostedEscrowPda,
+          treasury: treasury.publicKey,
+          prizePool: null,
+          host,
+          hostTreasury: hostTreasury.publicKey,
+        })
+        .rpc();
+
+      // 10% platform fee to the arena, the rest to the host
+      const treasuryBalanceAfter = await provider.connection.getBalance(treasury.publicKey);
+      const hostTreasuryBalanceAfter = await provider.connection.getBalance(hostTreasury.publicKey);
+      expect(treasuryBalanceAfter - treasuryBalanceBefore).to.equal(0.2 * BASIC_FEE);
+      expect(hostTreasuryBalanceAfter - hostTreasuryBalanceBefore).to.equal(1.8 * BASIC_FEE);
+    });
+  });
+
+  describe("host_bond_slashing", () => {
+    const host = hostPda(hostAuthority.publicKey);
+    const reasonHash = Array.from(createHash("sha256").update("host misconduct").digest());
+    let slashTournamentPda: PublicKey;
+    let slashEscrowPda: PublicKey;
+    let slashPlayers: Keypair[];
+    let bondBefore: number;
+
+    const slashHostBond = () =>
+      program.methods
+        .slashHostBond(new anchor.BN(bondBefore), reasonHash)
+        .accounts({
+          admin: admin.publicKey,
+          arenaConfig: arenaConfigPda,
+          tournament: slashTournamentPda,
+          host,
+          feeEscrow: slashEscrowPda,
+        })
+        .rpc();
+
+    const disputeTournament = (player: Keypair) =>
+      program.methods
+        .disputeTournament(reasonHash)
+        .accounts({
+          player: player.publicKey,
+          tournament: slashTournamentPda,
+          registration: registrationPda(slashTournamentPda, player.publicKey),
+        })
+        .signers([player])
+        .rpc();
+
+    const refund = (player: Keypair) =>
+      program.methods
+        .refundEscrowedFee()
+        .accounts({
+          caller: admin.publicKey,
+          arenaConfig: arenaConfigPda,
+          tournament: slashTournamentPda,
+          feeEscrow: slashEscrowPda,
+          registration: registrationPda(slashTournamentPda, player.publicKey),
+          feePayer: player.publicKey,
+        })
+        .rpc();
+
+    before(async () => {
+      slashTournamentPda = await createHostTournament(hostAuthority);
+      slashEscrowPda = feeEscrowPda(slashTournamentPda);
+      await openRegistration(slashTournamentPda, hostAuthority);
+
+      slashPlayers = [await fundedPlayer(), await fundedPlayer()];
+      for (const player of slashPlayers) {
+        await registerPlayer(slashTournamentPda, player, { basic: {} }, { feeEscrow: slashEscrowPda });
+      }
+      await startTournament(slashTournamentPda, hostAuthority);
+      await program.methods
+        .releaseFeeEscrow()
+        .accounts({
+          caller: admin.publicKey,
+          arenaConfig: arenaConfigPda,
+          tournament: slashTournamentPda,
+          feeEscrow: slashEscrowPda,
+          treasury: treasury.publicKey,
+          prizePool: null,
+          host,
+          hostTreasury: hostTreasury.publicKey,
+        })
+        .rpc();
+    });
+
+    it("should let a player dispute the tournament once", async () => {
+      await disputeTournament(slashPlayers[0]);
+
+      const tournament = await program.account.tournament.fetch(slashTournamentPda);
+      expect(tournament.disputed).to.equal(true);
+
+      try {
+        await disputeTournament(slashPlayers[0]);
+
+        expect.fail("Should have thrown AlreadyDisputed error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("AlreadyDisputed");
+      }
+    });
+
+    it("should slash a voided host's bond into the fee escrow", async () => {
+      await program.methods
+        .voidTournament(reasonHash)
+        .accounts({
+          admin: admin.publicKey,
+          arenaConfig: arenaConfigPda,
+          tournament: slashTournamentPda,
+          host,
+        })
+        .rpc();
+
+      bondBefore = (await program.account.host.fetch(host)).bondLamports.toNumber();
+      const escrowBalanceBefore = await provider.connection.getBalance(slashEscrowPda);
+
+      await slashHostBond();
+
+      const escrowBalanceAfter = await provider.connection.getBalance(slashEscrowPda);
+      expect(escrowBalanceAfter - escrowBalanceBefore).to.equal(bondBefore);
+
+      const feeEscrow = await program.account.feeEscrow.fetch(slashEscrowPda);
+      expect(feeEscrow.hostSlashed).to.equal(true);
+      expect(feeEscrow.slashedLamports.toNumber()).to.equal(bondBefore);
+
+      const hostAccount = await program.account.host.fetch(host);
+      expect(hostAccount.bondLamports.toNumber()).to.equal(0);
+      expect(hostAccount.timesSlashed).to.equal(1);
+    });
+
+    it("should not slash the host twice over the same tournament", async () => {
+      try {
+        await slashHostBond();
+
+        expect.fail("Should have thrown HostNotSlashable error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("HostNotSlashable");
+      }
+    });
+
+    it("should refund released fees only from the slashed bond", async () => {
+      // The slashed bond covers the first player's fee and part of the second's
+      const balancesBefore = await Promise.all(
+        slashPlayers.map((player) => provider.connection.getBalance(player.publicKey))
+      );
+      for (const player of slashPlayers) {
+        await refund(player);
+      }
+      const balancesAfter = await Promise.all(
+        slashPlayers.map((player) => provider.connection.getBalance(player.publicKey))
+      );
+      expect(balancesAfter[0] - balancesBefore[0]).to.equal(BASIC_FEE);
+      expect(balancesAfter[1] - balancesBefore[1]).to.equal(bondBefore - BASIC_FEE);
+
+      const feeEscrow = await program.account.feeEscrow.fetch(slashEscrowPda);
+      expect(feeEscrow.slashedLamports.toNumber()).to.equal(0);
+    });
+
+    it("should not refund past the slashed bond", async () => {
+      try {
+        await refund(slashPlayers[1]);
+
+        expect.fail("Should have thrown FeeEscrowReleased error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("FeeEscrowReleased");
+      }
+    });
+  });
+
+  describe("bracket_formats", () => {
+    const sides = [{ winners: {} }, { losers: {} }, { grandFinal: {} }];
+    const [WINNERS, LOSERS, GRAND_FINAL] = [0, 1, 2];
+
+    const bracketPda = (tournament: PublicKey) =>
+      pda([Buffer.from("bracket"), tournament.toBuffer()]);
+
+    const matchPda = (bracket: PublicKey, side: number, round: number, index: number) =>
+      pda([
+        Buffer.from("bracket_match"),
+        bracket.toBuffer(),
+        Buffer.from([side, round, index & 0xff, index >> 8]),
+      ]);
+
+    const generateBracket = (tournament: PublicKey, remainingAccounts: any[]) =>
+      program.methods
+        .generateBracket()
+        .accounts({
+          authority: admin.publicKey,
+          arenaConfig: arenaConfigPda,
+          tournament,
+          bracket: bracketPda(tournament),
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(remainingAccounts)
+        .rpc();
+
+    async function openFirstRoundMatch(bracket: PublicKey, index: number) {
+      await program.methods
+        .openBracketMatch(index)
+        .accounts({
+          payer: admin.publicKey,
+          bracket,
+          bracketMatch: matchPda(bracket, WINNERS, 0, index),
+          nextMatch: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    }
+
+    // Record a win for the match's player A, returning the winner
+    async function recordMatch(
+      tournament: PublicKey,
+      side: number,
+      round: number,
+      index: number,
+      nextMatch: PublicKey | null,
+      dropMatch: PublicKey | null
+    ): Promise<PublicKey> {
+      const bracket = bracketPda(tournament);
+      const bracketMatch = matchPda(bracket, side, round, index);
+      const { playerA, status } = await program.account.bracketMatch.fetch(bracketMatch);
+      expect(status).to.deep.equal({ ready: {} });
+
+      await program.methods
+        .recordBracketMatch(sides[side], round, index, playerA)
+        .accounts({
+          authority: admin.publicKey,
+          arenaConfig: arenaConfigPda,
+          tournament,
+          bracket,
+          bracketMatch,
+          nextMatch,
+          dropMatch,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      return playerA;
+    }
+
+    it("should crown a single-elimination champion", async () => {
+      const players = [await fundedPlayer(), await fundedPlayer()];
+      const { tournament, remainingAccounts } = await startFormatTournament(
+        { singleElimination: {} },
+        players
+      );
+      const bracket = bracketPda(tournament);
+
+      await generateBracket(tournament, remainingAccounts);
+      const bracketAccount = await program.account.bracket.fetch(bracket);
+      expect(bracketAccount.size).to.equal(2);
+      expect(bracketAccount.rounds).to.equal(1);
+      expect(bracketAccount.doubleElimination).to.equal(false);
+
+      await openFirstRoundMatch(bracket, 0);
+      const champion = await recordMatch(tournament, WINNERS, 0, 0, null, null);
+
+      const bracketAfter = await program.account.bracket.fetch(bracket);
+      expect(bracketAfter.champion.toString()).to.equal(champion.toString());
+      expect(bracketAfter.matchesCompleted).to.equal(1);
+    });
+
+    it("should run a double-elimination bracket through the grand final", async () => {
+      const players: Keypair[] = [];
+      for (let i = 0; i < 4; i++) {
+        players.push(await fundedPlayer());
+      }
+      const { tournament, remainingAccounts } = await startFormatTournament(
+        { doubleElimination: {} },
+        players
+      );
+      const bracket = bracketPda(tournament);
+      await generateBracket(tournament, remainingAccounts);
+
+      const winnersFinal = matchPda(bracket, WINNERS, 1, 0);
+      const losersFirst = matchPda(bracket, LOSERS, 0, 0);
+      const losersFinal = matchPda(bracket, LOSERS, 1, 0);
+      const grandFinal = matchPda(bracket, GRAND_FINAL, 0, 0);
+
+      // First-round losers drop into the losers bracket
+      for (const index of [0, 1]) {
+        await openFirstRoundMatch(bracket, index);
+        await recordMatch(tournament, WINNERS, 0, index, winnersFinal, losersFirst);
+      }
+      const winnersChampion = await recordMatch(tournament, WINNERS, 1, 0, grandFinal, losersFinal);
+      await recordMatch(tournament, LOSERS, 0, 0, losersFinal, null);
+      const losersChampion = await recordMatch(tournament, LOSERS, 1, 0, grandFinal, null);
+
+      const final = await program.account.bracketMatch.fetch(grandFinal);
+      expect(final.playerA.toString()).to.equal(winnersChampion.toString());
+      expect(final.playerB.toString()).to.equal(losersChampion.toString());
+
+      // The winners-bracket champion takes the grand final outright
+      await recordMatch(tournament, GRAND_FINAL, 0, 0, null, null);
+
+      const bracketAfter = await program.account.bracket.fetch(bracket);
+      expect(bracketAfter.champion.toString()).to.equal(winnersChampion.toString());
+      expect(bracketAfter.matchesCompleted).to.equal(6);
+    });
+
+    it("should fail to generate a double-elimination bracket for fewer than four players", async () => {
+      const players = [await fundedPlayer(), await fundedPlayer()];
+      const { tournament, remainingAccounts } = await startFormatTournament(
+        { doubleElimination: {} },
+        players
+      );
+
+      try {
+        await generateBracket(tournament, remainingAccounts);
+
+        expect.fail("Should have thrown InvalidBracketMatch error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("InvalidBracketMatch");
+      }
+    });
+  });
+
+  describe("swiss_format", () => {
+    let swissTournamentPda: PublicKey;
+    let swissEventPda: PublicKey;
+    let swissPlayers: Keypair[];
+
+    const standingPda = (wallet: PublicKey) =>
+      pda([Buffer.from("swiss_standing"), swissTournamentPda.toBuffer(), wallet.toBuffer()]);
+
+    const pairRound = () =>
+      program.methods
+        .pairSwissRound()
+        .accounts({
+          authority: admin.publicKey,
+          arenaConfig: arenaConfigPda,
+          tournament: swissTournamentPda,
+          swissEvent: swissEventPda,
+        })
+        .remainingAccounts(
+          swissPlayers.map((player) => ({
+            pubkey: standingPda(player.publicKey),
+            isSigner: false,
+            isWritable: true,
+          }))
+        )
+        .rpc();
+
+    // Record every pairing of the current round as a win for its first player
+    async function recordRound() {
+      const { pairings } = await program.account.swissEvent.fetch(swissEventPda);
+      for (let i = 0; i < pairings.length; i += 2) {
+        await program.methods
+          .recordSwissResult()
+          .accounts({
+            authority: admin.publicKey,
+            arenaConfig: arenaConfigPda,
+            tournament: swissTournamentPda,
+            swissEvent: swissEventPda,
+            winnerStanding: standingPda(pairings[i]),
+            loserStanding: standingPda(pairings[i + 1]),
+          })
+          .rpc();
+      }
+    }
+
+    before(async () => {
+      swissPlayers = [];
+      for (let i = 0; i < 4; i++) {
+        swissPlayers.push(await fundedPlayer());
+      }
+      ({ tournament: swissTournamentPda } = await startFormatTournament({ swiss: {} }, swissPlayers));
+      swissEventPda = pda([Buffer.from("swiss"), swissTournamentPda.toBuffer()]);
+    });
+
+    it("should create a Swiss schedule with a standing per player", async () => {
+      await program.methods
+        .createSwissEvent(2)
+        .accounts({
+          authority: admin.publicKey,
+          arenaConfig: arenaConfigPda,
+          tournament: swissTournamentPda,
+          swissEvent: swissEventPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      for (const player of swissPlayers) {
+        await program.methods
+          .initSwissStanding()
+          .accounts({
+            payer: admin.publicKey,
+            swissEvent: swissEventPda,
+            registration: registrationPda(swissTournamentPda, player.publicKey),
+            swissStanding: standingPda(player.publicKey),
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+      }
+
+      const swissEvent = await program.account.swissEvent.fetch(swissEventPda);
+      expect(swissEvent.rounds).to.equal(2);
+      expect(swissEvent.round).to.equal(0);
+    });
+
+    it("should pair a round and reject pairing the next before it is recorded", async () => {
+      await pairRound();
+
+      const swissEvent = await program.account.swissEvent.fetch(swissEventPda);
+      expect(swissEvent.round).to.equal(1);
+      expect(swissEvent.pairings.length).to.equal(4);
+
+      try {
+        await pairRound();
+
+        expect.fail("Should have thrown InvalidSwissRound error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("InvalidSwissRound");
+      }
+    });
+
+    it("should play every round and then stop pairing", async () => {
+      await recordRound();
+      await pairRound();
+      await recordRound();
+
+      const standings = await Promise.all(
+        swissPlayers.map((player) => program.account.swissStanding.fetch(standingPda(player.publicKey)))
+      );
+      expect(standings.reduce((wins, standing) => wins + standing.wins, 0)).to.equal(4);
+      expect(standings.every((standing) => standing.lastRound === 2)).to.equal(true);
+
+      try {
+        await pairRound();
+
+        expect.fail("Should have thrown InvalidSwissRound error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("InvalidSwissRound");
+      }
+    });
+  });
+
+  describe("shootout_format", () => {
+    let shootoutTournamentPda: PublicKey;
+    let shootoutPda: PublicKey;
+
+    const tablePda = (tableNumber: number) =>
+      pda([Buffer.from("table"), shootoutTournamentPda.toBuffer(), u16Le(tableNumber)]);
+
+    const advanceRound = () =>
+      program.methods
+        .advanceShootoutRound()
+        .accounts({
+          caller: admin.publicKey,
+          tournament: shootoutTournamentPda,
+          shootout: shootoutPda,
+        })
+        .rpc();
+
+    // Open every table of the current round and record a winner at each
+    async function playRound(): Promise<PublicKey[]> {
+      const { firstTable, winners } = await program.account.shootout.fetch(shootoutPda);
+      const tableWinners: PublicKey[] = [];
+      for (let index = 0; index < winners.length; index++) {
+        const table = tablePda(firstTable + index);
+        await program.methods
+          .openShootoutTable(index)
+          .accounts({
+            payer: admin.publicKey,
+            tournament: shootoutTournamentPda,
+            shootout: shootoutPda,
+            table,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+
+        const { seats } = await program.account.table.fetch(table);
+        const winner = seats.find((seat) => !seat.equals(PublicKey.default))!;
+        await program.methods
+          .recordShootoutTable(winner)
+          .accounts({
+            authority: admin.publicKey,
+            arenaConfig: arenaConfigPda,
+            tournament: shootoutTournamentPda,
+            shootout: shootoutPda,
+            table,
+          })
+          .rpc();
+        tableWinners.push(winner);
+      }
+      return tableWinners;
+    }
+
+    it("should advance table winners until one champion remains", async () => {
+      const players: Keypair[] = [];
+      for (let i = 0; i < 10; i++) {
+        players.push(await fundedPlayer());
+      }
+      const started = await startFormatTournament({ shootout: {} }, players);
+      shootoutTournamentPda = started.tournament;
+      shootoutPda = pda([Buffer.from("shootout"), shootoutTournamentPda.toBuffer()]);
+
+      await program.methods
+        .createShootout()
+        .accounts({
+          authority: admin.publicKey,
+          arenaConfig: arenaConfigPda,
+          tournament: shootoutTournamentPda,
+          shootout: shootoutPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(started.remainingAccounts)
+        .rpc();
+
+      // Ten players fill two tables in the first round
+      const shootout = await program.account.shootout.fetch(shootoutPda);
+      expect(shootout.round).to.equal(1);
+      expect(shootout.firstTable).to.equal(1);
+      expect(shootout.winners.length).to.equal(2);
+
+      const roundOneWinners = await playRound();
+      await advanceRound();
+
+      const roundTwo = await program.account.shootout.fetch(shootoutPda);
+      expect(roundTwo.round).to.equal(2);
+      expect(roundTwo.firstTable).to.equal(3);
+      expect(roundTwo.players.map((p) => p.toString()).sort()).to.deep.equal(
+        roundOneWinners.map((p) => p.toString()).sort()
+      );
+
+      const [champion] = await playRound();
+      const finished = await program.account.shootout.fetch(shootoutPda);
+      expect(finished.champion.toString()).to.equal(champion.toString());
+    });
+
+    it("should not advance past the champion", async () => {
+      try {
+        await advanceRound();
+
+        expect.fail("Should have thrown CrankNotReady error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("CrankNotReady");
+      }
+    });
+  });
+
+  describe("prize_pools", () => {
+    let poolTournamentPda: PublicKey;
+    let prizePoolPda: PublicKey;
+
+    // Fund the pool as `funder`, or as the admin if none is given
+    const fundPrizePool = (amount: number, funder?: Keypair) =>
+      program.methods
+        .fundPrizePool(new anchor.BN(amount))
+        .accounts({
+          funder: funder?.publicKey ?? admin.publicKey,
+          arenaConfig: arenaConfigPda,
+          tournament: poolTournamentPda,
+          prizePool: prizePoolPda,
+          systemProgram: SystemProgram.programId,
+          funderTokenAccount: null,
+          poolTokenAccount: null,
+          tokenProgram: null,
+        })
+        .signers(funder ? [funder] : [])
+        .rpc();
+
+    before(async () => {
+      poolTournamentPda = await createArenaTournament();
+      prizePoolPda = pda([Buffer.from("prize_pool"), poolTournamentPda.toBuffer()]);
+
+      const payoutBps = new Array(16).fill(0);
+      payoutBps[0] = 7_000;
+      payoutBps[1] = 3_000;
+      await program.methods
+        .createPrizePool(payoutBps, { firstPlace: {} })
+        .accounts({
+          authority: admin.publicKey,
+          arenaConfig: arenaConfigPda,
+          tournament: poolTournamentPda,
+          prizePool: prizePoolPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    });
+
+    it("should pay POINTS prizes in the tournament's points mint", async () => {
+      const prizePool = await program.account.prizePool.fetch(prizePoolPda);
+      expect(prizePool.mint.toString()).to.equal(pointsMint.publicKey.toString());
+      expect(prizePool.total.toNumber()).to.equal(0);
+    });
+
+    it("should let only the admin commit POINTS to the pool", async () => {
+      try {
+        await fundPrizePool(1_000, await fundedPlayer());
+
+        expect.fail("Should have thrown Unauthorized error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("Unauthorized");
+      }
+
+      await fundPrizePool(1_000);
+      const prizePool = await program.account.prizePool.fetch(prizePoolPda);
+      expect(prizePool.total.toNumber()).to.equal(1_000);
+    });
+  });
+
+  describe("penalties", () => {
+    let penaltyTournamentPda: PublicKey;
+    let penaltyPlayers: Keypair[];
+
+    const penalizedRegistration = (player: Keypair) =>
+      program.account.playerRegistration.fetch(registrationPda(penaltyTournamentPda, player.publicKey));
+
+    // Penalize `player`, passing the burn accounts in `extra` once POINTS are distributed
+    const applyPenalty = (
+      player: Keypair,
+      amount: anchor.BN,
+      reason: number[] = reasonHash,
+      extra: Record<string, PublicKey | null> = {}
+    ) =>
+      program.methods
+        .applyPenalty(amount, reason)
+        .accounts({
+          admin: admin.publicKey,
+          arenaConfig: arenaConfigPda,
+          tournament: penaltyTournamentPda,
+          registration: registrationPda(penaltyTournamentPda, player.publicKey),
+          mintAuthority: mintAuthorityPda,
+          ...extra,
+        })
+        .rpc();
+
+    before(async () => {
+      await ensurePointsMint();
+      penaltyPlayers = [await fundedPlayer(), await fundedPlayer(), await fundedPlayer()];
+      penaltyTournamentPda = await completeTournament(penaltyPlayers, [100, 50, 20]);
+    });
+
+    it("should reduce a pending award and record what it took", async () => {
+      await applyPenalty(penaltyPlayers[0], wholePoints(30));
+
+      const registration = await penalizedRegistration(penaltyPlayers[0]);
+      expect(registration.pointsAwarded!.toString()).to.equal(wholePoints(70).toString());
+      expect(registration.penaltyPoints.toString()).to.equal(wholePoints(30).toString());
+      expect(registration.appealableSanction.awardPoints.toString()).to.equal(
+        wholePoints(30).toString()
+      );
+      expect(registration.sanctionNonce).to.equal(1);
+    });
+
+    it("should hold the player's POINTS while the penalty can be appealed", async () => {
+      try {
+        await distributePoints(penaltyTournamentPda, penaltyPlayers[0].publicKey);
+
+        expect.fail("Should have thrown SanctionPending error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("SanctionPending");
+      }
+    });
+
+    it("should cap a penalty at the pending award", async () => {
+      await applyPenalty(penaltyPlayers[1], wholePoints(80));
+
+      const registration = await penalizedRegistration(penaltyPlayers[1]);
+      expect(registration.pointsAwarded!.toNumber()).to.equal(0);
+      expect(registration.penaltyPoints.toString()).to.equal(wholePoints(50).toString());
+    });
+
+    it("should fail without a reason", async () => {
+      try {
+        await applyPenalty(penaltyPlayers[0], wholePoints(1), new Array(32).fill(0));
+
+        expect.fail("Should have thrown InvalidPenalty error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("InvalidPenalty");
+      }
+    });
+
+    it("should fail when called by non-admin", async () => {
+      try {
+        await program.methods
+          .applyPenalty(wholePoints(1), reasonHash)
+          .accounts({
+            admin: player1.publicKey, // Not admin!
+            arenaConfig: arenaConfigPda,
+            tournament: penaltyTournamentPda,
+            registration: registrationPda(penaltyTournamentPda, penaltyPlayers[0].publicKey),
+            mintAuthority: mintAuthorityPda,
+          })
+          .signers([player1])
+          .rpc();
+
+        expect.fail("Should have thrown Unauthorized error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("Unauthorized");
+      }
+    });
+
+    describe("after distribution", () => {
+      let burnAccounts: Record<string, PublicKey>;
+
+      before(async () => {
+        await distributePoints(penaltyTournamentPda, penaltyPlayers[2].publicKey);
+        burnAccounts = {
+          pointsMint: pointsMint.publicKey,
+          playerTokenAccount: await tokenAccount(penaltyPlayers[2].publicKey),
+          tokenProgram: TOKEN_PROGRAM_ID,
+        };
+      });
+
+      it("should fail to burn POINTS the player has not delegated", async () => {
+        try {
+          await applyPenalty(penaltyPlayers[2], wholePoints(5), reasonHash, burnAccounts);
+
+          expect.fail("Should have thrown PenaltyBurnNotDelegated error");
+        } catch (error: any) {
+          expect(error.error.errorCode.code).to.equal("PenaltyBurnNotDelegated");
+        }
+      });
+
+      it("should burn delegated POINTS from the player's account", async () => {
+        await approvePoints(penaltyPlayers[2], mintAuthorityPda, wholePoints(5));
+        const balanceBefore = await tokenBalance(burnAccounts.playerTokenAccount);
+
+        await applyPenalty(penaltyPlayers[2], wholePoints(5), reasonHash, burnAccounts);
+
+        const balanceAfter = await tokenBalance(burnAccounts.playerTokenAccount);
+        expect(balanceBefore).to.equal(wholePoints(20).toString());
+        expect(balanceAfter).to.equal(wholePoints(15).toString());
+
+        const registration = await penalizedRegistration(penaltyPlayers[2]);
+        expect(registration.appealableSanction.burnedPoints.toString()).to.equal(
+          wholePoints(5).toString()
+        );
+      });
+    });
+  });
+
+  describe("appeals", () => {
+    let appealTournamentPda: PublicKey;
+    let appellants: Keypair[];
+    const arbiters = [Keypair.generate(), Keypair.generate()];
+    const arbiterSetPda = pda([Buffer.from("arbiter_set")]);
+
+    const appellantRegistration = (player: Keypair) =>
+      registrationPda(appealTournamentPda, player.publicKey);
+
+    const appealPda = (player: Keypair, nonce: number) =>
+      pda([Buffer.from("appeal"), appellantRegistration(player).toBuffer(), u16Le(nonce)]);
+
+    const penalize = (player: Keypair, amount: anchor.BN, extra: Record<string, PublicKey | null> = {}) =>
+      program.methods
+        .applyPenalty(amount, reasonHash)
+        .accounts({
+          admin: admin.publicKey,
+          arenaConfig: arenaConfigPda,
+          tournament: appealTournamentPda,
+          registration: appellantRegistration(player),
+          mintAuthority: mintAuthorityPda,
+          ...extra,
+        })
+        .rpc();
+
+    const openAppeal = (player: Keypair) =>
+      program.methods
+        .openAppeal(reasonHash)
+        .accounts({
+          player: player.publicKey,
+          tournament: appealTournamentPda,
+          registration: appellantRegistration(player),
+          appeal: appealPda(player, 1),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([player])
+        .rpc();
+
+    // Resolve `player`'s appeal as `caller`, or as the admin if none is given
+    const resolveAppeal = (
+      player: Keypair,
+      granted: boolean,
+      caller?: Keypair,
+      extra: Record<string, PublicKey | null> = {}
+    ) =>
+      program.methods
+        .resolveAppeal(granted)
+        .accounts({
+          caller: caller?.publicKey ?? admin.publicKey,
+          arenaConfig: arenaConfigPda,
+          tournament: appealTournamentPda,
+          registration: appellantRegistration(player),
+          appeal: appealPda(player, 1),
+          ...extra,
+        })
+        .signers(caller ? [caller] : [])
+        .rpc();
+
+    before(async () => {
+      await ensurePointsMint();
+      appellants = [await fundedPlayer(), await fundedPlayer(), await fundedPlayer()];
+      appealTournamentPda = await completeTournament(appellants, [100, 50, 40]);
+
+      // One penalty on a pending award, one burning distributed POINTS, and one to be denied
+      await penalize(appellants[0], wholePoints(40));
+      await distributePoints(appealTournamentPda, appellants[1].publicKey);
+      await approvePoints(appellants[1], mintAuthorityPda, wholePoints(10));
+      await penalize(appellants[1], wholePoints(10), {
+        pointsMint: pointsMint.publicKey,
+        playerTokenAccount: await tokenAccount(appellants[1].publicKey),
+        tokenProgram: TOKEN_PROGRAM_ID,
+      });
+      await penalize(appellants[2], wholePoints(15));
+    });
+
+    it("should open an appeal against the latest sanction", async () => {
+      await openAppeal(appellants[0]);
+
+      const appeal = await program.account.appeal.fetch(appealPda(appellants[0], 1));
+      expect(appeal.status).to.deep.equal({ open: {} });
+      expect(appeal.sanctionNonce).to.equal(1);
+      expect(appeal.wallet.toString()).to.equal(appellants[0].publicKey.toString());
+
+      const registration = await program.account.playerRegistration.fetch(
+        appellantRegistration(appellants[0])
+      );
+      expect(registration.appealPending).to.equal(true);
+    });
+
+    it("should stall further penalties while the appeal is pending", async () => {
+      try {
+        await penalize(appellants[0], wholePoints(1));
+
+        expect.fail("Should have thrown AppealPending error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("AppealPending");
+      }
+    });
+
+    it("should only let the admin resolve before the committee decides", async () => {
+      try {
+        await resolveAppeal(appellants[0], true, player1);
+
+        expect.fail("Should have thrown Unauthorized error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("Unauthorized");
+      }
+    });
+
+    it("should restore a penalized award on grant and release it", async () => {
+      await resolveAppeal(appellants[0], true);
+
+      const appeal = await program.account.appeal.fetch(appealPda(appellants[0], 1));
+      expect(appeal.status).to.deep.equal({ granted: {} });
+
+      const registration = await program.account.playerRegistration.fetch(
+        appellantRegistration(appellants[0])
+      );
+      expect(registration.pointsAwarded!.toString()).to.equal(wholePoints(100).toString());
+      expect(registration.penaltyPoints.toNumber()).to.equal(0);
+      expect(registration.appealPending).to.equal(false);
+      expect(registration.sanctionedAt.toNumber()).to.equal(0);
+
+      // The settled sanction no longer holds the player's POINTS
+      await distributePoints(appealTournamentPda, appellants[0].publicKey);
+      expect(await tokenBalance(await tokenAccount(appellants[0].publicKey))).to.equal(
+        wholePoints(100).toString()
+      );
+    });
+
+    it("should not resolve an appeal twice", async () => {
+      try {
+        await resolveAppeal(appellants[0], false);
+
+        expect.fail("Should have thrown AppealAlreadyResolved error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("AppealAlreadyResolved");
+      }
+    });
+
+    it("should re-mint burned POINTS on grant", async () => {
+      const playerTokenAccount = await tokenAccount(appellants[1].publicKey);
+      expect(await tokenBalance(playerTokenAccount)).to.equal(wholePoints(40).toString());
+      await openAppeal(appellants[1]);
+
+      try {
+        await resolveAppeal(appellants[1], true);
+
+        expect.fail("Should have thrown SanctionRestoreAccountsMissing error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("SanctionRestoreAccountsMissing");
+      }
+
+      await resolveAppeal(appellants[1], true, undefined, {
+        mintAuthority: mintAuthorityPda,
+        pointsMint: pointsMint.publicKey,
+        playerTokenAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      });
+      expect(await tokenBalance(playerTokenAccount)).to.equal(wholePoints(50).toString());
+    });
+
+    it("should let anyone resolve once the arbiter committee agrees", async () => {
+      const members = new Array(7).fill(PublicKey.default);
+      members[0] = arbiters[0].publicKey;
+      members[1] = arbiters[1].publicKey;
+      await program.methods
+        .setArbiterSet(members, 2)
+        .accounts({
+          admin: admin.publicKey,
+          arenaConfig: arenaConfigPda,
+          arbiterSet: arbiterSetPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      await openAppeal(appellants[2]);
+      for (const arbiter of arbiters) {
+        await program.methods
+          .voteOnAppeal(false)
+          .accounts({
+            arbiter: arbiter.publicKey,
+            arbiterSet: arbiterSetPda,
+            appeal: appealPda(appellants[2], 1),
+          })
+          .signers([arbiter])
+          .rpc();
+      }
+
+      // The committee denied the appeal, so it cannot be resolved the other way
+      try {
+        await resolveAppeal(appellants[2], true, player1, { arbiterSet: arbiterSetPda });
+
+        expect.fail("Should have thrown Unauthorized error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("Unauthorized");
+      }
+
+      await resolveAppeal(appellants[2], false, player1, { arbiterSet: arbiterSetPda });
+
+      const appeal = await program.account.appeal.fetch(appealPda(appellants[2], 1));
+      expect(appeal.status).to.deep.equal({ denied: {} });
+
+      const registration = await program.account.playerRegistration.fetch(
+        appellantRegistration(appellants[2])
+      );
+      expect(registration.pointsAwarded!.toString()).to.equal(wholePoints(25).toString());
+      expect(registration.penaltyPoints.toString()).to.equal(wholePoints(15).toString());
+    });
+  });
+
+  describe("merkle_points_claims", () => {
+    let claimTournamentPda: PublicKey;
+    let claimants: Keypair[];
+    let leaves: Buffer[];
+    let pointsRoot: Buffer;
+    // The second leaf claims more than the 30 POINTS recorded for the player
+    const claimed = [60, 45];
+
+    const sha256 = (...parts: Buffer[]) => createHash("sha256").update(Buffer.concat(parts)).digest();
+
+    const leafHash = (wallet: PublicKey, amount: anchor.BN) =>
+      sha256(Buffer.from([0]), claimTournamentPda.toBuffer(), wallet.toBuffer(), amount.toArrayLike(Buffer, "le", 8));
+
+    // Sorted-pair parent of two sibling nodes
+    const nodeHash = (a: Buffer, b: Buffer) =>
+      Buffer.compare(a, b) <= 0 ? sha256(Buffer.from([1]), a, b) : sha256(Buffer.from([1]), b, a);
+
+    const claimPoints = async (claimant: Keypair, amount: anchor.BN, proof: Buffer[]) =>
+      program.methods
+        .claimPointsWithProof(
+          amount,
+          proof.map((node) => Array.from(node))
+        )
+        .accounts({
+          claimant: claimant.publicKey,
+          arenaConfig: arenaConfigPda,
+          tournament: claimTournamentPda,
+          pointsClaim: pda([
+            Buffer.from("points_claim"),
+            claimTournamentPda.toBuffer(),
+            claimant.publicKey.toBuffer(),
+          ]),
+          registration: registrationPda(claimTournamentPda, claimant.publicKey),
+          backingPool: backingPoolPda(claimTournamentPda, claimant.publicKey),
+          pointsMint: pointsMint.publicKey,
+          mintAuthority: mintAuthorityPda,
+          claimantTokenAccount: await tokenAccount(claimant.publicKey),
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([claimant])
+        .rpc();
+
+    const commitPointsRoot = (root: Buffer) =>
+      program.methods
+        .commitPointsRoot(Array.from(root))
+        .accounts({
+          authority: admin.publicKey,
+          arenaConfig: arenaConfigPda,
+          tournament: claimTournamentPda,
+        })
+        .rpc();
+
+    before(async () => {
+      await ensurePointsMint();
+      claimants = [await fundedPlayer(), await fundedPlayer()];
+      claimTournamentPda = await completeTournament(claimants, [60, 30]);
+      leaves = claimants.map((claimant, i) => leafHash(claimant.publicKey, wholePoints(claimed[i])));
+      pointsRoot = nodeHash(leaves[0], leaves[1]);
+    });
+
+    it("should commit the points root once", async () => {
+      await commitPointsRoot(pointsRoot);
+
+      const tournament = await program.account.tournament.fetch(claimTournamentPda);
+      expect(Buffer.from(tournament.pointsRoot!)).to.deep.equal(pointsRoot);
+
+      try {
+        await commitPointsRoot(Buffer.alloc(32, 1));
+
+        expect.fail("Should have thrown PointsRootAlreadyCommitted error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("PointsRootAlreadyCommitted");
+      }
+    });
+
+    it("should settle the tournament only through proofs once a root is committed", async () => {
+      try {
+        await distributePoints(claimTournamentPda, claimants[0].publicKey);
+
+        expect.fail("Should have thrown PointsSettledByMerkleRoot error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("PointsSettledByMerkleRoot");
+      }
+    });
+
+    it("should reject a proof for a different amount", async () => {
+      try {
+        await claimPoints(claimants[0], wholePoints(61), [leaves[1]]);
+
+        expect.fail("Should have thrown InvalidMerkleProof error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("InvalidMerkleProof");
+      }
+    });
+
+    it("should mint a proven award to the claimant", async () => {
+      await claimPoints(claimants[0], wholePoints(60), [leaves[1]]);
+
+      expect(await tokenBalance(await tokenAccount(claimants[0].publicKey))).to.equal(
+        wholePoints(60).toString()
+      );
+      const registration = await program.account.playerRegistration.fetch(
+        registrationPda(claimTournamentPda, claimants[0].publicKey)
+      );
+      expect(registration.pointsDistributed).to.equal(true);
+    });
+
+    it("should not claim the same award twice", async () => {
+      try {
+        await claimPoints(claimants[0], wholePoints(60), [leaves[1]]);
+
+        expect.fail("Should have failed to create the claim marker again");
+      } catch (error: any) {
+        expect(error.logs.join("\n")).to.include("already in use");
+      }
+      expect(await tokenBalance(await tokenAccount(claimants[0].publicKey))).to.equal(
+        wholePoints(60).toString()
+      );
+    });
+
+    it("should cap a claim at the recorded award", async () => {
+      await claimPoints(claimants[1], wholePoints(45), [leaves[0]]);
+
+      expect(await tokenBalance(await tokenAccount(claimants[1].publicKey))).to.equal(
+        wholePoints(30).toString()
+      );
+      const pointsClaim = await program.account.pointsClaim.fetch(
+        pda([Buffer.from("points_claim"), claimTournamentPda.toBuffer(), claimants[1].publicKey.toBuffer()])
+      );
+      expect(pointsClaim.amount.toString()).to.equal(wholePoints(30).toString());
+    });
+  });
+
+  describe("redemption_vault", () => {
+    const redemptionVaultPda = pda([Buffer.from("redemption_vault")]);
+    // 0.001 SOL per whole POINT
+    const lamportsPerPoint = 1_000_000;
+    let holder: Keypair;
+
+    const fundRedemptionVault = (funder: Keypair, lamports: number) =>
+      program.methods
+        .fundRedemptionVault(new anchor.BN(lamports))
+        .accounts({
+          treasury: funder.publicKey,
+          arenaConfig: arenaConfigPda,
+          redemptionVault: redemptionVaultPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([funder])
+        .rpc();
+
+    const redeemPoints = async (amount: anchor.BN) =>
+      program.methods
+        .redeemPoints(amount)
+        .accounts({
+          holder: holder.publicKey,
+          arenaConfig: arenaConfigPda,
+          redemptionVault: redemptionVaultPda,
+          pointsMint: pointsMint.publicKey,
+          holderTokenAccount: await tokenAccount(holder.publicKey),
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([holder])
+        .rpc();
+
+    before(async () => {
+      holder = await pointsHolder(100);
+    });
+
+    it("should only accept funding from the treasury", async () => {
+      const outsider = await fundedPlayer();
+      try {
+        await fundRedemptionVault(outsider, 0.01 * LAMPORTS_PER_SOL);
+
+        expect.fail("Should have thrown Unauthorized error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("Unauthorized");
+      }
+
+      await fundRedemptionVault(treasury, 0.05 * LAMPORTS_PER_SOL);
+
+      const vaultBefore = await provider.connection.getBalance(redemptionVaultPda);
+      await fundRedemptionVault(treasury, 0.01 * LAMPORTS_PER_SOL);
+      expect(await provider.connection.getBalance(redemptionVaultPda)).to.equal(
+        vaultBefore + 0.01 * LAMPORTS_PER_SOL
+      );
+    });
+
+    it("should only let the admin set the rate", async () => {
+      const outsider = await fundedPlayer();
+      try {
+        await program.methods
+          .setRedemptionRate(new anchor.BN(lamportsPerPoint))
+          .accounts({
+            admin: outsider.publicKey,
+            arenaConfig: arenaConfigPda,
+            redemptionVault: redemptionVaultPda,
+          })
+          .signers([outsider])
+          .rpc();
+
+        expect.fail("Should have thrown Unauthorized error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("Unauthorized");
+      }
+
+      await program.methods
+        .setRedemptionRate(new anchor.BN(lamportsPerPoint))
+        .accounts({
+          admin: admin.publicKey,
+          arenaConfig: arenaConfigPda,
+          redemptionVault: redemptionVaultPda,
+        })
+        .rpc();
+
+      const vault = await program.account.redemptionVault.fetch(redemptionVaultPda);
+      expect(vault.lamportsPerPoint.toNumber()).to.equal(lamportsPerPoint);
+    });
+
+    it("should burn redeemed POINTS and pay the fixed rate", async () => {
+      const holderTokenAccount = await tokenAccount(holder.publicKey);
+      const vaultBefore = await program.account.redemptionVault.fetch(redemptionVaultPda);
+      const holderBefore = await provider.connection.getBalance(holder.publicKey);
+      const vaultLamportsBefore = await provider.connection.getBalance(redemptionVaultPda);
+
+      await redeemPoints(wholePoints(10));
+
+      const payout = 10 * lamportsPerPoint;
+      expect(await tokenBalance(holderTokenAccount)).to.equal(wholePoints(90).toString());
+      // The provider wallet pays the transaction fee
+      expect(await provider.connection.getBalance(holder.publicKey)).to.equal(holderBefore + payout);
+      expect(await provider.connection.getBalance(redemptionVaultPda)).to.equal(
+        vaultLamportsBefore - payout
+      );
+
+      const vault = await program.account.redemptionVault.fetch(redemptionVaultPda);
+      expect(vault.totalPointsRedeemed.sub(vaultBefore.totalPointsRedeemed).toString()).to.equal(
+        wholePoints(10).toString()
+      );
+      expect(vault.totalLamportsPaid.sub(vaultBefore.totalLamportsPaid).toNumber()).to.equal(payout);
+    });
+
+    it("should not pay out more than the vault holds above rent", async () => {
+      const holderTokenAccount = await tokenAccount(holder.publicKey);
+      // 90 POINTS at 0.001 SOL is more than the 0.05 SOL left in the vault
+      try {
+        await redeemPoints(wholePoints(90));
+
+        expect.fail("Should have thrown InsufficientRedemptionFunds error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("InsufficientRedemptionFunds");
+      }
+      expect(await tokenBalance(holderTokenAccount)).to.equal(wholePoints(90).toString());
+    });
+  });
+
+  describe("buyback_and_burn", () => {
+    const buybackLedgerPda = pda([Buffer.from("buyback_ledger")]);
+    let buybackVault: PublicKey;
+
+    const setBuybackBps = (buybackBps: number) =>
+      program.methods
+        .setBuybackBps(buybackBps)
+        .accounts({
+          admin: admin.publicKey,
+          arenaConfig: arenaConfigPda,
+          buybackLedger: buybackLedgerPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+    const buybackAndBurn = (signer: Keypair, rakeLamports: number, points: number) =>
+      program.methods
+        .buybackAndBurn(new anchor.BN(rakeLamports), wholePoints(points))
+        .accounts({
+          treasury: signer.publicKey,
+          arenaConfig: arenaConfigPda,
+          buybackLedger: buybackLedgerPda,
+          pointsMint: pointsMint.publicKey,
+          buybackVault,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([signer])
+        .rpc();
+
+    before(async () => {
+      // The treasury's off-chain purchase lands 40 POINTS in the ledger's vault
+      const seller = await pointsHolder(40);
+      buybackVault = await tokenAccount(buybackLedgerPda);
+      await provider.sendAndConfirm(
+        new anchor.web3.Transaction().add(
+          new anchor.web3.TransactionInstruction({
+            programId: TOKEN_PROGRAM_ID,
+            keys: [
+              { pubkey: await tokenAccount(seller.publicKey), isSigner: false, isWritable: true },
+              { pubkey: buybackVault, isSigner: false, isWritable: true },
+              { pubkey: seller.publicKey, isSigner: true, isWritable: false },
+            ],
+            data: Buffer.concat([Buffer.from([3]), wholePoints(40).toArrayLike(Buffer, "le", 8)]), // Transfer
+          })
+        ),
+        [seller]
+      );
+    });
+
+    it("should reject a buyback share above 100%", async () => {
+      try {
+        await setBuybackBps(10_001);
+
+        expect.fail("Should have thrown InvalidBasisPoints error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("InvalidBasisPoints");
+      }
+
+      await setBuybackBps(2_500);
+      const ledger = await program.account.buybackLedger.fetch(buybackLedgerPda);
+      expect(ledger.buybackBps).to.equal(2_500);
+    });
+
+    it("should only let the treasury burn", async () => {
+      const outsider = await fundedPlayer();
+      try {
+        await buybackAndBurn(outsider, LAMPORTS_PER_SOL, 30);
+
+        expect.fail("Should have thrown Unauthorized error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("Unauthorized");
+      }
+      expect(await tokenBalance(buybackVault)).to.equal(wholePoints(40).toString());
+    });
+
+    it("should burn bought-back POINTS and record the rake spent", async () => {
+      const supplyBefore = (await provider.connection.getTokenSupply(pointsMint.publicKey)).value.amount;
+
+      await buybackAndBurn(treasury, LAMPORTS_PER_SOL, 30);
+
+      expect(await tokenBalance(buybackVault)).to.equal(wholePoints(10).toString());
+      const supplyAfter = (await provider.connection.getTokenSupply(pointsMint.publicKey)).value.amount;
+      expect(new anchor.BN(supplyBefore).sub(new anchor.BN(supplyAfter)).toString()).to.equal(
+        wholePoints(30).toString()
+      );
+
+      const ledger = await program.account.buybackLedger.fetch(buybackLedgerPda);
+      expect(ledger.totalRakeLamports.toNumber()).to.equal(LAMPORTS_PER_SOL);
+      expect(ledger.totalLamportsSpent.toNumber()).to.equal(0.25 * LAMPORTS_PER_SOL);
+      expect(ledger.totalPointsBurned.toString()).to.equal(wholePoints(30).toString());
+      expect(ledger.burnCount.toNumber()).to.equal(1);
+      expect(ledger.lastBurnAt.toNumber()).to.be.greaterThan(0);
+    });
+
+    it("should enforce the interval between burns", async () => {
+      try {
+        await buybackAndBurn(treasury, LAMPORTS_PER_SOL, 10);
+
+        expect.fail("Should have thrown BuybackTooSoon error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("BuybackTooSoon");
+      }
+      expect(await tokenBalance(buybackVault)).to.equal(wholePoints(10).toString());
+    });
+  });
+
+  describe("governance", () => {
+    let voter: Keypair;
+    let lateStaker: Keypair;
+    let proposalPda: PublicKey;
+
+    const proposalAt = (id: anchor.BN) => pda([Buffer.from("proposal"), id.toArrayLike(Buffer, "le", 8)]);
+
+    async function createProposal(proposer: Keypair, action: any): Promise<PublicKey> {
+      const governance = await program.account.governance.fetch(governancePda);
+      const proposal = proposalAt(governance.proposalCount);
+      await program.methods
+        .createProposal(action)
+        .accounts({
+          proposer: proposer.publicKey,
+          governance: governancePda,
+          voteStake: voteStakePda(proposer.publicKey),
+          proposal,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([proposer])
+        .rpc();
+      return proposal;
+    }
+
+    const castVote = (wallet: Keypair, support: boolean) =>
+      program.methods
+        .castVote(support)
+        .accounts({
+          voter: wallet.publicKey,
+          proposal: proposalPda,
+          voteStake: voteStakePda(wallet.publicKey),
+          voteRecord: pda([Buffer.from("vote"), proposalPda.toBuffer(), wallet.publicKey.toBuffer()]),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([wallet])
+        .rpc();
+
+    const unstakePoints = async (staker: Keypair, points: number) =>
+      program.methods
+        .unstakePoints(wholePoints(points))
+        .accounts({
+          staker: staker.publicKey,
+          governance: governancePda,
+          voteStake: voteStakePda(staker.publicKey),
+          stakeVault: stakeVaultPda,
+          stakerTokenAccount: await tokenAccount(staker.publicKey),
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([staker])
+        .rpc();
+
+    before(async () => {
+      await ensureGovernance();
+      voter = await pointsHolder(100);
+      lateStaker = await pointsHolder(20);
+    });
+
+    it("should move staked POINTS into the vault", async () => {
+      const vaultBefore = new anchor.BN(await tokenBalance(stakeVaultPda));
+
+      await stakePoints(voter, 60);
+
+      expect(await tokenBalance(await tokenAccount(voter.publicKey))).to.equal(wholePoints(40).toString());
+      expect(new anchor.BN(await tokenBalance(stakeVaultPda)).sub(vaultBefore).toString()).to.equal(
+        wholePoints(60).toString()
+      );
+      const voteStake = await program.account.voteStake.fetch(voteStakePda(voter.publicKey));
+      expect(voteStake.amount.toString()).to.equal(wholePoints(60).toString());
+      expect(voteStake.wallet.toString()).to.equal(voter.publicKey.toString());
+    });
+
+    it("should reject a proposal no arena could accept", async () => {
+      try {
+        await createProposal(voter, { setBuybackBps: { 0: 10_001 } });
+
+        expect.fail("Should have thrown InvalidBasisPoints error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("InvalidBasisPoints");
+      }
+    });
+
+    it("should open voting on a proposal from a staker", async () => {
+      // Only stake placed before the proposal's creation time counts
+      const voteStake = await program.account.voteStake.fetch(voteStakePda(voter.publicKey));
+      await waitForChainTime(voteStake.stakedAt.toNumber());
+
+      proposalPda = await createProposal(voter, { setBuybackBps: { 0: 2_000 } });
+
+      const proposal = await program.account.proposal.fetch(proposalPda);
+      expect(proposal.proposer.toString()).to.equal(voter.publicKey.toString());
+      expect(proposal.votingEndsAt.sub(proposal.createdAt).toNumber()).to.equal(3 * 24 * 60 * 60);
+      expect(proposal.executableAt.sub(proposal.votingEndsAt).toNumber()).to.equal(2 * 24 * 60 * 60);
+      expect(proposal.executed).to.equal(false);
+    });
+
+    it("should not count stake placed after the proposal", async () => {
+      await stakePoints(lateStaker, 20);
+
+      try {
+        await castVote(lateStaker, true);
+
+        expect.fail("Should have thrown NoVotingPower error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("NoVotingPower");
+      }
+    });
+
+    it("should weight a vote by stake and lock the stake until voting ends", async () => {
+      await castVote(voter, true);
+
+      const proposal = await program.account.proposal.fetch(proposalPda);
+      expect(proposal.votesFor.toString()).to.equal(wholePoints(60).toString());
+      expect(proposal.votesAgainst.toNumber()).to.equal(0);
+      const voteStake = await program.account.voteStake.fetch(voteStakePda(voter.publicKey));
+      expect(voteStake.lockedUntil.toNumber()).to.equal(proposal.votingEndsAt.toNumber());
+    });
+
+    it("should not let a wallet vote twice", async () => {
+      try {
+        await castVote(voter, true);
+
+        expect.fail("Should have failed to create the vote record again");
+      } catch (error: any) {
+        expect(error.logs.join("\n")).to.include("already in use");
+      }
+      const proposal = await program.account.proposal.fetch(proposalPda);
+      expect(proposal.votesFor.toString()).to.equal(wholePoints(60).toString());
+    });
+
+    it("should keep a voter's stake locked while voting is open", async () => {
+      try {
+        await unstakePoints(voter, 60);
+
+        expect.fail("Should have thrown StakeLocked error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("StakeLocked");
+      }
+    });
+
+    it("should not execute a proposal before its timelock", async () => {
+      try {
+        await program.methods
+          .executeProposal()
+          .accounts({
+            executor: admin.publicKey,
+            governance: governancePda,
+            proposal: proposalPda,
+            arenaConfig: arenaConfigPda,
+            buybackLedger: pda([Buffer.from("buyback_ledger")]),
+          })
+          .rpc();
+
+        expect.fail("Should have thrown ProposalNotExecutable error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("ProposalNotExecutable");
+      }
+    });
+
+    it("should return unlocked stake, but no more than was staked", async () => {
+      try {
+        await unstakePoints(lateStaker, 21);
+
+        expect.fail("Should have thrown InsufficientBalance error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("InsufficientBalance");
+      }
+
+      await unstakePoints(lateStaker, 20);
+
+      expect(await tokenBalance(await tokenAccount(lateStaker.publicKey))).to.equal(
+        wholePoints(20).toString()
+      );
+      const voteStake = await program.account.voteStake.fetch(voteStakePda(lateStaker.publicKey));
+      expect(voteStake.amount.toNumber()).to.equal(0);
+    });
+  });
+
+  describe("backing", () => {
+    let backedTournamentPda: PublicKey;
+    let player: Keypair;
+    let rival: Keypair;
+    let backers: Keypair[];
+    const target = 0.1 * LAMPORTS_PER_SOL;
+
+    const backerSharePda = (pool: PublicKey, backer: PublicKey) =>
+      pda([Buffer.from("backer_share"), pool.toBuffer(), backer.toBuffer()]);
+
+    const openBacking = (wallet: Keypair, targetLamports: number, offeredShareBps: number) =>
+      program.methods
+        .openBacking(new anchor.BN(targetLamports), offeredShareBps)
+        .accounts({
+          player: wallet.publicKey,
+          tournament: backedTournamentPda,
+          backingPool: backingPoolPda(backedTournamentPda, wallet.publicKey),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([wallet])
+        .rpc();
+
+    const backPlayer = (backer: Keypair, wallet: PublicKey, lamports: number) => {
+      const backingPool = backingPoolPda(backedTournamentPda, wallet);
+      return program.methods
+        .backPlayer(new anchor.BN(lamports))
+        .accounts({
+          backer: backer.publicKey,
+          backingPool,
+          backerShare: backerSharePda(backingPool, backer.publicKey),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([backer])
+        .rpc();
+    };
+
+    const refundBacking = (backer: Keypair, wallet: PublicKey) => {
+      const backingPool = backingPoolPda(backedTournamentPda, wallet);
+      return program.methods
+        .refundBacking()
+        .accounts({
+          backer: backer.publicKey,
+          tournament: backedTournamentPda,
+          backingPool,
+          backerShare: backerSharePda(backingPool, backer.publicKey),
+        })
+        .signers([backer])
+        .rpc();
+    };
+
+    const claimBackerPoints = async (backer: Keypair) => {
+      const backingPool = backingPoolPda(backedTournamentPda, player.publicKey);
+      return program.methods
+        .claimBackerPoints()
+        .accounts({
+          backer: backer.publicKey,
+          arenaConfig: arenaConfigPda,
+          backingPool,
+          tournament: backedTournamentPda,
+          backerShare: backerSharePda(backingPool, backer.publicKey),
+          pointsMint: pointsMint.publicKey,
+          mintAuthority: mintAuthorityPda,
+          backerTokenAccount: await tokenAccount(backer.publicKey),
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([backer])
+        .rpc();
+    };
+
+    before(async () => {
+      await ensurePointsMint();
+      player = await fundedPlayer();
+      rival = await fundedPlayer();
+      backers = [await fundedPlayer(), await fundedPlayer(), await fundedPlayer()];
+      backedTournamentPda = await createArenaTournament();
+      await openRegistration(backedTournamentPda);
+    });
+
+    it("should reject an offered share outside 1-10000 bps", async () => {
+      try {
+        await openBacking(player, target, 10_001);
+
+        expect.fail("Should have thrown InvalidBasisPoints error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("InvalidBasisPoints");
+      }
+    });
+
+    it("should sell shares in proportion to the target", async () => {
+      // Half of the player's winnings for the full 0.1 SOL
+      await openBacking(player, target, 5_000);
+      await backPlayer(backers[0], player.publicKey, 0.04 * LAMPORTS_PER_SOL);
+      await backPlayer(backers[1], player.publicKey, 0.02 * LAMPORTS_PER_SOL);
+
+      const backingPool = backingPoolPda(backedTournamentPda, player.publicKey);
+      const pool = await program.account.backingPool.fetch(backingPool);
+      expect(pool.raisedLamports.toNumber()).to.equal(0.06 * LAMPORTS_PER_SOL);
+      expect(pool.soldShareBps).to.equal(3_000);
+      const share = await program.account.backerShare.fetch(backerSharePda(backingPool, backers[0].publicKey));
+      expect(share.lamports.toNumber()).to.equal(0.04 * LAMPORTS_PER_SOL);
+      expect(share.shareBps).to.equal(2_000);
+    });
+
+    it("should not raise more than the target", async () => {
+      try {
+        await backPlayer(backers[2], player.publicKey, 0.05 * LAMPORTS_PER_SOL);
+
+        expect.fail("Should have thrown BackingTargetExceeded error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("BackingTargetExceeded");
+      }
+    });
+
+    it("should not refund backers while registration is open", async () => {
+      try {
+        await refundBacking(backers[0], player.publicKey);
+
+        expect.fail("Should have thrown BackingNotRefundable error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("BackingNotRefundable");
+      }
+    });
+
+    it("should pay the raised backing to a registered player", async () => {
+      await registerPlayer(backedTournamentPda, player);
+      const balanceBefore = await provider.connection.getBalance(player.publicKey);
+
+      await program.methods
+        .withdrawBacking()
+        .accounts({
+          player: player.publicKey,
+          backingPool: backingPoolPda(backedTournamentPda, player.publicKey),
+          registration: registrationPda(backedTournamentPda, player.publicKey),
+        })
+        .signers([player])
+        .rpc();
+
+      expect(await provider.connection.getBalance(player.publicKey)).to.equal(
+        balanceBefore + 0.06 * LAMPORTS_PER_SOL
+      );
+      const pool = await program.account.backingPool.fetch(
+        backingPoolPda(backedTournamentPda, player.publicKey)
+      );
+      expect(pool.withdrawn).to.equal(true);
+
+      try {
+        await backPlayer(backers[2], player.publicKey, 0.01 * LAMPORTS_PER_SOL);
+
+        expect.fail("Should have thrown BackingAlreadyWithdrawn error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("BackingAlreadyWithdrawn");
+      }
+    });
+
+    it("should refund backers of a pool that was never withdrawn", async () => {
+      await openBacking(rival, target, 5_000);
+      await backPlayer(backers[2], rival.publicKey, 0.05 * LAMPORTS_PER_SOL);
+      await registerPlayer(backedTournamentPda, rival);
+      await startTournament(backedTournamentPda);
+
+      const backingPool = backingPoolPda(backedTournamentPda, rival.publicKey);
+      const backerShare = backerSharePda(backingPool, backers[2].publicKey);
+      const shareRent = await provider.connection.getBalance(backerShare);
+      const balanceBefore = await provider.connection.getBalance(backers[2].publicKey);
+
+      await refundBacking(backers[2], rival.publicKey);
+
+      // The contribution comes back along with the share account's rent
+      expect(await provider.connection.getBalance(backers[2].publicKey)).to.equal(
+        balanceBefore + 0.05 * LAMPORTS_PER_SOL + shareRent
+      );
+      expect(await provider.connection.getAccountInfo(backerShare)).to.equal(null);
+      const pool = await program.account.backingPool.fetch(backingPool);
+      expect(pool.raisedLamports.toNumber()).to.equal(0);
+      expect(pool.soldShareBps).to.equal(0);
+    });
+
+    it("should not pay backers before the player's POINTS are distributed", async () => {
+      await submitResults(backedTournamentPda, player.publicKey);
+      await confirmResults(backedTournamentPda);
+      await recordResult(backedTournamentPda, player.publicKey, 1, 100);
+      await recordResult(backedTournamentPda, rival.publicKey, 2, 0);
+
+      try {
+        await claimBackerPoints(backers[0]);
+
+        expect.fail("Should have thrown PointsNotDistributed error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("PointsNotDistributed");
+      }
+    });
+
+    it("should split the player's POINTS with their backers", async () => {
+      await distributePoints(backedTournamentPda, player.publicKey);
+
+      // 30% of the 100 POINTS was sold to backers
+      expect(await tokenBalance(await tokenAccount(player.publicKey))).to.equal(wholePoints(70).toString());
+      const pool = await program.account.backingPool.fetch(
+        backingPoolPda(backedTournamentPda, player.publicKey)
+      );
+      expect(pool.pointsSettled).to.equal(true);
+      expect(pool.pointsAwarded.toString()).to.equal(wholePoints(100).toString());
+
+      await claimBackerPoints(backers[0]);
+      await claimBackerPoints(backers[1]);
+
+      expect(await tokenBalance(await tokenAccount(backers[0].publicKey))).to.equal(
+        wholePoints(20).toString()
+      );
+      expect(await tokenBalance(await tokenAccount(backers[1].publicKey))).to.equal(
+        wholePoints(10).toString()
+      );
+    });
+
+    it("should not pay a backer twice", async () => {
+      try {
+        await claimBackerPoints(backers[0]);
+
+        expect.fail("Should have thrown PointsAlreadyDistributed error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("PointsAlreadyDistributed");
+      }
+      expect(await tokenBalance(await tokenAccount(backers[0].publicKey))).to.equal(
+        wholePoints(20).toString()
+      );
+    });
+  });
+
+  describe("action_marketplace", () => {
+    let actionTournamentPda: PublicKey;
+    let seller: Keypair;
+    let opponent: Keypair;
+    let buyer: Keypair;
+    const pricePerBps = 10_000;
+
+    const sellerRegistration = () => registrationPda(actionTournamentPda, seller.publicKey);
+    const actionSharePda = (wallet: PublicKey) =>
+      pda([Buffer.from("action_share"), sellerRegistration().toBuffer(), wallet.toBuffer()]);
+
+    const listAction = (availableBps: number) =>
+      program.methods
+        .listAction(availableBps, new anchor.BN(pricePerBps))
+        .accounts({
+          seller: seller.publicKey,
+          arenaConfig: arenaConfigPda,
+          tournament: actionTournamentPda,
+          registration: sellerRegistration(),
+          actionListing: pda([Buffer.from("action_listing"), sellerRegistration().toBuffer()]),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([seller])
+        .rpc();
+
+    const buyAction = (shareBps: number) =>
+      program.methods
+        .buyAction(shareBps)
+        .accounts({
+          buyer: buyer.publicKey,
+          arenaConfig: arenaConfigPda,
+          tournament: actionTournamentPda,
+          registration: sellerRegistration(),
+          actionListing: pda([Buffer.from("action_listing"), sellerRegistration().toBuffer()]),
+          actionShare: actionSharePda(buyer.publicKey),
+          seller: seller.publicKey,
+          treasury: treasury.publicKey,
+          backingPool: backingPoolPda(actionTournamentPda, seller.publicKey),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyer])
+        .rpc();
+
+    const claimActionShare = async () =>
+      program.methods
+        .claimActionShare()
+        .accounts({
+          buyer: buyer.publicKey,
+          arenaConfig: arenaConfigPda,
+          registration: sellerRegistration(),
+          tournament: actionTournamentPda,
+          actionShare: actionSharePda(buyer.publicKey),
+          pointsMint: pointsMint.publicKey,
+          mintAuthority: mintAuthorityPda,
+          buyerTokenAccount: await tokenAccount(buyer.publicKey),
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([buyer])
+        .rpc();
+
+    before(async () => {
+      await ensurePointsMint();
+      seller = await fundedPlayer();
+      opponent = await fundedPlayer();
+      buyer = await fundedPlayer();
+      actionTournamentPda = await createArenaTournament();
+      await openRegistration(actionTournamentPda);
+      await registerPlayer(actionTournamentPda, seller);
+      await registerPlayer(actionTournamentPda, opponent);
+    });
+
+    it("should not list more than 100% of winnings", async () => {
+      try {
+        await listAction(10_001);
+
+        expect.fail("Should have thrown InvalidBasisPoints error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("InvalidBasisPoints");
+      }
+
+      await listAction(2_000);
+    });
+
+    it("should pay the seller and take the marketplace fee", async () => {
+      const sellerBefore = await provider.connection.getBalance(seller.publicKey);
+      const treasuryBefore = await provider.connection.getBalance(treasury.publicKey);
+
+      await buyAction(1_000);
+
+      // 1000 bps at 10,000 lamports each, 2.5% of it to the treasury
+      const price = 1_000 * pricePerBps;
+      const fee = (price * 250) / 10_000;
+      expect(await provider.connection.getBalance(seller.publicKey)).to.equal(sellerBefore + price - fee);
+      expect(await provider.connection.getBalance(treasury.publicKey)).to.equal(treasuryBefore + fee);
+
+      const registration = await program.account.playerRegistration.fetch(sellerRegistration());
+      expect(registration.actionSoldBps).to.equal(1_000);
+      const actionShare = await program.account.actionShare.fetch(actionSharePda(buyer.publicKey));
+      expect(actionShare.shareBps).to.equal(1_000);
+    });
+
+    it("should not sell more than is listed", async () => {
+      try {
+        await buyAction(1_500);
+
+        expect.fail("Should have thrown ActionOversold error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("ActionOversold");
+      }
+    });
+
+    it("should not sell action already offered to backers", async () => {
+      await program.methods
+        .openBacking(new anchor.BN(0.1 * LAMPORTS_PER_SOL), 9_000)
+        .accounts({
+          player: seller.publicKey,
+          tournament: actionTournamentPda,
+          backingPool: backingPoolPda(actionTournamentPda, seller.publicKey),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([seller])
+        .rpc();
+
+      // 90% offered to backers plus 10% sold leaves nothing for the listing
+      try {
+        await buyAction(1_000);
+
+        expect.fail("Should have thrown ActionOversold error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("ActionOversold");
+      }
+    });
+
+    it("should not pay a share before the seller's POINTS are distributed", async () => {
+      await startTournament(actionTournamentPda);
+      await submitResults(actionTournamentPda, seller.publicKey);
+      await confirmResults(actionTournamentPda);
+      await recordResult(actionTournamentPda, seller.publicKey, 1, 100);
+      await recordResult(actionTournamentPda, opponent.publicKey, 2, 0);
+
+      try {
+        await claimActionShare();
+
+        expect.fail("Should have thrown PointsNotDistributed error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("PointsNotDistributed");
+      }
+    });
+
+    it("should split the seller's POINTS with the buyer once", async () => {
+      await distributePoints(actionTournamentPda, seller.publicKey);
+      await claimActionShare();
+
+      expect(await tokenBalance(await tokenAccount(seller.publicKey))).to.equal(wholePoints(90).toString());
+      expect(await tokenBalance(await tokenAccount(buyer.publicKey))).to.equal(wholePoints(10).toString());
+
+      try {
+        await claimActionShare();
+
+        expect.fail("Should have thrown PointsAlreadyDistributed error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("PointsAlreadyDistributed");
+      }
+    });
+  });
+
+  describe("session_keys", () => {
+    let sessionTournamentPda: PublicKey;
+    let wallet: Keypair;
+    let sessionKey: Keypair;
+    const SCOPE_REGISTER = 1;
+    const SCOPE_UPDATE_AGENT = 2;
+
+    const sessionPda = (key: PublicKey) =>
+      pda([Buffer.from("session"), wallet.publicKey.toBuffer(), key.toBuffer()]);
+
+    const createSession = (key: PublicKey, scope: number, expiresAt: number, fundLamports = 0) =>
+      program.methods
+        .createSession(key, scope, new anchor.BN(expiresAt), new anchor.BN(fundLamports))
+        .accounts({
+          wallet: wallet.publicKey,
+          sessionAuthority: sessionPda(key),
+          sessionSigner: key,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([wallet])
+        .rpc();
+
+    const registerWithSession = (key: Keypair) => {
+      const agentName = Buffer.alloc(32);
+      agentName.write("SessionAgent");
+      return program.methods
+        .registerPlayerWithSession(
+          { basic: {} },
+          Array.from(agentPromptHash),
+          Array.from(agentName),
+          Array.from(Buffer.alloc(128)),
+          new anchor.BN(0)
+        )
+        .accounts({
+          sessionKey: key.publicKey,
+          sessionAuthority: sessionPda(key.publicKey),
+          arenaConfig: arenaConfigPda,
+          tournament: sessionTournamentPda,
+          registration: registrationPda(sessionTournamentPda, wallet.publicKey),
+          treasury: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([key])
+        .rpc();
+    };
+
+    before(async () => {
+      wallet = await fundedPlayer();
+      sessionKey = Keypair.generate();
+      sessionTournamentPda = await createArenaTournament();
+      await openRegistration(sessionTournamentPda);
+    });
+
+    it("should not authorize a session for longer than seven days", async () => {
+      const now = await chainTime();
+      try {
+        await createSession(sessionKey.publicKey, SCOPE_REGISTER, now + 8 * 24 * 60 * 60);
+
+        expect.fail("Should have thrown InvalidSession error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("InvalidSession");
+      }
+    });
+
+    it("should fund and authorize a session key", async () => {
+      const expiresAt = (await chainTime()) + 60 * 60;
+
+      await createSession(sessionKey.publicKey, SCOPE_REGISTER, expiresAt, 0.2 * LAMPORTS_PER_SOL);
+
+      expect(await provider.connection.getBalance(sessionKey.publicKey)).to.equal(0.2 * LAMPORTS_PER_SOL);
+      const session = await program.account.sessionAuthority.fetch(sessionPda(sessionKey.publicKey));
+      expect(session.wallet.toString()).to.equal(wallet.publicKey.toString());
+      expect(session.scope).to.equal(SCOPE_REGISTER);
+      expect(session.expiresAt.toNumber()).to.equal(expiresAt);
+    });
+
+    it("should not register through a session without the register scope", async () => {
+      const agentKey = Keypair.generate();
+      const expiresAt = (await chainTime()) + 60 * 60;
+      await createSession(agentKey.publicKey, SCOPE_UPDATE_AGENT, expiresAt, 0.2 * LAMPORTS_PER_SOL);
+
+      try {
+        await registerWithSession(agentKey);
+
+        expect.fail("Should have thrown InvalidSession error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("InvalidSession");
+      }
+    });
+
+    it("should register the wallet with the fee paid by the session key", async () => {
+      const treasuryBefore = await provider.connection.getBalance(treasury.publicKey);
+      const walletBefore = await provider.connection.getBalance(wallet.publicKey);
+
+      await registerWithSession(sessionKey);
+
+      const registration = await program.account.playerRegistration.fetch(
+        registrationPda(sessionTournamentPda, wallet.publicKey)
+      );
+      expect(registration.wallet.toString()).to.equal(wallet.publicKey.toString());
+      expect(registration.tier).to.deep.equal({ basic: {} });
+      expect(await provider.connection.getBalance(treasury.publicKey)).to.equal(treasuryBefore + BASIC_FEE);
+      expect(await provider.connection.getBalance(wallet.publicKey)).to.equal(walletBefore);
+    });
+
+    it("should return the session's rent to the wallet on revoke", async () => {
+      const session = sessionPda(sessionKey.publicKey);
+      const sessionRent = await provider.connection.getBalance(session);
+      const walletBefore = await provider.connection.getBalance(wallet.publicKey);
+
+      await program.methods
+        .revokeSession()
+        .accounts({
+          wallet: wallet.publicKey,
+          sessionAuthority: session,
+        })
+        .signers([wallet])
+        .rpc();
+
+      expect(await provider.connection.getAccountInfo(session)).to.equal(null);
+      expect(await provider.connection.getBalance(wallet.publicKey)).to.equal(walletBefore + sessionRent);
+    });
+  });
+
+  describe("gifted_entries", () => {
+    let giftTournamentPda: PublicKey;
+    let sponsor: Keypair;
+    let beneficiary: Keypair;
+
+    const giftConsentPda = (wallet: PublicKey) =>
+      pda([Buffer.from("gift_consent"), giftTournamentPda.toBuffer(), wallet.toBuffer()]);
+
+    const approveGift = (wallet: Keypair, allowedSponsor: PublicKey) =>
+      program.methods
+        .approveGift(allowedSponsor)
+        .accounts({
+          beneficiary: wallet.publicKey,
+          tournament: giftTournamentPda,
+          giftConsent: giftConsentPda(wallet.publicKey),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([wallet])
+        .rpc();
+
+    const giftRegistration = (from: Keypair, wallet: PublicKey) => {
+      const agentName = Buffer.alloc(32);
+      agentName.write("GiftedAgent");
+      return program.methods
+        .giftRegistration(
+          { basic: {} },
+          Array.from(agentPromptHash),
+          Array.from(agentName),
+          Array.from(Buffer.alloc(128)),
+          new anchor.BN(0)
+        )
+        .accounts({
+          sponsor: from.publicKey,
+          beneficiary: wallet,
+          giftConsent: giftConsentPda(wallet),
+          arenaConfig: arenaConfigPda,
+          tournament: giftTournamentPda,
+          registration: registrationPda(giftTournamentPda, wallet),
+          treasury: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([from])
+        .rpc();
+    };
+
+    before(async () => {
+      sponsor = await fundedPlayer();
+      beneficiary = await fundedPlayer();
+      giftTournamentPda = await createArenaTournament();
+      await openRegistration(giftTournamentPda);
+    });
+
+    it("should not gift an entry without the beneficiary's consent", async () => {
+      try {
+        await giftRegistration(sponsor, beneficiary.publicKey);
+
+        expect.fail("Should have thrown AccountNotInitialized error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("AccountNotInitialized");
+      }
+    });
+
+    it("should only accept gifts from the approved sponsor", async () => {
+      await approveGift(beneficiary, sponsor.publicKey);
+
+      try {
+        await giftRegistration(await fundedPlayer(), beneficiary.publicKey);
+
+        expect.fail("Should have thrown GiftNotApproved error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("GiftNotApproved");
+      }
+    });
+
+    it("should register the beneficiary with the fee paid by the sponsor", async () => {
+      const consent = giftConsentPda(beneficiary.publicKey);
+      const consentRent = await provider.connection.getBalance(consent);
+      const treasuryBefore = await provider.connection.getBalance(treasury.publicKey);
+      const beneficiaryBefore = await provider.connection.getBalance(beneficiary.publicKey);
+
+      await giftRegistration(sponsor, beneficiary.publicKey);
+
+      const registration = await program.account.playerRegistration.fetch(
+        registrationPda(giftTournamentPda, beneficiary.publicKey)
+      );
+      expect(registration.wallet.toString()).to.equal(beneficiary.publicKey.toString());
+      expect(await provider.connection.getBalance(treasury.publicKey)).to.equal(treasuryBefore + BASIC_FEE);
+      // The consent is consumed and its rent returned to the beneficiary
+      expect(await provider.connection.getAccountInfo(consent)).to.equal(null);
+      expect(await provider.connection.getBalance(beneficiary.publicKey)).to.equal(
+        beneficiaryBefore + consentRent
+      );
+    });
+
+    it("should not gift an entry once consent is revoked", async () => {
+      const wallet = await fundedPlayer();
+      await approveGift(wallet, PublicKey.default);
+      const walletBefore = await provider.connection.getBalance(wallet.publicKey);
+      const consentRent = await provider.connection.getBalance(giftConsentPda(wallet.publicKey));
+
+      await program.methods
+        .revokeGiftApproval()
+        .accounts({
+          beneficiary: wallet.publicKey,
+          giftConsent: giftConsentPda(wallet.publicKey),
+        })
+        .signers([wallet])
+        .rpc();
+
+      expect(await provider.connection.getBalance(wallet.publicKey)).to.equal(walletBefore + consentRent);
+      try {
+        await giftRegistration(sponsor, wallet.publicKey);
+
+        expect.fail("Should have thrown AccountNotInitialized error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("AccountNotInitialized");
+      }
+    });
+  });
+
+  describe("guilds", () => {
+    let leader: Keypair;
+    let member: Keypair;
+    let guildTournamentPda: PublicKey;
+    let guildPda: PublicKey;
+    let guildVaultPda: PublicKey;
+
+    const guildName = (name: string) => {
+      const buffer = Buffer.alloc(32);
+      buffer.write(name);
+      return buffer;
+    };
+    const guildMemberPda = (wallet: PublicKey) => pda([Buffer.from("guild_member"), wallet.toBuffer()]);
+
+    const createGuild = (name: Buffer) => {
+      const guild = pda([Buffer.from("guild"), name]);
+      return program.methods
+        .createGuild(Array.from(name), 1_000, true)
+        .accounts({
+          leader: leader.publicKey,
+          guild,
+          guildVault: pda([Buffer.from("guild_vault"), guild.toBuffer()]),
+          guildMember: guildMemberPda(leader.publicKey),
+          guildStats: pda([Buffer.from("guild_stats"), guild.toBuffer()]),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([leader])
+        .rpc();
+    };
+
+    const joinGuild = (wallet: Keypair, officer: Keypair) =>
+      program.methods
+        .joinGuild()
+        .accounts({
+          wallet: wallet.publicKey,
+          officer: officer.publicKey,
+          guild: guildPda,
+          guildMember: guildMemberPda(wallet.publicKey),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([wallet, officer])
+        .rpc();
+
+    const withdrawGuildVault = (signer: Keypair, recipient: PublicKey, lamports: number) =>
+      program.methods
+        .withdrawGuildVault(new anchor.BN(lamports))
+        .accounts({
+          leader: signer.publicKey,
+          guild: guildPda,
+          guildVault: guildVaultPda,
+          recipient,
+        })
+        .signers([signer])
+        .rpc();
+
+    const leaveGuild = (authority: Keypair, wallet: PublicKey) =>
+      program.methods
+        .leaveGuild()
+        .accounts({
+          authority: authority.publicKey,
+          wallet,
+          guild: guildPda,
+          guildMember: guildMemberPda(wallet),
+        })
+        .signers([authority])
+        .rpc();
+
+    before(async () => {
+      await ensurePointsMint();
+      leader = await fundedPlayer();
+      member = await fundedPlayer();
+      guildPda = pda([Buffer.from("guild"), guildName("Test Guild")]);
+      guildVaultPda = pda([Buffer.from("guild_vault"), guildPda.toBuffer()]);
+    });
+
+    it("should reject a guild without a name", async () => {
+      try {
+        await createGuild(Buffer.alloc(32));
+
+        expect.fail("Should have thrown InvalidGuild error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("InvalidGuild");
+      }
+    });
+
+    it("should found a guild with the leader as first member", async () => {
+      await createGuild(guildName("Test Guild"));
+
+      const guild = await program.account.guild.fetch(guildPda);
+      expect(guild.leader.toString()).to.equal(leader.publicKey.toString());
+      expect(guild.memberCount).to.equal(1);
+      expect(guild.winningsShareBps).to.equal(1_000);
+      expect(guild.sponsorTierFees).to.equal(true);
+      expect(guild.vault.toString()).to.equal(guildVaultPda.toString());
+    });
+
+    it("should only admit members through the leader or an officer", async () => {
+      const outsider = await fundedPlayer();
+      try {
+        await joinGuild(member, outsider);
+
+        expect.fail("Should have thrown Unauthorized error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("Unauthorized");
+      }
+
+      await joinGuild(member, leader);
+
+      const guild = await program.account.guild.fetch(guildPda);
+      expect(guild.memberCount).to.equal(2);
+      const guildMember = await program.account.guildMember.fetch(guildMemberPda(member.publicKey));
+      expect(guildMember.guild.toString()).to.equal(guildPda.toString());
+    });
+
+    it("should only let the leader change the guild's policy", async () => {
+      const officers = [member.publicKey, PublicKey.default, PublicKey.default, PublicKey.default];
+      try {
+        await program.methods
+          .updateGuild(officers, 1_000, true)
+          .accounts({ leader: member.publicKey, guild: guildPda })
+          .signers([member])
+          .rpc();
+
+        expect.fail("Should have thrown Unauthorized error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("Unauthorized");
+      }
+    });
+
+    it("should pay a member's tier fee from a funded vault", async () => {
+      await program.methods
+        .fundGuildVault(new anchor.BN(0.5 * LAMPORTS_PER_SOL))
+        .accounts({
+          funder: leader.publicKey,
+          guild: guildPda,
+          guildVault: guildVaultPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([leader])
+        .rpc();
+
+      guildTournamentPda = await createArenaTournament();
+      await openRegistration(guildTournamentPda);
+      const memberBefore = await provider.connection.getBalance(member.publicKey);
+      const vaultBefore = await provider.connection.getBalance(guildVaultPda);
+      const treasuryBefore = await provider.connection.getBalance(treasury.publicKey);
+
+      const registration = await registerPlayer(
+        guildTournamentPda,
+        member,
+        { basic: {} },
+        { guildMember: guildMemberPda(member.publicKey), guild: guildPda, guildVault: guildVaultPda }
+      );
+
+      // The member only pays the registration's rent
+      const registrationRent = await provider.connection.getBalance(registration);
+      expect(await provider.connection.getBalance(member.publicKey)).to.equal(memberBefore - registrationRent);
+      expect(await provider.connection.getBalance(guildVaultPda)).to.equal(vaultBefore - BASIC_FEE);
+      expect(await provider.connection.getBalance(treasury.publicKey)).to.equal(treasuryBefore + BASIC_FEE);
+      const guildVault = await program.account.guildVault.fetch(guildVaultPda);
+      expect(guildVault.totalDeposited.toNumber()).to.equal(0.5 * LAMPORTS_PER_SOL);
+      expect(guildVault.totalFeesPaid.toNumber()).to.equal(BASIC_FEE);
+    });
+
+    it("should route the guild's share of a member's POINTS to the vault", async () => {
+      const opponent = await fundedPlayer();
+      await registerPlayer(guildTournamentPda, opponent);
+      await startTournament(guildTournamentPda);
+      await submitResults(guildTournamentPda, member.publicKey);
+      await confirmResults(guildTournamentPda);
+      await recordResult(guildTournamentPda, member.publicKey, 1, 100);
+      await recordResult(guildTournamentPda, opponent.publicKey, 2, 0);
+
+      const guildTokenAccount = await tokenAccount(guildVaultPda);
+      await distributePoints(guildTournamentPda, member.publicKey, {
+        guildMember: guildMemberPda(member.publicKey),
+        guild: guildPda,
+        guildTokenAccount,
+      });
+
+      expect(await tokenBalance(await tokenAccount(member.publicKey))).to.equal(wholePoints(90).toString());
+      expect(await tokenBalance(guildTokenAccount)).to.equal(wholePoints(10).toString());
+    });
+
+    it("should only let the leader withdraw what the vault holds above rent", async () => {
+      try {
+        await withdrawGuildVault(member, member.publicKey, 0.1 * LAMPORTS_PER_SOL);
+
+        expect.fail("Should have thrown Unauthorized error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("Unauthorized");
+      }
+
+      try {
+        await withdrawGuildVault(leader, leader.publicKey, 0.5 * LAMPORTS_PER_SOL);
+
+        expect.fail("Should have thrown InsufficientBalance error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("InsufficientBalance");
+      }
+
+      const leaderBefore = await provider.connection.getBalance(leader.publicKey);
+      await withdrawGuildVault(leader, leader.publicKey, 0.4 * LAMPORTS_PER_SOL);
+      expect(await provider.connection.getBalance(leader.publicKey)).to.equal(
+        leaderBefore + 0.4 * LAMPORTS_PER_SOL
+      );
+    });
+
+    it("should let members leave, but not the leader", async () => {
+      try {
+        await leaveGuild(leader, leader.publicKey);
+
+        expect.fail("Should have thrown InvalidGuild error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("InvalidGuild");
+      }
+
+      const membership = guildMemberPda(member.publicKey);
+      const membershipRent = await provider.connection.getBalance(membership);
+      const memberBefore = await provider.connection.getBalance(member.publicKey);
+
+      await leaveGuild(member, member.publicKey);
+
+      expect(await provider.connection.getAccountInfo(membership)).to.equal(null);
+      expect(await provider.connection.getBalance(member.publicKey)).to.equal(memberBefore + membershipRent);
+      const guild = await program.account.guild.fetch(guildPda);
+      expect(guild.memberCount).to.equal(1);
+    });
+  });
+
+  describe("points_vesting", () => {
+    let vestingTournamentPda: PublicKey;
+    let winner: Keypair;
+    let runnerUp: Keypair;
+
+    const vestingPda = (wallet: PublicKey) =>
+      pda([Buffer.from("vesting"), vestingTournamentPda.toBuffer(), wallet.toBuffer()]);
+
+    const setVestingPolicy = (thresholdPoints: anchor.BN, cliffSeconds: number, durationSeconds: number) =>
+      program.methods
+        .setVestingPolicy(thresholdPoints, new anchor.BN(cliffSeconds), new anchor.BN(durationSeconds))
+        .accounts({
+          admin: admin.publicKey,
+          arenaConfig: arenaConfigPda,
+        })
+        .rpc();
+
+    const claimVestedPoints = async () =>
+      program.methods
+        .claimVestedPoints()
+        .accounts({
+          player: winner.publicKey,
+          arenaConfig: arenaConfigPda,
+          vestingAccount: vestingPda(winner.publicKey),
+          tournament: vestingTournamentPda,
+          pointsMint: pointsMint.publicKey,
+          mintAuthority: mintAuthorityPda,
+          playerTokenAccount: await tokenAccount(winner.publicKey),
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([winner])
+        .rpc();
+
+    before(async () => {
+      await ensurePointsMint();
+      winner = await fundedPlayer();
+      runnerUp = await fundedPlayer();
+      vestingTournamentPda = await completeTournament([winner, runnerUp], [100, 10]);
+    });
+
+    after(async () => {
+      // Later awards are distributed without vesting
+      await setVestingPolicy(new anchor.BN(0), 0, 0);
+    });
+
+    it("should reject a cliff after the end of vesting", async () => {
+      try {
+        await setVestingPolicy(wholePoints(50), 10, 5);
+
+        expect.fail("Should have thrown InvalidVestingSchedule error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("InvalidVestingSchedule");
+      }
+
+      await setVestingPolicy(wholePoints(50), 4, 8);
+    });
+
+    it("should pay awards below the threshold directly", async () => {
+      await distributePoints(vestingTournamentPda, runnerUp.publicKey);
+
+      expect(await tokenBalance(await tokenAccount(runnerUp.publicKey))).to.equal(wholePoints(10).toString());
+      expect(await provider.connection.getAccountInfo(vestingPda(runnerUp.publicKey))).to.equal(null);
+    });
+
+    it("should require a vesting account for awards at the threshold", async () => {
+      try {
+        await distributePoints(vestingTournamentPda, winner.publicKey);
+
+        expect.fail("Should have thrown VestingAccountMismatch error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("VestingAccountMismatch");
+      }
+    });
+
+    it("should vest a large award instead of minting it", async () => {
+      await distributePoints(vestingTournamentPda, winner.publicKey, {
+        vestingAccount: vestingPda(winner.publicKey),
+      });
+
+      expect(await tokenBalance(await tokenAccount(winner.publicKey))).to.equal("0");
+      const vesting = await program.account.vestingAccount.fetch(vestingPda(winner.publicKey));
+      expect(vesting.totalPoints.toString()).to.equal(wholePoints(100).toString());
+      expect(vesting.claimedPoints.toNumber()).to.equal(0);
+      expect(vesting.cliffAt.sub(vesting.startAt).toNumber()).to.equal(4);
+      expect(vesting.endAt.sub(vesting.startAt).toNumber()).to.equal(8);
+    });
+
+    it("should not release anything before the cliff", async () => {
+      try {
+        await claimVestedPoints();
+
+        expect.fail("Should have thrown NothingVested error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("NothingVested");
+      }
+    });
+
+    it("should release the linearly unlocked portion after the cliff", async () => {
+      const vesting = await program.account.vestingAccount.fetch(vestingPda(winner.publicKey));
+      await waitForChainTime(vesting.cliffAt.toNumber());
+
+      await claimVestedPoints();
+
+      const claimed = (await program.account.vestingAccount.fetch(vestingPda(winner.publicKey))).claimedPoints;
+      expect(claimed.gte(wholePoints(50))).to.equal(true);
+      expect(claimed.lte(wholePoints(100))).to.equal(true);
+      expect(await tokenBalance(await tokenAccount(winner.publicKey))).to.equal(claimed.toString());
+    });
+
+    it("should release the rest once fully vested, and nothing more", async () => {
+      const vesting = await program.account.vestingAccount.fetch(vestingPda(winner.publicKey));
+      await waitForChainTime(vesting.endAt.toNumber());
+
+      await claimVestedPoints();
+
+      expect(await tokenBalance(await tokenAccount(winner.publicKey))).to.equal(wholePoints(100).toString());
+      try {
+        await claimVestedPoints();
+
+        expect.fail("Should have thrown NothingVested error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("NothingVested");
+      }
+    });
+  });
+
+  describe("points_escrow", () => {
+    const windowSeconds = 6;
+    const arbiterSetPda = pda([Buffer.from("arbiter_set")]);
+
+    const setDisputeWindow = (seconds: number) =>
+      program.methods
+        .setDisputeWindow(seconds)
+        .accounts({
+          admin: admin.publicKey,
+          arenaConfig: arenaConfigPda,
+        })
+        .rpc();
+
+    // Start a tournament for `players` in which players[0] knocks out players[1],
+    // recording 100 POINTS for the busted player while play continues
+    async function bustWithAward(players: Keypair[]): Promise<PublicKey> {
+      const tournament = await createArenaTournament();
+      await openRegistration(tournament);
+      for (const player of players) {
+        await registerPlayer(tournament, player);
+      }
+      await startTournament(tournament);
+
+      await recordElimination(tournament, players[0].publicKey, players[1].publicKey);
+      await recordResult(tournament, players[1].publicKey, players.length, 100);
+      return tournament;
+    }
+
+    const releasePoints = async (tournament: PublicKey, wallet: PublicKey) =>
+      program.methods
+        .releasePoints()
+        .accounts({
+          caller: admin.publicKey,
+          arenaConfig: arenaConfigPda,
+          tournament,
+          pointsEscrow: pointsEscrowPda(tournament, wallet),
+          registration: registrationPda(tournament, wallet),
+          pointsMint: pointsMint.publicKey,
+          mintAuthority: mintAuthorityPda,
+          playerTokenAccount: await tokenAccount(wallet),
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+
+    before(async () => {
+      await ensurePointsMint();
+      await setDisputeWindow(windowSeconds);
+    });
+
+    after(async () => {
+      await setDisputeWindow(0);
+    });
+
+    describe("undisputed", () => {
+      let escrowTournamentPda: PublicKey;
+      let winner: Keypair;
+      let busted: Keypair;
+
+      before(async () => {
+        winner = await fundedPlayer();
+        busted = await fundedPlayer();
+        escrowTournamentPda = await bustWithAward([winner, busted]);
+      });
+
+      it("should require an escrow before results are confirmed", async () => {
+        try {
+          await distributePoints(escrowTournamentPda, busted.publicKey);
+
+          expect.fail("Should have thrown PointsEscrowMismatch error");
+        } catch (error: any) {
+          expect(error.error.errorCode.code).to.equal("PointsEscrowMismatch");
+        }
+      });
+
+      it("should hold distributed POINTS in escrow instead of minting them", async () => {
+        await distributePoints(escrowTournamentPda, busted.publicKey, {
+          pointsEscrow: pointsEscrowPda(escrowTournamentPda, busted.publicKey),
+        });
+
+        expect(await tokenBalance(await tokenAccount(busted.publicKey))).to.equal("0");
+        const escrow = await program.account.pointsEscrow.fetch(
+          pointsEscrowPda(escrowTournamentPda, busted.publicKey)
+        );
+        expect(escrow.points.toString()).to.equal(wholePoints(100).toString());
+        expect(escrow.released).to.equal(false);
+      });
+
+      it("should not release POINTS before the dispute window closes", async () => {
+        try {
+          await releasePoints(escrowTournamentPda, busted.publicKey);
+
+          expect.fail("Should have thrown PointsEscrowLocked error");
+        } catch (error: any) {
+          expect(error.error.errorCode.code).to.equal("PointsEscrowLocked");
+        }
+
+        await submitResults(escrowTournamentPda, winner.publicKey);
+
+        try {
+          await releasePoints(escrowTournamentPda, busted.publicKey);
+
+          expect.fail("Should have thrown PointsEscrowLocked error");
+        } catch (error: any) {
+          expect(error.error.errorCode.code).to.equal("PointsEscrowLocked");
+        }
+      });
+
+      it("should release POINTS once the window closes, and only once", async () => {
+        const tournament = await program.account.tournament.fetch(escrowTournamentPda);
+        await waitForChainTime(tournament.disputeWindowEndsAt.toNumber());
+
+        await releasePoints(escrowTournamentPda, busted.publicKey);
+
+        expect(await tokenBalance(await tokenAccount(busted.publicKey))).to.equal(wholePoints(100).toString());
+        const escrow = await program.account.pointsEscrow.fetch(
+          pointsEscrowPda(escrowTournamentPda, busted.publicKey)
+        );
+        expect(escrow.released).to.equal(true);
+
+        try {
+          await releasePoints(escrowTournamentPda, busted.publicKey);
+
+          expect.fail("Should have thrown PointsAlreadyDistributed error");
+        } catch (error: any) {
+          expect(error.error.errorCode.code).to.equal("PointsAlreadyDistributed");
+        }
+      });
+    });
+
+    describe("disputed", () => {
+      let disputedTournamentPda: PublicKey;
+      let winner: Keypair;
+      let busted: Keypair;
+      let challenger: Keypair;
+      const arbiter = Keypair.generate();
+
+      before(async () => {
+        winner = await fundedPlayer();
+        busted = await fundedPlayer();
+        challenger = await fundedPlayer();
+        disputedTournamentPda = await bustWithAward([winner, busted, challenger]);
+        await distributePoints(disputedTournamentPda, busted.publicKey, {
+          pointsEscrow: pointsEscrowPda(disputedTournamentPda, busted.publicKey),
+        });
+        await submitResults(disputedTournamentPda, winner.publicKey);
+
+        await program.methods
+          .disputeTournament(reasonHash)
+          .accounts({
+            player: challenger.publicKey,
+            tournament: disputedTournamentPda,
+            registration: registrationPda(disputedTournamentPda, challenger.publicKey),
+          })
+          .signers([challenger])
+          .rpc();
+      });
+
+      it("should hold POINTS past the window while the dispute is open", async () => {
+        const tournament = await program.account.tournament.fetch(disputedTournamentPda);
+        await waitForChainTime(tournament.disputeWindowEndsAt.toNumber());
+
+        try {
+          await releasePoints(disputedTournamentPda, busted.publicKey);
+
+          expect.fail("Should have thrown PointsEscrowLocked error");
+        } catch (error: any) {
+          expect(error.error.errorCode.code).to.equal("PointsEscrowLocked");
+        }
+      });
+
+      it("should release POINTS once the arbiters uphold the results", async () => {
+        const members = new Array(7).fill(PublicKey.default);
+        members[0] = arbiter.publicKey;
+        await program.methods
+          .setArbiterSet(members, 1)
+          .accounts({
+            admin: admin.publicKey,
+            arenaConfig: arenaConfigPda,
+            arbiterSet: arbiterSetPda,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+        await provider.connection.confirmTransaction(
+          await provider.connection.requestAirdrop(arbiter.publicKey, LAMPORTS_PER_SOL)
+        );
+
+        const attestation = pda([
+          Buffer.from("arbiter_attestation"),
+          disputedTournamentPda.toBuffer(),
+          resultsHash,
+        ]);
+        await program.methods
+          .attestResults(Array.from(resultsHash), winner.publicKey)
+          .accounts({
+            arbiter: arbiter.publicKey,
+            arbiterSet: arbiterSetPda,
+            tournament: disputedTournamentPda,
+            attestation,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([arbiter])
+          .rpc();
+        await program.methods
+          .resolveDispute()
+          .accounts({
+            authority: admin.publicKey,
+            arenaConfig: arenaConfigPda,
+            tournament: disputedTournamentPda,
+            arbiterSet: arbiterSetPda,
+            arbiterAttestation: attestation,
+          })
+          .rpc();
+
+        await releasePoints(disputedTournamentPda, busted.publicKey);
+
+        expect(await tokenBalance(await tokenAccount(busted.publicKey))).to.equal(wholePoints(100).toString());
+      });
+    });
+  });
+
+
+  describe("voided_tournaments", () => {
+    const voidReasonHash = Array.from(createHash("sha256").update("server outage").digest());
+    let voidedTournamentPda: PublicKey;
+    let winner: Keypair;
+    let refunded: Keypair;
+    let busted: Keypair;
+
+    const voidTournament = (authority: Keypair) =>
+      program.methods
+        .voidTournament(voidReasonHash)
+        .accounts({
+          admin: authority.publicKey,
+          arenaConfig: arenaConfigPda,
+          tournament: voidedTournamentPda,
+        })
+        .signers([authority])
+        .rpc();
+
+    const discardVoidedResult = (wallet: PublicKey) =>
+      program.methods
+        .discardVoidedResult()
+        .accounts({
+          caller: admin.publicKey,
+          arenaConfig: arenaConfigPda,
+          tournament: voidedTournamentPda,
+          registration: registrationPda(voidedTournamentPda, wallet),
+          playerStats: pda([Buffer.from("player_stats"), wallet.toBuffer()]),
+          playerStatsV2: pda([Buffer.from("player_stats_v2"), wallet.toBuffer()]),
+          pointsEscrow: pointsEscrowPda(voidedTournamentPda, wallet),
+          backingPool: backingPoolPda(voidedTournamentPda, wallet),
+        })
+        .rpc();
+
+    const refundVoidedRegistration = (signer: Keypair, wallet: PublicKey) =>
+      program.methods
+        .refundVoidedRegistration()
+        .accounts({
+          treasury: signer.publicKey,
+          arenaConfig: arenaConfigPda,
+          tournament: voidedTournamentPda,
+          registration: registrationPda(voidedTournamentPda, wallet),
+          feePayer: wallet,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([signer])
+        .rpc();
+
+    before(async () => {
+      await ensurePointsMint();
+      winner = await fundedPlayer();
+      refunded = await fundedPlayer();
+      busted = await fundedPlayer();
+
+      voidedTournamentPda = await createArenaTournament();
+      await openRegistration(voidedTournamentPda);
+      for (const player of [winner, refunded, busted]) {
+        await registerPlayer(voidedTournamentPda, player, { basic: {} });
+      }
+      await startTournament(voidedTournamentPda);
+
+      // The busted player's award is escrowed while play continues
+      await recordElimination(voidedTournamentPda, winner.publicKey, busted.publicKey);
+      await recordResult(voidedTournamentPda, busted.publicKey, 3, 40);
+      await distributePoints(voidedTournamentPda, busted.publicKey, {
+        pointsEscrow: pointsEscrowPda(voidedTournamentPda, busted.publicKey),
+      });
+    });
+
+    it("should only let the admin void a tournament", async () => {
+      try {
+        await voidTournament(await fundedPlayer());
+
+        expect.fail("Should have thrown Unauthorized error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("Unauthorized");
+      }
+    });
+
+    it("should not refund before the tournament is voided", async () => {
+      try {
+        await refundVoidedRegistration(treasury, refunded.publicKey);
+
+        expect.fail("Should have thrown TournamentNotVoided error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("TournamentNotVoided");
+      }
+    });
+
+    it("should void an in-progress tournament", async () => {
+      await voidTournament(admin);
+
+      const tournament = await program.account.tournament.fetch(voidedTournamentPda);
+      expect(tournament.status).to.deep.equal({ voided: {} });
+    });
+
+    it("should discard a mid-event result and its escrowed POINTS", async () => {
+      const statsPda = pda([Buffer.from("player_stats"), busted.publicKey.toBuffer()]);
+      const statsBefore = await program.account.playerStats.fetch(statsPda);
+
+      await discardVoidedResult(busted.publicKey);
+
+      const registration = await program.account.playerRegistration.fetch(
+        registrationPda(voidedTournamentPda, busted.publicKey)
+      );
+      expect(registration.finalRank).to.be.null;
+      expect(registration.pointsAwarded).to.be.null;
+      expect(registration.pointsDistributed).to.equal(false);
+
+      const escrow = await program.account.pointsEscrow.fetch(
+        pointsEscrowPda(voidedTournamentPda, busted.publicKey)
+      );
+      expect(escrow.points.toNumber()).to.equal(0);
+      expect(escrow.released).to.equal(false);
+
+      const statsAfter = await program.account.playerStats.fetch(statsPda);
+      expect(statsAfter.tournamentsPlayed).to.equal(statsBefore.tournamentsPlayed - 1);
+    });
+
+    it("should reject discarding a registration without a result", async () => {
+      for (const wallet of [busted.publicKey, winner.publicKey]) {
+        try {
+          await discardVoidedResult(wallet);
+
+          expect.fail("Should have thrown NoResultRecorded error");
+        } catch (error: any) {
+          expect(error.error.errorCode.code).to.equal("NoResultRecorded");
+        }
+      }
+    });
+
+    it("should only let the treasury refund tier fees", async () => {
+      try {
+        await refundVoidedRegistration(await fundedPlayer(), refunded.publicKey);
+
+        expect.fail("Should have thrown Unauthorized error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("Unauthorized");
+      }
+    });
+
+    it("should refund the tier fee to its payer once", async () => {
+      const registrationPdaRefunded = registrationPda(voidedTournamentPda, refunded.publicKey);
+      const { feePaidLamports } = await program.account.playerRegistration.fetch(registrationPdaRefunded);
+      const feePaid = feePaidLamports.toNumber();
+      expect(feePaid).to.equal(BASIC_FEE);
+      const playerBefore = await provider.connection.getBalance(refunded.publicKey);
+      const treasuryBefore = await provider.connection.getBalance(treasury.publicKey);
+
+      await refundVoidedRegistration(treasury, refunded.publicKey);
+
+      expect(await provider.connection.getBalance(refunded.publicKey)).to.equal(playerBefore + feePaid);
+      expect(await provider.connection.getBalance(treasury.publicKey)).to.equal(treasuryBefore - feePaid);
+      const registration = await program.account.playerRegistration.fetch(registrationPdaRefunded);
+      expect(registration.refundedLamports.toNumber()).to.equal(feePaid);
+
+      try {
+        await refundVoidedRegistration(treasury, refunded.publicKey);
+
+        expect.fail("Should have thrown NothingToRefund error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("NothingToRefund");
+      }
+    });
+  });
+
+
+  describe("loyalty_discounts", () => {
+    const thresholds = [1, 2, 4];
+    const discountBps = [1000, 2500, 5000];
+    let veteran: Keypair;
+
+    const setLoyaltyConfig = (levels: number[], bps: number[]) =>
+      program.methods
+        .setLoyaltyConfig(levels, bps)
+        .accounts({
+          admin: admin.publicKey,
+          arenaConfig: arenaConfigPda,
+        })
+        .rpc();
+
+    const playerStatsPda = (wallet: PublicKey) => pda([Buffer.from("player_stats"), wallet.toBuffer()]);
+
+    after(async () => {
+      await setLoyaltyConfig([0, 0, 0], [0, 0, 0]);
+    });
+
+    it("should reject discounts above 100%", async () => {
+      try {
+        await setLoyaltyConfig(thresholds, [1000, 2500, 10_001]);
+
+        expect.fail("Should have thrown InvalidBasisPoints error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("InvalidBasisPoints");
+      }
+    });
+
+    it("should reject thresholds that do not increase", async () => {
+      try {
+        await setLoyaltyConfig([2, 2, 4], discountBps);
+
+        expect.fail("Should have thrown InvalidLoyaltyThresholds error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("InvalidLoyaltyThresholds");
+      }
+    });
+
+    it("should set loyalty thresholds and discounts", async () => {
+      await setLoyaltyConfig(thresholds, discountBps);
+
+      const arenaConfig = await program.account.arenaConfig.fetch(arenaConfigPda);
+      expect(arenaConfig.loyaltyThresholds).to.deep.equal(thresholds);
+      expect(arenaConfig.loyaltyDiscountBps).to.deep.equal(discountBps);
+    });
+
+    it("should level up a player as they play tournaments", async () => {
+      veteran = await fundedPlayer();
+      for (let i = 0; i < 2; i++) {
+        await completeTournament([veteran, await fundedPlayer()], [0, 0]);
+      }
+
+      const stats = await program.account.playerStatsV2.fetch(
+        pda([Buffer.from("player_stats_v2"), veteran.publicKey.toBuffer()])
+      );
+      expect(stats.loyaltyLevel).to.deep.equal({ silver: {} });
+    });
+
+    it("should discount the tier fee by the player's loyalty level", async () => {
+      const tournament = await createArenaTournament();
+      await openRegistration(tournament);
+      const treasuryBefore = await provider.connection.getBalance(treasury.publicKey);
+
+      await registerPlayer(tournament, veteran, { basic: {} }, { playerStats: playerStatsPda(veteran.publicKey) });
+
+      // Silver: 25% off the BASIC fee
+      const discountedFee = BASIC_FEE * 0.75;
+      expect(await provider.connection.getBalance(treasury.publicKey)).to.equal(treasuryBefore + discountedFee);
+      const registration = await program.account.playerRegistration.fetch(
+        registrationPda(tournament, veteran.publicKey)
+      );
+      expect(registration.feePaidLamports.toNumber()).to.equal(discountedFee);
+    });
+
+    it("should charge the full fee to a player without stats", async () => {
+      const tournament = await createArenaTournament();
+      await openRegistration(tournament);
+      const treasuryBefore = await provider.connection.getBalance(treasury.publicKey);
+
+      await registerPlayer(tournament, await fundedPlayer(), { basic: {} }, { playerStats: null });
+
+      expect(await provider.connection.getBalance(treasury.publicKey)).to.equal(treasuryBefore + BASIC_FEE);
+    });
+  });
+
+
+  describe("registration_gc", () => {
+    const gcReasonHash = Array.from(createHash("sha256").update("abandoned table").digest());
+    let completedTournamentPda: PublicKey;
+    let players: Keypair[];
+
+    const promptEscrowPda = (registration: PublicKey) =>
+      pda([Buffer.from("prompt_escrow"), registration.toBuffer()]);
+
+    // Accounts closing `wallet`'s registration, with its rent sent to `rentRecipient`
+    const gcAccounts = (tournament: PublicKey, wallet: PublicKey, rentRecipient: PublicKey) => {
+      const registration = registrationPda(tournament, wallet);
+      return {
+        registration,
+        rentRecipient,
+        promptEscrow: promptEscrowPda(registration),
+        backingPool: backingPoolPda(tournament, wallet),
+      };
+    };
+
+    const gcRegistration = (
+      tournament: PublicKey,
+      wallet: PublicKey,
+      rentRecipient: PublicKey,
+      remainingAccounts: anchor.web3.AccountMeta[] = []
+    ) =>
+      program.methods
+        .gcRegistration()
+        .accounts({
+          caller: admin.publicKey,
+          arenaConfig: arenaConfigPda,
+          tournament,
+          prizePool: pda([Buffer.from("prize_pool"), tournament.toBuffer()]),
+          ...gcAccounts(tournament, wallet, rentRecipient),
+        })
+        .remainingAccounts(remainingAccounts)
+        .rpc();
+
+    before(async () => {
+      await ensurePointsMint();
+      players = [await fundedPlayer(), await fundedPlayer(), await fundedPlayer()];
+      completedTournamentPda = await completeTournament(players, [10, 0, 0]);
+    });
+
+    it("should not collect a registration with undistributed POINTS", async () => {
+      try {
+        await gcRegistration(completedTournamentPda, players[0].publicKey, players[0].publicKey);
+
+        expect.fail("Should have thrown RegistrationNotCollectable error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("RegistrationNotCollectable");
+      }
+    });
+
+    it("should only send rent to whoever paid it", async () => {
+      try {
+        await gcRegistration(completedTournamentPda, players[1].publicKey, treasury.publicKey);
+
+        expect.fail("Should have thrown InvalidRentRecipient error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("InvalidRentRecipient");
+      }
+    });
+
+    it("should return a settled registration's rent to its payer", async () => {
+      const wallet = players[1].publicKey;
+      const registration = registrationPda(completedTournamentPda, wallet);
+      const rent = await provider.connection.getBalance(registration);
+      const balanceBefore = await provider.connection.getBalance(wallet);
+
+      await gcRegistration(completedTournamentPda, wallet, wallet);
+
+      expect(await provider.connection.getBalance(wallet)).to.equal(balanceBefore + rent);
+      expect(await provider.connection.getAccountInfo(registration)).to.be.null;
+    });
+
+    it("should collect further registrations passed as remaining accounts", async () => {
+      await distributePoints(completedTournamentPda, players[0].publicKey);
+      const [winner, third] = [players[0].publicKey, players[2].publicKey];
+      const rents = await Promise.all(
+        [winner, third].map((wallet) => provider.connection.getBalance(registrationPda(completedTournamentPda, wallet)))
+      );
+      const balancesBefore = await Promise.all(
+        [winner, third].map((wallet) => provider.connection.getBalance(wallet))
+      );
+
+      const group = gcAccounts(completedTournamentPda, third, third);
+      await gcRegistration(completedTournamentPda, winner, winner, [
+        { pubkey: group.registration, isWritable: true, isSigner: false },
+        { pubkey: group.rentRecipient, isWritable: true, isSigner: false },
+        { pubkey: group.promptEscrow, isWritable: false, isSigner: false },
+        { pubkey: group.backingPool, isWritable: false, isSigner: false },
+      ]);
+
+      expect(await provider.connection.getBalance(winner)).to.equal(balancesBefore[0] + rents[0]);
+      expect(await provider.connection.getBalance(third)).to.equal(balancesBefore[1] + rents[1]);
+      expect(await provider.connection.getAccountInfo(group.registration)).to.be.null;
+    });
+
+    it("should route a voided tournament's rent to the forfeited rent recipient", async () => {
+      const voided = await createArenaTournament();
+      await openRegistration(voided);
+      const entrants = [await fundedPlayer(), await fundedPlayer()];
+      for (const entrant of entrants) {
+        await registerPlayer(voided, entrant);
+      }
+
+      try {
+        await gcRegistration(voided, entrants[0].publicKey, entrants[0].publicKey);
+
+        expect.fail("Should have thrown RegistrationNotCollectable error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("RegistrationNotCollectable");
+      }
+
+      await startTournament(voided);
+      await program.methods
+        .voidTournament(gcReasonHash)
+        .accounts({
+          admin: admin.publicKey,
+          arenaConfig: arenaConfigPda,
+          tournament: voided,
+        })
+        .rpc();
+
+      try {
+        await gcRegistration(voided, entrants[0].publicKey, entrants[0].publicKey);
+
+        expect.fail("Should have thrown InvalidRentRecipient error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("InvalidRentRecipient");
+      }
+
+      const { forfeitedRentRecipient } = await program.account.arenaConfig.fetch(arenaConfigPda);
+      const registration = registrationPda(voided, entrants[0].publicKey);
+      const rent = await provider.connection.getBalance(registration);
+      const balanceBefore = await provider.connection.getBalance(forfeitedRentRecipient);
+
+      await gcRegistration(voided, entrants[0].publicKey, forfeitedRentRecipient);
+
+      expect(await provider.connection.getBalance(forfeitedRentRecipient)).to.equal(balanceBefore + rent);
+      expect(await provider.connection.getAccountInfo(registration)).to.be.null;
+    });
+  });
+
+
+  describe("prize_share_claims", () => {
+    const prize = 1 * LAMPORTS_PER_SOL;
+    let solTournamentPda: PublicKey;
+    let prizePoolPda: PublicKey;
+    let player: Keypair;
+    let opponent: Keypair;
+    let buyer: Keypair;
+    let backer: Keypair;
+
+    const playerRegistration = () => registrationPda(solTournamentPda, player.publicKey);
+    const actionSharePda = () =>
+      pda([Buffer.from("action_share"), playerRegistration().toBuffer(), buyer.publicKey.toBuffer()]);
+    const backerSharePda = () =>
+      pda([
+        Buffer.from("backer_share"),
+        backingPoolPda(solTournamentPda, player.publicKey).toBuffer(),
+        backer.publicKey.toBuffer(),
+      ]);
+    const shareClaimPda = (share: PublicKey) => pda([Buffer.from("prize_share_claim"), share.toBuffer()]);
+
+    const claimActionPrize = () =>
+      program.methods
+        .claimActionPrize()
+        .accounts({
+          buyer: buyer.publicKey,
+          tournament: solTournamentPda,
+          prizePool: prizePoolPda,
+          registration: playerRegistration(),
+          actionShare: actionSharePda(),
+          shareClaim: shareClaimPda(actionSharePda()),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyer])
+        .rpc();
+
+    const claimBackerPrize = () =>
+      program.methods
+        .claimBackerPrize()
+        .accounts({
+          backer: backer.publicKey,
+          tournament: solTournamentPda,
+          prizePool: prizePoolPda,
+          registration: playerRegistration(),
+          backingPool: backingPoolPda(solTournamentPda, player.publicKey),
+          backerShare: backerSharePda(),
+          shareClaim: shareClaimPda(backerSharePda()),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([backer])
+        .rpc();
+
+    before(async () => {
+      player = await fundedPlayer();
+      opponent = await fundedPlayer();
+      buyer = await fundedPlayer();
+      backer = await fundedPlayer();
+
+      // A winner-takes-all SOL pool of 1 SOL
+      solTournamentPda = await createArenaTournament(undefined, { sol: {} });
+      prizePoolPda = pda([Buffer.from("prize_pool"), solTournamentPda.toBuffer()]);
+      const payoutBps = new Array(16).fill(0);
+      payoutBps[0] = 10_000;
+      await program.methods
+        .createPrizePool(payoutBps, { firstPlace: {} })
+        .accounts({
+          authority: admin.publicKey,
+          arenaConfig: arenaConfigPda,
+          tournament: solTournamentPda,
+          prizePool: prizePoolPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      await program.methods
+        .fundPrizePool(new anchor.BN(prize))
+        .accounts({
+          funder: admin.publicKey,
+          arenaConfig: arenaConfigPda,
+          tournament: solTournamentPda,
+          prizePool: prizePoolPda,
+          systemProgram: SystemProgram.programId,
+          funderTokenAccount: null,
+          poolTokenAccount: null,
+          tokenProgram: null,
+        })
+        .rpc();
+
+      // 20% of the winnings sold to the backer: half of them for the full target
+      const backingPool = backingPoolPda(solTournamentPda, player.publicKey);
+      await program.methods
+        .openBacking(new anchor.BN(0.1 * LAMPORTS_PER_SOL), 4_000)
+        .accounts({
+          player: player.publicKey,
+          tournament: solTournamentPda,
+          backingPool,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([player])
+        .rpc();
+      await program.methods
+        .backPlayer(new anchor.BN(0.05 * LAMPORTS_PER_SOL))
+        .accounts({
+          backer: backer.publicKey,
+          backingPool,
+          backerShare: backerSharePda(),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([backer])
+        .rpc();
+
+      await openRegistration(solTournamentPda);
+      await registerPlayer(solTournamentPda, player);
+      await registerPlayer(solTournamentPda, opponent);
+      await program.methods
+        .withdrawBacking()
+        .accounts({
+          player: player.publicKey,
+          backingPool,
+          registration: playerRegistration(),
+        })
+        .signers([player])
+        .rpc();
+
+      // 10% of the winnings sold to the action buyer
+      const actionListing = pda([Buffer.from("action_listing"), playerRegistration().toBuffer()]);
+      await program.methods
+        .listAction(1_000, new anchor.BN(1_000))
+        .accounts({
+          seller: player.publicKey,
+          arenaConfig: arenaConfigPda,
+          tournament: solTournamentPda,
+          registration: playerRegistration(),
+          actionListing,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([player])
+        .rpc();
+      await program.methods
+        .buyAction(1_000)
+        .accounts({
+          buyer: buyer.publicKey,
+          arenaConfig: arenaConfigPda,
+          tournament: solTournamentPda,
+          registration: playerRegistration(),
+          actionListing,
+          actionShare: actionSharePda(),
+          seller: player.publicKey,
+          treasury: treasury.publicKey,
+          backingPool,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyer])
+        .rpc();
+
+      await startTournament(solTournamentPda);
+      await submitResults(solTournamentPda, player.publicKey);
+      await confirmResults(solTournamentPda);
+      await recordResult(solTournamentPda, player.publicKey, 1, 0);
+      await recordResult(solTournamentPda, opponent.publicKey, 2, 0);
+    });
+
+    it("should withhold the sold cuts from the winner's prize", async () => {
+      const balanceBefore = await provider.connection.getBalance(player.publicKey);
+
+      await program.methods
+        .claimPrize()
+        .accounts({
+          caller: admin.publicKey,
+          tournament: solTournamentPda,
+          prizePool: prizePoolPda,
+          registration: playerRegistration(),
+          backingPool: backingPoolPda(solTournamentPda, player.publicKey),
+          wallet: player.publicKey,
+        })
+        .rpc();
+
+      // 10% of the prize belongs to the action buyer and 20% to the backer
+      expect(await provider.connection.getBalance(player.publicKey)).to.equal(balanceBefore + (prize * 7) / 10);
+    });
+
+    it("should pay the action buyer their cut once", async () => {
+      const balanceBefore = await provider.connection.getBalance(buyer.publicKey);
+
+      await claimActionPrize();
+
+      const shareClaim = shareClaimPda(actionSharePda());
+      const claimRent = await provider.connection.getBalance(shareClaim);
+      expect(await provider.connection.getBalance(buyer.publicKey)).to.equal(
+        balanceBefore + prize / 10 - claimRent
+      );
+      const claim = await program.account.prizeShareClaim.fetch(shareClaim);
+      expect(claim.holder.toString()).to.equal(buyer.publicKey.toString());
+      expect(claim.amount.toNumber()).to.equal(prize / 10);
+
+      try {
+        await claimActionPrize();
+
+        expect.fail("Should have rejected a second claim");
+      } catch (error: any) {
+        expect(error.logs.join("\n")).to.include("already in use");
+      }
+    });
+
+    it("should pay the backer of a withdrawn pool their cut once", async () => {
+      const balanceBefore = await provider.connection.getBalance(backer.publicKey);
+
+      await claimBackerPrize();
+
+      const claimRent = await provider.connection.getBalance(shareClaimPda(backerSharePda()));
+      expect(await provider.connection.getBalance(backer.publicKey)).to.equal(
+        balanceBefore + prize / 5 - claimRent
+      );
+
+      try {
+        await claimBackerPrize();
+
+        expect.fail("Should have rejected a second claim");
+      } catch (error: any) {
+        expect(error.logs.join("\n")).to.include("already in use");
+      }
+
+      const prizePool = await program.account.prizePool.fetch(prizePoolPda);
+      expect(prizePool.paidOut.toNumber()).to.equal(prize);
+    });
+  });
+
+
+  describe("treasury_ledger", () => {
+    const treasuryLedgerPda = pda([Buffer.from("treasury_ledger")]);
+    const funding = 0.5 * LAMPORTS_PER_SOL;
+    let ledgerTournamentPda: PublicKey;
+    let prizePoolPda: PublicKey;
+    let winner: Keypair;
+
+    const initTreasuryLedger = (authority: Keypair) =>
+      program.methods
+        .initTreasuryLedger()
+        .accounts({
+          admin: authority.publicKey,
+          arenaConfig: arenaConfigPda,
+          treasuryLedger: treasuryLedgerPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+
+    // Ledger entry with the given sequence number, from the ring buffer
+    const ledgerEntry = async (sequence: number) => {
+      const ledger = await program.account.treasuryLedger.fetch(treasuryLedgerPda);
+      return ledger.entries[sequence % ledger.entries.length];
+    };
+
+    before(async () => {
+      winner = await fundedPlayer();
+      ledgerTournamentPda = await createArenaTournament(undefined, { sol: {} });
+      prizePoolPda = pda([Buffer.from("prize_pool"), ledgerTournamentPda.toBuffer()]);
+      const payoutBps = new Array(16).fill(0);
+      payoutBps[0] = 10_000;
+      await program.methods
+        .createPrizePool(payoutBps, { firstPlace: {} })
+        .accounts({
+          authority: admin.publicKey,
+          arenaConfig: arenaConfigPda,
+          tournament: ledgerTournamentPda,
+          prizePool: prizePoolPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    });
+
+    it("should let only the admin create the ledger", async () => {
+      try {
+        await initTreasuryLedger(await fundedPlayer());
+
+        expect.fail("Should have thrown Unauthorized error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("Unauthorized");
+      }
+
+      await initTreasuryLedger(admin);
+
+      const ledger = await program.account.treasuryLedger.fetch(treasuryLedgerPda);
+      expect(ledger.nextSequence.toNumber()).to.equal(0);
+      expect(ledger.totalInflowLamports.toNumber()).to.equal(0);
+      expect(ledger.totalOutflowLamports.toNumber()).to.equal(0);
+    });
+
+    it("should record prize pool funding as an inflow", async () => {
+      await program.methods
+        .fundPrizePool(new anchor.BN(funding))
+        .accounts({
+          funder: admin.publicKey,
+          arenaConfig: arenaConfigPda,
+          tournament: ledgerTournamentPda,
+          prizePool: prizePoolPda,
+          systemProgram: SystemProgram.programId,
+          funderTokenAccount: null,
+          poolTokenAccount: null,
+          tokenProgram: null,
+          treasuryLedger: treasuryLedgerPda,
+        })
+        .rpc();
+
+      const ledger = await program.account.treasuryLedger.fetch(treasuryLedgerPda);
+      expect(ledger.nextSequence.toNumber()).to.equal(1);
+      expect(ledger.totalInflowLamports.toNumber()).to.equal(funding);
+      const entry = await ledgerEntry(0);
+      expect(entry.sequence.toNumber()).to.equal(0);
+      expect(entry.category).to.deep.equal({ prizePool: {} });
+      expect(entry.direction).to.deep.equal({ inflow: {} });
+      expect(entry.vault.toString()).to.equal(prizePoolPda.toString());
+      expect(entry.amount.toNumber()).to.equal(funding);
+    });
+
+    it("should record a prize claim as an outflow", async () => {
+      await openRegistration(ledgerTournamentPda);
+      const opponent = await fundedPlayer();
+      await registerPlayer(ledgerTournamentPda, winner);
+      await registerPlayer(ledgerTournamentPda, opponent);
+      await startTournament(ledgerTournamentPda);
+      await submitResults(ledgerTournamentPda, winner.publicKey);
+      await confirmResults(ledgerTournamentPda);
+      await recordResult(ledgerTournamentPda, winner.publicKey, 1, 0);
+      await recordResult(ledgerTournamentPda, opponent.publicKey, 2, 0);
+      const balanceBefore = await provider.connection.getBalance(winner.publicKey);
+
+      await program.methods
+        .claimPrize()
+        .accounts({
+          caller: admin.publicKey,
+          tournament: ledgerTournamentPda,
+          prizePool: prizePoolPda,
+          registration: registrationPda(ledgerTournamentPda, winner.publicKey),
+          backingPool: backingPoolPda(ledgerTournamentPda, winner.publicKey),
+          wallet: winner.publicKey,
+          treasuryLedger: treasuryLedgerPda,
+        })
+        .rpc();
+
+      expect(await provider.connection.getBalance(winner.publicKey)).to.equal(balanceBefore + funding);
+      const ledger = await program.account.treasuryLedger.fetch(treasuryLedgerPda);
+      expect(ledger.nextSequence.toNumber()).to.equal(2);
+      expect(ledger.totalOutflowLamports.toNumber()).to.equal(funding);
+      const entry = await ledgerEntry(1);
+      expect(entry.direction).to.deep.equal({ outflow: {} });
+      expect(entry.vault.toString()).to.equal(prizePoolPda.toString());
+      expect(entry.amount.toNumber()).to.equal(funding);
+    });
+  });
+
+
+  describe("holder_discount", () => {
+    const discountBps = 2_000;
+    // 20% off the BASIC fee
+    const discountedFee = BASIC_FEE - (BASIC_FEE * discountBps) / 10_000;
+
+    const setHolderDiscount = (minPoints: number, bps: number) =>
+      program.methods
+        .setHolderDiscount(wholePoints(minPoints), bps)
+        .accounts({
+          admin: admin.publicKey,
+          arenaConfig: arenaConfigPda,
+        })
+        .rpc();
+
+    // Register `player` for BASIC with their governance stake, returning the fee the treasury received
+    async function registerWithStake(tournament: PublicKey, player: Keypair): Promise<number> {
+      const treasuryBefore = await provider.connection.getBalance(treasury.publicKey);
+      await registerPlayer(tournament, player, { basic: {} }, { voteStake: voteStakePda(player.publicKey) });
+      return (await provider.connection.getBalance(treasury.publicKey)) - treasuryBefore;
+    }
+
+    before(async () => {
+      await ensureGovernance();
+    });
+
+    after(async () => {
+      await setHolderDiscount(0, 0);
+    });
+
+    it("should reject a discount above 100%", async () => {
+      try {
+        await setHolderDiscount(50, 10_001);
+
+        expect.fail("Should have thrown InvalidBasisPoints error");
+      } catch (error: any) {
+        expect(error.error.errorCode.code).to.equal("InvalidBasisPoints");
+      }
+
+      await setHolderDiscount(50, discountBps);
+      const arenaConfig = await program.account.arenaConfig.fetch(arenaConfigPda);
+      expect(arenaConfig.holderDiscountMinPoints.toString()).to.equal(wholePoints(50).toString());
+      expect(arenaConfig.holderDiscountBps).to.equal(discountBps);
+    });
+
+    it("should discount wallets that staked enough before the tournament was created", async () => {
+      const holder = await pointsHolder(100);
+      await stakePoints(holder, 60);
+      const stake = await program.account.voteStake.fetch(voteStakePda(holder.publicKey));
+      await waitForChainTime(stake.stakedAt.toNumber());
+      const tournament = await createArenaTournament();
+      await openRegistration(tournament);
+
+      expect(await registerWithStake(tournament, holder)).to.equal(discountedFee);
+      const registration = await program.account.playerRegistration.fetch(
+        registrationPda(tournament, holder.publicKey)
+      );
+      expect(registration.holderDiscountBps).to.equal(discountBps);
+      expect(registration.feePaidLamports.toNumber()).to.equal(discountedFee);
+    });
+
+    it("should not discount wallets below the minimum stake", async () => {
+      const holder = await pointsHolder(100);
+      await stakePoints(holder, 40);
+      const stake = await program.account.voteStake.fetch(voteStakePda(holder.publicKey));
+      await waitForChainTime(stake.stakedAt.toNumber());
+      const tournament = await createArenaTournament();
+      await openRegistration(tournament);
+
+      expect(await registerWithStake(tournament, holder)).to.equal(BASIC_FEE);
+    });
+
+    it("should not discount POINTS staked after the tournament was created", async () => {
+      const holder = await pointsHolder(100);
+      const tournament = await createArenaTournament();
+      await openRegistration(tournament);
+      await stakePoints(holder, 60);
+
+      expect(await registerWithStake(tournament, holder)).to.equal(BASIC_FEE);
+      const registration = await program.account.playerRegistration.fetch(
+        registrationPda(tournament, holder.publicKey)
+      );
+      expect(registration.holderDiscountBps).to.equal(0);
+    });
+  });
 });